@@ -0,0 +1,46 @@
+//! Measures the deserialization of full pages of operations and trades.
+//!
+//! Run with `cargo bench --bench deserialization`. Criterion compares every run with the previous
+//! one, and reports a regression when a benchmark became significantly slower.
+//!
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use stellar_rs::models::prelude::*;
+use stellar_rs::operations::prelude::*;
+use stellar_rs::trades::prelude::*;
+
+/// The number of records on every page, matching the maximum page size of Horizon.
+const RECORDS_PER_PAGE: usize = 200;
+
+fn deserialization(c: &mut Criterion) {
+    let operations = page(
+        include_str!("../tests/fixtures/operations/operations_with_transactions.json"),
+        RECORDS_PER_PAGE,
+    );
+    let trades = page(
+        include_str!("../tests/fixtures/trades/all_trades.json"),
+        RECORDS_PER_PAGE,
+    );
+
+    let mut group = c.benchmark_group("deserialization");
+    group.throughput(Throughput::Elements(RECORDS_PER_PAGE as u64));
+    group.bench_function("operations", |b| {
+        b.iter(|| OperationResponse::from_json(black_box(operations.clone())).unwrap())
+    });
+    group.bench_function("trades", |b| {
+        b.iter(|| AllTradesResponse::from_json(black_box(trades.clone())).unwrap())
+    });
+    group.finish();
+}
+
+/// Builds a page with the given number of records, by repeating the records of a fixture.
+fn page(fixture: &str, records: usize) -> String {
+    let mut page: serde_json::Value = serde_json::from_str(fixture).unwrap();
+    let fixture_records = page["_embedded"]["records"].as_array().unwrap().clone();
+    page["_embedded"]["records"] = fixture_records.into_iter().cycle().take(records).collect();
+
+    page.to_string()
+}
+
+criterion_group!(benches, deserialization);
+criterion_main!(benches);
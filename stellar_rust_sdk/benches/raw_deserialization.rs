@@ -0,0 +1,106 @@
+//! Compares the throughput of typed and raw deserialization of pages of operations.
+//!
+//! Run with `cargo bench --bench raw_deserialization`.
+//!
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use stellar_rs::models::prelude::*;
+use stellar_rs::operations::prelude::*;
+
+/// The number of records on every page, matching the maximum page size of Horizon.
+const RECORDS_PER_PAGE: usize = 200;
+
+/// The number of pages deserialized in every measurement.
+const PAGES: usize = 500;
+
+fn main() {
+    let page = operations_page(RECORDS_PER_PAGE);
+
+    let typed = measure("typed (OperationResponse)", &page, |json| {
+        let page = OperationResponse::from_json(json).unwrap();
+        page.embedded().records().len()
+    });
+    let raw = measure("raw (RawPage)", &page, |json| {
+        let page = RawPage::from_json(json).unwrap();
+        page.len()
+    });
+    let borrowed = measure("raw + borrowed (BorrowedOperation)", &page, |json| {
+        let page = RawPage::from_json(json).unwrap();
+        let operations: Vec<BorrowedOperation> = page.deserialize_records().unwrap();
+        operations.len()
+    });
+
+    println!();
+    println!(
+        "raw is {:.2}x, raw + borrowed is {:.2}x the throughput of typed",
+        typed.as_secs_f64() / raw.as_secs_f64(),
+        typed.as_secs_f64() / borrowed.as_secs_f64()
+    );
+}
+
+/// Deserializes the page `PAGES` times and reports the throughput in records per second.
+fn measure(name: &str, page: &str, deserialize: impl Fn(String) -> usize) -> Duration {
+    // Warm up, so that allocations and caches do not skew the first measurement.
+    for _ in 0..PAGES / 10 {
+        black_box(deserialize(page.to_string()));
+    }
+
+    let start = Instant::now();
+    let mut records = 0;
+    for _ in 0..PAGES {
+        records += black_box(deserialize(page.to_string()));
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{:<36} {:>10.0} records/s ({:?} for {} records)",
+        name,
+        records as f64 / elapsed.as_secs_f64(),
+        elapsed,
+        records
+    );
+    elapsed
+}
+
+/// Builds a page of `create_account` operations, as returned by Horizon.
+fn operations_page(records: usize) -> String {
+    let records: Vec<String> = (0..records)
+        .map(|i| {
+            let id = 2314987376641u64 + i as u64;
+            format!(
+                r#"{{
+                    "_links": {{
+                        "self": {{"href": "https://horizon-testnet.stellar.org/operations/{id}"}},
+                        "transaction": {{"href": "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"}},
+                        "effects": {{"href": "https://horizon-testnet.stellar.org/operations/{id}/effects"}},
+                        "succeeds": {{"href": "https://horizon-testnet.stellar.org/effects?order=desc&cursor={id}"}},
+                        "precedes": {{"href": "https://horizon-testnet.stellar.org/effects?order=asc&cursor={id}"}}
+                    }},
+                    "id": "{id}",
+                    "paging_token": "{id}",
+                    "transaction_successful": true,
+                    "source_account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+                    "type": "create_account",
+                    "type_i": 0,
+                    "created_at": "2024-06-11T21:36:12Z",
+                    "transaction_hash": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020",
+                    "starting_balance": "10000000000.0000000",
+                    "funder": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+                    "account": "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE"
+                }}"#
+            )
+        })
+        .collect();
+
+    format!(
+        r#"{{
+            "_links": {{
+                "self": {{"href": "https://horizon-testnet.stellar.org/operations?cursor=&limit=200&order=asc"}},
+                "next": {{"href": "https://horizon-testnet.stellar.org/operations?cursor=2314987376840&limit=200&order=asc"}},
+                "prev": {{"href": "https://horizon-testnet.stellar.org/operations?cursor=2314987376641&limit=200&order=desc"}}
+            }},
+            "_embedded": {{"records": [{}]}}
+        }}"#,
+        records.join(",")
+    )
+}
@@ -0,0 +1,64 @@
+//! Measures building the URLs of requests with many query parameters.
+//!
+//! Run with `cargo bench --bench url_building`. Criterion compares every run with the previous
+//! one, and reports a regression when a benchmark became significantly slower.
+//!
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use stellar_rs::models::prelude::*;
+use stellar_rs::models::{Order, Request};
+use stellar_rs::paths::prelude::*;
+use stellar_rs::trade_aggregations::prelude::*;
+
+const BASE_URL: &str = "https://horizon-testnet.stellar.org";
+const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+const ACCOUNT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+fn url_building(c: &mut Criterion) {
+    let usdc = AssetData {
+        asset_code: "USDC".to_string(),
+        asset_issuer: ISSUER.to_string(),
+    };
+
+    // The source assets of a path request are the longest query parameter Horizon accepts.
+    let source_assets = (0..15)
+        .map(|i| AssetType::issued(format!("ASSET{}", i), ISSUER))
+        .chain([AssetType::Native])
+        .collect();
+    let strict_receive = ListStrictReceivePaymentPathsRequest::new()
+        .set_destination_asset(AssetType::Alphanumeric4(usdc.clone()))
+        .unwrap()
+        .set_destination_amount("100.0000000".to_string())
+        .unwrap()
+        .set_source(Source::SourceAssets(source_assets))
+        .unwrap()
+        .set_destination_account(ACCOUNT.to_string())
+        .unwrap();
+
+    let trade_aggregations = TradeAggregationsRequest::new()
+        .set_base_asset(AssetType::Native)
+        .unwrap()
+        .set_counter_asset(AssetType::Alphanumeric4(usdc))
+        .unwrap()
+        .set_resolution(Resolution(ResolutionData::Duration3600000))
+        .unwrap()
+        .set_start_time(Some(1_717_200_000_000))
+        .unwrap()
+        .set_end_time(Some(1_717_286_400_000))
+        .unwrap()
+        .set_max_limit()
+        .set_order(Order::Desc)
+        .unwrap();
+
+    let mut group = c.benchmark_group("url_building");
+    group.bench_function("strict_receive_paths", |b| {
+        b.iter(|| black_box(&strict_receive).build_url(BASE_URL))
+    });
+    group.bench_function("trade_aggregations", |b| {
+        b.iter(|| black_box(&trade_aggregations).build_url(BASE_URL))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, url_building);
+criterion_main!(benches);
@@ -0,0 +1,277 @@
+//! A cookbook of common tasks, exercising the SDK end-to-end against the Stellar testnet.
+//!
+//! The cookbook is only built when the `cookbook` feature is enabled:
+//!
+//! ```bash
+//! cargo run --example cookbook --features cookbook -- <subcommand> [arguments]
+//! ```
+//!
+//! # Subcommands
+//!
+//! * `fetch-account <account_id>` - Prints the balances and the required reserve of an account.
+//! * `watch-payments [paging_token]` - Streams payment operations, optionally resuming after a
+//!   paging token, until the end of the history is reached or Ctrl+C is pressed.
+//! * `submit-tx <transaction_envelope_xdr>` - Submits a signed transaction, resolving its outcome
+//!   if the submission times out.
+//! * `find-path <source_asset> <source_amount> <destination_account>` - Lists the strict send
+//!   payment paths from an asset to the assets held by the destination account.
+//! * `market-depth <selling_asset> <buying_asset>` - Prints the order book of an asset pair.
+//!
+//! Assets are written as `native` or `CODE:ISSUER`. The Horizon server can be changed by setting
+//! the `HORIZON_URL` environment variable.
+//!
+use futures::StreamExt;
+use stellar_rs::accounts::prelude::*;
+use stellar_rs::horizon_client::HorizonClient;
+use stellar_rs::models::prelude::*;
+use stellar_rs::operations::prelude::*;
+use stellar_rs::order_book::prelude::*;
+use stellar_rs::paths::prelude::*;
+use stellar_rs::streaming::{CancellationToken, StreamOptions};
+use stellar_rs::transactions::prelude::*;
+
+/// The Horizon server used when `HORIZON_URL` is not set.
+const TESTNET_HORIZON_URL: &str = "https://horizon-testnet.stellar.org";
+
+/// The passphrase of the test network.
+const TESTNET_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+const USAGE: &str = "Usage: cookbook <subcommand> [arguments]
+
+Subcommands:
+    fetch-account <account_id>
+    watch-payments [paging_token]
+    submit-tx <transaction_envelope_xdr>
+    find-path <source_asset> <source_amount> <destination_account>
+    market-depth <selling_asset> <buying_asset>
+
+Assets are written as `native` or `CODE:ISSUER`.";
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let base_url = std::env::var("HORIZON_URL").unwrap_or_else(|_| TESTNET_HORIZON_URL.to_string());
+
+    if let Err(e) = run(&base_url, &args).await {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Runs the subcommand selected by the command line arguments.
+async fn run(base_url: &str, args: &[String]) -> Result<(), String> {
+    let horizon_client = HorizonClient::new(base_url)?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["fetch-account", account_id] => fetch_account(&horizon_client, account_id).await,
+        ["watch-payments"] => watch_payments(&horizon_client, None).await,
+        ["watch-payments", paging_token] => {
+            watch_payments(&horizon_client, Some(paging_token)).await
+        }
+        ["submit-tx", transaction_envelope_xdr] => {
+            submit_tx(&horizon_client, transaction_envelope_xdr).await
+        }
+        ["find-path", source_asset, source_amount, destination_account] => {
+            find_path(
+                &horizon_client,
+                source_asset,
+                source_amount,
+                destination_account,
+            )
+            .await
+        }
+        ["market-depth", selling_asset, buying_asset] => {
+            market_depth(&horizon_client, selling_asset, buying_asset).await
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Prints the balances and the required reserve of an account.
+async fn fetch_account(horizon_client: &HorizonClient, account_id: &str) -> Result<(), String> {
+    let request = SingleAccountRequest::new().set_account_id(account_id)?;
+
+    let account = horizon_client.get_single_account(&request).await?;
+    println!("Account:  {}", account.account_id());
+    println!("Sequence: {}", account.sequence());
+    for balance in account.balances() {
+        println!("Balance:  {} ({})", balance.balance(), balance.asset_type());
+    }
+
+    let reserve = horizon_client
+        .get_account_required_reserve(&request)
+        .await?;
+    println!("Reserve:  {} XLM", reserve);
+    println!(
+        "Spendable: {} stroops",
+        reserve.available_to_send(&account)?
+    );
+
+    Ok(())
+}
+
+/// Streams payment operations until the end of the history is reached or Ctrl+C is pressed.
+async fn watch_payments(
+    horizon_client: &HorizonClient,
+    resume_from: Option<&str>,
+) -> Result<(), String> {
+    let shutdown = CancellationToken::new();
+    let mut options = StreamOptions::new()
+        .set_shutdown_token(shutdown.clone())
+        .set_checkpoint_callback(|paging_token| eprintln!("Checkpoint: {}", paging_token));
+    if let Some(paging_token) = resume_from {
+        options = options.set_resume_from(paging_token);
+    }
+
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            shutdown.cancel();
+        }
+    });
+
+    let request = AllOperationsRequest::new().set_limit(200)?;
+    let stream = horizon_client.stream_operations_filtered_with_options(
+        &request,
+        OperationTypeFilter::PAYMENTS,
+        options,
+    );
+    futures::pin_mut!(stream);
+
+    while let Some(operation) = stream.next().await {
+        let operation = operation?;
+        println!(
+            "{} {} from {} in {}",
+            operation.created_at(),
+            operation.type_field(),
+            operation.source_account(),
+            operation.transaction_hash()
+        );
+    }
+
+    Ok(())
+}
+
+/// Submits a signed transaction to the test network.
+async fn submit_tx(
+    horizon_client: &HorizonClient,
+    transaction_envelope_xdr: &str,
+) -> Result<(), String> {
+    let request =
+        PostTransactionRequest::new().set_transaction_envelope_xdr(transaction_envelope_xdr)?;
+    let options = SubmitTransactionOptions::new(TESTNET_PASSPHRASE);
+
+    match horizon_client
+        .submit_transaction(&request, &options)
+        .await?
+    {
+        TransactionSubmissionOutcome::Submitted(transaction) => {
+            println!(
+                "Transaction {} included in ledger {}",
+                transaction.hash(),
+                transaction.ledger()
+            );
+        }
+        TransactionSubmissionOutcome::ConfirmedAfterTimeout(transaction) => {
+            println!(
+                "Transaction {} included in ledger {} after the submission timed out",
+                transaction.hash(),
+                transaction.ledger()
+            );
+        }
+        TransactionSubmissionOutcome::Pending { hash } => {
+            println!(
+                "Transaction {} has not been included yet; it is safe to resubmit",
+                hash
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the strict send payment paths from an asset to the assets held by an account.
+async fn find_path(
+    horizon_client: &HorizonClient,
+    source_asset: &str,
+    source_amount: &str,
+    destination_account: &str,
+) -> Result<(), String> {
+    let request = ListStrictSendPaymentPathsRequest::new()
+        .set_source_asset(parse_asset(source_asset)?)?
+        .set_source_amount(source_amount)?
+        .set_destination(Destination::DestinationAccount(
+            destination_account.to_string(),
+        ))?;
+
+    let response = horizon_client
+        .get_list_strict_send_payment_paths(&request)
+        .await?;
+    for path in response.embedded().records() {
+        let hops: Vec<&str> = path.path().iter().map(asset_code).collect();
+        println!(
+            "{} {} -> {} {} via [{}]",
+            path.source_amount(),
+            path.source_asset_code().as_deref().unwrap_or("XLM"),
+            path.destination_amount(),
+            path.destination_asset_code().as_deref().unwrap_or("XLM"),
+            hops.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints the order book of an asset pair.
+async fn market_depth(
+    horizon_client: &HorizonClient,
+    selling_asset: &str,
+    buying_asset: &str,
+) -> Result<(), String> {
+    let request = DetailsRequest::new()
+        .set_selling_asset(parse_asset(selling_asset)?)?
+        .set_buying_asset(parse_asset(buying_asset)?)?;
+
+    let order_book = horizon_client.get_order_book_details(&request).await?;
+    println!("Asks:");
+    for ask in order_book.asks() {
+        println!("  {} @ {}", ask.amount(), ask.price());
+    }
+    println!("Bids:");
+    for bid in order_book.bids() {
+        println!("  {} @ {}", bid.amount(), bid.price());
+    }
+
+    Ok(())
+}
+
+/// Parses an asset written as `native` or `CODE:ISSUER`.
+fn parse_asset(asset: &str) -> Result<AssetType, String> {
+    if asset == "native" {
+        return Ok(AssetType::Native);
+    }
+
+    let (asset_code, asset_issuer) = asset
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid asset: {}", asset))?;
+    let asset_data = AssetData {
+        asset_code: asset_code.to_string(),
+        asset_issuer: asset_issuer.to_string(),
+    };
+
+    match asset_code.len() {
+        1..=4 => Ok(AssetType::Alphanumeric4(asset_data)),
+        5..=12 => Ok(AssetType::Alphanumeric12(asset_data)),
+        _ => Err(format!("Invalid asset code: {}", asset_code)),
+    }
+}
+
+/// Returns the code of an asset, or `XLM` for the native asset.
+fn asset_code(asset: &AssetType) -> &str {
+    match asset {
+        AssetType::Native => "XLM",
+        AssetType::Alphanumeric4(asset_data) | AssetType::Alphanumeric12(asset_data) => {
+            &asset_data.asset_code
+        }
+    }
+}
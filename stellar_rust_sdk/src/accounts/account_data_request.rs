@@ -0,0 +1,162 @@
+use super::account_id::AccountId;
+use super::single_account_request::NoAccountId;
+use crate::models::{resolve_account_id, Request};
+
+/// The largest number of bytes of the name of a data entry.
+const MAX_DATA_KEY_LENGTH: usize = 64;
+
+/// Represents the name of a data entry of an account.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct DataKey(String);
+
+/// Represents the absence of the name of a data entry.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct NoDataKey;
+
+/// Represents a request to fetch a single data entry of an account from the Horizon API.
+///
+/// `AccountDataRequest` is a struct tailored to querying the value of a data entry, which is a
+/// name and value pair that is stored on an account with the `manage_data` operation. This struct
+/// is designed to be used in conjunction with the
+/// [`HorizonClient::get_account_data`](crate::horizon_client::HorizonClient::get_account_data) method.
+///
+/// The struct matches the parameters necessary to construct a request for the
+/// <a href="https://developers.stellar.org/docs/data/horizon/api-reference/get-data-by-account-id">Retrieve an Account's Data</a>
+/// endpoint of the Horizon API.
+///
+/// # Fields
+/// Required:
+/// * `account_id` - The account's public key.
+/// * `key` - The name of the data entry.
+///
+/// ## Usage
+/// Instances of `AccountDataRequest` are created and configured using setter methods for each
+/// parameter.
+/// ```
+/// # use stellar_rs::accounts::prelude::AccountDataRequest;
+/// # use stellar_rs::models::Request;
+/// let request = AccountDataRequest::new()
+///     .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .unwrap()
+///     .set_key("config.memo_required")
+///     .unwrap();
+/// // Use with HorizonClient::get_account_data
+/// ```
+///
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct AccountDataRequest<I = NoAccountId, K = NoDataKey> {
+    /// The account's public key.
+    account_id: I,
+    /// The name of the data entry.
+    key: K,
+}
+
+impl AccountDataRequest<NoAccountId, NoDataKey> {
+    /// Creates a new `AccountDataRequest` with default parameters.
+    pub fn new() -> Self {
+        AccountDataRequest::default()
+    }
+}
+
+impl<K> AccountDataRequest<NoAccountId, K> {
+    /// Sets the account ID for the request.
+    ///
+    /// # Arguments
+    /// * `account_id` - A `String` specifying the account's public key. A muxed account (`M...`) is
+    ///   resolved to its underlying account.
+    ///
+    pub fn set_account_id(
+        self,
+        account_id: impl Into<String>,
+    ) -> Result<AccountDataRequest<AccountId, K>, String> {
+        Ok(AccountDataRequest {
+            account_id: AccountId::new(resolve_account_id(account_id.into()))?,
+            key: self.key,
+        })
+    }
+}
+
+impl<I> AccountDataRequest<I, NoDataKey> {
+    /// Sets the name of the data entry for the request.
+    ///
+    /// # Arguments
+    /// * `key` - The name of the data entry, of 1 to 64 bytes.
+    ///
+    pub fn set_key(self, key: impl Into<String>) -> Result<AccountDataRequest<I, DataKey>, String> {
+        let key = key.into();
+        if key.is_empty() || key.len() > MAX_DATA_KEY_LENGTH {
+            return Err(format!(
+                "Data key must be between 1 and {} bytes long.",
+                MAX_DATA_KEY_LENGTH
+            ));
+        }
+
+        Ok(AccountDataRequest {
+            account_id: self.account_id,
+            key: DataKey(key),
+        })
+    }
+}
+
+impl Request for AccountDataRequest<AccountId, DataKey> {
+    fn get_query_parameters(&self) -> String {
+        // The name of a data entry may contain any character, so it is percent-encoded to form a
+        // single segment of the path.
+        let key: String = self
+            .key
+            .0
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    char::from(byte).to_string()
+                }
+                _ => format!("%{:02X}", byte),
+            })
+            .collect();
+
+        format!("{}/data/{}", self.account_id, key)
+    }
+
+    fn build_url(&self, base_url: &str) -> String {
+        // This URL is not built with query paramaters, but with the AccountID and the name of the
+        // data entry as addition to the path.
+        format!(
+            "{}/{}/{}",
+            base_url,
+            super::ACCOUNTS_PATH,
+            self.get_query_parameters()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT_ID: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_account_data_request() {
+        let request = AccountDataRequest::new()
+            .set_key("config memo/required")
+            .unwrap()
+            .set_account_id(ACCOUNT_ID)
+            .unwrap();
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            format!(
+                "https://horizon-testnet.stellar.org/accounts/{}/data/config%20memo%2Frequired",
+                ACCOUNT_ID
+            )
+        );
+
+        assert_eq!(
+            AccountDataRequest::new().set_key("").err(),
+            Some("Data key must be between 1 and 64 bytes long.".to_string())
+        );
+        assert!(AccountDataRequest::new().set_key("a".repeat(65)).is_err());
+        assert!(AccountDataRequest::new()
+            .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEG")
+            .is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use super::prelude::{Account, AccountDataRequest, AccountId, SingleAccountRequest};
+use crate::claimable_balances::prelude::AllClaimableBalancesRequest;
+use crate::error::Error;
+use crate::horizon_client::HorizonClient;
+use crate::offers::prelude::{AllOffersResponse, OffersForAccountRequest};
+use crate::payments::prelude::{PaymentsForAccountRequest, PaymentsResponse};
+use crate::trades::prelude::{AllTradesResponse, TradesForAccountRequest};
+use derive_getters::Getters;
+
+/// The number of records requested per page when counting, which is the maximum Horizon allows.
+const PAGE_SIZE: u8 = 200;
+
+/// Represents the shares of an account in a liquidity pool.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct LiquidityPoolShare {
+    /// The ID of the liquidity pool.
+    liquidity_pool_id: String,
+    /// The number of pool shares held by the account.
+    balance: String,
+}
+
+impl LiquidityPoolShare {
+    /// Returns the liquidity pool shares held by the given account.
+    ///
+    /// # Arguments
+    /// * `account` - The account, as returned by the Horizon server.
+    ///
+    pub fn from_account(account: &Account) -> Vec<Self> {
+        account
+            .balances()
+            .iter()
+            .filter_map(|balance| {
+                Some(LiquidityPoolShare {
+                    liquidity_pool_id: balance.liquidity_pool_id().clone()?,
+                    balance: balance.balance().clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A handle to a single account, which summarizes the footprint of the account on the network.
+///
+/// An `AccountHandle` is returned by [`HorizonClient::account`], and borrows the client to send
+/// its requests. It retrieves the sub-resources of the account, such as its data entries, offers,
+/// trades and payments, without the need to build the requests of the respective modules.
+///
+/// Dashboards often need to know how many offers and claimable balances an account has, which
+/// Horizon does not report directly. The handle counts these by requesting the respective
+/// endpoints with the largest page size, and without deserializing the records, so that counting
+/// takes as few and as cheap requests as possible.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// let account = horizon_client.account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?;
+///
+/// println!("Offers: {}", account.offers_count().await?);
+/// println!("Claimable balances: {}", account.claimable_balances_count().await?);
+/// for share in account.liquidity_pool_shares().await? {
+///     println!("Pool {}: {}", share.liquidity_pool_id(), share.balance());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
+pub struct AccountHandle<'a> {
+    /// The client that sends the requests of the handle.
+    horizon_client: &'a HorizonClient,
+    /// The ID of the account.
+    account_id: AccountId,
+}
+
+impl<'a> AccountHandle<'a> {
+    /// Creates a new `AccountHandle`, or returns an error if the account ID is not a public key.
+    pub(crate) fn new(horizon_client: &'a HorizonClient, account_id: &str) -> Result<Self, String> {
+        Ok(AccountHandle {
+            horizon_client,
+            account_id: AccountId::new(account_id)?,
+        })
+    }
+
+    /// Returns the ID of the account.
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    /// Retrieves the account from the Horizon server.
+    pub async fn load(&self) -> Result<Account, Error> {
+        let request = SingleAccountRequest::new().set_account_id(self.account_id.as_str())?;
+
+        self.horizon_client.get_single_account(&request).await
+    }
+
+    /// Retrieves the value of the data entry of the account with the given name, decoded from
+    /// base64.
+    pub async fn data(&self, key: &str) -> Result<Vec<u8>, Error> {
+        let request = AccountDataRequest::new()
+            .set_account_id(self.account_id.as_str())?
+            .set_key(key)?;
+
+        let response = self.horizon_client.get_account_data(&request).await?;
+        Ok(response.decoded_value()?)
+    }
+
+    /// Retrieves the first page of the open offers of the account.
+    pub async fn offers(&self) -> Result<AllOffersResponse, Error> {
+        let request = OffersForAccountRequest::new().set_account_id(self.account_id.as_str())?;
+
+        self.horizon_client.get_offers_for_account(&request).await
+    }
+
+    /// Retrieves the first page of the trades of the account.
+    pub async fn trades(&self) -> Result<AllTradesResponse, Error> {
+        let request = TradesForAccountRequest::new().set_account_id(self.account_id.as_str())?;
+
+        self.horizon_client.get_trades_for_account(&request).await
+    }
+
+    /// Retrieves the first page of the payments of the account.
+    pub async fn payments(&self) -> Result<PaymentsResponse, Error> {
+        let request = PaymentsForAccountRequest::new().set_account_id(self.account_id.as_str());
+
+        self.horizon_client.get_payments_for_account(&request).await
+    }
+
+    /// Counts the open offers of the account.
+    pub async fn offers_count(&self) -> Result<usize, Error> {
+        let request = OffersForAccountRequest::new()
+            .set_account_id(self.account_id.as_str())?
+            .set_limit(PAGE_SIZE)?;
+
+        self.horizon_client
+            .count_records(&request, usize::from(PAGE_SIZE))
+            .await
+    }
+
+    /// Counts the claimable balances that the account is able to claim.
+    pub async fn claimable_balances_count(&self) -> Result<usize, Error> {
+        let request = AllClaimableBalancesRequest::new()
+            .set_claimant(self.account_id.as_str())?
+            .set_limit(PAGE_SIZE)?;
+
+        self.horizon_client
+            .count_records(&request, usize::from(PAGE_SIZE))
+            .await
+    }
+
+    /// Retrieves the liquidity pool shares held by the account.
+    pub async fn liquidity_pool_shares(&self) -> Result<Vec<LiquidityPoolShare>, Error> {
+        let account = self.load().await?;
+
+        Ok(LiquidityPoolShare::from_account(&account))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Response;
+
+    #[test]
+    fn test_liquidity_pool_shares() {
+        let json = include_str!("../../tests/fixtures/accounts/single_account.json");
+        let account = Account::from_json(json.to_string()).unwrap();
+
+        // Only the balance of pool shares is returned, not those of the USDC and native assets.
+        assert_eq!(
+            LiquidityPoolShare::from_account(&account),
+            vec![LiquidityPoolShare {
+                liquidity_pool_id:
+                    "0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3".to_string(),
+                balance: "31.6227766".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_account_handle_invalid() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+        assert!(horizon_client
+            .account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEG")
+            .is_err());
+    }
+}
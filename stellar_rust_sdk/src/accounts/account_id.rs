@@ -0,0 +1,128 @@
+use crate::models::is_public_key;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the ID of an account, which is its public key.
+///
+/// An `AccountId` validates the public key once, so that requests can not be built for, and
+/// responses can not contain, account IDs that are not public keys.
+///
+/// An `AccountId` is (de)serialized as a string, matching the representation used by Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::accounts::prelude::AccountId;
+///
+/// let account_id: AccountId = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(account_id, "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7");
+///
+/// assert!("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEG".parse::<AccountId>().is_err());
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AccountId(String);
+
+impl AccountId {
+    /// Creates a new `AccountId`.
+    ///
+    /// # Arguments
+    /// * `account_id` - The public key of the account.
+    ///
+    /// # Returns
+    /// The `AccountId`, or an error if the value is not a public key.
+    ///
+    pub fn new(account_id: impl Into<String>) -> Result<Self, String> {
+        let account_id = account_id.into();
+        is_public_key(&account_id)?;
+
+        Ok(AccountId(account_id))
+    }
+
+    /// Returns the public key of the account.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = String;
+
+    fn from_str(account_id: &str) -> Result<Self, Self::Err> {
+        AccountId::new(account_id)
+    }
+}
+
+impl TryFrom<String> for AccountId {
+    type Error = String;
+
+    fn try_from(account_id: String) -> Result<Self, Self::Error> {
+        AccountId::new(account_id)
+    }
+}
+
+impl From<AccountId> for String {
+    fn from(account_id: AccountId) -> Self {
+        account_id.0
+    }
+}
+
+impl AsRef<str> for AccountId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for AccountId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for AccountId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT_ID: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_account_id() {
+        let account_id = ACCOUNT_ID.parse::<AccountId>().unwrap();
+        assert_eq!(account_id.as_str(), ACCOUNT_ID);
+        assert_eq!(account_id.to_string(), ACCOUNT_ID);
+        assert_eq!(
+            "GDQJ".parse::<AccountId>().unwrap_err(),
+            "Public key must be 56 characters long"
+        );
+        assert_eq!(
+            AccountId::new(ACCOUNT_ID.replacen('G', "S", 1)).unwrap_err(),
+            "Public key must start with G"
+        );
+    }
+
+    #[test]
+    fn test_account_id_serde() {
+        let account_id: AccountId = serde_json::from_str(&format!("\"{}\"", ACCOUNT_ID)).unwrap();
+        assert_eq!(account_id, ACCOUNT_ID);
+        assert_eq!(
+            serde_json::to_string(&account_id).unwrap(),
+            format!("\"{}\"", ACCOUNT_ID)
+        );
+        assert!(serde_json::from_str::<AccountId>("\"GDQJ\"").is_err());
+    }
+}
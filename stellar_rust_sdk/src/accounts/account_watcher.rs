@@ -0,0 +1,310 @@
+use super::response::Balances;
+use crate::effects::prelude::{BalanceChangeEffect, Effect, EffectDetail};
+use crate::models::serde_adapters::{format_stroops, parse_stroops};
+use derive_getters::Getters;
+use std::collections::VecDeque;
+
+/// Represents a change of a single balance of an account, as yielded by
+/// [`HorizonClient::watch_account`](crate::horizon_client::HorizonClient::watch_account).
+///
+/// Changes are detected by comparing two consecutive snapshots of the balances of the account. The
+/// effects of the account that were recorded in between are used to determine the cause of each
+/// change. This is done on a best-effort basis: some changes, such as the fee charged for a
+/// transaction, do not have a matching effect, and the effect of a change that happened just
+/// after a snapshot may only be seen by the next snapshot.
+///
+#[derive(Debug, Clone, Getters)]
+pub struct BalanceChange {
+    /// The asset of the balance: `native`, `CODE:ISSUER`, or the ID of the liquidity pool for
+    /// liquidity pool shares.
+    asset: String,
+    /// The balance before the change, or `None` if the balance was added, for example by creating
+    /// a trustline.
+    previous_balance: Option<String>,
+    /// The balance after the change, or `None` if the balance was removed, for example by removing
+    /// a trustline.
+    balance: Option<String>,
+    /// The change of the balance, in stroops.
+    delta: i64,
+    /// The effect that most likely caused the change, if it could be determined.
+    cause: Option<Effect>,
+}
+
+impl BalanceChange {
+    /// Returns the change of the balance as an amount with 7 decimals, such as `-1.5000000`.
+    pub fn delta_amount(&self) -> String {
+        format_stroops(self.delta)
+    }
+}
+
+/// The state of an account watcher, shared between the items it yields.
+pub(crate) struct AccountWatcherState {
+    /// The URL of the account to watch.
+    pub(crate) account_url: String,
+    /// Whether the account has been polled before.
+    pub(crate) polled: bool,
+    /// The balances of the previous snapshot, if any.
+    balances: Option<Vec<Balances>>,
+    /// The paging token of the last effect that was seen, if any.
+    pub(crate) effects_cursor: Option<String>,
+    /// The changes that have not been yielded yet.
+    pub(crate) pending: VecDeque<BalanceChange>,
+}
+
+impl AccountWatcherState {
+    pub(crate) fn new(account_url: String) -> Self {
+        AccountWatcherState {
+            account_url,
+            polled: false,
+            balances: None,
+            effects_cursor: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Processes a new snapshot of the balances, and the effects recorded since the previous one.
+    /// The first snapshot only serves as the baseline, so it does not produce any changes.
+    pub(crate) fn update(
+        &mut self,
+        balances: Vec<Balances>,
+        effects: &[Effect],
+    ) -> Result<(), String> {
+        if let Some(last) = effects.last() {
+            self.effects_cursor = Some(last.paging_token.clone());
+        }
+
+        if let Some(previous) = &self.balances {
+            let mut changes = diff_balances(previous, &balances)?;
+            attribute_causes(&mut changes, effects);
+            self.pending.extend(changes);
+        }
+        self.balances = Some(balances);
+
+        Ok(())
+    }
+}
+
+/// Compares two snapshots of the balances of an account, and returns the balances that changed.
+pub(crate) fn diff_balances(
+    previous: &[Balances],
+    current: &[Balances],
+) -> Result<Vec<BalanceChange>, String> {
+    let find = |balances: &[Balances], asset: &str| {
+        balances
+            .iter()
+            .find(|balance| asset_of_balance(balance) == asset)
+            .cloned()
+    };
+    let change = |asset: String, before: Option<&Balances>, after: Option<&Balances>| {
+        let amount = |balance: Option<&Balances>| balance.map(|b| b.balance().clone());
+        let stroops = |balance: Option<&Balances>| match balance {
+            Some(balance) => parse_stroops(balance.balance()),
+            None => Ok(0),
+        };
+
+        Ok::<_, String>(BalanceChange {
+            delta: stroops(after)? - stroops(before)?,
+            previous_balance: amount(before),
+            balance: amount(after),
+            asset,
+            cause: None,
+        })
+    };
+
+    let mut changes = Vec::new();
+    for balance in current {
+        let asset = asset_of_balance(balance);
+        let before = find(previous, &asset);
+        if before.as_ref().map(|b| b.balance()) != Some(balance.balance()) {
+            changes.push(change(asset, before.as_ref(), Some(balance))?);
+        }
+    }
+    for balance in previous {
+        let asset = asset_of_balance(balance);
+        if find(current, &asset).is_none() {
+            changes.push(change(asset, Some(balance), None)?);
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Determines the cause of each change from the effects recorded in between the snapshots. An
+/// effect of the same asset whose amount matches the change is preferred; otherwise, the last
+/// effect of the same asset is used.
+pub(crate) fn attribute_causes(changes: &mut [BalanceChange], effects: &[Effect]) {
+    for change in changes.iter_mut() {
+        let candidates: Vec<&Effect> = effects
+            .iter()
+            .filter(|effect| asset_of_effect(effect).as_deref() == Some(change.asset.as_str()))
+            .collect();
+
+        change.cause = candidates
+            .iter()
+            .rev()
+            .find(|effect| delta_of_effect(effect) == Some(change.delta))
+            .or_else(|| candidates.last())
+            .map(|effect| (*effect).clone());
+    }
+}
+
+/// Returns the asset of a balance, in the format used by [`BalanceChange::asset`].
+fn asset_of_balance(balance: &Balances) -> String {
+    match (balance.asset_type().as_str(), balance.liquidity_pool_id()) {
+        ("native", _) => "native".to_string(),
+        (_, Some(liquidity_pool_id)) => liquidity_pool_id.clone(),
+        _ => format!(
+            "{}:{}",
+            balance.asset_code().as_deref().unwrap_or_default(),
+            balance.asset_issuer().as_deref().unwrap_or_default()
+        ),
+    }
+}
+
+/// Returns the asset of an effect, in the format used by [`BalanceChange::asset`], if any.
+fn asset_of_effect(effect: &Effect) -> Option<String> {
+    let detail = balance_change_of_effect(effect)?;
+    match detail.asset_type.as_str() {
+        "native" => Some("native".to_string()),
+        _ => Some(format!(
+            "{}:{}",
+            detail.asset_code.as_deref()?,
+            detail.asset_issuer.as_deref()?
+        )),
+    }
+}
+
+/// Returns the change of the balance described by an effect, in stroops, if it describes one.
+fn delta_of_effect(effect: &Effect) -> Option<i64> {
+    let amount = parse_stroops(&balance_change_of_effect(effect)?.amount).ok()?;
+    match effect.detail {
+        EffectDetail::AccountCredited(_) => Some(amount),
+        EffectDetail::AccountDebited(_) => Some(-amount),
+        _ => None,
+    }
+}
+
+/// Returns the balance change of an `account_credited` or `account_debited` effect.
+fn balance_change_of_effect(effect: &Effect) -> Option<&BalanceChangeEffect> {
+    match &effect.detail {
+        EffectDetail::AccountCredited(detail) | EffectDetail::AccountDebited(detail) => {
+            Some(detail)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    fn balances(json: &str) -> Vec<Balances> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn effect(paging_token: &str, effect_type: &str, asset: &str, amount: &str) -> Effect {
+        let asset = match asset.split_once(':') {
+            Some((code, issuer)) => format!(
+                r#""asset_type": "credit_alphanum4", "asset_code": "{}", "asset_issuer": "{}""#,
+                code, issuer
+            ),
+            None => r#""asset_type": "native""#.to_string(),
+        };
+        serde_json::from_str(&format!(
+            r#"{{
+                "_links": {{
+                    "operation": {{"href": null}},
+                    "succeeds": {{"href": null}},
+                    "precedes": {{"href": null}}
+                }},
+                "id": "{paging_token}",
+                "paging_token": "{paging_token}",
+                "account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+                "type": "{effect_type}",
+                "type_i": 0,
+                "created_at": "2024-06-11T21:36:12Z",
+                "amount": "{amount}",
+                {asset}
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_diff_balances() {
+        let previous = balances(&format!(
+            r#"[
+                {{"balance": "100.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "native"}},
+                {{"balance": "5.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "credit_alphanum4", "asset_code": "USDC", "asset_issuer": "{ISSUER}"}},
+                {{"balance": "1.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "credit_alphanum4", "asset_code": "EURC", "asset_issuer": "{ISSUER}"}}
+            ]"#
+        ));
+        let current = balances(&format!(
+            r#"[
+                {{"balance": "98.4999900", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "native"}},
+                {{"balance": "5.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "credit_alphanum4", "asset_code": "USDC", "asset_issuer": "{ISSUER}"}},
+                {{"balance": "2.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "liquidity_pool_shares", "liquidity_pool_id": "0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10"}}
+            ]"#
+        ));
+
+        let changes = diff_balances(&previous, &current).unwrap();
+        assert_eq!(changes.len(), 3);
+
+        assert_eq!(changes[0].asset(), "native");
+        assert_eq!(changes[0].delta(), &-15_000_100);
+        assert_eq!(changes[0].delta_amount(), "-1.5000100");
+        assert_eq!(
+            changes[0].previous_balance().as_deref(),
+            Some("100.0000000")
+        );
+
+        assert_eq!(
+            changes[1].asset(),
+            "0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10"
+        );
+        assert_eq!(changes[1].delta(), &20_000_000);
+        assert!(changes[1].previous_balance().is_none());
+
+        assert_eq!(changes[2].asset(), &format!("EURC:{}", ISSUER));
+        assert_eq!(changes[2].delta(), &-10_000_000);
+        assert!(changes[2].balance().is_none());
+    }
+
+    #[test]
+    fn test_attribute_causes() {
+        let usdc = format!("USDC:{}", ISSUER);
+        let effects = vec![
+            effect("1-1", "account_credited", "native", "1.0000000"),
+            effect("2-1", "account_debited", "native", "1.5000000"),
+            effect("3-1", "account_credited", &usdc, "3.0000000"),
+            effect("4-1", "account_credited", &usdc, "2.0000000"),
+        ];
+
+        let mut state = AccountWatcherState::new("https://example.org".to_string());
+        state
+            .update(balances(r#"[{"balance": "10.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "native"}]"#), &[])
+            .unwrap();
+        assert!(state.pending.is_empty());
+
+        state
+            .update(
+                balances(&format!(
+                    r#"[
+                        {{"balance": "8.5000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "native"}},
+                        {{"balance": "3.0000000", "buying_liabilities": "0.0000000", "selling_liabilities": "0.0000000", "asset_type": "credit_alphanum4", "asset_code": "USDC", "asset_issuer": "{ISSUER}"}}
+                    ]"#
+                )),
+                &effects,
+            )
+            .unwrap();
+        assert_eq!(state.effects_cursor.as_deref(), Some("4-1"));
+
+        // The effect with a matching amount is preferred over the last effect of the asset.
+        let native = state.pending.pop_front().unwrap();
+        assert_eq!(native.cause().as_ref().unwrap().paging_token, "2-1");
+        let usdc = state.pending.pop_front().unwrap();
+        assert_eq!(usdc.cause().as_ref().unwrap().paging_token, "3-1");
+    }
+}
@@ -1,15 +1,19 @@
+use crate::models::pagination::PageParams;
 use crate::{models::*, BuildQueryParametersExt};
+use stellar_rust_sdk_derive::pagination;
 
 /// Defines types for filtering the list of accounts retrieved.
 ///
 /// This module provides a set of filter types used in [`AccountsRequest`]
 /// to specify the criteria for filtering the list of accounts returned by the Horizon server.
 /// Each filter type corresponds to a potential query parameter that can be used in account-
-/// related queries. Exactly one filter is required by the API in the request.
+/// related queries. At least one filter is required by the API in the request. The `sponsor` filter
+/// can be combined with either the `signer` or the `asset` filter; all other filters must be used on
+/// their own.
 ///
 /// # Usage
 /// To use these filters, create an instance of [`AccountsRequest`]
-/// and call one of its setter methods to set exactly one of the filters. The request can then be executed through the `HorizonClient`.
+/// and call its setter methods to set one of the filters, or one of the valid combinations of filters. The request can then be executed through the `HorizonClient`.
 ///
 /// ```rust
 /// # use stellar_rs::accounts::prelude::*;
@@ -37,31 +41,31 @@ pub mod filters {
     use crate::models::Asset;
 
     /// Represents a filter for accounts sponsored by the given account ID.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct SponsorFilter(pub String);
     /// Indicates the absence of a sponsor filter in the request.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct NoSponsorFilter;
 
     /// Represents a filter for accounts that have the given account ID as a signer.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct SignerFilter(pub String);
     /// Indicates the absence of a signer filter in the request.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct NoSignerFilter;
 
     /// Represents a filter for accounts holding a trustline for the specified asset.
-    #[derive(Clone)]
+    #[derive(Clone, PartialEq, Eq, Hash)]
     pub struct AssetFilter<T>(pub Asset<T>);
     /// Indicates the absence of an asset filter in the request.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct NoAssetFilter;
 
     /// Represents a filter for accounts associated with the specified liquidity pool.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct LiquidityPoolFilter(pub String);
     /// Indicates the absence of a liquidity pool filter in the request.
-    #[derive(Default, Clone)]
+    #[derive(Default, Clone, PartialEq, Eq, Hash)]
     pub struct NoLiquidityPoolFilter;
 }
 
@@ -76,15 +80,18 @@ use filters::*;
 /// # Parameters
 /// - `$type`: The type of [`AccountsRequest`] for which to implement the [`Request`] trait. This type must already
 ///   conform to the structure expected by the Horizon API for account requests.
-/// - `$field`: The field within the `$type` that is being used as a filter for the account request. This field
-///   is included as a mandatory parameter in the query.
+/// - `$field`: The fields within the `$type` that are being used as filters for the account request. These fields
+///   are included as mandatory parameters in the query.
 ///
 /// # Provided Methods
 /// - `get_query_parameters`: Constructs the query string from the fields of the `$type`, including cursor, limit,
-///   order, and the specified `$field` as a filter parameter.
+///   order, and the specified `$field`s as filter parameters.
 /// - `build_url`: Assembles the complete URL for the account request using the base URL and the constructed query
 ///   parameters.
 ///
+/// The macro also implements [`Sealed`](crate::sealed::Sealed) for the `$type`, which is required to implement
+/// [`ValidAccountsRequest`].
+///
 /// # Note
 /// - The macro is intended for internal SDK use and contributes to the modularity of the account request system.
 /// - The `.$field.0` syntax assumes that the filter field within the [`AccountsRequest`] type is a tuple struct with
@@ -92,16 +99,18 @@ use filters::*;
 /// - The macro includes error handling to ensure that only the appropriate fields are included in the query parameters.
 ///
 macro_rules! valid_account_request_impl {
-    ($type:ty, $field:ident) => {
+    ($type:ty, $($field:ident),+) => {
+        impl crate::sealed::Sealed for $type {}
+
         impl Request for $type {
             fn get_query_parameters(&self) -> String {
                 let mut params = vec![
-                    self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-                    self.limit.as_ref().map(|l| format!("limit={}", l)),
-                    self.order.as_ref().map(|o| format!("order={}", o)),
+                    self.page_params.cursor().map(|c| format!("cursor={}", c)),
+                    self.page_params.limit().map(|l| format!("limit={}", l)),
+                    self.page_params.order().map(|o| format!("order={}", o)),
                 ];
 
-                params.push(Some(format!("{}={}", stringify!($field), self.$field.0)));
+                $(params.push(Some(format!("{}={}", stringify!($field), self.$field.0)));)+
 
                 params.build_query_parameters()
             }
@@ -129,7 +138,8 @@ macro_rules! valid_account_request_impl {
 ///   conform to the structure expected by the Horizon API for account requests.
 /// - `$field`: The field within the `$type` that is being used as a filter for the account request. This field
 ///   is included as a mandatory parameter in the query.
-/// - `$generic` : The generic type used for the [`AssetFilter`] when querying accounts.
+/// - `$generic` : The generic type used for the [`AssetFilter`] when querying accounts. It is passed before the
+///   fields, as the list of fields is variadic.
 ///
 /// # Provided Methods
 /// - `get_query_parameters`: Constructs the query string from the fields of the `$type`, including cursor, limit,
@@ -137,6 +147,9 @@ macro_rules! valid_account_request_impl {
 /// - `build_url`: Assembles the complete URL for the account request using the base URL and the constructed query
 ///   parameters.
 ///
+/// The macro also implements [`Sealed`](crate::sealed::Sealed) for the `$type`, which is required to implement
+/// [`ValidAccountsRequest`].
+///
 /// # Note
 /// - The macro is intended for internal SDK use and contributes to the modularity of the account request system.
 /// - The `.$field.0` syntax assumes that the filter field within the [`AccountsRequest`] type is a tuple struct with
@@ -144,19 +157,21 @@ macro_rules! valid_account_request_impl {
 /// - The macro includes error handling to ensure that only the appropriate fields are included in the query parameters.
 ///
 macro_rules! valid_generic_account_request_impl {
-    ($type:ty, $field:ident, $generic:ident) => {
+    ($type:ty, $generic:ident, $($field:ident),+) => {
+        impl<$generic> crate::sealed::Sealed for $type where Asset<T>: std::fmt::Display {}
+
         impl<$generic> Request for $type
         where
             Asset<T>: std::fmt::Display,
         {
             fn get_query_parameters(&self) -> String {
                 let mut params = vec![
-                    self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-                    self.limit.as_ref().map(|l| format!("limit={}", l)),
-                    self.order.as_ref().map(|o| format!("order={}", o)),
+                    self.page_params.cursor().map(|c| format!("cursor={}", c)),
+                    self.page_params.limit().map(|l| format!("limit={}", l)),
+                    self.page_params.order().map(|o| format!("order={}", o)),
                 ];
 
-                params.push(Some(format!("{}={}", stringify!($field), self.$field.0)));
+                $(params.push(Some(format!("{}={}", stringify!($field), self.$field.0)));)+
 
                 params.build_query_parameters()
             }
@@ -186,12 +201,17 @@ macro_rules! valid_generic_account_request_impl {
 /// - `AccountsRequest<NoSponsor, Signer, NoAsset, NoLiquidityPool>`: Requests accounts by signer.
 /// - `AccountsRequest<NoSponsor, NoSigner, Asset, NoLiquidityPool>`: Requests accounts by asset.
 /// - `AccountsRequest<NoSponsor, NoSigner, NoAsset, LiquidityPool>`: Requests accounts by liquidity pool.
+/// - `AccountsRequest<Sponsor, Signer, NoAsset, NoLiquidityPool>`: Requests accounts by sponsor and signer.
+/// - `AccountsRequest<Sponsor, NoSigner, Asset, NoLiquidityPool>`: Requests accounts by sponsor and asset.
 ///
 /// # Usage
 /// You generally do not need to use `ValidAccountsRequest` directly; it is used internally by the SDK.
 /// Instead, create an instance of [`AccountsRequest`] with the desired filters and pass it to the
 /// [`HorizonClient::get_account_list`](crate::horizon_client::HorizonClient::get_account_list) method.
 ///
+/// The trait is sealed: it can not be implemented outside of this crate, since Horizon only
+/// accepts the combinations of filters listed above.
+///
 /// ```rust
 /// # use stellar_rs::accounts::prelude::AccountsRequest;
 /// # use stellar_rs::horizon_client::HorizonClient;
@@ -203,7 +223,7 @@ macro_rules! valid_generic_account_request_impl {
 /// // Now, you can pass `request` to `horizon_client.get_account_list`.
 /// ```
 ///
-pub trait ValidAccountsRequest: Request {}
+pub trait ValidAccountsRequest: Request + crate::sealed::Sealed {}
 
 impl ValidAccountsRequest
     for AccountsRequest<SponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidityPoolFilter>
@@ -223,7 +243,7 @@ where
     Asset<T>: std::fmt::Display,
 {
 }
-valid_generic_account_request_impl!(AccountsRequest<NoSponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter>, asset, T);
+valid_generic_account_request_impl!(AccountsRequest<NoSponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter>, T, asset);
 
 impl ValidAccountsRequest
     for AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, LiquidityPoolFilter>
@@ -231,6 +251,20 @@ impl ValidAccountsRequest
 }
 valid_account_request_impl!(AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, LiquidityPoolFilter>, liquidity_pool);
 
+impl ValidAccountsRequest
+    for AccountsRequest<SponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter>
+{
+}
+valid_account_request_impl!(AccountsRequest<SponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter>, sponsor, signer);
+
+impl<T> ValidAccountsRequest
+    for AccountsRequest<SponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter>
+where
+    Asset<T>: std::fmt::Display,
+{
+}
+valid_generic_account_request_impl!(AccountsRequest<SponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter>, T, sponsor, asset);
+
 /// Represents a request to fetch multiple accounts from the Horizon API with a specific filter.
 ///
 /// `AccountsRequest` is a struct used to query a list of accounts on the Horizon API, allowing
@@ -249,6 +283,27 @@ valid_account_request_impl!(AccountsRequest<NoSponsorFilter, NoSignerFilter, NoA
 /// - `asset`: An issued asset in the format “Code:IssuerAccountID”. Filters for accounts with a trustline for the specified asset.
 /// - `liquidity_pool`: The liquidity pool ID. Filters for accounts associated with the specified liquidity pool.
 ///
+/// The `sponsor` filter can be combined with either the `signer` or the `asset` filter. Any other
+/// combination is rejected at compile time:
+///
+/// ```rust
+/// # use stellar_rs::accounts::prelude::AccountsRequest;
+/// let request = AccountsRequest::new()
+///     .set_signer_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .unwrap()
+///     .set_sponsor_filter("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")
+///     .unwrap();
+/// ```
+///
+/// ```compile_fail
+/// # use stellar_rs::accounts::prelude::AccountsRequest;
+/// # use stellar_rs::models::Asset;
+/// let request = AccountsRequest::new()
+///     .set_signer_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .unwrap()
+///     .set_asset_filter(Asset::new());
+/// ```
+///
 /// # Optional Parameters
 ///
 /// - `cursor`: A number that points to the current location in the collection of responses and is pulled from the paging_token value of a record.
@@ -257,8 +312,14 @@ valid_account_request_impl!(AccountsRequest<NoSponsorFilter, NoSignerFilter, NoA
 /// - `order`: The [`Order`] of the returned records, either ascending ([`Order::Asc`]) or descending ([`Order::Desc`]).
 ///   Defaults to ascending if not set.
 ///
-#[derive(Default)]
-pub struct AccountsRequest<Sp, Si, A, L> {
+#[pagination]
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct AccountsRequest<
+    Sp = NoSponsorFilter,
+    Si = NoSignerFilter,
+    A = NoAssetFilter,
+    L = NoLiquidityPoolFilter,
+> {
     /// Filter for accounts sponsored by the account ID or have a subentry
     /// (trustline, offer, or data entry) which is sponsored by the given account ID.
     sponsor: Sp,
@@ -272,65 +333,13 @@ pub struct AccountsRequest<Sp, Si, A, L> {
     /// Filter for accounts associated with the specified liquidity pool.
     liquidity_pool: L,
 
-    /// A number that points to the current location in the collection of responses and is pulled from the paging_token value of a record.
-    cursor: Option<u32>,
-
-    /// The maximum number of records to return, with a permissible range from 1 to 200.
-    ///   Defaults to 10 if not specified.
-    limit: Option<u32>,
-
-    /// The [`Order`] of the returned records, either ascending ([`Order::Asc`]) or descending ([`Order::Desc`]).
-    order: Option<Order>,
-}
-
-impl<Sp, Si, A, L> AccountsRequest<Sp, Si, A, L> {
-    /// Sets the cursor for pagination.
-    ///
-    /// # Arguments
-    /// * `cursor` - A `u32` value pointing to a specific location in a collection of responses.
-    ///
-    pub fn set_cursor(self, cursor: u32) -> Result<Self, String> {
-        if cursor < 1 {
-            return Err("cursor must be greater than or equal to 1".to_string());
-        }
-
-        Ok(Self {
-            cursor: Some(cursor),
-            ..self
-        })
-    }
-
-    /// Sets the maximum number of records to return.
-    ///
-    /// # Arguments
-    /// * `limit` - A `u8` value specifying the maximum number of records. Range: 1 to 200. Defaults to 10.
-    ///
-    pub fn set_limit(self, limit: u32) -> Result<Self, String> {
-        if limit < 1 || limit > 200 {
-            return Err("limit must be between 1 and 200".to_string());
-        }
-
-        Ok(Self {
-            limit: Some(limit),
-            ..self
-        })
-    }
-
-    /// Sets the order of the returned records.
-    ///
-    /// # Arguments
-    /// * `order` - An [`Order`] enum value specifying the order (ascending or descending).
-    ///
-    pub fn set_order(self, order: Order) -> Self {
-        Self {
-            order: Some(order),
-            ..self
-        }
-    }
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
-/// Since the Horizon API only allows for one of the following parameters to be set, we need to
-/// create an implementation for a combination of generics which are all unset.
+/// Since the Horizon API only allows for specific combinations of the following parameters to be
+/// set, we need to create an implementation for a combination of generics which are all unset,
+/// and for each filter that can be combined with another one.
 impl AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidityPoolFilter> {
     /// Creates a new `AccountsRequest` with default parameters.
     pub fn new() -> Self {
@@ -345,21 +354,14 @@ impl AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidity
     ///
     pub fn set_sponsor_filter(
         self,
-        sponsor: impl Into<String>
+        sponsor: impl Into<String>,
     ) -> Result<
         AccountsRequest<SponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidityPoolFilter>,
         String,
     > {
-        let sponsor = sponsor.into();
-        if let Err(e) = is_public_key(&sponsor) {
-            return Err(e.to_string());
-        }
-
         Ok(AccountsRequest {
-            sponsor: SponsorFilter(sponsor.into()),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            sponsor: sponsor_filter(sponsor)?,
+            page_params: self.page_params,
             ..Default::default()
         })
     }
@@ -372,20 +374,14 @@ impl AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidity
     ///
     pub fn set_signer_filter(
         self,
-        signer: &str,
+        signer: impl Into<String>,
     ) -> Result<
         AccountsRequest<NoSponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter>,
         String,
     > {
-        if let Err(e) = is_public_key(&signer) {
-            return Err(e.to_string());
-        }
-
         Ok(AccountsRequest {
-            signer: SignerFilter(signer.to_string()),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            signer: signer_filter(signer)?,
+            page_params: self.page_params,
             ..Default::default()
         })
     }
@@ -406,9 +402,7 @@ impl AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidity
             signer: self.signer,
             asset: AssetFilter(asset),
             liquidity_pool: self.liquidity_pool,
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         }
     }
 
@@ -424,14 +418,117 @@ impl AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidity
     ) -> AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, LiquidityPoolFilter> {
         AccountsRequest {
             liquidity_pool: LiquidityPoolFilter(liquidity_pool.into()),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
             ..Default::default()
         }
     }
 }
 
+impl AccountsRequest<SponsorFilter, NoSignerFilter, NoAssetFilter, NoLiquidityPoolFilter> {
+    /// Sets the signer account ID filter, in addition to the sponsor filter.
+    ///
+    /// # Arguments
+    /// * `signer` - A `String` specifying the signer account ID. Filters for accounts
+    ///   having this ID as a signer.
+    ///
+    pub fn set_signer_filter(
+        self,
+        signer: impl Into<String>,
+    ) -> Result<
+        AccountsRequest<SponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter>,
+        String,
+    > {
+        Ok(AccountsRequest {
+            sponsor: self.sponsor,
+            signer: signer_filter(signer)?,
+            asset: self.asset,
+            liquidity_pool: self.liquidity_pool,
+            page_params: self.page_params,
+        })
+    }
+
+    /// Sets the asset filter, in addition to the sponsor filter.
+    ///
+    /// # Arguments
+    /// * `asset` - An [`Asset`] specifying the asset. Filters for accounts with a
+    ///   trustline for this asset.
+    ///
+    pub fn set_asset_filter<T>(
+        self,
+        asset: Asset<T>,
+    ) -> AccountsRequest<SponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter> {
+        AccountsRequest {
+            sponsor: self.sponsor,
+            signer: self.signer,
+            asset: AssetFilter(asset),
+            liquidity_pool: self.liquidity_pool,
+            page_params: self.page_params,
+        }
+    }
+}
+
+impl AccountsRequest<NoSponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter> {
+    /// Sets the sponsor account ID filter, in addition to the signer filter.
+    ///
+    /// # Arguments
+    /// * `sponsor` - A `String` specifying the sponsor account ID. Filters for accounts
+    ///   sponsored by this ID or having a subentry sponsored by this ID.
+    ///
+    pub fn set_sponsor_filter(
+        self,
+        sponsor: impl Into<String>,
+    ) -> Result<
+        AccountsRequest<SponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter>,
+        String,
+    > {
+        Ok(AccountsRequest {
+            sponsor: sponsor_filter(sponsor)?,
+            signer: self.signer,
+            asset: self.asset,
+            liquidity_pool: self.liquidity_pool,
+            page_params: self.page_params,
+        })
+    }
+}
+
+impl<T> AccountsRequest<NoSponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter> {
+    /// Sets the sponsor account ID filter, in addition to the asset filter.
+    ///
+    /// # Arguments
+    /// * `sponsor` - A `String` specifying the sponsor account ID. Filters for accounts
+    ///   sponsored by this ID or having a subentry sponsored by this ID.
+    ///
+    pub fn set_sponsor_filter(
+        self,
+        sponsor: impl Into<String>,
+    ) -> Result<
+        AccountsRequest<SponsorFilter, NoSignerFilter, AssetFilter<T>, NoLiquidityPoolFilter>,
+        String,
+    > {
+        Ok(AccountsRequest {
+            sponsor: sponsor_filter(sponsor)?,
+            signer: self.signer,
+            asset: self.asset,
+            liquidity_pool: self.liquidity_pool,
+            page_params: self.page_params,
+        })
+    }
+}
+
+/// Validates the account ID of a sponsor filter.
+fn sponsor_filter(sponsor: impl Into<String>) -> Result<SponsorFilter, String> {
+    let sponsor = sponsor.into();
+    is_public_key(&sponsor)?;
+    Ok(SponsorFilter(sponsor))
+}
+
+/// Validates the account ID of a signer filter.
+fn signer_filter(signer: impl Into<String>) -> Result<SignerFilter, String> {
+    let signer = signer.into();
+    is_public_key(&signer)?;
+    Ok(SignerFilter(signer))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -454,10 +551,82 @@ mod tests {
         );
     }
 
+    const SPONSOR: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+    const SIGNER: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+    #[test]
+    fn test_sponsor_and_signer_query_parameters() {
+        let request = AccountsRequest::new()
+            .set_limit(20)
+            .unwrap()
+            .set_signer_filter(SIGNER)
+            .unwrap()
+            .set_sponsor_filter(SPONSOR)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+        assert_eq!(
+            request.get_query_parameters(),
+            format!("?limit=20&order=desc&sponsor={}&signer={}", SPONSOR, SIGNER)
+        );
+
+        let request = AccountsRequest::new()
+            .set_sponsor_filter(SPONSOR)
+            .unwrap()
+            .set_signer_filter(SIGNER)
+            .unwrap();
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            format!(
+                "https://horizon-testnet.stellar.org/accounts?sponsor={}&signer={}",
+                SPONSOR, SIGNER
+            )
+        );
+    }
+
+    #[test]
+    fn test_sponsor_and_asset_query_parameters() {
+        let asset = Asset::new().set_issued("USDC", SIGNER).unwrap();
+        let request = AccountsRequest::new()
+            .set_asset_filter(asset.clone())
+            .set_sponsor_filter(SPONSOR)
+            .unwrap()
+            .set_cursor(5)
+            .unwrap();
+        assert_eq!(
+            request.get_query_parameters(),
+            format!("?cursor=5&sponsor={}&asset=USDC:{}", SPONSOR, SIGNER)
+        );
+
+        let request = AccountsRequest::new()
+            .set_sponsor_filter(SPONSOR)
+            .unwrap()
+            .set_asset_filter(asset);
+        assert_eq!(
+            request.get_query_parameters(),
+            format!("?sponsor={}&asset=USDC:{}", SPONSOR, SIGNER)
+        );
+    }
+
+    #[test]
+    fn test_combined_filters_invalid() {
+        let request = AccountsRequest::new()
+            .set_signer_filter(SIGNER)
+            .unwrap()
+            .set_sponsor_filter("sponsor");
+        assert!(request.is_err());
+
+        let request = AccountsRequest::new()
+            .set_sponsor_filter(SPONSOR)
+            .unwrap()
+            .set_signer_filter("signer");
+        assert!(request.is_err());
+    }
+
     #[test]
     fn test_set_cursor_valid() {
         let request = AccountsRequest::new().set_cursor(12345).unwrap();
-        assert_eq!(request.cursor.unwrap(), 12345);
+        assert_eq!(request.page_params.cursor().unwrap(), 12345);
     }
 
     #[test]
@@ -465,14 +634,14 @@ mod tests {
         let request = AccountsRequest::new().set_cursor(0);
         assert_eq!(
             request.err().unwrap(),
-            "cursor must be greater than or equal to 1".to_string()
+            "Cursor must be greater than or equal to 1.".to_string()
         );
     }
 
     #[test]
     fn test_set_limit_valid() {
         let request = AccountsRequest::new().set_limit(20).unwrap();
-        assert_eq!(request.limit.unwrap(), 20);
+        assert_eq!(request.page_params.limit().unwrap(), 20);
     }
 
     #[test]
@@ -480,7 +649,7 @@ mod tests {
         let request = AccountsRequest::new().set_limit(0);
         assert_eq!(
             request.err().unwrap(),
-            "limit must be between 1 and 200".to_string()
+            "Limit must be between 1 and 200.".to_string()
         );
     }
 
@@ -489,7 +658,7 @@ mod tests {
         let request = AccountsRequest::new().set_limit(201);
         assert_eq!(
             request.err().unwrap(),
-            "limit must be between 1 and 200".to_string()
+            "Limit must be between 1 and 200.".to_string()
         );
     }
 }
@@ -16,6 +16,15 @@ pub mod accounts_request;
 ///
 pub mod single_account_request;
 
+/// Provides the `AccountDataRequest`.
+///
+/// This module provides the `AccountDataRequest` struct, specifically designed for constructing
+/// requests to query a single data entry of an account from the Horizon server. It is tailored
+/// for use with the [`HorizonClient::get_account_data`](crate::horizon_client::HorizonClient::get_account_data)
+/// method.
+///
+pub mod account_data_request;
+
 /// Provides the `Responses`.
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -27,6 +36,67 @@ pub mod single_account_request;
 ///
 pub mod response;
 
+/// Provides the `RequiredReserve`.
+///
+/// This module provides the `RequiredReserve` struct, which calculates the minimum balance an
+/// account is required to hold from its subentries, its sponsorships and the base reserve of the
+/// network. It is returned by the [`HorizonClient::get_account_required_reserve`](crate::horizon_client::HorizonClient::get_account_required_reserve)
+/// method.
+///
+pub mod required_reserve;
+
+/// Provides the `BalanceChange`.
+///
+/// This module provides the `BalanceChange` struct, which describes a change of a single balance
+/// of an account, along with the effect that most likely caused it. Balance changes are yielded by
+/// the [`HorizonClient::watch_account`](crate::horizon_client::HorizonClient::watch_account)
+/// method.
+///
+pub mod account_watcher;
+
+/// Provides the state of the watchers of new accounts.
+///
+/// This module keeps track of the accounts that match a filter, such as a sponsor or signer
+/// filter, so that the [`HorizonClient::watch_new_accounts`](crate::horizon_client::HorizonClient::watch_new_accounts)
+/// method can yield the accounts that start to match it.
+///
+pub(crate) mod new_accounts_watcher;
+
+/// Provides the `AccountId`.
+///
+/// This module provides the `AccountId` struct, a validated account ID that is shared by the
+/// account-related requests and by the responses that refer to an account, such as the seller of
+/// an [`OfferResponse`](crate::offers::prelude::OfferResponse).
+///
+pub mod account_id;
+
+/// Provides the `MultisigPolicy`.
+///
+/// This module provides the `MultisigPolicy` struct, which is derived from the thresholds and
+/// signers of an [`Account`](crate::accounts::prelude::Account), and evaluates whether a set of
+/// signers is able to authorize an operation, including pre-authorized transaction and hash-x
+/// signers.
+///
+pub mod multisig_policy;
+
+/// Provides the trustline authorization helpers.
+///
+/// This module provides the `TrustlineAuthorization` and `UnauthorizedTrustline` structs, which
+/// describe the authorization of trustlines by the issuer of an asset, and the
+/// `SetTrustLineFlagsIntent` struct, which builds the `set_trust_line_flags` operation that
+/// authorizes or freezes a trustline.
+///
+pub mod trustline_authorization;
+
+/// Provides the `AccountHandle`.
+///
+/// This module provides the `AccountHandle` struct, which is returned by
+/// [`HorizonClient::account`](crate::horizon_client::HorizonClient::account) and summarizes the
+/// footprint of an account, such as the number of its offers and claimable balances and its
+/// liquidity pool shares.
+///
+pub mod account_handle;
+
 /// The base path for account-related endpoints in the Horizon API.
 ///
 /// # Usage
@@ -51,6 +121,7 @@ pub(crate) static ACCOUNTS_PATH: &str = "accounts";
 ///
 /// The `prelude` includes the following re-exports:
 ///
+/// * From `account_data_request`: All items (e.g., `AccountDataRequest`).
 /// * From `accounts_request`: All items (e.g., `AccountsRequest`).
 /// * From `accounts_response`: All items (e.g., `AccountsResponse`, `Record`, etc.).
 /// * From `single_account_request`: All items (e.g., `SingleAccountRequest`).
@@ -67,15 +138,23 @@ pub(crate) static ACCOUNTS_PATH: &str = "accounts";
 /// ```
 ///
 pub mod prelude {
+    pub use super::account_data_request::*;
+    pub use super::account_handle::*;
+    pub use super::account_id::*;
+    pub use super::account_watcher::*;
     pub use super::accounts_request::*;
+    pub use super::multisig_policy::*;
+    pub use super::required_reserve::*;
     pub use super::response::*;
     pub use super::single_account_request::*;
+    pub use super::trustline_authorization::*;
 }
 
 #[cfg(test)]
 pub mod test {
 
     use super::prelude::*;
+    use crate::error::Error;
     use crate::horizon_client::HorizonClient;
 
     static ACCOUNT_ID: &str = "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE";
@@ -112,7 +191,7 @@ pub mod test {
             .unwrap();
 
         // call the get_account_list method to retrieve the account list response
-        let accounts_response: Result<AccountsResponse, String> =
+        let accounts_response: Result<AccountsResponse, Error> =
             horizon_client.get_account_list(&accounts_request).await;
 
         assert!(accounts_response.is_ok());
@@ -137,11 +216,17 @@ pub mod test {
         assert_eq!(response.balances()[0].balance(), BALANCE);
         assert_eq!(response.balances()[0].asset_type(), ASSET_TYPE);
         assert_eq!(
-            response.balances()[0].buying_liabilities(),
+            response.balances()[0]
+                .buying_liabilities()
+                .as_ref()
+                .unwrap(),
             BUYING_LIABILITY
         );
         assert_eq!(
-            response.balances()[0].selling_liabilities(),
+            response.balances()[0]
+                .selling_liabilities()
+                .as_ref()
+                .unwrap(),
             SELLING_LIABILITY
         );
         assert_eq!(response.signers()[0].key(), ACCOUNT_ID);
@@ -186,11 +271,17 @@ pub mod test {
         assert_eq!(response.balances()[0].balance(), BALANCE);
         assert_eq!(response.balances()[0].asset_type(), ASSET_TYPE);
         assert_eq!(
-            response.balances()[0].buying_liabilities(),
+            response.balances()[0]
+                .buying_liabilities()
+                .as_ref()
+                .unwrap(),
             BUYING_LIABILITY
         );
         assert_eq!(
-            response.balances()[0].selling_liabilities(),
+            response.balances()[0]
+                .selling_liabilities()
+                .as_ref()
+                .unwrap(),
             SELLING_LIABILITY
         );
         assert_eq!(response.signers()[0].key(), ACCOUNT_ID);
@@ -200,4 +291,56 @@ pub mod test {
         assert_eq!(response.num_sponsored(), NUM_SPONSORED);
         assert_eq!(response.paging_token(), ACCOUNT_ID);
     }
+
+    #[tokio::test]
+    async fn test_get_account_required_reserve() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let single_account_request = SingleAccountRequest::new()
+            .set_account_id(ACCOUNT_ID)
+            .unwrap();
+
+        let reserve = horizon_client
+            .get_account_required_reserve(&single_account_request)
+            .await
+            .unwrap();
+
+        assert_eq!(reserve.subentry_count(), SUBENTRY_COUNT);
+        assert_eq!(reserve.base_reserves(), 2);
+        assert!(reserve.stroops() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_unauthorized_trustlines() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let asset = crate::models::Asset::new()
+            .set_issued(
+                "USDC",
+                "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
+            )
+            .unwrap();
+
+        let trustlines = horizon_client
+            .get_unauthorized_trustlines(&asset)
+            .await
+            .unwrap();
+
+        for trustline in &trustlines {
+            assert_ne!(
+                trustline.authorization(),
+                &TrustlineAuthorization::Authorized
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_account_handle() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+        let account = horizon_client.account(ACCOUNT_ID).unwrap();
+
+        assert_eq!(account.offers_count().await.unwrap(), 0);
+        assert!(account.claimable_balances_count().await.is_ok());
+        assert!(account.liquidity_pool_shares().await.unwrap().is_empty());
+    }
 }
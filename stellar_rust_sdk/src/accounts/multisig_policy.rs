@@ -0,0 +1,440 @@
+use super::response::Account;
+use derive_getters::Getters;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Represents the class of an operation, which determines the threshold it has to meet.
+///
+/// Every operation belongs to one of three classes, each of which is tied to one of the
+/// thresholds of the source account of the operation:
+/// * `Low` - `allow_trust`, `set_trust_line_flags`, `bump_sequence` and `claim_claimable_balance`.
+/// * `Medium` - All other operations, such as payments, offers and trustline changes.
+/// * `High` - `account_merge`, and `set_options` when it changes the signers or thresholds.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationClass {
+    /// Operations that have to meet the low threshold.
+    Low,
+    /// Operations that have to meet the medium threshold.
+    Medium,
+    /// Operations that have to meet the high threshold.
+    High,
+}
+
+/// Represents the type of a signer of an account.
+///
+/// Besides the keys of other accounts, accounts can be signed for by the hash of a specific
+/// transaction (a pre-authorized transaction), or by the preimage of a SHA-256 hash (hash-x).
+/// Horizon reports the type of every signer, which is parsed into a `SignerType`.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignerType {
+    /// An Ed25519 public key, starting with `G`, which signs by means of a signature.
+    Ed25519PublicKey,
+    /// The hash of a pre-authorized transaction, starting with `T`. The signer is satisfied by
+    /// submitting the transaction with that hash, which removes the signer from the account.
+    PreAuthTx,
+    /// A SHA-256 hash, starting with `X`. The signer is satisfied by adding the preimage of the
+    /// hash to the signatures of a transaction.
+    HashX,
+    /// An Ed25519 signed payload, starting with `P`, which signs by means of a signature of the
+    /// payload.
+    Ed25519SignedPayload,
+    /// A signer type that is not known to this version of the SDK.
+    Other(String),
+}
+
+impl From<&str> for SignerType {
+    fn from(signer_type: &str) -> Self {
+        match signer_type {
+            "ed25519_public_key" => SignerType::Ed25519PublicKey,
+            "preauth_tx" => SignerType::PreAuthTx,
+            "sha256_hash" => SignerType::HashX,
+            "ed25519_signed_payload" => SignerType::Ed25519SignedPayload,
+            other => SignerType::Other(other.to_string()),
+        }
+    }
+}
+
+/// Represents the key of a signer of an account, tagged with the type of the signer.
+///
+/// Horizon reports the key and the type of a signer as separate strings. A `SignerKey` combines
+/// them, so that tooling that audits the signers of accounts, such as key rotation tools and
+/// security monitors, can match on the type and keep watch lists of keys in a `HashSet`.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignerKey {
+    /// An Ed25519 public key, starting with `G`.
+    Ed25519PublicKey(String),
+    /// The hash of a pre-authorized transaction, starting with `T`.
+    PreAuthTx(String),
+    /// A SHA-256 hash, starting with `X`.
+    HashX(String),
+    /// An Ed25519 signed payload, starting with `P`.
+    Ed25519SignedPayload(String),
+    /// The key of a signer type that is not known to this version of the SDK.
+    Other {
+        /// The type of the signer, as reported by Horizon.
+        signer_type: String,
+        /// The key of the signer.
+        key: String,
+    },
+}
+
+impl SignerKey {
+    /// Creates a new `SignerKey`.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the signer, as reported by Horizon.
+    /// * `signer_type` - The type of the signer.
+    ///
+    pub fn new(key: impl Into<String>, signer_type: SignerType) -> Self {
+        let key = key.into();
+        match signer_type {
+            SignerType::Ed25519PublicKey => SignerKey::Ed25519PublicKey(key),
+            SignerType::PreAuthTx => SignerKey::PreAuthTx(key),
+            SignerType::HashX => SignerKey::HashX(key),
+            SignerType::Ed25519SignedPayload => SignerKey::Ed25519SignedPayload(key),
+            SignerType::Other(signer_type) => SignerKey::Other { signer_type, key },
+        }
+    }
+
+    /// Returns the key of the signer.
+    pub fn key(&self) -> &str {
+        match self {
+            SignerKey::Ed25519PublicKey(key)
+            | SignerKey::PreAuthTx(key)
+            | SignerKey::HashX(key)
+            | SignerKey::Ed25519SignedPayload(key)
+            | SignerKey::Other { key, .. } => key,
+        }
+    }
+
+    /// Returns the type of the signer.
+    pub fn signer_type(&self) -> SignerType {
+        match self {
+            SignerKey::Ed25519PublicKey(_) => SignerType::Ed25519PublicKey,
+            SignerKey::PreAuthTx(_) => SignerType::PreAuthTx,
+            SignerKey::HashX(_) => SignerType::HashX,
+            SignerKey::Ed25519SignedPayload(_) => SignerType::Ed25519SignedPayload,
+            SignerKey::Other { signer_type, .. } => SignerType::Other(signer_type.clone()),
+        }
+    }
+}
+
+impl FromStr for SignerKey {
+    type Err = String;
+
+    /// Parses the strkey of a signer, such as an extra signer of a transaction, deriving its type
+    /// from its prefix: `G` for public keys, `T` for pre-authorized transactions, `X` for hash-x
+    /// signers and `P` for signed payloads. The checksum of the key is validated.
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid signer key: {}", key);
+
+        let signer_type =
+            match stellar_xdr::curr::SignerKey::from_str(key).map_err(|_| invalid())? {
+                stellar_xdr::curr::SignerKey::Ed25519(_) => SignerType::Ed25519PublicKey,
+                stellar_xdr::curr::SignerKey::PreAuthTx(_) => SignerType::PreAuthTx,
+                stellar_xdr::curr::SignerKey::HashX(_) => SignerType::HashX,
+                stellar_xdr::curr::SignerKey::Ed25519SignedPayload(_) => {
+                    SignerType::Ed25519SignedPayload
+                }
+            };
+
+        Ok(SignerKey::new(key, signer_type))
+    }
+}
+
+/// Represents a signer that is part of a [`MultisigPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct PolicySigner {
+    /// The key of the signer, as reported by Horizon.
+    key: String,
+    /// The type of the signer.
+    signer_type: SignerType,
+    /// The weight of the signer.
+    weight: u32,
+}
+
+impl PolicySigner {
+    /// Creates a new `PolicySigner`.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the signer, such as a public key or the hash of a transaction.
+    /// * `signer_type` - The type of the signer.
+    /// * `weight` - The weight of the signer.
+    ///
+    pub fn new(key: impl Into<String>, signer_type: SignerType, weight: u32) -> Self {
+        PolicySigner {
+            key: key.into(),
+            signer_type,
+            weight,
+        }
+    }
+}
+
+/// Represents the multisig policy of an account, which decides whether a set of signers may
+/// authorize an operation.
+///
+/// The policy consists of the thresholds and the signers of an account. To authorize an
+/// operation, the combined weight of the signers that signed for it has to meet the threshold of
+/// the [`OperationClass`] of the operation. This allows coordination tooling to track which
+/// signatures a multisig transaction still needs, before submitting it.
+///
+/// Signers are identified by their keys. Besides public keys, these include the keys of
+/// pre-authorized transaction signers and hash-x signers (see [`SignerType`]); it is up to the
+/// caller to decide whether such a signer is satisfied, for example because the preimage of a
+/// hash-x signer is known.
+///
+/// Note that the network always requires at least one signature, so the required weight of an
+/// operation class is at least `1`, even if its threshold is `0`.
+///
+/// # Example
+/// ```
+/// use stellar_rs::accounts::prelude::*;
+///
+/// let alice = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+/// let bob = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+/// let policy = MultisigPolicy::new(
+///     1,
+///     2,
+///     3,
+///     vec![
+///         PolicySigner::new(alice, SignerType::Ed25519PublicKey, 1),
+///         PolicySigner::new(bob, SignerType::Ed25519PublicKey, 1),
+///     ],
+/// );
+///
+/// assert_eq!(policy.weight_of([alice]), 1);
+/// assert_eq!(policy.missing_weight(OperationClass::Medium, [alice]), 1);
+/// assert_eq!(policy.missing_weight(OperationClass::Medium, [alice, bob]), 0);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct MultisigPolicy {
+    /// The threshold of operations of the class `Low`.
+    low_threshold: u32,
+    /// The threshold of operations of the class `Medium`.
+    med_threshold: u32,
+    /// The threshold of operations of the class `High`.
+    high_threshold: u32,
+    /// The signers of the account.
+    signers: Vec<PolicySigner>,
+}
+
+impl MultisigPolicy {
+    /// Creates a new `MultisigPolicy`.
+    ///
+    /// # Arguments
+    /// * `low_threshold` - The threshold of operations of the class `Low`.
+    /// * `med_threshold` - The threshold of operations of the class `Medium`.
+    /// * `high_threshold` - The threshold of operations of the class `High`.
+    /// * `signers` - The signers of the account.
+    ///
+    pub fn new(
+        low_threshold: u32,
+        med_threshold: u32,
+        high_threshold: u32,
+        signers: Vec<PolicySigner>,
+    ) -> Self {
+        MultisigPolicy {
+            low_threshold,
+            med_threshold,
+            high_threshold,
+            signers,
+        }
+    }
+
+    /// Creates the `MultisigPolicy` of the given account.
+    ///
+    /// # Arguments
+    /// * `account` - The account, as returned by the Horizon server.
+    ///
+    pub fn from_account(account: &Account) -> Self {
+        let thresholds = account.thresholds();
+        let signers = account
+            .signers()
+            .iter()
+            .map(|signer| {
+                PolicySigner::new(
+                    signer.key(),
+                    SignerType::from(signer.singer_type().as_str()),
+                    *signer.weight(),
+                )
+            })
+            .collect();
+
+        MultisigPolicy::new(
+            *thresholds.low_threshold(),
+            *thresholds.med_threshold(),
+            *thresholds.high_threshold(),
+            signers,
+        )
+    }
+
+    /// Returns the weight that is required to authorize operations of the given class.
+    pub fn required_weight(&self, operation_class: OperationClass) -> u32 {
+        let threshold = match operation_class {
+            OperationClass::Low => self.low_threshold,
+            OperationClass::Medium => self.med_threshold,
+            OperationClass::High => self.high_threshold,
+        };
+
+        threshold.max(1)
+    }
+
+    /// Returns the combined weight of the given signers.
+    ///
+    /// Keys that are not signers of the account are ignored, and every signer is counted once,
+    /// even if its key is given more than once.
+    ///
+    /// # Arguments
+    /// * `signers` - The keys of the signers that signed.
+    ///
+    pub fn weight_of<'a>(&self, signers: impl IntoIterator<Item = &'a str>) -> u32 {
+        let signers: HashSet<&str> = signers.into_iter().collect();
+
+        self.signers
+            .iter()
+            .filter(|signer| signers.contains(signer.key.as_str()))
+            .map(|signer| signer.weight)
+            .sum()
+    }
+
+    /// Returns the weight that the given signers lack to authorize operations of the given class.
+    ///
+    /// # Arguments
+    /// * `operation_class` - The class of the operations to authorize.
+    /// * `signers` - The keys of the signers that signed.
+    ///
+    /// # Returns
+    /// The missing weight, which is `0` if the signers are able to authorize the operations.
+    ///
+    pub fn missing_weight<'a>(
+        &self,
+        operation_class: OperationClass,
+        signers: impl IntoIterator<Item = &'a str>,
+    ) -> u32 {
+        self.required_weight(operation_class)
+            .saturating_sub(self.weight_of(signers))
+    }
+
+    /// Returns the signers of the given type.
+    pub fn signers_of_type<'a>(
+        &'a self,
+        signer_type: &'a SignerType,
+    ) -> impl Iterator<Item = &'a PolicySigner> + 'a {
+        self.signers
+            .iter()
+            .filter(move |signer| &signer.signer_type == signer_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Response;
+
+    const SIGNER_1: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+    const SIGNER_2: &str = "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE";
+    const PRE_AUTH_TX: &str = "TBU2RRGLXH3E5CQHTD3ODLDF2BWDCYUSSBLLZ5GNW7JXHDIYKXZWHXL7";
+
+    #[test]
+    fn test_from_account() {
+        let json = include_str!("../../tests/fixtures/accounts/single_account.json");
+        let account = Account::from_json(json.to_string()).unwrap();
+        let policy = MultisigPolicy::from_account(&account);
+
+        assert_eq!(policy.required_weight(OperationClass::Low), 1);
+        assert_eq!(policy.required_weight(OperationClass::High), 2);
+        assert_eq!(policy.weight_of([SIGNER_1, SIGNER_2]), 3);
+        assert_eq!(policy.missing_weight(OperationClass::Medium, [SIGNER_1]), 1);
+        assert_eq!(policy.missing_weight(OperationClass::Medium, [SIGNER_2]), 0);
+        assert_eq!(
+            policy
+                .signers_of_type(&SignerType::Ed25519PublicKey)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_weight_of() {
+        let policy = MultisigPolicy::new(
+            0,
+            2,
+            5,
+            vec![
+                PolicySigner::new(SIGNER_1, SignerType::Ed25519PublicKey, 1),
+                PolicySigner::new(SIGNER_2, SignerType::Ed25519PublicKey, 0),
+                PolicySigner::new(PRE_AUTH_TX, SignerType::PreAuthTx, 5),
+            ],
+        );
+
+        // Duplicate and unknown signers are ignored.
+        assert_eq!(policy.weight_of([SIGNER_1, SIGNER_1, "unknown"]), 1);
+        assert_eq!(policy.weight_of([PRE_AUTH_TX]), 5);
+        assert_eq!(policy.missing_weight(OperationClass::High, [SIGNER_1]), 4);
+        assert_eq!(
+            policy.missing_weight(OperationClass::High, [PRE_AUTH_TX]),
+            0
+        );
+
+        // A threshold of 0 still requires a signer with a weight.
+        assert_eq!(policy.required_weight(OperationClass::Low), 1);
+        assert_eq!(policy.missing_weight(OperationClass::Low, [SIGNER_2]), 1);
+        assert_eq!(policy.missing_weight(OperationClass::Low, []), 1);
+    }
+
+    #[test]
+    fn test_signer_keys() {
+        let json = include_str!("../../tests/fixtures/accounts/single_account.json");
+        let account = Account::from_json(json.to_string()).unwrap();
+
+        assert_eq!(
+            account.signer_keys(),
+            vec![
+                (SignerKey::Ed25519PublicKey(SIGNER_1.to_string()), 1),
+                (SignerKey::Ed25519PublicKey(SIGNER_2.to_string()), 2),
+            ]
+        );
+
+        let signer_key = SignerKey::new(PRE_AUTH_TX, SignerType::PreAuthTx);
+        assert_eq!(signer_key, SignerKey::PreAuthTx(PRE_AUTH_TX.to_string()));
+        assert_eq!(signer_key.key(), PRE_AUTH_TX);
+        assert_eq!(signer_key.signer_type(), SignerType::PreAuthTx);
+
+        assert_eq!(
+            SIGNER_1.parse::<SignerKey>(),
+            Ok(SignerKey::Ed25519PublicKey(SIGNER_1.to_string()))
+        );
+        assert_eq!(PRE_AUTH_TX.parse::<SignerKey>(), Ok(signer_key));
+        assert_eq!(
+            "GABC".parse::<SignerKey>(),
+            Err("Invalid signer key: GABC".to_string())
+        );
+
+        let signer_key = SignerKey::new("KEY", SignerType::from("unknown"));
+        assert_eq!(signer_key.key(), "KEY");
+        assert_eq!(
+            signer_key.signer_type(),
+            SignerType::Other("unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_signer_type() {
+        assert_eq!(SignerType::from("preauth_tx"), SignerType::PreAuthTx);
+        assert_eq!(SignerType::from("sha256_hash"), SignerType::HashX);
+        assert_eq!(
+            SignerType::from("ed25519_signed_payload"),
+            SignerType::Ed25519SignedPayload
+        );
+        assert_eq!(
+            SignerType::from("unknown"),
+            SignerType::Other("unknown".to_string())
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use super::response::Account;
+use std::collections::{HashSet, VecDeque};
+
+/// The state of a watcher of new accounts, shared between the items it yields.
+///
+/// Horizon orders the accounts of the `/accounts` endpoint by their ID rather than by the time
+/// they were created or started to match the filter, so new accounts can not be found by paging
+/// from a cursor. Instead, every poll lists all matching accounts, and the accounts that were not
+/// listed by the previous poll are new.
+pub(crate) struct NewAccountsWatcherState {
+    /// The URL of the first page of matching accounts.
+    pub(crate) url: String,
+    /// Whether the accounts have been polled before.
+    pub(crate) polled: bool,
+    /// The IDs of the accounts that matched the filter at the previous poll, if any.
+    known: Option<HashSet<String>>,
+    /// The new accounts that have not been yielded yet.
+    pub(crate) pending: VecDeque<Account>,
+}
+
+impl NewAccountsWatcherState {
+    /// Creates the state of a watcher. If `known` is `None`, the first poll only serves as the
+    /// baseline. Otherwise, the first poll yields every account that is not in `known`.
+    pub(crate) fn new(url: String, known: Option<HashSet<String>>) -> Self {
+        NewAccountsWatcherState {
+            url,
+            polled: false,
+            known,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Compares the accounts of a poll with those of the previous poll, and queues the accounts
+    /// that are new.
+    ///
+    /// Accounts that no longer match the filter are forgotten, so that an account that matches
+    /// the filter again later, for example because a removed signer is added again, is new again.
+    pub(crate) fn update(&mut self, accounts: Vec<Account>) {
+        let current: HashSet<String> = accounts
+            .iter()
+            .map(|account| account.account_id().clone())
+            .collect();
+
+        if let Some(known) = &self.known {
+            self.pending.extend(
+                accounts
+                    .into_iter()
+                    .filter(|account| !known.contains(account.account_id())),
+            );
+        }
+        self.known = Some(current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Response;
+
+    fn account(account_id: &str) -> Account {
+        let json = include_str!("../../tests/fixtures/accounts/single_account.json");
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        value["account_id"] = account_id.into();
+        Account::from_json(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_update() {
+        let mut state = NewAccountsWatcherState::new(String::new(), None);
+
+        // The first poll is the baseline.
+        state.update(vec![account("A"), account("B")]);
+        assert!(state.pending.is_empty());
+
+        state.update(vec![account("A"), account("B"), account("C")]);
+        let new: Vec<_> = state.pending.drain(..).collect();
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].account_id(), "C");
+
+        // An account that stops and starts matching again is new again.
+        state.update(vec![account("A"), account("C")]);
+        state.update(vec![account("A"), account("B"), account("C")]);
+        assert_eq!(state.pending.pop_front().unwrap().account_id(), "B");
+
+        // Known accounts replace the baseline.
+        let known = HashSet::from(["A".to_string()]);
+        let mut state = NewAccountsWatcherState::new(String::new(), Some(known));
+        state.update(vec![account("A"), account("B")]);
+        assert_eq!(state.pending.len(), 1);
+    }
+}
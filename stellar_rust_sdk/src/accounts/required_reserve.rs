@@ -0,0 +1,143 @@
+use super::response::Account;
+use crate::models::serde_adapters::{format_stroops, parse_stroops};
+use derive_getters::Getters;
+use std::fmt;
+
+/// The number of stroops in one lumen (XLM).
+pub const STROOPS_PER_XLM: i64 = 10_000_000;
+
+/// The number of base reserves every account needs, regardless of its subentries.
+const ACCOUNT_BASE_RESERVES: i64 = 2;
+
+/// Represents the minimum balance, in stroops, that an account is required to hold.
+///
+/// Every account must hold two base reserves, plus one base reserve for every subentry (trustlines,
+/// offers, signers and data entries) and for every entry it sponsors. Entries that are sponsored by
+/// another account do not count towards the reserve of the account itself.
+///
+/// The amount is kept in stroops, so that no precision is lost. Its `Display` implementation
+/// formats the amount in lumens, with the 7 decimals used throughout the Horizon API.
+///
+/// # Example
+/// ```
+/// use stellar_rs::accounts::prelude::*;
+///
+/// // An account with 3 subentries, on a network with a base reserve of 0.5 XLM.
+/// let reserve = RequiredReserve::new(5_000_000, 3, 0, 0);
+/// assert_eq!(reserve.stroops(), 25_000_000);
+/// assert_eq!(reserve.to_string(), "2.5000000");
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters)]
+pub struct RequiredReserve {
+    /// The base reserve of the network, in stroops.
+    base_reserve_in_stroops: i64,
+    /// The number of subentries of the account.
+    subentry_count: u32,
+    /// The number of entries the account is sponsoring.
+    num_sponsoring: u32,
+    /// The number of entries the account is sponsored for.
+    num_sponsored: u32,
+}
+
+impl RequiredReserve {
+    /// Creates a new `RequiredReserve`.
+    ///
+    /// # Arguments
+    /// * `base_reserve_in_stroops` - The base reserve of the network, in stroops.
+    /// * `subentry_count` - The number of subentries of the account.
+    /// * `num_sponsoring` - The number of entries the account is sponsoring.
+    /// * `num_sponsored` - The number of entries the account is sponsored for.
+    ///
+    pub fn new(
+        base_reserve_in_stroops: i64,
+        subentry_count: u32,
+        num_sponsoring: u32,
+        num_sponsored: u32,
+    ) -> Self {
+        RequiredReserve {
+            base_reserve_in_stroops,
+            subentry_count,
+            num_sponsoring,
+            num_sponsored,
+        }
+    }
+
+    /// Creates a new `RequiredReserve` for the given account.
+    ///
+    /// # Arguments
+    /// * `base_reserve_in_stroops` - The base reserve of the network, in stroops.
+    /// * `account` - The account, as returned by the Horizon server.
+    ///
+    pub fn for_account(base_reserve_in_stroops: i64, account: &Account) -> Self {
+        RequiredReserve::new(
+            base_reserve_in_stroops,
+            *account.subentry_count(),
+            *account.num_sponsoring(),
+            *account.num_sponsored(),
+        )
+    }
+
+    /// Returns the number of base reserves the account is required to hold.
+    pub fn base_reserves(&self) -> i64 {
+        ACCOUNT_BASE_RESERVES + self.subentry_count as i64 + self.num_sponsoring as i64
+            - self.num_sponsored as i64
+    }
+
+    /// Returns the required reserve, in stroops.
+    pub fn stroops(&self) -> i64 {
+        self.base_reserves() * self.base_reserve_in_stroops
+    }
+
+    /// Returns the amount of lumens the account can send, in stroops.
+    ///
+    /// This is the native balance of the account, minus the required reserve and the lumens that
+    /// are locked up in open sell offers (the selling liabilities).
+    ///
+    /// # Arguments
+    /// * `account` - The account, as returned by the Horizon server.
+    ///
+    /// # Returns
+    /// The available amount in stroops, which is never negative, or an error if the native
+    /// balance of the account could not be parsed.
+    ///
+    pub fn available_to_send(&self, account: &Account) -> Result<i64, String> {
+        let native = account
+            .balances()
+            .iter()
+            .find(|balance| balance.asset_type() == "native")
+            .ok_or("Account has no native balance.")?;
+
+        let balance = parse_stroops(native.balance())?;
+        let selling_liabilities = native
+            .selling_liabilities()
+            .as_deref()
+            .map_or(Ok(0), parse_stroops)?;
+
+        Ok((balance - self.stroops() - selling_liabilities).max(0))
+    }
+}
+
+impl fmt::Display for RequiredReserve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_stroops(self.stroops()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_reserve() {
+        let reserve = RequiredReserve::new(5_000_000, 4, 2, 1);
+        assert_eq!(reserve.base_reserves(), 7);
+        assert_eq!(reserve.stroops(), 35_000_000);
+        assert_eq!(reserve.to_string(), "3.5000000");
+
+        assert_eq!(
+            RequiredReserve::new(5_000_000, 0, 0, 0).to_string(),
+            "1.0000000"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::multisig_policy::{SignerKey, SignerType};
 use crate::models::prelude::*;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
@@ -8,7 +9,8 @@ use serde::{Deserialize, Serialize};
 /// when querying for multiple accounts. It includes navigational links and the embedded data
 /// containing account records.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct AccountsResponse {
     /// Navigational links related to the response.
     #[serde(rename = "_links")]
@@ -23,7 +25,8 @@ pub struct AccountsResponse {
 /// This struct defines the low, medium, and high thresholds for operations on an account,
 /// determining the minimum level of authorization required for various types of transactions.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Thresholds {
     /// The low threshold value for operations.
     low_threshold: u32,
@@ -38,7 +41,8 @@ pub struct Thresholds {
 /// This struct details information about a signer for an account, including their key,
 /// weight in authorization decisions, and type.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Signer {
     /// The weight of the signer's vote in authorization decisions.
     weight: u32,
@@ -47,6 +51,8 @@ pub struct Signer {
     /// The type of the signer (e.g., 'ed25519_public_key').
     #[serde(rename = "type")]
     singer_type: String,
+    /// The account ID of the sponsor who is paying the reserve for this signer, if any.
+    sponsor: Option<String>,
 }
 
 /// Represents a single balance within a single account.
@@ -54,16 +60,92 @@ pub struct Signer {
 /// This struct encapsulates the details of a single balance, including the amount, liabilities,
 /// and the type of the asset.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Balances {
     /// The total balance of the asset.
     balance: String,
-    /// Buying liabilities associated with the asset.
-    buying_liabilities: String,
-    /// Selling liabilities associated with the asset.
-    selling_liabilities: String,
+    /// The maximum amount of the asset the account is willing to hold, absent for the native asset.
+    limit: Option<String>,
+    /// Buying liabilities associated with the asset, absent for liquidity pool shares.
+    buying_liabilities: Option<String>,
+    /// Selling liabilities associated with the asset, absent for liquidity pool shares.
+    selling_liabilities: Option<String>,
+    /// The account ID of the sponsor who is paying the reserve for the trustline, if any.
+    sponsor: Option<String>,
+    /// The sequence number of the last ledger in which the trustline was modified, absent for the
+    /// native asset.
+    last_modified_ledger: Option<u32>,
+    /// Whether the account is authorized to hold the asset, absent for the native asset.
+    is_authorized: Option<bool>,
+    /// Whether the account is authorized to maintain its liabilities, but not to receive the asset,
+    /// absent for the native asset.
+    is_authorized_to_maintain_liabilities: Option<bool>,
+    /// Whether the issuer can claw back the asset, absent for the native asset.
+    is_clawback_enabled: Option<bool>,
     /// The type of the asset (e.g., native, credit_alphanum4, credit_alphanum12).
     asset_type: String,
+    /// The code of the asset, absent for the native asset and liquidity pool shares.
+    asset_code: Option<String>,
+    /// The issuer of the asset, absent for the native asset and liquidity pool shares.
+    asset_issuer: Option<String>,
+    /// The ID of the liquidity pool, present only for liquidity pool shares.
+    liquidity_pool_id: Option<String>,
+}
+
+impl Signer {
+    /// Returns the key of the signer, tagged with its type.
+    pub fn signer_key(&self) -> SignerKey {
+        SignerKey::new(
+            self.key.as_str(),
+            SignerType::from(self.singer_type.as_str()),
+        )
+    }
+}
+
+impl Balances {
+    /// Returns the authorization flags of the trustline, or `None` for the native balance, which
+    /// is not held in a trustline.
+    ///
+    /// Wallets can use the flags to show balances that are frozen by their issuer.
+    pub fn flags(&self) -> Option<BalanceFlags> {
+        if self.asset_type == "native" {
+            return None;
+        }
+
+        Some(BalanceFlags {
+            is_authorized: self.is_authorized.unwrap_or(false),
+            is_authorized_to_maintain_liabilities: self
+                .is_authorized_to_maintain_liabilities
+                .unwrap_or(false),
+            is_clawback_enabled: self.is_clawback_enabled.unwrap_or(false),
+        })
+    }
+}
+
+/// Represents the authorization flags of a single trustline, as set by the issuer of the asset.
+///
+/// A trustline that is neither authorized nor authorized to maintain liabilities is frozen: the
+/// account can not send, receive, buy or sell the asset.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Getters)]
+#[non_exhaustive]
+pub struct BalanceFlags {
+    /// Whether the account is authorized to hold and transact with the asset.
+    is_authorized: bool,
+    /// Whether the account is authorized to maintain its existing liabilities, but not to receive
+    /// the asset.
+    is_authorized_to_maintain_liabilities: bool,
+    /// Whether the issuer can claw back the asset.
+    is_clawback_enabled: bool,
+}
+
+impl BalanceFlags {
+    /// Returns whether the trustline is frozen, meaning that it is neither authorized nor
+    /// authorized to maintain liabilities.
+    pub fn is_frozen(&self) -> bool {
+        !self.is_authorized && !self.is_authorized_to_maintain_liabilities
+    }
 }
 
 /// Represents the navigational links in a single account response from the Horizon API.
@@ -72,6 +154,7 @@ pub struct Balances {
 /// operations, payments, effects, offers, trades, and data, providing quick access to related resources.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct AccountResponseLinks {
     /// The link to the account itself.
     #[serde(rename = "self")]
@@ -98,7 +181,8 @@ pub struct AccountResponseLinks {
 /// It includes navigational links, account identifiers, thresholds, flags, balances,
 /// signers, and additional data related to the account.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Account {
     /// Navigational links related to the account.
     #[serde(rename = "_links")]
@@ -109,8 +193,18 @@ pub struct Account {
     account_id: String,
     /// The sequence number of the account.
     sequence: String,
+    /// The sequence number of the ledger in which the sequence number was last bumped, if known.
+    sequence_ledger: Option<u32>,
+    /// The close time of the ledger in which the sequence number was last bumped, as a Unix
+    /// timestamp, if known.
+    sequence_time: Option<String>,
     /// The number of subentries in the account.
     subentry_count: u32,
+    /// The home domain of the account, if set.
+    home_domain: Option<String>,
+    /// The inflation destination of the account, if set. Inflation is no longer supported by the
+    /// network.
+    inflation_destination: Option<String>,
     /// The ledger number in which the account was last modified.
     last_modified_ledger: u64,
     /// The time at which the account was last modified.
@@ -125,10 +219,16 @@ pub struct Account {
     signers: Vec<Signer>,
     /// Additional data associated with the account.
     data: Option<Data>,
-    /// The number of entries the account is sponsoring.
+    /// The number of entries the account is sponsoring. Introduced in protocol 15, so it defaults
+    /// to `0` on older networks.
+    #[serde(default)]
     num_sponsoring: u32,
-    /// The number of entries the account is sponsored for.
+    /// The number of entries the account is sponsored for. Introduced in protocol 15, so it
+    /// defaults to `0` on older networks.
+    #[serde(default)]
     num_sponsored: u32,
+    /// The account ID of the sponsor who is paying the reserves for this account, if any.
+    sponsor: Option<String>,
     /// A token used for paging through results.
     paging_token: String,
 }
@@ -145,20 +245,68 @@ pub struct Account {
 /// additional data that may be added to the Horizon API response in the future. It can be extended to include specific
 /// fields as needed.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Data {
     // Future fields to be added here
 }
 
+/// Represents the response for a single data entry of an account in the Horizon API.
+///
+/// Horizon returns the value of the data entry base64-encoded, since it may hold arbitrary bytes.
+/// Use [`AccountDataResponse::decoded_value`] to obtain the bytes themselves.
+///
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
+pub struct AccountDataResponse {
+    /// The base64-encoded value of the data entry.
+    value: String,
+    /// The account ID of the sponsor who is paying the reserve for this data entry, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sponsor: Option<String>,
+}
+
+impl AccountDataResponse {
+    /// Decodes the value of the data entry from base64.
+    ///
+    /// # Returns
+    /// The bytes of the value, or an error if the value is not valid base64.
+    ///
+    pub fn decoded_value(&self) -> Result<Vec<u8>, String> {
+        use base64::{engine::general_purpose, Engine};
+
+        general_purpose::STANDARD
+            .decode(&self.value)
+            .map_err(|e| format!("Invalid data value {}: {}", self.value, e))
+    }
+}
+
+impl Account {
+    /// Returns the keys of the signers of the account, tagged with their type, together with
+    /// their weights.
+    pub fn signer_keys(&self) -> Vec<(SignerKey, u32)> {
+        self.signers
+            .iter()
+            .map(|signer| (signer.signer_key(), signer.weight))
+            .collect()
+    }
+}
+
 impl Response for Account {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
+    }
+}
+
+impl Response for AccountDataResponse {
+    fn from_json(json: String) -> Result<Self, String> {
+        crate::models::deserialize_json(&json)
     }
 }
 
 impl Response for AccountsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
@@ -1,10 +1,8 @@
-use crate::models::{is_public_key, Request};
+use super::account_id::AccountId;
+use crate::models::{resolve_account_id, Request};
 
-/// Represents a query parameter for the account's public key
-#[derive(Default, Clone)]
-pub struct AccountId(String);
 /// Represents the absence of a query parameter for the account's public key
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoAccountId;
 
 /// Represents a request to fetch details of a single account from the Horizon API.
@@ -33,8 +31,8 @@ pub struct NoAccountId;
 /// // Use with HorizonClient::get_single_account
 /// ```
 ///
-#[derive(Default)]
-pub struct SingleAccountRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct SingleAccountRequest<I = NoAccountId> {
     /// The account's public key.
     account_id: I,
 }
@@ -48,19 +46,15 @@ impl SingleAccountRequest<NoAccountId> {
     /// Sets the account ID for the request.
     ///
     /// # Arguments
-    /// * `account_id` - A `String` specifying the account's public key.
+    /// * `account_id` - A `String` specifying the account's public key. A muxed account (`M...`) is
+    ///   resolved to its underlying account.
     ///
     pub fn set_account_id(
         self,
         account_id: impl Into<String>,
     ) -> Result<SingleAccountRequest<AccountId>, String> {
-        let account_id = account_id.into();
-        if let Err(e) = is_public_key(&account_id) {
-            return Err(e.to_string());
-        }
-
         Ok(SingleAccountRequest {
-            account_id: AccountId(account_id),
+            account_id: AccountId::new(resolve_account_id(account_id.into()))?,
         })
     }
 }
@@ -68,7 +62,7 @@ impl SingleAccountRequest<NoAccountId> {
 impl Request for SingleAccountRequest<AccountId> {
     fn get_query_parameters(&self) -> String {
         let mut query = String::new();
-        query.push_str(&format!("{}", self.account_id.0));
+        query.push_str(&format!("{}", self.account_id));
 
         query.trim_end_matches('&').to_string()
     }
@@ -0,0 +1,272 @@
+use super::response::{Account, Balances};
+use crate::models::is_public_key;
+use crate::models::prelude::AssetType;
+use derive_getters::Getters;
+use std::str::FromStr;
+use stellar_xdr::curr as xdr;
+
+/// Represents the authorization state of a trustline, as set by the issuer of the asset.
+///
+/// Issuers of assets with the `auth_required` flag have to authorize every trustline before the
+/// account can hold the asset. Issuers with the `auth_revocable` flag can also revoke the
+/// authorization again, either completely or partially:
+/// * `Authorized` - The account can send, receive, buy and sell the asset.
+/// * `AuthorizedToMaintainLiabilities` - The account can keep its existing offers, but can not
+///   otherwise send or receive the asset.
+/// * `Unauthorized` - The account can not transact with the asset at all; the trustline is frozen.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrustlineAuthorization {
+    /// The trustline is fully authorized.
+    Authorized,
+    /// The trustline is authorized to maintain liabilities only.
+    AuthorizedToMaintainLiabilities,
+    /// The trustline is not authorized.
+    Unauthorized,
+}
+
+impl TrustlineAuthorization {
+    /// Returns the authorization state of a balance, or `None` for balances that are not
+    /// trustlines to an issued asset, such as the native balance and liquidity pool shares.
+    pub fn of(balance: &Balances) -> Option<Self> {
+        if balance.asset_issuer().is_none() {
+            return None;
+        }
+
+        match (
+            balance.is_authorized().unwrap_or(false),
+            balance
+                .is_authorized_to_maintain_liabilities()
+                .unwrap_or(false),
+        ) {
+            (true, _) => Some(TrustlineAuthorization::Authorized),
+            (false, true) => Some(TrustlineAuthorization::AuthorizedToMaintainLiabilities),
+            (false, false) => Some(TrustlineAuthorization::Unauthorized),
+        }
+    }
+
+    /// Returns the trustline flags that are set for this authorization state.
+    fn flags(&self) -> u32 {
+        match self {
+            TrustlineAuthorization::Authorized => xdr::TrustLineFlags::AuthorizedFlag as u32,
+            TrustlineAuthorization::AuthorizedToMaintainLiabilities => {
+                xdr::TrustLineFlags::AuthorizedToMaintainLiabilitiesFlag as u32
+            }
+            TrustlineAuthorization::Unauthorized => 0,
+        }
+    }
+}
+
+/// Represents a trustline to an asset that is not fully authorized by its issuer.
+///
+/// Unauthorized trustlines are returned by
+/// [`HorizonClient::get_unauthorized_trustlines`](crate::horizon_client::HorizonClient::get_unauthorized_trustlines),
+/// so that issuers can review pending trustlines and authorize them using a
+/// [`SetTrustLineFlagsIntent`].
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct UnauthorizedTrustline {
+    /// The account that holds the trustline.
+    account_id: String,
+    /// The balance of the asset held by the account.
+    balance: String,
+    /// The authorization state of the trustline.
+    authorization: TrustlineAuthorization,
+}
+
+impl UnauthorizedTrustline {
+    /// Returns the trustline of the account to the given asset, if it exists and is not fully
+    /// authorized.
+    ///
+    /// # Arguments
+    /// * `account` - The account, as returned by the Horizon server.
+    /// * `asset_code` - The code of the asset.
+    /// * `asset_issuer` - The account ID of the issuer of the asset.
+    ///
+    pub fn of(account: &Account, asset_code: &str, asset_issuer: &str) -> Option<Self> {
+        let balance = account.balances().iter().find(|balance| {
+            balance.asset_code().as_deref() == Some(asset_code)
+                && balance.asset_issuer().as_deref() == Some(asset_issuer)
+        })?;
+
+        match TrustlineAuthorization::of(balance)? {
+            TrustlineAuthorization::Authorized => None,
+            authorization => Some(UnauthorizedTrustline {
+                account_id: account.account_id().clone(),
+                balance: balance.balance().clone(),
+                authorization,
+            }),
+        }
+    }
+}
+
+/// Represents the parameters of a `set_trust_line_flags` operation, which changes the
+/// authorization of a trustline.
+///
+/// The operation has to be submitted by the issuer of the asset. It sets the flags of the
+/// requested [`TrustlineAuthorization`], and clears the flags of the other states, so that the
+/// trustline ends up in exactly the requested state. The clawback flag of the trustline is left
+/// unchanged.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::accounts::prelude::*;
+/// # use stellar_rs::models::prelude::*;
+/// let asset = AssetType::Alphanumeric4(AssetData {
+///     asset_code: "USDC".to_string(),
+///     asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+/// });
+///
+/// // Freeze the trustline of an account.
+/// let intent = SetTrustLineFlagsIntent::new(
+///     "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE",
+///     asset,
+///     TrustlineAuthorization::Unauthorized,
+/// )
+/// .unwrap();
+/// assert_eq!(intent.clear_flags(), 3);
+///
+/// let operation = intent.to_operation_body().unwrap();
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct SetTrustLineFlagsIntent {
+    /// The account that holds the trustline.
+    trustor: String,
+    /// The asset of the trustline.
+    asset: AssetType,
+    /// The requested authorization state of the trustline.
+    authorization: TrustlineAuthorization,
+}
+
+impl SetTrustLineFlagsIntent {
+    /// Creates a new `SetTrustLineFlagsIntent`.
+    ///
+    /// # Arguments
+    /// * `trustor` - The account that holds the trustline.
+    /// * `asset` - The issued asset of the trustline.
+    /// * `authorization` - The requested authorization state of the trustline.
+    ///
+    /// # Returns
+    /// The `SetTrustLineFlagsIntent`, or an error if the trustor is not a public key or the asset
+    /// is the native asset.
+    ///
+    pub fn new(
+        trustor: impl Into<String>,
+        asset: AssetType,
+        authorization: TrustlineAuthorization,
+    ) -> Result<Self, String> {
+        let trustor = trustor.into();
+        is_public_key(&trustor)?;
+        if asset == AssetType::Native {
+            return Err("The native asset has no trustlines".to_string());
+        }
+
+        Ok(SetTrustLineFlagsIntent {
+            trustor,
+            asset,
+            authorization,
+        })
+    }
+
+    /// Returns the trustline flags that the operation sets.
+    pub fn set_flags(&self) -> u32 {
+        self.authorization.flags()
+    }
+
+    /// Returns the trustline flags that the operation clears.
+    pub fn clear_flags(&self) -> u32 {
+        let authorization_flags = xdr::TrustLineFlags::AuthorizedFlag as u32
+            | xdr::TrustLineFlags::AuthorizedToMaintainLiabilitiesFlag as u32;
+
+        authorization_flags & !self.set_flags()
+    }
+
+    /// Builds the body of the `set_trust_line_flags` operation.
+    pub fn to_operation_body(&self) -> Result<xdr::OperationBody, String> {
+        Ok(xdr::OperationBody::SetTrustLineFlags(
+            xdr::SetTrustLineFlagsOp {
+                trustor: xdr::AccountId::from_str(&self.trustor)
+                    .map_err(|_| format!("Invalid trustor: {}", self.trustor))?,
+                asset: xdr::Asset::try_from(&self.asset)?,
+                clear_flags: self.clear_flags(),
+                set_flags: self.set_flags(),
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::prelude::AssetData;
+    use crate::models::Response;
+
+    const ACCOUNT_ID: &str = "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE";
+    const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+    fn account() -> Account {
+        let json = include_str!("../../tests/fixtures/accounts/single_account.json");
+        Account::from_json(json.to_string()).unwrap()
+    }
+
+    fn usdc() -> AssetType {
+        AssetType::Alphanumeric4(AssetData {
+            asset_code: "USDC".to_string(),
+            asset_issuer: ISSUER.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_trustline_authorization() {
+        let account = account();
+        let authorizations: Vec<_> = account
+            .balances()
+            .iter()
+            .map(TrustlineAuthorization::of)
+            .collect();
+
+        // The fixture holds USDC, liquidity pool shares and the native asset.
+        assert_eq!(
+            authorizations,
+            vec![Some(TrustlineAuthorization::Authorized), None, None]
+        );
+        assert_eq!(UnauthorizedTrustline::of(&account, "USDC", ISSUER), None);
+        assert_eq!(UnauthorizedTrustline::of(&account, "EURC", ISSUER), None);
+    }
+
+    #[test]
+    fn test_set_trust_line_flags_intent() {
+        let intent =
+            SetTrustLineFlagsIntent::new(ACCOUNT_ID, usdc(), TrustlineAuthorization::Authorized)
+                .unwrap();
+        assert_eq!((intent.set_flags(), intent.clear_flags()), (1, 2));
+
+        let intent = SetTrustLineFlagsIntent::new(
+            ACCOUNT_ID,
+            usdc(),
+            TrustlineAuthorization::AuthorizedToMaintainLiabilities,
+        )
+        .unwrap();
+        assert_eq!((intent.set_flags(), intent.clear_flags()), (2, 1));
+
+        let xdr::OperationBody::SetTrustLineFlags(operation) = intent.to_operation_body().unwrap()
+        else {
+            panic!("expected a set_trust_line_flags operation");
+        };
+        assert_eq!(operation.trustor.to_string(), ACCOUNT_ID);
+        assert_eq!(operation.set_flags, 2);
+        assert_eq!(operation.clear_flags, 1);
+
+        assert!(SetTrustLineFlagsIntent::new(
+            ACCOUNT_ID,
+            AssetType::Native,
+            TrustlineAuthorization::Authorized
+        )
+        .is_err());
+        assert!(
+            SetTrustLineFlagsIntent::new("GDIG", usdc(), TrustlineAuthorization::Authorized)
+                .is_err()
+        );
+    }
+}
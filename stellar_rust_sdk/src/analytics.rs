@@ -0,0 +1,558 @@
+use crate::datetime::{self, DateTime};
+use crate::models::serde_adapters::parse_stroops;
+use crate::order_book::prelude::{DetailsResponse, PriceR};
+use crate::trades::prelude::TradeResponse;
+use derive_getters::Getters;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Calculates the volume-weighted average price (VWAP) of the trades within a rolling window.
+///
+/// The price of a trade is the amount of the counter asset paid per unit of the base asset, so the
+/// VWAP is the total counter volume divided by the total base volume of the trades in the window.
+/// The window is based on the ledger close time of the trades rather than on the time at which they
+/// are received, so replaying historical trades yields the same results as following them live.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::analytics::RollingVwap;
+/// # use std::time::Duration;
+/// let mut vwap = RollingVwap::new(Duration::from_secs(5 * 60));
+/// assert_eq!(vwap.vwap(), None);
+///
+/// // Push every trade as it arrives:
+/// // let current = vwap.push(&trade);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct RollingVwap {
+    window: i64,
+    trades: VecDeque<(DateTime, i64, i64)>,
+    base_volume: i128,
+    counter_volume: i128,
+}
+
+impl RollingVwap {
+    /// Creates a new `RollingVwap` covering the given window.
+    ///
+    /// # Arguments
+    /// * `window` - The period, counted back from the most recent trade, of which the trades are
+    ///   included in the VWAP.
+    ///
+    pub fn new(window: Duration) -> Self {
+        RollingVwap {
+            window: i64::try_from(window.as_millis()).unwrap_or(i64::MAX),
+            trades: VecDeque::new(),
+            base_volume: 0,
+            counter_volume: 0,
+        }
+    }
+
+    /// Adds a trade to the window, evicts the trades that fall outside of it, and returns the
+    /// updated VWAP.
+    ///
+    pub fn push(&mut self, trade: &TradeResponse) -> Option<f64> {
+        let close_time = *trade.ledger_close_time();
+        let position = self
+            .trades
+            .iter()
+            .rposition(|(time, _, _)| *time <= close_time)
+            .map_or(0, |position| position + 1);
+        self.trades.insert(
+            position,
+            (close_time, *trade.base_amount(), *trade.counter_amount()),
+        );
+        self.base_volume += *trade.base_amount() as i128;
+        self.counter_volume += *trade.counter_amount() as i128;
+
+        let cutoff = self
+            .trades
+            .back()
+            .and_then(|(latest, _, _)| datetime::unix_millis(latest).checked_sub(self.window));
+        if let Some(cutoff) = cutoff {
+            while let Some((time, base_amount, counter_amount)) = self.trades.front() {
+                if datetime::unix_millis(time) > cutoff {
+                    break;
+                }
+                self.base_volume -= *base_amount as i128;
+                self.counter_volume -= *counter_amount as i128;
+                self.trades.pop_front();
+            }
+        }
+
+        self.vwap()
+    }
+
+    /// Returns the VWAP of the trades in the window, or `None` if the window contains no volume.
+    pub fn vwap(&self) -> Option<f64> {
+        ratio(self.counter_volume, self.base_volume)
+    }
+
+    /// Returns the number of trades in the window.
+    pub fn len(&self) -> usize {
+        self.trades.len()
+    }
+
+    /// Returns `true` if the window contains no trades.
+    pub fn is_empty(&self) -> bool {
+        self.trades.is_empty()
+    }
+}
+
+/// Represents the aggregated trades of a single interval of a [`TradeTape`].
+///
+/// Volumes are expressed in stroops, like the amounts of a [`TradeResponse`]. Prices are the
+/// amount of the counter asset paid per unit of the base asset.
+///
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct TapeInterval {
+    /// The start of the interval, inclusive.
+    start: DateTime,
+    /// The end of the interval, exclusive.
+    end: DateTime,
+    /// The number of trades in the interval.
+    trade_count: u64,
+    /// The total amount of the base asset traded in the interval.
+    base_volume: i64,
+    /// The total amount of the counter asset traded in the interval.
+    counter_volume: i64,
+    /// The price of the first trade in the interval.
+    open: f64,
+    /// The highest price in the interval.
+    high: f64,
+    /// The lowest price in the interval.
+    low: f64,
+    /// The price of the last trade in the interval.
+    close: f64,
+}
+
+impl TapeInterval {
+    /// Returns the VWAP of the trades in the interval, or `None` if the interval has no volume.
+    pub fn vwap(&self) -> Option<f64> {
+        ratio(self.counter_volume as i128, self.base_volume as i128)
+    }
+
+    fn add(&mut self, base_amount: i64, counter_amount: i64, price: f64) {
+        self.trade_count += 1;
+        self.base_volume = self.base_volume.saturating_add(base_amount);
+        self.counter_volume = self.counter_volume.saturating_add(counter_amount);
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+    }
+}
+
+/// Aggregates trades into a tape of fixed intervals.
+///
+/// Intervals are aligned to the Unix epoch, so an interval of one minute always starts at the
+/// beginning of a minute. An interval is completed as soon as a trade of a later interval is
+/// pushed. Intervals without trades are skipped. Trades that arrive after their interval has been
+/// completed are added to the current interval instead.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::analytics::TradeTape;
+/// # use std::time::Duration;
+/// let mut tape = TradeTape::new(Duration::from_secs(60)).unwrap();
+///
+/// // Push every trade as it arrives, and handle the completed intervals:
+/// // if let Some(interval) = tape.push(&trade) { ... }
+///
+/// // Retrieve the last, incomplete interval when done.
+/// assert!(tape.flush().is_none());
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct TradeTape {
+    interval: i64,
+    current: Option<TapeInterval>,
+}
+
+impl TradeTape {
+    /// Creates a new `TradeTape` with the given interval length.
+    ///
+    /// # Arguments
+    /// * `interval` - The length of the intervals. Must be at least one millisecond.
+    ///
+    pub fn new(interval: Duration) -> Result<Self, String> {
+        let interval =
+            i64::try_from(interval.as_millis()).map_err(|_| "Interval is too long".to_string())?;
+        if interval < 1 {
+            return Err("Interval must be at least one millisecond".to_string());
+        }
+
+        Ok(TradeTape {
+            interval,
+            current: None,
+        })
+    }
+
+    /// Adds a trade to the tape, and returns the previous interval if the trade completes it.
+    pub fn push(&mut self, trade: &TradeResponse) -> Option<TapeInterval> {
+        let base_amount = *trade.base_amount();
+        let counter_amount = *trade.counter_amount();
+        let price = ratio(counter_amount as i128, base_amount as i128)?;
+
+        let timestamp = datetime::unix_millis(trade.ledger_close_time());
+        let start = timestamp - timestamp.rem_euclid(self.interval);
+
+        match &mut self.current {
+            Some(current) if start <= datetime::unix_millis(&current.start) => {
+                current.add(base_amount, counter_amount, price);
+                None
+            }
+            _ => self.current.replace(TapeInterval {
+                start: datetime::from_unix_millis(start)?,
+                end: datetime::from_unix_millis(start.checked_add(self.interval)?)?,
+                trade_count: 1,
+                base_volume: base_amount,
+                counter_volume: counter_amount,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+            }),
+        }
+    }
+
+    /// Returns the current interval, which is not completed yet, and clears the tape.
+    pub fn flush(&mut self) -> Option<TapeInterval> {
+        self.current.take()
+    }
+}
+
+/// Calculates the imbalance between the bids and asks of an order book.
+///
+/// The imbalance is `(bids - asks) / (bids + asks)`, where both sides are measured in the base
+/// asset. It ranges from `-1.0`, when there are only asks, to `1.0`, when there are only bids.
+///
+/// # Arguments
+/// * `order_book` - The [`DetailsResponse`] of the order book.
+/// * `depth` - The number of price levels on each side to take into account.
+///
+/// # Returns
+///
+/// The imbalance ratio, or `None` if the selected price levels hold no volume or contain an amount
+/// that cannot be parsed.
+///
+pub fn order_book_imbalance(order_book: &DetailsResponse, depth: usize) -> Option<f64> {
+    // Bid amounts are denominated in the counter asset, so convert them using the price.
+    let bids = order_book
+        .bids
+        .iter()
+        .take(depth)
+        .map(|bid| {
+            let amount = parse_stroops(&bid.amount).ok()? as f64;
+            let price = price(&bid.price_ratio)?;
+            Some(amount / price)
+        })
+        .sum::<Option<f64>>()?;
+    let asks = order_book
+        .asks
+        .iter()
+        .take(depth)
+        .map(|ask| parse_stroops(&ask.amount).ok().map(|amount| amount as f64))
+        .sum::<Option<f64>>()?;
+
+    let total = bids + asks;
+    if total > 0.0 {
+        Some((bids - asks) / total)
+    } else {
+        None
+    }
+}
+
+/// Derives a stream of rolling VWAPs from a stream of trades.
+///
+/// Every trade yields the VWAP of the window after adding it, as calculated by [`RollingVwap`].
+/// Errors are passed through unchanged.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::analytics::vwap_stream;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::trades::prelude::AllTradesRequest;
+/// # use futures::StreamExt;
+/// # use std::time::Duration;
+/// #
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// let trades = horizon_client.stream_trades(&AllTradesRequest::new());
+/// let vwaps = vwap_stream(trades, Duration::from_secs(15 * 60));
+/// futures::pin_mut!(vwaps);
+///
+/// while let Some(vwap) = vwaps.next().await {
+///     println!("VWAP: {}", vwap?);
+/// #   break;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn vwap_stream<S, E>(trades: S, window: Duration) -> impl Stream<Item = Result<f64, E>>
+where
+    S: Stream<Item = Result<TradeResponse, E>>,
+{
+    trades
+        .scan(RollingVwap::new(window), |vwap, trade| {
+            future::ready(Some(trade.map(|trade| vwap.push(&trade))))
+        })
+        .filter_map(|vwap| future::ready(vwap.transpose()))
+}
+
+/// Derives a stream of trade tape intervals from a stream of trades.
+///
+/// Every interval is yielded as soon as it is completed, as described for [`TradeTape`]. When the
+/// stream of trades ends, the last, incomplete interval is yielded as well. Errors are passed
+/// through unchanged.
+///
+/// # Arguments
+/// * `trades` - The stream of trades to aggregate.
+/// * `interval` - The length of the intervals. Must be at least one millisecond.
+///
+pub fn tape_stream<S, E>(
+    trades: S,
+    interval: Duration,
+) -> Result<impl Stream<Item = Result<TapeInterval, E>>, String>
+where
+    S: Stream<Item = Result<TradeResponse, E>>,
+{
+    let tape = TradeTape::new(interval)?;
+
+    Ok(stream::unfold(
+        (Box::pin(trades), Some(tape)),
+        |(mut trades, mut tape)| async move {
+            loop {
+                let current = tape.as_mut()?;
+                match trades.next().await {
+                    Some(Ok(trade)) => {
+                        if let Some(interval) = current.push(&trade) {
+                            return Some((Ok(interval), (trades, tape)));
+                        }
+                    }
+                    Some(Err(e)) => return Some((Err(e), (trades, tape))),
+                    None => {
+                        let interval = current.flush();
+                        return interval.map(|interval| (Ok(interval), (trades, None)));
+                    }
+                }
+            }
+        },
+    ))
+}
+
+/// Derives a stream of order book imbalance ratios from a stream of order books.
+///
+/// Every order book yields its imbalance, as calculated by [`order_book_imbalance`]. Order books
+/// without volume in the selected price levels are skipped. Errors are passed through unchanged.
+///
+/// # Arguments
+/// * `order_books` - The stream of order book snapshots.
+/// * `depth` - The number of price levels on each side to take into account.
+///
+pub fn imbalance_stream<S, E>(order_books: S, depth: usize) -> impl Stream<Item = Result<f64, E>>
+where
+    S: Stream<Item = Result<DetailsResponse, E>>,
+{
+    order_books.filter_map(move |order_book| {
+        future::ready(match order_book {
+            Ok(order_book) => order_book_imbalance(&order_book, depth).map(Ok),
+            Err(e) => Some(Err(e)),
+        })
+    })
+}
+
+/// Divides two volumes, returning `None` if the denominator is not positive.
+fn ratio(numerator: i128, denominator: i128) -> Option<f64> {
+    if denominator > 0 {
+        Some(numerator as f64 / denominator as f64)
+    } else {
+        None
+    }
+}
+
+/// Converts the price of a price level into a decimal, returning `None` if it is not positive.
+fn price(price: &PriceR) -> Option<f64> {
+    ratio(*price.numenator() as i128, *price.denominator() as i128).filter(|price| *price > 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_book::prelude::{Ask, Bid};
+
+    fn trade(ledger_close_time: &str, base_amount: &str, counter_amount: &str) -> TradeResponse {
+        serde_json::from_str(&format!(
+            r#"{{
+                "_links": {{
+                    "self": {{"href": ""}},
+                    "base": {{"href": ""}},
+                    "counter": {{"href": ""}},
+                    "operation": {{"href": ""}}
+                }},
+                "id": "23944442687489-0",
+                "paging_token": "23944442687489-0",
+                "ledger_close_time": "{}",
+                "trade_type": "orderbook",
+                "base_amount": "{}",
+                "base_asset_type": "native",
+                "counter_amount": "{}",
+                "counter_asset_type": "native",
+                "base_is_seller": true
+            }}"#,
+            ledger_close_time, base_amount, counter_amount
+        ))
+        .unwrap()
+    }
+
+    fn order_book(bids: &[(&str, u32, u32)], asks: &[&str]) -> DetailsResponse {
+        DetailsResponse {
+            bids: bids
+                .iter()
+                .map(|(amount, n, d)| Bid {
+                    price_ratio: serde_json::from_str(&format!(r#"{{"n": {}, "d": {}}}"#, n, d))
+                        .unwrap(),
+                    amount: amount.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            asks: asks
+                .iter()
+                .map(|amount| Ask {
+                    amount: amount.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rolling_vwap() {
+        let mut vwap = RollingVwap::new(Duration::from_secs(60));
+        assert_eq!(vwap.vwap(), None);
+
+        assert_eq!(
+            vwap.push(&trade("2024-06-12T05:00:00Z", "10.0000000", "20.0000000")),
+            Some(2.0)
+        );
+        assert_eq!(
+            vwap.push(&trade("2024-06-12T05:00:30Z", "30.0000000", "120.0000000")),
+            Some(3.5)
+        );
+        // The first trade falls outside of the window.
+        assert_eq!(
+            vwap.push(&trade("2024-06-12T05:01:00Z", "10.0000000", "10.0000000")),
+            Some(3.25)
+        );
+        assert_eq!(vwap.len(), 2);
+    }
+
+    #[test]
+    fn test_trade_tape() {
+        assert!(TradeTape::new(Duration::ZERO).is_err());
+
+        let mut tape = TradeTape::new(Duration::from_secs(60)).unwrap();
+        assert_eq!(
+            tape.push(&trade("2024-06-12T05:00:10Z", "10.0000000", "20.0000000")),
+            None
+        );
+        assert_eq!(
+            tape.push(&trade("2024-06-12T05:00:40Z", "10.0000000", "40.0000000")),
+            None
+        );
+        assert_eq!(
+            tape.push(&trade("2024-06-12T05:00:50Z", "20.0000000", "30.0000000")),
+            None
+        );
+
+        let interval = tape
+            .push(&trade("2024-06-12T05:03:00Z", "1.0000000", "1.0000000"))
+            .unwrap();
+        assert_eq!(
+            *interval.start(),
+            datetime::parse_rfc3339("2024-06-12T05:00:00Z").unwrap()
+        );
+        assert_eq!(
+            *interval.end(),
+            datetime::parse_rfc3339("2024-06-12T05:01:00Z").unwrap()
+        );
+        assert_eq!(interval.trade_count(), &3);
+        assert_eq!(interval.base_volume(), &400_000_000);
+        assert_eq!(interval.counter_volume(), &900_000_000);
+        assert_eq!(interval.open(), &2.0);
+        assert_eq!(interval.high(), &4.0);
+        assert_eq!(interval.low(), &1.5);
+        assert_eq!(interval.close(), &1.5);
+        assert_eq!(interval.vwap(), Some(2.25));
+
+        let interval = tape.flush().unwrap();
+        assert_eq!(
+            *interval.start(),
+            datetime::parse_rfc3339("2024-06-12T05:03:00Z").unwrap()
+        );
+        assert_eq!(interval.trade_count(), &1);
+        assert!(tape.flush().is_none());
+    }
+
+    #[test]
+    fn test_order_book_imbalance() {
+        // 30 counter at a price of 3 buys 10 base, and 20 counter at a price of 1/2 buys 40 base.
+        let book = order_book(
+            &[("30.0000000", 3, 1), ("20.0000000", 1, 2)],
+            &["10.0000000", "40.0000000"],
+        );
+        assert_eq!(order_book_imbalance(&book, 1), Some(0.0));
+        assert_eq!(order_book_imbalance(&book, 2), Some(0.0));
+
+        let book = order_book(&[("30.0000000", 3, 1)], &[]);
+        assert_eq!(order_book_imbalance(&book, 10), Some(1.0));
+
+        let book = order_book(&[], &["10.0000000", "invalid"]);
+        assert_eq!(order_book_imbalance(&book, 1), Some(-1.0));
+        assert_eq!(order_book_imbalance(&book, 2), None);
+        assert_eq!(order_book_imbalance(&DetailsResponse::default(), 10), None);
+    }
+
+    #[tokio::test]
+    async fn test_derived_streams() {
+        let trades = vec![
+            Ok(trade("2024-06-12T05:00:10Z", "10.0000000", "20.0000000")),
+            Err("connection reset".to_string()),
+            Ok(trade("2024-06-12T05:01:10Z", "10.0000000", "40.0000000")),
+        ];
+
+        let vwaps: Vec<_> = vwap_stream(stream::iter(trades.clone()), Duration::from_secs(300))
+            .collect()
+            .await;
+        assert_eq!(
+            vwaps,
+            vec![Ok(2.0), Err("connection reset".to_string()), Ok(3.0)]
+        );
+
+        let intervals: Vec<_> = tape_stream(stream::iter(trades), Duration::from_secs(60))
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(intervals.len(), 3);
+        // The first interval is only completed by the trade following the error.
+        assert!(intervals[0].is_err());
+        assert_eq!(intervals[1].as_ref().unwrap().close(), &2.0);
+        assert_eq!(intervals[2].as_ref().unwrap().close(), &4.0);
+
+        let order_books = vec![
+            Ok(order_book(&[], &["10.0000000"])),
+            Ok(DetailsResponse::default()),
+            Err("connection reset".to_string()),
+        ];
+        let imbalances: Vec<_> = imbalance_stream(stream::iter(order_books), 5)
+            .collect()
+            .await;
+        assert_eq!(
+            imbalances,
+            vec![Ok(-1.0), Err("connection reset".to_string())]
+        );
+    }
+}
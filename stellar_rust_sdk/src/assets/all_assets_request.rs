@@ -1,5 +1,8 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::*;
+use std::str::FromStr;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
+use stellar_xdr::curr::AccountId;
 
 /// Represents a request for listing all assets in the Stellar Horizon API.
 ///
@@ -36,8 +39,19 @@ use stellar_rust_sdk_derive::pagination;
 ///
 /// ```
 ///
+/// Both filters can also be set at once from an issued asset:
+/// ```
+/// # use stellar_rs::assets::prelude::AllAssetsRequest;
+/// # use stellar_rs::models::*;
+/// let asset = Asset::new()
+///     .set_issued("USDC", "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5")
+///     .unwrap();
+/// let request = AllAssetsRequest::for_asset(&asset).unwrap();
+/// ```
+///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::ASSET_PATH)]
 pub struct AllAssetsRequest {
     /// The code of the asset to filter by. This is typically the identifier
     ///   assigned to custom assets on the Stellar network.
@@ -45,32 +59,9 @@ pub struct AllAssetsRequest {
     /// The Stellar address of the issuer for the asset you want to filter by.
     ///   It is relevant for assets that are custom issued on the Stellar network.
     asset_issuer: Option<String>,
-}
 
-impl Request for AllAssetsRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.asset_code
-                .as_ref()
-                .map(|ac| format!("asset_code={}", ac)),
-            self.asset_issuer
-                .as_ref()
-                .map(|ac| format!("asset_issuer={}", ac)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::ASSET_PATH,
-            self.get_query_parameters()
-        )
-    }
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllAssetsRequest {
@@ -79,6 +70,22 @@ impl AllAssetsRequest {
         AllAssetsRequest::default()
     }
 
+    /// Creates a new `AllAssetsRequest` that filters on both the code and the issuer of an asset.
+    ///
+    /// # Arguments
+    /// * `asset` - The issued asset to filter by.
+    ///
+    pub fn for_asset(asset: &Asset<IssuedAsset>) -> Result<AllAssetsRequest, String> {
+        let asset = asset.to_string();
+        let (asset_code, asset_issuer) = asset
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid issued asset: {}", asset))?;
+
+        AllAssetsRequest::new()
+            .set_asset_code(asset_code)?
+            .set_asset_issuer(asset_issuer)
+    }
+
     /// Sets the asset code filter for the `AllAssetsRequest`.
     ///
     /// This method specifies the code of the asset to filter by in the assets query. The asset code
@@ -86,13 +93,18 @@ impl AllAssetsRequest {
     ///
     /// # Arguments
     /// * `asset_code` - A string slice representing the asset code. The asset code must be 12 characters
-    ///   or fewer in length. It typically corresponds to custom asset identifiers on the Stellar network.
+    ///   or fewer in length, and consist of alphanumeric characters only. It typically corresponds to
+    ///   custom asset identifiers on the Stellar network.
     ///
     pub fn set_asset_code(self, asset_code: &str) -> Result<AllAssetsRequest, String> {
         if asset_code.len() > 12 {
             return Err("asset_code must be 12 characters or less".to_string());
         }
 
+        if asset_code.is_empty() || !asset_code.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err("asset_code must consist of alphanumeric characters".to_string());
+        }
+
         Ok(AllAssetsRequest {
             asset_code: Some(asset_code.to_string()),
             ..self
@@ -105,14 +117,18 @@ impl AllAssetsRequest {
     ///
     /// # Arguments
     /// * `asset_issuer` - A string slice representing the Stellar address of the asset issuer.
-    ///   The address must be exactly 56 characters long, conforming to the standard Stellar public
-    ///   key format.
+    ///   The address must be exactly 56 characters long, and be a valid Stellar public key,
+    ///   including its checksum.
     ///
     pub fn set_asset_issuer(self, asset_issuer: &str) -> Result<AllAssetsRequest, String> {
         if asset_issuer.len() != 56 {
             return Err("asset_issuer must be 56 characters".to_string());
         }
 
+        if AccountId::from_str(asset_issuer).is_err() {
+            return Err("asset_issuer must be a valid public key".to_string());
+        }
+
         Ok(AllAssetsRequest {
             asset_issuer: Some(asset_issuer.to_string()),
             ..self
@@ -124,6 +140,8 @@ impl AllAssetsRequest {
 mod tests {
     use super::*;
 
+    const ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
     #[test]
     fn test_set_asset_code_valid() {
         let request = AllAssetsRequest::new().set_asset_code("XLM").unwrap();
@@ -139,15 +157,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_asset_code_not_alphanumeric() {
+        for asset_code in ["", "US$"] {
+            let request = AllAssetsRequest::new().set_asset_code(asset_code);
+            assert_eq!(
+                request.err().unwrap(),
+                "asset_code must consist of alphanumeric characters"
+            );
+        }
+    }
+
     #[test]
     fn test_set_asset_issuer_valid() {
-        let request = AllAssetsRequest::new()
-            .set_asset_issuer("Baseflow_TechnologyInnovationAndSoftwareDevelopment_2023")
-            .unwrap();
-        assert_eq!(
-            request.asset_issuer.unwrap(),
-            "Baseflow_TechnologyInnovationAndSoftwareDevelopment_2023"
-        );
+        let request = AllAssetsRequest::new().set_asset_issuer(ISSUER).unwrap();
+        assert_eq!(request.asset_issuer.unwrap(), ISSUER);
     }
 
     #[test]
@@ -158,12 +182,49 @@ mod tests {
             request.err().unwrap(),
             "asset_issuer must be 56 characters".to_string()
         );
+
+        let request = AllAssetsRequest::new()
+            .set_asset_issuer("Baseflow_TechnologyInnovationAndSoftwareDevelopment_2023");
+        assert_eq!(
+            request.err().unwrap(),
+            "asset_issuer must be a valid public key"
+        );
+    }
+
+    #[test]
+    fn test_for_asset() {
+        let asset = Asset::new().set_issued("USDC", ISSUER).unwrap();
+        let request = AllAssetsRequest::for_asset(&asset).unwrap();
+        assert_eq!(
+            request.get_query_parameters(),
+            format!("?asset_code=USDC&asset_issuer={}", ISSUER)
+        );
+    }
+
+    #[test]
+    fn test_query_parameters_combined() {
+        let request = AllAssetsRequest::new()
+            .set_asset_code("USDC")
+            .unwrap()
+            .set_asset_issuer(ISSUER)
+            .unwrap()
+            .set_limit(20)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            format!(
+                "https://horizon-testnet.stellar.org/assets?asset_code=USDC&asset_issuer={}&limit=20&order=desc",
+                ISSUER
+            )
+        );
     }
 
     #[test]
     fn test_set_cursor_valid() {
         let request = AllAssetsRequest::new().set_cursor(12345).unwrap();
-        assert_eq!(request.cursor.unwrap(), 12345);
+        assert_eq!(request.page_params.cursor().unwrap(), 12345);
     }
 
     #[test]
@@ -178,7 +239,7 @@ mod tests {
     #[test]
     fn test_set_limit_valid() {
         let request = AllAssetsRequest::new().set_limit(20).unwrap();
-        assert_eq!(request.limit.unwrap(), 20);
+        assert_eq!(request.page_params.limit().unwrap(), 20);
     }
 
     #[test]
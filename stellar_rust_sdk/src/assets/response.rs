@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// asset records, providing a comprehensive view of the assets available on the Stellar network.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct AllAssetsResponse {
     /// A `Links` struct containing navigational links. These links are used for
     ///   pagination purposes, allowing access to the current, next, and previous pages of the asset list.
@@ -28,6 +29,7 @@ pub struct AllAssetsResponse {
 /// resources or additional information. It is a component of the [`Links`] struct within the [`AllAssetsResponse`].
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct AssetTomlLink {
     /// The URL of the linked resource. This field is used to provide direct access to relevant resources
     ///     or additional data related to an asset.
@@ -43,6 +45,7 @@ pub struct AssetTomlLink {
 /// and various other statistics and flags.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Asset {
     /// Links related to the asset, including a link to the asset's TOML file.
     _links: AssetTomlLink,
@@ -55,13 +58,19 @@ pub struct Asset {
     asset_issuer: String,
     /// A token used for paging through results.
     paging_token: String,
+    /// The ID of the Stellar Asset Contract of the asset, if it has been deployed.
+    contract_id: Option<String>,
     /// The number of accounts holding the asset.
     num_accounts: u32,
     /// The number of claimable balances for the asset.
     num_claimable_balances: u32,
-    /// The number of liquidity pools that include the asset.
+    /// The number of liquidity pools that include the asset. Introduced in protocol 18, so it
+    ///   defaults to `0` on older networks.
+    #[serde(default)]
     num_liquidity_pools: u32,
-    /// The number of contracts involving the asset.
+    /// The number of contracts involving the asset. Introduced in protocol 20, so it defaults to
+    ///   `0` on older networks.
+    #[serde(default)]
     num_contracts: u32,
     /// The total amount of the asset.
     amount: String,
@@ -70,9 +79,13 @@ pub struct Asset {
     accounts: AccountInfo,
     /// The total amount of the asset in claimable balances.
     claimable_balances_amount: String,
-    /// The total amount of the asset in liquidity pools.
+    /// The total amount of the asset in liquidity pools. Introduced in protocol 18, so it
+    ///   defaults to an empty string on older networks.
+    #[serde(default)]
     liquidity_pools_amount: String,
-    /// The total amount of the asset in contracts.
+    /// The total amount of the asset in contracts. Introduced in protocol 20, so it defaults to
+    ///   an empty string on older networks.
+    #[serde(default)]
     contracts_amount: String,
     /// An `AccountBalances` struct detailing the total balances held in authorized,
     ///   authorized to maintain liabilities, and unauthorized accounts.
@@ -87,6 +100,7 @@ pub struct Asset {
 /// categorized by their trustline flag state.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct AccountInfo {
     /// The number of accounts that are authorized to transact with the asset.
     authorized: u32,
@@ -104,6 +118,7 @@ pub struct AccountInfo {
 /// across various account types in the Stellar network.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct AccountBalances {
     /// A `String` representing the total balance of the asset held by accounts that
     ///   are authorized to transact with it.
@@ -118,7 +133,7 @@ pub struct AccountBalances {
 
 impl Response for AllAssetsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
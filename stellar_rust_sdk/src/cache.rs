@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Describes whether and for how long the response of a resource may be cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheDirective {
+    /// The response is never cached.
+    NoStore,
+    /// The response is cached for the given duration.
+    Ttl(Duration),
+    /// The response never changes, and is cached until it is evicted or invalidated.
+    Immutable,
+}
+
+/// The kinds of single resources of which the responses can be cached.
+///
+/// Only single resources are cached, such as `/accounts/{account_id}`; lists, such as
+/// `/accounts?sponsor=...` or `/accounts/{account_id}/operations`, are never cached. The order
+/// book is the exception: it is addressed by query parameters, but is a single resource.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceKind {
+    /// A single ledger, at `/ledgers/{sequence}`.
+    Ledger,
+    /// A single transaction, at `/transactions/{hash}`.
+    Transaction,
+    /// A single operation, at `/operations/{id}`.
+    Operation,
+    /// A single account, at `/accounts/{account_id}`.
+    Account,
+    /// A single offer, at `/offers/{offer_id}`.
+    Offer,
+    /// A single claimable balance, at `/claimable_balances/{id}`.
+    ClaimableBalance,
+    /// A single liquidity pool, at `/liquidity_pools/{id}`.
+    LiquidityPool,
+    /// The order book of an asset pair, at `/order_book`.
+    OrderBook,
+}
+
+impl ResourceKind {
+    /// Returns the kind of single resource addressed by a URL, or `None` if the URL does not
+    /// address a single resource.
+    ///
+    /// # Arguments
+    /// * `base_url` - The base URL of the Horizon server, which is stripped from the URL.
+    /// * `url` - The URL of the request.
+    ///
+    pub fn from_url(base_url: &str, url: &str) -> Option<Self> {
+        let path = url.strip_prefix(base_url).unwrap_or(url);
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        match segments.as_slice() {
+            ["ledgers", _] => Some(ResourceKind::Ledger),
+            ["transactions", _] => Some(ResourceKind::Transaction),
+            ["operations", _] => Some(ResourceKind::Operation),
+            ["accounts", _] => Some(ResourceKind::Account),
+            ["offers", _] => Some(ResourceKind::Offer),
+            ["claimable_balances", _] => Some(ResourceKind::ClaimableBalance),
+            ["liquidity_pools", _] => Some(ResourceKind::LiquidityPool),
+            ["order_book"] => Some(ResourceKind::OrderBook),
+            _ => None,
+        }
+    }
+
+    /// Returns whether a resource of this kind can change once it exists.
+    ///
+    /// Closed ledgers and the transactions and operations in them are historical, and never
+    /// change. Accounts, offers, claimable balances, liquidity pools and order books change with
+    /// every transaction that affects them.
+    pub fn is_mutable(&self) -> bool {
+        !matches!(
+            self,
+            ResourceKind::Ledger | ResourceKind::Transaction | ResourceKind::Operation
+        )
+    }
+}
+
+/// The cache directives of the kinds of single resources.
+///
+/// By default, historical resources (ledgers, transactions and operations) are
+/// [`Immutable`](CacheDirective::Immutable), the order book is cached for one second, and the
+/// other resources, such as accounts, for five seconds. Every directive can be overridden with
+/// [`CachePolicy::set_directive`].
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::cache::{CacheDirective, CachePolicy, ResourceKind};
+/// # use std::time::Duration;
+/// // Never cache accounts, and cache order books for half a second.
+/// let cache_policy = CachePolicy::default()
+///     .set_directive(ResourceKind::Account, CacheDirective::NoStore)
+///     .set_directive(
+///         ResourceKind::OrderBook,
+///         CacheDirective::Ttl(Duration::from_millis(500)),
+///     );
+/// assert_eq!(
+///     cache_policy.directive(ResourceKind::Ledger),
+///     CacheDirective::Immutable
+/// );
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// The directives that override the default directive of a kind of resource.
+    directives: HashMap<ResourceKind, CacheDirective>,
+}
+
+impl CachePolicy {
+    /// Sets the cache directive of a kind of resource.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of resource.
+    /// * `directive` - The [`CacheDirective`] of the responses of resources of that kind.
+    ///
+    pub fn set_directive(mut self, kind: ResourceKind, directive: CacheDirective) -> Self {
+        self.directives.insert(kind, directive);
+        self
+    }
+
+    /// Returns the cache directive of a kind of resource.
+    pub fn directive(&self, kind: ResourceKind) -> CacheDirective {
+        if let Some(directive) = self.directives.get(&kind) {
+            return *directive;
+        }
+
+        match kind {
+            ResourceKind::Ledger | ResourceKind::Transaction | ResourceKind::Operation => {
+                CacheDirective::Immutable
+            }
+            ResourceKind::OrderBook => CacheDirective::Ttl(Duration::from_secs(1)),
+            _ => CacheDirective::Ttl(Duration::from_secs(5)),
+        }
+    }
+
+    /// Returns the cache directive of the resource addressed by a URL. URLs that do not address a
+    /// single resource are never cached.
+    pub(crate) fn directive_for_url(&self, base_url: &str, url: &str) -> CacheDirective {
+        ResourceKind::from_url(base_url, url)
+            .map_or(CacheDirective::NoStore, |kind| self.directive(kind))
+    }
+}
+
+/// A cache of the bodies of responses to `GET` requests, by URL.
+///
+/// Once a cache is attached to the [`HorizonClient`](crate::horizon_client::HorizonClient) with
+/// [`set_cache`](crate::horizon_client::HorizonClient::set_cache), the bodies of successful
+/// responses for single resources are stored in it, as directed by the [`CachePolicy`] of the
+/// client, and requests for URLs that are in the cache are not sent to the Horizon server.
+///
+/// With the `moka` feature, the crate provides `MokaCache`, an implementation backed by a
+/// concurrent, size-bounded [moka](https://docs.rs/moka) cache. Applications that share a cache
+/// between processes, for example in Redis, can implement this trait themselves.
+///
+pub trait ResponseCache: Send + Sync {
+    /// Returns the cached body of the response for a URL, if present and not expired.
+    fn get(&self, url: &str) -> Option<String>;
+
+    /// Stores the body of the response for a URL, as directed by the given directive. The
+    /// directive is never [`NoStore`](CacheDirective::NoStore).
+    fn insert(&self, url: &str, body: String, directive: CacheDirective);
+
+    /// Removes the cached body of the response for a URL, if present.
+    fn invalidate(&self, url: &str);
+
+    /// Removes the cached bodies of the responses for all URLs that match the predicate.
+    fn invalidate_matching(&self, predicate: &dyn Fn(&str) -> bool);
+
+    /// Removes all cached bodies.
+    fn clear(&self) {
+        self.invalidate_matching(&|_| true);
+    }
+}
+
+/// A [`ResponseCache`] backed by a concurrent, size-bounded [moka](https://docs.rs/moka) cache.
+///
+/// Entries expire as directed by their [`CacheDirective`]; immutable entries only leave the cache
+/// when it is full, or when they are invalidated.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::cache::MokaCache;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// // Cache up to 10.000 responses.
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+///     .expect("Failed to create HorizonClient")
+///     .set_cache(MokaCache::new(10_000));
+/// ```
+///
+#[cfg(feature = "moka")]
+pub struct MokaCache {
+    /// The underlying cache, by URL.
+    cache: moka::sync::Cache<String, MokaEntry>,
+}
+
+/// A cached body, along with the time after which it expires, if any.
+#[cfg(feature = "moka")]
+#[derive(Debug, Clone)]
+struct MokaEntry {
+    body: String,
+    ttl: Option<Duration>,
+}
+
+/// Expires the entries of a [`MokaCache`] after their time to live.
+#[cfg(feature = "moka")]
+struct MokaExpiry;
+
+#[cfg(feature = "moka")]
+impl moka::Expiry<String, MokaEntry> for MokaExpiry {
+    fn expire_after_create(
+        &self,
+        _url: &String,
+        entry: &MokaEntry,
+        _created_at: std::time::Instant,
+    ) -> Option<Duration> {
+        entry.ttl
+    }
+}
+
+#[cfg(feature = "moka")]
+impl MokaCache {
+    /// Creates a new `MokaCache`.
+    ///
+    /// # Arguments
+    /// * `max_capacity` - The maximum number of responses in the cache. Once it is reached, the
+    ///   least recently used responses are evicted.
+    ///
+    pub fn new(max_capacity: u64) -> Self {
+        MokaCache {
+            cache: moka::sync::Cache::builder()
+                .max_capacity(max_capacity)
+                .expire_after(MokaExpiry)
+                .build(),
+        }
+    }
+}
+
+#[cfg(feature = "moka")]
+impl std::fmt::Debug for MokaCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MokaCache")
+            .field("entry_count", &self.cache.entry_count())
+            .finish()
+    }
+}
+
+#[cfg(feature = "moka")]
+impl ResponseCache for MokaCache {
+    fn get(&self, url: &str) -> Option<String> {
+        self.cache.get(url).map(|entry| entry.body)
+    }
+
+    fn insert(&self, url: &str, body: String, directive: CacheDirective) {
+        let ttl = match directive {
+            CacheDirective::NoStore => return,
+            CacheDirective::Ttl(ttl) => Some(ttl),
+            CacheDirective::Immutable => None,
+        };
+        self.cache.insert(url.to_string(), MokaEntry { body, ttl });
+    }
+
+    fn invalidate(&self, url: &str) {
+        self.cache.invalidate(url);
+    }
+
+    fn invalidate_matching(&self, predicate: &dyn Fn(&str) -> bool) {
+        for (url, _) in self.cache.iter() {
+            if predicate(&url) {
+                self.cache.invalidate(url.as_str());
+            }
+        }
+    }
+
+    fn clear(&self) {
+        self.cache.invalidate_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_URL: &str = "https://horizon-testnet.stellar.org";
+
+    #[test]
+    fn test_resource_kind_from_url() {
+        let kind = |path: &str| ResourceKind::from_url(BASE_URL, &format!("{}{}", BASE_URL, path));
+
+        assert_eq!(kind("/ledgers/48483"), Some(ResourceKind::Ledger));
+        assert_eq!(kind("/accounts/GA?join=x"), Some(ResourceKind::Account));
+        assert_eq!(
+            kind("/order_book?selling_asset_type=native"),
+            Some(ResourceKind::OrderBook)
+        );
+        assert_eq!(kind("/ledgers"), None);
+        assert_eq!(kind("/accounts/GA/operations"), None);
+        assert_eq!(kind("/"), None);
+    }
+
+    #[test]
+    fn test_cache_policy() {
+        let cache_policy = CachePolicy::default();
+        assert_eq!(
+            cache_policy.directive(ResourceKind::Transaction),
+            CacheDirective::Immutable
+        );
+        assert_eq!(
+            cache_policy.directive(ResourceKind::Account),
+            CacheDirective::Ttl(Duration::from_secs(5))
+        );
+        assert_eq!(
+            cache_policy.directive_for_url(BASE_URL, &format!("{}/trades", BASE_URL)),
+            CacheDirective::NoStore
+        );
+
+        let cache_policy =
+            cache_policy.set_directive(ResourceKind::Account, CacheDirective::NoStore);
+        assert_eq!(
+            cache_policy.directive(ResourceKind::Account),
+            CacheDirective::NoStore
+        );
+    }
+
+    #[cfg(feature = "moka")]
+    #[test]
+    fn test_moka_cache() {
+        let cache = MokaCache::new(100);
+        cache.insert(
+            "/ledgers/1",
+            "ledger".to_string(),
+            CacheDirective::Immutable,
+        );
+        cache.insert(
+            "/accounts/GA",
+            "account".to_string(),
+            CacheDirective::Ttl(Duration::ZERO),
+        );
+        cache.insert(
+            "/accounts/GB",
+            "account".to_string(),
+            CacheDirective::NoStore,
+        );
+
+        assert_eq!(cache.get("/ledgers/1").as_deref(), Some("ledger"));
+        assert_eq!(cache.get("/accounts/GA"), None);
+        assert_eq!(cache.get("/accounts/GB"), None);
+
+        cache.invalidate_matching(&|url| url.starts_with("/ledgers"));
+        assert_eq!(cache.get("/ledgers/1"), None);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::error::Error;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Represents the state of the circuit of an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests to the endpoint are sent as usual.
+    Closed,
+    /// The endpoint failed too often, and requests to it fail immediately with
+    /// [`Error::CircuitOpen`].
+    Open,
+    /// The circuit has been open for long enough, and the next request to the endpoint is sent as
+    /// a probe. If the probe succeeds, the circuit closes; otherwise it opens again.
+    HalfOpen,
+}
+
+/// The mutable state of the circuit of a single endpoint.
+#[derive(Debug, Default)]
+struct Circuit {
+    /// The number of consecutive requests to the endpoint that failed.
+    consecutive_failures: u32,
+    /// The moment at which the circuit was opened, if it is not closed.
+    opened_at: Option<Instant>,
+    /// The moment at which the probe of a half-open circuit was sent, if any.
+    probe_sent_at: Option<Instant>,
+}
+
+/// A client-side circuit breaker, which keeps a separate circuit for every Horizon endpoint.
+///
+/// When Horizon suffers a partial outage, some endpoints may keep failing while others work fine.
+/// After `failure_threshold` consecutive requests to an endpoint failed, its circuit opens, and
+/// further requests to that endpoint fail immediately with [`Error::CircuitOpen`] instead of
+/// waiting for yet another timeout. After `open_duration`, the circuit becomes half-open, and a
+/// single request is let through as a probe: if it succeeds the circuit closes, otherwise it opens
+/// again. Requests to other endpoints are not affected, so a failing `/trades` endpoint does not
+/// take down the loading of accounts.
+///
+/// A request fails if it could not be sent or received, or if Horizon responded with a `5xx`
+/// status. Other errors, such as a `404 Not Found`, are not caused by an outage and reset the
+/// circuit like a successful response does.
+///
+/// The endpoint of a request is the resource it addresses, such as `/trades` or `/accounts`,
+/// followed by the sub-resource for the resources of a single record, such as `/accounts/trades`.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::circuit_breaker::CircuitBreaker;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use std::time::Duration;
+/// // Open a circuit after 5 consecutive failures, and probe the endpoint again after 30 seconds.
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+///     .expect("Failed to create HorizonClient")
+///     .set_circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(30)).unwrap());
+/// ```
+///
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    /// The number of consecutive failures after which the circuit of an endpoint opens.
+    failure_threshold: u32,
+    /// The duration for which a circuit stays open before a probe is let through.
+    open_duration: Duration,
+    /// The circuits of the endpoints that failed since they last succeeded.
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+impl CircuitBreaker {
+    /// Creates a new `CircuitBreaker`, of which all circuits are closed.
+    ///
+    /// # Arguments
+    /// * `failure_threshold` - The number of consecutive failures after which a circuit opens.
+    /// * `open_duration` - The duration for which a circuit stays open before it is probed.
+    ///
+    /// # Returns
+    /// Returns the `CircuitBreaker`, or an error if the failure threshold is `0`.
+    ///
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Result<Self, String> {
+        if failure_threshold < 1 {
+            return Err("Failure threshold must be greater than or equal to 1.".to_string());
+        }
+
+        Ok(CircuitBreaker {
+            failure_threshold,
+            open_duration,
+            circuits: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Returns the number of consecutive failures after which the circuit of an endpoint opens.
+    pub fn failure_threshold(&self) -> u32 {
+        self.failure_threshold
+    }
+
+    /// Returns the duration for which a circuit stays open before a probe is let through.
+    pub fn open_duration(&self) -> Duration {
+        self.open_duration
+    }
+
+    /// Returns the state of the circuit of the given endpoint.
+    ///
+    /// # Arguments
+    /// * `endpoint` - The endpoint, such as `/trades` or `/accounts/trades`.
+    ///
+    pub fn state(&self, endpoint: &str) -> CircuitState {
+        let circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        match circuits.get(endpoint).and_then(|circuit| circuit.opened_at) {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Checks whether a request may be sent to the given endpoint.
+    ///
+    /// If the circuit is half-open, the request is let through as the probe of the endpoint. While
+    /// the probe is in flight, other requests are rejected; if the probe does not complete within
+    /// the open duration, for example because it was cancelled, another probe is let through.
+    ///
+    pub(crate) fn check(&self, endpoint: &str) -> Result<(), Error> {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(circuit) = circuits.get_mut(endpoint) else {
+            return Ok(());
+        };
+        let Some(opened_at) = circuit.opened_at else {
+            return Ok(());
+        };
+
+        let waiting_since = circuit.probe_sent_at.unwrap_or(opened_at);
+        let elapsed = waiting_since.elapsed();
+        if elapsed < self.open_duration {
+            return Err(Error::CircuitOpen {
+                endpoint: endpoint.to_string(),
+                retry_after: self.open_duration - elapsed,
+            });
+        }
+
+        circuit.probe_sent_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Records the outcome of a request to the given endpoint.
+    pub(crate) fn record<T>(&self, endpoint: &str, result: &Result<T, Error>) {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        if !result.as_ref().is_err_and(is_outage) {
+            circuits.remove(endpoint);
+            return;
+        }
+
+        let circuit = circuits.entry(endpoint.to_string()).or_default();
+        circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+        if circuit.opened_at.is_some() || circuit.consecutive_failures >= self.failure_threshold {
+            circuit.opened_at = Some(Instant::now());
+            circuit.probe_sent_at = None;
+        }
+    }
+}
+
+/// Returns whether an error indicates that an endpoint is unavailable.
+fn is_outage(error: &Error) -> bool {
+    matches!(error, Error::Http(_)) || error.is_server_error()
+}
+
+/// Returns the endpoint of the given URL, relative to the base URL of the Horizon server.
+///
+/// The endpoint consists of the first segment of the path, followed by the third segment if
+/// present, skipping the ID of the record. For example, both `/accounts/{account_id}` and
+/// `/accounts` yield `/accounts`, and `/accounts/{account_id}/trades` yields `/accounts/trades`.
+///
+pub(crate) fn endpoint(base_url: &str, url: &str) -> String {
+    let path = url.strip_prefix(base_url).unwrap_or(url);
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match segments.as_slice() {
+        [] => "/".to_string(),
+        [resource] | [resource, _] => format!("/{}", resource),
+        [resource, _, sub_resource, ..] => format!("/{}/{}", resource, sub_resource),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE_URL: &str = "https://horizon-testnet.stellar.org";
+
+    fn outage() -> Result<(), Error> {
+        Err(Error::Horizon {
+            status: 503,
+            body: "Service Unavailable".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_circuit_breaker_invalid() {
+        assert_eq!(
+            CircuitBreaker::new(0, Duration::from_secs(1)).unwrap_err(),
+            "Failure threshold must be greater than or equal to 1."
+        );
+    }
+
+    #[test]
+    fn test_circuit_opens_after_threshold() {
+        let circuit_breaker = CircuitBreaker::new(2, Duration::from_secs(60)).unwrap();
+
+        circuit_breaker.record("/trades", &outage());
+        assert_eq!(circuit_breaker.state("/trades"), CircuitState::Closed);
+        assert!(circuit_breaker.check("/trades").is_ok());
+
+        circuit_breaker.record("/trades", &outage());
+        assert_eq!(circuit_breaker.state("/trades"), CircuitState::Open);
+        assert!(matches!(
+            circuit_breaker.check("/trades"),
+            Err(Error::CircuitOpen { endpoint, .. }) if endpoint == "/trades"
+        ));
+
+        // Other endpoints are not affected.
+        assert_eq!(circuit_breaker.state("/accounts"), CircuitState::Closed);
+        assert!(circuit_breaker.check("/accounts").is_ok());
+    }
+
+    #[test]
+    fn test_client_errors_reset_circuit() {
+        let circuit_breaker = CircuitBreaker::new(2, Duration::from_secs(60)).unwrap();
+        let not_found: Result<(), Error> = Err(Error::Horizon {
+            status: 404,
+            body: "Not Found".to_string(),
+        });
+
+        circuit_breaker.record("/accounts", &outage());
+        circuit_breaker.record("/accounts", &not_found);
+        circuit_breaker.record("/accounts", &outage());
+        assert_eq!(circuit_breaker.state("/accounts"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_probe() {
+        let circuit_breaker = CircuitBreaker::new(1, Duration::from_millis(20)).unwrap();
+        circuit_breaker.record("/trades", &outage());
+        assert_eq!(circuit_breaker.state("/trades"), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(circuit_breaker.state("/trades"), CircuitState::HalfOpen);
+
+        // Only a single probe is let through.
+        assert!(circuit_breaker.check("/trades").is_ok());
+        assert!(circuit_breaker.check("/trades").is_err());
+
+        // A failing probe opens the circuit again, a successful one closes it.
+        circuit_breaker.record("/trades", &outage());
+        assert_eq!(circuit_breaker.state("/trades"), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(circuit_breaker.check("/trades").is_ok());
+        circuit_breaker.record("/trades", &Ok(()));
+        assert_eq!(circuit_breaker.state("/trades"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_endpoint() {
+        assert_eq!(endpoint(BASE_URL, BASE_URL), "/");
+        assert_eq!(
+            endpoint(BASE_URL, &format!("{}/trades?limit=10", BASE_URL)),
+            "/trades"
+        );
+        assert_eq!(
+            endpoint(BASE_URL, &format!("{}/accounts/GABC", BASE_URL)),
+            "/accounts"
+        );
+        assert_eq!(
+            endpoint(BASE_URL, &format!("{}/accounts/GABC/trades", BASE_URL)),
+            "/accounts/trades"
+        );
+    }
+}
@@ -1,10 +1,13 @@
+use crate::models::pagination::PageParams;
+use crate::models::prelude::{asset_list_parameter, AssetType};
 use crate::{models::*, BuildQueryParametersExt};
 use stellar_rust_sdk_derive::pagination;
 
 /// Represents a request to list all claimable balances from the Stellar Horizon API.
 ///
 /// This structure is used to construct a query to retrieve a comprehensive list of claimable balances, which
-/// can be filtered by sponsor, asset, or claimant. Claimable balances are a feature of the Stellar network
+/// can be filtered by sponsor, asset, or claimant. The filters can be combined, in which case only the
+/// claimable balances that match all of them are returned. Claimable balances are a feature of the Stellar network
 /// that allows users to create a balance of assets that can be claimed by another account. It adheres to the structure and parameters required
 /// by the Horizon API for retrieving a
 /// <a href="https://developers.stellar.org/api/horizon/resources/list-all-claimable-balances">list of claimable balances</a>.
@@ -21,48 +24,55 @@ use stellar_rust_sdk_derive::pagination;
 /// # use stellar_rs::models::{Asset, Order, IssuedAsset};
 ///
 /// let request = AllClaimableBalancesRequest::new()
-///     .set_sponsor("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7").unwrap() // Optional sponsor filter
-///     .set_asset(Asset::new().set_issued("USD", "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7").unwrap()) // Optional asset filter
-///     .set_claimant("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7").unwrap() // Optional claimant filter
-///     .set_cursor(123).unwrap() // Optional cursor for pagination
-///     .set_limit(100).unwrap() // Optional limit for response records
-///     .set_order(Order::Desc); // Optional order of records
+///     .set_sponsor("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")? // Optional sponsor filter
+///     .set_asset(Asset::new().set_issued("USD", "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?) // Optional asset filter
+///     .set_claimant("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")? // Optional claimant filter
+///     .set_cursor(123)? // Optional cursor for pagination
+///     .set_limit(100)? // Optional limit for response records
+///     .set_order(Order::Desc)?; // Optional order of records
 ///
+/// assert_eq!(request.validate(), Ok(()));
 /// // Use with HorizonClient::get_all_claimable_balances
+/// # Ok::<(), String>(())
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash)]
 pub struct AllClaimableBalancesRequest {
     /// Optional. Representing the account ID of the sponsor. When set, the response will
     ///   only include claimable balances sponsored by the specified account.
     sponsor: Option<String>,
 
-    /// Optional. Indicates issued asset for which claimable balances are being queried.
+    /// Optional. Indicates the asset for which claimable balances are being queried.
     ///   When set, the response will filter claimable balances that hold this specific asset.
-    asset: Option<Asset<IssuedAsset>>,
+    asset: Option<AssetType>,
 
     /// Optional. Represents the account ID of the claimant. If provided, the response will
     ///   include only claimable balances that are claimable by the specified account.
     claimant: Option<String>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl Request for AllClaimableBalancesRequest {
     fn get_query_parameters(&self) -> String {
-        vec![
+        [
             self.sponsor.as_ref().map(|s| format!("sponsor={}", s)),
-            self.asset.as_ref().map(|a| format!("asset={}", a)),
+            self.asset
+                .as_ref()
+                .map(|a| asset_list_parameter("asset", std::slice::from_ref(a))),
             self.claimant.as_ref().map(|c| format!("claimant={}", c)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
+            self.page_params.cursor().map(|c| format!("cursor={}", c)),
+            self.page_params.limit().map(|l| format!("limit={}", l)),
+            self.page_params.order().map(|o| format!("order={}", o)),
         ]
         .build_query_parameters()
     }
 
     fn build_url(&self, base_url: &str) -> String {
         format!(
-            "{}/{}/{}",
+            "{}/{}{}",
             base_url,
             super::CLAIMABLE_BALANCES_PATH,
             self.get_query_parameters()
@@ -80,9 +90,13 @@ impl AllClaimableBalancesRequest {
     ///
     /// # Arguments
     /// * `sponsor` - A Stellar public key of the sponsor whose claimable balances are to be retrieved.
+    ///   A muxed account (`M...`) is resolved to its underlying account.
     ///
-    pub fn set_sponsor(self, sponsor: impl Into<String>) -> Result<AllClaimableBalancesRequest, String> {
-        let sponsor = sponsor.into();
+    pub fn set_sponsor(
+        self,
+        sponsor: impl Into<String>,
+    ) -> Result<AllClaimableBalancesRequest, String> {
+        let sponsor = resolve_account_id(sponsor.into());
         if let Err(e) = is_public_key(&sponsor) {
             return Err(e.to_string());
         }
@@ -95,12 +109,16 @@ impl AllClaimableBalancesRequest {
 
     /// Specifies the asset in the request.
     ///
+    /// The asset is sent in its canonical form, `native` or `CODE:ISSUER`. Its code and issuer
+    /// are checked by [`validate`](Self::validate).
+    ///
     /// # Arguments
-    /// * `asset` - The issued asset to filter claimable balances by asset type.
+    /// * `asset` - The asset to filter claimable balances by, such as an [`AssetType`] or an
+    ///   [`Asset`].
     ///
-    pub fn set_asset(self, asset: Asset<IssuedAsset>) -> AllClaimableBalancesRequest {
+    pub fn set_asset(self, asset: impl Into<AssetType>) -> AllClaimableBalancesRequest {
         AllClaimableBalancesRequest {
-            asset: Some(asset),
+            asset: Some(asset.into()),
             ..self
         }
     }
@@ -109,9 +127,13 @@ impl AllClaimableBalancesRequest {
     ///
     /// # Arguments
     /// * `claimant` - A Stellar public key of the claimant whose claimable balances are to be retrieved.
+    ///   A muxed account (`M...`) is resolved to its underlying account.
     ///
-    pub fn set_claimant(self, claimant: impl Into<String>) -> Result<AllClaimableBalancesRequest, String> {
-        let claimant = claimant.into();
+    pub fn set_claimant(
+        self,
+        claimant: impl Into<String>,
+    ) -> Result<AllClaimableBalancesRequest, String> {
+        let claimant = resolve_account_id(claimant.into());
         if let Err(e) = is_public_key(&claimant) {
             return Err(e.to_string());
         }
@@ -121,18 +143,81 @@ impl AllClaimableBalancesRequest {
             ..self
         })
     }
+
+    /// Validates the filters of the request, and returns every violation instead of only the
+    /// first.
+    ///
+    /// The sponsor and claimant are checked by their setters, but the asset is only checked
+    /// here, including the length and characters of its code and the checksum of its issuer, so
+    /// that an invalid asset is rejected before it is sent to Horizon.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::claimable_balances::prelude::*;
+    /// use stellar_rs::models::prelude::AssetType;
+    ///
+    /// let request = AllClaimableBalancesRequest::new()
+    ///     .set_claimant("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?
+    ///     .set_asset(AssetType::issued(
+    ///         "US-D",
+    ///         "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7",
+    ///     ));
+    ///
+    /// assert_eq!(
+    ///     request.validate().unwrap_err(),
+    ///     vec!["Asset code must consist of 1 to 4 alphanumeric characters: US-D"]
+    /// );
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        collect_violations(self.asset.iter().map(is_valid_asset))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const ACCOUNT_ID: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+    #[test]
+    fn test_combined_filters() {
+        let request = AllClaimableBalancesRequest::new()
+            .set_sponsor(ACCOUNT_ID)
+            .unwrap()
+            .set_claimant("MBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OAAAAAAAAAAAPOGVY")
+            .unwrap()
+            .set_asset(Asset::new().set_issued("USDC", ACCOUNT_ID).unwrap())
+            .set_limit(2)
+            .unwrap();
+
+        assert_eq!(request.validate(), Ok(()));
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            format!(
+                "https://horizon-testnet.stellar.org/claimable_balances?sponsor={0}\
+                 &asset=USDC%3A{0}&claimant={0}&limit=2",
+                ACCOUNT_ID
+            )
+        );
+
+        let request = AllClaimableBalancesRequest::new().set_asset(AssetType::Native);
+        assert_eq!(request.get_query_parameters(), "?asset=native");
+
+        let request = request.set_asset(AssetType::issued("USDC", "GBRPYHIL"));
+        assert_eq!(
+            request.validate().unwrap_err(),
+            vec!["Invalid asset issuer: GBRPYHIL"]
+        );
+    }
+
     #[test]
     fn test_set_cursor_valid() {
         let request = AllClaimableBalancesRequest::new()
             .set_cursor(12345)
             .unwrap();
-        assert_eq!(request.cursor.unwrap(), 12345);
+        assert_eq!(request.page_params.cursor().unwrap(), 12345);
     }
 
     #[test]
@@ -147,7 +232,7 @@ mod tests {
     #[test]
     fn test_set_limit_valid() {
         let request = AllClaimableBalancesRequest::new().set_limit(20).unwrap();
-        assert_eq!(request.limit.unwrap(), 20);
+        assert_eq!(request.page_params.limit().unwrap(), 20);
     }
 
     #[test]
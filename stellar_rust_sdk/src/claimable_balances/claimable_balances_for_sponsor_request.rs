@@ -0,0 +1,133 @@
+use crate::models::pagination::PageParams;
+use crate::{models::*, BuildQueryParametersExt};
+use stellar_rust_sdk_derive::pagination;
+
+/// Represents the account ID of the sponsor of the claimable balances to be retrieved.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct ClaimableBalanceSponsor(String);
+
+/// Represents the absence of the account ID of the sponsor of the claimable balances to be retrieved.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct NoClaimableBalanceSponsor;
+
+/// Represents a request to list the claimable balances sponsored by an account from the Stellar
+/// Horizon API.
+///
+/// This structure is used to construct a query to retrieve the claimable balances whose reserve is
+/// sponsored by the given account. The sponsor is required, and is validated to be a Stellar public
+/// key when it is set. It adheres to the structure and parameters required by the Horizon API for
+/// retrieving a
+/// <a href="https://developers.stellar.org/api/horizon/resources/list-all-claimable-balances">list of claimable balances</a>.
+///
+/// # Usage
+///
+/// Create an instance of this struct, set the sponsor and any pagination parameters, and pass it to
+/// the [`HorizonClient::get_claimable_balances_for_sponsor`](crate::horizon_client::HorizonClient::get_claimable_balances_for_sponsor)
+/// method. To fetch the first page using the default pagination parameters, the
+/// [`HorizonClient::get_claimable_balances_sponsored_by`](crate::horizon_client::HorizonClient::get_claimable_balances_sponsored_by)
+/// method can be used instead.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::claimable_balances::prelude::*;
+/// # use stellar_rs::models::Order;
+///
+/// let request = ClaimableBalancesForSponsorRequest::new()
+///     .set_sponsor("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7").unwrap()
+///     .set_limit(100).unwrap() // Optional limit for response records
+///     .set_order(Order::Desc); // Optional order of records
+///
+/// // Use with HorizonClient::get_claimable_balances_for_sponsor
+/// ```
+///
+#[pagination]
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct ClaimableBalancesForSponsorRequest<S = NoClaimableBalanceSponsor> {
+    /// The account ID of the sponsor of the claimable balances.
+    sponsor: S,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
+}
+
+impl ClaimableBalancesForSponsorRequest<NoClaimableBalanceSponsor> {
+    /// Creates a new `ClaimableBalancesForSponsorRequest` with default parameters.
+    pub fn new() -> Self {
+        ClaimableBalancesForSponsorRequest::default()
+    }
+
+    /// Sets the account ID of the sponsor.
+    ///
+    /// # Arguments
+    /// * `sponsor` - A Stellar public key of the sponsor whose claimable balances are to be retrieved.
+    ///
+    pub fn set_sponsor(
+        self,
+        sponsor: impl Into<String>,
+    ) -> Result<ClaimableBalancesForSponsorRequest<ClaimableBalanceSponsor>, String> {
+        let sponsor = sponsor.into();
+        is_public_key(&sponsor)?;
+
+        Ok(ClaimableBalancesForSponsorRequest {
+            sponsor: ClaimableBalanceSponsor(sponsor),
+            page_params: self.page_params,
+        })
+    }
+}
+
+impl Request for ClaimableBalancesForSponsorRequest<ClaimableBalanceSponsor> {
+    fn get_query_parameters(&self) -> String {
+        [
+            Some(format!("sponsor={}", self.sponsor.0)),
+            self.page_params.cursor().map(|c| format!("cursor={}", c)),
+            self.page_params.limit().map(|l| format!("limit={}", l)),
+            self.page_params.order().map(|o| format!("order={}", o)),
+        ]
+        .build_query_parameters()
+    }
+
+    fn build_url(&self, base_url: &str) -> String {
+        format!(
+            "{}/{}{}",
+            base_url,
+            super::CLAIMABLE_BALANCES_PATH,
+            self.get_query_parameters()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPONSOR: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_set_sponsor_invalid() {
+        let request = ClaimableBalancesForSponsorRequest::new().set_sponsor("sponsor");
+        assert_eq!(
+            request.err().unwrap(),
+            "Public key must be 56 characters long"
+        );
+    }
+
+    #[test]
+    fn test_build_url() {
+        let request = ClaimableBalancesForSponsorRequest::new()
+            .set_cursor(12345)
+            .unwrap()
+            .set_sponsor(SPONSOR)
+            .unwrap()
+            .set_limit(20)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            format!(
+                "https://horizon-testnet.stellar.org/claimable_balances?sponsor={}&cursor=12345&limit=20&order=desc",
+                SPONSOR
+            )
+        );
+    }
+}
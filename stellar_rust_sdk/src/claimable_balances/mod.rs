@@ -1,5 +1,4 @@
-use chrono::DateTime;
-use chrono::Utc;
+use crate::datetime::{self, DateTime};
 
 /// Provides the `AllClaimableBalancesRequest` struct.
 ///
@@ -14,6 +13,17 @@ use chrono::Utc;
 ///
 pub mod all_claimable_balances_request;
 
+/// Provides the `ClaimableBalancesForSponsorRequest` struct.
+///
+/// This module contains the `ClaimableBalancesForSponsorRequest` struct, which is designed to create
+/// requests for querying the claimable balances sponsored by a given account. Unlike the generic
+/// filters of the `AllClaimableBalancesRequest`, the sponsor is required, and is validated when it is set.
+///
+/// The struct is intended to be used with the [`HorizonClient::get_claimable_balances_for_sponsor`](crate::horizon_client::HorizonClient::get_claimable_balances_for_sponsor)
+/// method.
+///
+pub mod claimable_balances_for_sponsor_request;
+
 /// Provides the claimable balance responses.
 ///
 /// The `response` module provides structures to parse and encapsulate
@@ -45,7 +55,7 @@ pub mod single_claimable_balance_request;
 /// This variable is intended to be used internally by the request-building logic
 /// to ensure consistent and accurate path construction for claimable-balance-related API calls.
 ///
-static CLAIMABLE_BALANCES_PATH: &str = "claimable_balances";
+pub(crate) static CLAIMABLE_BALANCES_PATH: &str = "claimable_balances";
 
 /// The `prelude` module of the `claimable_balance` module.
 ///
@@ -63,6 +73,7 @@ static CLAIMABLE_BALANCES_PATH: &str = "claimable_balances";
 /// The `prelude` includes the following re-exports:
 ///
 /// * From `all_claimable_balances_request`: All items (e.g., `AllClaimableBalancesRequest`).
+/// * From `claimable_balances_for_sponsor_request`: All items (e.g., `ClaimableBalancesForSponsorRequest`).
 /// * From `all_claimable_balances_response`: All items (e.g., `AllClaimableBalancesResponse`, `Record`, etc.).
 ///
 /// This approach allows for a more concise and focused usage pattern, especially beneficial
@@ -78,19 +89,18 @@ static CLAIMABLE_BALANCES_PATH: &str = "claimable_balances";
 /// // Further usage...
 /// ```
 
-fn parse_epoch(epoch_str: &str) -> DateTime<Utc> {
+fn parse_epoch(epoch_str: &str) -> Option<DateTime> {
     // Convert the timestamp string into an i64
-    let timestamp = epoch_str.parse::<i64>().unwrap();
+    let timestamp = epoch_str.parse::<i64>().ok()?;
 
     // Create a DateTime from the timestamp
-    let datetime = DateTime::from_timestamp(timestamp, 0).unwrap();
-
-    return datetime;
+    datetime::from_unix_millis(timestamp.checked_mul(1000)?)
 }
 
 pub mod prelude {
     pub use super::{
-        all_claimable_balances_request::*, response::*, single_claimable_balance_request::*,
+        all_claimable_balances_request::*, claimable_balances_for_sponsor_request::*, response::*,
+        single_claimable_balance_request::*,
     };
 }
 
@@ -98,57 +108,164 @@ pub mod prelude {
 mod tests {
     use super::parse_epoch;
     use super::prelude::*;
+    use crate::datetime::{self, DateTime};
     use crate::horizon_client::HorizonClient;
-    use chrono::DateTime;
-    use chrono::{TimeZone, Utc};
     use lazy_static::lazy_static;
 
     lazy_static! {
-        static ref DATE: DateTime<Utc> =
-            Utc::with_ymd_and_hms(&Utc, 2021, 9, 30, 18, 40, 0).unwrap();
-        static ref DATE_AND_ONE_SECOND: chrono::DateTime<Utc> =
-            Utc::with_ymd_and_hms(&Utc, 2021, 9, 30, 18, 40, 1).unwrap();
+        static ref DATE: DateTime = datetime::parse_rfc3339("2021-09-30T18:40:00Z").unwrap();
+        static ref DATE_AND_ONE_SECOND: DateTime =
+            datetime::parse_rfc3339("2021-09-30T18:40:01Z").unwrap();
+        static ref DATE_MINUS_ONE_SECOND: DateTime =
+            datetime::parse_rfc3339("2021-09-30T18:39:59Z").unwrap();
         static ref EPOCH_STR: String = "1633027200".to_string();
     }
 
+    fn abs_before(epoch: &str) -> Predicate {
+        Predicate {
+            abs_before_epoch: Some(epoch.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn not(predicate: Predicate) -> Predicate {
+        Predicate {
+            not: Some(Box::new(predicate)),
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_and_is_valid() {
-        let and = And {
-            not: Some(Not {
-                abs_before: EPOCH_STR.to_string(),
-                abs_before_epoch: EPOCH_STR.to_string(),
-            }),
-            abs_before: None,
-            abs_before_epoch: None,
+        let and = Predicate {
+            and: Some(vec![not(abs_before(&EPOCH_STR))]),
+            ..Default::default()
         };
-        assert_eq!(and.is_valid(*DATE), false);
+        assert!(!and.is_valid(*DATE_MINUS_ONE_SECOND));
+        assert!(and.is_valid(*DATE));
     }
 
     #[test]
     fn test_or_is_valid() {
-        let or = Or {
-            not: Some(Not {
-                abs_before: EPOCH_STR.to_string(),
-                abs_before_epoch: EPOCH_STR.to_string(),
-            }),
-            abs_before: None,
-            abs_before_epoch: None,
+        let or = Predicate {
+            or: Some(vec![abs_before(&EPOCH_STR), not(abs_before(&EPOCH_STR))]),
+            ..Default::default()
         };
-        assert_eq!(or.is_valid(*DATE), true);
+        assert!(or.is_valid(*DATE));
+        assert!(or.is_valid(*DATE_MINUS_ONE_SECOND));
     }
 
     #[test]
     fn test_not_is_valid() {
-        let not = Not {
-            abs_before: EPOCH_STR.to_string(),
-            abs_before_epoch: EPOCH_STR.to_string(),
+        let predicate = abs_before(&EPOCH_STR);
+        assert!(!predicate.is_valid(*DATE_AND_ONE_SECOND));
+        assert!(not(predicate).is_valid(*DATE_AND_ONE_SECOND));
+    }
+
+    #[test]
+    fn test_nested_predicate_is_valid() {
+        // The deepest nesting allowed by the protocol: claimable between two dates, or always
+        // by the unconditional branch if it were not negated.
+        let json = r#"{
+            "or": [
+                {
+                    "and": [
+                        {"not": {"abs_before": "2021-09-30T18:40:00Z", "abs_before_epoch": "1633027200"}},
+                        {"abs_before": "2021-10-01T18:40:00Z", "abs_before_epoch": "1633113600"}
+                    ]
+                },
+                {"not": {"unconditional": true}}
+            ]
+        }"#;
+        let predicate: Predicate = serde_json::from_str(json).unwrap();
+
+        let end_date = datetime::parse_rfc3339("2021-10-01T18:40:00Z").unwrap();
+        assert!(!predicate.is_valid(*DATE_MINUS_ONE_SECOND));
+        assert!(predicate.is_valid(*DATE));
+        assert!(!predicate.is_valid(end_date));
+
+        // Evaluation does not recurse, so it is not limited by the depth of the nesting.
+        let mut deep = Predicate {
+            unconditional: Some(true),
+            ..Default::default()
+        };
+        for _ in 0..1001 {
+            deep = not(deep);
+        }
+        assert!(!deep.is_valid(*DATE));
+    }
+
+    #[test]
+    fn test_claimants_for() {
+        let claimant = |destination: &str| Claimant {
+            destination: destination.to_string(),
+            predicate: Predicate {
+                unconditional: Some(true),
+                ..Default::default()
+            },
+        };
+        let balance = |id: &str, claimants: Vec<Claimant>| ClaimableBalance {
+            id: id.to_string(),
+            claimants,
+            ..Default::default()
+        };
+        let response = AllClaimableBalancesResponse {
+            links: Default::default(),
+            embedded: crate::models::prelude::Embedded {
+                records: vec![
+                    balance("1", vec![claimant("GA"), claimant("GB")]),
+                    balance("2", vec![claimant("GC")]),
+                    balance("3", vec![claimant("GB")]),
+                ],
+            },
+        };
+
+        let ids: Vec<&str> = response
+            .claimants_for("GB")
+            .map(|(balance, claimant)| {
+                assert_eq!(claimant.destination(), "GB");
+                balance.id().as_str()
+            })
+            .collect();
+        assert_eq!(ids, vec!["1", "3"]);
+        assert!(response.embedded().records()[1].claimant("GA").is_none());
+    }
+
+    #[test]
+    fn test_is_claimable_by() {
+        let balance = ClaimableBalance {
+            sponsor: "GA7UL5DDCP6WR7KV5GXKXSHBMP577U7TBDBTBY33J57RZE2A37KW67JB".to_string(),
+            claimants: vec![
+                Claimant {
+                    destination: "GA".to_string(),
+                    predicate: abs_before(&EPOCH_STR),
+                },
+                Claimant {
+                    destination: "GB".to_string(),
+                    predicate: not(abs_before(&EPOCH_STR)),
+                },
+            ],
+            ..Default::default()
         };
-        assert_eq!(not.is_valid(*DATE_AND_ONE_SECOND), false);
+
+        assert!(balance.is_claimable_by("GA", *DATE_MINUS_ONE_SECOND));
+        assert!(!balance.is_claimable_by("GA", *DATE));
+        assert!(!balance.is_claimable_by("GB", *DATE_MINUS_ONE_SECOND));
+        assert!(balance.is_claimable_by("GB", *DATE));
+        assert!(!balance.is_claimable_by("GC", *DATE));
+
+        assert_eq!(
+            balance.sponsor_id().unwrap(),
+            "GA7UL5DDCP6WR7KV5GXKXSHBMP577U7TBDBTBY33J57RZE2A37KW67JB"
+        );
+        assert!(ClaimableBalance::default().sponsor_id().is_none());
+        assert!(!balance.is_clawback_enabled());
     }
 
     #[test]
     fn test_parse_epoch() {
-        assert_eq!(parse_epoch(&EPOCH_STR.to_string()), *DATE);
+        assert_eq!(parse_epoch(&EPOCH_STR.to_string()), Some(*DATE));
+        assert_eq!(parse_epoch("invalid"), None);
     }
 
     #[tokio::test]
@@ -178,8 +295,8 @@ mod tests {
         let binding = all_claimable_balances_response.unwrap();
         let predicate = binding.embedded().records()[1].claimants()[0].predicate();
 
-        let jan_first_2024 = Utc::with_ymd_and_hms(&Utc, 2024, 1, 1, 0, 0, 0).unwrap();
-        let valid_date = Utc::with_ymd_and_hms(&Utc, 2024, 2, 10, 0, 0, 0).unwrap();
+        let jan_first_2024 = datetime::parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        let valid_date = datetime::parse_rfc3339("2024-02-10T00:00:00Z").unwrap();
 
         assert_eq!(predicate.is_valid(jan_first_2024), true);
         assert_eq!(predicate.is_valid(valid_date), true);
@@ -200,6 +317,42 @@ mod tests {
         assert_eq!(record.flags().clawback_enabled(), CLAWBACK_ENABLED);
     }
 
+    #[tokio::test]
+    async fn test_get_claimable_balances_for_sponsor() {
+        static SPONSOR: &str = "GCRHSLTKEPLLRLC4MB5OJPO4DJYIMYHYBDHX4TET3XKUKFAYMWERHXVG";
+        static ID: &str =
+            "0000000010a8f6991f79df306f22a2032f6007ad594dd30f966b21556f7d75658ec1c4e9";
+
+        // Initialize horizon client
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let request = ClaimableBalancesForSponsorRequest::new()
+            .set_sponsor(SPONSOR)
+            .unwrap()
+            .set_limit(2)
+            .unwrap();
+
+        let response = horizon_client
+            .get_claimable_balances_for_sponsor(&request)
+            .await;
+
+        assert!(response.is_ok());
+        let binding = response.unwrap();
+        let record = &binding.embedded().records()[0];
+        assert_eq!(record.id(), ID);
+        assert_eq!(record.sponsor(), SPONSOR);
+
+        let sponsored_by = horizon_client
+            .get_claimable_balances_sponsored_by(SPONSOR)
+            .await
+            .unwrap();
+        assert!(sponsored_by
+            .embedded()
+            .records()
+            .iter()
+            .all(|record| record.sponsor() == SPONSOR));
+    }
+
     #[tokio::test]
     async fn test_get_single_claimable_balance() {
         static CLAIMABLE_BALANCE_ID: &str =
@@ -228,13 +381,13 @@ mod tests {
         assert!(single_claimable_balance_response.is_ok());
 
         let binding = single_claimable_balance_response.clone().unwrap();
-        let predicate = binding.claimants()[0].predicate();
+        let claimant = binding.claimants()[0].destination();
 
-        let jan_first_2024 = Utc::with_ymd_and_hms(&Utc, 2021, 1, 1, 0, 0, 0).unwrap();
-        let valid_date = Utc::with_ymd_and_hms(&Utc, 2021, 1, 1, 0, 0, 0).unwrap();
+        let jan_first_2024 = datetime::parse_rfc3339("2021-01-01T00:00:00Z").unwrap();
+        let valid_date = datetime::parse_rfc3339("2021-01-01T00:00:00Z").unwrap();
 
-        assert_eq!(predicate.is_valid(jan_first_2024), true);
-        assert_eq!(predicate.is_valid(valid_date), true);
+        assert!(binding.is_claimable_by(claimant, jan_first_2024));
+        assert!(binding.is_claimable_by(claimant, valid_date));
 
         let single_claimable_balance_response = single_claimable_balance_response.unwrap();
         assert_eq!(single_claimable_balance_response.id().to_string(), ID);
@@ -1,11 +1,12 @@
 use super::*;
+use crate::accounts::prelude::AccountId;
 use crate::models::prelude::*;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
 impl Response for AllClaimableBalancesResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
@@ -13,6 +14,7 @@ impl Response for AllClaimableBalancesResponse {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AllClaimableBalancesResponse {
     #[serde(rename = "_links")]
     pub links: ResponseLinks,
@@ -23,6 +25,7 @@ pub struct AllClaimableBalancesResponse {
 /// Represents a claimable balance query in the Horizon API.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ClaimableBalance {
     /// Links to related resources in the Horizon API response.
     #[serde(rename = "_links")]
@@ -62,6 +65,7 @@ pub struct ClaimableBalance {
 /// Contains navigational links related to the single claimable balance response.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ClaimableBalanceLinks {
     /// The link to the current claimable balance resource.
     #[serde(rename = "self")]
@@ -77,6 +81,7 @@ pub struct ClaimableBalanceLinks {
 /// Represents a claimant of a claimable balance.
 #[derive(Default, Debug, Clone, Serialize, PartialEq, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Claimant {
     /// The account ID of the claimant.
     pub destination: String,
@@ -86,104 +91,189 @@ pub struct Claimant {
 }
 
 #[derive(Default, Debug, Clone, Serialize, PartialEq, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct ClaimableBalanceFlag {
     /// The flag indicating whether the claimable balance is clawback-enabled.
     pub clawback_enabled: bool,
 }
 
+/// Represents the conditions under which a claimant can claim a claimable balance.
+///
+/// A predicate is either unconditional, a time bound (`abs_before`), or a combination of other
+/// predicates using `and`, `or` and `not`. Combinations can be nested, so a predicate forms a
+/// tree. Exactly one of the fields is set on every node of the tree.
+///
+/// Predicates with a relative time bound (`rel_before`) are converted into absolute time bounds
+/// when the claimable balance is created, so Horizon only reports them for balances that have not
+/// been created yet; they are never satisfied when evaluated.
+///
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Predicate {
+    /// Set to `true` if the balance can be claimed without conditions.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unconditional: Option<bool>,
-    pub and: Option<Vec<And>>,
-    pub or: Option<Vec<Or>>,
-    pub not: Option<Not>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
-#[serde(rename_all = "camelCase")]
-pub struct And {
-    pub not: Option<Not>,
-    #[serde(rename = "abs_before")]
+    /// Predicates of which all must be satisfied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub and: Option<Vec<Predicate>>,
+    /// Predicates of which at least one must be satisfied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub or: Option<Vec<Predicate>>,
+    /// A predicate that must not be satisfied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not: Option<Box<Predicate>>,
+    /// The time before which the balance can be claimed, as an ISO 8601 timestamp.
+    #[serde(rename = "abs_before", skip_serializing_if = "Option::is_none")]
     pub abs_before: Option<String>,
-    #[serde(rename = "abs_before_epoch")]
+    /// The time before which the balance can be claimed, in seconds since the Unix epoch.
+    #[serde(rename = "abs_before_epoch", skip_serializing_if = "Option::is_none")]
     pub abs_before_epoch: Option<String>,
+    /// The number of seconds after the creation of the balance before which it can be claimed.
+    #[serde(rename = "rel_before", skip_serializing_if = "Option::is_none")]
+    pub rel_before: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
-#[serde(rename_all = "camelCase")]
-pub struct Not {
-    #[serde(rename = "abs_before")]
-    pub abs_before: String,
-    #[serde(rename = "abs_before_epoch")]
-    pub abs_before_epoch: String,
+/// A step in the iterative evaluation of a [`Predicate`].
+enum Evaluation<'a> {
+    /// Evaluates a predicate, pushing the outcome onto the stack of outcomes.
+    Visit(&'a Predicate),
+    /// Combines the given number of outcomes into one, which is `true` if all of them are.
+    All(usize),
+    /// Combines the given number of outcomes into one, which is `true` if any of them is.
+    Any(usize),
+    /// Negates the last outcome.
+    Negate,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
-#[serde(rename_all = "camelCase")]
-pub struct Or {
-    #[serde(rename = "abs_before")]
-    pub abs_before: Option<String>,
-    #[serde(rename = "abs_before_epoch")]
-    pub abs_before_epoch: Option<String>,
-    pub not: Option<Not>,
-}
-
-#[allow(dead_code)]
 impl Predicate {
-    pub(crate) fn is_valid(&self, date: DateTime<Utc>) -> bool {
-        match self {
-            Predicate {
-                unconditional: Some(true),
-                ..
-            } => true,
-            Predicate {
-                and: Some(ands), ..
-            } => ands.iter().all(|cond| cond.is_valid(date)),
-            Predicate { or: Some(ors), .. } => ors.iter().any(|cond| cond.is_valid(date)),
-            Predicate { not: Some(not), .. } => !not.is_valid(date),
-            _ => false,
+    /// Evaluates whether the predicate is satisfied at the given date.
+    ///
+    /// The predicate tree is evaluated iteratively rather than recursively, so that the depth of
+    /// the nesting is not limited by the size of the stack.
+    ///
+    pub fn is_valid(&self, date: DateTime) -> bool {
+        let mut evaluations = vec![Evaluation::Visit(self)];
+        let mut outcomes: Vec<bool> = Vec::new();
+
+        while let Some(evaluation) = evaluations.pop() {
+            match evaluation {
+                Evaluation::Visit(Predicate {
+                    and: Some(predicates),
+                    ..
+                }) => {
+                    evaluations.push(Evaluation::All(predicates.len()));
+                    evaluations.extend(predicates.iter().map(Evaluation::Visit));
+                }
+                Evaluation::Visit(Predicate {
+                    or: Some(predicates),
+                    ..
+                }) => {
+                    evaluations.push(Evaluation::Any(predicates.len()));
+                    evaluations.extend(predicates.iter().map(Evaluation::Visit));
+                }
+                Evaluation::Visit(Predicate {
+                    not: Some(predicate),
+                    ..
+                }) => {
+                    evaluations.push(Evaluation::Negate);
+                    evaluations.push(Evaluation::Visit(predicate));
+                }
+                Evaluation::Visit(predicate) => outcomes.push(predicate.is_leaf_valid(date)),
+                Evaluation::All(count) => {
+                    let start = outcomes.len().saturating_sub(count);
+                    let outcome = outcomes.drain(start..).all(|outcome| outcome);
+                    outcomes.push(outcome);
+                }
+                Evaluation::Any(count) => {
+                    let start = outcomes.len().saturating_sub(count);
+                    let outcome = outcomes.drain(start..).any(|outcome| outcome);
+                    outcomes.push(outcome);
+                }
+                Evaluation::Negate => {
+                    let outcome = outcomes.pop().unwrap_or(false);
+                    outcomes.push(!outcome);
+                }
+            }
         }
+
+        outcomes.pop().unwrap_or(false)
     }
-}
 
-impl And {
-    pub(crate) fn is_valid(&self, date: DateTime<Utc>) -> bool {
-        if let Some(not) = &self.not {
-            if not.is_valid(date) {
-                return false;
-            }
+    /// Evaluates a predicate that does not combine other predicates.
+    fn is_leaf_valid(&self, date: DateTime) -> bool {
+        if self.unconditional == Some(true) {
+            return true;
         }
-        self.abs_before_epoch
-            .as_ref()
-            .map(|d| date < parse_epoch(d))
-            .unwrap_or(true)
+
+        let abs_before = match (&self.abs_before_epoch, &self.abs_before) {
+            (Some(epoch), _) => parse_epoch(epoch),
+            (None, Some(abs_before)) => datetime::parse_rfc3339(abs_before).ok(),
+            (None, None) => None,
+        };
+        abs_before.is_some_and(|abs_before| date < abs_before)
     }
 }
 
-impl Or {
-    pub(crate) fn is_valid(&self, date: DateTime<Utc>) -> bool {
-        if let Some(not) = &self.not {
-            if not.is_valid(date) {
-                return true;
-            }
-        }
-        self.abs_before_epoch
-            .as_ref()
-            .map(|d| date < parse_epoch(d))
-            .unwrap_or(false)
+impl ClaimableBalance {
+    /// Returns the claimant entry of the given account, if the account is a claimant of this balance.
+    ///
+    /// # Arguments
+    /// * `account_id` - The public key of the account.
+    ///
+    pub fn claimant(&self, account_id: &str) -> Option<&Claimant> {
+        self.claimants
+            .iter()
+            .find(|claimant| claimant.destination == account_id)
+    }
+
+    /// Returns whether the given account can claim this balance at the given date.
+    ///
+    /// The account can claim the balance when it is one of the claimants, and the predicate of
+    /// its claimant entry is satisfied at the given date.
+    ///
+    /// # Arguments
+    /// * `account_id` - The public key of the account.
+    /// * `at` - The date at which the balance would be claimed.
+    ///
+    pub fn is_claimable_by(&self, account_id: &str, at: DateTime) -> bool {
+        self.claimant(account_id)
+            .is_some_and(|claimant| claimant.predicate.is_valid(at))
+    }
+
+    /// Returns the account ID of the sponsor of the balance, or `None` if the balance has no
+    /// sponsor.
+    pub fn sponsor_id(&self) -> Option<AccountId> {
+        AccountId::new(self.sponsor.as_str()).ok()
+    }
+
+    /// Returns whether the issuer of the asset can claw back the balance.
+    pub fn is_clawback_enabled(&self) -> bool {
+        self.flags.clawback_enabled
     }
 }
 
-impl Not {
-    pub(crate) fn is_valid(&self, date: DateTime<Utc>) -> bool {
-        date <= parse_epoch(&self.abs_before_epoch)
+impl AllClaimableBalancesResponse {
+    /// Returns the claimable balances of which the given account is a claimant, together with the
+    /// claimant entry of the account.
+    ///
+    /// # Arguments
+    /// * `account_id` - The public key of the account.
+    ///
+    pub fn claimants_for<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> impl Iterator<Item = (&'a ClaimableBalance, &'a Claimant)> + 'a {
+        self.embedded
+            .records
+            .iter()
+            .filter_map(move |balance| Some((balance, balance.claimant(account_id)?)))
     }
 }
 
 impl Response for ClaimableBalance {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
         Ok(response)
     }
 }
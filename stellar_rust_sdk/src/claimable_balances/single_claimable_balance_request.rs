@@ -1,11 +1,11 @@
 use crate::models::*;
 
 /// Represents the ID of a claimable balance.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct ClaimableBalanceId(String);
 
 /// Represents the absence of a claimable balance ID.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoClaimableBalanceId;
 
 /// Represents a request to retrieve information about a single claimable balance from the Stellar Horizon API.
@@ -41,8 +41,8 @@ pub struct NoClaimableBalanceId;
 /// # }
 /// ```
 ///
-#[derive(Default)]
-pub struct SingleClaimableBalanceRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct SingleClaimableBalanceRequest<I = NoClaimableBalanceId> {
     claimable_balance_id: I,
 }
 
@@ -0,0 +1,302 @@
+#![allow(deprecated)]
+
+use crate::models::prelude::{AssetData, AssetType, RationalPrice};
+use crate::trade_aggregations::prelude::Ratio;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Represents whether failed operations, payments or transactions are included in a response.
+///
+/// The requests now take a plain `bool` instead. Use `.into()` to convert an existing value.
+///
+#[deprecated(
+    note = "use a `bool` instead; `IncludeFailed` will be removed in the next major release"
+)]
+#[derive(Default, Clone, PartialEq, Debug)]
+pub enum IncludeFailed {
+    True,
+    #[default]
+    False,
+}
+
+impl std::fmt::Display for IncludeFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", bool::from(self.clone()))
+    }
+}
+
+impl From<IncludeFailed> for bool {
+    fn from(include_failed: IncludeFailed) -> Self {
+        include_failed == IncludeFailed::True
+    }
+}
+
+impl From<bool> for IncludeFailed {
+    fn from(include_failed: bool) -> Self {
+        if include_failed {
+            IncludeFailed::True
+        } else {
+            IncludeFailed::False
+        }
+    }
+}
+
+/// Represents the precise buy and sell price of a trade, as previously returned by
+/// [`TradeResponse::price`](crate::trades::prelude::TradeResponse::price).
+///
+/// Trade prices are now returned as a [`RationalPrice`]. Use `TryFrom` to convert an existing
+/// value.
+///
+#[deprecated(
+    note = "use `RationalPrice` instead; `Price` will be removed in the next major release"
+)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+pub struct Price {
+    /// The numenator.
+    #[serde(rename = "n")]
+    numenator: String,
+    /// The denominator.
+    #[serde(rename = "d")]
+    denominator: String,
+}
+
+impl TryFrom<&Price> for RationalPrice {
+    type Error = String;
+
+    /// Converts the price fraction into an exact [`RationalPrice`], failing if either part is not
+    /// a non-negative integer or if the denominator is zero.
+    fn try_from(price: &Price) -> Result<Self, Self::Error> {
+        parse_fraction(&price.numenator, &price.denominator)
+    }
+}
+
+impl From<RationalPrice> for Price {
+    fn from(price: RationalPrice) -> Self {
+        Price {
+            numenator: price.numerator().to_string(),
+            denominator: price.denominator().to_string(),
+        }
+    }
+}
+
+impl TryFrom<&Ratio> for RationalPrice {
+    type Error = String;
+
+    /// Converts the trade aggregation ratio into an exact [`RationalPrice`], failing if either
+    /// part is not a non-negative integer or if the denominator is zero.
+    fn try_from(ratio: &Ratio) -> Result<Self, Self::Error> {
+        parse_fraction(ratio.numenator(), ratio.denominator())
+    }
+}
+
+/// Represents a single asset used in a payment path, as previously returned by
+/// [`Path::path`](crate::paths::prelude::Path::path).
+///
+/// The hops of a payment path are now returned as an [`AssetType`]. Use `TryFrom` to convert an
+/// existing value. This type is still available as `paths::prelude::Asset`.
+///
+#[deprecated(
+    note = "use `AssetType` instead; `PathAsset` will be removed in the next major release"
+)]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct PathAsset {
+    asset_type: String,
+    asset_code: Option<String>,
+    asset_issuer: Option<String>,
+}
+
+impl TryFrom<&PathAsset> for AssetType {
+    type Error = String;
+
+    /// Converts an asset of a payment path into the shared [`AssetType`].
+    fn try_from(asset: &PathAsset) -> Result<Self, Self::Error> {
+        asset_type(
+            Some(asset.asset_type()),
+            asset.asset_code(),
+            asset.asset_issuer(),
+        )
+    }
+}
+
+/// Represents the base asset of an order book, as previously returned by
+/// [`DetailsResponse::base`](crate::order_book::prelude::DetailsResponse::base).
+///
+/// The assets of an order book are now returned as an [`AssetType`]. Use `TryFrom` to convert an
+/// existing value. This type is still available as `order_book::prelude::Base`.
+///
+#[deprecated(note = "use `AssetType` instead; `Base` will be removed in the next major release")]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct Base {
+    /// The type for the base asset. Either native, credit_alphanum4, or credit_alphanum12.
+    pub asset_type: Option<String>,
+    /// The code for the base asset.
+    pub asset_code: Option<String>,
+    /// The Stellar address of the base asset’s issuer.
+    pub asset_issuer: Option<String>,
+}
+
+/// Represents the counter asset of an order book, as previously returned by
+/// [`DetailsResponse::counter`](crate::order_book::prelude::DetailsResponse::counter).
+///
+/// The assets of an order book are now returned as an [`AssetType`]. Use `TryFrom` to convert an
+/// existing value. This type is still available as `order_book::prelude::Counter`.
+///
+#[deprecated(note = "use `AssetType` instead; `Counter` will be removed in the next major release")]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct Counter {
+    /// The type for the counter asset. Either native, credit_alphanum4, or credit_alphanum12.
+    pub asset_type: Option<String>,
+    /// The code for the counter asset.
+    pub asset_code: Option<String>,
+    /// The Stellar address of the counter asset’s issuer.
+    pub asset_issuer: Option<String>,
+}
+
+impl TryFrom<&Base> for AssetType {
+    type Error = String;
+
+    /// Converts the base asset of an order book into the shared [`AssetType`].
+    fn try_from(asset: &Base) -> Result<Self, Self::Error> {
+        asset_type(
+            asset.asset_type.as_ref(),
+            &asset.asset_code,
+            &asset.asset_issuer,
+        )
+    }
+}
+
+impl TryFrom<&Counter> for AssetType {
+    type Error = String;
+
+    /// Converts the counter asset of an order book into the shared [`AssetType`].
+    fn try_from(asset: &Counter) -> Result<Self, Self::Error> {
+        asset_type(
+            asset.asset_type.as_ref(),
+            &asset.asset_code,
+            &asset.asset_issuer,
+        )
+    }
+}
+
+/// Represents an asset in the list of source or destination assets of a payment path request.
+///
+/// The requests now take a list of [`AssetType`] instead. Use `.into()` to convert an existing
+/// value. This type is still available as `paths::IssuedOrNative`.
+///
+#[deprecated(
+    note = "use `AssetType` instead; `IssuedOrNative` will be removed in the next major release"
+)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IssuedOrNative {
+    #[default]
+    Native,
+    Issued(AssetData),
+}
+
+impl From<IssuedOrNative> for AssetType {
+    /// Converts an asset of a payment path request into the shared [`AssetType`], choosing
+    /// `Alphanumeric4` or `Alphanumeric12` based on the length of the asset code.
+    fn from(asset: IssuedOrNative) -> Self {
+        match asset {
+            IssuedOrNative::Native => AssetType::Native,
+            IssuedOrNative::Issued(asset_data) => {
+                AssetType::issued(asset_data.asset_code, asset_data.asset_issuer)
+            }
+        }
+    }
+}
+
+/// Parses a price fraction of which both parts are encoded as strings.
+fn parse_fraction(numerator: &str, denominator: &str) -> Result<RationalPrice, String> {
+    let parse = |value: &str| {
+        value
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid price: {}/{}", numerator, denominator))
+    };
+    RationalPrice::new(parse(numerator)?, parse(denominator)?)
+}
+
+/// Combines the type, code and issuer of an asset, as returned by Horizon, into an [`AssetType`].
+fn asset_type(
+    asset_type: Option<&String>,
+    asset_code: &Option<String>,
+    asset_issuer: &Option<String>,
+) -> Result<AssetType, String> {
+    AssetType::from_horizon(
+        asset_type.map(String::as_str).unwrap_or_default(),
+        asset_code.as_deref(),
+        asset_issuer.as_deref(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::prelude::AssetData;
+
+    const ISSUER: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_include_failed_to_bool() {
+        assert!(bool::from(IncludeFailed::True));
+        assert!(!bool::from(IncludeFailed::default()));
+        assert_eq!(IncludeFailed::from(true), IncludeFailed::True);
+        assert_eq!(IncludeFailed::True.to_string(), "true");
+    }
+
+    #[test]
+    fn test_price_to_rational_price() {
+        let price: Price = serde_json::from_str(r#"{ "n": "10", "d": "4" }"#).unwrap();
+        assert_eq!(
+            RationalPrice::try_from(&price).unwrap(),
+            RationalPrice::new(5, 2).unwrap()
+        );
+
+        let price = Price::from(RationalPrice::new(3, 10).unwrap());
+        assert_eq!(price.numenator(), "3");
+        assert_eq!(price.denominator(), "10");
+
+        let price: Price = serde_json::from_str(r#"{ "n": "-1", "d": "4" }"#).unwrap();
+        assert!(RationalPrice::try_from(&price).is_err());
+    }
+
+    #[test]
+    fn test_assets_to_asset_type() {
+        let path_asset: PathAsset = serde_json::from_str(&format!(
+            r#"{{"asset_type": "credit_alphanum12", "asset_code": "LONGASSET", "asset_issuer": "{}"}}"#,
+            ISSUER
+        ))
+        .unwrap();
+        assert_eq!(
+            AssetType::try_from(&path_asset).unwrap(),
+            AssetType::Alphanumeric12(AssetData {
+                asset_code: "LONGASSET".to_string(),
+                asset_issuer: ISSUER.to_string(),
+            })
+        );
+
+        let base = Base {
+            asset_type: Some("native".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(AssetType::try_from(&base).unwrap(), AssetType::Native);
+
+        let counter = Counter {
+            asset_type: Some("credit_alphanum4".to_string()),
+            ..Default::default()
+        };
+        assert!(AssetType::try_from(&counter).is_err());
+
+        let issued = IssuedOrNative::Issued(AssetData {
+            asset_code: "USDC".to_string(),
+            asset_issuer: ISSUER.to_string(),
+        });
+        assert!(matches!(
+            AssetType::from(issued),
+            AssetType::Alphanumeric4(_)
+        ));
+    }
+}
@@ -0,0 +1,402 @@
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// The prefix of the environment variables read by [`HorizonClientConfig::from_env`].
+const ENV_PREFIX: &str = "HORIZON_";
+
+/// The prefix of the environment variables that set a default header of every request.
+const ENV_HEADER_PREFIX: &str = "HORIZON_HEADER_";
+
+/// Represents the retry policy of a [`HorizonClientConfig`].
+///
+/// The configuration is converted into a [`RetryPolicy`] when the client is created. If the
+/// backoffs are omitted, the client waits 100 milliseconds before the first retry, and at most
+/// 10 seconds before any retry.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryConfig {
+    /// The maximum number of times a failed request is retried.
+    pub max_retries: u32,
+    /// The time to wait before the first retry, in milliseconds.
+    #[serde(default = "RetryConfig::default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    /// The maximum time to wait before a retry, in milliseconds.
+    #[serde(default = "RetryConfig::default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+}
+
+impl RetryConfig {
+    /// Creates a new `RetryConfig` with the default backoffs.
+    ///
+    /// # Arguments
+    /// * `max_retries` - The maximum number of times a failed request is retried.
+    ///
+    pub fn new(max_retries: u32) -> Self {
+        RetryConfig {
+            max_retries,
+            initial_backoff_ms: Self::default_initial_backoff_ms(),
+            max_backoff_ms: Self::default_max_backoff_ms(),
+        }
+    }
+
+    fn default_initial_backoff_ms() -> u64 {
+        100
+    }
+
+    fn default_max_backoff_ms() -> u64 {
+        10_000
+    }
+}
+
+impl TryFrom<&RetryConfig> for RetryPolicy {
+    type Error = String;
+
+    fn try_from(config: &RetryConfig) -> Result<Self, Self::Error> {
+        RetryPolicy::new(
+            config.max_retries,
+            Duration::from_millis(config.initial_backoff_ms),
+            Duration::from_millis(config.max_backoff_ms),
+        )
+    }
+}
+
+/// Represents the client-side rate limit of a [`HorizonClientConfig`].
+///
+/// The configuration is converted into a [`RateLimiter`] when the client is created.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitConfig {
+    /// The largest burst of requests allowed.
+    pub capacity: u32,
+    /// The sustained number of requests allowed per second.
+    pub refill_per_second: u32,
+}
+
+impl TryFrom<&RateLimitConfig> for RateLimiter {
+    type Error = String;
+
+    fn try_from(config: &RateLimitConfig) -> Result<Self, Self::Error> {
+        RateLimiter::new(config.capacity, config.refill_per_second)
+    }
+}
+
+/// Represents the configuration of a [`HorizonClient`](crate::horizon_client::HorizonClient).
+///
+/// The configuration bundles the settings of a client, so that deployments can change the Horizon
+/// server, timeouts, retries, headers and rate limits without changing code. It can be loaded
+/// from a TOML document or from environment variables, and is turned into a client using
+/// [`HorizonClient::from_config`](crate::horizon_client::HorizonClient::from_config). Settings that
+/// are omitted keep the defaults of [`HorizonClient::new`](crate::horizon_client::HorizonClient::new).
+///
+/// # TOML
+/// ```toml
+/// base_url = "https://horizon-testnet.stellar.org"
+/// network_passphrase = "Test SDF Network ; September 2015"
+/// timeout_ms = 30000
+/// connect_timeout_ms = 5000
+///
+/// [retry]
+/// max_retries = 3
+/// initial_backoff_ms = 100
+/// max_backoff_ms = 2000
+///
+/// [rate_limit]
+/// capacity = 10
+/// refill_per_second = 5
+///
+/// [headers]
+/// X-Client-Name = "my-app"
+/// ```
+///
+/// # Environment variables
+/// * `HORIZON_BASE_URL` - The base URL of the Horizon server. Required.
+/// * `HORIZON_NETWORK_PASSPHRASE` - The passphrase of the network.
+/// * `HORIZON_TIMEOUT_MS` and `HORIZON_CONNECT_TIMEOUT_MS` - The timeouts, in milliseconds.
+/// * `HORIZON_MAX_RETRIES`, `HORIZON_RETRY_INITIAL_BACKOFF_MS` and `HORIZON_RETRY_MAX_BACKOFF_MS` -
+///   The retry policy, which is only set if `HORIZON_MAX_RETRIES` is present.
+/// * `HORIZON_RATE_LIMIT_CAPACITY` and `HORIZON_RATE_LIMIT_REFILL_PER_SECOND` - The client-side
+///   rate limit, which is only set if both are present.
+/// * `HORIZON_HEADER_<NAME>` - A header that is sent with every request. The name of the header
+///   is derived from the name of the variable, replacing underscores with dashes, so that
+///   `HORIZON_HEADER_X_CLIENT_NAME` sets the `x-client-name` header.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::{HorizonClient, HorizonClientConfig};
+/// let config = HorizonClientConfig::from_toml_str(
+///     r#"
+///     base_url = "https://horizon-testnet.stellar.org"
+///     timeout_ms = 30000
+///
+///     [retry]
+///     max_retries = 3
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let horizon_client = HorizonClient::from_config(&config).unwrap();
+/// assert_eq!(horizon_client.retry_policy().unwrap().max_retries(), 3);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HorizonClientConfig {
+    /// The base URL of the Horizon server.
+    pub base_url: String,
+    /// The passphrase of the network served by the Horizon server, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_passphrase: Option<String>,
+    /// The timeout of a request, from sending it until its response has been read, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    /// The timeout of opening a connection to the Horizon server, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_ms: Option<u64>,
+    /// The retry policy of failed requests, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+    /// The client-side rate limit, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// The headers that are sent with every request.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+}
+
+impl HorizonClientConfig {
+    /// Creates a new `HorizonClientConfig` for the given Horizon server, with default settings.
+    ///
+    /// # Arguments
+    /// * `base_url` - The base URL of the Horizon server.
+    ///
+    pub fn new(base_url: impl Into<String>) -> Self {
+        HorizonClientConfig {
+            base_url: base_url.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Parses a `HorizonClientConfig` from a TOML document.
+    ///
+    /// # Arguments
+    /// * `toml` - The TOML document.
+    ///
+    /// # Returns
+    /// The `HorizonClientConfig`, or an error if the document is not valid TOML, or contains
+    /// unknown or invalid settings.
+    ///
+    pub fn from_toml_str(toml: &str) -> Result<Self, String> {
+        toml::from_str(toml).map_err(|e| format!("Invalid configuration: {}", e))
+    }
+
+    /// Reads a `HorizonClientConfig` from a TOML file.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the TOML file.
+    ///
+    /// # Returns
+    /// The `HorizonClientConfig`, or an error if the file can not be read or is not a valid
+    /// configuration.
+    ///
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let toml = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        Self::from_toml_str(&toml)
+    }
+
+    /// Reads a `HorizonClientConfig` from the environment variables of the process.
+    ///
+    /// See the [type-level documentation](HorizonClientConfig#environment-variables) for the
+    /// variables that are read.
+    ///
+    /// # Returns
+    /// The `HorizonClientConfig`, or an error if `HORIZON_BASE_URL` is missing or a variable has
+    /// an invalid value.
+    ///
+    pub fn from_env() -> Result<Self, String> {
+        Self::from_vars(std::env::vars())
+    }
+
+    /// Reads a `HorizonClientConfig` from the given environment variables.
+    fn from_vars(vars: impl IntoIterator<Item = (String, String)>) -> Result<Self, String> {
+        let mut vars: BTreeMap<String, String> = vars
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+            .collect();
+
+        let headers = vars
+            .iter()
+            .filter_map(|(name, value)| {
+                let header = name.strip_prefix(ENV_HEADER_PREFIX)?;
+                Some((header.to_lowercase().replace('_', "-"), value.clone()))
+            })
+            .collect();
+
+        let mut var = |name: &str| vars.remove(&format!("{}{}", ENV_PREFIX, name));
+        let base_url = var("BASE_URL").ok_or("HORIZON_BASE_URL is not set".to_string())?;
+        let network_passphrase = var("NETWORK_PASSPHRASE");
+        let timeout_ms = var("TIMEOUT_MS")
+            .map(|v| parse_var("TIMEOUT_MS", &v))
+            .transpose()?;
+        let connect_timeout_ms = var("CONNECT_TIMEOUT_MS")
+            .map(|v| parse_var("CONNECT_TIMEOUT_MS", &v))
+            .transpose()?;
+
+        let initial_backoff_ms = var("RETRY_INITIAL_BACKOFF_MS");
+        let max_backoff_ms = var("RETRY_MAX_BACKOFF_MS");
+        let retry = match var("MAX_RETRIES") {
+            None => None,
+            Some(max_retries) => {
+                let mut retry = RetryConfig::new(parse_var("MAX_RETRIES", &max_retries)?);
+                if let Some(v) = initial_backoff_ms {
+                    retry.initial_backoff_ms = parse_var("RETRY_INITIAL_BACKOFF_MS", &v)?;
+                }
+                if let Some(v) = max_backoff_ms {
+                    retry.max_backoff_ms = parse_var("RETRY_MAX_BACKOFF_MS", &v)?;
+                }
+                Some(retry)
+            }
+        };
+
+        let rate_limit = match (
+            var("RATE_LIMIT_CAPACITY"),
+            var("RATE_LIMIT_REFILL_PER_SECOND"),
+        ) {
+            (Some(capacity), Some(refill_per_second)) => Some(RateLimitConfig {
+                capacity: parse_var("RATE_LIMIT_CAPACITY", &capacity)?,
+                refill_per_second: parse_var("RATE_LIMIT_REFILL_PER_SECOND", &refill_per_second)?,
+            }),
+            _ => None,
+        };
+
+        Ok(HorizonClientConfig {
+            base_url,
+            network_passphrase,
+            timeout_ms,
+            connect_timeout_ms,
+            retry,
+            rate_limit,
+            headers,
+        })
+    }
+}
+
+/// Parses the value of an environment variable.
+fn parse_var<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid value of {}{}: {}", ENV_PREFIX, name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_toml_str() {
+        let config = HorizonClientConfig::from_toml_str(
+            r#"
+            base_url = "https://horizon-testnet.stellar.org"
+            network_passphrase = "Test SDF Network ; September 2015"
+            timeout_ms = 30000
+
+            [retry]
+            max_retries = 3
+            max_backoff_ms = 2000
+
+            [rate_limit]
+            capacity = 10
+            refill_per_second = 5
+
+            [headers]
+            X-Client-Name = "my-app"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.base_url, "https://horizon-testnet.stellar.org");
+        assert_eq!(config.timeout_ms, Some(30000));
+        assert_eq!(config.connect_timeout_ms, None);
+        assert_eq!(
+            config.retry,
+            Some(RetryConfig {
+                max_retries: 3,
+                initial_backoff_ms: 100,
+                max_backoff_ms: 2000,
+            })
+        );
+        assert_eq!(config.rate_limit.unwrap().capacity, 10);
+        assert_eq!(config.headers["X-Client-Name"], "my-app");
+    }
+
+    #[test]
+    fn test_from_toml_str_invalid() {
+        assert!(HorizonClientConfig::from_toml_str("timeout_ms = 30000").is_err());
+        assert!(HorizonClientConfig::from_toml_str(
+            "base_url = \"https://horizon-testnet.stellar.org\"\nretries = 3"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let config = HorizonClientConfig::from_vars(vars(&[
+            ("HORIZON_BASE_URL", "https://horizon-testnet.stellar.org"),
+            ("HORIZON_TIMEOUT_MS", "30000"),
+            ("HORIZON_MAX_RETRIES", "2"),
+            ("HORIZON_RETRY_INITIAL_BACKOFF_MS", "50"),
+            ("HORIZON_RATE_LIMIT_CAPACITY", "10"),
+            ("HORIZON_HEADER_X_CLIENT_NAME", "my-app"),
+            ("PATH", "/usr/bin"),
+        ]))
+        .unwrap();
+
+        assert_eq!(config.base_url, "https://horizon-testnet.stellar.org");
+        assert_eq!(config.timeout_ms, Some(30000));
+        assert_eq!(
+            config.retry,
+            Some(RetryConfig {
+                max_retries: 2,
+                initial_backoff_ms: 50,
+                max_backoff_ms: 10_000,
+            })
+        );
+        // The rate limit requires both the capacity and the refill rate.
+        assert_eq!(config.rate_limit, None);
+        assert_eq!(
+            config.headers,
+            BTreeMap::from([("x-client-name".to_string(), "my-app".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_from_vars_invalid() {
+        assert_eq!(
+            HorizonClientConfig::from_vars(vars(&[])).unwrap_err(),
+            "HORIZON_BASE_URL is not set"
+        );
+        assert_eq!(
+            HorizonClientConfig::from_vars(vars(&[
+                ("HORIZON_BASE_URL", "https://horizon-testnet.stellar.org"),
+                ("HORIZON_TIMEOUT_MS", "soon"),
+            ]))
+            .unwrap_err(),
+            "Invalid value of HORIZON_TIMEOUT_MS: soon"
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use derive_getters::Getters;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::collections::{HashMap, VecDeque};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The time for which resolved addresses are reused before the host is resolved again.
+const DNS_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// The round-trip latency from which a ping classifies the Horizon server as
+/// [`Health::Degraded`].
+pub const DEGRADED_LATENCY: Duration = Duration::from_secs(1);
+
+/// The resolved addresses per host, along with the time at which they were resolved.
+type DnsCache = Mutex<HashMap<String, (Instant, Vec<SocketAddr>)>>;
+
+/// Represents statistics about the connections of a
+/// [`HorizonClient`](crate::horizon_client::HorizonClient).
+///
+/// The client keeps connections to the Horizon server open, and reuses them for subsequent
+/// requests, which saves the DNS lookup and the TCP and TLS handshakes. These statistics show how
+/// effective this is, for example to verify that
+/// [`HorizonClient::warm_up`](crate::horizon_client::HorizonClient::warm_up) established a
+/// connection before a latency-critical request.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// horizon_client.warm_up().await?;
+///
+/// let stats = horizon_client.connection_stats();
+/// println!(
+///     "{} requests over {} connections",
+///     stats.requests(),
+///     stats.connections()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Getters)]
+pub struct ConnectionStats {
+    /// The number of requests sent by the client.
+    requests: u64,
+    /// The number of new connections opened by the client.
+    connections: u64,
+    /// The number of new connections for which the address of the host was already resolved.
+    dns_cache_hits: u64,
+}
+
+impl ConnectionStats {
+    /// Returns the number of requests that were sent over an existing connection.
+    pub fn reused_connections(&self) -> u64 {
+        self.requests.saturating_sub(self.connections)
+    }
+}
+
+/// Represents the health of a Horizon server, as classified by a ping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Health {
+    /// The server responded successfully, within [`DEGRADED_LATENCY`].
+    Healthy,
+    /// The server responded successfully, but took [`DEGRADED_LATENCY`] or longer, or it rejected
+    /// the request because the rate limit is exhausted.
+    Degraded,
+    /// The server responded with an error status, such as `503 Service Unavailable`.
+    Unhealthy,
+}
+
+/// Represents the outcome of a ping of a Horizon server, as returned by
+/// [`HorizonClient::ping`](crate::horizon_client::HorizonClient::ping).
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::connection::Health;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+///
+/// let ping = horizon_client.ping().await?;
+/// if *ping.health() != Health::Healthy {
+///     eprintln!("Horizon is {:?}, with a latency of {:?}", ping.health(), ping.latency());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters)]
+pub struct Ping {
+    /// The time from sending the request until the headers of the response were received.
+    latency: Duration,
+    /// The HTTP status of the response.
+    status: u16,
+    /// The health of the server, derived from the status and the latency.
+    health: Health,
+}
+
+impl Ping {
+    /// Classifies the response to a ping.
+    pub(crate) fn new(status: reqwest::StatusCode, latency: Duration) -> Self {
+        let health = if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Health::Degraded
+        } else if !status.is_success() {
+            Health::Unhealthy
+        } else if latency >= DEGRADED_LATENCY {
+            Health::Degraded
+        } else {
+            Health::Healthy
+        };
+
+        Ping {
+            latency,
+            status: status.as_u16(),
+            health,
+        }
+    }
+}
+
+/// Keeps the latencies of the most recent pings of a Horizon server, and reports their
+/// percentiles.
+///
+/// A `LatencyMonitor` is filled by the stream of
+/// [`HorizonClient::monitor_latency`](crate::horizon_client::HorizonClient::monitor_latency),
+/// which is typically consumed by a background task. The monitor can be cloned, and every clone
+/// reports the same latencies, so that they can be read from anywhere, such as a metrics endpoint.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::connection::LatencyMonitor;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use futures::StreamExt;
+/// # use std::time::Duration;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// let monitor = LatencyMonitor::new(100);
+///
+/// let pings = horizon_client.monitor_latency(&monitor, Duration::from_secs(10));
+/// futures::pin_mut!(pings);
+/// while let Some(ping) = pings.next().await {
+///     println!(
+///         "{:?}, median {:?}, p99 {:?}",
+///         ping?.latency(),
+///         monitor.percentile(50.0),
+///         monitor.percentile(99.0)
+///     );
+/// #   break;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct LatencyMonitor {
+    /// The number of most recent latencies that are kept.
+    window: usize,
+    /// The most recent latencies, oldest first.
+    latencies: Arc<Mutex<VecDeque<Duration>>>,
+}
+
+impl LatencyMonitor {
+    /// Creates a monitor that keeps the latencies of the given number of most recent pings. A
+    /// window of `0` is treated as `1`.
+    pub fn new(window: usize) -> Self {
+        let window = window.max(1);
+        LatencyMonitor {
+            window,
+            latencies: Arc::new(Mutex::new(VecDeque::with_capacity(window))),
+        }
+    }
+
+    /// Records the latency of a ping, forgetting the oldest latency if the window is full.
+    pub(crate) fn record(&self, latency: Duration) {
+        let mut latencies = self.latencies.lock().unwrap_or_else(|e| e.into_inner());
+        if latencies.len() == self.window {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+
+    /// Returns the number of latencies in the window.
+    pub fn len(&self) -> usize {
+        self.latencies
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// Returns whether no latency has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the latency of the most recent ping, if any.
+    pub fn latest(&self) -> Option<Duration> {
+        let latencies = self.latencies.lock().unwrap_or_else(|e| e.into_inner());
+        latencies.back().copied()
+    }
+
+    /// Returns the given percentile of the latencies in the window, using the nearest-rank
+    /// method, or `None` if no latency has been recorded yet.
+    ///
+    /// # Arguments
+    /// * `percentile` - The percentile, from `0.0` to `100.0`, such as `99.0`. Values outside this
+    ///   range are clamped.
+    ///
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        let mut latencies: Vec<Duration> = {
+            let latencies = self.latencies.lock().unwrap_or_else(|e| e.into_inner());
+            latencies.iter().copied().collect()
+        };
+        if latencies.is_empty() {
+            return None;
+        }
+        latencies.sort();
+
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * latencies.len() as f64).ceil() as usize;
+        Some(latencies[rank.clamp(1, latencies.len()) - 1])
+    }
+}
+
+/// Represents a content encoding in which the Horizon server may compress response bodies.
+///
+/// By default, the [`HorizonClient`](crate::horizon_client::HorizonClient) accepts all of these
+/// encodings through the `Accept-Encoding` header, and transparently decompresses the responses.
+/// Compressed responses are considerably smaller, which matters when paging through large amounts
+/// of history. The accepted encodings can be changed using
+/// [`HorizonClient::set_accepted_encodings`](crate::horizon_client::HorizonClient::set_accepted_encodings).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentEncoding {
+    /// The `gzip` encoding.
+    Gzip,
+    /// The `br` (Brotli) encoding.
+    Brotli,
+    /// The `deflate` encoding.
+    Deflate,
+}
+
+impl ContentEncoding {
+    /// All content encodings supported by the client, which are accepted by default.
+    pub const ALL: [ContentEncoding; 3] = [
+        ContentEncoding::Gzip,
+        ContentEncoding::Brotli,
+        ContentEncoding::Deflate,
+    ];
+}
+
+/// A DNS resolver that caches resolved addresses, and counts the connections it resolves for.
+///
+/// The HTTP client only resolves the host when it opens a new connection, so the number of
+/// resolutions equals the number of connections opened.
+#[derive(Default)]
+pub(crate) struct CachingResolver {
+    /// The resolved addresses per host, along with the time at which they were resolved.
+    cache: Arc<DnsCache>,
+    /// The number of requests sent by the client.
+    requests: AtomicU64,
+    /// The number of connections opened by the client.
+    connections: AtomicU64,
+    /// The number of connections for which the addresses were taken from the cache.
+    dns_cache_hits: AtomicU64,
+}
+
+impl CachingResolver {
+    /// Resolves a host and stores its addresses in the cache, so that the next connection to the
+    /// host does not need a DNS lookup.
+    pub(crate) async fn pre_resolve(&self, host: &str) -> Result<(), String> {
+        lookup(&self.cache, host).await.map(|_| ())
+    }
+
+    /// Records that a request is sent.
+    pub(crate) fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the statistics about the connections resolved so far.
+    pub(crate) fn stats(&self) -> ConnectionStats {
+        ConnectionStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            connections: self.connections.load(Ordering::Relaxed),
+            dns_cache_hits: self.dns_cache_hits.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the cached addresses of a host, if they have not expired.
+    fn cached(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .get(host)
+            .filter(|(resolved_at, _)| resolved_at.elapsed() < DNS_CACHE_TTL)
+            .map(|(_, addresses)| addresses.clone())
+    }
+}
+
+impl Resolve for CachingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        self.connections.fetch_add(1, Ordering::Relaxed);
+        let host = name.as_str().to_string();
+
+        if let Some(addresses) = self.cached(&host) {
+            self.dns_cache_hits.fetch_add(1, Ordering::Relaxed);
+            return Box::pin(async move { Ok(Box::new(addresses.into_iter()) as Addrs) });
+        }
+
+        let cache = self.cache.clone();
+        Box::pin(async move {
+            let addresses = lookup(&cache, &host).await?;
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Looks up the addresses of a host, and stores them in the cache.
+///
+/// The lookup of the operating system blocks, so it runs on a thread of its own, which works the
+/// same on every async runtime.
+async fn lookup(cache: &DnsCache, host: &str) -> Result<Vec<SocketAddr>, String> {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    let name = host.to_string();
+    std::thread::spawn(move || {
+        // The port is replaced by the port of the URL that is requested.
+        let _ = sender.send((name.as_str(), 0).to_socket_addrs());
+    });
+
+    let addresses: Vec<SocketAddr> = receiver
+        .await
+        .map_err(|_| format!("Failed to resolve {}: the lookup was aborted", host))?
+        .map_err(|e| format!("Failed to resolve {}: {}", host, e))?
+        .collect();
+
+    cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(host.to_string(), (Instant::now(), addresses.clone()));
+
+    Ok(addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_caching_resolver() {
+        let resolver = Arc::new(CachingResolver::default());
+        resolver.pre_resolve("localhost").await.unwrap();
+
+        let addresses: Vec<SocketAddr> = resolver
+            .resolve(Name::from_str("localhost").unwrap())
+            .await
+            .unwrap()
+            .collect();
+        assert!(addresses.iter().all(|address| address.ip().is_loopback()));
+
+        resolver.record_request();
+        resolver.record_request();
+        let stats = resolver.stats();
+        assert_eq!(stats.connections(), &1);
+        assert_eq!(stats.dns_cache_hits(), &1);
+        assert_eq!(stats.reused_connections(), 1);
+    }
+
+    #[test]
+    fn test_ping_health() {
+        let fast = Duration::from_millis(80);
+        let slow = DEGRADED_LATENCY;
+
+        assert_eq!(
+            Ping::new(reqwest::StatusCode::OK, fast).health(),
+            &Health::Healthy
+        );
+        assert_eq!(
+            Ping::new(reqwest::StatusCode::OK, slow).health(),
+            &Health::Degraded
+        );
+        assert_eq!(
+            Ping::new(reqwest::StatusCode::TOO_MANY_REQUESTS, fast).health(),
+            &Health::Degraded
+        );
+        let ping = Ping::new(reqwest::StatusCode::SERVICE_UNAVAILABLE, fast);
+        assert_eq!(ping.health(), &Health::Unhealthy);
+        assert_eq!(ping.status(), &503);
+    }
+
+    #[test]
+    fn test_latency_monitor() {
+        let monitor = LatencyMonitor::new(4);
+        assert_eq!(monitor.percentile(50.0), None);
+
+        for millis in [500, 10, 40, 20, 30] {
+            monitor.clone().record(Duration::from_millis(millis));
+        }
+
+        // The oldest latency has left the window.
+        assert_eq!(monitor.len(), 4);
+        assert_eq!(monitor.latest(), Some(Duration::from_millis(30)));
+        assert_eq!(monitor.percentile(0.0), Some(Duration::from_millis(10)));
+        assert_eq!(monitor.percentile(50.0), Some(Duration::from_millis(20)));
+        assert_eq!(monitor.percentile(99.0), Some(Duration::from_millis(40)));
+    }
+}
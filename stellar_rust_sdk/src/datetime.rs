@@ -0,0 +1,120 @@
+/// The type of the dates and times in the requests and responses of this crate.
+///
+/// With the `chrono` feature, which is enabled by default, this is a
+/// [`chrono::DateTime<Utc>`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html).
+/// With only the `time` feature enabled, this is a
+/// [`time::OffsetDateTime`](https://docs.rs/time/latest/time/struct.OffsetDateTime.html) with a
+/// UTC offset. If both features are enabled, `chrono` takes precedence.
+///
+#[cfg(feature = "chrono")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// The type of the dates and times in the requests and responses of this crate.
+///
+/// With the `chrono` feature, which is enabled by default, this is a
+/// [`chrono::DateTime<Utc>`](https://docs.rs/chrono/latest/chrono/struct.DateTime.html).
+/// With only the `time` feature enabled, this is a
+/// [`time::OffsetDateTime`](https://docs.rs/time/latest/time/struct.OffsetDateTime.html) with a
+/// UTC offset. If both features are enabled, `chrono` takes precedence.
+///
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type DateTime = time::OffsetDateTime;
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+compile_error!("Either the `chrono` or the `time` feature of `stellar-rs` must be enabled.");
+
+/// Creates a date and time from the number of milliseconds since the Unix epoch, or returns
+/// `None` if it is out of range.
+#[cfg(feature = "chrono")]
+pub(crate) fn from_unix_millis(millis: i64) -> Option<DateTime> {
+    DateTime::from_timestamp_millis(millis)
+}
+
+/// Creates a date and time from the number of milliseconds since the Unix epoch, or returns
+/// `None` if it is out of range.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn from_unix_millis(millis: i64) -> Option<DateTime> {
+    DateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000).ok()
+}
+
+/// Returns the number of milliseconds since the Unix epoch of a date and time.
+#[cfg(feature = "chrono")]
+pub(crate) fn unix_millis(date_time: &DateTime) -> i64 {
+    date_time.timestamp_millis()
+}
+
+/// Returns the number of milliseconds since the Unix epoch of a date and time.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn unix_millis(date_time: &DateTime) -> i64 {
+    // The range of an `OffsetDateTime` fits in an `i64` of milliseconds.
+    (date_time.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// Parses an RFC 3339 timestamp, such as `2024-06-01T12:00:00Z`, into a date and time in UTC.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_rfc3339(timestamp: &str) -> Result<DateTime, String> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|date_time| date_time.with_timezone(&chrono::Utc))
+        .map_err(|e| e.to_string())
+}
+
+/// Parses an RFC 3339 timestamp, such as `2024-06-01T12:00:00Z`, into a date and time in UTC.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub(crate) fn parse_rfc3339(timestamp: &str) -> Result<DateTime, String> {
+    DateTime::parse(timestamp, &time::format_description::well_known::Rfc3339)
+        .map(|date_time| date_time.to_offset(time::UtcOffset::UTC))
+        .map_err(|e| e.to_string())
+}
+
+/// Serializes and deserializes a date and time as an RFC 3339 timestamp, which is the format
+/// Horizon uses. To be used with `#[serde(with = "crate::datetime::rfc3339")]`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) mod rfc3339 {
+    use super::DateTime;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes a date and time as an RFC 3339 timestamp in UTC.
+    pub fn serialize<S: Serializer>(
+        date_time: &DateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        #[cfg(feature = "chrono")]
+        let timestamp = date_time.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true);
+        #[cfg(all(feature = "time", not(feature = "chrono")))]
+        let timestamp = date_time
+            .format(&time::format_description::well_known::Rfc3339)
+            .map_err(serde::ser::Error::custom)?;
+
+        serializer.serialize_str(&timestamp)
+    }
+
+    /// Deserializes an RFC 3339 timestamp into a date and time in UTC.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        let timestamp = String::deserialize(deserializer)?;
+        super::parse_rfc3339(&timestamp).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc3339() {
+        let date_time = parse_rfc3339("2024-06-01T12:00:00.5+02:00").unwrap();
+        assert_eq!(unix_millis(&date_time), 1_717_236_000_500);
+        assert_eq!(from_unix_millis(1_717_236_000_500), Some(date_time));
+        assert!(parse_rfc3339("2024-06-01").is_err());
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Record {
+            #[serde(with = "rfc3339")]
+            closed_at: DateTime,
+        }
+
+        let json = r#"{"closed_at":"2024-06-01T10:00:00Z"}"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        assert_eq!(unix_millis(&record.closed_at), 1_717_236_000_000);
+        assert_eq!(serde_json::to_string(&record).unwrap(), json);
+    }
+}
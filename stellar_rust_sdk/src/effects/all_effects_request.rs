@@ -1,5 +1,5 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to fetch effect data from the Stellar Horizon API.
 ///
@@ -26,9 +26,11 @@ use stellar_rust_sdk_derive::pagination;
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::EFFECTS_PATH)]
 pub struct AllEffectsRequest {
-    // All fields are injected by the `pagination` macro.
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllEffectsRequest {
@@ -38,26 +40,6 @@ impl AllEffectsRequest {
     }
 }
 
-impl Request for AllEffectsRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::EFFECTS_PATH,
-            self.get_query_parameters()
-        )
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
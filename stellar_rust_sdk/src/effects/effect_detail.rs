@@ -0,0 +1,592 @@
+use derive_getters::Getters;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::response::EffectLiquidityPool;
+use crate::claimable_balances::prelude::Predicate;
+use crate::liquidity_pools::prelude::Reserve;
+use crate::models::prelude::AssetType;
+
+/// Defines the `EffectDetail` enum, along with the names of its variants, from a single list.
+macro_rules! effect_details {
+    ($($(#[$doc:meta])* $variant:ident $(($detail:ty))? = $name:literal;)+) => {
+        /// Represents the fields of an effect that depend on its type, with a variant per effect
+        /// type.
+        ///
+        /// The variant is selected by the `type` field of the effect, such as `account_credited`,
+        /// and its fields are those Horizon returns for effects of that type. Effects of types
+        /// that this version of the SDK does not know yet deserialize into
+        /// [`EffectDetail::Other`], which keeps their fields as JSON, so that a protocol upgrade
+        /// does not break the deserialization of pages of effects. Effects of known types that
+        /// lack a field of their type fail to deserialize.
+        ///
+        /// # Example
+        /// ```
+        /// use stellar_rs::effects::prelude::*;
+        ///
+        /// # fn print(effect: &Effect) {
+        /// match effect.detail() {
+        ///     EffectDetail::AccountCredited(credit) => {
+        ///         println!("{} received {}", effect.account(), credit.amount());
+        ///     }
+        ///     EffectDetail::Trade(trade) => {
+        ///         println!("{} sold {} to {}", effect.account(), trade.sold_amount(), trade.seller());
+        ///     }
+        ///     other => println!("{} effect", other.type_name()),
+        /// }
+        /// # }
+        /// ```
+        ///
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[serde(remote = "Self", tag = "type")]
+        #[non_exhaustive]
+        pub enum EffectDetail {
+            $($(#[$doc])* #[serde(rename = $name)] $variant $(($detail))?,)+
+            /// An effect of a type that this version of the SDK does not know yet. Contains all
+            /// fields of the effect that are not common to all effects, including its `type`.
+            #[serde(untagged, skip_deserializing)]
+            Other(Map<String, Value>),
+        }
+
+        impl EffectDetail {
+            /// Returns the type of the effect, as found in its `type` field, such as
+            /// `account_credited`.
+            pub fn type_name(&self) -> &str {
+                match self {
+                    $(EffectDetail::$variant { .. } => $name,)+
+                    EffectDetail::Other(fields) => fields
+                        .get("type")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default(),
+                }
+            }
+
+            /// Returns whether the SDK knows effects of the type with the given name.
+            fn is_known_type(name: &str) -> bool {
+                matches!(name, $($name)|+)
+            }
+        }
+    };
+}
+
+effect_details! {
+    /// A new account was created and funded.
+    AccountCreated(AccountCreatedEffect) = "account_created";
+    /// The account was merged into another account, and removed.
+    AccountRemoved = "account_removed";
+    /// The account received an amount of an asset.
+    AccountCredited(BalanceChangeEffect) = "account_credited";
+    /// The account sent an amount of an asset.
+    AccountDebited(BalanceChangeEffect) = "account_debited";
+    /// The thresholds of the account changed.
+    AccountThresholdsUpdated(ThresholdsUpdatedEffect) = "account_thresholds_updated";
+    /// The home domain of the account changed.
+    AccountHomeDomainUpdated(HomeDomainUpdatedEffect) = "account_home_domain_updated";
+    /// The authorization flags of the account changed.
+    AccountFlagsUpdated(AccountFlagsUpdatedEffect) = "account_flags_updated";
+    /// The inflation destination of the account changed.
+    AccountInflationDestinationUpdated = "account_inflation_destination_updated";
+    /// A signer was added to the account.
+    SignerCreated(SignerEffect) = "signer_created";
+    /// A signer was removed from the account.
+    SignerRemoved(SignerEffect) = "signer_removed";
+    /// The weight of a signer of the account changed.
+    SignerUpdated(SignerEffect) = "signer_updated";
+    /// The account added a trustline.
+    TrustlineCreated(TrustlineEffect) = "trustline_created";
+    /// The account removed a trustline.
+    TrustlineRemoved(TrustlineEffect) = "trustline_removed";
+    /// The limit of a trustline of the account changed.
+    TrustlineUpdated(TrustlineEffect) = "trustline_updated";
+    /// The issuer authorized a trustline. Superseded by `trustline_flags_updated`.
+    TrustlineAuthorized(TrustlineAuthorizationEffect) = "trustline_authorized";
+    /// The issuer revoked the authorization of a trustline. Superseded by
+    /// `trustline_flags_updated`.
+    TrustlineDeauthorized(TrustlineAuthorizationEffect) = "trustline_deauthorized";
+    /// The issuer authorized a trustline to maintain its liabilities only. Superseded by
+    /// `trustline_flags_updated`.
+    TrustlineAuthorizedToMaintainLiabilities(TrustlineAuthorizationEffect) =
+        "trustline_authorized_to_maintain_liabilities";
+    /// The issuer changed the flags of a trustline.
+    TrustlineFlagsUpdated(TrustlineFlagsUpdatedEffect) = "trustline_flags_updated";
+    /// An offer was created. Horizon does not produce this effect.
+    OfferCreated = "offer_created";
+    /// An offer was removed. Horizon does not produce this effect.
+    OfferRemoved = "offer_removed";
+    /// An offer was updated. Horizon does not produce this effect.
+    OfferUpdated = "offer_updated";
+    /// The account traded with an offer.
+    Trade(TradeEffect) = "trade";
+    /// A data entry was added to the account.
+    DataCreated(DataEffect) = "data_created";
+    /// A data entry was removed from the account.
+    DataRemoved(DataEffect) = "data_removed";
+    /// The value of a data entry of the account changed.
+    DataUpdated(DataEffect) = "data_updated";
+    /// The sequence number of the account was bumped.
+    SequenceBumped(SequenceBumpedEffect) = "sequence_bumped";
+    /// The account created a claimable balance.
+    ClaimableBalanceCreated(ClaimableBalanceEffect) = "claimable_balance_created";
+    /// The account was made a claimant of a claimable balance.
+    ClaimableBalanceClaimantCreated(ClaimableBalanceEffect) =
+        "claimable_balance_claimant_created";
+    /// The account claimed a claimable balance.
+    ClaimableBalanceClaimed(ClaimableBalanceEffect) = "claimable_balance_claimed";
+    /// The account started sponsoring the reserve of an account.
+    AccountSponsorshipCreated(SponsorshipEffect) = "account_sponsorship_created";
+    /// The sponsorship of the reserve of the account was transferred.
+    AccountSponsorshipUpdated(SponsorshipEffect) = "account_sponsorship_updated";
+    /// The sponsorship of the reserve of the account was revoked.
+    AccountSponsorshipRemoved(SponsorshipEffect) = "account_sponsorship_removed";
+    /// The reserve of a trustline of the account was sponsored.
+    TrustlineSponsorshipCreated(SponsorshipEffect) = "trustline_sponsorship_created";
+    /// The sponsorship of the reserve of a trustline was transferred.
+    TrustlineSponsorshipUpdated(SponsorshipEffect) = "trustline_sponsorship_updated";
+    /// The sponsorship of the reserve of a trustline was revoked.
+    TrustlineSponsorshipRemoved(SponsorshipEffect) = "trustline_sponsorship_removed";
+    /// The reserve of a data entry of the account was sponsored.
+    DataSponsorshipCreated(SponsorshipEffect) = "data_sponsorship_created";
+    /// The sponsorship of the reserve of a data entry was transferred.
+    DataSponsorshipUpdated(SponsorshipEffect) = "data_sponsorship_updated";
+    /// The sponsorship of the reserve of a data entry was revoked.
+    DataSponsorshipRemoved(SponsorshipEffect) = "data_sponsorship_removed";
+    /// The reserve of a claimable balance was sponsored.
+    ClaimableBalanceSponsorshipCreated(SponsorshipEffect) =
+        "claimable_balance_sponsorship_created";
+    /// The sponsorship of the reserve of a claimable balance was transferred.
+    ClaimableBalanceSponsorshipUpdated(SponsorshipEffect) =
+        "claimable_balance_sponsorship_updated";
+    /// The sponsorship of the reserve of a claimable balance was revoked.
+    ClaimableBalanceSponsorshipRemoved(SponsorshipEffect) =
+        "claimable_balance_sponsorship_removed";
+    /// The reserve of a signer of the account was sponsored.
+    SignerSponsorshipCreated(SponsorshipEffect) = "signer_sponsorship_created";
+    /// The sponsorship of the reserve of a signer was transferred.
+    SignerSponsorshipUpdated(SponsorshipEffect) = "signer_sponsorship_updated";
+    /// The sponsorship of the reserve of a signer was revoked.
+    SignerSponsorshipRemoved(SponsorshipEffect) = "signer_sponsorship_removed";
+    /// The issuer clawed back a claimable balance.
+    ClaimableBalanceClawedBack(ClaimableBalanceClawedBackEffect) =
+        "claimable_balance_clawed_back";
+    /// The account deposited reserves into a liquidity pool.
+    LiquidityPoolDeposited(LiquidityPoolDepositedEffect) = "liquidity_pool_deposited";
+    /// The account withdrew reserves from a liquidity pool.
+    LiquidityPoolWithdrew(LiquidityPoolWithdrewEffect) = "liquidity_pool_withdrew";
+    /// A liquidity pool traded one reserve for the other.
+    LiquidityPoolTrade(LiquidityPoolTradeEffect) = "liquidity_pool_trade";
+    /// A liquidity pool was created.
+    LiquidityPoolCreated(LiquidityPoolEffect) = "liquidity_pool_created";
+    /// A liquidity pool was removed.
+    LiquidityPoolRemoved(LiquidityPoolRemovedEffect) = "liquidity_pool_removed";
+    /// The pool shares of the account were revoked, because the issuer of a reserve revoked the
+    /// authorization of the account.
+    LiquidityPoolRevoked(LiquidityPoolRevokedEffect) = "liquidity_pool_revoked";
+    /// A contract received an amount of a Stellar asset.
+    ContractCredited(ContractBalanceChangeEffect) = "contract_credited";
+    /// A contract sent an amount of a Stellar asset.
+    ContractDebited(ContractBalanceChangeEffect) = "contract_debited";
+}
+
+impl Serialize for EffectDetail {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EffectDetail::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EffectDetail {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Map::deserialize(deserializer)?;
+        let is_known = fields
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(EffectDetail::is_known_type);
+        if !is_known {
+            return Ok(EffectDetail::Other(fields));
+        }
+
+        EffectDetail::deserialize(Value::Object(fields)).map_err(de::Error::custom)
+    }
+}
+
+/// The fields of an `account_created` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct AccountCreatedEffect {
+    /// The amount of lumens the new account was funded with.
+    pub starting_balance: String,
+}
+
+/// The fields of an `account_credited` or `account_debited` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct BalanceChangeEffect {
+    /// The type of the asset, such as `native` or `credit_alphanum4`.
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The amount that was received or sent.
+    pub amount: String,
+}
+
+impl BalanceChangeEffect {
+    /// Returns the asset that was received or sent.
+    pub fn asset(&self) -> Result<AssetType, String> {
+        AssetType::from_horizon(
+            &self.asset_type,
+            self.asset_code.as_deref(),
+            self.asset_issuer.as_deref(),
+        )
+    }
+}
+
+/// The fields of an `account_thresholds_updated` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ThresholdsUpdatedEffect {
+    pub low_threshold: u32,
+    pub med_threshold: u32,
+    pub high_threshold: u32,
+}
+
+/// The fields of an `account_home_domain_updated` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct HomeDomainUpdatedEffect {
+    pub home_domain: String,
+}
+
+/// The fields of an `account_flags_updated` effect. Only the flags that changed are present.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct AccountFlagsUpdatedEffect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_required_flag: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_revokable_flag: Option<bool>,
+}
+
+/// The fields of a `signer_created`, `signer_removed` or `signer_updated` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct SignerEffect {
+    /// The weight of the signer, which is `0` if the signer was removed.
+    pub weight: u32,
+    /// The public key of the signer. Deprecated in favor of `key`.
+    pub public_key: String,
+    /// The key of the signer, such as a public key or the hash of a pre-authorized transaction.
+    #[serde(default)]
+    pub key: String,
+}
+
+/// The fields of a `trustline_created`, `trustline_removed` or `trustline_updated` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct TrustlineEffect {
+    /// The type of the asset of the trustline, which is `liquidity_pool_shares` for a trustline
+    /// to the shares of a liquidity pool.
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The liquidity pool of a trustline to the shares of a liquidity pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity_pool_id: Option<String>,
+    /// The limit of the trustline.
+    pub limit: String,
+}
+
+/// The fields of a `trustline_authorized`, `trustline_deauthorized` or
+/// `trustline_authorized_to_maintain_liabilities` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct TrustlineAuthorizationEffect {
+    /// The account that holds the trustline.
+    pub trustor: String,
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+}
+
+/// The fields of a `trustline_flags_updated` effect. Only the flags that changed are present.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct TrustlineFlagsUpdatedEffect {
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The account that holds the trustline.
+    pub trustor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized_flag: Option<bool>,
+    // Horizon misspells the name of this field.
+    #[serde(
+        rename = "authorized_to_maintain_liabilites_flag",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub authorized_to_maintain_liabilities_flag: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clawback_enabled_flag: Option<bool>,
+}
+
+/// The fields of a `trade` effect, from the point of view of the account of the effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct TradeEffect {
+    /// The account that owned the other side of the trade.
+    pub seller: String,
+    /// The ID of the offer that was traded with.
+    pub offer_id: String,
+    /// The amount the account sold.
+    pub sold_amount: String,
+    pub sold_asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sold_asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sold_asset_issuer: Option<String>,
+    /// The amount the account bought.
+    pub bought_amount: String,
+    pub bought_asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bought_asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bought_asset_issuer: Option<String>,
+}
+
+/// The fields of a `data_created`, `data_removed` or `data_updated` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct DataEffect {
+    /// The name of the data entry.
+    pub name: String,
+    /// The base64-encoded value of the data entry. Not present on `data_removed` effects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// The fields of a `sequence_bumped` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct SequenceBumpedEffect {
+    /// The new sequence number of the account.
+    pub new_seq: String,
+}
+
+/// The fields of a `claimable_balance_created`, `claimable_balance_claimant_created` or
+/// `claimable_balance_claimed` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ClaimableBalanceEffect {
+    /// The asset of the claimable balance, such as `native` or `USDC:G...`.
+    pub asset: String,
+    /// The ID of the claimable balance.
+    pub balance_id: String,
+    /// The amount of the claimable balance.
+    pub amount: String,
+    /// The conditions under which the account can claim the balance. Only present on
+    /// `claimable_balance_claimant_created` effects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<Predicate>,
+}
+
+/// The fields of a sponsorship effect, such as `account_sponsorship_created`.
+///
+/// A sponsorship that was created has a `sponsor`, one that was transferred has a
+/// `former_sponsor` and a `new_sponsor`, and one that was revoked has a `former_sponsor`. The
+/// sponsored entry is identified by the fields that match the type of the effect, such as the
+/// `signer` of a signer sponsorship.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct SponsorshipEffect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sponsor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub former_sponsor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_sponsor: Option<String>,
+    /// The type of the asset of a sponsored trustline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_type: Option<String>,
+    /// The asset of a sponsored trustline, such as `USDC:G...`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+    /// The liquidity pool of a sponsored trustline to pool shares.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity_pool_id: Option<String>,
+    /// The name of a sponsored data entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_name: Option<String>,
+    /// The ID of a sponsored claimable balance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_id: Option<String>,
+    /// The key of a sponsored signer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+}
+
+/// The fields of a `claimable_balance_clawed_back` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ClaimableBalanceClawedBackEffect {
+    /// The ID of the claimable balance that was clawed back.
+    pub balance_id: String,
+}
+
+/// The fields of a `liquidity_pool_deposited` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolDepositedEffect {
+    /// The liquidity pool, after the deposit.
+    pub liquidity_pool: EffectLiquidityPool,
+    /// The reserves that were deposited into the liquidity pool.
+    pub reserves_deposited: Vec<Reserve>,
+    /// The number of pool shares received for the deposit.
+    pub shares_received: String,
+}
+
+/// The fields of a `liquidity_pool_withdrew` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolWithdrewEffect {
+    /// The liquidity pool, after the withdrawal.
+    pub liquidity_pool: EffectLiquidityPool,
+    /// The reserves that were withdrawn from the liquidity pool.
+    pub reserves_received: Vec<Reserve>,
+    /// The number of pool shares redeemed for the withdrawal.
+    pub shares_redeemed: String,
+}
+
+/// The fields of a `liquidity_pool_trade` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolTradeEffect {
+    /// The liquidity pool, after the trade.
+    pub liquidity_pool: EffectLiquidityPool,
+    /// The reserve that the liquidity pool sold.
+    pub sold: Reserve,
+    /// The reserve that the liquidity pool bought.
+    pub bought: Reserve,
+}
+
+/// The fields of a `liquidity_pool_created` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolEffect {
+    /// The liquidity pool that was created.
+    pub liquidity_pool: EffectLiquidityPool,
+}
+
+/// The fields of a `liquidity_pool_removed` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolRemovedEffect {
+    /// The ID of the liquidity pool that was removed.
+    pub liquidity_pool_id: String,
+}
+
+/// The fields of a `liquidity_pool_revoked` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolRevokedEffect {
+    /// The liquidity pool, after the revocation.
+    pub liquidity_pool: EffectLiquidityPool,
+    /// The reserves that were moved into claimable balances for the account.
+    pub reserves_revoked: Vec<RevokedReserve>,
+    /// The number of pool shares that were revoked.
+    pub shares_revoked: String,
+}
+
+/// A reserve of a liquidity pool that was moved into a claimable balance, because the pool
+/// shares of an account were revoked.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct RevokedReserve {
+    /// The asset of the reserve, such as `native` or `USDC:G...`.
+    pub asset: String,
+    /// The amount of the reserve.
+    pub amount: String,
+    /// The ID of the claimable balance the reserve was moved into.
+    pub claimable_balance_id: String,
+}
+
+/// The fields of a `contract_credited` or `contract_debited` effect.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ContractBalanceChangeEffect {
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The contract that received or sent the asset.
+    pub contract: String,
+    /// The amount that was received or sent.
+    pub amount: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effects::prelude::{Effect, EffectsResponse};
+    use serde_json::json;
+
+    /// Returns the first effect of the effects fixture, an `account_created` effect, with its
+    /// type-specific fields replaced by the given fields.
+    fn effect(fields: Value) -> Effect {
+        let json = include_str!("../../tests/fixtures/effects/effects_for_account.json");
+        let value: Value = serde_json::from_str(json).unwrap();
+        let mut value = value["_embedded"]["records"][0].clone();
+        let object = value.as_object_mut().unwrap();
+        for field in ["type", "starting_balance"] {
+            object.remove(field);
+        }
+        object.extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_effect_detail() {
+        let trade = effect(json!({
+            "type": "trade",
+            "seller": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+            "offer_id": "12345",
+            "sold_amount": "10.0000000",
+            "sold_asset_type": "native",
+            "bought_amount": "2.5000000",
+            "bought_asset_type": "credit_alphanum4",
+            "bought_asset_code": "USDC",
+            "bought_asset_issuer": "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+        }));
+        let EffectDetail::Trade(detail) = trade.detail() else {
+            panic!("expected a trade effect");
+        };
+        assert_eq!(detail.offer_id(), "12345");
+        assert_eq!(detail.bought_asset_code().as_deref(), Some("USDC"));
+        assert_eq!(trade.effect_type(), "trade");
+        assert!(trade.typed_amount().is_none());
+
+        let account_removed = effect(json!({"type": "account_removed"}));
+        assert!(matches!(
+            account_removed.detail(),
+            EffectDetail::AccountRemoved
+        ));
+
+        // Effects of known types must have the fields of their type.
+        let json = include_str!("../../tests/fixtures/effects/effects_for_account.json");
+        let json = json.replacen("\"starting_balance\"", "\"balance\"", 1);
+        assert!(serde_json::from_str::<EffectsResponse>(&json).is_err());
+
+        // Effects of unknown types keep their fields.
+        let unknown = effect(json!({"type": "account_teleported", "destination": "moon"}));
+        assert_eq!(unknown.effect_type(), "account_teleported");
+        let value = serde_json::to_value(&unknown).unwrap();
+        assert_eq!(value["type"], "account_teleported");
+        assert_eq!(value["destination"], "moon");
+    }
+}
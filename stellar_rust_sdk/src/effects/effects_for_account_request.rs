@@ -1,8 +1,6 @@
-use crate::{
-    models::{Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::resolve_account_id;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the request to fetch effects for a specific account from the Horizon API.
 ///
@@ -35,10 +33,15 @@ use stellar_rust_sdk_derive::pagination;
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::EFFECTS_PATH)]
 pub struct EffectsForAccountRequest {
     /// The accounts public id
+    #[horizon(rename = "account")]
     account_id: Option<String>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl EffectsForAccountRequest {
@@ -50,40 +53,22 @@ impl EffectsForAccountRequest {
     /// Sets the account id for the request.
     ///
     /// # Arguments
-    /// * `account_id` - A `String` value representing the account id.
+    /// * `account_id` - A `String` value representing the account id. A muxed account (`M...`) is
+    ///   resolved to its underlying account.
     ///
     pub fn set_account_id(self, account_id: impl Into<String>) -> EffectsForAccountRequest {
         EffectsForAccountRequest {
-            account_id: Some(account_id.into()),
+            account_id: Some(resolve_account_id(account_id.into())),
             ..self
         }
     }
 }
 
-impl Request for EffectsForAccountRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.account_id.as_ref().map(|a| format!("account={}", a)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::EFFECTS_PATH,
-            self.get_query_parameters()
-        )
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Order;
+    use crate::models::Request;
 
     #[test]
     fn test_effects_for_account_request() {
@@ -1,8 +1,6 @@
-use crate::{
-    models::{Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::ledgers::{prelude::LedgerSequence, LEDGERS_PATH};
+use crate::models::pagination::PageParams;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to fetch effects associated with a specific ledger from the Stellar Horizon API.
 ///
@@ -16,17 +14,22 @@ use stellar_rust_sdk_derive::pagination;
 /// # use stellar_rs::models::Order;
 ///
 /// let mut request = EffectsForLedgerRequest::new()
-///     .set_sequence(&1000)
+///     .set_sequence(1000)
+///     .unwrap()
 ///     .set_limit(2);
 ///
 /// // The request is now ready to be used with a Horizon client to fetch effects for the specified ledger.
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::EFFECTS_PATH, parent = sequence, parent_path = LEDGERS_PATH)]
 pub struct EffectsForLedgerRequest {
     /// The ledger's sequence number for which effects are to be retrieved.
-    sequence: Option<u32>,
+    sequence: Option<LedgerSequence>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl EffectsForLedgerRequest {
@@ -38,52 +41,32 @@ impl EffectsForLedgerRequest {
     /// Sets the ledger sequence for the request.
     ///
     /// # Arguments
-    /// * `sequence` - A `String` value representing the ledger sequence.
+    /// * `sequence` - The sequence number of the ledger.
+    ///
+    /// # Returns
+    /// The `EffectsForLedgerRequest` with the ledger sequence set, or an error if the sequence is
+    /// `0`.
     ///
-    pub fn set_sequence(self, sequence: &u32) -> EffectsForLedgerRequest {
-        EffectsForLedgerRequest {
-            sequence: Some(*sequence),
+    pub fn set_sequence(self, sequence: u32) -> Result<EffectsForLedgerRequest, String> {
+        Ok(EffectsForLedgerRequest {
+            sequence: Some(LedgerSequence::new(sequence)?),
             ..self
-        }
-    }
-}
-
-impl Request for EffectsForLedgerRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        // Extract the sequence as a string if set
-        let seq = self
-            .sequence
-            .as_ref()
-            .map_or(String::new(), |s| s.to_string());
-
-        format!(
-            "{}/ledgers/{}/{}{}",
-            base_url,
-            seq,
-            super::EFFECTS_PATH,
-            self.get_query_parameters()
-        )
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Request;
 
     #[test]
     fn test_effects_for_ledger_request_build_url() {
         let sequence: u32 = 125;
 
-        let request = EffectsForLedgerRequest::new().set_sequence(&sequence);
+        let request = EffectsForLedgerRequest::new()
+            .set_sequence(sequence)
+            .unwrap();
 
         let url = request.build_url("https://horizon-testnet.stellar.org");
 
@@ -97,6 +80,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_effects_for_ledger_request_set_sequence() {
+        let request = EffectsForLedgerRequest::new().set_sequence(0);
+
+        assert!(request.is_err());
+    }
+
     #[test]
     fn test_effects_for_ledger_request_set_limit() {
         let invalid_limit: u8 = 255;
@@ -1,4 +1,5 @@
-use crate::models::{Order, Request};
+use crate::models::pagination::PageParams;
+use crate::models::Request;
 use crate::BuildQueryParametersExt;
 use stellar_rust_sdk_derive::pagination;
 
@@ -34,10 +35,13 @@ use stellar_rust_sdk_derive::pagination;
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash)]
 pub struct EffectsForLiquidityPoolRequest {
     /// The liquidity pool id
     liquidity_pool_id: Option<String>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl EffectsForLiquidityPoolRequest {
@@ -63,32 +67,42 @@ impl EffectsForLiquidityPoolRequest {
 }
 
 impl Request for EffectsForLiquidityPoolRequest {
-    //TODO research different url buildig methods
     fn get_query_parameters(&self) -> String {
-        vec![
-            self.liquidity_pool_id
-                .as_ref()
-                .map(|l| format!("liquidity_pool_id={}", l)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
+        [
+            self.page_params.cursor().map(|c| format!("cursor={}", c)),
+            self.page_params.limit().map(|l| format!("limit={}", l)),
+            self.page_params.order().map(|o| format!("order={}", o)),
         ]
         .build_query_parameters()
     }
 
     fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::EFFECTS_PATH,
-            self.get_query_parameters()
-        )
+        // Horizon only filters effects by liquidity pool when the liquidity pool's ID is part of
+        // the path, so this request uses the API endpoint for `liquidity_pools` when an ID is set.
+        use crate::liquidity_pools::LIQUIDITY_POOLS_PATH;
+        match &self.liquidity_pool_id {
+            Some(liquidity_pool_id) => format!(
+                "{}/{}/{}/{}{}",
+                base_url,
+                LIQUIDITY_POOLS_PATH,
+                liquidity_pool_id,
+                super::EFFECTS_PATH,
+                self.get_query_parameters()
+            ),
+            None => format!(
+                "{}/{}{}",
+                base_url,
+                super::EFFECTS_PATH,
+                self.get_query_parameters()
+            ),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Order;
     use crate::BuildQueryParametersExt;
 
     #[test]
@@ -103,8 +117,7 @@ mod tests {
             .unwrap();
 
         let url = request.build_url("https://horizon-testnet.stellar.org");
-        let query_parameters = vec![
-            Some("liquidity_pool_id=liquidity_pool_id".to_string()),
+        let query_parameters = [
             Some("cursor=1".to_string()),
             Some("limit=10".to_string()),
             Some("order=asc".to_string()),
@@ -113,11 +126,18 @@ mod tests {
 
         assert_eq!(
             url,
-            "https://horizon-testnet.stellar.org/effects?liquidity_pool_id=liquidity_pool_id&cursor=1&limit=10&order=asc"
+            "https://horizon-testnet.stellar.org/liquidity_pools/liquidity_pool_id/effects?cursor=1&limit=10&order=asc"
         );
+        assert_eq!(query_parameters, "?cursor=1&limit=10&order=asc");
+    }
+
+    #[test]
+    fn test_effects_for_liquidity_pools_request_without_id() {
+        let request = EffectsForLiquidityPoolRequest::new().set_limit(10).unwrap();
+
         assert_eq!(
-            query_parameters,
-            "?liquidity_pool_id=liquidity_pool_id&cursor=1&limit=10&order=asc"
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/effects?limit=10"
         );
     }
 }
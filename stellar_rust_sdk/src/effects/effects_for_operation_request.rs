@@ -1,8 +1,6 @@
-use crate::{
-    models::{Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::operations::prelude::OperationId;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the request to fetch the effects for a specific operation from the Horizon API.
 ///
@@ -28,6 +26,7 @@ use stellar_rust_sdk_derive::pagination;
 ///
 /// let request = EffectsForOperationRequest::new()
 ///     .set_operation_id("123")
+///     .unwrap()
 ///     .set_cursor(1).unwrap()
 ///     .set_limit(10).unwrap()
 ///     .set_order(Order::Asc);
@@ -36,10 +35,14 @@ use stellar_rust_sdk_derive::pagination;
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::EFFECTS_PATH)]
 pub struct EffectsForOperationRequest {
     /// The operation id to filter effects.
-    operation_id: Option<String>,
+    operation_id: Option<OperationId>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl EffectsForOperationRequest {
@@ -47,45 +50,28 @@ impl EffectsForOperationRequest {
         EffectsForOperationRequest::default()
     }
 
-    pub fn set_operation_id(self, operation_id: impl Into<String>) -> EffectsForOperationRequest {
-        EffectsForOperationRequest {
-            operation_id: Some(operation_id.into()),
+    pub fn set_operation_id(
+        self,
+        operation_id: impl Into<String>,
+    ) -> Result<EffectsForOperationRequest, String> {
+        Ok(EffectsForOperationRequest {
+            operation_id: Some(operation_id.into().parse()?),
             ..self
-        }
-    }
-}
-
-impl Request for EffectsForOperationRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.operation_id
-                .as_ref()
-                .map(|l| format!("operation_id={}", l)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::EFFECTS_PATH,
-            self.get_query_parameters(),
-        )
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Order;
+    use crate::models::Request;
 
     #[test]
     fn test_get_query_parameters() {
         let request = EffectsForOperationRequest::new()
             .set_operation_id("123")
+            .unwrap()
             .set_cursor(1)
             .unwrap()
             .set_limit(10)
@@ -100,6 +86,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_operation_id_invalid() {
+        let request = EffectsForOperationRequest::new().set_operation_id("operation_id");
+        assert_eq!(request.err().unwrap(), "invalid operation ID: operation_id");
+    }
+
     #[test]
     fn test_build_url() {
         let request = EffectsForOperationRequest::new();
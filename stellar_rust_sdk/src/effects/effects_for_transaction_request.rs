@@ -1,8 +1,6 @@
-use crate::{
-    models::{Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::transactions::prelude::TransactionHash;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to fetch effect data from the Stellar Horizon API.
 ///
@@ -21,7 +19,8 @@ use stellar_rust_sdk_derive::pagination;
 /// # use stellar_rs::models::*;
 ///
 /// let request = EffectForTransactionRequest::new()
-///     .set_transaction_hash("transaction_hash")
+///     .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020")
+///     .unwrap()
 ///     .set_cursor(1234).unwrap()
 ///     .set_limit(20).unwrap()
 ///     .set_order(Order::Desc);
@@ -29,10 +28,14 @@ use stellar_rust_sdk_derive::pagination;
 /// // The request can now be used with a Horizon client to fetch effects.
 /// ```
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::EFFECTS_PATH)]
 pub struct EffectForTransactionRequest {
     /// The transaction hash of the transaction of the effect
-    transaction_hash: Option<String>,
+    transaction_hash: Option<TransactionHash>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl EffectForTransactionRequest {
@@ -46,46 +49,36 @@ impl EffectForTransactionRequest {
     /// # Arguments
     /// * `liquidity_pool_id` - A `String` value representing the liquidity pool id.
     ///
-    pub fn set_transaction_hash(self, transaction_hash: impl Into<String>) -> EffectForTransactionRequest {
-        EffectForTransactionRequest {
-            transaction_hash: Some(transaction_hash.into()),
+    /// # Returns
+    /// The `EffectForTransactionRequest` with the transaction hash set, or an error if the
+    /// transaction hash is invalid.
+    ///
+    pub fn set_transaction_hash(
+        self,
+        transaction_hash: impl Into<String>,
+    ) -> Result<EffectForTransactionRequest, String> {
+        Ok(EffectForTransactionRequest {
+            transaction_hash: Some(transaction_hash.into().parse()?),
             ..self
-        }
-    }
-}
-
-impl Request for EffectForTransactionRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.transaction_hash
-                .as_ref()
-                .map(|l| format!("transaction_hash={}", l)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::EFFECTS_PATH,
-            self.get_query_parameters()
-        )
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Order;
+    use crate::models::Request;
     use crate::BuildQueryParametersExt;
 
+    const TRANSACTION_HASH: &str =
+        "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+
     #[test]
     fn test_effects_for_liquidity_pools_request() {
         let request = EffectForTransactionRequest::new()
-            .set_transaction_hash("transaction_hash")
+            .set_transaction_hash(TRANSACTION_HASH)
+            .unwrap()
             .set_cursor(1)
             .unwrap()
             .set_limit(10)
@@ -94,8 +87,8 @@ mod tests {
             .unwrap();
 
         let url = request.build_url("https://horizon-testnet.stellar.org");
-        let query_parameters = vec![
-            Some("transaction_hash=transaction_hash".to_string()),
+        let query_parameters = [
+            Some(format!("transaction_hash={}", TRANSACTION_HASH)),
             Some("cursor=1".to_string()),
             Some("limit=10".to_string()),
             Some("order=asc".to_string()),
@@ -104,11 +97,26 @@ mod tests {
 
         assert_eq!(
             url,
-            "https://horizon-testnet.stellar.org/effects?transaction_hash=transaction_hash&cursor=1&limit=10&order=asc"
+            format!(
+                "https://horizon-testnet.stellar.org/effects?transaction_hash={}&cursor=1&limit=10&order=asc",
+                TRANSACTION_HASH
+            )
         );
         assert_eq!(
             query_parameters,
-            "?transaction_hash=transaction_hash&cursor=1&limit=10&order=asc"
+            format!(
+                "?transaction_hash={}&cursor=1&limit=10&order=asc",
+                TRANSACTION_HASH
+            )
+        );
+    }
+
+    #[test]
+    fn test_set_transaction_hash_invalid() {
+        let request = EffectForTransactionRequest::new().set_transaction_hash("transaction_hash");
+        assert_eq!(
+            request.err().unwrap(),
+            "Transaction hash must be 64 characters long"
         );
     }
 }
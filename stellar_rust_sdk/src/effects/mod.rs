@@ -54,6 +54,15 @@ pub mod effects_for_operation_request;
 ///
 pub mod effects_for_transaction_request;
 
+/// Provides the `EffectDetail`.
+///
+/// # Usage
+/// This module provides the `EffectDetail` enum, with a variant per effect type, which holds the
+/// fields that Horizon only returns for effects of that type, such as the amount of an
+/// `account_credited` effect.
+///
+pub mod effect_detail;
+
 /// Provides the responses.
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -89,6 +98,7 @@ static EFFECTS_PATH: &str = "effects";
 /// The `prelude` includes the following re-exports:
 ///
 /// * From `all_effects_request`: All items (e.g. `AllEffectsRequest`).
+/// * From `effect_detail`: All items (e.g. `EffectDetail`, `TradeEffect`).
 /// * From `effects_for_account_request`: All items (e.g. `EffectsForAccountRequest`).
 /// * From `effects_for_ledger_request`: All items (e.g. `EffectsForLedgerRequest`).
 /// * From `effects_for_liquidity_pools_request`: All items (e.g. `EffectsForLiquidityPoolRequest`).
@@ -108,6 +118,7 @@ static EFFECTS_PATH: &str = "effects";
 ///
 pub mod prelude {
     pub use super::all_effects_request::*;
+    pub use super::effect_detail::*;
     pub use super::effects_for_account_request::*;
     pub use super::effects_for_ledger_request::*;
     pub use super::effects_for_liquidity_pools_request::*;
@@ -121,11 +132,65 @@ mod tests {
     use super::prelude::*;
     use crate::horizon_client::HorizonClient;
 
+    /// Returns the starting balance of an `account_created` effect.
+    fn starting_balance(effect: &Effect) -> &str {
+        let EffectDetail::AccountCreated(account_created) = effect.detail() else {
+            panic!("expected an account_created effect");
+        };
+        account_created.starting_balance()
+    }
+
     #[test]
     fn dummy_test() {
         assert_eq!(super::EFFECTS_PATH, "effects");
     }
 
+    #[test]
+    fn test_effect_liquidity_pool() {
+        let json = r#"{
+            "_links": {
+                "operation": { "href": "https://horizon-testnet.stellar.org/operations/1" },
+                "succeeds": { "href": "https://horizon-testnet.stellar.org/effects?order=desc&cursor=1-1" },
+                "precedes": { "href": "https://horizon-testnet.stellar.org/effects?order=asc&cursor=1-1" }
+            },
+            "id": "0000000000000000001-0000000001",
+            "paging_token": "1-1",
+            "account": "GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR",
+            "type": "liquidity_pool_deposited",
+            "type_i": 90,
+            "created_at": "2024-06-11T21:36:12Z",
+            "liquidity_pool": {
+                "id": "01c58ab8fb283c8b083a26bf2fe06b7b6c6304c13f9d29d956cdf15a48bea72d",
+                "fee_bp": 30,
+                "type": "constant_product",
+                "total_trustlines": "1",
+                "total_shares": "100.0000000",
+                "reserves": [
+                    { "asset": "native", "amount": "100.0000000" },
+                    { "asset": "USDC:GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR", "amount": "100.0000000" }
+                ]
+            },
+            "reserves_deposited": [
+                { "asset": "native", "amount": "100.0000000" },
+                { "asset": "USDC:GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR", "amount": "100.0000000" }
+            ],
+            "shares_received": "100.0000000"
+        }"#;
+
+        let effect: Effect = serde_json::from_str(json).unwrap();
+        let liquidity_pool = effect.liquidity_pool().unwrap();
+
+        assert_eq!(
+            liquidity_pool.id(),
+            "01c58ab8fb283c8b083a26bf2fe06b7b6c6304c13f9d29d956cdf15a48bea72d"
+        );
+        assert_eq!(liquidity_pool.fee_bp(), &30);
+        assert_eq!(liquidity_pool.pool_type(), "constant_product");
+        assert_eq!(liquidity_pool.total_shares(), "100.0000000");
+        assert_eq!(liquidity_pool.reserves().len(), 2);
+        assert_eq!(liquidity_pool.reserves()[0].asset(), "native");
+    }
+
     #[tokio::test]
     async fn test_get_all_effects() {
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
@@ -191,10 +256,7 @@ mod tests {
         assert_eq!(record.effect_type(), RECORD_TYPE);
         assert_eq!(record.type_i(), &TYPE_I);
         assert_eq!(record.created_at(), CREATED_AT);
-        assert_eq!(
-            record.starting_balance().as_ref().unwrap(),
-            &STARTING_BALANCE
-        );
+        assert_eq!(starting_balance(record), STARTING_BALANCE);
     }
 
     #[tokio::test]
@@ -225,10 +287,7 @@ mod tests {
         assert_eq!(record.effect_type(), RECORD_TYPE);
         assert_eq!(record.type_i(), &TYPE_I);
         assert_eq!(record.created_at(), CREATED_AT);
-        assert_eq!(
-            record.starting_balance().as_ref().unwrap(),
-            &STARTING_BALANCE
-        );
+        assert_eq!(starting_balance(record), STARTING_BALANCE);
 
         // TODO: LEONARD FIX
         let _effects_for_liquidity_pools_request_with_id = EffectsForLiquidityPoolRequest::new()
@@ -246,7 +305,7 @@ mod tests {
     #[tokio::test]
     async fn test_get_effects_for_ledger() {
         // found by trial and error in the Stellar laboratory
-        static LEDGER_SEQUENCE: &u32 = &1000;
+        const LEDGER_SEQUENCE: u32 = 1000;
         const ID: &str = "0000004294967300098-0000000001";
         const PAGING_TOKEN: &str = "4294967300098-1";
         const ACCOUNT: &str = "GA7MC32ZYG5G7XSOR7TARZXXK5E4Y74VMWXIUZZNKIZ3Y3YQLCD25FV5";
@@ -257,8 +316,9 @@ mod tests {
 
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
 
-        let effects_for_ledger_request =
-            EffectsForLedgerRequest::new().set_sequence(LEDGER_SEQUENCE);
+        let effects_for_ledger_request = EffectsForLedgerRequest::new()
+            .set_sequence(LEDGER_SEQUENCE)
+            .unwrap();
         let effects_for_ledger_response = horizon_client
             .get_effects_for_ledger(&effects_for_ledger_request)
             .await;
@@ -273,13 +333,13 @@ mod tests {
 
         assert_eq!(record.account, ACCOUNT);
 
-        assert_eq!(record.effect_type, RECORD_TYPE);
+        assert_eq!(record.effect_type(), RECORD_TYPE);
 
         assert_eq!(record.type_i, TYPE_I);
 
         assert_eq!(record.created_at, CREATED_AT);
 
-        assert_eq!(record.starting_balance.as_ref().unwrap(), STARTING_BALANCE);
+        assert_eq!(starting_balance(record), STARTING_BALANCE);
     }
 
     #[tokio::test]
@@ -297,6 +357,7 @@ mod tests {
 
         let effects_for_operation_request = EffectsForOperationRequest::new()
             .set_operation_id(OPERATION_ID)
+            .unwrap()
             .set_limit(2)
             .unwrap();
         let effects_for_operation_response = horizon_client
@@ -314,10 +375,7 @@ mod tests {
         assert_eq!(record.effect_type(), RECORD_TYPE);
         assert_eq!(record.type_i(), &TYPE_I);
         assert_eq!(record.created_at(), CREATED_AT);
-        assert_eq!(
-            record.starting_balance().as_ref().unwrap(),
-            &STARTING_BALANCE
-        );
+        assert_eq!(starting_balance(record), STARTING_BALANCE);
     }
 
     #[tokio::test]
@@ -336,6 +394,7 @@ mod tests {
 
         let effects_for_transaction_request = EffectForTransactionRequest::new()
             .set_transaction_hash(TRANSACTION_HASH)
+            .unwrap()
             .set_limit(2)
             .unwrap();
 
@@ -354,9 +413,29 @@ mod tests {
         assert_eq!(record.effect_type(), RECORD_TYPE);
         assert_eq!(record.type_i(), &TYPE_I);
         assert_eq!(record.created_at(), CREATED_AT);
-        assert_eq!(
-            record.starting_balance().as_ref().unwrap(),
-            &STARTING_BALANCE
-        );
+        assert_eq!(starting_balance(record), STARTING_BALANCE);
+    }
+
+    #[tokio::test]
+    async fn test_get_effects_grouped_by_operation() {
+        const TRANSACTION_HASH: &str =
+            "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+        const OPERATION_ID: &str = "2314987376641";
+
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let grouped = horizon_client
+            .get_effects_grouped_by_operation(TRANSACTION_HASH)
+            .await
+            .unwrap();
+
+        let (operation_id, effects) = grouped.iter().next().unwrap();
+        assert_eq!(operation_id, OPERATION_ID);
+        assert_eq!(effects[0].effect_type(), "account_created");
+        for (operation_id, effects) in &grouped {
+            for effect in effects {
+                assert_eq!(&effect.operation_id().unwrap(), operation_id);
+            }
+        }
     }
 }
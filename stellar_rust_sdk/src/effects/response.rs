@@ -1,14 +1,20 @@
 use derive_getters::Getters;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use super::effect_detail::EffectDetail;
+use crate::liquidity_pools::prelude::Reserve;
+use crate::models::parse_muxed_account;
 use crate::models::prelude::*;
+use crate::operations::operation_id::OperationId;
 
 /// Represents the navigational links belonging to an effect from the Stellar Horizon API.
 ///
 /// This struct includes links such as the operation (current effect), succeeds, and precedes,
 /// providing quick navigation across operational sequence belonging to the effect.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct EffectLink {
     /// The link to the current operation of the effect.
     operation: Link,
@@ -18,12 +24,73 @@ pub struct EffectLink {
     precedes: Link,
 }
 
+/// Represents the liquidity pool involved in an effect.
+///
+/// This struct is included in liquidity pool related effects, such as `liquidity_pool_deposited`,
+/// `liquidity_pool_withdrew` and `liquidity_pool_trade`, and describes the state of the pool
+/// after the effect was applied.
+///
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
+pub struct EffectLiquidityPool {
+    /// The unique identifier of the liquidity pool.
+    pub id: String,
+    /// The fee of the liquidity pool, in basis points.
+    pub fee_bp: u32,
+    /// The type of the liquidity pool, such as `constant_product`.
+    #[serde(rename = "type")]
+    pub pool_type: String,
+    /// The number of trustlines to the pool shares.
+    pub total_trustlines: String,
+    /// The total number of pool shares.
+    pub total_shares: String,
+    /// The reserves of the liquidity pool.
+    pub reserves: Vec<Reserve>,
+}
+
+/// Represents the amount of an effect that changed a balance of an account, along with its asset.
+///
+/// Horizon reports the amounts of effects as decimal strings, of which the meaning and asset
+/// depend on the type of the effect: an `account_created` effect has a `starting_balance` in
+/// lumens, while `account_credited` and `account_debited` effects have an `amount` of the asset
+/// described by their `asset_type`, `asset_code` and `asset_issuer`. The variants of
+/// `EffectAmount` combine these fields into typed values, as returned by
+/// [`Effect::typed_amount`].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EffectAmount {
+    /// The account was created and funded with a starting balance in lumens.
+    AccountCreated { starting_balance: Amount },
+    /// The account received an amount of an asset.
+    AccountCredited { amount: Amount, asset: AssetType },
+    /// The account sent an amount of an asset.
+    AccountDebited { amount: Amount, asset: AssetType },
+}
+
+impl EffectAmount {
+    /// Returns the asset of the balance that changed, and the change of the balance, which is
+    /// negative for debits.
+    pub fn balance_delta(&self) -> (AssetType, Amount) {
+        match self {
+            EffectAmount::AccountCreated { starting_balance } => {
+                (AssetType::Native, *starting_balance)
+            }
+            EffectAmount::AccountCredited { amount, asset } => (asset.clone(), *amount),
+            EffectAmount::AccountDebited { amount, asset } => {
+                (asset.clone(), Amount::from_stroops(-amount.stroops()))
+            }
+        }
+    }
+}
+
 /// Represents a single effect record in the Horizon API response.
 ///
 /// This struct encapsulates detailed information about a single effect, including its ID,
 /// account, effect type, timestamps, and other relevant data.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Effect {
     /// Navigational links related to the operation of the effect.
     #[serde(rename = "_links")]
@@ -34,19 +101,91 @@ pub struct Effect {
     pub paging_token: String,
     /// The ID of the account related to the effect.
     pub account: String,
-    /// The type of the effect.
-    #[serde(rename = "type")]
-    pub effect_type: String,
+    /// The muxed account related to the effect, if the effect concerns a muxed account.
+    pub account_muxed: Option<String>,
+    /// The ID of the muxed account related to the effect, if the effect concerns a muxed account.
+    pub account_muxed_id: Option<String>,
     /// The integer representation of the effect type.
     pub type_i: u32,
     /// The timestamp when the effect was created.
     pub created_at: String,
-    /// The starting balance of the account, applicable for certain effect types.
-    pub starting_balance: Option<String>,
-    /// The type of the asset involved in the effect, if applicable.
-    pub asset_type: Option<String>,
-    /// The amount of the asset transacted in the effect, if applicable.
-    pub amount: Option<String>,
+    /// The fields of the effect that depend on its type, including the type itself.
+    #[serde(flatten)]
+    pub detail: EffectDetail,
+}
+
+impl Effect {
+    /// Returns the muxed account related to the effect, or `None` if the effect does not
+    /// concern a muxed account.
+    pub fn muxed_account(&self) -> Option<Result<MuxedAccount, String>> {
+        parse_muxed_account(self.account_muxed.as_deref())
+    }
+
+    /// Returns the type of the effect, such as `account_credited`.
+    pub fn effect_type(&self) -> &str {
+        self.detail.type_name()
+    }
+
+    /// Returns the liquidity pool involved in the effect, or `None` if the effect is not a
+    /// liquidity pool effect that describes the pool, such as a `trade` effect.
+    pub fn liquidity_pool(&self) -> Option<&EffectLiquidityPool> {
+        match &self.detail {
+            EffectDetail::LiquidityPoolDeposited(detail) => Some(&detail.liquidity_pool),
+            EffectDetail::LiquidityPoolWithdrew(detail) => Some(&detail.liquidity_pool),
+            EffectDetail::LiquidityPoolTrade(detail) => Some(&detail.liquidity_pool),
+            EffectDetail::LiquidityPoolCreated(detail) => Some(&detail.liquidity_pool),
+            EffectDetail::LiquidityPoolRevoked(detail) => Some(&detail.liquidity_pool),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the operation that produced the effect.
+    ///
+    /// The ID is derived from the paging token of the effect, which Horizon composes of the ID of
+    /// the operation and the index of the effect within that operation, such as `2314987376641-1`.
+    ///
+    /// # Returns
+    /// The ID of the operation, or an error if the paging token is malformed.
+    ///
+    pub fn operation_id(&self) -> Result<OperationId, String> {
+        let operation_id = self
+            .paging_token
+            .split_once('-')
+            .map_or(self.paging_token.as_str(), |(operation_id, _)| operation_id);
+
+        operation_id
+            .parse()
+            .map_err(|e| format!("invalid paging token {}: {}", self.paging_token, e))
+    }
+
+    /// Returns the amount of the effect along with its asset, or `None` if the effect did not
+    /// change a balance of the account, such as a `signer_created` effect.
+    ///
+    /// # Returns
+    /// The [`EffectAmount`], or an error if the amount or asset of the effect is malformed.
+    ///
+    pub fn typed_amount(&self) -> Option<Result<EffectAmount, String>> {
+        let typed_amount = match &self.detail {
+            EffectDetail::AccountCreated(detail) => detail
+                .starting_balance
+                .parse()
+                .map(|starting_balance| EffectAmount::AccountCreated { starting_balance }),
+            EffectDetail::AccountCredited(detail) => detail.amount.parse().and_then(|amount| {
+                Ok(EffectAmount::AccountCredited {
+                    amount,
+                    asset: detail.asset()?,
+                })
+            }),
+            EffectDetail::AccountDebited(detail) => detail.amount.parse().and_then(|amount| {
+                Ok(EffectAmount::AccountDebited {
+                    amount,
+                    asset: detail.asset()?,
+                })
+            }),
+            _ => return None,
+        };
+        Some(typed_amount)
+    }
 }
 
 /// Represents the response to a request for listing all effects from the Stellar Horizon API.
@@ -54,7 +193,8 @@ pub struct Effect {
 /// This struct contains the overall structure of the response for querying all effects. It includes
 /// navigational links and a collection of effect records, each with comprehensive details about the effect.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct EffectsResponse {
     /// Navigational links for the current, next, and previous pages of the response.
     #[serde(rename = "_links")]
@@ -65,8 +205,107 @@ pub struct EffectsResponse {
     embedded: Embedded<Effect>,
 }
 
+impl EffectsResponse {
+    /// Returns the net change of every balance by the effects in this response, such as the
+    /// effects of an account between two snapshots of its balances.
+    ///
+    /// Only effects that have a [`typed_amount`](Effect::typed_amount) are taken into account.
+    /// Balances are keyed by the account and the asset, since a response may contain the effects
+    /// of several accounts.
+    ///
+    /// # Returns
+    /// The net change of every balance, or an error if an amount is malformed or a sum overflows.
+    ///
+    pub fn net_balance_changes(&self) -> Result<HashMap<(String, AssetType), Amount>, String> {
+        let mut changes: HashMap<(String, AssetType), Amount> = HashMap::new();
+        for effect in &self.embedded.records {
+            let Some(typed_amount) = effect.typed_amount() else {
+                continue;
+            };
+            let (asset, delta) = typed_amount?.balance_delta();
+            let change = changes.entry((effect.account.clone(), asset)).or_default();
+            *change = change
+                .checked_add(delta)
+                .ok_or_else(|| format!("balance change overflows at effect {}", effect.id))?;
+        }
+
+        Ok(changes)
+    }
+}
+
 impl Response for EffectsResponse {
     fn from_json(json: String) -> Result<EffectsResponse, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effects::effect_detail::BalanceChangeEffect;
+
+    #[test]
+    fn test_operation_id() {
+        let json = r#"{
+            "_links": {
+                "operation": {"href": "https://horizon-testnet.stellar.org/operations/2314987376641"},
+                "succeeds": {"href": "https://horizon-testnet.stellar.org/effects?order=desc&cursor=2314987376641-1"},
+                "precedes": {"href": "https://horizon-testnet.stellar.org/effects?order=asc&cursor=2314987376641-1"}
+            },
+            "id": "0000002314987376641-0000000001",
+            "paging_token": "2314987376641-1",
+            "account": "GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR",
+            "type": "account_created",
+            "type_i": 0,
+            "created_at": "2024-06-11T21:36:12Z",
+            "starting_balance": "10000000000.0000000"
+        }"#;
+        let mut effect: Effect = serde_json::from_str(json).unwrap();
+        assert_eq!(effect.operation_id().unwrap(), "2314987376641");
+
+        effect.paging_token = "invalid-1".to_string();
+        assert!(effect
+            .operation_id()
+            .unwrap_err()
+            .starts_with("invalid paging token invalid-1"));
+    }
+
+    #[test]
+    fn test_typed_amount() {
+        let json = include_str!("../../tests/fixtures/effects/effects_for_account.json");
+        let response = EffectsResponse::from_json(json.to_string()).unwrap();
+        let records = &response.embedded().records;
+
+        for effect in records {
+            match effect.effect_type() {
+                "account_created" | "account_credited" | "account_debited" => {
+                    assert!(effect.typed_amount().unwrap().is_ok())
+                }
+                _ => assert!(effect.typed_amount().is_none()),
+            }
+        }
+
+        let mut effect = records[0].clone();
+        let mut debit = BalanceChangeEffect {
+            asset_type: "native".to_string(),
+            asset_code: None,
+            asset_issuer: None,
+            amount: "2.5".to_string(),
+        };
+        effect.detail = EffectDetail::AccountDebited(debit.clone());
+        assert_eq!(
+            effect.typed_amount().unwrap().unwrap().balance_delta(),
+            (AssetType::Native, Amount::from_stroops(-25_000_000))
+        );
+        debit.asset_type = "credit_alphanum4".to_string();
+        effect.detail = EffectDetail::AccountDebited(debit);
+        assert!(effect.typed_amount().unwrap().is_err());
+        // The lumens received by creating the account, less the lumens sent.
+        let changes = response.net_balance_changes().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[&(records[0].account.clone(), AssetType::Native)].to_string(),
+            "9990.0000000"
+        );
     }
 }
@@ -0,0 +1,404 @@
+use crate::transactions::result_codes::TransactionResultCodes;
+use std::fmt;
+use std::time::Duration;
+
+/// Represents an error returned by the methods of the
+/// [`HorizonClient`](crate::horizon_client::HorizonClient).
+///
+/// The variants distinguish where a request failed: before a response was received, at the
+/// Horizon server, or while processing the response. In particular, responses that are not JSON,
+/// such as the HTML error page of a proxy or load balancer in front of Horizon, are reported as
+/// [`Error::NonJsonResponse`] instead of as a confusing deserialization error.
+///
+/// An `Error` converts into a `String`, so it can be propagated with `?` from functions that
+/// return a `Result<T, String>`.
+///
+/// New variants may be added in minor releases, so matches on an `Error` require a wildcard arm.
+///
+/// Errors that a pagination loop should handle are reported with dedicated variants: paging past
+/// the history retained by the server yields [`Error::BeforeHistory`], and an invalid cursor
+/// yields [`Error::BadPagingToken`].
+///
+/// A transaction that is rejected when it is submitted yields [`Error::TransactionFailed`], with
+/// the result codes of the transaction and its operations.
+///
+/// # Classification
+///
+/// Applications that apply the same retry or alerting policy to every request can classify an
+/// error without matching on its variants:
+/// * [`Error::is_retryable`] - The error is caused by a temporary condition, such as a network
+///   failure, a rate limit, a server error or a stale response, so the request may succeed when
+///   it is sent again. The [`RetryPolicy`](crate::retry::RetryPolicy) retries exactly these.
+/// * [`Error::is_client_error`] - The Horizon server rejected the request with a `4xx` status,
+///   so sending it again will not help unless it is changed. [`Error::is_not_found`] singles out
+///   requests for records that do not exist.
+/// * [`Error::is_server_error`] - The Horizon server failed with a `5xx` status. Together with
+///   network failures, these open the circuit of a
+///   [`CircuitBreaker`](crate::circuit_breaker::CircuitBreaker).
+///
+/// Errors of requests that could not be sent include the URL of the request, and deserialization
+/// errors include the URL and the start of the response body, so that the failing request can be
+/// identified from the error alone.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::error::Error;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::ledgers::prelude::LedgersRequest;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// match horizon_client.get_all_ledgers(&LedgersRequest::new()).await {
+///     Ok(ledgers) => println!("{} ledgers", ledgers.embedded().records().len()),
+///     Err(Error::NonJsonResponse { status, snippet, .. }) => {
+///         eprintln!("Unexpected response with status {}: {}", status, snippet)
+///     }
+///     Err(e) => eprintln!("Request failed: {}", e),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The request could not be sent, or the response could not be received.
+    Http(String),
+    /// The Horizon server responded with an error status and a JSON body, which usually is a
+    /// problem document describing the error.
+    Horizon {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The body of the response.
+        body: String,
+    },
+    /// The requested records precede the oldest ledger retained by the Horizon server, which is
+    /// reported with the `before_history` problem type. Horizon instances with limited history
+    /// retention return this error when a pagination loop reaches the end of the retained
+    /// history, so that the loop can stop with the records it has retrieved so far.
+    BeforeHistory {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The body of the response.
+        body: String,
+    },
+    /// The cursor of a paginated request is not a valid paging token, which is reported with the
+    /// `bad_paging_token` problem type, or with the `bad_request` problem type for the `cursor`
+    /// field.
+    BadPagingToken {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The body of the response.
+        body: String,
+    },
+    /// A submitted transaction failed, which is reported with the `transaction_failed` problem
+    /// type. The result codes tell why the transaction, or one of its operations, failed.
+    TransactionFailed {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The body of the response.
+        body: String,
+        /// The result codes of the transaction and its operations.
+        result_codes: TransactionResultCodes,
+    },
+    /// The response body is not JSON, for example because a proxy responded with an HTML error
+    /// page, or because the server could not produce an acceptable representation.
+    NonJsonResponse {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The `Content-Type` header of the response, if present.
+        content_type: Option<String>,
+        /// The start of the response body, truncated to the configured snippet length.
+        snippet: String,
+    },
+    /// The response body is larger than the maximum response size configured on the client, and
+    /// was discarded without being deserialized.
+    ResponseTooLarge {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The maximum response size, in bytes.
+        max_size: usize,
+    },
+    /// The request was not sent, because the circuit of its endpoint is open after repeated
+    /// failures. See [`CircuitBreaker`](crate::circuit_breaker::CircuitBreaker).
+    CircuitOpen {
+        /// The endpoint of the request, such as `/trades`.
+        endpoint: String,
+        /// The time after which a request to the endpoint will be let through again.
+        retry_after: Duration,
+    },
+    /// The response body is JSON, but could not be deserialized into the expected model.
+    Deserialization(String),
+    /// The response reports a `Latest-Ledger` that lags behind the latest ledger reported earlier
+    /// by more than the maximum ledger lag configured on the client, so its data is stale. See
+    /// [`HorizonClient::set_max_ledger_lag`](crate::horizon_client::HorizonClient::set_max_ledger_lag).
+    StaleResponse {
+        /// The latest ledger reported by the response.
+        latest_ledger: u32,
+        /// The highest latest ledger reported by any response received by the client.
+        highest_ledger: u32,
+    },
+    /// Any other error, such as an invalid request or an invalid argument.
+    Other(String),
+}
+
+impl Error {
+    /// Returns the HTTP status code of the response, if a response was received.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Horizon { status, .. }
+            | Error::BeforeHistory { status, .. }
+            | Error::BadPagingToken { status, .. }
+            | Error::TransactionFailed { status, .. }
+            | Error::NonJsonResponse { status, .. }
+            | Error::ResponseTooLarge { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the error is caused by a temporary condition, so that sending the same
+    /// request again may succeed.
+    ///
+    /// Network failures, stale responses, rate limited requests (`429 Too Many Requests`) and
+    /// server errors (`5xx`) are retryable.
+    ///
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Http(_) | Error::StaleResponse { .. } => true,
+            error => error
+                .status()
+                .is_some_and(|status| status == 429 || status >= 500),
+        }
+    }
+
+    /// Returns whether the Horizon server rejected the request with a client error status (`4xx`).
+    pub fn is_client_error(&self) -> bool {
+        self.status()
+            .is_some_and(|status| (400..500).contains(&status))
+    }
+
+    /// Returns whether the Horizon server failed with a server error status (`5xx`).
+    pub fn is_server_error(&self) -> bool {
+        self.status().is_some_and(|status| status >= 500)
+    }
+
+    /// Returns whether the requested resource does not exist (`404 Not Found`).
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    /// Creates an error from a response of the Horizon server with an error status, mapping the
+    /// problem types that concern pagination and failed transactions to their dedicated variants.
+    pub(crate) fn from_problem(status: u16, body: String) -> Self {
+        let problem = serde_json::from_str::<serde_json::Value>(&body).unwrap_or_default();
+        let problem_type = problem["type"]
+            .as_str()
+            .and_then(|problem_type| problem_type.rsplit('/').next());
+        let invalid_field = problem["extras"]["invalid_field"].as_str();
+
+        match (problem_type, invalid_field) {
+            (Some("before_history"), _) => Error::BeforeHistory { status, body },
+            (Some("bad_paging_token"), _) | (Some("bad_request"), Some("cursor")) => {
+                Error::BadPagingToken { status, body }
+            }
+            (Some("transaction_failed"), _) => {
+                match TransactionResultCodes::from_extras(&problem["extras"]) {
+                    Some(result_codes) => Error::TransactionFailed {
+                        status,
+                        body,
+                        result_codes,
+                    },
+                    None => Error::Horizon { status, body },
+                }
+            }
+            _ => Error::Horizon { status, body },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(message) | Error::Deserialization(message) | Error::Other(message) => {
+                write!(f, "{}", message)
+            }
+            Error::Horizon { body, .. }
+            | Error::BeforeHistory { body, .. }
+            | Error::BadPagingToken { body, .. }
+            | Error::TransactionFailed { body, .. } => write!(f, "{}", body),
+            Error::NonJsonResponse {
+                status,
+                content_type,
+                snippet,
+            } => write!(
+                f,
+                "received a non-JSON response with status {} and content type {}: {}",
+                status,
+                content_type.as_deref().unwrap_or("unknown"),
+                snippet
+            ),
+            Error::ResponseTooLarge { status, max_size } => write!(
+                f,
+                "received a response with status {} that exceeds the maximum size of {} bytes",
+                status, max_size
+            ),
+            Error::CircuitOpen {
+                endpoint,
+                retry_after,
+            } => write!(
+                f,
+                "the circuit of endpoint {} is open; retry after {} ms",
+                endpoint,
+                retry_after.as_millis()
+            ),
+            Error::StaleResponse {
+                latest_ledger,
+                highest_ledger,
+            } => write!(
+                f,
+                "received a stale response at ledger {}, while ledger {} was reported before",
+                latest_ledger, highest_ledger
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Http(error.to_string())
+    }
+}
+
+impl From<Error> for String {
+    fn from(error: Error) -> Self {
+        error.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transactions::result_codes::TransactionResultCode;
+
+    #[test]
+    fn test_error_display() {
+        let error = Error::NonJsonResponse {
+            status: 502,
+            content_type: Some("text/html".to_string()),
+            snippet: "<html>".to_string(),
+        };
+        assert_eq!(error.status(), Some(502));
+        assert_eq!(
+            String::from(error),
+            "received a non-JSON response with status 502 and content type text/html: <html>"
+        );
+
+        let error = Error::from("invalid request".to_string());
+        assert_eq!(error, Error::Other("invalid request".to_string()));
+        assert_eq!(error.status(), None);
+        assert_eq!(error.to_string(), "invalid request");
+
+        let error = Error::ResponseTooLarge {
+            status: 200,
+            max_size: 1024,
+        };
+        assert_eq!(error.status(), Some(200));
+        assert_eq!(
+            error.to_string(),
+            "received a response with status 200 that exceeds the maximum size of 1024 bytes"
+        );
+
+        let error = Error::CircuitOpen {
+            endpoint: "/trades".to_string(),
+            retry_after: Duration::from_secs(3),
+        };
+        assert_eq!(error.status(), None);
+        assert_eq!(
+            error.to_string(),
+            "the circuit of endpoint /trades is open; retry after 3000 ms"
+        );
+
+        let error = Error::StaleResponse {
+            latest_ledger: 94,
+            highest_ledger: 100,
+        };
+        assert_eq!(error.status(), None);
+        assert_eq!(
+            error.to_string(),
+            "received a stale response at ledger 94, while ledger 100 was reported before"
+        );
+    }
+
+    #[test]
+    fn test_from_problem() {
+        let body = r#"{"type":"https://stellar.org/horizon-errors/before_history","status":410}"#;
+        assert_eq!(
+            Error::from_problem(410, body.to_string()),
+            Error::BeforeHistory {
+                status: 410,
+                body: body.to_string(),
+            }
+        );
+
+        let body = r#"{"type":"https://stellar.org/horizon-errors/bad_request","status":400,"extras":{"invalid_field":"cursor"}}"#;
+        assert!(matches!(
+            Error::from_problem(400, body.to_string()),
+            Error::BadPagingToken { status: 400, .. }
+        ));
+
+        let body = r#"{"type":"https://stellar.org/horizon-errors/bad_request","status":400,"extras":{"invalid_field":"limit"}}"#;
+        assert!(matches!(
+            Error::from_problem(400, body.to_string()),
+            Error::Horizon { status: 400, .. }
+        ));
+
+        let body = r#"{"type":"https://stellar.org/horizon-errors/transaction_failed","status":400,"extras":{"result_codes":{"transaction":"tx_bad_seq"}}}"#;
+        let Error::TransactionFailed { result_codes, .. } =
+            Error::from_problem(400, body.to_string())
+        else {
+            panic!("expected a failed transaction");
+        };
+        assert_eq!(*result_codes.transaction(), TransactionResultCode::BadSeq);
+        assert!(result_codes.operations().is_empty());
+
+        let error = Error::from_problem(500, "{}".to_string());
+        assert_eq!(error.status(), Some(500));
+        assert_eq!(error.to_string(), "{}");
+    }
+
+    #[test]
+    fn test_classification() {
+        let error = |status| Error::Horizon {
+            status,
+            body: "{}".to_string(),
+        };
+
+        assert!(error(404).is_not_found());
+        assert!(error(404).is_client_error());
+        assert!(!error(404).is_retryable());
+        assert!(error(429).is_client_error());
+        assert!(error(429).is_retryable());
+        assert!(error(503).is_server_error());
+        assert!(error(503).is_retryable());
+        assert!(!error(503).is_client_error());
+
+        let error = Error::BadPagingToken {
+            status: 400,
+            body: "{}".to_string(),
+        };
+        assert!(error.is_client_error());
+        assert!(!error.is_retryable());
+
+        let error = Error::Http("connection refused".to_string());
+        assert!(error.is_retryable());
+        assert!(!error.is_client_error() && !error.is_server_error());
+        assert!(!Error::Other("invalid".to_string()).is_retryable());
+    }
+}
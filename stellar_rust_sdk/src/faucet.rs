@@ -0,0 +1,278 @@
+use crate::accounts::prelude::AccountId;
+use crate::error::Error;
+use crate::horizon_client::{handle_response, url_validate, DEFAULT_ERROR_BODY_SNIPPET_LENGTH};
+use crate::models::Response;
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::future::Future;
+
+/// The URL of the Friendbot of the test network.
+const TESTNET_FRIENDBOT_URL: &str = "https://friendbot.stellar.org";
+/// The URL of the Friendbot of the future network.
+const FUTURENET_FRIENDBOT_URL: &str = "https://friendbot-futurenet.stellar.org";
+/// The placeholder in the URL of a [`CustomFaucet`] that is replaced by the account to fund.
+const ACCOUNT_ID_PLACEHOLDER: &str = "{account_id}";
+
+/// Represents a service that funds accounts on a network other than the public network.
+///
+/// Integration tests need funded accounts to transact with. On the test and future networks,
+/// accounts are funded by [`Friendbot`]; private networks, such as the standalone network of the
+/// `stellar/quickstart` image, run a faucet of their own, which can be used through a
+/// [`CustomFaucet`]. Both implement this trait, so that tests can fund accounts through the same
+/// API regardless of the network they run against.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::faucet::{CustomFaucet, Faucet, Friendbot};
+/// async fn fund(faucet: &impl Faucet) -> Result<(), Box<dyn std::error::Error>> {
+///     let funding = faucet
+///         .fund_account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///         .await?;
+///     println!("Funded in transaction {}", funding.hash());
+///     Ok(())
+/// }
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// fund(&Friendbot::testnet()).await?;
+/// fund(&CustomFaucet::new("http://localhost:8000/friendbot?addr={account_id}")?).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+pub trait Faucet {
+    /// Funds an account, creating it if it does not exist yet.
+    ///
+    /// # Arguments
+    /// * `account_id` - The public key of the account to fund.
+    ///
+    /// # Returns
+    /// The [`FundingResponse`] describing the funding transaction, or an error if the account ID
+    /// is invalid or the faucet could not fund the account. Faucets typically refuse to fund an
+    /// account that already exists, which is reported as an [`Error::Horizon`].
+    ///
+    fn fund_account(
+        &self,
+        account_id: &str,
+    ) -> impl Future<Output = Result<FundingResponse, Error>> + Send;
+}
+
+/// Represents the response of a faucet that funded an account.
+///
+/// Faucets respond with the transaction that funded the account, as submitted to Horizon. Only the
+/// fields needed to look up the transaction are retained.
+///
+#[derive(Debug, Clone, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct FundingResponse {
+    /// The hash of the transaction that funded the account.
+    hash: String,
+    /// The sequence number of the ledger in which the transaction was included, if reported.
+    #[serde(default)]
+    ledger: Option<u32>,
+}
+
+impl Response for FundingResponse {
+    fn from_json(json: String) -> Result<Self, String> {
+        crate::models::deserialize_json(&json)
+    }
+}
+
+/// Funds accounts using Friendbot, the faucet of the test and future networks.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::faucet::{Faucet, Friendbot};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let funding = Friendbot::testnet()
+///     .fund_account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Friendbot {
+    /// The URL of the Friendbot.
+    url: String,
+    /// The HTTP client used to call the Friendbot.
+    client: reqwest::Client,
+}
+
+impl Friendbot {
+    /// Creates a `Friendbot` that funds accounts on the test network.
+    pub fn testnet() -> Self {
+        Friendbot::with_url(TESTNET_FRIENDBOT_URL)
+    }
+
+    /// Creates a `Friendbot` that funds accounts on the future network.
+    pub fn futurenet() -> Self {
+        Friendbot::with_url(FUTURENET_FRIENDBOT_URL)
+    }
+
+    /// Returns the URL of the Friendbot.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn with_url(url: &str) -> Self {
+        Friendbot {
+            url: url.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Returns the URL that funds the given account.
+    fn funding_url(&self, account_id: &AccountId) -> String {
+        format!("{}/?addr={}", self.url, account_id)
+    }
+}
+
+impl Faucet for Friendbot {
+    fn fund_account(
+        &self,
+        account_id: &str,
+    ) -> impl Future<Output = Result<FundingResponse, Error>> + Send {
+        let account_id = AccountId::new(account_id);
+        async move { fund(&self.client, &self.funding_url(&account_id?)).await }
+    }
+}
+
+/// Funds accounts using a faucet at a custom URL, such as the faucet of a private network.
+///
+/// The URL is a template, in which the placeholder `{account_id}` is replaced by the public key of
+/// the account to fund. The faucet is called using a `GET` request, and is expected to respond the
+/// way Friendbot does. For example, the faucet of the standalone network of the
+/// `stellar/quickstart` image is available at `http://localhost:8000/friendbot?addr={account_id}`.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::faucet::{CustomFaucet, Faucet};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let faucet = CustomFaucet::new("http://localhost:8000/friendbot?addr={account_id}")?;
+/// let funding = faucet
+///     .fund_account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct CustomFaucet {
+    /// The URL template of the faucet.
+    url_template: String,
+    /// The HTTP client used to call the faucet.
+    client: reqwest::Client,
+}
+
+impl CustomFaucet {
+    /// Creates a new `CustomFaucet`.
+    ///
+    /// # Arguments
+    /// * `url_template` - The URL of the faucet, containing the placeholder `{account_id}`.
+    ///
+    /// # Returns
+    /// The `CustomFaucet`, or an error if the URL is invalid or does not contain the placeholder.
+    ///
+    pub fn new(url_template: impl Into<String>) -> Result<Self, String> {
+        let url_template = url_template.into();
+        if !url_template.contains(ACCOUNT_ID_PLACEHOLDER) {
+            return Err(format!(
+                "URL template must contain {}: {}",
+                ACCOUNT_ID_PLACEHOLDER, url_template
+            ));
+        }
+        url_validate(&url_template.replace(ACCOUNT_ID_PLACEHOLDER, "account_id"))?;
+
+        Ok(CustomFaucet {
+            url_template,
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Returns the URL template of the faucet.
+    pub fn url_template(&self) -> &str {
+        &self.url_template
+    }
+
+    /// Returns the URL that funds the given account.
+    fn funding_url(&self, account_id: &AccountId) -> String {
+        self.url_template
+            .replace(ACCOUNT_ID_PLACEHOLDER, account_id.as_str())
+    }
+}
+
+impl Faucet for CustomFaucet {
+    fn fund_account(
+        &self,
+        account_id: &str,
+    ) -> impl Future<Output = Result<FundingResponse, Error>> + Send {
+        let account_id = AccountId::new(account_id);
+        async move { fund(&self.client, &self.funding_url(&account_id?)).await }
+    }
+}
+
+/// Calls a faucet at the given URL, and processes its response.
+async fn fund(client: &reqwest::Client, url: &str) -> Result<FundingResponse, Error> {
+    let response = crate::runtime::send(client.get(url)).await?;
+
+    handle_response(response, DEFAULT_ERROR_BODY_SNIPPET_LENGTH, None).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT_ID: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_friendbot_funding_url() {
+        let account_id = AccountId::new(ACCOUNT_ID).unwrap();
+        assert_eq!(
+            Friendbot::testnet().funding_url(&account_id),
+            format!("https://friendbot.stellar.org/?addr={}", ACCOUNT_ID)
+        );
+        assert_eq!(
+            Friendbot::futurenet().funding_url(&account_id),
+            format!(
+                "https://friendbot-futurenet.stellar.org/?addr={}",
+                ACCOUNT_ID
+            )
+        );
+    }
+
+    #[test]
+    fn test_custom_faucet_funding_url() {
+        let faucet =
+            CustomFaucet::new("http://localhost:8000/friendbot?addr={account_id}").unwrap();
+        assert_eq!(
+            faucet.funding_url(&AccountId::new(ACCOUNT_ID).unwrap()),
+            format!("http://localhost:8000/friendbot?addr={}", ACCOUNT_ID)
+        );
+
+        assert_eq!(
+            CustomFaucet::new("http://localhost:8000/friendbot").unwrap_err(),
+            "URL template must contain {account_id}: http://localhost:8000/friendbot"
+        );
+        assert!(CustomFaucet::new("localhost:8000/friendbot?addr={account_id}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fund_invalid_account() {
+        let result = Friendbot::testnet().fund_account("GDQJ").await;
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Other("Public key must be 56 characters long".to_string())
+        );
+    }
+
+    #[test]
+    fn test_funding_response() {
+        let json = r#"{"hash": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020", "ledger": 1049845, "envelope_xdr": "AAAA"}"#;
+        let response = FundingResponse::from_json(json.to_string()).unwrap();
+        assert_eq!(
+            response.hash(),
+            "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+        );
+        assert_eq!(response.ledger(), &Some(1049845));
+    }
+}
@@ -20,7 +20,7 @@ use crate::models::Request;
 /// // The request can now be used with a Horizon client to fetch fee stats.
 /// ```
 ///
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash)]
 pub struct FeeStatsRequest {}
 
 impl FeeStatsRequest {
@@ -10,6 +10,7 @@ use crate::models::Response;
 ///
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct FeeStatsResponse {
     /// The last ledger number.
     #[serde(rename = "last_ledger")]
@@ -35,6 +36,7 @@ pub struct FeeStatsResponse {
 ///
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Fee {
     /// The maximum fee for transactions.
     pub max: String,
@@ -68,6 +70,6 @@ pub struct Fee {
 
 impl Response for FeeStatsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
@@ -0,0 +1,45 @@
+use crate::models::Request;
+
+/// The path of the health endpoint in the Horizon API.
+static HEALTH_PATH: &str = "health";
+
+/// Represents a request to fetch the health of a Horizon server.
+///
+/// `HealthRequest` is a struct used to construct queries for retrieving the health of the
+/// Horizon server. This request does not include any parameters.
+///
+/// # Usage
+/// The request is sent by the [`HorizonClient::get_health`](crate::horizon_client::HorizonClient::get_health)
+/// method, but it can also be executed directly.
+///
+/// # Example
+/// ```rust
+/// use stellar_rs::health::health_request::HealthRequest;
+/// use stellar_rs::models::*;
+///
+/// let request = HealthRequest::new();
+/// assert_eq!(
+///     request.build_url("https://horizon-testnet.stellar.org"),
+///     "https://horizon-testnet.stellar.org/health"
+/// );
+/// ```
+///
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct HealthRequest {}
+
+impl HealthRequest {
+    /// Creates a new `HealthRequest`.
+    pub fn new() -> HealthRequest {
+        HealthRequest::default()
+    }
+}
+
+impl Request for HealthRequest {
+    fn get_query_parameters(&self) -> String {
+        "".to_string()
+    }
+
+    fn build_url(&self, base_url: &str) -> String {
+        format!("{}/{}", base_url, HEALTH_PATH)
+    }
+}
@@ -0,0 +1,34 @@
+/// Provides the `HealthRequest`.
+///
+/// This module provides the `HealthRequest` struct, specifically designed for constructing
+/// requests to query the health of the Horizon server. It is tailored for use with the
+/// [`HorizonClient::get_health`](crate::horizon_client::HorizonClient::get_health) method.
+///
+pub mod health_request;
+
+/// Provides the response.
+///
+/// This module defines the structure representing the response from the Horizon API when
+/// querying its health, which reports whether Horizon is connected to its database and whether
+/// Stellar Core is up and in sync with the network.
+///
+pub mod response;
+
+pub mod prelude {
+    pub use super::health_request::*;
+    pub use super::response::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::horizon_client::HorizonClient;
+
+    #[tokio::test]
+    async fn test_get_health() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let health_response = horizon_client.get_health().await.unwrap();
+
+        assert!(health_response.is_healthy());
+    }
+}
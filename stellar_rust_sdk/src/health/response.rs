@@ -0,0 +1,37 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::models::Response;
+
+/// Represents the health of a Horizon server.
+///
+/// Horizon reports whether it is connected to its database, and whether the Stellar Core
+/// instance it ingests from is up and in sync with the network. A server of which Stellar Core is
+/// not in sync stops ingesting new ledgers, so its responses become stale. Horizon responds with
+/// `503 Service Unavailable` if any of the checks fails, but the body still describes which
+/// check failed, so this response is returned for both statuses.
+///
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Getters)]
+#[non_exhaustive]
+pub struct HealthResponse {
+    /// Whether Horizon is connected to its database.
+    pub database_connected: bool,
+    /// Whether Stellar Core is up.
+    pub core_up: bool,
+    /// Whether Stellar Core is in sync with the network.
+    pub core_synced: bool,
+}
+
+impl HealthResponse {
+    /// Returns whether all checks passed, so that the server can be trusted to serve current
+    /// data.
+    pub fn is_healthy(&self) -> bool {
+        self.database_connected && self.core_up && self.core_synced
+    }
+}
+
+impl Response for HealthResponse {
+    fn from_json(json: String) -> Result<Self, String> {
+        crate::models::deserialize_json(&json)
+    }
+}
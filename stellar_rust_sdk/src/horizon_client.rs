@@ -1,50 +1,144 @@
+use crate::datetime::{self, DateTime};
 use crate::{
-    accounts::prelude::*,
+    accounts::{
+        account_watcher::AccountWatcherState, new_accounts_watcher::NewAccountsWatcherState,
+        prelude::*,
+    },
     assets::prelude::{AllAssetsRequest, AllAssetsResponse},
+    cache::{CacheDirective, CachePolicy, ResourceKind, ResponseCache},
+    circuit_breaker::{self, CircuitBreaker},
     claimable_balances::{
         all_claimable_balances_request::AllClaimableBalancesRequest,
+        claimable_balances_for_sponsor_request::{
+            ClaimableBalanceSponsor, ClaimableBalancesForSponsorRequest,
+        },
         prelude::{AllClaimableBalancesResponse, ClaimableBalance},
         single_claimable_balance_request::{ClaimableBalanceId, SingleClaimableBalanceRequest},
     },
+    connection::{CachingResolver, ConnectionStats, ContentEncoding, LatencyMonitor, Ping},
     effects::prelude::*,
+    error::Error,
     fee_stats::{fee_stats_request::FeeStatsRequest, response::FeeStatsResponse},
+    health::prelude::{HealthRequest, HealthResponse},
     ledgers::{
         prelude::{Ledger, LedgersRequest, LedgersResponse, SingleLedgerRequest},
         single_ledger_request::Sequence,
     },
     liquidity_pools::{
         all_liquidity_pools_request::AllLiquidityPoolsRequest,
+        liquidity_pool_report::build_liquidity_pool_report,
         prelude::{
-            AllLiquidityPoolsResponse, LiquidityPool, LiquidityPoolId, SingleLiquidityPoolRequest,
+            AllLiquidityPoolsResponse, LiquidityPool, LiquidityPoolId, LiquidityPoolReport,
+            SingleLiquidityPoolRequest,
         },
     },
-    models::{PostRequest, Request, Response},
+    models::{
+        pagination::{PageParams, Paginatable},
+        prelude::{expand_template, AssetPair, AssetType, RawPage, ResponseLinks},
+        Asset, IssuedAsset, Order, PostRequest, Request, Response,
+    },
+    network::{omit_unsupported_parameters, Network},
     offers::prelude::*,
     operations::{
+        operation_id::OperationId,
         operations_for_account_request::OperationsForAccountRequest,
         prelude::{
-            AllOperationsRequest, OperationResponse, OperationsForLedgerRequest,
-            OperationsForLiquidityPoolRequest, OperationsForTransactionRequest,
+            AllOperationsRequest, OperationResponse, OperationTypeFilter,
+            OperationsClaimableBalanceId, OperationsForClaimableBalanceRequest,
+            OperationsForLedgerRequest, OperationsForLiquidityPoolRequest,
+            OperationsForTransactionRequest,
         },
         response::Operation,
-        single_operation_request::{OperationId, SingleOperationRequest},
+        single_operation_request::SingleOperationRequest,
     },
     order_book::{
         details_request::{BuyingAsset, DetailsRequest, SellingAsset},
+        order_book_poller::OrderBookPollerState,
         response::DetailsResponse,
     },
     paths::prelude::*,
-    payments::prelude::*,
+    payments::{payment_history::build_payment_history, prelude::*},
+    rate_limit::{RateLimitInfo, RateLimiter},
+    retry::RetryPolicy,
+    root::prelude::{RootRequest, RootResponse},
+    streaming::{with_cursor, SseConnection, StreamOptions, StreamState},
     trade_aggregations::prelude::*,
     trades::prelude::*,
     transactions::prelude::*,
 };
+use futures::channel::oneshot;
+use futures::future::{FutureExt, Shared};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 use reqwest;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use url::Url;
 
+pub use crate::config::HorizonClientConfig;
+
+/// The default number of characters of a response body that are included in a deserialization error.
+pub(crate) const DEFAULT_ERROR_BODY_SNIPPET_LENGTH: usize = 512;
+
+/// The media types accepted by the client. Horizon serves its resources as HAL documents, and its
+/// errors as problem documents; without these, a server may respond with `406 Not Acceptable`.
+const ACCEPTED_MEDIA_TYPES: &str =
+    "application/hal+json, application/problem+json, application/json";
+
 pub struct HorizonClient {
     /// The URL of the Horizon API server
     base_url: String,
+    /// The maximum number of characters of the response body that are appended to a
+    /// deserialization error. A value of `0` omits the body altogether.
+    error_body_snippet_length: usize,
+    /// The maximum size of a response body in bytes, if any. Larger responses are discarded.
+    max_response_size: Option<usize>,
+    /// The content encodings in which the Horizon server may compress response bodies.
+    accepted_encodings: Vec<ContentEncoding>,
+    /// An optional client-side rate limiter, consulted before every request.
+    rate_limiter: Option<RateLimiter>,
+    /// The rate limit state reported by the Horizon server in the most recent response.
+    last_rate_limit: Mutex<Option<RateLimitInfo>>,
+    /// The latest ledger reported by the Horizon server in the most recent response.
+    last_latest_ledger: Mutex<Option<u32>>,
+    /// The highest latest ledger reported by the Horizon server in any response, or `0` if none.
+    highest_latest_ledger: AtomicU32,
+    /// The maximum number of ledgers a response may lag behind the highest latest ledger, if any.
+    max_ledger_lag: Option<u32>,
+    /// An optional client-side circuit breaker, which rejects requests to failing endpoints.
+    circuit_breaker: Option<CircuitBreaker>,
+    /// An optional retry policy, which retries `GET` requests that failed with a transient error.
+    retry_policy: Option<RetryPolicy>,
+    /// The network served by the Horizon server, if known.
+    network: Option<Network>,
+    /// Whether the network is verified against the root resource before the first submission.
+    verify_network_on_submit: bool,
+    /// Whether the network has been verified against the root resource.
+    network_verified: AtomicBool,
+    /// The protocol version of the network served by the Horizon server, if known.
+    protocol_version: Option<u32>,
+    /// The timeout of a request, from sending it until its response has been read, if any.
+    timeout: Option<Duration>,
+    /// The timeout of opening a connection to the Horizon server, if any.
+    connect_timeout: Option<Duration>,
+    /// The headers that are sent with every request, besides the `Accept` header.
+    default_headers: reqwest::header::HeaderMap,
+    /// Whether simultaneous `GET` requests for the same URL share a single request.
+    coalesce_requests: bool,
+    /// The responses of the `GET` requests that are in flight, by URL, if requests are coalesced.
+    in_flight: Mutex<HashMap<String, InFlightResponse>>,
+    /// An optional cache of the responses for single resources.
+    cache: Option<Arc<dyn ResponseCache>>,
+    /// The cache directives of the kinds of single resources, if a cache is set.
+    cache_policy: CachePolicy,
+    /// The HTTP client, which keeps connections to the Horizon server open for reuse.
+    client: reqwest::Client,
+    /// The DNS resolver of the HTTP client, which also keeps the connection statistics.
+    resolver: Arc<CachingResolver>,
 }
 
 impl HorizonClient {
@@ -70,993 +164,1672 @@ impl HorizonClient {
     pub fn new(base_url: impl Into<String>) -> Result<Self, String> {
         let base_url = base_url.into();
         url_validate(&base_url)?;
-        Ok(Self { base_url })
+
+        let resolver = Arc::new(CachingResolver::default());
+        let accepted_encodings = ContentEncoding::ALL.to_vec();
+        let default_headers = reqwest::header::HeaderMap::new();
+        let client =
+            build_http_client(&resolver, &accepted_encodings, None, None, &default_headers)?;
+
+        Ok(Self {
+            base_url,
+            error_body_snippet_length: DEFAULT_ERROR_BODY_SNIPPET_LENGTH,
+            max_response_size: None,
+            accepted_encodings,
+            rate_limiter: None,
+            last_rate_limit: Mutex::new(None),
+            last_latest_ledger: Mutex::new(None),
+            highest_latest_ledger: AtomicU32::new(0),
+            max_ledger_lag: None,
+            circuit_breaker: None,
+            retry_policy: None,
+            network: None,
+            verify_network_on_submit: false,
+            network_verified: AtomicBool::new(false),
+            protocol_version: None,
+            timeout: None,
+            connect_timeout: None,
+            default_headers,
+            coalesce_requests: false,
+            in_flight: Mutex::new(HashMap::new()),
+            cache: None,
+            cache_policy: CachePolicy::default(),
+            client,
+            resolver,
+        })
     }
 
-    /// Sends a GET request to the Horizon server and retrieves a specified response type.
-    ///
-    /// This internal asynchronous method is designed to handle various GET requests to the
-    /// Horizon server. It is generic over the response type, allowing for flexibility in
-    /// handling different types of responses as dictated by the caller. This method performs
-    /// key tasks such as request validation, URL construction, sending the request, and
-    /// processing the received response.
-    ///
-    /// # Type Parameters
+    /// Creates a new instance of the `HorizonClient` for a network.
     ///
-    /// * `Response` - Defines the expected response type. This type must implement the
-    /// [`Response`] trait.
+    /// The client connects to the Horizon server of the network, such as the public Horizon
+    /// server of a well-known network, and has the network set.
     ///
     /// # Arguments
+    /// * `network` - The [`Network`] to connect to.
     ///
-    /// * `request` - A reference to an object implementing the [`Request`] trait. It contains
-    /// specific details about the GET request to be sent.
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result` containing the response of type [`Response`] if the request is
-    /// successful. In case of failure (e.g., network issues, server errors), it returns an
-    /// error encapsulated as a `String`.
-    ///
-    /// # Example Usage
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::network::Network;
+    /// let horizon_client = HorizonClient::for_network(Network::Testnet)
+    ///     .expect("Failed to create HorizonClient");
+    /// ```
+    pub fn for_network(network: Network) -> Result<Self, String> {
+        Ok(HorizonClient::new(network.horizon_url())?.set_network(network))
+    }
+
+    /// Creates a new instance of the `HorizonClient` from a [`HorizonClientConfig`].
     ///
-    /// This function is typically not called directly but through other specific methods of
-    /// the `HorizonClient` that define the type of request and response.
+    /// This constructor method allows deployments to configure the client without code changes,
+    /// by loading the configuration from a TOML file or from environment variables. Settings that
+    /// are omitted from the configuration keep the defaults of [`HorizonClient::new`].
     ///
-    /// # Remarks
+    /// # Arguments
+    /// * `config` - The [`HorizonClientConfig`] containing the settings of the client.
     ///
-    /// As a core utility function within `HorizonClient`, it centralizes the logic of sending
-    /// GET requests and handling responses. Modifications or enhancements to the request or
-    /// response handling logic should be implemented here to maintain consistency across the
-    /// client's interface.
+    /// # Returns
+    /// If successful, this method returns a `Result` containing the configured `HorizonClient`
+    /// instance. If the configuration is invalid, such as an invalid URL or header, it returns an
+    /// error encapsulated within `Result`.
     ///
-    async fn get<R: Response>(&self, request: &impl Request) -> Result<R, String> {
-        // Construct the URL with potential query parameters.
-        let url = request.build_url(&self.base_url);
-
-        // Send the request and await the response.
-        let response = reqwest::get(&url).await.map_err(|e| e.to_string())?;
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::{HorizonClient, HorizonClientConfig};
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = HorizonClientConfig::from_env()?;
+    /// let horizon_client = HorizonClient::from_config(&config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_config(config: &HorizonClientConfig) -> Result<Self, String> {
+        let mut horizon_client = HorizonClient::new(config.base_url.as_str())?;
 
-        // Process the response and return the result.
-        let result: R = handle_response(response).await?;
+        if let Some(network_passphrase) = &config.network_passphrase {
+            horizon_client = horizon_client.set_network_passphrase(network_passphrase);
+        }
+        horizon_client.timeout = config.timeout_ms.map(Duration::from_millis);
+        horizon_client.connect_timeout = config.connect_timeout_ms.map(Duration::from_millis);
+        horizon_client.retry_policy = config
+            .retry
+            .as_ref()
+            .map(RetryPolicy::try_from)
+            .transpose()?;
+        horizon_client.rate_limiter = config
+            .rate_limit
+            .as_ref()
+            .map(RateLimiter::try_from)
+            .transpose()?;
+        for (name, value) in &config.headers {
+            insert_header(&mut horizon_client.default_headers, name, value)?;
+        }
 
-        Ok(result)
+        horizon_client.with_http_client()
     }
 
-    /// Sends a POST request to the Horizon server and retrieves a specified response type.
-    ///
-    /// This internal asynchronous method is designed to handle various POST requests to the
-    /// Horizon server. It is generic over the response type, allowing for flexibility in
-    /// handling different types of responses as dictated by the caller. This method performs
-    /// key tasks such as request validation, URL construction, sending the request, and
-    /// processing the received response.
-    ///
-    /// # Type Parameters
+    /// Sets the number of characters of a response body that are included in deserialization errors.
     ///
-    /// * `Response` - Defines the expected response type. This type must implement the
-    /// [`Response`] trait.
+    /// When a response from the Horizon server can not be deserialized into the expected model, the
+    /// returned error names the JSON path and the expected type of the offending field. To further
+    /// ease diagnosing model drift, the start of the response body is appended to the error as well.
+    /// This method configures how much of the body is included; by default this is
+    /// 512 characters. Setting the length to `0` omits the body from the error.
     ///
     /// # Arguments
-    ///
-    /// * `request` - A reference to an object implementing the [`PostRequest`] trait. It contains
-    /// specific details about the POST request to be sent.
+    /// * `length` - The maximum number of characters of the response body to include.
     ///
     /// # Returns
+    /// The `HorizonClient` with the updated snippet length.
     ///
-    /// Returns a `Result` containing the response of type [`Response`] if the request is
-    /// successful. In case of failure (e.g., network issues, server errors), it returns an
-    /// error encapsulated as a `String`.
-    ///
-    /// # Example Usage
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_error_body_snippet_length(128);
+    /// ```
+    pub fn set_error_body_snippet_length(self, length: usize) -> Self {
+        Self {
+            error_body_snippet_length: length,
+            ..self
+        }
+    }
+
+    /// Sets the maximum size of a response body.
     ///
-    /// This function is typically not called directly but through other specific methods of
-    /// the `HorizonClient` that define the type of request and response.
+    /// Some responses, such as pages of transactions with large envelopes, can be unexpectedly
+    /// large. In memory-constrained environments, this method protects the client from buffering
+    /// such a response in full: once the body exceeds the given size, it is discarded without
+    /// being deserialized, and an [`Error::ResponseTooLarge`] is returned instead. The size
+    /// applies to the decompressed body. By default, the size of a response is not limited.
     ///
-    /// # Remarks
+    /// # Arguments
+    /// * `max_size` - The maximum size of a response body, in bytes.
     ///
-    /// As a core utility function within `HorizonClient`, it centralizes the logic of sending
-    /// POST requests and handling responses. Modifications or enhancements to the request or
-    /// response handling logic should be implemented here to maintain consistency across the
-    /// client's interface.
+    /// # Returns
+    /// The `HorizonClient` with the maximum response size set.
     ///
-    async fn post<R: Response>(&self, request: &impl PostRequest) -> Result<R, String> {
-        // Construct the URL.
-        let url = request.build_url(&self.base_url);
-
-        // Send the request and await the response.
-        // The vector of tuples (containing the key/value pairs) returned by the `get_body()` method can
-        // be passed directly to `reqwest`s `form()` method, which will automatically create a valid
-        // formdata body for the request.
-        let response = reqwest::Client::new()
-            .post(&url)
-            .form(&request.get_body())
-            .send()
-            .await
-            .map_err(|e| e.to_string())?;
-
-        // Process the response and return the result.
-        let result: R = handle_response(response).await?;
-
-        Ok(result)
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_max_response_size(4 * 1024 * 1024);
+    /// ```
+    pub fn set_max_response_size(self, max_size: usize) -> Self {
+        Self {
+            max_response_size: Some(max_size),
+            ..self
+        }
     }
 
-    /// Retrieves a list of accounts filtered by specific criteria.
+    /// Sets the content encodings in which the Horizon server may compress response bodies.
     ///
-    /// This method retrieves a list of accounts from the Horizon server, filtering the results
-    /// based on one of four categories: sponsor, signer, asset, or liquidity pool.
+    /// The accepted encodings are advertised in the `Accept-Encoding` header of every request, and
+    /// compressed responses are decompressed transparently. By default, all encodings in
+    /// [`ContentEncoding::ALL`] are accepted. Passing an empty slice requests uncompressed
+    /// responses, which trades bandwidth for the CPU time spent on decompression.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-accounts">List All Accounts</a>
-    /// endpoint.
+    /// Changing the encodings replaces the underlying HTTP client, so connections that were opened
+    /// before, for example by [`HorizonClient::warm_up`], are not reused.
     ///
     /// # Arguments
-    /// * `request` - A reference to an implementation of the [`ValidAccountsRequest`] trait,
-    /// which specifies the filter criteria for the account list request.
+    /// * `encodings` - The content encodings to accept.
     ///
     /// # Returns
-    /// If successful, this method returns a `Result` containing an [`AccountsResponse`],
-    /// which encapsulates the list of accounts retrieved from the server.
-    /// In case of a failure in the request, it returns an error encapsulated within `Result`.
+    /// The `HorizonClient` with the accepted encodings set, or an error if the HTTP client could
+    /// not be created.
     ///
     /// # Example
-    /// To use this method, create an instance of [`AccountsRequest`] and set at least
-    /// one of the four filter options. For example, filtering by signer:
-    ///
     /// ```rust
-    /// # use stellar_rs::accounts::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::connection::ContentEncoding;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = AccountsRequest::new()
-    ///     .set_signer_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
-    ///     .unwrap();
-    ///
-    /// let response: Result<AccountsResponse, String> = horizon_client
-    ///     .get_account_list(&request)
-    ///     .await;
-    ///
-    /// // Access the account details
-    /// for record in response?.embedded().records() {
-    ///     println!("Account ID: {}", record.account_id());
-    ///     // Further processing...
-    ///  }
-    ///
-    /// # Ok({})
-    /// # }
-    pub async fn get_account_list(
-        &self,
-        request: &impl ValidAccountsRequest,
-    ) -> Result<AccountsResponse, String> {
-        self.get::<AccountsResponse>(request).await
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_accepted_encodings(&[ContentEncoding::Gzip])
+    ///     .expect("Failed to create HorizonClient");
+    /// ```
+    pub fn set_accepted_encodings(self, encodings: &[ContentEncoding]) -> Result<Self, String> {
+        Self {
+            accepted_encodings: encodings.to_vec(),
+            ..self
+        }
+        .with_http_client()
     }
 
-    /// Retrieves detailed information for a specific account from the Horizon server.
-    ///
-    /// This asynchronous method is designed to fetch information for a single account on the Horizon server.
-    /// It requires a [`SingleAccountRequest`] with the account ID to be queried.
+    /// Returns the content encodings in which the Horizon server may compress response bodies.
+    pub fn accepted_encodings(&self) -> &[ContentEncoding] {
+        &self.accepted_encodings
+    }
+
+    /// Sets a client-side rate limiter.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/retrieve-an-account">Retrieve an Account</a>
-    /// endpoint.
+    /// Once set, every request sent by this client first takes a token from the given
+    /// [`RateLimiter`]. When no tokens are available, the request is delayed until the bucket has
+    /// been refilled, which keeps bulk operations under the rate limits enforced by Horizon.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`SingleAccountRequest`] instance, containing the
-    /// account ID for which details are to be fetched.
+    /// * `rate_limiter` - The [`RateLimiter`] to consult before each request.
     ///
     /// # Returns
+    /// The `HorizonClient` with the rate limiter set.
     ///
-    /// On success, returns a `Result` wrapping a [`SingleAccountResponse`], which includes the
-    /// detailed information of the requested account. If the request fails, it returns an error
-    /// encapsulated within `Result`.
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::rate_limit::RateLimiter;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_rate_limiter(RateLimiter::new(10, 5).unwrap());
+    /// ```
+    pub fn set_rate_limiter(self, rate_limiter: RateLimiter) -> Self {
+        Self {
+            rate_limiter: Some(rate_limiter),
+            ..self
+        }
+    }
+
+    /// Sets a client-side circuit breaker.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`SingleAccountRequest`] and set the
-    /// account ID of the target account.
+    /// Once set, the outcome of every request sent by this client is recorded per endpoint. After
+    /// repeated failures of an endpoint, such as during a partial outage of Horizon, requests to
+    /// that endpoint fail immediately with [`Error::CircuitOpen`], until a probe shows that the
+    /// endpoint has recovered. Requests to other endpoints are sent as usual.
     ///
-    /// ```
-    /// # use stellar_rs::accounts::prelude::*;
-    /// # use stellar_rs::models::Request;
-    /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = SingleAccountRequest::new()
-    ///     .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
-    ///     .unwrap();
+    /// # Arguments
+    /// * `circuit_breaker` - The [`CircuitBreaker`] to consult before each request.
     ///
-    /// let response = horizon_client.get_single_account(&request).await;
+    /// # Returns
+    /// The `HorizonClient` with the circuit breaker set.
     ///
-    /// // Access the account details
-    /// println!("Account ID: {}", response?.account_id());
-    /// # Ok({})
-    /// # }
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::circuit_breaker::CircuitBreaker;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use std::time::Duration;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(30)).unwrap());
     /// ```
-    ///
-    pub async fn get_single_account(
-        &self,
-        request: &SingleAccountRequest<AccountId>,
-    ) -> Result<Account, String> {
-        self.get::<Account>(request).await
+    pub fn set_circuit_breaker(self, circuit_breaker: CircuitBreaker) -> Self {
+        Self {
+            circuit_breaker: Some(circuit_breaker),
+            ..self
+        }
     }
 
-    /// Retrieves a list of all assets.
-    ///
-    /// This asynchronous method fetches a complete list of assets.
-    /// It requires a [`AllAssetsRequest`] to specify  optional query parameters
-    /// such as filters by `asset_code` or `asset_issuer`.
+    /// Returns the circuit breaker of this client, if any, to inspect the state of its circuits.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Sets the retry policy of failed requests.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-assets">List all Assets</a>
-    /// endpoint.
+    /// Once set, `GET` requests that fail with a transient error, such as a dropped connection or
+    /// a `503 Service Unavailable` response, are sent again after a backoff, as described by the
+    /// [`RetryPolicy`]. Every attempt passes the rate limiter and the circuit breaker, if any.
+    /// Transactions are never resubmitted.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllAssetsRequest`] instance, containing the
-    /// parameters for the assets list request.
+    /// * `retry_policy` - The [`RetryPolicy`] of failed requests.
     ///
     /// # Returns
-    ///
-    /// On success, this method returns a `Result` wrapping an [`AllAssetsResponse`], which includes
-    /// the comprehensive list of assets retrieved from the Horizon server. If the request
-    /// encounters an issue, an error is returned within `Result`.
+    /// The `HorizonClient` with the retry policy set.
     ///
     /// # Example
-    /// To use this method, create an instance of [`AllAssetsRequest`], set any desired
-    /// filters or parameters and pass
-    ///
-    /// ```
-    /// # use stellar_rs::assets::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
     /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllAssetsRequest::new()
-    ///     .set_asset_code("USD")
-    ///     .unwrap();
-    ///
-    /// let response = horizon_client.get_all_assets(&request).await;
-    ///
-    /// // Access asset details
-    /// for asset in response?.embedded().records() {
-    ///     println!("Asset Code: {}", asset.asset_code());
-    ///     // Further processing...
-    /// }
-    /// # Ok({})
-    /// # }
+    /// # use stellar_rs::retry::RetryPolicy;
+    /// # use std::time::Duration;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_retry_policy(
+    ///         RetryPolicy::new(3, Duration::from_millis(100), Duration::from_secs(2)).unwrap(),
+    ///     );
     /// ```
-    ///
-    pub async fn get_all_assets(
-        &self,
-        request: &AllAssetsRequest,
-    ) -> Result<AllAssetsResponse, String> {
-        self.get::<AllAssetsResponse>(request).await
+    pub fn set_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(retry_policy),
+            ..self
+        }
     }
 
-    /// Retrieves all claimable balances.
-    ///
-    /// This asynchronous method queries the Horizon server for all claimable balances. It
-    /// requires a [`AllClaimableBalancesRequest`] to specify the query parameters.
+    /// Returns the retry policy of this client, if any.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Sets the network served by the Horizon server.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-claimable-balances">List All Claimable Balances</a>
-    /// endpoint.
+    /// The client keeps the network along with the URL of the server, so that code signing or
+    /// submitting transactions can take the passphrase of the network from the same
+    /// configuration. The network is also what [`verify_network`](HorizonClient::verify_network)
+    /// checks the server against.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllClaimableBalancesRequest`] instance, which contains
-    /// the parameters for the claimable balances request.
+    /// * `network` - The [`Network`] served by the Horizon server.
     ///
     /// # Returns
+    /// The `HorizonClient` with the network set.
     ///
-    /// Returns a `Result` containing an [`AllClaimableBalancesResponse`] with the list of all
-    /// claimable balances if successful. In case of a failure, it returns an error within `Result`.
-    ///
-    /// # Example
-    /// To use this method, create an instance of [`AllClaimableBalancesRequest`], set any desired
-    /// filters or parameters and pass
+    pub fn set_network(self, network: Network) -> Self {
+        Self {
+            network: Some(network),
+            network_verified: AtomicBool::new(false),
+            ..self
+        }
+    }
+
+    /// Returns the network served by the Horizon server, if set.
+    pub fn network(&self) -> Option<&Network> {
+        self.network.as_ref()
+    }
+
+    /// Sets the passphrase of the network served by the Horizon server.
     ///
-    /// ```
-    /// # use stellar_rs::claimable_balances::prelude::*;
-    /// # use stellar_rs::models::Request;
-    /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllClaimableBalancesRequest::new()
-    ///     .set_sponsor("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
-    ///     .unwrap();
+    /// The passphrase of a well-known network sets the matching preset of [`Network`]; any other
+    /// passphrase sets a [`Network::Custom`] served by the Horizon server of this client.
     ///
-    /// let response = horizon_client.get_all_claimable_balances(&request).await;
+    /// # Arguments
+    /// * `network_passphrase` - The passphrase of the network, such as
+    ///   `"Test SDF Network ; September 2015"`.
     ///
-    /// match response {
-    ///     Ok(all_balances) => {
-    ///         for balance in all_balances.embedded().records() {
-    ///             println!("Balance ID: {}", balance.id());
-    ///             // Further processing...
-    ///         }
-    ///     },
-    ///     Err(e) => eprintln!("Error fetching claimable balances: {}", e),
-    /// }
-    /// # Ok({})
-    /// # }
-    /// ```
+    /// # Returns
+    /// The `HorizonClient` with the network passphrase set.
     ///
-    pub async fn get_all_claimable_balances(
-        &self,
-        request: &AllClaimableBalancesRequest,
-    ) -> Result<AllClaimableBalancesResponse, String> {
-        self.get::<AllClaimableBalancesResponse>(request).await
+    pub fn set_network_passphrase(self, network_passphrase: impl Into<String>) -> Self {
+        let network_passphrase = network_passphrase.into();
+        let network = Network::from_passphrase(&network_passphrase)
+            .unwrap_or_else(|| Network::custom(self.base_url.as_str(), network_passphrase));
+
+        self.set_network(network)
     }
 
-    /// Retrieves detailed information about a specific claimable balance from the Horizon server.
+    /// Returns the passphrase of the network served by the Horizon server, if set.
+    pub fn network_passphrase(&self) -> Option<&str> {
+        self.network.as_ref().map(Network::passphrase)
+    }
+
+    /// Sets whether the network is verified before the first transaction is submitted.
     ///
-    /// This asynchronous method is used to fetch detailed information about a single claimable
-    /// balance from the Horizon server. It requires a [`SingleClaimableBalanceRequest`] that
-    /// includes the unique identifier of the claimable balance to be retrieved.
+    /// When enabled, the first call to [`post_transaction`](HorizonClient::post_transaction),
+    /// [`submit_transaction`](HorizonClient::submit_transaction) or
+    /// [`post_transaction_async`](HorizonClient::post_transaction_async) first calls
+    /// [`verify_network`](HorizonClient::verify_network), and fails without submitting the
+    /// transaction if the Horizon server serves another network than the one set on the client.
+    /// This prevents accidentally submitting a transaction signed for the test network to the
+    /// public network, or vice versa. Once verified, the network is not checked again.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/retrieve-a-claimable-balance">Retrieve a Claimable Balance</a>
-    /// endpoint.
+    /// # Arguments
+    /// * `verify_network_on_submit` - Whether to verify the network before the first submission.
+    ///
+    /// # Returns
+    /// The `HorizonClient` with the verification enabled or disabled.
+    ///
+    pub fn set_verify_network_on_submit(self, verify_network_on_submit: bool) -> Self {
+        Self {
+            verify_network_on_submit,
+            ..self
+        }
+    }
+
+    /// Verifies the network before the first submission, if enabled with
+    /// [`set_verify_network_on_submit`](HorizonClient::set_verify_network_on_submit).
+    async fn verify_network_before_submit(&self) -> Result<(), Error> {
+        if self.verify_network_on_submit && !self.network_verified.load(Ordering::Relaxed) {
+            self.verify_network().await?;
+            self.network_verified.store(true, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    /// Sets the protocol version of the network served by the Horizon server.
+    ///
+    /// Some query parameters, such as the `liquidity_pool` filter, are only supported by networks
+    /// that run a recent enough protocol. Once the protocol version is set, the client omits the
+    /// parameters that the network does not support yet from its requests, so that the same code
+    /// can run against older networks. See
+    /// [`min_protocol_version`](crate::network::min_protocol_version) for the protocol version
+    /// that a parameter requires. By default, all parameters are sent.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`SingleClaimableBalanceRequest`] instance containing the
-    /// unique ID of the claimable balance to be fetched.
+    /// * `protocol_version` - The protocol version of the network, as reported by its ledgers.
     ///
     /// # Returns
+    /// The `HorizonClient` with the protocol version set.
     ///
-    /// On successful execution, returns a `Result` containing a [`ClaimableBalance`],
-    /// which includes detailed information about the requested claimable balance. If the request
-    /// fails, it returns an error within `Result`.
+    pub fn set_protocol_version(self, protocol_version: u32) -> Self {
+        Self {
+            protocol_version: Some(protocol_version),
+            ..self
+        }
+    }
+
+    /// Returns the protocol version of the network served by the Horizon server, if set.
+    pub fn protocol_version(&self) -> Option<u32> {
+        self.protocol_version
+    }
+
+    /// Sets the timeout of a request.
     ///
-    /// # Example
-    /// To use this method, create an instance of [`SingleClaimableBalanceRequest`]
-    /// with the specific claimable balance ID.
+    /// The timeout applies from sending a request until its response has been read in full. A
+    /// request that times out fails with an [`Error::Http`], which is retried if a
+    /// [`RetryPolicy`] is set. By default, requests do not time out.
     ///
-    /// ```
-    /// # use stellar_rs::claimable_balances::prelude::*;
-    /// # use stellar_rs::models::Request;
-    /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    ///  let request = SingleClaimableBalanceRequest::new()
-    ///    .set_claimable_balance_id("000000006520216af66d20d63a58534d6cbdf28ba9f2a9c1e03f8d9a756bb7d988b29bca");
+    /// Changing the timeout replaces the underlying HTTP client, so connections that were opened
+    /// before are not reused.
     ///
-    /// let response = horizon_client.get_single_claimable_balance(&request).await;
+    /// # Arguments
+    /// * `timeout` - The timeout of a request.
     ///
-    /// // Access the details of the claimable balance
-    /// if let Ok(balance_response) = response {
-    ///     println!("Balance Amount: {}", balance_response.amount());
-    ///     // Further processing...
-    /// }
+    /// # Returns
+    /// The `HorizonClient` with the timeout set, or an error if the HTTP client could not be
+    /// created.
     ///
-    /// # Ok({})
-    /// # }
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use std::time::Duration;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_timeout(Duration::from_secs(30))
+    ///     .expect("Failed to create HorizonClient");
     /// ```
-    ///
-    pub async fn get_single_claimable_balance(
-        &self,
-        request: &SingleClaimableBalanceRequest<ClaimableBalanceId>,
-    ) -> Result<ClaimableBalance, String> {
-        self.get::<ClaimableBalance>(request).await
+    pub fn set_timeout(self, timeout: Duration) -> Result<Self, String> {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+        .with_http_client()
     }
 
-    /// Retrieves a list of effects for a specific account from the Horizon server.
+    /// Returns the timeout of a request, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the timeout of opening a connection to the Horizon server.
     ///
-    /// This asynchronous method fetches a list of effects for a specific account from the Horizon server.
-    /// It requires an [`EffectsForAccountRequest`] to specify the account ID and optional query parameters.
+    /// Changing the timeout replaces the underlying HTTP client, so connections that were opened
+    /// before are not reused.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`EffectsForAccountRequest`] instance, containing the account ID
-    /// and optional query parameters for the effects request.
+    /// * `connect_timeout` - The timeout of opening a connection.
     ///
     /// # Returns
+    /// The `HorizonClient` with the connect timeout set, or an error if the HTTP client could not
+    /// be created.
     ///
-    /// On successful execution, returns a `Result` containing an [`EffectsForAccountResponse`], which includes
-    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
-    ///
-    /// # Example
-    /// To use this method, create an instance of [`EffectsForAccountRequest`] and set the account ID and any
-    /// desired query parameters.
+    pub fn set_connect_timeout(self, connect_timeout: Duration) -> Result<Self, String> {
+        Self {
+            connect_timeout: Some(connect_timeout),
+            ..self
+        }
+        .with_http_client()
+    }
+
+    /// Returns the timeout of opening a connection to the Horizon server, if any.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Sets a header that is sent with every request.
     ///
-    /// ```
-    /// # use stellar_rs::effects::prelude::*;
-    /// # use stellar_rs::models::Request;
-    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// Default headers allow the client to identify itself, or to authenticate with a Horizon
+    /// deployment behind a gateway. Setting a header that was set before replaces its value.
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = EffectsForAccountRequest::new()
-    ///    .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7");
+    /// Changing the headers replaces the underlying HTTP client, so connections that were opened
+    /// before are not reused.
     ///
-    /// let response = horizon_client.get_effects_for_account(&request).await;
+    /// # Arguments
+    /// * `name` - The name of the header.
+    /// * `value` - The value of the header.
     ///
-    /// // Access the effects
-    /// if let Ok(effects_response) = response {
-    ///    for effect in effects_response.embedded().records() {
-    ///       println!("Effect ID: {}", effect.id());
-    ///      // Further processing...
-    ///   }
-    /// }
+    /// # Returns
+    /// The `HorizonClient` with the header set, or an error if the name or value is not a valid
+    /// header.
     ///
-    /// # Ok({})
-    /// # }
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_default_header("X-Client-Name", "my-app")
+    ///     .expect("Invalid header");
     /// ```
-    ///
-    pub async fn get_effects_for_account(
-        &self,
-        request: &EffectsForAccountRequest,
-    ) -> Result<EffectsResponse, String> {
-        self.get::<EffectsResponse>(request).await
+    pub fn set_default_header(mut self, name: &str, value: &str) -> Result<Self, String> {
+        insert_header(&mut self.default_headers, name, value)?;
+        self.with_http_client()
     }
 
-    /// Retrieves a list of effects for a specific account from the Horizon server.
+    /// Sets the maximum number of ledgers that a response may lag behind.
     ///
-    /// This asynchronous method fetches a list of effects for a specific account from the Horizon server.
-    /// It requires an [`EffectsForLiquidityPoolsRequest`] to specify the account ID and optional query parameters.
+    /// Every response of the Horizon server reports the latest ledger it has ingested in the
+    /// `Latest-Ledger` header. Behind a load balancer, a request may be served by an instance that
+    /// lags behind the others, and an instance that falls behind keeps serving outdated data. With
+    /// this guard set, the client remembers the highest latest ledger reported by any response,
+    /// and rejects responses whose latest ledger lags behind it by more than `max_lag` ledgers with
+    /// an [`Error::StaleResponse`], protecting trading systems from acting on stale data. Stale
+    /// responses are retried by the retry policy, if any. By default, responses are not checked.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`EffectsForLiquidityPoolsRequest`] instance, containing the account ID
-    /// and optional query parameters for the effects request.
+    /// * `max_lag` - The maximum number of ledgers a response may lag behind. A value of `0`
+    ///   rejects every response that is behind the highest latest ledger.
     ///
     /// # Returns
-    ///
-    /// On successful execution, returns a `Result` containing an [`EffectsForLiquidityPoolResponse`], which includes
-    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// The `HorizonClient` with the staleness guard set.
     ///
     /// # Example
-    /// To use this method, create an instance of [`EffectsForLiquidityPoolsRequest`] and set the account ID and any
-    /// desired query parameters.
-    ///
-    /// ```
-    /// # use stellar_rs::effects::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_max_ledger_lag(2);
+    /// ```
+    pub fn set_max_ledger_lag(self, max_lag: u32) -> Self {
+        Self {
+            max_ledger_lag: Some(max_lag),
+            ..self
+        }
+    }
+
+    /// Sets whether simultaneous `GET` requests for the same URL share a single request.
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = EffectsForAccountRequest::new()
-    ///    .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7");
+    /// Applications such as user interfaces often fetch the same resource from several places at
+    /// once. With coalescing enabled, a `GET` request for a URL that is already being requested by
+    /// this client is not sent again; instead, it waits for the request in flight and shares its
+    /// response. This saves round trips, and reduces the load on the Horizon server and the rate
+    /// limit. Since requests with equal parameters build equal URLs, equal requests are
+    /// coalesced. By default, requests are not coalesced.
     ///
-    /// let response = horizon_client.get_effects_for_account(&request).await;
+    /// Only requests that are in flight at the same time are coalesced; responses are not cached.
+    /// If the request in flight is cancelled, the waiting requests send their own request.
     ///
-    /// // Access the effects
-    /// if let Ok(effects_response) = response {
-    ///    for effect in effects_response.embedded().records() {
-    ///       println!("Effect ID: {}", effect.id());
-    ///      // Further processing...
-    ///   }
-    /// }
+    /// # Arguments
+    /// * `coalesce_requests` - Whether to coalesce simultaneous requests for the same URL.
+    ///
+    /// # Returns
+    /// The `HorizonClient` with request coalescing enabled or disabled.
     ///
-    /// # Ok({})
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::ledgers::prelude::LedgersRequest;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?
+    ///     .set_request_coalescing(true);
+    ///
+    /// // Both calls share a single round trip to the Horizon server.
+    /// let request = LedgersRequest::new();
+    /// let (first, second) = futures::join!(
+    ///     horizon_client.get_all_ledgers(&request),
+    ///     horizon_client.get_all_ledgers(&request),
+    /// );
+    /// # Ok(())
     /// # }
     /// ```
-    ///
-    pub async fn get_effects_for_liquidity_pools(
-        &self,
-        request: &EffectsForLiquidityPoolRequest,
-    ) -> Result<EffectsResponse, String> {
-        self.get::<EffectsResponse>(request).await
+    pub fn set_request_coalescing(self, coalesce_requests: bool) -> Self {
+        Self {
+            coalesce_requests,
+            ..self
+        }
     }
 
-    /// Retrieves a list of effects for a specific operation from the Horizon server.
+    /// Sets the cache of the responses for single resources.
     ///
-    /// This asynchronous method fetches a list of effects for a specific operation from the Horizon server.
-    /// It requires an [`EffectsForOperationRequest`] to specify the operation ID and optional query parameters.
+    /// Explorer backends and wallets often request the same ledgers, transactions and accounts
+    /// over and over. Once a cache is set, the body of every successful response for a single
+    /// resource, such as `/ledgers/{sequence}` or `/accounts/{account_id}`, is stored in the
+    /// cache as directed by the [`CachePolicy`] of this client, and later requests for the same URL
+    /// are served from the cache without a round trip. Lists, such as `/ledgers`, are never cached.
+    ///
+    /// Submitting a transaction invalidates all cached resources that can change, such as
+    /// accounts and offers. Changes made by other clients are only seen once the cached response
+    /// expires, or after it is invalidated with [`HorizonClient::invalidate_cached`].
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`EffectsForOperationRequest`] instance, containing the operation ID
-    /// and optional query parameters for the effects request.
+    /// * `cache` - The [`ResponseCache`] to store the responses in, such as a
+    ///   `MokaCache` with the `moka` feature.
     ///
     /// # Returns
-    ///
-    /// On successful execution, returns a `Result` containing an [`EffectsResponse`], which includes
-    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// The `HorizonClient` with the cache set.
     ///
     /// # Example
-    /// To use this method, create an instance of [`EffectsForOperationRequest`] and set the operation ID and any
-    /// desired query parameters.
-    ///
-    /// ```
-    /// # use stellar_rs::effects::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
+    /// # use stellar_rs::cache::ResponseCache;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = EffectsForOperationRequest::new()
-    ///   .set_operation_id("123");
-    ///     
-    /// let response = horizon_client.get_effects_for_operation(&request).await;
-    ///
-    /// // Access the effects
-    /// if let Ok(effects_response) = response {
-    ///     for effect in effects_response.embedded().records() {
-    ///         println!("Effect ID: {}", effect.id());
-    ///     // Further processing...
-    ///     }
-    /// }
-    ///
-    /// # Ok({})
+    /// # fn example(cache: impl ResponseCache + 'static) {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_cache(cache);
     /// # }
     /// ```
-    ///
-    pub async fn get_effects_for_operation(
-        &self,
-        request: &EffectsForOperationRequest,
-    ) -> Result<EffectsResponse, String> {
-        self.get::<EffectsResponse>(request).await
+    pub fn set_cache(self, cache: impl ResponseCache + 'static) -> Self {
+        Self {
+            cache: Some(Arc::new(cache)),
+            ..self
+        }
     }
 
-    /// Retrieves a list of effects for a specific transaction from the Horizon server.
+    /// Sets the cache directives of the kinds of single resources.
     ///
-    /// This asynchronous method fetches a list of effects for a specific transaction from the Horizon server.
-    /// It requires an [`EffectForTransactionRequest`] to specify the transaction hash and optional query parameters.
+    /// The policy only takes effect once a cache is set with [`HorizonClient::set_cache`].
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`EffectForTransactionRequest`] instance, containing the transaction hash
-    /// and optional query parameters for the effects request.
+    /// * `cache_policy` - The [`CachePolicy`], which tells whether and for how long the responses
+    ///   for each kind of resource are cached.
     ///
     /// # Returns
-    ///
-    /// On successful execution, returns a `Result` containing an [`EffectsResponse`], which includes
-    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// The `HorizonClient` with the cache policy set.
     ///
     /// # Example
-    /// To use this method, create an instance of [`EffectForTransactionRequest`] and set the transaction hash and any
-    /// desired query parameters.
-    ///
-    /// ```
-    /// # use stellar_rs::effects::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
+    /// # use stellar_rs::cache::{CacheDirective, CachePolicy, ResourceKind};
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+    ///     .expect("Failed to create HorizonClient")
+    ///     .set_cache_policy(
+    ///         CachePolicy::default().set_directive(ResourceKind::Account, CacheDirective::NoStore),
+    ///     );
+    /// ```
+    pub fn set_cache_policy(self, cache_policy: CachePolicy) -> Self {
+        Self {
+            cache_policy,
+            ..self
+        }
+    }
+
+    /// Returns the cache directives of the kinds of single resources.
+    pub fn cache_policy(&self) -> &CachePolicy {
+        &self.cache_policy
+    }
+
+    /// Removes the cached response for a request, if any, so that the next identical request is
+    /// sent to the Horizon server.
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = EffectForTransactionRequest::new()
-    ///  .set_transaction_hash("transaction_hash");
-    ///
-    /// let response = horizon_client.get_effects_for_transaction(&request).await;
+    /// # Arguments
+    /// * `request` - The request of which to remove the cached response.
     ///
-    /// // Access the effects
-    /// if let Ok(effects_response) = response {
-    ///     for effect in effects_response.embedded().records() {
-    ///         println!("Effect ID: {}", effect.id());
-    ///     // Further processing...
-    ///     }
-    /// }
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::accounts::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+    /// let request = SingleAccountRequest::new()
+    ///     .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")
+    ///     .unwrap();
+    /// horizon_client.invalidate_cached(&request);
+    /// ```
+    pub fn invalidate_cached(&self, request: &impl Request) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate(&request.build_url(&self.base_url));
+        }
+    }
+
+    /// Removes the cached responses for all resources of a kind, such as all accounts.
+    pub fn invalidate_cached_kind(&self, kind: ResourceKind) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate_matching(&|url| {
+                ResourceKind::from_url(&self.base_url, url) == Some(kind)
+            });
+        }
+    }
+
+    /// Removes all cached responses.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Removes the cached responses for all resources that can change, such as accounts, since a
+    /// transaction may have changed them.
+    fn invalidate_mutable_cached(&self) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate_matching(&|url| {
+                ResourceKind::from_url(&self.base_url, url).is_some_and(|kind| kind.is_mutable())
+            });
+        }
+    }
+
+    /// Replaces the HTTP client with one that reflects the current settings of this client.
+    fn with_http_client(self) -> Result<Self, String> {
+        let client = build_http_client(
+            &self.resolver,
+            &self.accepted_encodings,
+            self.timeout,
+            self.connect_timeout,
+            &self.default_headers,
+        )?;
+
+        Ok(Self { client, ..self })
+    }
+
+    /// Returns the rate limit state reported by the Horizon server in the most recent response.
+    ///
+    /// The state is parsed from the `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+    /// `X-RateLimit-Reset` headers, and is updated after each call made by this client.
+    ///
+    /// # Returns
+    /// The most recent [`RateLimitInfo`], or `None` if no request has been made yet or the last
+    /// response did not contain the rate limit headers.
+    ///
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        *self
+            .last_rate_limit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the latest ledger reported by the Horizon server in the most recent response.
+    ///
+    /// The ledger is parsed from the `Latest-Ledger` header, and is updated after each call made by
+    /// this client. It tells how recent the data of the response is.
     ///
-    /// # Ok({})
+    /// # Returns
+    /// The sequence number of the latest ledger, or `None` if no request has been made yet or the
+    /// last response did not contain the `Latest-Ledger` header.
+    ///
+    pub fn latest_ledger(&self) -> Option<u32> {
+        *self
+            .last_latest_ledger
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Establishes a connection to the Horizon server ahead of time.
+    ///
+    /// Opening a connection requires a DNS lookup and the TCP and TLS handshakes, which add
+    /// considerable latency to the first request. This method resolves the host of the Horizon
+    /// server and sends a `HEAD` request to its root, so that the connection is kept open and
+    /// reused by the next request. Call it before a latency-critical window, such as submitting a
+    /// time-sensitive transaction. Idle connections are closed after a while, so warming up long
+    /// before the request has no effect.
+    ///
+    /// # Returns
+    /// An empty `Result`, or an error if the host could not be resolved or the Horizon server
+    /// could not be reached. The status of the response is not checked.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// horizon_client.warm_up().await?;
+    ///
+    /// // The next request reuses the established connection.
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_effects_for_transaction(
-        &self,
-        request: &EffectForTransactionRequest,
-    ) -> Result<EffectsResponse, String> {
-        self.get::<EffectsResponse>(request).await
+    pub async fn warm_up(&self) -> Result<(), Error> {
+        let url = Url::parse(&self.base_url).map_err(|e| e.to_string())?;
+        if let Some(host) = url.host_str() {
+            self.resolver.pre_resolve(host).await?;
+        }
+
+        self.acquire_rate_limit().await;
+        self.resolver.record_request();
+        let response = crate::runtime::send(self.client.head(url)).await?;
+        self.record_rate_limit(&response);
+
+        Ok(())
     }
 
-    /// Retrieves a list of all ledgers.
+    /// Returns statistics about the connections opened and reused by this client.
     ///
-    /// This asynchronous method is designed to fetch list of ledgers
-    /// from the Horizon server. It requires a [`LedgersRequest`] to specify the parameters
-    /// for the ledger retrieval.
+    /// # Returns
+    /// The [`ConnectionStats`], counting all requests sent by this client so far.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-ledgers">List All Ledgers</a>
-    /// endpoint.
+    pub fn connection_stats(&self) -> ConnectionStats {
+        self.resolver.stats()
+    }
+
+    /// Measures the round-trip latency to the Horizon server.
+    ///
+    /// This method sends a `HEAD` request to the root of the Horizon server, and measures the
+    /// time until the headers of its response arrive. The response carries no body, so the ping
+    /// is cheap, and it bypasses the cache, the retry policy and the circuit breaker, so that it
+    /// reflects the actual state of the server. It does respect the client-side rate limiter, if
+    /// any. Like every request, the first ping opens a connection, so its latency includes the
+    /// DNS lookup and the handshakes; see [`HorizonClient::warm_up`].
+    ///
+    /// # Returns
+    /// A [`Ping`] with the latency, the status of the response, and the health of the server
+    /// derived from them, or an error if the Horizon server could not be reached.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    ///
+    /// let ping = horizon_client.ping().await?;
+    /// println!("{:?} in {:?}", ping.health(), ping.latency());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn ping(&self) -> Result<Ping, Error> {
+        self.acquire_rate_limit().await;
+        self.resolver.record_request();
+
+        let start = Instant::now();
+        let response = crate::runtime::send(self.client.head(&self.base_url)).await?;
+        let latency = start.elapsed();
+        self.record_rate_limit(&response);
+
+        Ok(Ping::new(response.status(), latency))
+    }
+
+    /// Pings the Horizon server every `interval`, and records the latencies in a
+    /// [`LatencyMonitor`].
+    ///
+    /// The returned stream yields every ping, and does not end by itself. It is meant to be
+    /// consumed by a background task, while the rolling percentiles are read from the monitor,
+    /// or from any of its clones. Pings that fail are yielded as errors, and are not recorded in
+    /// the monitor. To stop monitoring, drop the stream.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`LedgersRequest`] instance, specifying the query
-    /// parameters for retrieving the ledgers.
+    /// * `monitor` - The monitor that records the latencies.
+    /// * `interval` - The time to wait between two pings.
     ///
     /// # Returns
+    /// Returns a `Stream` of `Result`s, each containing a [`Ping`], or an error if the Horizon
+    /// server could not be reached.
     ///
-    /// On successful execution, returns a `Result` containing a [`LedgersResponse`],
-    /// which includes the list of all ledgers obtained from the Horizon server. If the request
-    /// fails, it returns an error within `Result`.
+    /// See [`LatencyMonitor`] for an example.
     ///
-    /// # Example
-    /// To use this method, create an instance of [`LedgersRequest`], set any
-    /// desired pagination parameters.
+    pub fn monitor_latency<'a>(
+        &'a self,
+        monitor: &LatencyMonitor,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Ping, Error>> + 'a {
+        let state = (monitor.clone(), false);
+
+        stream::unfold(state, move |(monitor, pinged)| async move {
+            if pinged {
+                crate::runtime::sleep(interval).await;
+            }
+
+            let ping = self.ping().await;
+            if let Ok(ping) = &ping {
+                monitor.record(*ping.latency());
+            }
+            Some((ping, (monitor, true)))
+        })
+    }
+
+    /// Follows a navigational link of a response, such as the `operations` link of a transaction,
+    /// and retrieves the linked resource.
+    ///
+    /// Horizon embeds links to related resources in the `_links` of every response. Templated
+    /// links, such as `.../operations{?cursor,limit,order}`, are resolved with the given
+    /// pagination parameters, leaving out the parameters that are not set. The responses also
+    /// offer typed methods for their most common links, such as
+    /// [`TransactionResponse::operations`], which follow the link with the default pagination.
+    ///
+    /// # Arguments
+    /// * `href` - The URL of the link, which may be templated.
+    /// * `page_params` - The pagination parameters with which to resolve a templated link.
     ///
+    /// # Returns
+    ///
+    /// On success, returns a `Result` containing the linked resource, deserialized into `R`. If
+    /// the request fails, or if the resource is not an `R`, it returns an error within `Result`.
+    ///
+    /// # Usage
     /// ```
-    /// # use stellar_rs::ledgers::prelude::*;
-    /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::models::pagination::PageParams;
+    /// # use stellar_rs::operations::prelude::OperationResponse;
+    /// # use stellar_rs::transactions::prelude::TransactionResponse;
     /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = LedgersRequest::new()
-    ///     .set_limit(2)
-    ///     .unwrap();
+    /// # async fn example(transaction: TransactionResponse) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let mut page_params = PageParams::new();
+    /// page_params.set_limit(2)?;
     ///
-    /// let response = horizon_client.get_all_ledgers(&request).await;
-    /// // Process the response
-    /// match response {
-    ///     Ok(ledgers_response) => {
-    ///         for ledger in ledgers_response.embedded().records() {
-    ///             println!("Ledger ID: {}", ledger.id());
-    ///             // Further processing...
-    ///         }
-    ///     }
-    ///     Err(e) => println!("Error parsing response: {}", e),
-    /// }
-    /// # Ok({})
+    /// let href = transaction.links().operations().href().clone().unwrap_or_default();
+    /// let operations: OperationResponse = horizon_client.follow(&href, &page_params).await?;
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_all_ledgers(
+    ///
+    pub async fn follow<R: Response>(
         &self,
-        request: &LedgersRequest,
-    ) -> Result<LedgersResponse, String> {
-        self.get::<LedgersResponse>(request).await
+        href: &str,
+        page_params: &PageParams,
+    ) -> Result<R, Error> {
+        self.get_from_url(&expand_template(href, page_params)).await
     }
 
-    /// Retrieves detailed information for a specific ledger from the Horizon server.
-    ///
-    /// This asynchronous method fetches details of a single ledger from the Horizon server.
-    /// It requires a [`SingleLedgerRequest`] parameterized with `Sequence`, which includes the sequence number
-    /// of the ledger to be retrieved.
+    /// Follows the link of a response with the given name, with the default pagination, or
+    /// returns an error if the response does not have the link.
+    pub(crate) async fn follow_link<R: Response>(
+        &self,
+        href: Option<&str>,
+        name: &str,
+    ) -> Result<R, Error> {
+        let href = href.ok_or_else(|| Error::Other(format!("response has no {} link", name)))?;
+
+        self.follow(href, &PageParams::new()).await
+    }
+
+    /// Retrieves a page of records from the Horizon server, keeping the records as raw JSON.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/retrieve-a-ledger">Retrieve a Ledger</a>
-    /// endpoint.
+    /// This asynchronous method accepts any request that returns a page of records, such as an
+    /// [`AllOperationsRequest`] or an [`AllTransactionsRequest`]. Instead of deserializing every
+    /// record into a typed response, it returns a [`RawPage`], of which the records can be
+    /// deserialized on demand. This is intended for indexers that ingest large amounts of history
+    /// and only need a few fields of every record, which can then be borrowed from the raw JSON.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`SingleLedgerRequest<Sequence>`] instance, containing the
-    ///   sequence number of the ledger for which details are to be fetched.
+    /// * `request` - A reference to a request that returns a page of records.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing a [`Ledger`], which includes detailed
-    /// information about the requested ledger. If the request fails, it returns an error
-    /// encapsulated within `Result`.
+    /// On success, returns a `Result` containing a [`RawPage`]. If the request fails, or if the
+    /// response is not a page of records, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`SingleLedgerRequest`] and set the
-    /// sequence number of the ledger to be queried.
-    ///
     /// ```
-    /// # use stellar_rs::ledgers::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::operations::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = SingleLedgerRequest::new()
-    ///     .set_sequence(2).unwrap();
-    ///
-    /// let response = horizon_client.get_single_ledger(&request).await;
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = AllOperationsRequest::new().set_limit(200)?;
+    /// let page = horizon_client.fetch_raw(&request).await?;
     ///
-    /// if let Ok(ledger) = response {
-    ///     println!("Ledger ID: {}", ledger.id());
-    ///     // Additional processing...
+    /// let operations: Vec<BorrowedOperation> = page.deserialize_records()?;
+    /// for operation in operations {
+    ///     println!("{}: {}", operation.paging_token(), operation.type_field());
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_single_ledger(
-        &self,
-        request: &SingleLedgerRequest<Sequence>,
-    ) -> Result<Ledger, String> {
-        self.get::<Ledger>(request).await
+    pub async fn fetch_raw(&self, request: &impl Request) -> Result<RawPage, Error> {
+        self.get::<RawPage>(request).await
     }
 
-    /// Retrieves a list of all effects from the Horizon server.
+    /// Retrieves the records of all pages of a collection endpoint, one record at a time.
     ///
-    /// This asynchronous method fetches a list of all effects from the Horizon server.
-    /// It requires an [`AllEffectsRequest`] to specify the optional query parameters.
+    /// The returned stream starts at the first page selected by the request, and follows the
+    /// `next` links of the pages returned by the Horizon server until it returns an empty page.
+    /// Pages are only requested once the records of the previous page have been consumed, so that
+    /// a consumer that stops early does not request more pages than it needs. Setting the limit of
+    /// the request to the largest page size Horizon allows, using `set_max_limit`, takes the
+    /// fewest requests.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-effects">Retrieve a Ledger</a>
-    /// endpoint.
+    /// This works for any request of a collection, such as an [`AccountsRequest`], an
+    /// [`AllTransactionsRequest`], an [`AllOperationsRequest`] or an [`AllEffectsRequest`]. The
+    /// records are deserialized into the given type, which is the type of the records of the
+    /// corresponding response, such as an [`Account`] or a [`TransactionResponse`].
     ///
     /// # Arguments
-    ///
-    /// * `request` - A reference to an [`AllEffectsRequest`] instance, containing the
-    /// parameters for the effects request.
+    /// * `request` - A reference to a request that returns a page of records.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllEffectsResponse`], which includes
-    /// the list of all effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Stream` of `Result`s, each containing a record, or an error if a page could not
+    /// be retrieved or deserialized. The stream ends after an error.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`AllEffectsRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example
     ///
-    /// ```
-    /// # use stellar_rs::effects::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use futures::StreamExt;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllEffectsRequest::new()
-    ///    .set_limit(2).unwrap();
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = AllTransactionsRequest::new().set_max_limit();
     ///
-    /// let response = horizon_client.get_all_effects(&request).await;
+    /// let transactions = horizon_client.paginate::<TransactionResponse>(&request);
+    /// futures::pin_mut!(transactions);
     ///
-    /// // Access the effects
-    /// if let Ok(effects_response) = response {
-    ///   for effect in effects_response.embedded().records() {
-    ///     println!("Effect ID: {}", effect.id());
-    ///    // Further processing...
-    ///  }
+    /// while let Some(transaction) = transactions.next().await {
+    ///     println!("Transaction hash: {}", transaction?.hash());
+    /// #   break;
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_all_effects(
-        &self,
-        request: &AllEffectsRequest,
-    ) -> Result<EffectsResponse, String> {
-        self.get::<EffectsResponse>(request).await
+    pub fn paginate<'a, R: DeserializeOwned + 'a>(
+        &'a self,
+        request: &impl Request,
+    ) -> impl Stream<Item = Result<R, Error>> + 'a {
+        let first_url = request.build_url(&self.base_url);
+
+        stream::unfold(
+            StreamState::new(first_url),
+            move |mut state: StreamState<R>| async move {
+                loop {
+                    if let Some(record) = state.buffered.pop_front() {
+                        return Some((Ok(record), state));
+                    }
+
+                    // Fetch the next page, if any. Taking its URL ends the stream after an error.
+                    let url = state.next_url.take()?;
+                    let page = match self.get_from_url::<RawPage>(&url).await {
+                        Ok(page) if page.is_empty() => return None,
+                        Ok(page) => page,
+                        Err(e) => return Some((Err(e), state)),
+                    };
+                    match page.deserialize_records::<R>() {
+                        Ok(records) => state.buffered.extend(records),
+                        Err(e) => {
+                            let error = Error::Deserialization(format!("{}: {}", url, e));
+                            return Some((Err(error), state));
+                        }
+                    }
+                    state.next_url = page.next_url().map(str::to_string);
+                }
+            },
+        )
     }
 
-    /// Fetches effects associated with a specific ledger from the Stellar Horizon API.
+    /// Retrieves the records on all pages of a collection, up to an optional maximum number of
+    /// records.
     ///
-    /// This asynchronous method retrieves effects for a given ledger, facilitating detailed analysis
-    /// and insight into the various operations and changes that occurred within that ledger. It requires
-    /// a [`EffectsForLedgerRequest`], which includes options  for pagination, record limits,
-    /// and sorting order, among others.
+    /// This asynchronous method follows the `next` links of the pages, like
+    /// [`HorizonClient::paginate`], and collects the records into a single `Vec`. It stops at the
+    /// first empty page, or as soon as `max_records` records have been collected, without
+    /// requesting any further pages. Setting the limit of the request to the largest page size
+    /// Horizon allows, using `set_max_limit`, takes the fewest requests.
     ///
-    /// Adheres to <a href="https://developers.stellar.org/api/horizon/resources/retrieve-a-ledgers-effects">Retrieve a Ledgers's Effects</a> endpoint.
+    /// Without a maximum, a large collection, such as all operations of the network, takes a very
+    /// long time to retrieve and may not fit in memory, so the maximum should only be omitted for
+    /// collections that are known to be small, such as the offers of a single account.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`EffectsForLedgerRequest`] instance, specifying the ledger sequence
-    ///   and optional parameters such as cursor, limit, and order for the query.
+    /// * `request` - A reference to a request that returns a page of records.
+    /// * `max_records` - The maximum number of records to return, or `None` to return all
+    ///   records.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing an [`AllEffectsResponse`], which encompasses a collection of effects
-    /// related to the requested ledger. If the operation fails, it returns an error message encapsulated
-    /// within `Result`.
+    /// On success, returns a `Result` containing the records in the order of the pages. If a page
+    /// could not be retrieved or deserialized, it returns the error within `Result`, and the
+    /// records retrieved before are discarded.
     ///
-    /// # Usage
-    /// To utilize this method, instantiate an `EffectsForLedgerRequest` with the desired parameters.
+    /// # Example
     ///
-    /// ```
-    /// # use stellar_rs::effects::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
     /// # use stellar_rs::horizon_client::HorizonClient;
-    ///
+    /// # use stellar_rs::offers::prelude::*;
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)?;
-    /// let mut request = EffectsForLedgerRequest::new()
-    ///     .set_sequence(&125)
-    ///     .set_limit(2).unwrap();
-    ///
-    /// let response = horizon_client.get_effects_for_ledger(&request).await;
-    ///
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = OffersForAccountRequest::new()
+    ///     .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")?
+    ///     .set_max_limit();
+    ///
+    /// let offers = horizon_client
+    ///     .get_all_pages::<OfferResponse>(&request, Some(1000))
+    ///     .await?;
+    /// println!("{} offers", offers.len());
     /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_effects_for_ledger(
+    pub async fn get_all_pages<R: DeserializeOwned>(
         &self,
-        request: &EffectsForLedgerRequest,
-    ) -> Result<EffectsResponse, String> {
-        self.get::<EffectsResponse>(request).await
+        request: &impl Request,
+        max_records: Option<usize>,
+    ) -> Result<Vec<R>, Error> {
+        self.paginate::<R>(request)
+            .take(max_records.unwrap_or(usize::MAX))
+            .try_collect()
+            .await
     }
 
-    /// Retrieves a list of all fee stats from the Horizon server.
+    /// Sends any request to the Horizon server, and deserializes the response into any response type.
     ///
-    /// This asynchronous method fetches a list of all fee stats from the Horizon server.
-    /// It requires a [`FeeStatsRequest`] to specify the optional query parameters.
+    /// The methods of the `HorizonClient` cover the endpoints and parameters modeled by the SDK. This
+    /// asynchronous method is an escape hatch for everything else, such as endpoints that were added
+    /// to Horizon after the release of the SDK, or endpoints of a customized Horizon deployment. It
+    /// accepts any implementor of [`Request`], and deserializes the response into any implementor
+    /// of [`Response`], including [`serde_json::Value`] for responses that are not modeled at all.
+    /// Requests are sent like those of the other methods, so the rate limiter, the maximum response
+    /// size and the error handling of the client apply.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`FeeStatsRequest`] instance, containing the
-    /// parameters for the fee stats request.
+    /// * `request` - A reference to any request, which determines the URL to send a `GET` request to.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`FeeStatsResponse`], which includes
-    /// the list of all fee stats obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On success, returns a `Result` containing the deserialized response. If the request fails, or
+    /// if the response can not be deserialized into `R`, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`FeeStatsRequest`] and set any desired
-    /// filters or parameters.
-    ///
     /// ```
-    /// # use stellar_rs::fee_stats::fee_stats_request::FeeStatsRequest;
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::models::Request;
     /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = FeeStatsRequest::new();
+    /// /// A request for an endpoint of a customized Horizon deployment.
+    /// struct ValidatorsRequest;
     ///
-    /// let response = horizon_client.get_fee_stats(&request).await;
+    /// impl Request for ValidatorsRequest {
+    ///     fn get_query_parameters(&self) -> String {
+    ///         String::new()
+    ///     }
     ///
-    /// // Access the fee stats
-    /// if let Ok(fee_stats_response) = response {
-    ///  println!("Max Fee: {:?}", fee_stats_response.max_fee());
-    /// // Further processing...
+    ///     fn build_url(&self, base_url: &str) -> String {
+    ///         format!("{}/validators", base_url)
+    ///     }
     /// }
-    /// # Ok({})
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let validators: serde_json::Value = horizon_client.execute(&ValidatorsRequest).await?;
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_fee_stats(
-        &self,
-        request: &FeeStatsRequest,
-    ) -> Result<FeeStatsResponse, String> {
-        self.get::<FeeStatsResponse>(request).await
+    pub async fn execute<R: Response>(&self, request: &impl Request) -> Result<R, Error> {
+        self.get::<R>(request).await
     }
 
-    /// Retrieves detailed information for a specific offer from the Horizon server.
+    /// Streams the records of a collection endpoint in near real time, using Server-Sent Events.
     ///
-    /// This asynchronous method fetches details of a single offer from the Horizon server.
-    /// It requires a [`SingleOfferRequest`] parameterized with `OfferId`, which includes the ID
-    /// of the offer to be retrieved.
+    /// Unlike the streams that fetch page after page, such as
+    /// [`HorizonClient::stream_trades`], this stream keeps a connection to the Horizon server
+    /// open, over which the server pushes every new record as soon as it has been ingested. The
+    /// stream starts after the cursor of the request, or after the paging token the options
+    /// resume from. Resuming from `"now"` skips the existing records, so that the stream only
+    /// yields records that are created after it starts.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/network/horizon/resources/get-offer-by-offer-id">Retrieve An Offer endpoint</a>
-    /// endpoint.
+    /// Horizon closes these connections from time to time. The stream then reconnects after the
+    /// delay the server requested, resuming after the last record it received, so that no records
+    /// are skipped or repeated. The paging token of every record is reported to the checkpoint
+    /// callback of the options, if any, once the record has been processed.
     ///
-    /// # Arguments
+    /// Streaming is supported by most collection endpoints of Horizon, such as those for ledgers,
+    /// transactions, operations, payments, effects and trades, and by the order book endpoint.
     ///
-    /// * `request` - A reference to a [`SingleOfferRequest<OfferId>`] instance, containing the
-    ///   id of the offer for which details are to be fetched.
+    /// # Arguments
+    /// * `request` - The request for the collection to stream.
+    /// * `options` - The [`StreamOptions`] controlling the lifecycle of the stream.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing an [`Offer`], which includes detailed
-    /// information about the requested offer. If the request fails, it returns an error
-    /// encapsulated within `Result`.
+    /// Returns a `Stream` of `Result`s, each containing a record, or an error if the connection
+    /// could not be established or a record could not be deserialized. The stream ends after an
+    /// error, or when it is shut down.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`SingleOfferRequest`] and set the
-    /// id of the offer to be queried.
+    /// # Example
     ///
-    /// ```
-    /// # use stellar_rs::offers::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::streaming::StreamOptions;
+    /// # use futures::StreamExt;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = SingleOfferRequest::new()
-    ///     .set_offer_id("1") // example offer ID
-    ///     .unwrap();
-    ///
-    /// let response = horizon_client.get_single_offer(&request).await;
-    ///
-    /// if let Ok(offer) = response {
-    ///     println!("Offer ID: {}", offer.id());
-    ///     // Additional processing...
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let options = StreamOptions::new().set_resume_from("now");
+    /// let stream = horizon_client.stream_sse::<Operation>(&AllOperationsRequest::new(), options);
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(operation) = stream.next().await {
+    ///     println!("Paging token: {}", operation?.paging_token());
+    /// #   break;
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_single_offer(
+    pub fn stream_sse<'a, R: DeserializeOwned + 'a>(
+        &'a self,
+        request: &impl Request,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<R, Error>> + 'a {
+        let first_url = options.first_url(request.build_url(&self.base_url));
+
+        stream::unfold(
+            (
+                StreamState::new(first_url),
+                SseConnection::default(),
+                options,
+            ),
+            move |(mut state, mut connection, options): (
+                StreamState<(Option<String>, R)>,
+                SseConnection,
+                StreamOptions,
+            )| async move {
+                state.confirm_yielded();
+
+                loop {
+                    if options.is_shut_down() {
+                        state.checkpoint(&options);
+                        return None;
+                    }
+
+                    if let Some((paging_token, record)) = state.buffered.pop_front() {
+                        if let Some(paging_token) = &paging_token {
+                            state.set_yielded(paging_token);
+                        }
+                        return Some((Ok(record), (state, connection, options)));
+                    }
+
+                    // The records received so far have been processed completely.
+                    state.checkpoint(&options);
+
+                    if let Some(error) = connection.error.take() {
+                        state.finish();
+                        return Some((Err(error), (state, connection, options)));
+                    }
+
+                    // Connect, unless the stream is already connected.
+                    let response = match &mut connection.response {
+                        Some(response) => response,
+                        None => {
+                            let url = state.next_url.clone()?;
+                            let response = futures::select_biased! {
+                                _ = options.shut_down().fuse() => return None,
+                                response = self.connect_event_stream(&url).fuse() => response,
+                            };
+                            match response {
+                                Ok(response) => connection.response.insert(response),
+                                Err(e) => {
+                                    state.finish();
+                                    return Some((Err(e), (state, connection, options)));
+                                }
+                            }
+                        }
+                    };
+
+                    let chunk = futures::select_biased! {
+                        _ = options.shut_down().fuse() => return None,
+                        chunk = response.chunk().fuse() => chunk,
+                    };
+                    match chunk {
+                        Ok(Some(chunk)) => {
+                            // Events with a type, such as `open`, announce the state of the
+                            // connection rather than a record.
+                            for event in connection.parser.push(&chunk) {
+                                if event.event.is_some() {
+                                    continue;
+                                }
+                                match serde_json::from_str::<R>(&event.data) {
+                                    Ok(record) => state.buffered.push_back((event.id, record)),
+                                    Err(e) => {
+                                        // Yield the records received before the invalid one
+                                        // first, and then end the stream with the error.
+                                        let url = state.next_url.take().unwrap_or_default();
+                                        connection.error =
+                                            Some(Error::Deserialization(format!("{}: {}", url, e)));
+                                        connection.response = None;
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                        // The server closed the connection. Reconnect after the requested delay,
+                        // resuming after the last event that was received.
+                        Ok(None) | Err(_) => {
+                            connection.response = None;
+                            connection.parser.reset();
+                            if let Some(paging_token) = connection.parser.last_event_id() {
+                                state.next_url = state
+                                    .next_url
+                                    .take()
+                                    .map(|url| with_cursor(url, paging_token));
+                            }
+                            let delay = connection.parser.reconnect_delay();
+                            futures::select_biased! {
+                                _ = options.shut_down().fuse() => return None,
+                                _ = crate::runtime::sleep(delay).fuse() => {}
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Counts the records on all pages of the given request, following the `next` links.
+    ///
+    /// The records are kept as raw JSON instead of being deserialized, so that counting is cheap.
+    /// The request should have its limit set to `page_size`, preferably the largest limit Horizon
+    /// allows, so that as few pages as possible are requested.
+    ///
+    pub(crate) async fn count_records(
         &self,
-        request: &SingleOfferRequest<OfferId>,
-    ) -> Result<OfferResponse, String> {
-        self.get::<OfferResponse>(request).await
+        request: &impl Request,
+        page_size: usize,
+    ) -> Result<usize, Error> {
+        let mut count = 0;
+        let mut next_url = Some(request.build_url(&self.base_url));
+        while let Some(url) = next_url.take() {
+            let page = self.get_from_url::<RawPage>(&url).await?;
+            count += page.len();
+
+            // A page that is not full is the last one; skip requesting the empty page after it.
+            if page.len() == page_size {
+                next_url = page.next_url().map(str::to_string);
+            }
+        }
+
+        Ok(count)
     }
 
-    /// Retrieves a list of all offers from the Horizon server.
+    /// Waits for the rate limiter, if any, to allow a request to be sent.
+    async fn acquire_rate_limit(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+
+    /// Sends a request to the given URL, unless the circuit breaker, if any, rejects it, and
+    /// records the outcome with the circuit breaker.
+    async fn with_circuit_breaker<R>(
+        &self,
+        url: &str,
+        request: impl Future<Output = Result<R, Error>>,
+    ) -> Result<R, Error> {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return request.await;
+        };
+
+        let endpoint = circuit_breaker::endpoint(&self.base_url, url);
+        circuit_breaker.check(&endpoint)?;
+        let result = request.await;
+        circuit_breaker.record(&endpoint, &result);
+
+        result
+    }
+
+    /// Stores the rate limit state reported in the headers of a response.
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        let rate_limit = RateLimitInfo::from_headers(response.headers());
+        *self
+            .last_rate_limit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = rate_limit;
+    }
+
+    /// Stores the latest ledger reported in the headers of a response, and checks that the
+    /// response does not lag behind by more than the maximum ledger lag, if any.
+    fn record_latest_ledger(&self, headers: &reqwest::header::HeaderMap) -> Result<(), Error> {
+        let latest_ledger = headers
+            .get("Latest-Ledger")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u32>().ok());
+        *self
+            .last_latest_ledger
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = latest_ledger;
+
+        let Some(latest_ledger) = latest_ledger else {
+            return Ok(());
+        };
+        let highest_ledger = self
+            .highest_latest_ledger
+            .fetch_max(latest_ledger, Ordering::Relaxed)
+            .max(latest_ledger);
+
+        match self.max_ledger_lag {
+            Some(max_lag) if highest_ledger - latest_ledger > max_lag => {
+                Err(Error::StaleResponse {
+                    latest_ledger,
+                    highest_ledger,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Sends a GET request to the Horizon server and retrieves a specified response type.
     ///
-    /// This asynchronous method fetches a list of all offers from the Horizon server.
-    /// It requires an [`AllOffersRequest`] to specify the optional query parameters.
+    /// This internal asynchronous method is designed to handle various GET requests to the
+    /// Horizon server. It is generic over the response type, allowing for flexibility in
+    /// handling different types of responses as dictated by the caller. This method performs
+    /// key tasks such as request validation, URL construction, sending the request, and
+    /// processing the received response.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Response` - Defines the expected response type. This type must implement the
+    /// [`Response`] trait.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllOffersRequest`] instance, containing the
-    /// parameters for the offers request.
+    ///
+    /// * `request` - A reference to an object implementing the [`Request`] trait. It contains
+    /// specific details about the GET request to be sent.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`OfferResponse`], which includes
-    /// the list of all offers obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing the response of type [`Response`] if the request is
+    /// successful. In case of failure (e.g., network issues, server errors), it returns an
+    /// [`Error`].
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`AllOffersRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example Usage
     ///
-    /// ```
-    /// # use stellar_rs::offers::prelude::*;
-    /// # use stellar_rs::models::Request;
-    /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllOffersRequest::new()
-    ///   .set_limit(2).unwrap();
+    /// This function is typically not called directly but through other specific methods of
+    /// the `HorizonClient` that define the type of request and response.
     ///
-    /// let response = horizon_client.get_all_offers(&request).await;
+    /// # Remarks
     ///
-    /// // Access the offers
-    /// if let Ok(offers_response) = response {
-    ///     for offer in offers_response.embedded().records() {
-    ///         println!("Offer ID: {}", offer.id());
-    ///         // Further processing...
-    ///     }
-    /// }
-    /// # Ok({})
-    /// # }
-    /// ```
+    /// As a core utility function within `HorizonClient`, it centralizes the logic of sending
+    /// GET requests and handling responses. Modifications or enhancements to the request or
+    /// response handling logic should be implemented here to maintain consistency across the
+    /// client's interface.
     ///
-    pub async fn get_all_offers(
-        &self,
-        request: &AllOffersRequest,
-    ) -> Result<AllOffersResponse, String> {
-        self.get::<AllOffersResponse>(request).await
+    async fn get<R: Response>(&self, request: &impl Request) -> Result<R, Error> {
+        // Construct the URL with potential query parameters.
+        let url = request.build_url(&self.base_url);
+
+        self.get_from_url(&url).await
     }
 
-    /// Retrieves a list of all offers from the Horizon server.
+    /// Sends a GET request to the given URL and retrieves a specified response type.
+    ///
+    /// Unlike [`HorizonClient::get`], this method takes a complete URL instead of a request. It is
+    /// used to follow the navigational links (such as `next`) that are part of Horizon responses.
+    ///
+    async fn get_from_url<R: Response>(&self, url: &str) -> Result<R, Error> {
+        // Omit the query parameters that the network does not support yet, if its protocol
+        // version is known.
+        let gated_url = self
+            .protocol_version
+            .map(|protocol_version| omit_unsupported_parameters(url, protocol_version));
+        let url = gated_url.as_deref().unwrap_or(url);
+
+        // Serve single resources from the cache, if any.
+        let cache = self.cache.as_ref().and_then(|cache| {
+            match self.cache_policy.directive_for_url(&self.base_url, url) {
+                CacheDirective::NoStore => None,
+                directive => Some((cache, directive)),
+            }
+        });
+        if let Some(body) = cache.and_then(|(cache, _)| cache.get(url)) {
+            return ResponseBody::cached(body).parse(self.error_body_snippet_length);
+        }
+
+        let mut retry = 0;
+        loop {
+            let body = match self.coalesce_requests {
+                true => self.fetch_coalesced(url).await,
+                false => self.fetch(url).await,
+            };
+            let result = body
+                .and_then(|body| match cache {
+                    Some((cache, directive)) if body.status == reqwest::StatusCode::OK => {
+                        let cached_body = body.body.clone();
+                        let response = body.parse(self.error_body_snippet_length)?;
+                        cache.insert(url, cached_body, directive);
+                        Ok(response)
+                    }
+                    _ => body.parse(self.error_body_snippet_length),
+                })
+                .map_err(|error| match error {
+                    Error::Deserialization(message) => {
+                        Error::Deserialization(format!("{}: {}", url, message))
+                    }
+                    error => error,
+                });
+
+            // Retry transient errors, if a retry policy is set.
+            match (&result, &self.retry_policy) {
+                (Err(error), Some(retry_policy)) if retry_policy.should_retry(retry, error) => {
+                    crate::runtime::sleep(retry_policy.backoff(retry)).await;
+                    retry += 1;
+                }
+                _ => return result,
+            }
+        }
+    }
+
+    /// Sends a GET request to the given URL, and reads the body of the response.
+    async fn fetch(&self, url: &str) -> Result<ResponseBody, Error> {
+        self.with_circuit_breaker(url, async {
+            // Respect the client-side rate limit, if any.
+            self.acquire_rate_limit().await;
+
+            // Send the request and await the response.
+            self.resolver.record_request();
+            let response = crate::runtime::send(self.client.get(url)).await?;
+            self.record_rate_limit(&response);
+            self.record_latest_ledger(response.headers())?;
+
+            ResponseBody::read(response, self.max_response_size).await
+        })
+        .await
+    }
+
+    /// Opens a Server-Sent Events connection to the given URL, and returns the response once the
+    /// server has accepted it, without reading its body.
+    async fn connect_event_stream(&self, url: &str) -> Result<reqwest::Response, Error> {
+        let gated_url = self
+            .protocol_version
+            .map(|protocol_version| omit_unsupported_parameters(url, protocol_version));
+        let url = gated_url.as_deref().unwrap_or(url);
+
+        self.with_circuit_breaker(url, async {
+            self.acquire_rate_limit().await;
+
+            self.resolver.record_request();
+            let response = crate::runtime::send(
+                self.client
+                    .get(url)
+                    .header(reqwest::header::ACCEPT, "text/event-stream"),
+            )
+            .await?;
+            self.record_rate_limit(&response);
+            self.record_latest_ledger(response.headers())?;
+
+            match response.status() {
+                reqwest::StatusCode::OK => Ok(response),
+                status => Err(Error::from_problem(
+                    status.as_u16(),
+                    read_body(response, self.max_response_size).await?,
+                )),
+            }
+        })
+        .await
+    }
+
+    /// Sends a GET request to the given URL, unless a request for the same URL is in flight, in
+    /// which case the body of its response is shared.
+    async fn fetch_coalesced(&self, url: &str) -> Result<ResponseBody, Error> {
+        let sender = {
+            let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            match in_flight.get(url) {
+                Some(receiver) => Err(receiver.clone()),
+                None => {
+                    let (sender, receiver) = oneshot::channel();
+                    in_flight.insert(url.to_string(), receiver.shared());
+                    Ok(sender)
+                }
+            }
+        };
+
+        let sender = match sender {
+            Ok(sender) => sender,
+            Err(receiver) => {
+                // If the request in flight is cancelled, its sender is dropped without a response,
+                // and the request is sent again.
+                return match receiver.await {
+                    Ok(response) => response,
+                    Err(_) => self.fetch(url).await,
+                };
+            }
+        };
+
+        // Remove the request from the requests in flight once it completes or is cancelled.
+        let guard = InFlightGuard {
+            in_flight: &self.in_flight,
+            url,
+        };
+        let response = self.fetch(url).await;
+        drop(guard);
+        let _ = sender.send(response.clone());
+
+        response
+    }
+
+    /// Sends a POST request to the Horizon server and retrieves a specified response type.
     ///
-    /// This asynchronous method fetches a list of all offers an account currently has open from the Horizon server.
-    /// It requires an [`OffersForAccountRequest`] to specify the optional query parameters.
+    /// This internal asynchronous method is designed to handle various POST requests to the
+    /// Horizon server. It is generic over the response type, allowing for flexibility in
+    /// handling different types of responses as dictated by the caller. This method performs
+    /// key tasks such as request validation, URL construction, sending the request, and
+    /// processing the received response.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Response` - Defines the expected response type. This type must implement the
+    /// [`Response`] trait.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`OffersForAccountRequest`] instance, containing the
-    /// parameters for the request.
+    ///
+    /// * `request` - A reference to an object implementing the [`PostRequest`] trait. It contains
+    /// specific details about the POST request to be sent.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllOffersResponse`], which includes
-    /// the list of all offers currently open for an account, obtained from the Horizon server.
-    /// If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing the response of type [`Response`] if the request is
+    /// successful. In case of failure (e.g., network issues, server errors), it returns an
+    /// [`Error`].
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`OffersForAccountRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example Usage
     ///
-    /// ```
-    pub async fn get_offers_for_account(
+    /// This function is typically not called directly but through other specific methods of
+    /// the `HorizonClient` that define the type of request and response.
+    ///
+    /// # Remarks
+    ///
+    /// As a core utility function within `HorizonClient`, it centralizes the logic of sending
+    /// POST requests and handling responses. Modifications or enhancements to the request or
+    /// response handling logic should be implemented here to maintain consistency across the
+    /// client's interface.
+    ///
+    async fn post<R: Response>(&self, request: &impl PostRequest) -> Result<R, Error> {
+        // Construct the URL.
+        let url = request.build_url(&self.base_url);
+
+        self.with_circuit_breaker(&url, async {
+            // Send the request and await the response.
+            let response = self.send_post(&url, request).await?;
+
+            // Process the response and return the result.
+            handle_response(
+                response,
+                self.error_body_snippet_length,
+                self.max_response_size,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Sends a POST request to the given URL, without processing the response.
+    async fn send_post(
         &self,
-        request: &OffersForAccountRequest<OfferAccountId>,
-    ) -> Result<AllOffersResponse, String> {
-        self.get::<AllOffersResponse>(request).await
+        url: &str,
+        request: &impl PostRequest,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        // The vector of tuples (containing the key/value pairs) returned by the `get_body()` method can
+        // be passed directly to `reqwest`s `form()` method, which will automatically create a valid
+        // formdata body for the request.
+        self.acquire_rate_limit().await;
+        self.resolver.record_request();
+        let response =
+            crate::runtime::send(self.client.post(url).form(&request.get_body())).await?;
+        self.record_rate_limit(&response);
+        self.invalidate_mutable_cached();
+
+        Ok(response)
     }
 
-    /// Retrieves a list of all operations from the Horizon server.
+    /// Retrieves a list of accounts filtered by specific criteria.
     ///
-    /// This asynchronous method fetches a list of all operations from the Horizon server.
-    /// It requires an [`AllOperationsRequest`] to specify the optional query parameters.
+    /// This method retrieves a list of accounts from the Horizon server, filtering the results
+    /// based on one of four categories: sponsor, signer, asset, or liquidity pool.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-accounts">List All Accounts</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllOperationsRequest`] instance, containing the
-    /// parameters for the operations request.
+    /// * `request` - A reference to an implementation of the [`ValidAccountsRequest`] trait,
+    /// which specifies the filter criteria for the account list request.
     ///
     /// # Returns
+    /// If successful, this method returns a `Result` containing an [`AccountsResponse`],
+    /// which encapsulates the list of accounts retrieved from the server.
+    /// In case of a failure in the request, it returns an error encapsulated within `Result`.
     ///
-    /// On successful execution, returns a `Result` containing an [`OperationResponse`], which includes
-    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
-    ///
-    /// # Usage
-    /// To use this method, create an instance of [`AllOperationsRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example
+    /// To use this method, create an instance of [`AccountsRequest`] and set at least
+    /// one of the four filter options. For example, filtering by signer:
     ///
-    /// ```
-    /// # use stellar_rs::operations::prelude::*;
+    /// ```rust
+    /// # use stellar_rs::accounts::prelude::*;
+    /// # use stellar_rs::error::Error;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1064,95 +1837,154 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllOperationsRequest::new()
-    ///   .set_limit(2).unwrap();
-    ///
-    /// let response = horizon_client.get_all_operations(&request).await;
+    /// let request = AccountsRequest::new()
+    ///     .set_signer_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+    ///     .unwrap();
     ///
-    /// // Access the operations
-    /// if let Ok(operations_response) = response {
-    ///     for operation in operations_response.embedded().records() {
-    ///         println!("Operation ID: {}", operation.id());
-    ///         // Further processing...
-    ///     }
-    /// }
-    /// # Ok({})
-    /// # }
-    /// ```
+    /// let response: Result<AccountsResponse, Error> = horizon_client
+    ///     .get_account_list(&request)
+    ///     .await;
     ///
-    pub async fn get_all_operations(
+    /// // Access the account details
+    /// for record in response?.embedded().records() {
+    ///     println!("Account ID: {}", record.account_id());
+    ///     // Further processing...
+    ///  }
+    ///
+    /// # Ok(())
+    /// # }
+    pub async fn get_account_list(
         &self,
-        request: &AllOperationsRequest,
-    ) -> Result<OperationResponse, String> {
-        self.get::<OperationResponse>(request).await
+        request: &impl ValidAccountsRequest,
+    ) -> Result<AccountsResponse, Error> {
+        self.get::<AccountsResponse>(request).await
     }
 
-    /// Retrieves detailed information for a specific operation from the Horizon server.
+    /// Returns a handle to the account with the given ID.
     ///
-    /// This asynchronous method fetches details of a single operation from the Horizon server.
-    /// It requires a [`SingleOperationRequest`] parameterized with `OperationId`, which includes the unique identifier
-    /// of the operation to be retrieved.
+    /// The [`AccountHandle`] borrows this client, and offers methods that summarize the footprint
+    /// of the account, such as the number of its offers and claimable balances, which are
+    /// computed from the respective endpoints. No request is sent until one of these methods is
+    /// called.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`SingleOperationRequest`] instance containing the unique ID of the operation to be fetched.
+    /// * `account_id` - The ID of the account.
     ///
     /// # Returns
+    /// The [`AccountHandle`], or an error if the account ID is not a public key.
     ///
-    /// Returns a `Result` containing an [`Operation`], which includes detailed information about the requested operation.
-    /// If the request fails, it returns an error encapsulated within `Result`.
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let offers_count = horizon_client
+    ///     .account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?
+    ///     .offers_count()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`SingleOperationRequest`] and set the unique ID of the operation to be queried.
+    pub fn account(&self, account_id: &str) -> Result<AccountHandle<'_>, String> {
+        AccountHandle::new(self, account_id)
+    }
+
+    /// Retrieves the trustlines to an asset that are not fully authorized by its issuer.
     ///
-    /// ```
-    /// # use stellar_rs::operations::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// This asynchronous method is intended for issuers of assets that require authorization. It
+    /// loads all accounts with a trustline to the asset, following the pages of the accounts
+    /// endpoint, and inspects their balances to find the trustlines that are either not
+    /// authorized or only authorized to maintain liabilities. These trustlines can then be
+    /// authorized or frozen using a [`SetTrustLineFlagsIntent`].
+    ///
+    /// # Arguments
+    /// * `asset` - The issued asset of which to retrieve the trustlines.
+    ///
+    /// # Returns
+    /// The trustlines that are not fully authorized, ordered by account ID, or an error if one
+    /// of the requests fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::accounts::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::models::Asset;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = SingleOperationRequest::new()
-    ///    .set_operation_id("459561504769");
-    ///
-    /// let response = horizon_client.get_single_operation(&request).await;
+    /// let asset = Asset::new()
+    ///     .set_issued("USDC", "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5")?;
     ///
-    /// if let Ok(operation) = response {
-    ///    println!("Operation ID: {}", operation.id());
-    ///   // Additional processing...
+    /// for trustline in horizon_client.get_unauthorized_trustlines(&asset).await? {
+    ///     println!("{}: {:?}", trustline.account_id(), trustline.authorization());
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_single_operation(
+    pub async fn get_unauthorized_trustlines(
         &self,
-        request: &SingleOperationRequest<OperationId>,
-    ) -> Result<Operation, String> {
-        self.get::<Operation>(request).await
+        asset: &Asset<IssuedAsset>,
+    ) -> Result<Vec<UnauthorizedTrustline>, Error> {
+        const PAGE_SIZE: u8 = 200;
+
+        let canonical = asset.to_string();
+        let (asset_code, asset_issuer) = canonical
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid asset: {}", canonical))?;
+        let request = AccountsRequest::new()
+            .set_asset_filter(asset.clone())
+            .set_limit(PAGE_SIZE)?;
+
+        let mut trustlines = Vec::new();
+        let mut next_url = Some(request.build_url(&self.base_url));
+        while let Some(url) = next_url.take() {
+            let page = self.get_from_url::<AccountsResponse>(&url).await?;
+            let records = page.embedded().records();
+
+            // A page that is not full is the last one; skip requesting the empty page after it.
+            if records.len() == usize::from(PAGE_SIZE) {
+                next_url = page
+                    .links()
+                    .next
+                    .as_ref()
+                    .and_then(|link| link.href.clone());
+            }
+            trustlines.extend(records.iter().filter_map(|account| {
+                UnauthorizedTrustline::of(account, asset_code, asset_issuer)
+            }));
+        }
+
+        Ok(trustlines)
     }
 
-    /// Retrieves a list of all operations for an account from the Horizon server.
+    /// Retrieves detailed information for a specific account from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all operations for an account from the Horizon server.
-    /// It requires an [`OperationsForAccountRequest`] to specify the optional query parameters.
+    /// This asynchronous method is designed to fetch information for a single account on the Horizon server.
+    /// It requires a [`SingleAccountRequest`] with the account ID to be queried.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/retrieve-an-account">Retrieve an Account</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`OperationsForAccountRequest`] instance, containing the
-    /// parameters for the operations for account request.
+    /// * `request` - A reference to a [`SingleAccountRequest`] instance, containing the
+    /// account ID for which details are to be fetched.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
-    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On success, returns a `Result` wrapping a [`SingleAccountResponse`], which includes the
+    /// detailed information of the requested account. If the request fails, it returns an error
+    /// encapsulated within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`OperationsForAccountRequest`] and set any desired
-    /// filters or parameters.
+    /// To use this method, create an instance of [`SingleAccountRequest`] and set the
+    /// account ID of the target account.
     ///
     /// ```
-    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::accounts::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1160,295 +1992,463 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = OperationsForAccountRequest::new()
-    ///   .set_limit(2).unwrap();
+    /// let request = SingleAccountRequest::new()
+    ///     .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+    ///     .unwrap();
     ///
-    /// let response = horizon_client.get_operations_for_account(&request).await;
+    /// let response = horizon_client.get_single_account(&request).await;
     ///
-    /// // Access the payments
-    /// if let Ok(operations_for_account_response) = response {
-    ///   for operation in operations_for_account_response.embedded().records() {
-    ///    println!("operation ID: {}", operation.id());
-    ///  // Further processing...
-    /// }
-    /// }
-    /// # Ok({})
+    /// // Access the account details
+    /// println!("Account ID: {}", response?.account_id());
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_operations_for_account(
+    pub async fn get_single_account(
         &self,
-        request: &OperationsForAccountRequest,
-    ) -> Result<OperationResponse, String> {
-        self.get::<OperationResponse>(request).await
+        request: &SingleAccountRequest<AccountId>,
+    ) -> Result<Account, Error> {
+        self.get::<Account>(request).await
     }
 
-    /// Retrieves a list of all operations for a ledger from the Horizon server.
+    /// Retrieves a single data entry of a specific account from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all operations for a ledger from the Horizon server.
-    /// It requires an [`OperationsForLedgerRequest`] to specify the optional query parameters.
+    /// This asynchronous method fetches the value of a data entry, which is a name and value pair
+    /// stored on an account with the `manage_data` operation. It requires an
+    /// [`AccountDataRequest`] with the account ID and the name of the data entry.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/docs/data/horizon/api-reference/get-data-by-account-id">Retrieve an Account's Data</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`OperationsForLedgerRequest`] instance, containing the
-    /// parameters for the operations for ledger request.
+    /// * `request` - A reference to an [`AccountDataRequest`] instance, containing the account ID
+    ///   and the name of the data entry to be fetched.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
-    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On success, returns a `Result` wrapping an [`AccountDataResponse`], of which the value can
+    /// be decoded with [`AccountDataResponse::decoded_value`]. If the account does not have a data
+    /// entry with the name, Horizon responds with a not found error.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`OperationsForLedgerRequest`] and set any desired
-    /// filters or parameters.
-    ///
     /// ```
-    /// # use stellar_rs::operations::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::accounts::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = OperationsForLedgerRequest::new()
-    ///   .set_limit(2).unwrap();
+    /// let request = AccountDataRequest::new()
+    ///     .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?
+    ///     .set_key("config.memo_required")?;
     ///
-    /// let response = horizon_client.get_operations_for_ledger(&request).await;
+    /// let response = horizon_client.get_account_data(&request).await?;
     ///
-    /// // Access the payments
-    /// if let Ok(operations_for_ledger_response) = response {
-    ///   for operation in operations_for_ledger_response.embedded().records() {
-    ///    println!("operation ID: {}", operation.id());
-    ///  // Further processing...
-    /// }
-    /// }
-    /// # Ok({})
+    /// // Decode the value of the data entry
+    /// println!("Value: {:?}", response.decoded_value()?);
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_operations_for_ledger(
+    pub async fn get_account_data(
         &self,
-        request: &OperationsForLedgerRequest,
-    ) -> Result<OperationResponse, String> {
-        self.get::<OperationResponse>(request).await
+        request: &AccountDataRequest<AccountId, DataKey>,
+    ) -> Result<AccountDataResponse, Error> {
+        self.get::<AccountDataResponse>(request).await
     }
 
-    /// Retrieves a list of all operations for a specific liquidity pool from the Horizon server.
+    /// Calculates the minimum balance a specific account is required to hold.
     ///
-    /// This asynchronous method fetches a list of all operations for a specific liquidity pool from the Horizon server.
-    /// It requires an [`OperationsForLiquidityPoolRequest`] to specify the liquidity pool ID and optional query parameters.
+    /// This asynchronous method fetches the account, to determine its number of subentries and
+    /// sponsorships, and the latest ledger, to determine the current base reserve of the network.
+    /// The result can be used to calculate the amount of lumens the account is able to send,
+    /// using [`RequiredReserve::available_to_send`].
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`OperationsForLiquidityPoolRequest`] instance, containing the liquidity pool ID
-    /// and optional query parameters for the operations for liquidity pool request.
+    /// * `request` - A reference to a [`SingleAccountRequest`] instance, containing the
+    ///   account ID of the account for which the reserve is to be calculated.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
-    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
-    ///
-    /// # Usage
-    /// To use this method, create an instance of [`OperationsForLiquidityPoolRequest`] and set the liquidity pool ID and any desired
-    /// filters or parameters.
+    /// On success, returns a `Result` wrapping a [`RequiredReserve`], which holds the required
+    /// reserve in stroops. If either request fails, an error is returned within `Result`.
     ///
+    /// # Example
     /// ```
-    /// # use stellar_rs::operations::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::accounts::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = OperationsForLiquidityPoolRequest::new()
-    ///  .set_liquidity_pool_id("000000006520216af66d20d63a58534d6cbdf28ba9f2a9c1e03f8d9a756bb7d988b29bca");
-    ///
-    /// let response = horizon_client.get_operations_for_liquidity_pool(&request).await;
-    ///
-    /// // Access the operations
-    /// if let Ok(operations_for_liquidity_pool_response) = response {
-    ///  for operation in operations_for_liquidity_pool_response.embedded().records() {
+    /// let request = SingleAccountRequest::new()
+    ///     .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+    ///     .unwrap();
     ///
-    ///   println!("Operation ID: {}", operation.id());
-    /// // Further processing...
-    /// }
-    /// }
-    /// # Ok({})
+    /// let reserve = horizon_client.get_account_required_reserve(&request).await?;
+    /// println!("Required reserve: {} XLM", reserve);
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_operations_for_liquidity_pool(
+    pub async fn get_account_required_reserve(
         &self,
-        request: &OperationsForLiquidityPoolRequest,
-    ) -> Result<OperationResponse, String> {
-        self.get::<OperationResponse>(request).await
+        request: &SingleAccountRequest<AccountId>,
+    ) -> Result<RequiredReserve, Error> {
+        let account = self.get_single_account(request).await?;
+
+        let ledgers_request = LedgersRequest::new().set_order(Order::Desc)?.set_limit(1)?;
+        let ledgers = self.get_all_ledgers(&ledgers_request).await?;
+        let latest_ledger = ledgers
+            .embedded()
+            .records()
+            .first()
+            .ok_or_else(|| "No ledgers returned by the Horizon server.".to_string())?;
+
+        Ok(RequiredReserve::for_account(
+            *latest_ledger.base_reserve_in_stroops() as i64,
+            &account,
+        ))
     }
 
-    /// Retrieves a list of all operations for a specific transaction from the Horizon server.
+    /// Watches an account, yielding every change of its balances.
     ///
-    /// This asynchronous method fetches a list of all operations for a specific transaction from the Horizon server.
-    /// It requires an [`OperationsForTransactionRequest`] to specify the transaction hash and optional query parameters.
+    /// This method polls the account every `interval`, and compares its balances with those of the
+    /// previous poll. Every balance that changed is yielded as a [`BalanceChange`], which includes
+    /// the change in stroops and, if it could be determined, the effect that caused it. To find
+    /// the causes, the effects of the account that were recorded since the previous poll are
+    /// fetched as well. The first poll only serves as the baseline, so the stream does not yield
+    /// the existing balances.
+    ///
+    /// The stream does not end by itself. Errors, such as a failure to reach the Horizon server,
+    /// are yielded as items, after which polling continues at the next interval. To stop watching
+    /// the account, drop the stream.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`OperationsForTransactionRequest`] instance, containing the transaction hash
-    /// and optional query parameters for the operations for transaction request.
+    /// * `request` - A reference to a [`SingleAccountRequest`] containing the ID of the account
+    ///   to watch.
+    /// * `interval` - The time to wait between two polls.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
-    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Stream` of `Result`s, each containing a [`BalanceChange`], or an error if the
+    /// account or its effects could not be retrieved.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`OperationsForTransactionRequest`] and set the transaction hash and any desired
-    /// filters or parameters.
+    /// # Example
     ///
-    /// ```
-    /// # use stellar_rs::operations::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// ```rust
+    /// # use stellar_rs::accounts::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use futures::StreamExt;
+    /// # use std::time::Duration;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// let request = OperationsForTransactionRequest::new()
-    ///  .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020");
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = SingleAccountRequest::new()
+    ///     .set_account_id("GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE")?;
     ///
-    /// let response = horizon_client.get_operations_for_transaction(&request).await;
+    /// let changes = horizon_client.watch_account(&request, Duration::from_secs(5));
+    /// futures::pin_mut!(changes);
     ///
-    /// // Access the operations
-    /// if let Ok(operations_for_transaction_response) = response {
-    ///     for operation in operations_for_transaction_response.embedded().records() {
-    ///         println!("Operation ID: {}", operation.id());
-    ///         // Further processing...
-    ///     }
+    /// while let Some(change) = changes.next().await {
+    ///     let change = change?;
+    ///     println!("{} changed by {}", change.asset(), change.delta_amount());
+    /// #   break;
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_operations_for_transaction(
-        &self,
-        request: &OperationsForTransactionRequest,
-    ) -> Result<OperationResponse, String> {
-        self.get::<OperationResponse>(request).await
+    pub fn watch_account<'a>(
+        &'a self,
+        request: &SingleAccountRequest<AccountId>,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<BalanceChange, Error>> + 'a {
+        let state = AccountWatcherState::new(request.build_url(&self.base_url));
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(change) = state.pending.pop_front() {
+                    return Some((Ok(change), state));
+                }
+
+                if state.polled {
+                    crate::runtime::sleep(interval).await;
+                }
+                state.polled = true;
+
+                if let Err(e) = self.poll_account(&mut state).await {
+                    return Some((Err(e), state));
+                }
+            }
+        })
     }
 
-    /// Retrieves a list of order book details from the Horizon server.
+    /// Takes a snapshot of the balances of a watched account, along with the effects recorded
+    /// since the previous snapshot.
+    async fn poll_account(&self, state: &mut AccountWatcherState) -> Result<(), Error> {
+        let account: Account = self.get_from_url(&state.account_url).await?;
+        let request = EffectsForAccountRequest::new().set_account_id(account.account_id());
+
+        let mut effects = Vec::new();
+        match &state.effects_cursor {
+            // Before the first snapshot, only the latest effect is needed, to start from.
+            None => {
+                let request = request.set_order(Order::Desc)?.set_limit(1)?;
+                let response = self.get_effects_for_account(&request).await?;
+                effects.extend(response.embedded().records().first().cloned());
+            }
+            Some(cursor) => {
+                let request = request.set_limit(200)?;
+                let first_url = StreamOptions::new()
+                    .set_resume_from(cursor)
+                    .first_url(request.build_url(&self.base_url));
+
+                let mut next_url = Some(first_url);
+                while let Some(url) = next_url.take() {
+                    let page: EffectsResponse = self.get_from_url(&url).await?;
+                    if page.embedded().records().is_empty() {
+                        break;
+                    }
+                    effects.extend(page.embedded().records().iter().cloned());
+                    next_url = page
+                        .links()
+                        .next
+                        .as_ref()
+                        .and_then(|link| link.href.clone());
+                }
+            }
+        }
+
+        // If the account has no effects yet, start from the very first one.
+        if effects.is_empty() && state.effects_cursor.is_none() {
+            state.effects_cursor = Some("0".to_string());
+        }
+
+        state
+            .update(account.balances().clone(), &effects)
+            .map_err(Error::from)
+    }
+
+    /// Watches the accounts that match a filter, yielding every account that starts to match it.
     ///
-    /// This asynchronous method fetches a list of order book details from the Horizon server.
-    /// It requires a [`DetailsRequest`] to specify the parameters for the order book details request.
+    /// This method allows services to react to new accounts of interest, such as accounts that
+    /// are newly sponsored by an account, or accounts that add a given signer. Horizon does not
+    /// stream the list of accounts, so it is polled every `interval` instead, and compared with
+    /// the accounts of the previous poll. The first poll only serves as the baseline, so the
+    /// stream does not yield the accounts that already match the filter.
+    ///
+    /// Every poll requests all pages of matching accounts, so the request should have its limit
+    /// set to 200, the largest limit Horizon allows.
+    ///
+    /// The stream does not end by itself. Errors, such as a failure to reach the Horizon server
+    /// after the retries of the [`RetryPolicy`] of the client, if any, are yielded as items, after
+    /// which polling continues at the next interval. To stop watching the accounts, drop the
+    /// stream.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`DetailsRequest`] instance, containing the parameters for the order book details request.
+    /// * `request` - A reference to an [`AccountsRequest`] with the filter to watch, such as a
+    ///   sponsor or signer filter.
+    /// * `interval` - The time to wait between two polls.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`DetailsResponse`], which includes the list of order book details obtained from the Horizon server.
-    /// If the request fails, it returns an error within `Result`.
+    /// Returns a `Stream` of `Result`s, each containing an [`Account`] that started to match the
+    /// filter, or an error if the accounts could not be retrieved.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`DetailsRequest`] and set any desired filters or parameters.
+    /// # Example
     ///
-    /// ```
-    /// # use stellar_rs::order_book::prelude::*;
-    /// # use stellar_rs::models::prelude::*;
+    /// ```rust
+    /// # use stellar_rs::accounts::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    ///
+    /// # use futures::StreamExt;
+    /// # use std::time::Duration;
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)
-    /// #    .expect("Failed to create Horizon Client");
-    /// # let details_request = DetailsRequest::new()
-    /// # .set_buying_asset(AssetType::Native)
-    /// # .unwrap()
-    /// # .set_selling_asset(AssetType::Alphanumeric4(AssetData {
-    /// #     asset_code: "USDC".to_string(),
-    /// #     asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
-    /// #         .to_string(),
-    /// # }))
-    /// # .unwrap();
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = AccountsRequest::new()
+    ///     .set_sponsor_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?
+    ///     .set_limit(200)?;
     ///
-    /// let response = horizon_client.get_order_book_details(&details_request).await;
+    /// let accounts = horizon_client.watch_new_accounts(&request, Duration::from_secs(30));
+    /// futures::pin_mut!(accounts);
     ///
-    /// assert!(response.is_ok());
-    /// # Ok({})
+    /// while let Some(account) = accounts.next().await {
+    ///     println!("Newly sponsored account: {}", account?.account_id());
+    /// #   break;
+    /// }
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_order_book_details(
-        &self,
-        request: &DetailsRequest<SellingAsset, BuyingAsset>,
-    ) -> Result<DetailsResponse, String> {
-        self.get::<DetailsResponse>(request).await
+    pub fn watch_new_accounts<'a>(
+        &'a self,
+        request: &impl ValidAccountsRequest,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Account, Error>> + 'a {
+        self.watch_new_accounts_with_options(request, interval, None, StreamOptions::new())
     }
 
-    /// Retrieves a list of trade aggregations from the Horizon server.
+    /// Watches the accounts that match a filter, with control over shutdown and resumption.
     ///
-    /// This asynchronous method fetches a list of trade aggregations from the Horizon server.
-    /// It requires a [`TradeAggregationsRequest`] to specify the parameters for the trade aggregations request.
+    /// This method behaves like [`HorizonClient::watch_new_accounts`], but additionally accepts
+    /// the IDs of the accounts that are already known, and [`StreamOptions`].
+    ///
+    /// Since Horizon orders accounts by their ID rather than by the time they started to match a
+    /// filter, a watcher can not resume from a paging token. Instead, a service that persists the
+    /// IDs of the accounts it has processed can pass them as `known_accounts` when it restarts.
+    /// The first poll then yields every matching account that is not known, including the
+    /// accounts that started to match the filter while the service was down. Only the shutdown
+    /// token of the options is used; their checkpoint callback and resume point are ignored.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`TradeAggregationsRequest`] instance, containing the parameters for the order book details request.
+    /// * `request` - A reference to an [`AccountsRequest`] with the filter to watch.
+    /// * `interval` - The time to wait between two polls.
+    /// * `known_accounts` - The IDs of the accounts that are already known, or `None` to use the
+    ///   accounts of the first poll as the baseline.
+    /// * `options` - The [`StreamOptions`] controlling the lifecycle of the stream.
+    ///
+    pub fn watch_new_accounts_with_options<'a>(
+        &'a self,
+        request: &impl ValidAccountsRequest,
+        interval: Duration,
+        known_accounts: Option<HashSet<String>>,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<Account, Error>> + 'a {
+        let state = NewAccountsWatcherState::new(request.build_url(&self.base_url), known_accounts);
+
+        stream::unfold(
+            (state, options),
+            move |(mut state, options): (NewAccountsWatcherState, StreamOptions)| async move {
+                loop {
+                    if options.is_shut_down() {
+                        return None;
+                    }
+
+                    if let Some(account) = state.pending.pop_front() {
+                        return Some((Ok(account), (state, options)));
+                    }
+
+                    if state.polled {
+                        futures::select_biased! {
+                            _ = options.shut_down().fuse() => return None,
+                            _ = crate::runtime::sleep(interval).fuse() => {}
+                        }
+                    }
+                    state.polled = true;
+
+                    let accounts = futures::select_biased! {
+                        _ = options.shut_down().fuse() => return None,
+                        accounts = self.get_all_matching_accounts(&state.url).fuse() => accounts,
+                    };
+                    match accounts {
+                        Ok(accounts) => state.update(accounts),
+                        Err(e) => return Some((Err(e), (state, options))),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Retrieves the accounts on all pages of a list of accounts, following the `next` links.
+    async fn get_all_matching_accounts(&self, url: &str) -> Result<Vec<Account>, Error> {
+        let mut accounts = Vec::new();
+        let mut next_url = Some(url.to_string());
+        while let Some(url) = next_url.take() {
+            let page: AccountsResponse = self.get_from_url(&url).await?;
+            if page.embedded().records.is_empty() {
+                break;
+            }
+            accounts.extend(page.embedded().records.iter().cloned());
+            next_url = page
+                .links()
+                .next
+                .as_ref()
+                .and_then(|link| link.href.clone());
+        }
+
+        Ok(accounts)
+    }
+
+    /// Retrieves a list of all assets.
     ///
-    /// # Returns
+    /// This asynchronous method fetches a complete list of assets.
+    /// It requires a [`AllAssetsRequest`] to specify  optional query parameters
+    /// such as filters by `asset_code` or `asset_issuer`.
     ///
-    /// On successful execution, returns a `Result` containing a [`AllTradeAggregationsResponse`], which includes the list of order book details obtained from the Horizon server.
-    /// If the request fails, it returns an error within `Result`.
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-assets">List all Assets</a>
+    /// endpoint.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`TradeAggregationsRequest`] and set any desired filters or parameters.
+    /// # Arguments
+    /// * `request` - A reference to an [`AllAssetsRequest`] instance, containing the
+    /// parameters for the assets list request.
     ///
-    /// ```rust
-    /// use stellar_rs::horizon_client::HorizonClient;
-    /// use stellar_rs::trade_aggregations::prelude::*;
-    /// use stellar_rs::models::prelude::*;
+    /// # Returns
+    ///
+    /// On success, this method returns a `Result` wrapping an [`AllAssetsResponse`], which includes
+    /// the comprehensive list of assets retrieved from the Horizon server. If the request
+    /// encounters an issue, an error is returned within `Result`.
     ///
+    /// # Example
+    /// To use this method, create an instance of [`AllAssetsRequest`], set any desired
+    /// filters or parameters and pass
+    ///
+    /// ```
+    /// # use stellar_rs::assets::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = AllAssetsRequest::new()
+    ///     .set_asset_code("USD")
+    ///     .unwrap();
     ///
-    /// // Example: Fetching trade aggregations
-    /// let request = TradeAggregationsRequest::new()
-    ///     .set_base_asset(AssetType::Native).unwrap()
-    ///     .set_counter_asset(AssetType::Alphanumeric4(AssetData {
-    ///         asset_code: "USDC".to_string(),
-    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
-    ///     })).unwrap()
-    ///     .set_resolution(Resolution(ResolutionData::Duration604800000)).unwrap();
-    /// let response = horizon_client.get_trade_aggregations(&request).await?;
+    /// let response = horizon_client.get_all_assets(&request).await;
     ///
-    /// // Process the response...
+    /// // Access asset details
+    /// for asset in response?.embedded().records() {
+    ///     println!("Asset Code: {}", asset.asset_code());
+    ///     // Further processing...
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_trade_aggregations(
+    pub async fn get_all_assets(
         &self,
-        request: &TradeAggregationsRequest<BaseAsset, CounterAsset, Resolution>,
-    ) -> Result<AllTradeAggregationsResponse, String> {
-        self.get::<AllTradeAggregationsResponse>(request).await
+        request: &AllAssetsRequest,
+    ) -> Result<AllAssetsResponse, Error> {
+        self.get::<AllAssetsResponse>(request).await
     }
 
-    /// Retrieves a list of all trades from the Horizon server.
+    /// Retrieves all claimable balances.
     ///
-    /// This asynchronous method fetches a list of all trades from the Horizon server.
-    /// It requires an [`AllTradesRequest`] to specify the optional query parameters.
+    /// This asynchronous method queries the Horizon server for all claimable balances. It
+    /// requires a [`AllClaimableBalancesRequest`] to specify the query parameters.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-claimable-balances">List All Claimable Balances</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllTradesRequest`] instance, containing the
-    ///     parameters for the trades request.
+    /// * `request` - A reference to an [`AllClaimableBalancesRequest`] instance, which contains
+    /// the parameters for the claimable balances request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
-    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing an [`AllClaimableBalancesResponse`] with the list of all
+    /// claimable balances if successful. In case of a failure, it returns an error within `Result`.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`AllTradesRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example
+    /// To use this method, create an instance of [`AllClaimableBalancesRequest`], set any desired
+    /// filters or parameters and pass
     ///
     /// ```
-    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::claimable_balances::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1456,99 +2456,125 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllTradesRequest::new();
+    /// let request = AllClaimableBalancesRequest::new()
+    ///     .set_sponsor("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+    ///     .unwrap();
     ///
-    /// let response = horizon_client.get_all_trades(&request).await;
+    /// let response = horizon_client.get_all_claimable_balances(&request).await;
     ///
-    /// // Access the trades
-    /// if let Ok(trades_response) = response {
-    ///     for trade in trades_response.embedded().records() {
-    ///         println!("Trade ID: {}", trade.id());
-    ///         // Further processing...
-    ///     }
+    /// match response {
+    ///     Ok(all_balances) => {
+    ///         for balance in all_balances.embedded().records() {
+    ///             println!("Balance ID: {}", balance.id());
+    ///             // Further processing...
+    ///         }
+    ///     },
+    ///     Err(e) => eprintln!("Error fetching claimable balances: {}", e),
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_all_trades(
+    pub async fn get_all_claimable_balances(
         &self,
-        request: &AllTradesRequest,
-    ) -> Result<AllTradesResponse, String> {
-        self.get::<AllTradesResponse>(request).await
+        request: &AllClaimableBalancesRequest,
+    ) -> Result<AllClaimableBalancesResponse, Error> {
+        self.get::<AllClaimableBalancesResponse>(request).await
     }
 
-    /// Retrieves a list of all trades for a given account from the Horizon server.
+    /// Retrieves the claimable balances sponsored by a specific account.
     ///
-    /// This asynchronous method fetches a list of all trades for a given account from the Horizon server.
-    /// It requires an [`TradesForAccountRequest`] to specify the optional query parameters.
+    /// This asynchronous method queries the Horizon server for the claimable balances whose reserve
+    /// is sponsored by an account. It requires a [`ClaimableBalancesForSponsorRequest`] to specify the
+    /// sponsor and optional pagination parameters.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-claimable-balances">List All Claimable Balances</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`TradesForAccountRequest`] instance, containing the
-    ///     parameters for the trades request.
+    /// * `request` - A reference to a [`ClaimableBalancesForSponsorRequest`] instance, which contains
+    ///   the sponsor and the pagination parameters.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
-    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
-    ///
-    /// # Usage
-    /// To use this method, create an instance of [`TradesForAccountRequest`] and set any desired
-    /// filters or parameters.
+    /// Returns a `Result` containing an [`AllClaimableBalancesResponse`] with the claimable balances
+    /// sponsored by the account if successful. In case of a failure, it returns an error within `Result`.
     ///
+    /// # Example
     /// ```
-    /// # use stellar_rs::trades::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::claimable_balances::prelude::*;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = TradesForAccountRequest::new()
-    ///    .set_account_id("GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A")
-    ///    .unwrap();
-    ///
-    /// let response = horizon_client.get_trades_for_account(&request).await;
+    /// let request = ClaimableBalancesForSponsorRequest::new()
+    ///     .set_sponsor("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")?
+    ///     .set_limit(50)?;
     ///
-    /// // Access the trades
-    /// if let Ok(trades_response) = response {
-    ///     for trade in trades_response.embedded().records() {
-    ///         println!("Trade ID: {}", trade.id());
-    ///         // Further processing...
-    ///     }
+    /// let response = horizon_client.get_claimable_balances_for_sponsor(&request).await?;
+    /// for balance in response.embedded().records() {
+    ///     println!("Balance ID: {}", balance.id());
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_trades_for_account(
+    pub async fn get_claimable_balances_for_sponsor(
         &self,
-        request: &TradesForAccountRequest<TradeAccountId>,
-    ) -> Result<AllTradesResponse, String> {
-        self.get::<AllTradesResponse>(request).await
+        request: &ClaimableBalancesForSponsorRequest<ClaimableBalanceSponsor>,
+    ) -> Result<AllClaimableBalancesResponse, Error> {
+        self.get::<AllClaimableBalancesResponse>(request).await
     }
 
-    /// Retrieves a list of all successful trades fulfilled by the given liquidity pool from the Horizon server.
+    /// Retrieves the first page of claimable balances sponsored by a specific account.
     ///
-    /// This asynchronous method fetches a list of all successful trades fulfilled by the given liquidity pool
-    /// from the Horizon server. It requires an [`TradesForLiquidityPoolRequest`] to specify the optional query parameters.
+    /// This is a convenience method for
+    /// [`get_claimable_balances_for_sponsor`](Self::get_claimable_balances_for_sponsor), using the
+    /// default pagination parameters of the Horizon server.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`TradesForLiquidityPoolRequest`] instance, containing the
-    ///     parameters for the trades request.
+    /// * `account_id` - The Stellar public key of the sponsor.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
-    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing an [`AllClaimableBalancesResponse`] with the claimable balances
+    /// sponsored by the account, or an error if the account ID is invalid or the request fails.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`TradesForLiquidityPoolRequest`] and set any desired
-    /// filters or parameters.
+    pub async fn get_claimable_balances_sponsored_by(
+        &self,
+        account_id: &str,
+    ) -> Result<AllClaimableBalancesResponse, Error> {
+        let request = ClaimableBalancesForSponsorRequest::new().set_sponsor(account_id)?;
+        self.get_claimable_balances_for_sponsor(&request).await
+    }
+
+    /// Retrieves detailed information about a specific claimable balance from the Horizon server.
+    ///
+    /// This asynchronous method is used to fetch detailed information about a single claimable
+    /// balance from the Horizon server. It requires a [`SingleClaimableBalanceRequest`] that
+    /// includes the unique identifier of the claimable balance to be retrieved.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/retrieve-a-claimable-balance">Retrieve a Claimable Balance</a>
+    /// endpoint.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`SingleClaimableBalanceRequest`] instance containing the
+    /// unique ID of the claimable balance to be fetched.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`ClaimableBalance`],
+    /// which includes detailed information about the requested claimable balance. If the request
+    /// fails, it returns an error within `Result`.
+    ///
+    /// # Example
+    /// To use this method, create an instance of [`SingleClaimableBalanceRequest`]
+    /// with the specific claimable balance ID.
     ///
     /// ```
-    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::claimable_balances::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1556,304 +2582,339 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = TradesForLiquidityPoolRequest::new()
-    ///    .set_liquidity_pool_id("0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3")
-    ///    .unwrap();
+    ///  let request = SingleClaimableBalanceRequest::new()
+    ///    .set_claimable_balance_id("000000006520216af66d20d63a58534d6cbdf28ba9f2a9c1e03f8d9a756bb7d988b29bca");
     ///
-    /// let response = horizon_client.get_trades_for_liquidity_pool(&request).await;
+    /// let response = horizon_client.get_single_claimable_balance(&request).await;
     ///
-    /// // Access the trades
-    /// if let Ok(trades_response) = response {
-    ///     for trade in trades_response.embedded().records() {
-    ///         println!("Trade ID: {}", trade.id());
-    ///         // Further processing...
-    ///     }
+    /// // Access the details of the claimable balance
+    /// if let Ok(balance_response) = response {
+    ///     println!("Balance Amount: {}", balance_response.amount());
+    ///     // Further processing...
     /// }
-    /// # Ok({})
+    ///
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_trades_for_liquidity_pool(
+    pub async fn get_single_claimable_balance(
         &self,
-        request: &TradesForLiquidityPoolRequest<TradeLiquidityPoolId>,
-    ) -> Result<AllTradesResponse, String> {
-        self.get::<AllTradesResponse>(request).await
+        request: &SingleClaimableBalanceRequest<ClaimableBalanceId>,
+    ) -> Result<ClaimableBalance, Error> {
+        self.get::<ClaimableBalance>(request).await
     }
 
-    /// Retrieves a list of all trades for a given offer from the Horizon server.
+    /// Retrieves a list of effects for a specific account from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all trades for a given offer from the Horizon server.
-    /// It requires an [`TradesForOfferRequest`] to specify the optional query parameters.
+    /// This asynchronous method fetches a list of effects for a specific account from the Horizon server.
+    /// It requires an [`EffectsForAccountRequest`] to specify the account ID and optional query parameters.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`TradesForOfferRequest`] instance, containing the
-    ///     parameters for the trades request.
+    /// * `request` - A reference to an [`EffectsForAccountRequest`] instance, containing the account ID
+    /// and optional query parameters for the effects request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
-    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On successful execution, returns a `Result` containing an [`EffectsForAccountResponse`], which includes
+    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`TradesForOfferRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example
+    /// To use this method, create an instance of [`EffectsForAccountRequest`] and set the account ID and any
+    /// desired query parameters.
     ///
     /// ```
-    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::effects::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
+    ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = TradesForOfferRequest::new()
-    ///    .set_offer_id("42")
-    ///    .unwrap();
+    /// let request = EffectsForAccountRequest::new()
+    ///    .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7");
     ///
-    /// let response = horizon_client.get_trades_for_offer(&request).await;
+    /// let response = horizon_client.get_effects_for_account(&request).await;
     ///
-    /// // Access the trades
-    /// if let Ok(trades_response) = response {
-    ///     for trade in trades_response.embedded().records() {
-    ///         println!("Trade ID: {}", trade.id());
-    ///         // Further processing...
-    ///     }
+    /// // Access the effects
+    /// if let Ok(effects_response) = response {
+    ///    for effect in effects_response.embedded().records() {
+    ///       println!("Effect ID: {}", effect.id());
+    ///      // Further processing...
+    ///   }
     /// }
-    /// # Ok({})
+    ///
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_trades_for_offer(
+    pub async fn get_effects_for_account(
         &self,
-        request: &TradesForOfferRequest<TradeOfferId>,
-    ) -> Result<AllTradesResponse, String> {
-        self.get::<AllTradesResponse>(request).await
+        request: &EffectsForAccountRequest,
+    ) -> Result<EffectsResponse, Error> {
+        self.get::<EffectsResponse>(request).await
     }
 
-    /// Fetches all liquidity pools from the Stellar Horizon API.
+    /// Retrieves a list of effects for a specific account from the Horizon server.
     ///
-    /// This asynchronous method retrieves a list of all liquidity pools from the Horizon server.
-    /// It requires an [`AllLiquidityPoolsRequest`] to specify optional query parameters such as
-    /// filters by `asset_code` or `asset_issuer`.
+    /// This asynchronous method fetches a list of effects for a specific account from the Horizon server.
+    /// It requires an [`EffectsForLiquidityPoolsRequest`] to specify the account ID and optional query parameters.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllLiquidityPoolsRequest`] instance, containing the
-    /// parameters for the liquidity pools request.
+    /// * `request` - A reference to an [`EffectsForLiquidityPoolsRequest`] instance, containing the account ID
+    /// and optional query parameters for the effects request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllLiquidityPoolsResponse`], which includes
-    /// the comprehensive list of liquidity pools retrieved from the Horizon server. If the request
-    /// encounters an issue, an error is returned within `Result`.
+    /// On successful execution, returns a `Result` containing an [`EffectsForLiquidityPoolResponse`], which includes
+    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
     /// # Example
-    /// To use this method, create an instance of [`AllLiquidityPoolsRequest`], set any desired
-    /// filters or parameters and pass
+    /// To use this method, create an instance of [`EffectsForLiquidityPoolsRequest`] and set the account ID and any
+    /// desired query parameters.
     ///
     /// ```
-    /// # use stellar_rs::liquidity_pools::all_liquidity_pools_request::AllLiquidityPoolsRequest;
+    /// # use stellar_rs::effects::prelude::*;
+    /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
+    ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)?;
-    /// let request = AllLiquidityPoolsRequest::new()
-    ///     .add_alphanumeric4_reserve("USDC", "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5");
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = EffectsForAccountRequest::new()
+    ///    .set_account_id("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7");
     ///
-    /// let response = horizon_client.get_all_liquidity_pools(&request).await;
+    /// let response = horizon_client.get_effects_for_account(&request).await;
     ///
-    /// // Access liquidity pool details
-    /// for pool in response?.embedded().records() {
-    ///     println!("Pool ID: {}", pool.id());
-    ///     // Further processing...
+    /// // Access the effects
+    /// if let Ok(effects_response) = response {
+    ///    for effect in effects_response.embedded().records() {
+    ///       println!("Effect ID: {}", effect.id());
+    ///      // Further processing...
+    ///   }
     /// }
+    ///
     /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_all_liquidity_pools(
+    pub async fn get_effects_for_liquidity_pools(
         &self,
-        request: &AllLiquidityPoolsRequest,
-    ) -> Result<AllLiquidityPoolsResponse, String> {
-        self.get::<AllLiquidityPoolsResponse>(request).await
+        request: &EffectsForLiquidityPoolRequest,
+    ) -> Result<EffectsResponse, Error> {
+        self.get::<EffectsResponse>(request).await
     }
 
-    /// Retrieves detailed information for a specific liquidity pool from the Horizon server.
+    /// Retrieves a list of effects for a specific operation from the Horizon server.
     ///
-    /// This asynchronous method is designed to fetch detailed information about a single liquidity
-    /// pool from the Horizon server. It requires a [`SingleLiquidityPoolRequest`] that includes the
-    /// unique identifier of the liquidity pool to be retrieved.
+    /// This asynchronous method fetches a list of effects for a specific operation from the Horizon server.
+    /// It requires an [`EffectsForOperationRequest`] to specify the operation ID and optional query parameters.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`SingleLiquidityPoolRequest`] instance containing the
-    /// unique ID of the liquidity pool to be fetched.
+    /// * `request` - A reference to an [`EffectsForOperationRequest`] instance, containing the operation ID
+    /// and optional query parameters for the effects request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`SingleLiquidityPoolResponse`],
-    /// which includes detailed information about the requested liquidity pool. If the request fails,
-    /// it returns an error encapsulated within `Result`.
+    /// On successful execution, returns a `Result` containing an [`EffectsResponse`], which includes
+    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
     /// # Example
-    /// To use this method, create an instance of [`SingleLiquidityPoolRequest`]
-    /// with the specific liquidity pool ID.
+    /// To use this method, create an instance of [`EffectsForOperationRequest`] and set the operation ID and any
+    /// desired query parameters.
     ///
     /// ```
-    /// # use stellar_rs::liquidity_pools::single_liquidity_pool_request::SingleLiquidityPoolRequest;
+    /// # use stellar_rs::effects::prelude::*;
+    /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
-    /// # let horizon_client = HorizonClient::new(base_url)?;
-    /// let request = SingleLiquidityPoolRequest::new()
-    ///     .set_liquidity_pool_id("000000006520216af66d20d63a58534d6cbdf28ba9f2a9c1e03f8d9a756bb7d988b29bca").unwrap();
-    ///
-    /// let response = horizon_client.get_single_liquidity_pool(&request).await;
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = EffectsForOperationRequest::new()
+    ///   .set_operation_id("123")?;
+    ///     
+    /// let response = horizon_client.get_effects_for_operation(&request).await;
     ///
-    /// // Access the details of the liquidity pool
-    /// if let Ok(pool_response) = response {
-    ///     println!("Pool ID: {}", pool_response.id());
+    /// // Access the effects
+    /// if let Ok(effects_response) = response {
+    ///     for effect in effects_response.embedded().records() {
+    ///         println!("Effect ID: {}", effect.id());
     ///     // Further processing...
+    ///     }
     /// }
     ///
     /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_single_liquidity_pool(
+    pub async fn get_effects_for_operation(
         &self,
-        request: &SingleLiquidityPoolRequest<LiquidityPoolId>,
-    ) -> Result<LiquidityPool, String> {
-        self.get::<LiquidityPool>(request).await
+        request: &EffectsForOperationRequest,
+    ) -> Result<EffectsResponse, Error> {
+        self.get::<EffectsResponse>(request).await
     }
 
-    /// Retrieves detailed information for a specific transaction from the Horizon server.
-    ///
-    /// This asynchronous method fetches details of a single transaction from the Horizon server.
-    /// It requires a [`SingleTransactionRequest`] parameterized with `TransactionHash`, which includes the hash
-    /// of the transaction to be retrieved.
+    /// Retrieves a list of effects for a specific transaction from the Horizon server.
     ///
-    /// Adheres to the <a href="https://developers.stellar.org/network/horizon/api-reference/resources/retrieve-a-transaction">Retrieve a Transaction endpoint</a>
-    /// endpoint.
+    /// This asynchronous method fetches a list of effects for a specific transaction from the Horizon server.
+    /// It requires an [`EffectForTransactionRequest`] to specify the transaction hash and optional query parameters.
     ///
     /// # Arguments
-    ///
-    /// * `request` - A reference to a [`SingleTransactionRequest<TransactionHash>`] instance, containing the
-    ///   hash of the transaction for which details are to be fetched.
+    /// * `request` - A reference to an [`EffectForTransactionRequest`] instance, containing the transaction hash
+    /// and optional query parameters for the effects request.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing an [`TransactionResponse`], which includes detailed
-    /// information about the requested transaction. If the request fails, it returns an error
-    /// encapsulated within `Result`.
+    /// On successful execution, returns a `Result` containing an [`EffectsResponse`], which includes
+    /// the list of effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`SingleTransactionRequest`] and set the
-    /// hash of the transaction to be queried.
+    /// # Example
+    /// To use this method, create an instance of [`EffectForTransactionRequest`] and set the transaction hash and any
+    /// desired query parameters.
     ///
     /// ```
-    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::effects::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    /// #
+    ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = SingleTransactionRequest::new()
-    ///     .set_transaction_hash("be0d59c8706e8fd525d2ab10910a55ec57323663858c65b330a3f93afb13ab0f") // example transaction hash
-    ///     .unwrap();
+    /// let request = EffectForTransactionRequest::new()
+    ///  .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020")?;
     ///
-    /// let response = horizon_client.get_single_transaction(&request).await;
+    /// let response = horizon_client.get_effects_for_transaction(&request).await;
     ///
-    /// // Access the details of the claimable balance
-    /// if let Ok(transaction_response) = response {
-    ///     println!("Created at: {}", transaction_response.created_at());
+    /// // Access the effects
+    /// if let Ok(effects_response) = response {
+    ///     for effect in effects_response.embedded().records() {
+    ///         println!("Effect ID: {}", effect.id());
     ///     // Further processing...
+    ///     }
     /// }
     ///
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_single_transaction(
+    pub async fn get_effects_for_transaction(
         &self,
-        request: &SingleTransactionRequest<TransactionHash>,
-    ) -> Result<TransactionResponse, String> {
-        self.get::<TransactionResponse>(request).await
+        request: &EffectForTransactionRequest,
+    ) -> Result<EffectsResponse, Error> {
+        self.get::<EffectsResponse>(request).await
     }
 
-    /// Retrieves a list of all transactions from the Horizon server.
+    /// Retrieves all effects of a transaction, grouped by the operation that produced them.
     ///
-    /// This asynchronous method fetches a list of all transactions from the Horizon server.
-    /// It requires an [`AllTransactionsRequest`] to specify the optional query parameters.
+    /// This asynchronous method pages through all effects of the given transaction and groups them
+    /// by operation ID, which makes it easy to render a transaction the way a block explorer does:
+    /// each operation, followed by its effects. The operations are ordered by ID, and thereby in
+    /// the order in which they appear in the transaction. The effects of an operation retain the
+    /// order in which Horizon returned them.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllTransactionsRequest`] instance, containing the
-    /// parameters for the transactions request.
+    /// * `transaction_hash` - The hash of the transaction to retrieve the effects of.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
-    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing a map from the ID of each operation to its effects.
+    /// Operations without effects are not part of the map. If the hash is invalid or any of the
+    /// requests fails, it returns an error encapsulated within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`AllTransactionsRequest`] and set any desired
-    /// filters or parameters.
-    ///
     /// ```
-    /// # use stellar_rs::transactions::prelude::*;
-    /// # use stellar_rs::models::{Request, IncludeFailed};
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllTransactionsRequest::new()
-    ///   .set_include_failed(IncludeFailed::True).unwrap();
-    ///
-    /// let response = horizon_client.get_all_transactions(&request).await;
-    ///
-    /// // Access the transactions
-    /// if let Ok(transactions_response) = response {
-    ///     for transaction in transactions_response.embedded().records() {
-    ///         println!("Transaction ID: {}", transaction.id());
-    ///         // Further processing...
+    /// let effects = horizon_client
+    ///     .get_effects_grouped_by_operation(
+    ///         "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020",
+    ///     )
+    ///     .await?;
+    ///
+    /// for (operation_id, effects) in &effects {
+    ///     println!("Operation {}", operation_id);
+    ///     for effect in effects {
+    ///         println!("  {}", effect.effect_type());
     ///     }
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_all_transactions(
+    pub async fn get_effects_grouped_by_operation(
         &self,
-        request: &AllTransactionsRequest,
-    ) -> Result<AllTransactionsResponse, String> {
-        self.get::<AllTransactionsResponse>(request).await
+        transaction_hash: &str,
+    ) -> Result<BTreeMap<OperationId, Vec<Effect>>, Error> {
+        const PAGE_SIZE: u8 = 200;
+
+        let request = EffectForTransactionRequest::new()
+            .set_transaction_hash(transaction_hash)?
+            .set_limit(PAGE_SIZE)?
+            .set_order(Order::Asc)?;
+
+        let mut grouped: BTreeMap<OperationId, Vec<Effect>> = BTreeMap::new();
+        let mut next_url = Some(request.build_url(&self.base_url));
+        while let Some(url) = next_url.take() {
+            let page = self.get_from_url::<EffectsResponse>(&url).await?;
+            let records = &page.embedded().records;
+
+            // A page that is not full is the last one; skip requesting the empty page after it.
+            if records.len() == usize::from(PAGE_SIZE) {
+                next_url = page
+                    .links()
+                    .next
+                    .as_ref()
+                    .and_then(|link| link.href.clone());
+            }
+            for effect in records {
+                grouped
+                    .entry(effect.operation_id()?)
+                    .or_default()
+                    .push(effect.clone());
+            }
+        }
+
+        Ok(grouped)
     }
 
-    /// Retrieves a list of all transactions for a given account from the Horizon server.
+    /// Retrieves a list of all ledgers.
     ///
-    /// This asynchronous method fetches a list of all transactions for a given account from
-    /// the Horizon server. It requires an [`TransactionsForAccountRequest`] to specify the optional query parameters.
+    /// This asynchronous method is designed to fetch list of ledgers
+    /// from the Horizon server. It requires a [`LedgersRequest`] to specify the parameters
+    /// for the ledger retrieval.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-ledgers">List All Ledgers</a>
+    /// endpoint.
+    ///
+    /// If the request has time filters, such as [`LedgersRequest::set_closed_after`], this method
+    /// sends additional requests to find the range of ledgers that were closed within the filters.
+    /// The returned page only contains ledgers within that range, and may therefore contain fewer
+    /// records than the limit of the request.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`TransactionsForAccountRequest`] instance, containing the
-    /// parameters for the transactions request.
+    /// * `request` - A reference to a [`LedgersRequest`] instance, specifying the query
+    /// parameters for retrieving the ledgers.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
-    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On successful execution, returns a `Result` containing a [`LedgersResponse`],
+    /// which includes the list of all ledgers obtained from the Horizon server. If the request
+    /// fails, it returns an error within `Result`.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`TransactionsForAccountRequest`] and set any desired
-    /// filters or parameters.
+    /// # Example
+    /// To use this method, create an instance of [`LedgersRequest`], set any
+    /// desired pagination parameters.
     ///
     /// ```
-    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::ledgers::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1861,50 +2922,196 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = TransactionsForAccountRequest::new()
-    ///     .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H").unwrap()
-    ///     .set_include_failed(true).unwrap();
-    ///
-    /// let response = horizon_client.get_transactions_for_account(&request).await;
+    /// let request = LedgersRequest::new()
+    ///     .set_limit(2)
+    ///     .unwrap();
     ///
-    /// // Access the transactions
-    /// if let Ok(transactions_response) = response {
-    ///     for transaction in transactions_response.embedded().records() {
-    ///         println!("Transaction ID: {}", transaction.id());
-    ///         // Further processing...
-    ///     }
-    /// }
-    /// # Ok({})
-    /// # }
+    /// let response = horizon_client.get_all_ledgers(&request).await;
+    /// // Process the response
+    /// match response {
+    ///     Ok(ledgers_response) => {
+    ///         for ledger in ledgers_response.embedded().records() {
+    ///             println!("Ledger ID: {}", ledger.id());
+    ///             // Further processing...
+    ///         }
+    ///     }
+    ///     Err(e) => println!("Error parsing response: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn get_all_ledgers(
+        &self,
+        request: &LedgersRequest,
+    ) -> Result<LedgersResponse, Error> {
+        let (closed_after, closed_before) = (request.closed_after(), request.closed_before());
+        if closed_after.is_none() && closed_before.is_none() {
+            return self.get::<LedgersResponse>(request).await;
+        }
+
+        let (lower, upper) = match request.search_bounds() {
+            Some((lower, upper)) => (lower.value(), upper.value()),
+            None => self.ledger_history_bounds().await?,
+        };
+        let (lower, upper) = (lower as u64, upper as u64);
+
+        // The ledgers within the filters form the range `first..end` of sequence numbers.
+        let first = match closed_after {
+            Some(closed_after) => {
+                self.first_ledger_closed_since(closed_after, lower, upper)
+                    .await?
+            }
+            None => lower,
+        };
+        let end = match closed_before {
+            Some(closed_before) => {
+                self.first_ledger_closed_since(closed_before, lower, upper)
+                    .await?
+            }
+            None => upper + 1,
+        };
+
+        // Start paging at the range, unless the cursor of the request already lies beyond it. A
+        // ledger's paging token is its sequence number shifted by 32 bits.
+        let page_params = request.page_params();
+        let cursor = match page_params.order() {
+            Some(Order::Desc) => {
+                let range_cursor = end << 32;
+                Some(
+                    page_params
+                        .cursor()
+                        .map_or(range_cursor, |c| c.min(range_cursor)),
+                )
+            }
+            _ => {
+                let range_cursor = first.saturating_sub(1) << 32;
+                page_params
+                    .cursor()
+                    .map(|c| c.max(range_cursor))
+                    .or((range_cursor > 0).then_some(range_cursor))
+            }
+        };
+
+        let mut response = self
+            .get::<LedgersResponse>(&request.with_cursor(cursor))
+            .await?;
+        response.retain_sequences(first..end);
+
+        Ok(response)
+    }
+
+    /// Streams ledgers in near real time, as the Horizon server ingests them.
     ///
-    pub async fn get_transactions_for_account(
+    /// This method opens a Server-Sent Events connection, as described at
+    /// [`HorizonClient::stream_sse`], without further [`StreamOptions`].
+    /// The time filters of the request are not applied to the stream.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`LedgersRequest`], whose cursor determines the first
+    ///   ledger to be streamed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing a [`Ledger`], or an error if the
+    /// connection could not be established. The stream ends after an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::ledgers::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let stream = horizon_client.stream_ledgers(&LedgersRequest::new());
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(ledger) = stream.next().await {
+    ///     println!("Ledger: {}", ledger?.sequence());
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_ledgers<'a>(
+        &'a self,
+        request: &LedgersRequest,
+    ) -> impl Stream<Item = Result<Ledger, Error>> + 'a {
+        self.stream_sse(request, StreamOptions::new())
+    }
+
+    /// Returns the sequence numbers of the oldest and the latest ledger known to the Horizon
+    /// server.
+    async fn ledger_history_bounds(&self) -> Result<(u32, u32), Error> {
+        let mut bounds = [0; 2];
+        for (bound, order) in bounds.iter_mut().zip([Order::Asc, Order::Desc]) {
+            let request = LedgersRequest::new().set_limit(1)?.set_order(order)?;
+            let response = self.get::<LedgersResponse>(&request).await?;
+            *bound = response
+                .embedded()
+                .records()
+                .first()
+                .ok_or_else(|| Error::Other("the Horizon server has no ledgers".to_string()))?
+                .sequence()
+                .value();
+        }
+
+        Ok((bounds[0], bounds[1]))
+    }
+
+    /// Returns the sequence number of the first ledger in `lower..=upper` that was closed at or
+    /// after the given moment, or `upper + 1` if there is no such ledger, using a binary search.
+    async fn first_ledger_closed_since(
         &self,
-        request: &TransactionsForAccountRequest<TransactionsAccountId>,
-    ) -> Result<AllTransactionsResponse, String> {
-        self.get::<AllTransactionsResponse>(request).await
+        moment: DateTime,
+        lower: u64,
+        upper: u64,
+    ) -> Result<u64, Error> {
+        let (mut low, mut high) = (lower, upper + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let request = SingleLedgerRequest::new().set_sequence(middle as u32)?;
+            let ledger = self.get_single_ledger(&request).await?;
+            let closed_at = datetime::parse_rfc3339(ledger.closed_at())
+                .map_err(|e| Error::Deserialization(format!("invalid closed_at: {}", e)))?;
+
+            if closed_at >= moment {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+
+        Ok(low)
     }
 
-    /// Retrieves a list of all transactions in a given ledger from the Horizon server.
+    /// Retrieves detailed information for a specific ledger from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all transactions in a given ledger from
-    /// the Horizon server. It requires an [`TransactionsForLedgerRequest`] to specify the optional query parameters.
+    /// This asynchronous method fetches details of a single ledger from the Horizon server.
+    /// It requires a [`SingleLedgerRequest`] parameterized with `Sequence`, which includes the sequence number
+    /// of the ledger to be retrieved.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/retrieve-a-ledger">Retrieve a Ledger</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`TransactionsForLedgerRequest`] instance, containing the
-    /// parameters for the transactions request.
+    /// * `request` - A reference to a [`SingleLedgerRequest<Sequence>`] instance, containing the
+    ///   sequence number of the ledger for which details are to be fetched.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
-    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing a [`Ledger`], which includes detailed
+    /// information about the requested ledger. If the request fails, it returns an error
+    /// encapsulated within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`TransactionsForLedgerRequest`] and set any desired
-    /// filters or parameters.
+    /// To use this method, create an instance of [`SingleLedgerRequest`] and set the
+    /// sequence number of the ledger to be queried.
     ///
     /// ```
-    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::ledgers::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1912,50 +3119,50 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = TransactionsForLedgerRequest::new()
-    ///     .set_ledger_sequence("539").unwrap()
-    ///     .set_include_failed(true).unwrap();
+    /// let request = SingleLedgerRequest::new()
+    ///     .set_sequence(2).unwrap();
     ///
-    /// let response = horizon_client.get_transactions_for_ledger(&request).await;
+    /// let response = horizon_client.get_single_ledger(&request).await;
     ///
-    /// // Access the transactions
-    /// if let Ok(transactions_response) = response {
-    ///     for transaction in transactions_response.embedded().records() {
-    ///         println!("Transaction ID: {}", transaction.id());
-    ///         // Further processing...
-    ///     }
+    /// if let Ok(ledger) = response {
+    ///     println!("Ledger ID: {}", ledger.id());
+    ///     // Additional processing...
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_transactions_for_ledger(
+    pub async fn get_single_ledger(
         &self,
-        request: &TransactionsForLedgerRequest<TransactionsLedgerId>,
-    ) -> Result<AllTransactionsResponse, String> {
-        self.get::<AllTransactionsResponse>(request).await
+        request: &SingleLedgerRequest<Sequence>,
+    ) -> Result<Ledger, Error> {
+        self.get::<Ledger>(request).await
     }
 
-    /// Retrieves a list of all transactions referencing a given liquidity pool from the Horizon server.
+    /// Retrieves a list of all effects from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all transactions referencing a given liquidity pool from
-    /// the Horizon server. It requires an [`TransactionsForLiquidityPoolRequest`] to specify the optional query parameters.
+    /// This asynchronous method fetches a list of all effects from the Horizon server.
+    /// It requires an [`AllEffectsRequest`] to specify the optional query parameters.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/api/horizon/resources/list-all-effects">Retrieve a Ledger</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`TransactionsForLiquidityPoolRequest`] instance, containing the
-    /// parameters for the transactions request.
+    ///
+    /// * `request` - A reference to an [`AllEffectsRequest`] instance, containing the
+    /// parameters for the effects request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
-    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On successful execution, returns a `Result` containing an [`AllEffectsResponse`], which includes
+    /// the list of all effects obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`TransactionsForLiquidityPoolRequest`] and set any desired
+    /// To use this method, create an instance of [`AllEffectsRequest`] and set any desired
     /// filters or parameters.
     ///
     /// ```
-    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::effects::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -1963,328 +3170,441 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = TransactionsForLiquidityPoolRequest::new()
-    ///     .set_liquidity_pool_id("0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10").unwrap()
-    ///     .set_include_failed(true).unwrap();
+    /// let request = AllEffectsRequest::new()
+    ///    .set_limit(2).unwrap();
     ///
-    /// let response = horizon_client.get_transactions_for_liquidity_pool(&request).await;
+    /// let response = horizon_client.get_all_effects(&request).await;
     ///
-    /// // Access the transactions
-    /// if let Ok(transactions_response) = response {
-    ///     for transaction in transactions_response.embedded().records() {
-    ///         println!("Transaction ID: {}", transaction.id());
-    ///         // Further processing...
-    ///     }
+    /// // Access the effects
+    /// if let Ok(effects_response) = response {
+    ///   for effect in effects_response.embedded().records() {
+    ///     println!("Effect ID: {}", effect.id());
+    ///    // Further processing...
+    ///  }
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_transactions_for_liquidity_pool(
+    pub async fn get_all_effects(
         &self,
-        request: &TransactionsForLiquidityPoolRequest<TransactionsLiquidityPoolId>,
-    ) -> Result<AllTransactionsResponse, String> {
-        self.get::<AllTransactionsResponse>(request).await
+        request: &AllEffectsRequest,
+    ) -> Result<EffectsResponse, Error> {
+        self.get::<EffectsResponse>(request).await
     }
 
-    /// Retrieves payment paths from the Horizon server.
+    /// Streams effects in near real time, as the Horizon server ingests them.
     ///
-    /// This asynchronous method fetches a list of payment paths from
-    /// the Horizon server. It requires an [`FindPaymentsPathRequest`] to specify the optional query parameters.
+    /// This method opens a Server-Sent Events connection, as described at
+    /// [`HorizonClient::stream_sse`], without further [`StreamOptions`].
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`FindPaymentsPathRequest`] instance, containing the
-    /// parameters for the paths request.
+    /// * `request` - A reference to an [`AllEffectsRequest`], whose cursor determines the first
+    ///   effect to be streamed.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`PathsResponse`], which includes
-    /// the list of the payment paths obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Stream` of `Result`s, each containing a [`Effect`], or an error if the
+    /// connection could not be established. The stream ends after an error.
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`FindPaymentsPathRequest`] and set any desired
-    /// filters or parameters.
-    pub async fn get_find_payment_paths(
-        &self,
-        request: &FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount>,
-    ) -> Result<PathsResponse, String> {
-        self.get::<PathsResponse>(request).await
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::effects::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let stream = horizon_client.stream_effects(&AllEffectsRequest::new());
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(effect) = stream.next().await {
+    ///     println!("Effect ID: {}", effect?.id());
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_effects<'a>(
+        &'a self,
+        request: &AllEffectsRequest,
+    ) -> impl Stream<Item = Result<Effect, Error>> + 'a {
+        self.stream_sse(request, StreamOptions::new())
     }
 
-    /// Retrieves a list of strict receive payment paths from the Horizon server.
+    /// Fetches effects associated with a specific ledger from the Stellar Horizon API.
     ///
-    /// This asynchronous method fetches a list of strict receive payment paths from
-    /// the Horizon server. It requires an [`ListStrictReceivePaymentPathsRequest`] to specify the optional query parameters.
+    /// This asynchronous method retrieves effects for a given ledger, facilitating detailed analysis
+    /// and insight into the various operations and changes that occurred within that ledger. It requires
+    /// a [`EffectsForLedgerRequest`], which includes options  for pagination, record limits,
+    /// and sorting order, among others.
+    ///
+    /// Adheres to <a href="https://developers.stellar.org/api/horizon/resources/retrieve-a-ledgers-effects">Retrieve a Ledgers's Effects</a> endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`ListStrictReceivePaymentPathsRequest`] instance, containing the
-    /// parameters for the paths request.
+    /// * `request` - A reference to an [`EffectsForLedgerRequest`] instance, specifying the ledger sequence
+    ///   and optional parameters such as cursor, limit, and order for the query.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing an [`PathsResponse`], which includes
-    /// the list of the strict receive payment paths obtained from the Horizon server.
-    /// If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing an [`AllEffectsResponse`], which encompasses a collection of effects
+    /// related to the requested ledger. If the operation fails, it returns an error message encapsulated
+    /// within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`ListStrictReceivePaymentPathsRequest`] and set any desired
-    /// filters or parameters.
-    pub async fn get_list_strict_receive_payment_paths(
-        &self,
-        request: &ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source>,
-    ) -> Result<PathsResponse, String> {
-        self.get::<PathsResponse>(request).await
-    }
-
-    /// Retrieves a list of strict send payment paths from the Horizon server.
+    /// To utilize this method, instantiate an `EffectsForLedgerRequest` with the desired parameters.
     ///
-    /// This asynchronous method fetches a list of strict send payment paths from
-    /// the Horizon server. It requires an [`ListStrictSendPaymentPathsRequest`] to specify the optional query parameters.
+    /// ```
+    /// # use stellar_rs::effects::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
     ///
-    /// # Arguments
-    /// * `request` - A reference to an [`ListStrictSendPaymentPathsRequest`] instance, containing the
-    /// parameters for the paths request.
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)?;
+    /// let mut request = EffectsForLedgerRequest::new()
+    ///     .set_sequence(125)?
+    ///     .set_limit(2)?;
     ///
-    /// # Returns
+    /// let response = horizon_client.get_effects_for_ledger(&request).await;
     ///
-    /// On successful execution, returns a `Result` containing an [`PathsResponse`], which includes
-    /// the list of the strict send payment paths obtained from the Horizon server.
-    /// If the request fails, it returns an error within `Result`.
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// # Usage
-    /// To use this method, create an instance of [`ListStrictSendPaymentPathsRequest`] and set any desired
-    /// filters or parameters.
-    pub async fn get_list_strict_send_payment_paths(
+    pub async fn get_effects_for_ledger(
         &self,
-        request: &ListStrictSendPaymentPathsRequest<SourceAsset, SourceAmount, Destination>,
-    ) -> Result<PathsResponse, String> {
-        self.get::<PathsResponse>(request).await
+        request: &EffectsForLedgerRequest,
+    ) -> Result<EffectsResponse, Error> {
+        self.get::<EffectsResponse>(request).await
     }
 
-    /// Retrieves a list of all payments from the Horizon server.
+    /// Retrieves a list of all fee stats from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all payments from the Horizon server.
-    /// It requires an [`AllPaymentsRequest`] to specify the optional query parameters.
+    /// This asynchronous method fetches a list of all fee stats from the Horizon server.
+    /// It requires a [`FeeStatsRequest`] to specify the optional query parameters.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`AllPaymentsRequest`] instance, containing the
-    /// parameters for the payments request.
+    /// * `request` - A reference to a [`FeeStatsRequest`] instance, containing the
+    /// parameters for the fee stats request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
-    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On successful execution, returns a `Result` containing a [`FeeStatsResponse`], which includes
+    /// the list of all fee stats obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`AllPaymentsRequest`] and set any desired
+    /// To use this method, create an instance of [`FeeStatsRequest`] and set any desired
     /// filters or parameters.
     ///
     /// ```
-    /// # use stellar_rs::payments::prelude::*;
-    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::fee_stats::fee_stats_request::FeeStatsRequest;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = AllPaymentsRequest::new()
-    ///  .set_limit(2).unwrap();
+    /// let request = FeeStatsRequest::new();
     ///
-    /// let response = horizon_client.get_all_payments(&request).await;
+    /// let response = horizon_client.get_fee_stats(&request).await;
     ///
-    /// // Access the payments
-    /// if let Ok(payments_response) = response {
-    ///    for payment in payments_response.embedded().records() {
-    ///       println!("Payment ID: {}", payment.id());
-    ///      // Further processing...
-    /// }
+    /// // Access the fee stats
+    /// if let Ok(fee_stats_response) = response {
+    ///  println!("Max Fee: {:?}", fee_stats_response.max_fee());
+    /// // Further processing...
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_all_payments(
+    pub async fn get_fee_stats(
         &self,
-        request: &AllPaymentsRequest,
-    ) -> Result<PaymentsResponse, String> {
-        self.get::<PaymentsResponse>(request).await
+        request: &FeeStatsRequest,
+    ) -> Result<FeeStatsResponse, Error> {
+        self.get::<FeeStatsResponse>(request).await
     }
 
-    /// Retrieves a list of all payments for an account from the Horizon server.
-    ///
-    /// This asynchronous method fetches a list of all payments for an account from the Horizon server.
-    /// It requires an [`PaymentsForAccountRequest`] to specify the optional query parameters.
+    /// Retrieves the root resource of the Horizon server.
     ///
-    /// # Arguments
-    /// * `request` - A reference to an [`PaymentsForAccountRequest`] instance, containing the
-    /// parameters for the payments for account request.
+    /// This asynchronous method fetches the root resource, which describes the Horizon server and
+    /// the network it serves, such as the versions of Horizon and Stellar Core, the passphrase of
+    /// the network, its current protocol version and the latest ingested ledger.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
-    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On successful execution, returns a `Result` containing a [`RootResponse`]. If the request
+    /// fails, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`PaymentsForAccountRequest`] and set any desired
-    /// filters or parameters.
+    /// The root resource can be used to gate features by the protocol version of the network, or,
+    /// through [`verify_network`](HorizonClient::verify_network), to check which network the
+    /// server serves before submitting transactions.
     ///
     /// ```
-    /// # use stellar_rs::payments::prelude::*;
-    /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    ///
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = PaymentsForAccountRequest::new()
-    ///  .set_limit(2).unwrap();
+    /// let root_response = horizon_client.get_root().await?;
     ///
-    /// let response = horizon_client.get_payments_for_account(&request).await;
+    /// println!("Network: {}", root_response.network_passphrase());
+    /// println!("Protocol: {}", root_response.current_protocol_version());
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
-    /// // Access the payments
-    /// if let Ok(payments_response) = response {
-    ///   for payment in payments_response.embedded().records() {
-    ///   println!("Payment ID: {}", payment.id());
-    /// // Further processing...
-    /// }
+    pub async fn get_root(&self) -> Result<RootResponse, Error> {
+        self.get::<RootResponse>(&RootRequest::new()).await
+    }
+
+    /// Retrieves the health of the Horizon server.
+    ///
+    /// This asynchronous method fetches the health endpoint, which reports whether Horizon is
+    /// connected to its database, and whether Stellar Core is up and in sync with the network.
+    /// Horizon responds with `503 Service Unavailable` if any of these checks fails; the response
+    /// is returned in that case as well, so that the failed check can be inspected. The request
+    /// bypasses the cache and the retry policy, so that it reflects the current state of the
+    /// server.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`HealthResponse`]. If the server
+    /// could not be reached, or responded with anything else, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// The health can be used as a readiness check, before trusting the results of queries. Use
+    /// [`RootResponse::ingestion_lag`] to find out how far ingestion is behind.
+    ///
+    /// ```
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let health = horizon_client.get_health().await?;
+    ///
+    /// if !health.is_healthy() {
+    ///     println!("Stellar Core in sync: {}", health.core_synced());
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_payments_for_account(
-        &self,
-        request: &PaymentsForAccountRequest,
-    ) -> Result<PaymentsResponse, String> {
-        self.get::<PaymentsResponse>(request).await
+    pub async fn get_health(&self) -> Result<HealthResponse, Error> {
+        let url = HealthRequest::new().build_url(&self.base_url);
+        let body = self.fetch(&url).await?;
+
+        // An unhealthy server describes the failed checks in the body of its error response.
+        if body.status == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            if let Ok(health) = HealthResponse::from_json(body.body.clone()) {
+                return Ok(health);
+            }
+        }
+        body.parse(self.error_body_snippet_length)
     }
 
-    /// Retrieves a list of all payments for a specific ledger from the Horizon server.
+    /// Verifies that the Horizon server serves the network set on the client.
     ///
-    /// This asynchronous method fetches a list of all payments for a specific ledger from the Horizon server.
-    /// It requires an [`PaymentsForLedgerRequest`] to specify the ledger sequence number and optional query parameters.
+    /// Transactions are signed for a specific network, so submitting a transaction to a server
+    /// of another network fails. This method retrieves the root resource of the server and
+    /// compares its network passphrase with the passphrase of the network set with
+    /// [`set_network`](HorizonClient::set_network) or
+    /// [`set_network_passphrase`](HorizonClient::set_network_passphrase). When no network is set,
+    /// the root resource is returned without comparing.
+    ///
+    /// # Returns
+    ///
+    /// The [`RootResponse`] of the server, or an [`Error::Other`] if the server serves another
+    /// network.
+    ///
+    /// # Example
+    /// ```
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::network::Network;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?
+    ///     .set_network_passphrase(Network::Testnet.passphrase());
+    ///
+    /// horizon_client.verify_network().await?;
+    /// // Submit transactions...
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn verify_network(&self) -> Result<RootResponse, Error> {
+        let root_response = self.get_root().await?;
+
+        match self.network_passphrase() {
+            Some(expected) if expected != root_response.network_passphrase() => {
+                Err(Error::Other(format!(
+                    "Horizon server at {} serves network \"{}\", expected \"{}\"",
+                    self.base_url,
+                    root_response.network_passphrase(),
+                    expected
+                )))
+            }
+            _ => Ok(root_response),
+        }
+    }
+
+    /// Retrieves detailed information for a specific offer from the Horizon server.
+    ///
+    /// This asynchronous method fetches details of a single offer from the Horizon server.
+    /// It requires a [`SingleOfferRequest`] parameterized with `OfferId`, which includes the ID
+    /// of the offer to be retrieved.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/network/horizon/resources/get-offer-by-offer-id">Retrieve An Offer endpoint</a>
+    /// endpoint.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`PaymentsForLedgerRequest`] instance, containing the
-    /// parameters for the payments for ledger request.
+    ///
+    /// * `request` - A reference to a [`SingleOfferRequest<OfferId>`] instance, containing the
+    ///   id of the offer for which details are to be fetched.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
-    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// Returns a `Result` containing an [`Offer`], which includes detailed
+    /// information about the requested offer. If the request fails, it returns an error
+    /// encapsulated within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`PaymentsForLedgerRequest`] and set the ledger sequence number and any desired
-    /// filters or parameters.
+    /// To use this method, create an instance of [`SingleOfferRequest`] and set the
+    /// id of the offer to be queried.
     ///
     /// ```
-    /// # use stellar_rs::payments::prelude::*;
+    /// # use stellar_rs::offers::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    ///
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = PaymentsForLedgerRequest::new()
-    /// .set_ledger_sequence("48483");
-    ///
-    /// let response = horizon_client.get_payments_for_ledger(&request).await;
+    /// let request = SingleOfferRequest::new()
+    ///     .set_offer_id("1") // example offer ID
+    ///     .unwrap();
     ///
-    /// // Access the payments
-    /// if let Ok(payments_response) = response {
-    /// for payment in payments_response.embedded().records() {
-    /// println!("Payment ID: {}", payment.id());
+    /// let response = horizon_client.get_single_offer(&request).await;
     ///
-    /// // Further processing...
-    /// }
+    /// if let Ok(offer) = response {
+    ///     println!("Offer ID: {}", offer.id());
+    ///     // Additional processing...
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_payments_for_ledger(
+    pub async fn get_single_offer(
         &self,
-        request: &PaymentsForLedgerRequest,
-    ) -> Result<PaymentsResponse, String> {
-        self.get::<PaymentsResponse>(request).await
+        request: &SingleOfferRequest<OfferId>,
+    ) -> Result<OfferResponse, Error> {
+        self.get::<OfferResponse>(request).await
     }
 
-    /// Retrieves a list of all payments for a specific transaction from the Horizon server.
+    /// Retrieves a list of all offers from the Horizon server.
     ///
-    /// This asynchronous method fetches a list of all payments for a specific transaction from the Horizon server.
-    /// It requires an [`PaymentsForTransactionRequest`] to specify the transaction hash and optional query parameters.
+    /// This asynchronous method fetches a list of all offers from the Horizon server.
+    /// It requires an [`AllOffersRequest`] to specify the optional query parameters.
     ///
     /// # Arguments
-    /// * `request` - A reference to an [`PaymentsForTransactionRequest`] instance, containing the
-    /// parameters for the payments for transaction request.
+    /// * `request` - A reference to an [`AllOffersRequest`] instance, containing the
+    /// parameters for the offers request.
     ///
     /// # Returns
     ///
-    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
-    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    /// On successful execution, returns a `Result` containing an [`OfferResponse`], which includes
+    /// the list of all offers obtained from the Horizon server. If the request fails, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`PaymentsForTransactionRequest`] and set the transaction hash and any desired
+    /// To use this method, create an instance of [`AllOffersRequest`] and set any desired
     /// filters or parameters.
     ///
     /// ```
-    /// # use stellar_rs::payments::prelude::*;
+    /// # use stellar_rs::offers::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
-    ///
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let request = PaymentsForTransactionRequest::new()
-    /// .set_transaction_hash("be0d59c8706e8fd525d2ab10910a55ec57323663858c65b330a3f93afb13ab0f");
-    ///
-    /// let response = horizon_client.get_payments_for_transaction(&request).await;
+    /// let request = AllOffersRequest::new()
+    ///   .set_limit(2).unwrap();
     ///
-    /// // Access the payments
-    /// if let Ok(payments_response) = response {
-    /// for payment in payments_response.embedded().records() {
-    /// println!("Payment ID: {}", payment.id());
+    /// let response = horizon_client.get_all_offers(&request).await;
     ///
-    /// // Further processing...
-    /// }
+    /// // Access the offers
+    /// if let Ok(offers_response) = response {
+    ///     for offer in offers_response.embedded().records() {
+    ///         println!("Offer ID: {}", offer.id());
+    ///         // Further processing...
+    ///     }
     /// }
-    /// # Ok({})
+    /// # Ok(())
     /// # }
     /// ```
     ///
-    pub async fn get_payments_for_transaction(
+    pub async fn get_all_offers(
         &self,
-        request: &PaymentsForTransactionRequest,
-    ) -> Result<PaymentsResponse, String> {
-        self.get::<PaymentsResponse>(request).await
+        request: &AllOffersRequest,
+    ) -> Result<AllOffersResponse, Error> {
+        self.get::<AllOffersResponse>(request).await
     }
 
-    /// Submits a transaction to the Horizon server.
+    /// Retrieves a list of all offers from the Horizon server.
     ///
-    /// This asynchronous method submits a transaction to the Stellar network. It only takes a
-    /// single, required parameter: the signed transaction. Refer to the Transactions page for
-    /// details on how to craft a proper one. If you submit a transaction that has already been
-    /// included in a ledger, this endpoint will return the same response as would have been
-    /// returned for the original transaction submission. This allows for safe resubmission of
-    /// transactions in error scenarios, as highlighted in the error handling guide.
+    /// This asynchronous method fetches a list of all offers an account currently has open from the Horizon server.
+    /// It requires an [`OffersForAccountRequest`] to specify the optional query parameters.
     ///
     /// # Arguments
-    /// * `request` - A reference to a [`PostTransactionRequest<TransactionEnvelope>`] instance, containing the
-    /// signed transaction to be submitted.
+    /// * `request` - A reference to an [`OffersForAccountRequest`] instance, containing the
+    /// parameters for the request.
     ///
     /// # Returns
-    /// On successful execution, returns a `Result` containing a [`TransactionResponse`], which includes
-    /// the details of the submitted transaction. If the request fails, it returns an error within `Result`.
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllOffersResponse`], which includes
+    /// the list of all offers currently open for an account, obtained from the Horizon server.
+    /// If the request fails, it returns an error within `Result`.
     ///
     /// # Usage
-    /// To use this method, create an instance of [`PostTransactionRequest`] and set the signed
-    /// transaction to be submitted.
+    /// To use this method, create an instance of [`OffersForAccountRequest`] and set any desired
+    /// filters or parameters.
+    ///
     /// ```
-    /// # use stellar_rs::transactions::prelude::*;
+    pub async fn get_offers_for_account(
+        &self,
+        request: &OffersForAccountRequest<OfferAccountId>,
+    ) -> Result<AllOffersResponse, Error> {
+        self.get::<AllOffersResponse>(request).await
+    }
+
+    /// Retrieves a list of all operations from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all operations from the Horizon server.
+    /// It requires an [`AllOperationsRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllOperationsRequest`] instance, containing the
+    /// parameters for the operations request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`OperationResponse`], which includes
+    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`AllOperationsRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
     /// # use stellar_rs::models::Request;
     /// # use stellar_rs::horizon_client::HorizonClient;
     /// #
@@ -2292,72 +3612,2712 @@ impl HorizonClient {
     /// # let base_url = "https://horizon-testnet.stellar.org";
     /// # let horizon_client = HorizonClient::new(base_url)
     /// #    .expect("Failed to create Horizon Client");
-    /// let signed_transaction_xdr = "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAABEwAAAAAAAAAAQAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAsAAAAAAAAAAAAAAAAQfdFrLDgzSIIugR73qs8U0ZiKbwBUclTTPh5thlbgnAFjRXhdigAAAAAAAAAAAAAAAAAA3b5KF6uk1w1fSKYLrzR8gF2lB+AHAi6oU6CaWhunAskAAAAXSHboAAAAAAAAAAAAAAAAAHfmNeMLin2aTUfxa530ZRn4zwRu7ROAQfUJeJco8HSCAAHGv1JjQAAAAAAAAAAAAAAAAAAAlRt2go9sp7E1a5ZWvr7vin4UPrFQThpQax1lOFm33AAAABdIdugAAAAAAAAAAAAAAAAAmv+knlR6JR2VqWeU0k/4FgvZ/tSV5DEY4gu0iOTKgpUAAAAXSHboAAAAAAAAAAAAAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAF0h26AAAAAABAAAAAACVG3aCj2ynsTVrlla+vu+KfhQ+sVBOGlBrHWU4WbfcAAAABgAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bf/////////8AAAABAAAAAJr/pJ5UeiUdlalnlNJP+BYL2f7UleQxGOILtIjkyoKVAAAABgAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bf/////////8AAAABAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAAQAAAAAAlRt2go9sp7E1a5ZWvr7vin4UPrFQThpQax1lOFm33AAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAJGE5yoAAAAAABAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAAQAAAACa/6SeVHolHZWpZ5TST/gWC9n+1JXkMRjiC7SI5MqClQAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAJGE5yoAAAAAAAAAAAAAAAAABKBB+2UBMP/abwcm/M1TXO+/JQWhPwkalgqizKmXyRIQx7qh6aAFYAAAAAAAAAAARW/AX3AAAAQDVB8fT2ZXF0PZqtZX9brK0kz+P4G8VKs1DkDklP6ULsvXRexXFBdH4xG8xRAsR1HJeEBH278hiBNNvUwNw6zgzGYc0bAAAAQLgZUU/oYGL7frWDQhJHhCQu9JmfqN03PrJq4/cJrN1OSUWXnmLc94sv8m2L+cxl2p0skr2Jxy+vt1Lcxkv7wAI4WbfcAAAAQHvZEVqlygIProf3jVTZohDWm2WUNrFAFXf1LctTqDCQBHph14Eo+APwrTURLLYTIvNoXeGzBKbL03SsOARWcQLkyoKVAAAAQHAvKv2/Ro4+cNh6bKQO/G9NNiUozYysGwG1GvJQkFjwy/OTsL6WBfuI0Oye84lVBVrQVk2EY1ERFhgdMpuFSg4=";
-    /// let request = PostTransactionRequest::new()
-    ///    .set_transaction_envelope_xdr(signed_transaction_xdr).unwrap();
-    /// let response = horizon_client.post_transaction(&request).await;
-    /// # Ok({})
-    /// # }
-    /// ```
+    /// let request = AllOperationsRequest::new()
+    ///   .set_limit(2).unwrap();
     ///
-    pub async fn post_transaction(
-        &self,
-        request: &PostTransactionRequest<TransactionEnvelope>,
-    ) -> Result<TransactionResponse, String> {
-        self.post::<TransactionResponse>(request).await
-    }
+    /// let response = horizon_client.get_all_operations(&request).await;
+    ///
+    /// // Access the operations
+    /// if let Ok(operations_response) = response {
+    ///     for operation in operations_response.embedded().records() {
+    ///         println!("Operation ID: {}", operation.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_all_operations(
+        &self,
+        request: &AllOperationsRequest,
+    ) -> Result<OperationResponse, Error> {
+        self.get::<OperationResponse>(request).await
+    }
+
+    /// Streams all operations, yielding only those of the selected operation types.
+    ///
+    /// Horizon does not support filtering operations by type on the server side. This method
+    /// emulates such a filter: it pages through the operations matching the given
+    /// [`AllOperationsRequest`] by following the `next` links of the responses, and yields only the
+    /// operations whose type is included in the given [`OperationTypeFilter`].
+    ///
+    /// **Note:** the filtering takes place on the client side. Every operation, including the ones
+    /// that are filtered out, is still transferred from the Horizon server, and each page counts
+    /// towards the rate limit. When only a small fraction of the operations matches the filter,
+    /// consider narrowing the request (for example by using a more specific endpoint, such as
+    /// [`HorizonClient::get_operations_for_account`]) or increasing its page size with `set_limit`.
+    ///
+    /// The stream ends once a page without records is returned, or after the first error, which
+    /// is yielded as the last item.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllOperationsRequest`] that determines the first page
+    ///   to be fetched, including the cursor, page size, order and whether to include failed operations.
+    /// * `filter` - The [`OperationTypeFilter`] selecting the operation types to yield.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing an [`Operation`] matching the filter, or
+    /// an error if a page could not be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = AllOperationsRequest::new().set_limit(200)?;
+    /// let filter = OperationTypeFilter::PAYMENTS | OperationTypeFilter::CHANGE_TRUST;
+    ///
+    /// let stream = horizon_client.stream_operations_filtered(&request, filter);
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(operation) = stream.next().await {
+    ///     println!("Operation ID: {}", operation?.id());
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_operations_filtered<'a>(
+        &'a self,
+        request: &AllOperationsRequest,
+        filter: OperationTypeFilter,
+    ) -> impl Stream<Item = Result<Operation, Error>> + 'a {
+        self.stream_operations_filtered_with_options(request, filter, StreamOptions::new())
+    }
+
+    /// Streams operations of the selected types, with control over shutdown and checkpointing.
+    ///
+    /// This method behaves like [`HorizonClient::stream_operations_filtered`], but additionally
+    /// accepts [`StreamOptions`]. These allow the stream to be shut down gracefully using a
+    /// [`CancellationToken`](crate::streaming::CancellationToken), to report the paging token of the
+    /// last processed operation to a checkpoint callback, and to resume after a previously reported
+    /// paging token. Operations that are filtered out count as processed as well, so resuming from
+    /// a checkpoint never fetches them again.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllOperationsRequest`] that determines the first page
+    ///   to be fetched. Its cursor is overridden if the options contain a paging token to resume from.
+    /// * `filter` - The [`OperationTypeFilter`] selecting the operation types to yield.
+    /// * `options` - The [`StreamOptions`] controlling the lifecycle of the stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing an [`Operation`] matching the filter, or
+    /// an error if a page could not be retrieved. The stream ends when it is shut down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::streaming::{CancellationToken, StreamOptions};
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let shutdown = CancellationToken::new();
+    /// let options = StreamOptions::new()
+    ///     .set_shutdown_token(shutdown.clone())
+    ///     .set_checkpoint_callback(|paging_token| println!("Checkpoint: {}", paging_token));
+    ///
+    /// let request = AllOperationsRequest::new().set_limit(200)?;
+    /// let stream = horizon_client.stream_operations_filtered_with_options(
+    ///     &request,
+    ///     OperationTypeFilter::PAYMENTS,
+    ///     options,
+    /// );
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(operation) = stream.next().await {
+    ///     println!("Operation ID: {}", operation?.id());
+    ///     shutdown.cancel();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_operations_filtered_with_options<'a>(
+        &'a self,
+        request: &AllOperationsRequest,
+        filter: OperationTypeFilter,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<Operation, Error>> + 'a {
+        let first_url = options.first_url(request.build_url(&self.base_url));
+
+        stream::unfold(
+            (StreamState::new(first_url), options),
+            move |(mut state, options): (StreamState<Operation>, StreamOptions)| async move {
+                state.confirm_yielded();
+
+                loop {
+                    if options.is_shut_down() {
+                        state.checkpoint(&options);
+                        return None;
+                    }
+
+                    // Yield the buffered operations that match the filter.
+                    while let Some(operation) = state.buffered.pop_front() {
+                        if filter.matches(&operation) {
+                            state.set_yielded(&operation.paging_token);
+                            return Some((Ok(operation), (state, options)));
+                        }
+                        state.set_skipped(&operation.paging_token);
+                    }
+
+                    // The current page has been processed completely.
+                    state.checkpoint(&options);
+
+                    // Fetch the next page, if any.
+                    let url = state.next_url.take()?;
+                    let page = futures::select_biased! {
+                        _ = options.shut_down().fuse() => return None,
+                        page = self.get_from_url::<OperationResponse>(&url).fuse() => page,
+                    };
+                    match page {
+                        Ok(page) => {
+                            if page.embedded.records.is_empty() {
+                                return None;
+                            }
+                            state.next_url = page.links.next.and_then(|link| link.href);
+                            state.buffered.extend(page.embedded.records);
+                        }
+                        Err(e) => {
+                            state.finish();
+                            return Some((Err(e), (state, options)));
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Retrieves detailed information for a specific operation from the Horizon server.
+    ///
+    /// This asynchronous method fetches details of a single operation from the Horizon server.
+    /// It requires a [`SingleOperationRequest`] parameterized with `OperationId`, which includes the unique identifier
+    /// of the operation to be retrieved.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`SingleOperationRequest`] instance containing the unique ID of the operation to be fetched.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an [`Operation`], which includes detailed information about the requested operation.
+    /// If the request fails, it returns an error encapsulated within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`SingleOperationRequest`] and set the unique ID of the operation to be queried.
+    ///
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = SingleOperationRequest::new()
+    ///    .set_operation_id("459561504769")?;
+    ///
+    /// let response = horizon_client.get_single_operation(&request).await;
+    ///
+    /// if let Ok(operation) = response {
+    ///    println!("Operation ID: {}", operation.id());
+    ///   // Additional processing...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_single_operation(
+        &self,
+        request: &SingleOperationRequest<OperationId>,
+    ) -> Result<Operation, Error> {
+        self.get::<Operation>(request).await
+    }
+
+    /// Retrieves the trades that were produced by an operation, such as a path payment.
+    ///
+    /// The trades are retrieved by following the `trades` link of the operation. Horizon only
+    /// returns this link for operations that can produce trades, so an error is returned for other
+    /// operations. Together with the `path`, the `source_max` and the `destination_min` of a path
+    /// payment, as found in its
+    /// [`PathPaymentDetails`](crate::operations::prelude::PathPaymentDetails), the trades show how
+    /// the payment was routed through the order books and liquidity pools.
+    ///
+    /// # Arguments
+    /// * `operation` - The operation for which to retrieve the trades.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the first page of trades of the operation, as an
+    /// [`AllTradesResponse`].
+    ///
+    pub async fn get_trades_for_operation(
+        &self,
+        operation: &Operation,
+    ) -> Result<AllTradesResponse, Error> {
+        let href = operation
+            .links()
+            .trades()
+            .as_ref()
+            .and_then(|link| link.href().as_deref())
+            .ok_or_else(|| {
+                Error::Other(format!("operation {} has no trades link", operation.id()))
+            })?;
+
+        self.follow(href, &PageParams::new()).await
+    }
+
+    /// Retrieves a list of all operations for an account from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all operations for an account from the Horizon server.
+    /// It requires an [`OperationsForAccountRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`OperationsForAccountRequest`] instance, containing the
+    /// parameters for the operations for account request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
+    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`OperationsForAccountRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = OperationsForAccountRequest::new()
+    ///   .set_limit(2).unwrap();
+    ///
+    /// let response = horizon_client.get_operations_for_account(&request).await;
+    ///
+    /// // Access the payments
+    /// if let Ok(operations_for_account_response) = response {
+    ///   for operation in operations_for_account_response.embedded().records() {
+    ///    println!("operation ID: {}", operation.id());
+    ///  // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_operations_for_account(
+        &self,
+        request: &OperationsForAccountRequest,
+    ) -> Result<OperationResponse, Error> {
+        self.get::<OperationResponse>(request).await
+    }
+
+    /// Retrieves a list of all operations for a ledger from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all operations for a ledger from the Horizon server.
+    /// It requires an [`OperationsForLedgerRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`OperationsForLedgerRequest`] instance, containing the
+    /// parameters for the operations for ledger request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
+    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`OperationsForLedgerRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = OperationsForLedgerRequest::new()
+    ///   .set_limit(2).unwrap();
+    ///
+    /// let response = horizon_client.get_operations_for_ledger(&request).await;
+    ///
+    /// // Access the payments
+    /// if let Ok(operations_for_ledger_response) = response {
+    ///   for operation in operations_for_ledger_response.embedded().records() {
+    ///    println!("operation ID: {}", operation.id());
+    ///  // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_operations_for_ledger(
+        &self,
+        request: &OperationsForLedgerRequest,
+    ) -> Result<OperationResponse, Error> {
+        self.get::<OperationResponse>(request).await
+    }
+
+    /// Retrieves a list of all operations for a specific liquidity pool from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all operations for a specific liquidity pool from the Horizon server.
+    /// It requires an [`OperationsForLiquidityPoolRequest`] to specify the liquidity pool ID and optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`OperationsForLiquidityPoolRequest`] instance, containing the liquidity pool ID
+    /// and optional query parameters for the operations for liquidity pool request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
+    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`OperationsForLiquidityPoolRequest`] and set the liquidity pool ID and any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = OperationsForLiquidityPoolRequest::new()
+    ///  .set_liquidity_pool_id("000000006520216af66d20d63a58534d6cbdf28ba9f2a9c1e03f8d9a756bb7d988b29bca");
+    ///
+    /// let response = horizon_client.get_operations_for_liquidity_pool(&request).await;
+    ///
+    /// // Access the operations
+    /// if let Ok(operations_for_liquidity_pool_response) = response {
+    ///  for operation in operations_for_liquidity_pool_response.embedded().records() {
+    ///
+    ///   println!("Operation ID: {}", operation.id());
+    /// // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_operations_for_liquidity_pool(
+        &self,
+        request: &OperationsForLiquidityPoolRequest,
+    ) -> Result<OperationResponse, Error> {
+        self.get::<OperationResponse>(request).await
+    }
+
+    /// Retrieves a list of the operations that reference a specific claimable balance from the
+    /// Horizon server.
+    ///
+    /// This asynchronous method fetches the operations that created, claimed or clawed back a
+    /// claimable balance, which allows auditing the lifecycle of the balance. It requires an
+    /// [`OperationsForClaimableBalanceRequest`] with the ID of the claimable balance.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`OperationsForClaimableBalanceRequest`] instance, containing
+    ///   the claimable balance ID and optional query parameters.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`OperationResponse`], which
+    /// includes the operations obtained from the Horizon server. If the request fails, it returns
+    /// an error within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = OperationsForClaimableBalanceRequest::new()
+    ///     .set_claimable_balance_id("00000000e7a2e5a4c47cd0d4fe1b3e2d9c1f5b8b1f2c1fa1a4ddf2f8b7a8e5ab4d2e1f30");
+    ///
+    /// let response = horizon_client.get_operations_for_claimable_balance(&request).await?;
+    ///
+    /// for operation in response.embedded().records() {
+    ///     println!("{}: {}", operation.id(), operation.type_field());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_operations_for_claimable_balance(
+        &self,
+        request: &OperationsForClaimableBalanceRequest<OperationsClaimableBalanceId>,
+    ) -> Result<OperationResponse, Error> {
+        self.get::<OperationResponse>(request).await
+    }
+
+    /// Retrieves a list of all operations for a specific transaction from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all operations for a specific transaction from the Horizon server.
+    /// It requires an [`OperationsForTransactionRequest`] to specify the transaction hash and optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`OperationsForTransactionRequest`] instance, containing the transaction hash
+    /// and optional query parameters for the operations for transaction request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`OperationResponse`], which includes
+    /// the list of all operations obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`OperationsForTransactionRequest`] and set the transaction hash and any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::operations::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = OperationsForTransactionRequest::new()
+    ///  .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020")?;
+    ///
+    /// let response = horizon_client.get_operations_for_transaction(&request).await;
+    ///
+    /// // Access the operations
+    /// if let Ok(operations_for_transaction_response) = response {
+    ///     for operation in operations_for_transaction_response.embedded().records() {
+    ///         println!("Operation ID: {}", operation.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_operations_for_transaction(
+        &self,
+        request: &OperationsForTransactionRequest,
+    ) -> Result<OperationResponse, Error> {
+        self.get::<OperationResponse>(request).await
+    }
+
+    /// Retrieves a list of order book details from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of order book details from the Horizon server.
+    /// It requires a [`DetailsRequest`] to specify the parameters for the order book details request.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`DetailsRequest`] instance, containing the parameters for the order book details request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`DetailsResponse`], which includes the list of order book details obtained from the Horizon server.
+    /// If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`DetailsRequest`] and set any desired filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::order_book::prelude::*;
+    /// # use stellar_rs::models::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// # let details_request = DetailsRequest::new()
+    /// # .set_buying_asset(AssetType::Native)
+    /// # .unwrap()
+    /// # .set_selling_asset(AssetType::Alphanumeric4(AssetData {
+    /// #     asset_code: "USDC".to_string(),
+    /// #     asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+    /// #         .to_string(),
+    /// # }))
+    /// # .unwrap();
+    ///
+    /// let response = horizon_client.get_order_book_details(&details_request).await;
+    ///
+    /// assert!(response.is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_order_book_details(
+        &self,
+        request: &DetailsRequest<SellingAsset, BuyingAsset>,
+    ) -> Result<DetailsResponse, Error> {
+        self.get::<DetailsResponse>(request).await
+    }
+
+    /// Polls the order book of an asset pair, yielding only the snapshots that changed.
+    ///
+    /// This method requests the order book every `interval`, and compares its bids and asks with
+    /// those of the previous snapshot. Only snapshots that differ are yielded, so consumers such as
+    /// trading bots and price tickers do not reprocess an unchanged order book. The first snapshot
+    /// is always yielded. This is a pragmatic alternative to streaming the order book.
+    ///
+    /// The polls are scheduled with backpressure in mind:
+    /// * The order book is only polled when the consumer asks for the next snapshot. If the
+    ///   consumer took longer than the interval, the next poll is sent right away, without
+    ///   catching up on the polls that were missed.
+    /// * The interval varies by up to 10% in either direction, so that many pollers do not send
+    ///   their requests at the same moment.
+    /// * After a failed poll, the interval doubles, up to 8 times its length, until a poll
+    ///   succeeds again. Once the rate limit of the Horizon server is exhausted, polling waits
+    ///   until the rate limit window resets.
+    ///
+    /// The stream does not end by itself. Errors are yielded as items, after which polling
+    /// continues. To stop polling, drop the stream.
+    ///
+    /// # Arguments
+    /// * `asset_pair` - The [`AssetPair`] of the order book, of which the base asset is the
+    ///   selling asset and the counter asset is the buying asset.
+    /// * `interval` - The time between the start of two polls.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing a [`DetailsResponse`] that differs from
+    /// the previous one, or an error if the order book could not be retrieved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::models::prelude::*;
+    /// # use futures::StreamExt;
+    /// # use std::time::Duration;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let usdc = AssetType::Alphanumeric4(AssetData {
+    ///     asset_code: "USDC".to_string(),
+    ///     asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+    /// });
+    /// let snapshots = horizon_client
+    ///     .poll_order_book(AssetPair::new(AssetType::Native, usdc), Duration::from_secs(2));
+    /// futures::pin_mut!(snapshots);
+    ///
+    /// while let Some(snapshot) = snapshots.next().await {
+    ///     let snapshot = snapshot?;
+    ///     println!("{} bids, {} asks", snapshot.bids().len(), snapshot.asks().len());
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn poll_order_book(
+        &self,
+        asset_pair: AssetPair,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<DetailsResponse, Error>> + '_ {
+        let state = DetailsRequest::new()
+            .set_asset_pair(asset_pair)
+            .map(|request| OrderBookPollerState::new(request.build_url(&self.base_url)));
+
+        stream::unfold(Some(state), move |state| async move {
+            let mut state = match state? {
+                Ok(state) => state,
+                // An invalid asset pair ends the stream after its error.
+                Err(e) => return Some((Err(e.into()), None)),
+            };
+
+            loop {
+                let delay = state.next_delay(interval, self.last_rate_limit(), Instant::now());
+                if !delay.is_zero() {
+                    crate::runtime::sleep(delay).await;
+                }
+                state.last_poll = Some(Instant::now());
+
+                match self.get_from_url::<DetailsResponse>(&state.url).await {
+                    Ok(snapshot) => {
+                        state.consecutive_failures = 0;
+                        if state.is_changed(&snapshot) {
+                            return Some((Ok(snapshot), Some(Ok(state))));
+                        }
+                    }
+                    Err(e) => {
+                        state.consecutive_failures += 1;
+                        return Some((Err(e), Some(Ok(state))));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Streams the order book of an asset pair in near real time, using Server-Sent Events.
+    ///
+    /// Unlike [`HorizonClient::poll_order_book`], which requests the order book at a fixed
+    /// interval, this stream keeps a connection to the Horizon server open, over which the server
+    /// pushes a new snapshot of the order book whenever it changes. Each snapshot contains the
+    /// complete order book; the differences between consecutive snapshots can be computed with
+    /// [`DetailsResponse::diff`]. When Horizon closes the connection, the stream reconnects after
+    /// the delay the server requested, as described for [`HorizonClient::stream_sse`].
+    ///
+    /// # Arguments
+    /// * `selling_asset` - The selling asset of the order book, which is its base asset.
+    /// * `buying_asset` - The buying asset of the order book, which is its counter asset.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing a [`DetailsResponse`], or an error if the
+    /// connection could not be established or a snapshot could not be deserialized. The stream
+    /// ends after an error. To stop streaming, drop the stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::models::prelude::*;
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let usdc = AssetType::issued(
+    ///     "USDC",
+    ///     "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
+    /// );
+    /// let snapshots = horizon_client.stream_order_book(AssetType::Native, usdc);
+    /// futures::pin_mut!(snapshots);
+    ///
+    /// let mut previous = None;
+    /// while let Some(snapshot) = snapshots.next().await {
+    ///     let snapshot = snapshot?;
+    ///     if let Some(previous) = &previous {
+    ///         let diff = snapshot.diff(previous);
+    ///         println!("{} bids and {} asks changed", diff.bids().len(), diff.asks().len());
+    ///     }
+    ///     previous = Some(snapshot);
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_order_book<'a>(
+        &'a self,
+        selling_asset: impl Into<AssetType>,
+        buying_asset: impl Into<AssetType>,
+    ) -> impl Stream<Item = Result<DetailsResponse, Error>> + 'a {
+        let request = DetailsRequest::new()
+            .set_selling_asset(selling_asset)
+            .and_then(|request| request.set_buying_asset(buying_asset));
+
+        match request {
+            Ok(request) => self
+                .stream_sse::<DetailsResponse>(&request, StreamOptions::new())
+                .left_stream(),
+            // An invalid asset pair ends the stream after its error.
+            Err(e) => stream::once(async move { Err(e.into()) }).right_stream(),
+        }
+    }
+
+    /// Retrieves a list of trade aggregations from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of trade aggregations from the Horizon server.
+    /// It requires a [`TradeAggregationsRequest`] to specify the parameters for the trade aggregations request.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`TradeAggregationsRequest`] instance, containing the parameters for the order book details request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`AllTradeAggregationsResponse`], which includes the list of order book details obtained from the Horizon server.
+    /// If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TradeAggregationsRequest`] and set any desired filters or parameters.
+    ///
+    /// ```rust
+    /// use stellar_rs::horizon_client::HorizonClient;
+    /// use stellar_rs::trade_aggregations::prelude::*;
+    /// use stellar_rs::models::prelude::*;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    ///
+    /// // Example: Fetching trade aggregations
+    /// let request = TradeAggregationsRequest::new()
+    ///     .set_base_asset(AssetType::Native).unwrap()
+    ///     .set_counter_asset(AssetType::Alphanumeric4(AssetData {
+    ///         asset_code: "USDC".to_string(),
+    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+    ///     })).unwrap()
+    ///     .set_resolution(Resolution(ResolutionData::Duration604800000)).unwrap();
+    /// let response = horizon_client.get_trade_aggregations(&request).await?;
+    ///
+    /// // Process the response...
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_trade_aggregations(
+        &self,
+        request: &TradeAggregationsRequest<BaseAsset, CounterAsset, Resolution>,
+    ) -> Result<AllTradeAggregationsResponse, Error> {
+        self.get::<AllTradeAggregationsResponse>(request).await
+    }
+
+    /// Retrieves the trade aggregations of a time range of any length.
+    ///
+    /// Horizon returns at most 200 segments per response, so a year of daily segments, or a day
+    /// of minute segments, takes several requests. This asynchronous method splits the time range
+    /// into requests of at most 200 segments each, sends them with up to `concurrency` requests in
+    /// flight at a time, and concatenates the segments in ascending order. Segments are aligned to
+    /// the resolution, so none of them is split between two requests; segments that are returned
+    /// twice anyway are deduplicated by their timestamp.
+    ///
+    /// The start time, end time, limit and order of the given request are ignored.
+    ///
+    /// # Arguments
+    /// * `request` - The request, containing the base asset, counter asset and resolution.
+    /// * `start_time` - The lower time boundary, as milliseconds since epoch.
+    /// * `end_time` - The upper time boundary, as milliseconds since epoch.
+    /// * `concurrency` - The maximum number of requests in flight at a time. Use `1` to send the
+    ///   requests sequentially.
+    ///
+    /// # Returns
+    /// The segments of the time range in ascending order, or the first error that occurred.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::trade_aggregations::prelude::*;
+    /// # use stellar_rs::models::prelude::*;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    ///
+    /// let request = TradeAggregationsRequest::new()
+    ///     .set_base_asset(AssetType::Native)?
+    ///     .set_counter_asset(AssetType::Alphanumeric4(AssetData {
+    ///         asset_code: "USDC".to_string(),
+    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+    ///     }))?
+    ///     .set_resolution(Resolution(ResolutionData::Duration3600000))?;
+    ///
+    /// // A month of hourly segments, in four requests with two in flight at a time.
+    /// let start_time = 1_717_200_000_000;
+    /// let end_time = start_time + 30 * 24 * 3_600_000;
+    /// let segments = horizon_client
+    ///     .get_trade_aggregations_range(&request, start_time, end_time, 2)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_trade_aggregations_range(
+        &self,
+        request: &TradeAggregationsRequest<BaseAsset, CounterAsset, Resolution>,
+        start_time: i64,
+        end_time: i64,
+        concurrency: usize,
+    ) -> Result<Vec<TradeAggregationResponse>, Error> {
+        let requests = request.split_range(start_time, end_time)?;
+
+        let responses: Vec<AllTradeAggregationsResponse> = stream::iter(requests)
+            .map(|request| async move { self.get_trade_aggregations(&request).await })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        let mut segments: Vec<TradeAggregationResponse> = responses
+            .into_iter()
+            .flat_map(|response| response.embedded.records)
+            .collect();
+        segments.dedup_by(|segment, previous| segment.timestamp() == previous.timestamp());
+
+        Ok(segments)
+    }
+
+    /// Retrieves a list of all trades from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all trades from the Horizon server.
+    /// It requires an [`AllTradesRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllTradesRequest`] instance, containing the
+    ///     parameters for the trades request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
+    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`AllTradesRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = AllTradesRequest::new();
+    ///
+    /// let response = horizon_client.get_all_trades(&request).await;
+    ///
+    /// // Access the trades
+    /// if let Ok(trades_response) = response {
+    ///     for trade in trades_response.embedded().records() {
+    ///         println!("Trade ID: {}", trade.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_all_trades(
+        &self,
+        request: &AllTradesRequest,
+    ) -> Result<AllTradesResponse, Error> {
+        self.get::<AllTradesResponse>(request).await
+    }
+
+    /// Streams trades, fetching the following pages as they become available.
+    ///
+    /// The returned stream starts at the first page selected by the request, and follows the
+    /// `next` links of the pages returned by the Horizon server. Combined with the derived streams
+    /// of the [`analytics`](crate::analytics) module, it allows computing trading statistics on the
+    /// fly.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllTradesRequest`] that determines the first page to be
+    ///   fetched.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing a [`TradeResponse`], or an error if a page
+    /// could not be retrieved. The stream ends after an error, or when an empty page is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = AllTradesRequest::new().set_limit(200)?;
+    ///
+    /// let stream = horizon_client.stream_trades(&request);
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(trade) = stream.next().await {
+    ///     println!("Trade ID: {}", trade?.id());
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_trades<'a>(
+        &'a self,
+        request: &AllTradesRequest,
+    ) -> impl Stream<Item = Result<TradeResponse, Error>> + 'a {
+        self.stream_trades_with_options(request, StreamOptions::new())
+    }
+
+    /// Streams trades, with control over shutdown and checkpointing.
+    ///
+    /// This method behaves like [`HorizonClient::stream_trades`], but additionally accepts
+    /// [`StreamOptions`], which allow the stream to be shut down gracefully, to report the paging
+    /// token of the last processed trade to a checkpoint callback, and to resume after a previously
+    /// reported paging token.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllTradesRequest`] that determines the first page to be
+    ///   fetched. Its cursor is overridden if the options contain a paging token to resume from.
+    /// * `options` - The [`StreamOptions`] controlling the lifecycle of the stream.
+    ///
+    pub fn stream_trades_with_options<'a>(
+        &'a self,
+        request: &AllTradesRequest,
+        options: StreamOptions,
+    ) -> impl Stream<Item = Result<TradeResponse, Error>> + 'a {
+        let first_url = options.first_url(request.build_url(&self.base_url));
+
+        stream::unfold(
+            (StreamState::new(first_url), options),
+            move |(mut state, options): (StreamState<TradeResponse>, StreamOptions)| async move {
+                state.confirm_yielded();
+
+                loop {
+                    if options.is_shut_down() {
+                        state.checkpoint(&options);
+                        return None;
+                    }
+
+                    if let Some(trade) = state.buffered.pop_front() {
+                        state.set_yielded(trade.paging_token());
+                        return Some((Ok(trade), (state, options)));
+                    }
+
+                    // The current page has been processed completely.
+                    state.checkpoint(&options);
+
+                    // Fetch the next page, if any.
+                    let url = state.next_url.take()?;
+                    let page = futures::select_biased! {
+                        _ = options.shut_down().fuse() => return None,
+                        page = self.get_from_url::<AllTradesResponse>(&url).fuse() => page,
+                    };
+                    match page {
+                        Ok(page) => {
+                            if page.embedded.records.is_empty() {
+                                return None;
+                            }
+                            state.next_url = page.links.next.and_then(|link| link.href);
+                            state.buffered.extend(page.embedded.records);
+                        }
+                        Err(e) => {
+                            state.finish();
+                            return Some((Err(e), (state, options)));
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Retrieves a list of all trades for a given account from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all trades for a given account from the Horizon server.
+    /// It requires an [`TradesForAccountRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`TradesForAccountRequest`] instance, containing the
+    ///     parameters for the trades request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
+    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TradesForAccountRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TradesForAccountRequest::new()
+    ///    .set_account_id("GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A")
+    ///    .unwrap();
+    ///
+    /// let response = horizon_client.get_trades_for_account(&request).await;
+    ///
+    /// // Access the trades
+    /// if let Ok(trades_response) = response {
+    ///     for trade in trades_response.embedded().records() {
+    ///         println!("Trade ID: {}", trade.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_trades_for_account(
+        &self,
+        request: &TradesForAccountRequest<TradeAccountId>,
+    ) -> Result<AllTradesResponse, Error> {
+        self.get::<AllTradesResponse>(request).await
+    }
+
+    /// Retrieves a list of all successful trades fulfilled by the given liquidity pool from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all successful trades fulfilled by the given liquidity pool
+    /// from the Horizon server. It requires an [`TradesForLiquidityPoolRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`TradesForLiquidityPoolRequest`] instance, containing the
+    ///     parameters for the trades request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
+    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TradesForLiquidityPoolRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TradesForLiquidityPoolRequest::new()
+    ///    .set_liquidity_pool_id("0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3")
+    ///    .unwrap();
+    ///
+    /// let response = horizon_client.get_trades_for_liquidity_pool(&request).await;
+    ///
+    /// // Access the trades
+    /// if let Ok(trades_response) = response {
+    ///     for trade in trades_response.embedded().records() {
+    ///         println!("Trade ID: {}", trade.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_trades_for_liquidity_pool(
+        &self,
+        request: &TradesForLiquidityPoolRequest<TradeLiquidityPoolId>,
+    ) -> Result<AllTradesResponse, Error> {
+        self.get::<AllTradesResponse>(request).await
+    }
+
+    /// Retrieves a list of all trades for a given offer from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all trades for a given offer from the Horizon server.
+    /// It requires an [`TradesForOfferRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`TradesForOfferRequest`] instance, containing the
+    ///     parameters for the trades request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTradesResponse`], which includes
+    /// the list of all trades obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TradesForOfferRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::trades::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TradesForOfferRequest::new()
+    ///    .set_offer_id("42")
+    ///    .unwrap();
+    ///
+    /// let response = horizon_client.get_trades_for_offer(&request).await;
+    ///
+    /// // Access the trades
+    /// if let Ok(trades_response) = response {
+    ///     for trade in trades_response.embedded().records() {
+    ///         println!("Trade ID: {}", trade.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_trades_for_offer(
+        &self,
+        request: &TradesForOfferRequest<OfferId>,
+    ) -> Result<AllTradesResponse, Error> {
+        self.get::<AllTradesResponse>(request).await
+    }
+
+    /// Fetches all liquidity pools from the Stellar Horizon API.
+    ///
+    /// This asynchronous method retrieves a list of all liquidity pools from the Horizon server.
+    /// It requires an [`AllLiquidityPoolsRequest`] to specify optional query parameters such as
+    /// filters by `asset_code` or `asset_issuer`.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllLiquidityPoolsRequest`] instance, containing the
+    /// parameters for the liquidity pools request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllLiquidityPoolsResponse`], which includes
+    /// the comprehensive list of liquidity pools retrieved from the Horizon server. If the request
+    /// encounters an issue, an error is returned within `Result`.
+    ///
+    /// # Example
+    /// To use this method, create an instance of [`AllLiquidityPoolsRequest`], set any desired
+    /// filters or parameters and pass
+    ///
+    /// ```
+    /// # use stellar_rs::liquidity_pools::all_liquidity_pools_request::AllLiquidityPoolsRequest;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::models::prelude::AssetType;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)?;
+    /// let request = AllLiquidityPoolsRequest::new().add_reserve(AssetType::issued(
+    ///     "USDC",
+    ///     "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
+    /// ))?;
+    ///
+    /// let response = horizon_client.get_all_liquidity_pools(&request).await;
+    ///
+    /// // Access liquidity pool details
+    /// for pool in response?.embedded().records() {
+    ///     println!("Pool ID: {}", pool.id());
+    ///     // Further processing...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_all_liquidity_pools(
+        &self,
+        request: &AllLiquidityPoolsRequest,
+    ) -> Result<AllLiquidityPoolsResponse, Error> {
+        self.get::<AllLiquidityPoolsResponse>(request).await
+    }
+
+    /// Retrieves detailed information for a specific liquidity pool from the Horizon server.
+    ///
+    /// This asynchronous method is designed to fetch detailed information about a single liquidity
+    /// pool from the Horizon server. It requires a [`SingleLiquidityPoolRequest`] that includes the
+    /// unique identifier of the liquidity pool to be retrieved.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`SingleLiquidityPoolRequest`] instance containing the
+    /// unique ID of the liquidity pool to be fetched.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`SingleLiquidityPoolResponse`],
+    /// which includes detailed information about the requested liquidity pool. If the request fails,
+    /// it returns an error encapsulated within `Result`.
+    ///
+    /// # Example
+    /// To use this method, create an instance of [`SingleLiquidityPoolRequest`]
+    /// with the specific liquidity pool ID.
+    ///
+    /// ```
+    /// # use stellar_rs::liquidity_pools::single_liquidity_pool_request::SingleLiquidityPoolRequest;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)?;
+    /// let request = SingleLiquidityPoolRequest::new()
+    ///     .set_liquidity_pool_id("000000006520216af66d20d63a58534d6cbdf28ba9f2a9c1e03f8d9a756bb7d988b29bca").unwrap();
+    ///
+    /// let response = horizon_client.get_single_liquidity_pool(&request).await;
+    ///
+    /// // Access the details of the liquidity pool
+    /// if let Ok(pool_response) = response {
+    ///     println!("Pool ID: {}", pool_response.id());
+    ///     // Further processing...
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_single_liquidity_pool(
+        &self,
+        request: &SingleLiquidityPoolRequest<LiquidityPoolId>,
+    ) -> Result<LiquidityPool, Error> {
+        self.get::<LiquidityPool>(request).await
+    }
+
+    /// Retrieves detailed information for a specific transaction from the Horizon server.
+    ///
+    /// This asynchronous method fetches details of a single transaction from the Horizon server.
+    /// It requires a [`SingleTransactionRequest`] parameterized with `TransactionHash`, which includes the hash
+    /// of the transaction to be retrieved.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/network/horizon/api-reference/resources/retrieve-a-transaction">Retrieve a Transaction endpoint</a>
+    /// endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A reference to a [`SingleTransactionRequest<TransactionHash>`] instance, containing the
+    ///   hash of the transaction for which details are to be fetched.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an [`TransactionResponse`], which includes detailed
+    /// information about the requested transaction. If the request fails, it returns an error
+    /// encapsulated within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`SingleTransactionRequest`] and set the
+    /// hash of the transaction to be queried.
+    ///
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = SingleTransactionRequest::new()
+    ///     .set_transaction_hash("be0d59c8706e8fd525d2ab10910a55ec57323663858c65b330a3f93afb13ab0f") // example transaction hash
+    ///     .unwrap();
+    ///
+    /// let response = horizon_client.get_single_transaction(&request).await;
+    ///
+    /// // Access the details of the claimable balance
+    /// if let Ok(transaction_response) = response {
+    ///     println!("Created at: {}", transaction_response.created_at());
+    ///     // Further processing...
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_single_transaction(
+        &self,
+        request: &SingleTransactionRequest<TransactionHash>,
+    ) -> Result<TransactionResponse, Error> {
+        self.get::<TransactionResponse>(request).await
+    }
+
+    /// Verifies the signatures of a transaction against the current signers of the account that
+    /// authorizes it.
+    ///
+    /// This asynchronous method fetches the account that authorizes the transaction, which is the
+    /// fee account: for a regular transaction, the fee account is the source account, and for a
+    /// fee bump transaction, the signatures of the envelope are those of the fee account. It then
+    /// verifies the signatures using [`TransactionResponse::verify_signatures`].
+    ///
+    /// # Arguments
+    /// * `transaction` - The transaction, as returned by the Horizon server.
+    /// * `network_passphrase` - The passphrase of the network the transaction was applied on.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a `Result` containing a [`SignatureReport`]. If the account could not
+    /// be retrieved, or if the signatures could not be verified, it returns an error within
+    /// `Result`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::network::Network;
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = SingleTransactionRequest::new()
+    ///     .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020")?;
+    /// let transaction = horizon_client.get_single_transaction(&request).await?;
+    ///
+    /// let report = horizon_client
+    ///     .verify_transaction_signatures(&transaction, Network::Testnet.passphrase())
+    ///     .await?;
+    /// for check in report.signatures() {
+    ///     match check.signer() {
+    ///         Some(signer) => println!("{} signed with weight {:?}", signer.key(), check.weight()),
+    ///         None => println!("Unknown signature {}", check.signature()),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn verify_transaction_signatures(
+        &self,
+        transaction: &TransactionResponse,
+        network_passphrase: &str,
+    ) -> Result<SignatureReport, Error> {
+        let request = SingleAccountRequest::new().set_account_id(transaction.fee_account())?;
+        let account = self.get_single_account(&request).await?;
+
+        Ok(transaction.verify_signatures(network_passphrase, account.signers())?)
+    }
+
+    /// Retrieves the paging token of a specific transaction, to resume listing transactions after it.
+    ///
+    /// Wallets and indexers commonly remember the last transaction they have seen, and only want
+    /// to list the transactions that follow it. This asynchronous method fetches the transaction
+    /// and returns its paging token, which can be passed to
+    /// [`AllTransactionsRequest::set_resume_after`].
+    ///
+    /// # Arguments
+    /// * `transaction_hash` - The hash of the transaction to resume after.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the paging token of the transaction. If the hash is invalid
+    /// or the request fails, it returns an error encapsulated within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Order;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let paging_token = horizon_client
+    ///     .cursor_for_transaction("be0d59c8706e8fd525d2ab10910a55ec57323663858c65b330a3f93afb13ab0f")
+    ///     .await?;
+    ///
+    /// let request = AllTransactionsRequest::new()
+    ///     .set_resume_after(paging_token)?
+    ///     .set_order(Order::Asc)?;
+    /// let response = horizon_client.get_all_transactions(&request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn cursor_for_transaction(&self, transaction_hash: &str) -> Result<String, Error> {
+        let request = SingleTransactionRequest::new().set_transaction_hash(transaction_hash)?;
+        let transaction = self.get_single_transaction(&request).await?;
+
+        Ok(transaction.paging_token().clone())
+    }
+
+    /// Retrieves a list of all transactions from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all transactions from the Horizon server.
+    /// It requires an [`AllTransactionsRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllTransactionsRequest`] instance, containing the
+    /// parameters for the transactions request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
+    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`AllTransactionsRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = AllTransactionsRequest::new()
+    ///   .set_include_failed(true).unwrap();
+    ///
+    /// let response = horizon_client.get_all_transactions(&request).await;
+    ///
+    /// // Access the transactions
+    /// if let Ok(transactions_response) = response {
+    ///     for transaction in transactions_response.embedded().records() {
+    ///         println!("Transaction ID: {}", transaction.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_all_transactions(
+        &self,
+        request: &AllTransactionsRequest,
+    ) -> Result<AllTransactionsResponse, Error> {
+        self.get::<AllTransactionsResponse>(request).await
+    }
+
+    /// Streams transactions in near real time, as the Horizon server ingests them.
+    ///
+    /// This method opens a Server-Sent Events connection, as described at
+    /// [`HorizonClient::stream_sse`], without further [`StreamOptions`].
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllTransactionsRequest`], whose cursor determines the first
+    ///   transaction to be streamed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result`s, each containing a [`TransactionResponse`], or an error if the
+    /// connection could not be established. The stream ends after an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use futures::StreamExt;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let stream = horizon_client.stream_transactions(&AllTransactionsRequest::new());
+    /// futures::pin_mut!(stream);
+    ///
+    /// while let Some(transaction) = stream.next().await {
+    ///     println!("Transaction hash: {}", transaction?.hash());
+    /// #   break;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stream_transactions<'a>(
+        &'a self,
+        request: &AllTransactionsRequest,
+    ) -> impl Stream<Item = Result<TransactionResponse, Error>> + 'a {
+        self.stream_sse(request, StreamOptions::new())
+    }
+
+    /// Retrieves a list of all transactions for a given account from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all transactions for a given account from
+    /// the Horizon server. It requires an [`TransactionsForAccountRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`TransactionsForAccountRequest`] instance, containing the
+    /// parameters for the transactions request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
+    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TransactionsForAccountRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TransactionsForAccountRequest::new()
+    ///     .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H").unwrap()
+    ///     .set_include_failed(true).unwrap();
+    ///
+    /// let response = horizon_client.get_transactions_for_account(&request).await;
+    ///
+    /// // Access the transactions
+    /// if let Ok(transactions_response) = response {
+    ///     for transaction in transactions_response.embedded().records() {
+    ///         println!("Transaction ID: {}", transaction.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_transactions_for_account(
+        &self,
+        request: &TransactionsForAccountRequest<TransactionsAccountId>,
+    ) -> Result<AllTransactionsResponse, Error> {
+        self.get::<AllTransactionsResponse>(request).await
+    }
+
+    /// Retrieves a list of all transactions in a given ledger from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all transactions in a given ledger from
+    /// the Horizon server. It requires an [`TransactionsForLedgerRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`TransactionsForLedgerRequest`] instance, containing the
+    /// parameters for the transactions request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
+    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TransactionsForLedgerRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TransactionsForLedgerRequest::new()
+    ///     .set_ledger_sequence(539).unwrap()
+    ///     .set_include_failed(true).unwrap();
+    ///
+    /// let response = horizon_client.get_transactions_for_ledger(&request).await;
+    ///
+    /// // Access the transactions
+    /// if let Ok(transactions_response) = response {
+    ///     for transaction in transactions_response.embedded().records() {
+    ///         println!("Transaction ID: {}", transaction.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_transactions_for_ledger(
+        &self,
+        request: &TransactionsForLedgerRequest<TransactionsLedgerId>,
+    ) -> Result<AllTransactionsResponse, Error> {
+        self.get::<AllTransactionsResponse>(request).await
+    }
+
+    /// Retrieves a list of all transactions referencing a given liquidity pool from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all transactions referencing a given liquidity pool from
+    /// the Horizon server. It requires an [`TransactionsForLiquidityPoolRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`TransactionsForLiquidityPoolRequest`] instance, containing the
+    /// parameters for the transactions request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which includes
+    /// the list of all transactions obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`TransactionsForLiquidityPoolRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TransactionsForLiquidityPoolRequest::new()
+    ///     .set_liquidity_pool_id("0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10").unwrap()
+    ///     .set_include_failed(true).unwrap();
+    ///
+    /// let response = horizon_client.get_transactions_for_liquidity_pool(&request).await;
+    ///
+    /// // Access the transactions
+    /// if let Ok(transactions_response) = response {
+    ///     for transaction in transactions_response.embedded().records() {
+    ///         println!("Transaction ID: {}", transaction.id());
+    ///         // Further processing...
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_transactions_for_liquidity_pool(
+        &self,
+        request: &TransactionsForLiquidityPoolRequest<TransactionsLiquidityPoolId>,
+    ) -> Result<AllTransactionsResponse, Error> {
+        self.get::<AllTransactionsResponse>(request).await
+    }
+
+    /// Retrieves a list of the transactions that reference a specific claimable balance from the
+    /// Horizon server.
+    ///
+    /// This asynchronous method fetches the transactions that created, claimed or clawed back a
+    /// claimable balance, which allows auditing the lifecycle of the balance. It requires a
+    /// [`TransactionsForClaimableBalanceRequest`] with the ID of the claimable balance.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`TransactionsForClaimableBalanceRequest`] instance,
+    ///   containing the claimable balance ID and optional query parameters.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`AllTransactionsResponse`], which
+    /// includes the transactions obtained from the Horizon server. If the request fails, it returns
+    /// an error within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = TransactionsForClaimableBalanceRequest::new()
+    ///     .set_claimable_balance_id("00000000e7a2e5a4c47cd0d4fe1b3e2d9c1f5b8b1f2c1fa1a4ddf2f8b7a8e5ab4d2e1f30")
+    ///     .set_include_failed(true);
+    ///
+    /// let response = horizon_client.get_transactions_for_claimable_balance(&request).await?;
+    ///
+    /// for transaction in response.embedded().records() {
+    ///     println!("Transaction ID: {}", transaction.id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_transactions_for_claimable_balance(
+        &self,
+        request: &TransactionsForClaimableBalanceRequest<TransactionsClaimableBalanceId>,
+    ) -> Result<AllTransactionsResponse, Error> {
+        self.get::<AllTransactionsResponse>(request).await
+    }
+
+    /// Retrieves payment paths from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of payment paths from
+    /// the Horizon server. It requires an [`FindPaymentsPathRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`FindPaymentsPathRequest`] instance, containing the
+    /// parameters for the paths request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`PathsResponse`], which includes
+    /// the list of the payment paths obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`FindPaymentsPathRequest`] and set any desired
+    /// filters or parameters.
+    pub async fn get_find_payment_paths(
+        &self,
+        request: &FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount>,
+    ) -> Result<PathsResponse, Error> {
+        self.get::<PathsResponse>(request).await
+    }
+
+    /// Retrieves a list of strict receive payment paths from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of strict receive payment paths from
+    /// the Horizon server. It requires an [`ListStrictReceivePaymentPathsRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`ListStrictReceivePaymentPathsRequest`] instance, containing the
+    /// parameters for the paths request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`PathsResponse`], which includes
+    /// the list of the strict receive payment paths obtained from the Horizon server.
+    /// If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`ListStrictReceivePaymentPathsRequest`] and set any desired
+    /// filters or parameters.
+    pub async fn get_list_strict_receive_payment_paths(
+        &self,
+        request: &ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source>,
+    ) -> Result<PathsResponse, Error> {
+        self.get::<PathsResponse>(request).await
+    }
+
+    /// Retrieves a list of strict send payment paths from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of strict send payment paths from
+    /// the Horizon server. It requires an [`ListStrictSendPaymentPathsRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`ListStrictSendPaymentPathsRequest`] instance, containing the
+    /// parameters for the paths request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing an [`PathsResponse`], which includes
+    /// the list of the strict send payment paths obtained from the Horizon server.
+    /// If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`ListStrictSendPaymentPathsRequest`] and set any desired
+    /// filters or parameters.
+    pub async fn get_list_strict_send_payment_paths(
+        &self,
+        request: &ListStrictSendPaymentPathsRequest<SourceAsset, SourceAmount, Destination>,
+    ) -> Result<PathsResponse, Error> {
+        self.get::<PathsResponse>(request).await
+    }
+
+    /// Retrieves a list of all payments from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all payments from the Horizon server.
+    /// It requires an [`AllPaymentsRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`AllPaymentsRequest`] instance, containing the
+    /// parameters for the payments request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
+    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`AllPaymentsRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::payments::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = AllPaymentsRequest::new()
+    ///  .set_limit(2).unwrap();
+    ///
+    /// let response = horizon_client.get_all_payments(&request).await;
+    ///
+    /// // Access the payments
+    /// if let Ok(payments_response) = response {
+    ///    for payment in payments_response.embedded().records() {
+    ///       println!("Payment ID: {}", payment.id());
+    ///      // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_all_payments(
+        &self,
+        request: &AllPaymentsRequest,
+    ) -> Result<PaymentsResponse, Error> {
+        self.get::<PaymentsResponse>(request).await
+    }
+
+    /// Retrieves a list of all payments for an account from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all payments for an account from the Horizon server.
+    /// It requires an [`PaymentsForAccountRequest`] to specify the optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`PaymentsForAccountRequest`] instance, containing the
+    /// parameters for the payments for account request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
+    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`PaymentsForAccountRequest`] and set any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::payments::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = PaymentsForAccountRequest::new()
+    ///  .set_limit(2).unwrap();
+    ///
+    /// let response = horizon_client.get_payments_for_account(&request).await;
+    ///
+    /// // Access the payments
+    /// if let Ok(payments_response) = response {
+    ///   for payment in payments_response.embedded().records() {
+    ///   println!("Payment ID: {}", payment.id());
+    /// // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_payments_for_account(
+        &self,
+        request: &PaymentsForAccountRequest,
+    ) -> Result<PaymentsResponse, Error> {
+        self.get::<PaymentsResponse>(request).await
+    }
+
+    /// Retrieves a list of all payments for a specific ledger from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all payments for a specific ledger from the Horizon server.
+    /// It requires an [`PaymentsForLedgerRequest`] to specify the ledger sequence number and optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`PaymentsForLedgerRequest`] instance, containing the
+    /// parameters for the payments for ledger request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
+    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`PaymentsForLedgerRequest`] and set the ledger sequence number and any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::payments::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = PaymentsForLedgerRequest::new()
+    /// .set_ledger_sequence(48483)?;
+    ///
+    /// let response = horizon_client.get_payments_for_ledger(&request).await;
+    ///
+    /// // Access the payments
+    /// if let Ok(payments_response) = response {
+    /// for payment in payments_response.embedded().records() {
+    /// println!("Payment ID: {}", payment.id());
+    ///
+    /// // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_payments_for_ledger(
+        &self,
+        request: &PaymentsForLedgerRequest,
+    ) -> Result<PaymentsResponse, Error> {
+        self.get::<PaymentsResponse>(request).await
+    }
+
+    /// Retrieves a list of all payments for a specific transaction from the Horizon server.
+    ///
+    /// This asynchronous method fetches a list of all payments for a specific transaction from the Horizon server.
+    /// It requires an [`PaymentsForTransactionRequest`] to specify the transaction hash and optional query parameters.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to an [`PaymentsForTransactionRequest`] instance, containing the
+    /// parameters for the payments for transaction request.
+    ///
+    /// # Returns
+    ///
+    /// On successful execution, returns a `Result` containing a [`PaymentsResponse`], which includes
+    /// the list of all payments obtained from the Horizon server. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`PaymentsForTransactionRequest`] and set the transaction hash and any desired
+    /// filters or parameters.
+    ///
+    /// ```
+    /// # use stellar_rs::payments::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let request = PaymentsForTransactionRequest::new()
+    /// .set_transaction_hash("be0d59c8706e8fd525d2ab10910a55ec57323663858c65b330a3f93afb13ab0f")?;
+    ///
+    /// let response = horizon_client.get_payments_for_transaction(&request).await;
+    ///
+    /// // Access the payments
+    /// if let Ok(payments_response) = response {
+    /// for payment in payments_response.embedded().records() {
+    /// println!("Payment ID: {}", payment.id());
+    ///
+    /// // Further processing...
+    /// }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_payments_for_transaction(
+        &self,
+        request: &PaymentsForTransactionRequest,
+    ) -> Result<PaymentsResponse, Error> {
+        self.get::<PaymentsResponse>(request).await
+    }
+
+    /// Retrieves the payment history of an account within a time range.
+    ///
+    /// This asynchronous method combines everything that moved funds into or out of the account
+    /// into a single chronological feed: payments, incoming and outgoing path payments, the
+    /// creation of the account, account merges, and the creation and claiming of claimable
+    /// balances. Each [`PaymentHistoryEntry`] carries the amount that was effectively credited or
+    /// debited, as recorded by the effects of the account, rather than the limits of a path
+    /// payment such as `source_max`.
+    ///
+    /// The effects and operations of the account are paged backwards from the most recent one
+    /// until the start of the range, so a range far in the past takes as many requests as there
+    /// are payments since its start.
+    ///
+    /// # Arguments
+    /// * `account_id` - The ID of the account to retrieve the payment history of.
+    /// * `range` - The time range, including its start and excluding its end.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the entries within the range, in ascending order. If any of
+    /// the requests fails, it returns an error encapsulated within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use stellar_rs::payments::prelude::*;
+    /// # use chrono::{TimeZone, Utc};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+    /// let history = horizon_client
+    ///     .get_payment_history(
+    ///         "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+    ///         start..end,
+    ///     )
+    ///     .await?;
+    ///
+    /// for entry in &history {
+    ///     let sign = match entry.direction() {
+    ///         PaymentDirection::Incoming => "+",
+    ///         PaymentDirection::Outgoing => "-",
+    ///     };
+    ///     println!("{} {}{} {:?}", entry.created_at(), sign, entry.amount(), entry.asset());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_payment_history(
+        &self,
+        account_id: &str,
+        range: Range<DateTime>,
+    ) -> Result<Vec<PaymentHistoryEntry>, Error> {
+        const PAGE_SIZE: u8 = 200;
+
+        let effects_request = EffectsForAccountRequest::new()
+            .set_account_id(account_id)
+            .set_limit(PAGE_SIZE)?
+            .set_order(Order::Desc)?;
+        let mut effects = self
+            .get_records_since(
+                effects_request.build_url(&self.base_url),
+                PAGE_SIZE,
+                &range.start,
+                |page: &EffectsResponse| (page.links(), &page.embedded().records),
+                |effect| &effect.created_at,
+            )
+            .await?;
+        effects.retain(|effect| {
+            datetime::parse_rfc3339(&effect.created_at)
+                .is_ok_and(|created_at| range.contains(&created_at))
+        });
+        effects.reverse();
+        if effects.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let operations_request = OperationsForAccountRequest::new()
+            .set_account_id(account_id)
+            .set_limit(PAGE_SIZE)?
+            .set_order(Order::Desc)?;
+        let operations = self
+            .get_records_since(
+                operations_request.build_url(&self.base_url),
+                PAGE_SIZE,
+                &range.start,
+                |page: &OperationResponse| (&page.links, &page.embedded.records),
+                |operation| &operation.created_at,
+            )
+            .await?
+            .into_iter()
+            .map(|operation| (operation.id, operation))
+            .collect();
+
+        Ok(build_payment_history(account_id, &effects, &operations)?)
+    }
+
+    /// Retrieves a report of the value that flowed into and out of a liquidity pool within a time
+    /// range.
+    ///
+    /// This asynchronous method combines the deposits, withdrawals and trades of the liquidity
+    /// pool into a [`LiquidityPoolReport`], which lists every
+    /// [`LiquidityPoolFlow`](crate::liquidity_pools::prelude::LiquidityPoolFlow) along with the
+    /// totals per reserve, the fees the pool charged on trades, and the pool shares that were
+    /// issued and redeemed. This is useful for tracking the position of a liquidity provider.
+    ///
+    /// The effects of the liquidity pool are paged backwards from the most recent one until the
+    /// start of the range, so a range far in the past takes as many requests as there are
+    /// effects since its start.
+    ///
+    /// # Arguments
+    /// * `liquidity_pool_id` - The ID of the liquidity pool to report on.
+    /// * `range` - The time range, including its start and excluding its end.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the report of the range. If any of the requests fails, it
+    /// returns an error encapsulated within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use chrono::{TimeZone, Utc};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let start = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+    /// let report = horizon_client
+    ///     .get_liquidity_pool_report(
+    ///         "dd7b1ab831c273310ddbec6f97870aa83c2fbd78ce22aded37ecbf4f3380fac7",
+    ///         start..end,
+    ///     )
+    ///     .await?;
+    ///
+    /// for (asset, fee) in report.fees() {
+    ///     println!("Fees in {}: {}", asset, fee);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn get_liquidity_pool_report(
+        &self,
+        liquidity_pool_id: &str,
+        range: Range<DateTime>,
+    ) -> Result<LiquidityPoolReport, Error> {
+        const PAGE_SIZE: u8 = 200;
+
+        let effects_request = EffectsForLiquidityPoolRequest::new()
+            .set_liquidity_pool_id(liquidity_pool_id)
+            .set_limit(PAGE_SIZE)?
+            .set_order(Order::Desc)?;
+        let mut effects = self
+            .get_records_since(
+                effects_request.build_url(&self.base_url),
+                PAGE_SIZE,
+                &range.start,
+                |page: &EffectsResponse| (page.links(), &page.embedded().records),
+                |effect| &effect.created_at,
+            )
+            .await?;
+        effects.retain(|effect| {
+            datetime::parse_rfc3339(&effect.created_at)
+                .is_ok_and(|created_at| range.contains(&created_at))
+        });
+        effects.reverse();
+
+        Ok(build_liquidity_pool_report(liquidity_pool_id, &effects)?)
+    }
+
+    /// Pages backwards through a list of records in descending order, until the records are older
+    /// than `start`. The last page may contain records that are older than `start`.
+    async fn get_records_since<R: Response, T: Clone>(
+        &self,
+        url: String,
+        page_size: u8,
+        start: &DateTime,
+        page_parts: fn(&R) -> (&ResponseLinks, &Vec<T>),
+        created_at: fn(&T) -> &String,
+    ) -> Result<Vec<T>, Error> {
+        let mut collected = Vec::new();
+        let mut next_url = Some(url);
+        while let Some(url) = next_url.take() {
+            let page = self.get_from_url::<R>(&url).await?;
+            let (links, page_records) = page_parts(&page);
+
+            // A page that is not full is the last one; skip requesting the empty page after it.
+            let reached_start = match page_records.last() {
+                Some(record) => datetime::parse_rfc3339(created_at(record))? < *start,
+                None => true,
+            };
+            if !reached_start && page_records.len() == usize::from(page_size) {
+                next_url = links.next.as_ref().and_then(|link| link.href.clone());
+            }
+            collected.extend(page_records.iter().cloned());
+        }
+
+        Ok(collected)
+    }
+
+    /// Submits a transaction to the Horizon server.
+    ///
+    /// This asynchronous method submits a transaction to the Stellar network. It only takes a
+    /// single, required parameter: the signed transaction. Refer to the Transactions page for
+    /// details on how to craft a proper one. If you submit a transaction that has already been
+    /// included in a ledger, this endpoint will return the same response as would have been
+    /// returned for the original transaction submission. This allows for safe resubmission of
+    /// transactions in error scenarios, as highlighted in the error handling guide.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`PostTransactionRequest<TransactionEnvelope>`] instance, containing the
+    /// signed transaction to be submitted.
+    ///
+    /// # Returns
+    /// On successful execution, returns a `Result` containing a [`TransactionResponse`], which includes
+    /// the details of the submitted transaction. If the request fails, it returns an error within `Result`.
+    ///
+    /// # Usage
+    /// To use this method, create an instance of [`PostTransactionRequest`] and set the signed
+    /// transaction to be submitted.
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::models::Request;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let base_url = "https://horizon-testnet.stellar.org";
+    /// # let horizon_client = HorizonClient::new(base_url)
+    /// #    .expect("Failed to create Horizon Client");
+    /// let signed_transaction_xdr = "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAABEwAAAAAAAAAAQAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAsAAAAAAAAAAAAAAAAQfdFrLDgzSIIugR73qs8U0ZiKbwBUclTTPh5thlbgnAFjRXhdigAAAAAAAAAAAAAAAAAA3b5KF6uk1w1fSKYLrzR8gF2lB+AHAi6oU6CaWhunAskAAAAXSHboAAAAAAAAAAAAAAAAAHfmNeMLin2aTUfxa530ZRn4zwRu7ROAQfUJeJco8HSCAAHGv1JjQAAAAAAAAAAAAAAAAAAAlRt2go9sp7E1a5ZWvr7vin4UPrFQThpQax1lOFm33AAAABdIdugAAAAAAAAAAAAAAAAAmv+knlR6JR2VqWeU0k/4FgvZ/tSV5DEY4gu0iOTKgpUAAAAXSHboAAAAAAAAAAAAAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAF0h26AAAAAABAAAAAACVG3aCj2ynsTVrlla+vu+KfhQ+sVBOGlBrHWU4WbfcAAAABgAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bf/////////8AAAABAAAAAJr/pJ5UeiUdlalnlNJP+BYL2f7UleQxGOILtIjkyoKVAAAABgAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bf/////////8AAAABAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAAQAAAAAAlRt2go9sp7E1a5ZWvr7vin4UPrFQThpQax1lOFm33AAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAJGE5yoAAAAAABAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAAQAAAACa/6SeVHolHZWpZ5TST/gWC9n+1JXkMRjiC7SI5MqClQAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAJGE5yoAAAAAAAAAAAAAAAAABKBB+2UBMP/abwcm/M1TXO+/JQWhPwkalgqizKmXyRIQx7qh6aAFYAAAAAAAAAAARW/AX3AAAAQDVB8fT2ZXF0PZqtZX9brK0kz+P4G8VKs1DkDklP6ULsvXRexXFBdH4xG8xRAsR1HJeEBH278hiBNNvUwNw6zgzGYc0bAAAAQLgZUU/oYGL7frWDQhJHhCQu9JmfqN03PrJq4/cJrN1OSUWXnmLc94sv8m2L+cxl2p0skr2Jxy+vt1Lcxkv7wAI4WbfcAAAAQHvZEVqlygIProf3jVTZohDWm2WUNrFAFXf1LctTqDCQBHph14Eo+APwrTURLLYTIvNoXeGzBKbL03SsOARWcQLkyoKVAAAAQHAvKv2/Ro4+cNh6bKQO/G9NNiUozYysGwG1GvJQkFjwy/OTsL6WBfuI0Oye84lVBVrQVk2EY1ERFhgdMpuFSg4=";
+    /// let request = PostTransactionRequest::new()
+    ///    .set_transaction_envelope_xdr(signed_transaction_xdr).unwrap();
+    /// let response = horizon_client.post_transaction(&request).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn post_transaction(
+        &self,
+        request: &PostTransactionRequest<TransactionEnvelope>,
+    ) -> Result<TransactionResponse, Error> {
+        self.verify_network_before_submit().await?;
+        self.post::<TransactionResponse>(request).await
+    }
+
+    /// Submits a transaction to the Horizon server, resolving the outcome if the submission times out.
+    ///
+    /// This asynchronous method behaves like [`HorizonClient::post_transaction`], but handles the
+    /// case in which Horizon does not receive a result from Stellar Core in time and responds with
+    /// a `504 Gateway Timeout`, or in which the request itself times out. In that case, the
+    /// transaction may still be included in a ledger. Instead of returning an error, this method
+    /// computes the hash of the transaction locally and polls
+    /// [`HorizonClient::get_single_transaction`] to determine whether the submission succeeded,
+    /// as recommended by the Horizon documentation.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`PostTransactionRequest<TransactionEnvelope>`] instance, containing the
+    ///   signed transaction to be submitted.
+    /// * `options` - The [`SubmitTransactionOptions`], containing the network passphrase and the
+    ///   polling behaviour.
+    ///
+    /// # Returns
+    /// On success, returns a `Result` containing a [`TransactionSubmissionOutcome`], which tells
+    /// whether the transaction was included in a ledger directly, was found after a timeout, or
+    /// could not be found yet. If the submission fails for any other reason, such as a rejected
+    /// transaction, or polling fails with an error that is neither a `404 Not Found` nor
+    /// [retryable](Error::is_retryable), an error is returned within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// #
+    /// # async fn example(signed_transaction_xdr: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = PostTransactionRequest::new()
+    ///    .set_transaction_envelope_xdr(signed_transaction_xdr)?;
+    /// let options = SubmitTransactionOptions::new("Test SDF Network ; September 2015");
+    ///
+    /// match horizon_client.submit_transaction(&request, &options).await? {
+    ///     TransactionSubmissionOutcome::Submitted(transaction)
+    ///     | TransactionSubmissionOutcome::ConfirmedAfterTimeout(transaction) => {
+    ///         println!("Included in ledger {}", transaction.ledger());
+    ///     }
+    ///     TransactionSubmissionOutcome::Pending { hash } => {
+    ///         println!("Transaction {} not found yet; it is safe to resubmit", hash);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn submit_transaction(
+        &self,
+        request: &PostTransactionRequest<TransactionEnvelope>,
+        options: &SubmitTransactionOptions,
+    ) -> Result<TransactionSubmissionOutcome, Error> {
+        // Compute the hash before submitting, so that an invalid envelope or passphrase is reported
+        // without the transaction being sent.
+        let hash = request.transaction_hash(options.network_passphrase())?;
+        self.verify_network_before_submit().await?;
+
+        let url = request.build_url(&self.base_url);
+        match self.send_post(&url, request).await {
+            Ok(response) if response.status() != reqwest::StatusCode::GATEWAY_TIMEOUT => {
+                let transaction: TransactionResponse = handle_response(
+                    response,
+                    self.error_body_snippet_length,
+                    self.max_response_size,
+                )
+                .await?;
+                return Ok(TransactionSubmissionOutcome::Submitted(transaction));
+            }
+            Err(e) if !e.is_timeout() => return Err(e.into()),
+            // The submission timed out; poll for the transaction below.
+            _ => {}
+        }
+
+        let transaction_request = SingleTransactionRequest::new().set_transaction_hash(&hash)?;
+        for _ in 0..options.max_polls() {
+            crate::runtime::sleep(options.poll_interval()).await;
+
+            // Until the transaction is included in a ledger, Horizon responds with a `404 Not Found`.
+            match self.get_single_transaction(&transaction_request).await {
+                Ok(transaction) => {
+                    return Ok(TransactionSubmissionOutcome::ConfirmedAfterTimeout(
+                        transaction,
+                    ))
+                }
+                Err(e) if e.is_not_found() || e.is_retryable() => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(TransactionSubmissionOutcome::Pending { hash })
+    }
+
+    /// Submits a transaction to the Horizon server, without waiting for it to be included in a ledger.
+    ///
+    /// This asynchronous method submits a transaction to the `/transactions_async` endpoint, which
+    /// responds as soon as Stellar Core has accepted or rejected the transaction, instead of
+    /// waiting for it to be included in a ledger like [`HorizonClient::post_transaction`]. Use
+    /// [`HorizonClient::wait_for_transaction`] to wait for the transaction record afterwards.
+    ///
+    /// Adheres to the <a href="https://developers.stellar.org/docs/data/horizon/api-reference/submit-async-transaction">Submit a Transaction Asynchronously</a>
+    /// endpoint.
+    ///
+    /// # Arguments
+    /// * `request` - A reference to a [`PostTransactionAsyncRequest<TransactionEnvelope>`] instance,
+    ///   containing the signed transaction to be submitted.
+    ///
+    /// # Returns
+    /// On success, returns a `Result` containing an [`AsyncTransactionResponse`], whose status is
+    /// `PENDING`, `DUPLICATE`, `TRY_AGAIN_LATER` or `ERROR`. Horizon responds to all but the first
+    /// with an error status code, which is not treated as an error by this method. If the request
+    /// itself is invalid, an error is returned within `Result`.
+    ///
+    /// # Usage
+    /// ```
+    /// # use stellar_rs::transactions::prelude::*;
+    /// # use stellar_rs::horizon_client::HorizonClient;
+    /// # use std::time::Duration;
+    /// #
+    /// # async fn example(signed_transaction_xdr: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+    /// let request = PostTransactionAsyncRequest::new()
+    ///     .set_transaction_envelope_xdr(signed_transaction_xdr)?;
+    /// let response = horizon_client.post_transaction_async(&request).await?;
+    ///
+    /// match response.tx_status() {
+    ///     AsyncTransactionStatus::Pending | AsyncTransactionStatus::Duplicate => {
+    ///         let transaction = horizon_client
+    ///             .wait_for_transaction(response.hash().as_str(), Duration::from_secs(1), 30)
+    ///             .await?;
+    ///         println!("Included in ledger {}", transaction.ledger());
+    ///     }
+    ///     AsyncTransactionStatus::TryAgainLater => println!("Submit the transaction again later"),
+    ///     AsyncTransactionStatus::Error => {
+    ///         println!("Rejected: {:?}", response.decoded_error_result_xdr()?);
+    ///     }
+    ///     _ => println!("Unknown status"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    pub async fn post_transaction_async(
+        &self,
+        request: &PostTransactionAsyncRequest<TransactionEnvelope>,
+    ) -> Result<AsyncTransactionResponse, Error> {
+        self.verify_network_before_submit().await?;
+        let url = request.build_url(&self.base_url);
+
+        self.with_circuit_breaker(&url, async {
+            let response = self.send_post(&url, request).await?;
+            let body = ResponseBody::read(response, self.max_response_size).await?;
+
+            // Horizon responds with `201 Created`, `409 Conflict`, `503 Service Unavailable` or
+            // `400 Bad Request`, depending on the status of the submission. A `400 Bad Request`
+            // is also used for malformed requests, which carry a problem instead of a status.
+            match body.status {
+                reqwest::StatusCode::CREATED
+                | reqwest::StatusCode::CONFLICT
+                | reqwest::StatusCode::SERVICE_UNAVAILABLE
+                | reqwest::StatusCode::BAD_REQUEST => {
+                    match AsyncTransactionResponse::from_json(body.body.clone()) {
+                        Ok(response) => Ok(response),
+                        Err(_) => body.parse(self.error_body_snippet_length),
+                    }
+                }
+                _ => body.parse(self.error_body_snippet_length),
+            }
+        })
+        .await
+    }
+
+    /// Waits for a transaction to be included in a ledger.
+    ///
+    /// This asynchronous method polls [`HorizonClient::get_single_transaction`] until the
+    /// transaction is found, which is typically used after submitting a transaction with
+    /// [`HorizonClient::post_transaction_async`]. Until the transaction is included in a ledger,
+    /// Horizon responds with a `404 Not Found`.
+    ///
+    /// # Arguments
+    /// * `hash` - The hash of the transaction.
+    /// * `poll_interval` - The time to wait before every attempt to find the transaction.
+    /// * `max_polls` - The maximum number of attempts to find the transaction.
+    ///
+    /// # Returns
+    /// On success, returns a `Result` containing the [`TransactionResponse`] of the transaction,
+    /// which may be a failed transaction. If the transaction was not found before the maximum
+    /// number of polls was reached, or if a request fails for any other reason, an error is
+    /// returned within `Result`.
+    ///
+    pub async fn wait_for_transaction(
+        &self,
+        hash: &str,
+        poll_interval: Duration,
+        max_polls: u32,
+    ) -> Result<TransactionResponse, Error> {
+        let request = SingleTransactionRequest::new().set_transaction_hash(hash)?;
+        for _ in 0..max_polls {
+            crate::runtime::sleep(poll_interval).await;
+
+            match self.get_single_transaction(&request).await {
+                Ok(transaction) => return Ok(transaction),
+                Err(e) if e.is_not_found() => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(Error::Other(format!(
+            "Transaction {} was not included in a ledger in time",
+            hash
+        )))
+    }
 }
 
 /// Handles the response received from an HTTP request made to the Horizon server.
 ///
 /// This asynchronous internal function processes the [`reqwest::Response`] obtained from a
 /// GET request. It is generic over the type `Response` which must implement the
-/// [`Response`] trait. The function primarily checks the HTTP status code of the
-/// response. If the status is `OK`, it attempts to deserialize the response body into
-/// the specified `Response` type. For other status codes, it treats the response as an
-/// error message.
+/// [`Response`] trait. The function first checks whether the response body is JSON, based on the
+/// `Content-Type` header of the response, or on the body itself if the header is missing. It then
+/// checks the HTTP status code of the response. If the status is `OK`, it attempts to deserialize
+/// the response body into the specified `Response` type. For other status codes, it treats the
+/// response as an error message.
 ///
 /// # Type Parameters
 ///
-/// * `Response` - The type into which the response body is to be deserialized. This type
-/// must implement the [`Response`] trait.
+/// * `Response` - The type into which the response body is to be deserialized. This type
+/// must implement the [`Response`] trait.
+///
+/// # Arguments
+///
+/// * `response` - The [`reqwest::Response`] object obtained from the HTTP request.
+/// * `error_body_snippet_length` - The maximum number of characters of the body to include in
+///   an error.
+///
+/// # Returns
+///
+/// On success (HTTP status `OK`), returns a `Result` containing the deserialized
+/// `Response`. If deserialization fails, if the body is not JSON, or if the HTTP status is not
+/// `OK`, it returns an [`Error`].
+///
+/// # Example Usage
+/// This function is not intended to be called directly. It is designed to be called
+/// exclusively by the [`HorizonClient::get`](crate::horizon_client::HorizonClient::get) function.
+///
+/// # Errors
+///
+/// Errors can arise from various situations, such as:
+/// - Non-`OK` HTTP status codes, reported as [`Error::Horizon`], or as
+///   [`Error::BeforeHistory`] or [`Error::BadPagingToken`] for the problems concerning pagination.
+/// - Response bodies that are not JSON, reported as [`Error::NonJsonResponse`].
+/// - Failure in reading the response body, reported as [`Error::Http`].
+/// - Deserialization errors when converting the response body into the `Response` type, reported
+///   as [`Error::Deserialization`].
+///
+pub(crate) async fn handle_response<R: Response>(
+    response: reqwest::Response,
+    error_body_snippet_length: usize,
+    max_response_size: Option<usize>,
+) -> Result<R, Error> {
+    ResponseBody::read(response, max_response_size)
+        .await?
+        .parse(error_body_snippet_length)
+}
+
+/// The response of a `GET` request that is in flight, which resolves once the request completes.
+type InFlightResponse = Shared<oneshot::Receiver<Result<ResponseBody, Error>>>;
+
+/// Removes a coalesced request from the requests in flight when it is dropped, even if the
+/// request is cancelled.
+struct InFlightGuard<'a> {
+    /// The requests in flight of the client.
+    in_flight: &'a Mutex<HashMap<String, InFlightResponse>>,
+    /// The URL of the request.
+    url: &'a str,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(self.url);
+    }
+}
+
+/// The body of a response, along with its status and content type.
+///
+/// The body is read separately from being parsed, so that the body of a coalesced request can be
+/// shared by all requests waiting for it, each of which parses it into its own response type.
+///
+#[derive(Debug, Clone)]
+struct ResponseBody {
+    /// The status of the response.
+    status: reqwest::StatusCode,
+    /// The `Content-Type` header of the response, if present.
+    content_type: Option<String>,
+    /// The body of the response.
+    body: String,
+}
+
+impl ResponseBody {
+    /// Creates a response body from a body that was cached after a successful response.
+    fn cached(body: String) -> Self {
+        ResponseBody {
+            status: reqwest::StatusCode::OK,
+            content_type: Some("application/hal+json".to_string()),
+            body,
+        }
+    }
+
+    /// Reads the body of a response, discarding it once it exceeds the maximum response size.
+    async fn read(
+        response: reqwest::Response,
+        max_response_size: Option<usize>,
+    ) -> Result<Self, Error> {
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(str::to_string);
+        let body = read_body(response, max_response_size).await?;
+
+        Ok(ResponseBody {
+            status,
+            content_type,
+            body,
+        })
+    }
+
+    /// Parses the body into the given response type, or into an error if the status of the
+    /// response is not `200 OK` or the body is not JSON.
+    fn parse<R: Response>(self, error_body_snippet_length: usize) -> Result<R, Error> {
+        let ResponseBody {
+            status,
+            content_type,
+            body,
+        } = self;
+
+        if !is_json(content_type.as_deref(), &body) {
+            return Err(Error::NonJsonResponse {
+                status: status.as_u16(),
+                content_type,
+                snippet: body_snippet(&body, error_body_snippet_length).unwrap_or_default(),
+            });
+        }
+
+        match status {
+            reqwest::StatusCode::OK => {
+                let snippet = body_snippet(&body, error_body_snippet_length);
+                R::from_json(body).map_err(|e| {
+                    Error::Deserialization(match snippet {
+                        Some(snippet) => format!("{}; response body: {}", e, snippet),
+                        None => e,
+                    })
+                })
+            }
+            _ => Err(Error::from_problem(status.as_u16(), body)),
+        }
+    }
+}
+
+/// Determines whether a response body is JSON.
+///
+/// Horizon responds with `application/hal+json`, or with `application/problem+json` for errors. If
+/// the `Content-Type` header is missing, the body itself is checked to start like a JSON document.
 ///
-/// # Arguments
+fn is_json(content_type: Option<&str>, body: &str) -> bool {
+    match content_type {
+        Some(content_type) => {
+            let media_type = content_type.split(';').next().unwrap_or_default().trim();
+            media_type.eq_ignore_ascii_case("application/json")
+                || media_type.to_ascii_lowercase().ends_with("+json")
+        }
+        None => body.trim_start().starts_with(['{', '[']),
+    }
+}
+
+/// Truncates a response body for inclusion in an error message.
 ///
-/// * `response` - The [`reqwest::Response`] object obtained from the HTTP request.
+/// Returns `None` if `max_length` is `0`. Otherwise the first `max_length` characters of the body
+/// are returned, followed by an ellipsis and the total length if the body was truncated.
 ///
-/// # Returns
+fn body_snippet(body: &str, max_length: usize) -> Option<String> {
+    if max_length == 0 {
+        return None;
+    }
+
+    match body.char_indices().nth(max_length) {
+        Some((index, _)) => Some(format!(
+            "{}... ({} bytes total)",
+            &body[..index],
+            body.len()
+        )),
+        None => Some(body.to_string()),
+    }
+}
+
+/// Validates the format of a given URL.
 ///
-/// On success (HTTP status `OK`), returns a `Result` containing the deserialized
-/// `Response`. If deserialization fails, or if the HTTP status is not `OK`, it returns
-/// an error encapsulated as a `String`.
+/// Builds the HTTP client used to send requests to the Horizon server.
 ///
-/// # Example Usage
-/// This function is not intended to be called directly. It is designed to be called
-/// exclusively by the [`HorizonClient::get`](crate::horizon_client::HorizonClient::get) function.
+/// The client resolves hosts using the given resolver, accepts the media types served by Horizon,
+/// and advertises the given content encodings.
 ///
-/// # Errors
+fn build_http_client(
+    resolver: &Arc<CachingResolver>,
+    accepted_encodings: &[ContentEncoding],
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    default_headers: &reqwest::header::HeaderMap,
+) -> Result<reqwest::Client, String> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static(ACCEPTED_MEDIA_TYPES),
+    );
+    headers.extend(default_headers.clone());
+
+    let mut builder = reqwest::Client::builder()
+        .dns_resolver(resolver.clone())
+        .default_headers(headers)
+        .gzip(accepted_encodings.contains(&ContentEncoding::Gzip))
+        .brotli(accepted_encodings.contains(&ContentEncoding::Brotli))
+        .deflate(accepted_encodings.contains(&ContentEncoding::Deflate));
+    if let Some(timeout) = timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(connect_timeout) = connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
+
+/// Validates a header and inserts it into the given headers, replacing any previous value.
+fn insert_header(
+    headers: &mut reqwest::header::HeaderMap,
+    name: &str,
+    value: &str,
+) -> Result<(), String> {
+    let name = reqwest::header::HeaderName::from_bytes(name.as_bytes())
+        .map_err(|_| format!("Invalid header name: {}", name))?;
+    let value = reqwest::header::HeaderValue::from_str(value)
+        .map_err(|_| format!("Invalid value of header {}", name))?;
+
+    headers.insert(name, value);
+    Ok(())
+}
+
+/// Reads the body of a response, discarding it once it exceeds the maximum response size.
 ///
-/// Errors can arise from various situations, such as:
-/// - Non-`OK` HTTP status codes.
-/// - Failure in reading the response body.
-/// - Deserialization errors when converting the response body into the `Response` type.
+/// A response that announces a larger size in its `Content-Length` header is rejected before any
+/// of its body is read. Otherwise, the body is read chunk by chunk, so that at most the maximum
+/// size is buffered.
 ///
-async fn handle_response<R: Response>(response: reqwest::Response) -> Result<R, String> {
-    match response.status() {
-        reqwest::StatusCode::OK => {
-            let _response = response.text().await.map_err(|e| e.to_string())?;
-            R::from_json(_response)
-        }
-        _ => {
-            let response = response.text().await.map_err(|e| e.to_string())?;
-            Err(response)
+async fn read_body(
+    mut response: reqwest::Response,
+    max_response_size: Option<usize>,
+) -> Result<String, Error> {
+    let Some(max_size) = max_response_size else {
+        return Ok(response.text().await?);
+    };
+    let too_large = Error::ResponseTooLarge {
+        status: response.status().as_u16(),
+        max_size,
+    };
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_size as u64)
+    {
+        return Err(too_large);
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > max_size {
+            return Err(too_large);
         }
+        body.extend_from_slice(&chunk);
     }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
 }
 
-/// Validates the format of a given URL.
-///
 /// This function is an internal utility for validating the format of a URL.
 /// It is typically invoked by [`HorizonClient::new`](crate::horizon_client::HorizonClient::new) to ensure that the URL
 /// provided for initializing the client is correctly formatted. The function checks if
@@ -2380,7 +6340,7 @@ async fn handle_response<R: Response>(response: reqwest::Response) -> Result<R,
 /// it can also be utilized in scenarios where URL validation is necessary before further
 /// processing or usage.
 ///
-fn url_validate(url: &str) -> Result<(), String> {
+pub(crate) fn url_validate(url: &str) -> Result<(), String> {
     // Check if the URL starts with http:// or https://
     if !url.starts_with("http://") && !url.starts_with("https://") {
         return Err(format!("URL must start with http:// or https://: {}", url));
@@ -2395,6 +6355,7 @@ fn url_validate(url: &str) -> Result<(), String> {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::config::RetryConfig;
 
     #[test]
     fn test_url_validate_invalid_url() {
@@ -2411,4 +6372,1048 @@ pub mod tests {
         let result = url_validate("https://horizon-testnet.stellar.org");
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_body_snippet() {
+        assert_eq!(body_snippet("{\"id\":\"1\"}", 0), None);
+        assert_eq!(
+            body_snippet("{\"id\":\"1\"}", 512),
+            Some("{\"id\":\"1\"}".to_string())
+        );
+        assert_eq!(
+            body_snippet("{\"id\":\"1\"}", 4),
+            Some("{\"id... (10 bytes total)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deserialization_error_names_path() {
+        let json = r#"{"_links":{"self":{"href":null},"next":{"href":null},"prev":{"href":null}},"_embedded":{"records":[{"id":1}]}}"#;
+        let result = LedgersResponse::from_json(json.to_string());
+        let error = result.unwrap_err();
+        assert!(error.starts_with("failed to deserialize at `_embedded.records[0]"));
+    }
+
+    fn response(status: u16, content_type: Option<&str>, body: &str) -> reqwest::Response {
+        let mut response = http::Response::builder().status(status);
+        if let Some(content_type) = content_type {
+            response = response.header(reqwest::header::CONTENT_TYPE, content_type);
+        }
+        response.body(body.to_string()).unwrap().into()
+    }
+
+    #[test]
+    fn test_is_json() {
+        assert!(is_json(Some("application/hal+json; charset=utf-8"), ""));
+        assert!(is_json(Some("application/problem+json"), ""));
+        assert!(is_json(Some("Application/JSON"), ""));
+        assert!(!is_json(Some("text/html"), "{}"));
+        assert!(is_json(None, "  {\"status\": 404}"));
+        assert!(!is_json(None, "Bad Gateway"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_non_json_response() {
+        let html = "<html><body><h1>502 Bad Gateway</h1></body></html>";
+        let result: Result<Ledger, Error> =
+            handle_response(response(502, Some("text/html"), html), 16, None).await;
+        assert_eq!(
+            result.unwrap_err(),
+            Error::NonJsonResponse {
+                status: 502,
+                content_type: Some("text/html".to_string()),
+                snippet: "<html><body><h1>... (50 bytes total)".to_string(),
+            }
+        );
+
+        let result: Result<Ledger, Error> =
+            handle_response(response(406, None, "Not Acceptable"), 512, None).await;
+        assert_eq!(result.unwrap_err().status(), Some(406));
+    }
+
+    #[tokio::test]
+    async fn test_handle_error_response() {
+        let problem = r#"{"type":"https://stellar.org/horizon-errors/not_found","status":404}"#;
+        let result: Result<Ledger, Error> = handle_response(
+            response(404, Some("application/problem+json"), problem),
+            512,
+            None,
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Horizon {
+                status: 404,
+                body: problem.to_string(),
+            }
+        );
+
+        let result: Result<Ledger, Error> =
+            handle_response(response(200, Some("application/hal+json"), "{}"), 0, None).await;
+        assert!(matches!(result, Err(Error::Deserialization(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_response_too_large() {
+        let body = r#"{"type":"https://stellar.org/horizon-errors/not_found","status":404}"#;
+        let result: Result<Ledger, Error> = handle_response(
+            response(404, Some("application/problem+json"), body),
+            512,
+            Some(16),
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err(),
+            Error::ResponseTooLarge {
+                status: 404,
+                max_size: 16,
+            }
+        );
+
+        let result: Result<Ledger, Error> = handle_response(
+            response(404, Some("application/problem+json"), body),
+            512,
+            Some(body.len()),
+        )
+        .await;
+        assert!(matches!(result, Err(Error::Horizon { status: 404, .. })));
+    }
+
+    #[test]
+    fn test_set_accepted_encodings() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+        assert_eq!(horizon_client.accepted_encodings(), ContentEncoding::ALL);
+
+        let horizon_client = horizon_client.set_accepted_encodings(&[]).unwrap();
+        assert!(horizon_client.accepted_encodings().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_rejects_failing_endpoint() {
+        // Nothing listens on port 1, so every request fails to connect.
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1")
+            .unwrap()
+            .set_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)).unwrap());
+
+        let request = AllTradesRequest::new();
+        let result = horizon_client.get_all_trades(&request).await;
+        assert!(matches!(result, Err(Error::Http(_))));
+
+        let result = horizon_client.get_all_trades(&request).await;
+        assert!(matches!(
+            result,
+            Err(Error::CircuitOpen { endpoint, .. }) if endpoint == "/trades"
+        ));
+
+        // Requests to other endpoints are still sent.
+        let result = horizon_client.get_all_ledgers(&LedgersRequest::new()).await;
+        assert!(matches!(result, Err(Error::Http(_))));
+    }
+
+    #[tokio::test]
+    async fn test_ping_unreachable_server() {
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+        assert!(matches!(horizon_client.ping().await, Err(Error::Http(_))));
+
+        // Failed pings are yielded, but not recorded.
+        let monitor = LatencyMonitor::new(10);
+        let pings = horizon_client.monitor_latency(&monitor, Duration::from_millis(1));
+        futures::pin_mut!(pings);
+        assert!(matches!(pings.next().await, Some(Err(Error::Http(_)))));
+        assert!(monitor.is_empty());
+    }
+
+    /// Serves the given bodies from a local server, one per connection, and returns the base URL
+    /// of the server and a handle that resolves to the request lines it received.
+    async fn serve(
+        content_type: &'static str,
+        bodies: impl FnOnce(&str) -> Vec<String>,
+    ) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        serve_with_status(content_type, |base_url| {
+            bodies(base_url)
+                .into_iter()
+                .map(|body| ("200 OK", body))
+                .collect()
+        })
+        .await
+    }
+
+    /// Serves the given bodies from a local server like [`serve`], each with its own status.
+    async fn serve_with_status(
+        content_type: &'static str,
+        responses: impl FnOnce(&str) -> Vec<(&'static str, String)>,
+    ) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses(&base_url);
+        let server = tokio::spawn(async move {
+            let mut request_lines = Vec::new();
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = [0; 1024];
+                let length = socket.read(&mut request).await.unwrap();
+                let request = String::from_utf8_lossy(&request[..length]).into_owned();
+                request_lines.push(request.lines().next().unwrap().to_string());
+
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    content_type,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            request_lines
+        });
+
+        (base_url, server)
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        // Two pages of records, followed by an empty page.
+        let (base_url, server) = serve("application/hal+json", |base_url| {
+            [r#"{"id": "1"}, {"id": "2"}"#, r#"{"id": "3"}"#, ""]
+                .iter()
+                .enumerate()
+                .map(|(page, records)| {
+                    format!(
+                        r#"{{"_links": {{"self": {{"href": ""}}, "next": {{"href": "{}/operations?cursor={}&limit=2"}}}}, "_embedded": {{"records": [{}]}}}}"#,
+                        base_url,
+                        page + 1,
+                        records
+                    )
+                })
+                .collect()
+        })
+        .await;
+
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let request = AllOperationsRequest::new().set_limit(2).unwrap();
+        let records: Vec<serde_json::Value> = horizon_client
+            .paginate(&request)
+            .try_collect()
+            .await
+            .unwrap();
+        let ids: Vec<_> = records.iter().map(|record| record["id"].clone()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+
+        let request_lines = server.await.unwrap();
+        assert_eq!(
+            request_lines,
+            [
+                "GET /operations?limit=2 HTTP/1.1",
+                "GET /operations?cursor=1&limit=2 HTTP/1.1",
+                "GET /operations?cursor=2&limit=2 HTTP/1.1",
+            ]
+        );
+
+        // Collecting stops at the maximum number of records, without requesting more pages.
+        let (base_url, server) = serve("application/hal+json", |base_url| {
+            vec![format!(
+                r#"{{"_links": {{"self": {{"href": ""}}, "next": {{"href": "{}/operations?cursor=2&limit=2"}}}}, "_embedded": {{"records": [{{"id": "1"}}, {{"id": "2"}}]}}}}"#,
+                base_url
+            )]
+        })
+        .await;
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let records = horizon_client
+            .get_all_pages::<serde_json::Value>(&request, Some(2))
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(server.await.unwrap().len(), 1);
+
+        // An error ends the stream.
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+        assert!(matches!(
+            horizon_client
+                .get_all_pages::<serde_json::Value>(&request, None)
+                .await,
+            Err(Error::Http(_))
+        ));
+        let records = horizon_client.paginate::<serde_json::Value>(&request);
+        futures::pin_mut!(records);
+        assert!(matches!(records.next().await, Some(Err(Error::Http(_)))));
+        assert!(records.next().await.is_none());
+    }
+
+    #[test]
+    fn test_set_network() {
+        let horizon_client = HorizonClient::for_network(Network::Testnet).unwrap();
+        assert_eq!(horizon_client.network(), Some(&Network::Testnet));
+        assert_eq!(
+            horizon_client.network_passphrase(),
+            Some("Test SDF Network ; September 2015")
+        );
+
+        let horizon_client = HorizonClient::new("https://horizon.example.org")
+            .unwrap()
+            .set_network_passphrase("Private Network ; 2024");
+        assert_eq!(
+            horizon_client.network(),
+            Some(&Network::custom(
+                "https://horizon.example.org",
+                "Private Network ; 2024"
+            ))
+        );
+
+        let horizon_client = horizon_client.set_network_passphrase(Network::Pubnet.passphrase());
+        assert_eq!(horizon_client.network(), Some(&Network::Pubnet));
+    }
+
+    #[tokio::test]
+    async fn test_verify_network_on_submit() {
+        let hash = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+        let root = include_str!("../tests/fixtures/root/root.json");
+        let (base_url, server) = serve_with_status("application/json", |_| {
+            vec![
+                ("200 OK", root.to_string()),
+                ("200 OK", root.to_string()),
+                (
+                    "201 Created",
+                    format!(r#"{{"tx_status": "PENDING", "hash": "{}"}}"#, hash),
+                ),
+                (
+                    "201 Created",
+                    format!(r#"{{"tx_status": "PENDING", "hash": "{}"}}"#, hash),
+                ),
+            ]
+        })
+        .await;
+        let request = PostTransactionAsyncRequest::new()
+            .set_transaction_envelope_xdr("AAAA")
+            .unwrap();
+
+        // The root resource reports the test network, so nothing is submitted to the public one.
+        let horizon_client = HorizonClient::new(&base_url)
+            .unwrap()
+            .set_network(Network::Pubnet)
+            .set_verify_network_on_submit(true);
+        assert!(horizon_client
+            .post_transaction_async(&request)
+            .await
+            .is_err());
+
+        // Setting another network verifies it again, but only before the first submission.
+        let horizon_client = horizon_client.set_network(Network::Testnet);
+        assert!(horizon_client
+            .post_transaction_async(&request)
+            .await
+            .is_ok());
+        assert!(horizon_client
+            .post_transaction_async(&request)
+            .await
+            .is_ok());
+
+        assert_eq!(
+            server.await.unwrap(),
+            vec![
+                "GET / HTTP/1.1",
+                "GET / HTTP/1.1",
+                "POST /transactions_async HTTP/1.1",
+                "POST /transactions_async HTTP/1.1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_health() {
+        let (base_url, server) = serve_with_status("application/json", |_| {
+            vec![
+                (
+                    "200 OK",
+                    r#"{"database_connected":true,"core_up":true,"core_synced":true}"#.to_string(),
+                ),
+                (
+                    "503 Service Unavailable",
+                    r#"{"database_connected":true,"core_up":true,"core_synced":false}"#.to_string(),
+                ),
+                ("503 Service Unavailable", "{}".to_string()),
+            ]
+        })
+        .await;
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+
+        assert!(horizon_client.get_health().await.unwrap().is_healthy());
+
+        let health = horizon_client.get_health().await.unwrap();
+        assert!(!health.is_healthy());
+        assert!(!health.core_synced());
+
+        // A body that does not describe the health is an error, like for any other request.
+        let error = horizon_client.get_health().await.unwrap_err();
+        assert_eq!(error.status(), Some(503));
+
+        let request_lines = server.await.unwrap();
+        assert_eq!(request_lines[0], "GET /health HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_verify_network() {
+        let root = include_str!("../tests/fixtures/root/root.json");
+        let (base_url, server) = serve("application/hal+json", |_| {
+            vec![root.to_string(), root.to_string(), root.to_string()]
+        })
+        .await;
+
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let root_response = horizon_client.verify_network().await.unwrap();
+        assert_eq!(root_response.current_protocol_version(), &22);
+
+        let horizon_client = horizon_client.set_network_passphrase(Network::Testnet.passphrase());
+        assert!(horizon_client.verify_network().await.is_ok());
+
+        let horizon_client = horizon_client.set_network_passphrase(Network::Pubnet.passphrase());
+        let error = horizon_client.verify_network().await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Horizon server at {} serves network \"Test SDF Network ; September 2015\", expected \"Public Global Stellar Network ; September 2015\"",
+                base_url
+            )
+        );
+
+        assert_eq!(server.await.unwrap(), vec!["GET / HTTP/1.1"; 3]);
+    }
+
+    #[tokio::test]
+    async fn test_follow() {
+        let page = r#"{"_links": {"self": {"href": ""}}, "_embedded": {"records": []}}"#;
+        let (base_url, server) = serve("application/hal+json", |_| {
+            vec![page.to_string(), page.to_string()]
+        })
+        .await;
+
+        let json = include_str!("../tests/fixtures/transactions/single_transaction.json")
+            .replace("https://horizon-testnet.stellar.org", &base_url);
+        let transaction = TransactionResponse::from_json(json).unwrap();
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let operations = transaction.operations(&horizon_client).await.unwrap();
+        assert!(operations.embedded().records().is_empty());
+
+        let mut page_params = PageParams::new();
+        page_params.set_limit(2).unwrap();
+        let href = transaction.links().effects().href().clone().unwrap();
+        let effects: EffectsResponse = horizon_client.follow(&href, &page_params).await.unwrap();
+        assert!(effects.embedded().records().is_empty());
+
+        let hash = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+        assert_eq!(
+            server.await.unwrap(),
+            [
+                format!("GET /transactions/{}/operations HTTP/1.1", hash),
+                format!("GET /transactions/{}/effects?limit=2 HTTP/1.1", hash),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_transaction_async() {
+        let hash = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+        let (base_url, server) = serve_with_status("application/json", |_| {
+            vec![
+                (
+                    "201 Created",
+                    format!(r#"{{"tx_status": "PENDING", "hash": "{}"}}"#, hash),
+                ),
+                (
+                    "409 Conflict",
+                    format!(r#"{{"tx_status": "DUPLICATE", "hash": "{}"}}"#, hash),
+                ),
+                (
+                    "400 Bad Request",
+                    format!(
+                        r#"{{"tx_status": "ERROR", "hash": "{}", "errorResultXdr": "AAAAAAAAAGT////7AAAAAA=="}}"#,
+                        hash
+                    ),
+                ),
+                (
+                    "400 Bad Request",
+                    r#"{"type": "https://stellar.org/horizon-errors/transaction_malformed", "status": 400}"#
+                        .to_string(),
+                ),
+            ]
+        })
+        .await;
+
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let request = PostTransactionAsyncRequest::new()
+            .set_transaction_envelope_xdr("AAAA")
+            .unwrap();
+
+        let response = horizon_client
+            .post_transaction_async(&request)
+            .await
+            .unwrap();
+        assert_eq!(*response.tx_status(), AsyncTransactionStatus::Pending);
+        assert_eq!(response.hash().as_str(), hash);
+        assert_eq!(response.decoded_error_result_xdr(), Ok(None));
+
+        let response = horizon_client
+            .post_transaction_async(&request)
+            .await
+            .unwrap();
+        assert_eq!(*response.tx_status(), AsyncTransactionStatus::Duplicate);
+
+        let response = horizon_client
+            .post_transaction_async(&request)
+            .await
+            .unwrap();
+        assert_eq!(*response.tx_status(), AsyncTransactionStatus::Error);
+        let result = response.decoded_error_result_xdr().unwrap().unwrap();
+        assert_eq!(result.fee_charged, 100);
+
+        let error = horizon_client
+            .post_transaction_async(&request)
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(400));
+
+        assert_eq!(
+            server.await.unwrap(),
+            vec!["POST /transactions_async HTTP/1.1"; 4]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_transaction() {
+        let hash = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+        let (base_url, server) = serve_with_status("application/json", |_| {
+            vec![
+                (
+                    "404 Not Found",
+                    r#"{"type": "https://stellar.org/horizon-errors/not_found", "status": 404}"#
+                        .to_string(),
+                ),
+                (
+                    "200 OK",
+                    include_str!("../tests/fixtures/transactions/single_transaction.json")
+                        .to_string(),
+                ),
+            ]
+        })
+        .await;
+
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let transaction = horizon_client
+            .wait_for_transaction(hash, Duration::from_millis(1), 3)
+            .await
+            .unwrap();
+        assert_eq!(transaction.hash().as_str(), hash);
+        assert_eq!(server.await.unwrap().len(), 2);
+
+        // A transaction that is never found.
+        let (base_url, _server) = serve_with_status("application/json", |_| {
+            vec![(
+                "404 Not Found",
+                r#"{"type": "https://stellar.org/horizon-errors/not_found", "status": 404}"#
+                    .to_string(),
+            )]
+        })
+        .await;
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let error = horizon_client
+            .wait_for_transaction(hash, Duration::from_millis(1), 1)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Transaction {} was not included in a ledger in time", hash)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_sse_reconnects() {
+        // A server that sends a single record per connection, and then closes it.
+        let (base_url, server) = serve("text/event-stream", |_| {
+            (1..=2)
+                .map(|id| {
+                    format!(
+                        "retry: 10\nevent: open\ndata: \"hello\"\n\nid: {}\ndata: {{\"id\": {}}}\n\n",
+                        id, id
+                    )
+                })
+                .collect()
+        })
+        .await;
+
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let stream = horizon_client
+            .stream_sse::<serde_json::Value>(&AllEffectsRequest::new(), StreamOptions::new());
+        let records: Vec<_> = stream.take(2).try_collect().await.unwrap();
+        assert_eq!(records[0]["id"], 1);
+        assert_eq!(records[1]["id"], 2);
+
+        // The stream resumes after the last record it received.
+        let request_lines = server.await.unwrap();
+        assert_eq!(request_lines[0], "GET /effects HTTP/1.1");
+        assert_eq!(request_lines[1], "GET /effects?cursor=1 HTTP/1.1");
+
+        // Failing to connect ends the stream.
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+        let stream = horizon_client.stream_effects(&AllEffectsRequest::new());
+        futures::pin_mut!(stream);
+        assert!(matches!(stream.next().await, Some(Err(Error::Http(_)))));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_sse_invalid_record() {
+        let (base_url, _server) = serve("text/event-stream", |_| {
+            vec!["id: 1\ndata: {\"id\": 1}\n\nid: 2\ndata: invalid\n\n".to_string()]
+        })
+        .await;
+
+        // The record before the invalid one is yielded, and then the error, with the URL of the
+        // stream.
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let stream = horizon_client
+            .stream_sse::<serde_json::Value>(&AllEffectsRequest::new(), StreamOptions::new());
+        futures::pin_mut!(stream);
+        assert_eq!(stream.next().await.unwrap().unwrap()["id"], 1);
+        match stream.next().await {
+            Some(Err(Error::Deserialization(message))) => {
+                assert!(message.starts_with(&format!("{}/effects: ", base_url)))
+            }
+            other => panic!("expected a deserialization error, got {:?}", other),
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_order_book() {
+        let snapshot = |amount: &str| {
+            format!(
+                concat!(
+                    r#"{{"bids":[{{"price_r":{{"n":1,"d":5}},"price":"0.2000000","amount":"{}"}}],"#,
+                    r#""asks":[],"base":{{"asset_type":"native"}},"#,
+                    r#""counter":{{"asset_type":"credit_alphanum4","asset_code":"USDC","#,
+                    r#""asset_issuer":"GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"}}}}"#
+                ),
+                amount
+            )
+        };
+        let (base_url, server) = serve("text/event-stream", move |_| {
+            vec![format!(
+                "retry: 10\nevent: open\ndata: \"hello\"\n\ndata: {}\n\ndata: {}\n\n",
+                snapshot("10.0000000"),
+                snapshot("12.5000000")
+            )]
+        })
+        .await;
+
+        let horizon_client = HorizonClient::new(&base_url).unwrap();
+        let usdc = AssetType::issued(
+            "USDC",
+            "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
+        );
+        let stream = horizon_client.stream_order_book(AssetType::Native, usdc);
+        let snapshots: Vec<_> = stream.take(2).try_collect().await.unwrap();
+        assert_eq!(snapshots[0].bids()[0].amount(), "10.0000000");
+
+        let diff = snapshots[1].diff(&snapshots[0]);
+        assert_eq!(diff.bids().len(), 1);
+        assert!(diff.asks().is_empty());
+
+        let request_lines = server.await.unwrap();
+        assert_eq!(
+            request_lines[0],
+            "GET /order_book?selling_asset_type=native&buying_asset_type=credit_alphanum4\
+             &buying_asset_code=USDC\
+             &buying_asset_issuer=GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5 HTTP/1.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_retries_failed_requests() {
+        let config = HorizonClientConfig {
+            retry: Some(RetryConfig {
+                max_retries: 2,
+                initial_backoff_ms: 1,
+                max_backoff_ms: 1,
+            }),
+            headers: BTreeMap::from([("X-Client-Name".to_string(), "tests".to_string())]),
+            ..HorizonClientConfig::new("http://127.0.0.1:1")
+        };
+        let horizon_client = HorizonClient::from_config(&config).unwrap();
+
+        let result = horizon_client
+            .get_all_trades(&AllTradesRequest::new())
+            .await;
+        assert!(matches!(result, Err(Error::Http(_))));
+        assert_eq!(*horizon_client.connection_stats().requests(), 3);
+    }
+
+    #[test]
+    fn test_record_latest_ledger() {
+        let headers = |latest_ledger: &'static str| {
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert(
+                "Latest-Ledger",
+                reqwest::header::HeaderValue::from_static(latest_ledger),
+            );
+            headers
+        };
+
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+        assert_eq!(horizon_client.latest_ledger(), None);
+        horizon_client
+            .record_latest_ledger(&headers("100"))
+            .unwrap();
+        horizon_client.record_latest_ledger(&headers("90")).unwrap();
+        assert_eq!(horizon_client.latest_ledger(), Some(90));
+
+        let horizon_client = horizon_client.set_max_ledger_lag(5);
+        horizon_client.record_latest_ledger(&headers("95")).unwrap();
+        assert_eq!(
+            horizon_client.record_latest_ledger(&headers("94")),
+            Err(Error::StaleResponse {
+                latest_ledger: 94,
+                highest_ledger: 100,
+            })
+        );
+        horizon_client
+            .record_latest_ledger(&headers("101"))
+            .unwrap();
+        assert!(horizon_client.record_latest_ledger(&headers("95")).is_err());
+
+        // Responses without the header are not checked.
+        horizon_client
+            .record_latest_ledger(&reqwest::header::HeaderMap::new())
+            .unwrap();
+        assert_eq!(horizon_client.latest_ledger(), None);
+    }
+
+    #[tokio::test]
+    async fn test_protocol_version_omits_unsupported_parameters() {
+        let request = AccountsRequest::new().set_liquidity_pool_filter("0b3c88caa5aeada2");
+
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+        let error = horizon_client.get_account_list(&request).await.unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("liquidity_pool=0b3c88caa5aeada2"));
+
+        // Liquidity pools were introduced in protocol 18.
+        let horizon_client = horizon_client.set_protocol_version(17);
+        let error = horizon_client.get_account_list(&request).await.unwrap_err();
+        assert!(error.to_string().contains("http://127.0.0.1:1/accounts"));
+        assert!(!error.to_string().contains("liquidity_pool"));
+    }
+
+    #[tokio::test]
+    async fn test_request_coalescing() {
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1")
+            .unwrap()
+            .set_request_coalescing(true);
+
+        // Equal requests that are sent simultaneously share a single request.
+        let request = AllTradesRequest::new();
+        let equal_request = AllTradesRequest::new();
+        assert!(request == equal_request);
+        let (first, second) = tokio::join!(
+            horizon_client.get_all_trades(&request),
+            horizon_client.get_all_trades(&equal_request),
+        );
+        assert!(matches!(first, Err(Error::Http(_))));
+        assert!(first
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("http://127.0.0.1:1/trades"));
+        assert_eq!(first.unwrap_err(), second.unwrap_err());
+        assert_eq!(*horizon_client.connection_stats().requests(), 1);
+
+        // Once completed, the request is sent again.
+        let result = horizon_client.get_all_trades(&request).await;
+        assert!(matches!(result, Err(Error::Http(_))));
+        assert_eq!(*horizon_client.connection_stats().requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_poll_order_book() {
+        use crate::models::prelude::AssetType;
+
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+
+        // Errors are yielded, after which polling continues.
+        let snapshots = horizon_client.poll_order_book(
+            AssetPair::new(AssetType::Native, AssetType::Native),
+            Duration::from_millis(1),
+        );
+        let results: Vec<_> = snapshots.take(2).collect().await;
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Err(Error::Http(_)))));
+        assert!(results[0]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("http://127.0.0.1:1/order_book?selling_asset_type=native"));
+        assert_eq!(*horizon_client.connection_stats().requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_watch_new_accounts() {
+        use crate::streaming::CancellationToken;
+
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+        let request = AccountsRequest::new()
+            .set_sponsor_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+            .unwrap();
+
+        // Errors are yielded, after which polling continues.
+        let accounts = horizon_client.watch_new_accounts(&request, Duration::from_millis(1));
+        let results: Vec<_> = accounts.take(2).collect().await;
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Err(Error::Http(_)))));
+
+        // A cancelled shutdown token ends the stream.
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+        let accounts = horizon_client.watch_new_accounts_with_options(
+            &request,
+            Duration::from_millis(1),
+            None,
+            StreamOptions::new().set_shutdown_token(shutdown),
+        );
+        assert_eq!(accounts.count().await, 0);
+        assert_eq!(*horizon_client.connection_stats().requests(), 2);
+    }
+
+    /// A cache that ignores the directives, for testing.
+    #[derive(Default)]
+    struct TestCache(Mutex<HashMap<String, String>>);
+
+    impl ResponseCache for TestCache {
+        fn get(&self, url: &str) -> Option<String> {
+            self.0.lock().unwrap().get(url).cloned()
+        }
+
+        fn insert(&self, url: &str, body: String, _directive: CacheDirective) {
+            self.0.lock().unwrap().insert(url.to_string(), body);
+        }
+
+        fn invalidate(&self, url: &str) {
+            self.0.lock().unwrap().remove(url);
+        }
+
+        fn invalidate_matching(&self, predicate: &dyn Fn(&str) -> bool) {
+            self.0.lock().unwrap().retain(|url, _| !predicate(url));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache() {
+        let cache = TestCache::default();
+        let ledger = include_str!("../tests/fixtures/ledgers/single_ledger.json");
+        cache.insert(
+            "http://127.0.0.1:1/ledgers/1049845",
+            ledger.to_string(),
+            CacheDirective::Immutable,
+        );
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1")
+            .unwrap()
+            .set_cache(cache);
+
+        // Cached resources are served without a request.
+        let request = SingleLedgerRequest::new().set_sequence(1049845).unwrap();
+        let response = horizon_client.get_single_ledger(&request).await.unwrap();
+        assert_eq!(response.sequence().value(), 1049845);
+        assert_eq!(*horizon_client.connection_stats().requests(), 0);
+
+        // Once invalidated, the resource is requested again.
+        horizon_client.invalidate_cached_kind(ResourceKind::Account);
+        assert!(horizon_client.get_single_ledger(&request).await.is_ok());
+        horizon_client.invalidate_cached(&request);
+        let result = horizon_client.get_single_ledger(&request).await;
+        assert!(matches!(result, Err(Error::Http(_))));
+        assert_eq!(*horizon_client.connection_stats().requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_for_operation() {
+        let horizon_client = HorizonClient::new("http://127.0.0.1:1").unwrap();
+
+        // Operations that cannot produce trades have no trades link.
+        let json = include_str!("../tests/fixtures/operations/single_operation.json");
+        let operation = Operation::from_json(json.to_string()).unwrap();
+        let result = horizon_client.get_trades_for_operation(&operation).await;
+        assert!(matches!(result, Err(Error::Other(_))));
+        assert_eq!(*horizon_client.connection_stats().requests(), 0);
+
+        // The template parameters of the trades link are stripped.
+        let json = include_str!("../tests/fixtures/operations/path_payment.json")
+            .replace("https://horizon-testnet.stellar.org", "http://127.0.0.1:1");
+        let operation = Operation::from_json(json).unwrap();
+        let result = horizon_client.get_trades_for_operation(&operation).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("http://127.0.0.1:1/operations/23944442687489/trades)"));
+    }
+
+    #[test]
+    fn test_requests_are_hashable() {
+        use crate::accounts::accounts_request::filters::*;
+
+        fn assert_hashable<T: Eq + std::hash::Hash>() {}
+
+        assert_hashable::<
+            AccountsRequest<SponsorFilter, SignerFilter, NoAssetFilter, NoLiquidityPoolFilter>,
+        >();
+        assert_hashable::<
+            AccountsRequest<
+                NoSponsorFilter,
+                NoSignerFilter,
+                AssetFilter<IssuedAsset>,
+                NoLiquidityPoolFilter,
+            >,
+        >();
+        assert_hashable::<
+            AccountsRequest<NoSponsorFilter, NoSignerFilter, NoAssetFilter, LiquidityPoolFilter>,
+        >();
+        assert_hashable::<AllAssetsRequest>();
+        assert_hashable::<AllClaimableBalancesRequest>();
+        assert_hashable::<AllEffectsRequest>();
+        assert_hashable::<AllLiquidityPoolsRequest>();
+        assert_hashable::<AllOffersRequest>();
+        assert_hashable::<AllOperationsRequest>();
+        assert_hashable::<AllPaymentsRequest>();
+        assert_hashable::<AllTradesRequest>();
+        assert_hashable::<AllTransactionsRequest>();
+        assert_hashable::<ClaimableBalancesForSponsorRequest<ClaimableBalanceSponsor>>();
+        assert_hashable::<DetailsRequest<SellingAsset, BuyingAsset>>();
+        assert_hashable::<EffectForTransactionRequest>();
+        assert_hashable::<EffectsForAccountRequest>();
+        assert_hashable::<EffectsForLedgerRequest>();
+        assert_hashable::<EffectsForLiquidityPoolRequest>();
+        assert_hashable::<EffectsForOperationRequest>();
+        assert_hashable::<FeeStatsRequest>();
+        assert_hashable::<
+            FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount>,
+        >();
+        assert_hashable::<LedgersRequest>();
+        assert_hashable::<
+            ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source>,
+        >();
+        assert_hashable::<ListStrictSendPaymentPathsRequest<SourceAsset, SourceAmount, Destination>>(
+        );
+        assert_hashable::<OffersForAccountRequest<OfferAccountId>>();
+        assert_hashable::<OperationsForAccountRequest>();
+        assert_hashable::<OperationsForLedgerRequest>();
+        assert_hashable::<OperationsForLiquidityPoolRequest>();
+        assert_hashable::<OperationsForTransactionRequest>();
+        assert_hashable::<PaymentsForAccountRequest>();
+        assert_hashable::<PaymentsForLedgerRequest>();
+        assert_hashable::<PaymentsForTransactionRequest>();
+        assert_hashable::<PostTransactionRequest<TransactionEnvelope>>();
+        assert_hashable::<SingleAccountRequest<AccountId>>();
+        assert_hashable::<SingleClaimableBalanceRequest<ClaimableBalanceId>>();
+        assert_hashable::<SingleLedgerRequest<Sequence>>();
+        assert_hashable::<SingleLiquidityPoolRequest<LiquidityPoolId>>();
+        assert_hashable::<SingleOfferRequest<OfferId>>();
+        assert_hashable::<SingleOperationRequest<OperationId>>();
+        assert_hashable::<SingleTransactionRequest<TransactionHash>>();
+        assert_hashable::<TradeAggregationsRequest<BaseAsset, CounterAsset, Resolution>>();
+        assert_hashable::<TradesForAccountRequest<TradeAccountId>>();
+        assert_hashable::<TradesForLiquidityPoolRequest<TradeLiquidityPoolId>>();
+        assert_hashable::<TradesForOfferRequest<OfferId>>();
+        assert_hashable::<TransactionsForAccountRequest<TransactionsAccountId>>();
+        assert_hashable::<TransactionsForLedgerRequest<TransactionsLedgerId>>();
+        assert_hashable::<TransactionsForLiquidityPoolRequest<TransactionsLiquidityPoolId>>();
+    }
+
+    #[test]
+    fn test_requests_new_is_default() {
+        fn assert_new_is_default<T: Default + PartialEq>(request: T) {
+            assert!(request == T::default(), "{}", std::any::type_name::<T>());
+        }
+
+        assert_new_is_default(AccountsRequest::new());
+        assert_new_is_default(AllAssetsRequest::new());
+        assert_new_is_default(AllClaimableBalancesRequest::new());
+        assert_new_is_default(AllEffectsRequest::new());
+        assert_new_is_default(AllLiquidityPoolsRequest::new());
+        assert_new_is_default(AllOffersRequest::new());
+        assert_new_is_default(AllOperationsRequest::new());
+        assert_new_is_default(AllPaymentsRequest::new());
+        assert_new_is_default(AllTradesRequest::new());
+        assert_new_is_default(AllTransactionsRequest::new());
+        assert_new_is_default(ClaimableBalancesForSponsorRequest::new());
+        assert_new_is_default(DetailsRequest::new());
+        assert_new_is_default(EffectForTransactionRequest::new());
+        assert_new_is_default(EffectsForAccountRequest::new());
+        assert_new_is_default(EffectsForLedgerRequest::new());
+        assert_new_is_default(EffectsForLiquidityPoolRequest::new());
+        assert_new_is_default(EffectsForOperationRequest::new());
+        assert_new_is_default(FeeStatsRequest::new());
+        assert_new_is_default(FindPaymentsPathRequest::new());
+        assert_new_is_default(LedgersRequest::new());
+        assert_new_is_default(ListStrictReceivePaymentPathsRequest::new());
+        assert_new_is_default(ListStrictSendPaymentPathsRequest::new());
+        assert_new_is_default(OffersForAccountRequest::new());
+        assert_new_is_default(OperationsForAccountRequest::new());
+        assert_new_is_default(OperationsForLedgerRequest::new());
+        assert_new_is_default(OperationsForLiquidityPoolRequest::new());
+        assert_new_is_default(OperationsForTransactionRequest::new());
+        assert_new_is_default(PaymentsForAccountRequest::new());
+        assert_new_is_default(PaymentsForLedgerRequest::new());
+        assert_new_is_default(PaymentsForTransactionRequest::new());
+        assert_new_is_default(PostTransactionRequest::new());
+        assert_new_is_default(SingleAccountRequest::new());
+        assert_new_is_default(SingleClaimableBalanceRequest::new());
+        assert_new_is_default(SingleLedgerRequest::new());
+        assert_new_is_default(SingleLiquidityPoolRequest::new());
+        assert_new_is_default(SingleOfferRequest::new());
+        assert_new_is_default(SingleOperationRequest::new());
+        assert_new_is_default(SingleTransactionRequest::new());
+        assert_new_is_default(TradeAggregationsRequest::new());
+        assert_new_is_default(TradesForAccountRequest::new());
+        assert_new_is_default(TradesForLiquidityPoolRequest::new());
+        assert_new_is_default(TradesForOfferRequest::new());
+        assert_new_is_default(TransactionsForAccountRequest::new());
+        assert_new_is_default(TransactionsForLedgerRequest::new());
+        assert_new_is_default(TransactionsForLiquidityPoolRequest::new());
+    }
+
+    #[test]
+    fn test_reset_pagination() {
+        let request = AllPaymentsRequest::new()
+            .set_cursor(42)
+            .unwrap()
+            .set_max_limit()
+            .set_order(Order::Desc)
+            .unwrap()
+            .reset_pagination();
+        assert!(request == AllPaymentsRequest::new());
+
+        let request = AccountsRequest::new()
+            .set_sponsor_filter("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+            .unwrap()
+            .set_cursor(42)
+            .unwrap()
+            .reset_pagination();
+        assert_eq!(
+            request.build_url(""),
+            "/accounts?sponsor=GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7"
+        );
+    }
+
+    #[test]
+    fn test_from_config_invalid() {
+        let config = HorizonClientConfig {
+            headers: BTreeMap::from([("X Client".to_string(), "tests".to_string())]),
+            ..HorizonClientConfig::new("https://horizon-testnet.stellar.org")
+        };
+        assert_eq!(
+            HorizonClient::from_config(&config).err().unwrap(),
+            "Invalid header name: X Client"
+        );
+    }
 }
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the sequence number of a ledger.
+///
+/// Ledger sequence numbers are positive 32-bit integers, starting at `1` for the genesis ledger.
+/// A `LedgerSequence` is validated once, so that requests can not be built for a sequence number
+/// that can never refer to a ledger, and so that a ledger sequence can not be passed where, for
+/// example, a transaction hash or an operation ID is expected.
+///
+/// A `LedgerSequence` is (de)serialized as a number, matching the representation used by Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::ledgers::prelude::LedgerSequence;
+///
+/// let sequence: LedgerSequence = "1234567".parse().unwrap();
+/// assert_eq!(sequence.value(), 1234567);
+///
+/// assert!("0".parse::<LedgerSequence>().is_err());
+/// assert!("4294967296".parse::<LedgerSequence>().is_err());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
+pub struct LedgerSequence(u32);
+
+impl LedgerSequence {
+    /// Creates a new `LedgerSequence`.
+    ///
+    /// # Arguments
+    /// * `sequence` - The numeric ledger sequence number.
+    ///
+    /// # Returns
+    /// The `LedgerSequence`, or an error if the sequence number is `0`.
+    ///
+    pub fn new(sequence: u32) -> Result<Self, String> {
+        if sequence < 1 {
+            return Err("sequence must be greater than or equal to 1".to_string());
+        }
+
+        Ok(LedgerSequence(sequence))
+    }
+
+    /// Returns the numeric value of the ledger sequence number.
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl FromStr for LedgerSequence {
+    type Err = String;
+
+    fn from_str(sequence: &str) -> Result<Self, Self::Err> {
+        let sequence = sequence
+            .parse::<u32>()
+            .map_err(|_| format!("invalid ledger sequence: {}", sequence))?;
+
+        LedgerSequence::new(sequence)
+    }
+}
+
+impl TryFrom<u32> for LedgerSequence {
+    type Error = String;
+
+    fn try_from(sequence: u32) -> Result<Self, Self::Error> {
+        LedgerSequence::new(sequence)
+    }
+}
+
+impl TryFrom<String> for LedgerSequence {
+    type Error = String;
+
+    fn try_from(sequence: String) -> Result<Self, Self::Error> {
+        sequence.parse()
+    }
+}
+
+impl From<LedgerSequence> for u32 {
+    fn from(sequence: LedgerSequence) -> Self {
+        sequence.0
+    }
+}
+
+impl From<LedgerSequence> for String {
+    fn from(sequence: LedgerSequence) -> Self {
+        sequence.to_string()
+    }
+}
+
+impl fmt::Display for LedgerSequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger_sequence() {
+        assert_eq!(
+            "1234567".parse::<LedgerSequence>().unwrap().value(),
+            1234567
+        );
+        assert_eq!(
+            "0".parse::<LedgerSequence>().unwrap_err(),
+            "sequence must be greater than or equal to 1"
+        );
+        assert_eq!(
+            "-1".parse::<LedgerSequence>().unwrap_err(),
+            "invalid ledger sequence: -1"
+        );
+        assert!(LedgerSequence::try_from(u32::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_ledger_sequence_serde() {
+        let sequence: LedgerSequence = serde_json::from_str("1888").unwrap();
+        assert_eq!(sequence.value(), 1888);
+        assert_eq!(serde_json::to_string(&sequence).unwrap(), "1888");
+        assert!(serde_json::from_str::<LedgerSequence>("0").is_err());
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use super::ledger_sequence::LedgerSequence;
+use crate::datetime::DateTime;
+use crate::models::pagination::PageParams;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to fetch ledger data from the Stellar Horizon API.
 ///
@@ -25,10 +27,45 @@ use stellar_rust_sdk_derive::pagination;
 /// // The request can now be used with a Horizon client to fetch ledgers.
 /// ```
 ///
+/// # Time filters
+/// Horizon can not filter ledgers by the time at which they were closed. When
+/// [`set_closed_after`](Self::set_closed_after) or [`set_closed_before`](Self::set_closed_before)
+/// is used, [`HorizonClient::get_all_ledgers`](crate::horizon_client::HorizonClient::get_all_ledgers)
+/// first looks up the sequence numbers of the ledgers closed at these moments using a binary
+/// search, and then only returns the ledgers within that range. Every binary search sends about
+/// `log2(n)` additional requests for single ledgers, where `n` is the number of ledgers between
+/// the search bounds; over the full history of the public network, that is about 26 requests.
+/// Unless [`set_search_bounds`](Self::set_search_bounds) is used, the search covers the ledgers
+/// known to the Horizon server, which takes two more requests to determine.
+///
+/// ```rust
+/// # use stellar_rs::ledgers::prelude::*;
+/// # use chrono::{TimeZone, Utc};
+/// let request = LedgersRequest::new()
+///     .set_closed_after(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap())
+///     .unwrap()
+///     .set_closed_before(Utc.with_ymd_and_hms(2024, 6, 1, 0, 1, 0).unwrap())
+///     .unwrap()
+///     .set_search_bounds(1_000_000, 2_000_000)
+///     .unwrap();
+/// ```
+///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::LEDGERS_PATH)]
 pub struct LedgersRequest {
-    // All fields are injected by the `pagination` macro.
+    /// The moment at or after which the returned ledgers were closed.
+    #[horizon(skip)]
+    closed_after: Option<DateTime>,
+    /// The moment before which the returned ledgers were closed.
+    #[horizon(skip)]
+    closed_before: Option<DateTime>,
+    /// The range of ledger sequence numbers in which the time filters are looked up.
+    #[horizon(skip)]
+    search_bounds: Option<(LedgerSequence, LedgerSequence)>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl LedgersRequest {
@@ -36,31 +73,111 @@ impl LedgersRequest {
     pub fn new() -> Self {
         LedgersRequest::default()
     }
-}
 
-impl Request for LedgersRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
+    /// Only returns the ledgers closed at or after the given moment.
+    ///
+    /// # Arguments
+    /// * `closed_after` - The earliest closing time of the returned ledgers.
+    ///
+    /// # Returns
+    /// The `LedgersRequest` with the filter set, or an error if the moment is not before the
+    /// moment set with [`set_closed_before`](Self::set_closed_before).
+    ///
+    pub fn set_closed_after(self, closed_after: DateTime) -> Result<Self, String> {
+        if self
+            .closed_before
+            .is_some_and(|before| closed_after >= before)
+        {
+            return Err("closed_after must be before closed_before".to_string());
+        }
+
+        Ok(LedgersRequest {
+            closed_after: Some(closed_after),
+            ..self
+        })
+    }
+
+    /// Only returns the ledgers closed before the given moment.
+    ///
+    /// # Arguments
+    /// * `closed_before` - The moment before which the returned ledgers were closed.
+    ///
+    /// # Returns
+    /// The `LedgersRequest` with the filter set, or an error if the moment is not after the
+    /// moment set with [`set_closed_after`](Self::set_closed_after).
+    ///
+    pub fn set_closed_before(self, closed_before: DateTime) -> Result<Self, String> {
+        if self
+            .closed_after
+            .is_some_and(|after| after >= closed_before)
+        {
+            return Err("closed_after must be before closed_before".to_string());
+        }
+
+        Ok(LedgersRequest {
+            closed_before: Some(closed_before),
+            ..self
+        })
+    }
+
+    /// Sets the range of ledgers in which the time filters are looked up.
+    ///
+    /// Narrowing the range reduces the number of requests of the binary search, and avoids
+    /// looking up the ledgers known to the Horizon server. Both ledgers must be available on the
+    /// Horizon server.
+    ///
+    /// # Arguments
+    /// * `lower` - The sequence number of the first ledger of the range.
+    /// * `upper` - The sequence number of the last ledger of the range.
+    ///
+    /// # Returns
+    /// The `LedgersRequest` with the search bounds set, or an error if a sequence number is
+    /// invalid or if `lower` is greater than `upper`.
+    ///
+    pub fn set_search_bounds(self, lower: u32, upper: u32) -> Result<Self, String> {
+        if lower > upper {
+            return Err("lower bound must not be greater than upper bound".to_string());
+        }
+
+        Ok(LedgersRequest {
+            search_bounds: Some((LedgerSequence::new(lower)?, LedgerSequence::new(upper)?)),
+            ..self
+        })
+    }
+
+    /// Returns the moment at or after which the returned ledgers were closed, if set.
+    pub(crate) fn closed_after(&self) -> Option<DateTime> {
+        self.closed_after
+    }
+
+    /// Returns the moment before which the returned ledgers were closed, if set.
+    pub(crate) fn closed_before(&self) -> Option<DateTime> {
+        self.closed_before
+    }
+
+    /// Returns the search bounds of the time filters, if set.
+    pub(crate) fn search_bounds(&self) -> Option<(LedgerSequence, LedgerSequence)> {
+        self.search_bounds
     }
 
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::LEDGERS_PATH,
-            self.get_query_parameters()
-        )
+    /// Returns a copy of the pagination parameters of the request, with the given cursor and
+    /// without time filters.
+    pub(crate) fn with_cursor(&self, cursor: Option<u64>) -> LedgersRequest {
+        LedgersRequest {
+            page_params: PageParams {
+                cursor,
+                ..self.page_params.clone()
+            },
+            ..Default::default()
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::datetime;
+    use crate::models::Request;
 
     #[test]
     fn test_ledgers_request() {
@@ -70,4 +187,29 @@ mod tests {
             "https://horizon-testnet.stellar.org/ledgers"
         );
     }
+
+    #[test]
+    fn test_ledgers_request_time_filters() {
+        let after = datetime::parse_rfc3339("2024-06-01T00:00:00Z").unwrap();
+        let before = datetime::parse_rfc3339("2024-06-02T00:00:00Z").unwrap();
+
+        let request = LedgersRequest::new()
+            .set_closed_after(after)
+            .unwrap()
+            .set_closed_before(before)
+            .unwrap();
+        assert_eq!(request.closed_after(), Some(after));
+        assert_eq!(request.closed_before(), Some(before));
+
+        // Time filters are resolved by the client, and are not part of the query.
+        assert_eq!(request.get_query_parameters(), "");
+
+        assert!(LedgersRequest::new()
+            .set_closed_before(after)
+            .unwrap()
+            .set_closed_after(before)
+            .is_err());
+        assert!(LedgersRequest::new().set_search_bounds(2, 1).is_err());
+        assert!(LedgersRequest::new().set_search_bounds(0, 1).is_err());
+    }
 }
@@ -37,6 +37,14 @@ pub mod response;
 ///
 pub mod single_ledger_request;
 
+/// Provides the `LedgerSequence`.
+///
+/// This module provides the `LedgerSequence` struct, a validated ledger sequence number that is
+/// shared by the ledger-related requests and responses, and by the requests for the effects,
+/// operations, payments and transactions of a ledger.
+///
+pub mod ledger_sequence;
+
 /// The base path for ledger-related endpoints in the Horizon API.
 ///
 /// # Usage
@@ -61,6 +69,7 @@ pub(crate) static LEDGERS_PATH: &str = "ledgers";
 /// The `prelude` module includes the following re-exports:
 ///
 /// * From `ledgers_request`: All items (e.g., `LedgersRequest`).
+/// * From `ledger_sequence`: All items (e.g., `LedgerSequence`).
 /// * From `ledgers_response`: All items (e.g., `LedgersResponse`, `Record`, etc.).
 /// * From `single_ledger_request`: All items (e.g., `SingleLedgerRequest`).
 /// * From `single_ledger_response`: All items (e.g., `SingleLedgerResponse`, `Links`, etc.).
@@ -77,6 +86,7 @@ pub(crate) static LEDGERS_PATH: &str = "ledgers";
 /// ```
 ///
 pub mod prelude {
+    pub use super::ledger_sequence::*;
     pub use super::ledgers_request::*;
     pub use super::response::*;
     pub use super::single_ledger_request::*;
@@ -85,6 +95,7 @@ pub mod prelude {
 #[cfg(test)]
 pub mod tests {
     use super::prelude::*;
+    use crate::datetime;
     use crate::horizon_client::HorizonClient;
     use base64::{engine::general_purpose, Engine};
 
@@ -94,7 +105,7 @@ pub mod tests {
         static PAGING_TOKEN: &str = "8589934592";
         static HASH: &str = "546c5bccad35413e75324e0e63dd4d9f1ba87a3f4c97c84f83b7c09150f61caa";
         static PREV_HASH: &str = "63d98f536ee68d1b27b5b89f23af5311b7569a24faf1403ad0b52b633b07be99";
-        static SEQUENCE: i32 = 2;
+        static SEQUENCE: u32 = 2;
         static SUCCESSFUL_TRANSACTION_COUNT: i32 = 0;
         static FAILED_TRANSACTION_COUNT: i32 = 0;
         static OPERATION_COUNT: i32 = 0;
@@ -123,7 +134,7 @@ pub mod tests {
         assert_eq!(all_ledgers_response.paging_token(), PAGING_TOKEN);
         assert_eq!(all_ledgers_response.hash(), HASH);
         assert_eq!(all_ledgers_response.prev_hash(), PREV_HASH);
-        assert_eq!(all_ledgers_response.sequence(), &SEQUENCE);
+        assert_eq!(all_ledgers_response.sequence().value(), SEQUENCE);
         assert_eq!(
             all_ledgers_response.successful_transaction_count(),
             &SUCCESSFUL_TRANSACTION_COUNT
@@ -158,7 +169,7 @@ pub mod tests {
         static PAGING_TOKEN: &str = "8589934592";
         static HASH: &str = "546c5bccad35413e75324e0e63dd4d9f1ba87a3f4c97c84f83b7c09150f61caa";
         static PREV_HASH: &str = "63d98f536ee68d1b27b5b89f23af5311b7569a24faf1403ad0b52b633b07be99";
-        static SEQUENCE: &i32 = &2;
+        static SEQUENCE: u32 = 2;
         static SUCCESSFUL_TRANSACTION_COUNT: &i32 = &0;
         static FAILED_TRANSACTION_COUNT: &i32 = &0;
         static OPERATION_COUNT: &i32 = &0;
@@ -190,7 +201,7 @@ pub mod tests {
         assert_eq!(single_ledger_response.paging_token(), PAGING_TOKEN);
         assert_eq!(single_ledger_response.hash(), HASH);
         assert_eq!(single_ledger_response.prev_hash(), PREV_HASH);
-        assert_eq!(single_ledger_response.sequence(), SEQUENCE);
+        assert_eq!(single_ledger_response.sequence().value(), SEQUENCE);
         assert_eq!(
             single_ledger_response.successful_transaction_count(),
             SUCCESSFUL_TRANSACTION_COUNT
@@ -259,4 +270,38 @@ pub mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_execute() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+        let request = SingleLedgerRequest::new().set_sequence(2).unwrap();
+
+        let ledger: Ledger = horizon_client.execute(&request).await.unwrap();
+        assert_eq!(ledger.sequence().value(), 2);
+
+        let ledger: serde_json::Value = horizon_client.execute(&request).await.unwrap();
+        assert_eq!(ledger["sequence"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_ledgers_closed_between() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+        let closed_after = datetime::parse_rfc3339("2024-06-11T20:49:11Z").unwrap();
+        let closed_before = datetime::parse_rfc3339("2024-06-11T20:49:21Z").unwrap();
+
+        let request = LedgersRequest::new()
+            .set_closed_after(closed_after)
+            .unwrap()
+            .set_closed_before(closed_before)
+            .unwrap()
+            .set_search_bounds(1, 100)
+            .unwrap();
+        let response = horizon_client.get_all_ledgers(&request).await.unwrap();
+
+        let records = response.embedded().records();
+        assert_eq!(records[0].sequence().value(), 2);
+        assert!(records
+            .iter()
+            .all(|ledger| ledger.closed_at().as_str() < "2024-06-11T20:49:21Z"));
+    }
 }
@@ -1,15 +1,24 @@
 use derive_getters::Getters;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use stellar_xdr::curr::{LedgerHeader, Limits, ReadXdr};
 
+use super::ledger_sequence::LedgerSequence;
+use crate::effects::prelude::EffectsResponse;
+use crate::error::Error;
+use crate::horizon_client::HorizonClient;
 use crate::models::prelude::*;
+use crate::operations::prelude::OperationResponse;
+use crate::payments::prelude::PaymentsResponse;
+use crate::transactions::prelude::AllTransactionsResponse;
 
 /// Represents the navigational links in a single ledger response from the Horizon API.
 ///
 /// This struct includes various hyperlinks such as links to the ledger itself,
 /// transactions, operations, payments and effects
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct LedgerLinks {
     #[serde(rename = "self")]
     pub self_link: Link,
@@ -25,7 +34,8 @@ pub struct LedgerLinks {
 /// It includes navigational links, offer identifiers, the ID, the hash, the sequence number,
 /// and additional data
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Ledger {
     /// Navigational links related to the ledger.
     #[serde(rename = "_links")]
@@ -39,7 +49,7 @@ pub struct Ledger {
     /// The hash of the previous ledger.
     prev_hash: String,
     /// The sequence number of the ledger.
-    sequence: i32,
+    sequence: LedgerSequence,
     /// The number of successful transactions in the ledger.
     successful_transaction_count: i32,
     /// The number of failed transactions in the ledger.
@@ -71,7 +81,8 @@ pub struct Ledger {
 /// This struct contains the overall structure of the response for querying all ledgers. It includes
 /// navigational links and a collection of ledger records, each with comprehensive details about the ledger.
 ///
-#[derive(Debug, Deserialize, Clone, Getters)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct LedgersResponse {
     /// Navigational links for the current, next, and previous pages of the response.
     #[serde(rename = "_links")]
@@ -81,15 +92,24 @@ pub struct LedgersResponse {
     embedded: Embedded<Ledger>,
 }
 
+impl LedgersResponse {
+    /// Removes the ledgers of which the sequence number is outside the given range.
+    pub(crate) fn retain_sequences(&mut self, sequences: Range<u64>) {
+        self.embedded
+            .records
+            .retain(|ledger| sequences.contains(&(ledger.sequence.value() as u64)));
+    }
+}
+
 impl Response for LedgersResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
 
 impl Response for Ledger {
     fn from_json(json: String) -> Result<Self, String> {
-        let ledger_record = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let ledger_record = crate::models::deserialize_json(&json)?;
 
         Ok(ledger_record)
     }
@@ -102,4 +122,45 @@ impl Ledger {
         let decoded = LedgerHeader::from_xdr_base64(encoded, Limits::none()).unwrap();
         Ok(decoded)
     }
+
+    /// Retrieves the first page of the transactions of the ledger, by following its
+    /// `transactions` link. Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn transactions(
+        &self,
+        horizon_client: &HorizonClient,
+    ) -> Result<AllTransactionsResponse, Error> {
+        horizon_client
+            .follow_link(self.links.transactions.href.as_deref(), "transactions")
+            .await
+    }
+
+    /// Retrieves the first page of the operations of the ledger, by following its `operations`
+    /// link. Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn operations(
+        &self,
+        horizon_client: &HorizonClient,
+    ) -> Result<OperationResponse, Error> {
+        horizon_client
+            .follow_link(self.links.operations.href.as_deref(), "operations")
+            .await
+    }
+
+    /// Retrieves the first page of the payments of the ledger, by following its `payments` link.
+    /// Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn payments(
+        &self,
+        horizon_client: &HorizonClient,
+    ) -> Result<PaymentsResponse, Error> {
+        horizon_client
+            .follow_link(self.links.payments.href.as_deref(), "payments")
+            .await
+    }
+
+    /// Retrieves the first page of the effects of the ledger, by following its `effects` link.
+    /// Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn effects(&self, horizon_client: &HorizonClient) -> Result<EffectsResponse, Error> {
+        horizon_client
+            .follow_link(self.links.effects.href.as_deref(), "effects")
+            .await
+    }
 }
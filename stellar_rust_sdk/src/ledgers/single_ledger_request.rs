@@ -1,11 +1,11 @@
+use super::ledger_sequence::LedgerSequence;
 use crate::models::*;
 
 /// Represents a ledger sequence number.
-#[derive(Default, Clone)]
-pub struct Sequence(u32);
+pub type Sequence = LedgerSequence;
 
 /// Represents the absence of a ledger sequence number.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoSequence;
 
 /// `SingleLedgerRequest` is used to request information for a specific ledger from the Stellar Horizon API.
@@ -27,8 +27,8 @@ pub struct NoSequence;
 /// // Use with HorizonClient::get_single_ledger
 /// ```
 ///
-#[derive(Default)]
-pub struct SingleLedgerRequest<S> {
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct SingleLedgerRequest<S = NoSequence> {
     /// The sequence number of the ledger to be retrieved.
     sequence: S,
 }
@@ -48,19 +48,15 @@ impl SingleLedgerRequest<NoSequence> {
     /// A `SingleLedgerRequest` with the specified sequence number, or an error if the sequence number is invalid.
     ///
     pub fn set_sequence(self, sequence: u32) -> Result<SingleLedgerRequest<Sequence>, String> {
-        if sequence < 1 {
-            return Err("sequence must be greater than or equal to 1".to_string());
-        }
-
         Ok(SingleLedgerRequest {
-            sequence: Sequence(sequence),
+            sequence: LedgerSequence::new(sequence)?,
         })
     }
 }
 
 impl Request for SingleLedgerRequest<Sequence> {
     fn get_query_parameters(&self) -> String {
-        format!("/{}", self.sequence.0)
+        format!("/{}", self.sequence)
     }
 
     fn build_url(&self, base_url: &str) -> String {
@@ -79,6 +79,16 @@
 //!
 //! Visit the documentation for `HorizonClient` and endpoint-specific request and response
 //! types for more examples and detailed usage instructions.
+//!
+//! ## Stability
+//!
+//! Horizon regularly adds fields to its responses. To be able to follow these additions without
+//! breaking changes, all response structs and enums, as well as [`Error`](crate::error::Error),
+//! are marked `#[non_exhaustive]`: responses can not be constructed using struct expressions
+//! outside of this crate, and matches on their enums require a wildcard arm. Use the `testing`
+//! module to fabricate responses in tests. Likewise, traits that are only meant to be implemented
+//! by this crate, such as
+//! [`ValidAccountsRequest`](crate::accounts::accounts_request::ValidAccountsRequest), are sealed.
 
 use derive_getters::Getters;
 /// Provides `Request` and `Response` structs for retrieving accounts.
@@ -341,6 +351,59 @@ pub mod effects;
 ///
 pub mod fee_stats;
 
+/// Provides the health of a Horizon server.
+///
+/// Horizon reports whether it is connected to its database, and whether Stellar Core is up and in
+/// sync with the network. A server that fails any of these checks may serve stale data, so the
+/// health can be used for readiness checks before trusting the results of queries.
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::horizon_client::HorizonClient;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+///
+/// let health = horizon_client.get_health().await?;
+/// if !health.is_healthy() {
+///     // Fall back to another Horizon server...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod health;
+
+/// Provides the root resource of a Horizon server.
+///
+/// The root resource describes the Horizon server and the network it serves: the versions of
+/// Horizon and Stellar Core, the passphrase of the network, the current and supported protocol
+/// versions, and the range of ingested ledgers. It can be used to verify which network a base URL
+/// points to before submitting transactions, and to enable features depending on the protocol
+/// version of the network.
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::horizon_client::HorizonClient;
+/// use stellar_rs::network::Network;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+///
+/// let root = horizon_client.get_root().await?;
+/// assert_eq!(root.network(), Some(Network::Testnet));
+///
+/// if root.supports_protocol(18) {
+///     // Query liquidity pools...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod root;
+
 /// Provides `Request` and `Response` structs for retrieving liquidity pools.
 ///
 /// The `liquidity_pools` module in the Stellar Horizon SDK includes structures and methods that facilitate
@@ -656,8 +719,8 @@ pub mod trades;
 /// let request = FindPaymentsPathRequest::new()
 ///     .set_destination_asset(AssetType::Native).unwrap() // Sets the destination asset to native XLM.
 ///     .set_destination_amount("100.0".to_string()).unwrap() // Sets the amount of the destination asset.
-///     .set_source_account("GCDNJUBQSXK57MSKJ4NSXK5DT5CJMMXMWUE7BN6NTJ6JTH23HQVYXG2C".to_string()).unwrap() // Sets the source account.
-///     .set_destination_account("GAZD7JY7RCZN7KJ27SMUGKDPF7GQTYPXLDU7TFTJNSDB3MLO3M22DEIV".to_string()).unwrap(); // Sets the destination account.
+///     .set_source_account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7".to_string()).unwrap() // Sets the source account.
+///     .set_destination_account("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H".to_string()).unwrap(); // Sets the destination account.
 ///
 /// let paths_response = horizon_client.get_find_payment_paths(&request).await?;
 ///
@@ -708,6 +771,392 @@ pub mod paths;
 ///
 pub mod payments;
 
+/// Provides rate limit information and a client-side rate limiter.
+///
+/// The Horizon server limits the number of requests a client can make within a given time window,
+/// and reports the state of this limit through the `X-RateLimit-*` response headers. This module
+/// contains the [`RateLimitInfo`](crate::rate_limit::RateLimitInfo) struct, which represents these
+/// headers, and the [`RateLimiter`](crate::rate_limit::RateLimiter), a token bucket that can be
+/// attached to the [`HorizonClient`](crate::horizon_client::HorizonClient) to proactively stay under
+/// the limits during bulk operations.
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::horizon_client::HorizonClient;
+/// use stellar_rs::rate_limit::RateLimiter;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?
+///     .set_rate_limiter(RateLimiter::new(10, 5)?);
+///
+/// // After making a request, the reported rate limit state can be inspected.
+/// let rate_limit = horizon_client.last_rate_limit();
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod rate_limit;
+
+/// Provides client-side circuit breakers.
+///
+/// During a partial outage of Horizon, some endpoints may keep failing while others work fine.
+/// This module contains the [`CircuitBreaker`](crate::circuit_breaker::CircuitBreaker), which can
+/// be attached to the [`HorizonClient`](crate::horizon_client::HorizonClient) to stop sending
+/// requests to an endpoint after repeated failures, and to probe it again after a while. Requests
+/// rejected by an open circuit fail with
+/// [`Error::CircuitOpen`](crate::error::Error::CircuitOpen).
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::circuit_breaker::{CircuitBreaker, CircuitState};
+/// use stellar_rs::horizon_client::HorizonClient;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?
+///     .set_circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(30))?);
+///
+/// // The state of the circuit of an endpoint can be inspected.
+/// let circuit_breaker = horizon_client.circuit_breaker().unwrap();
+/// if circuit_breaker.state("/trades") == CircuitState::Open {
+///     println!("Trades are currently unavailable");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod circuit_breaker;
+
+/// Provides caches of the responses for single resources.
+///
+/// Explorer backends and wallets often request the same resources over and over. This module
+/// contains the [`ResponseCache`](crate::cache::ResponseCache) trait, which can be implemented by
+/// a cache and attached to the [`HorizonClient`](crate::horizon_client::HorizonClient), and the
+/// [`CachePolicy`](crate::cache::CachePolicy), which tells for how long the responses for each
+/// kind of resource are cached: historical ledgers, transactions and operations never change,
+/// while accounts and order books do. With the `moka` feature, the module also contains the
+/// `MokaCache`, a ready-made implementation.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "moka")]
+/// # {
+/// use stellar_rs::cache::{CacheDirective, CachePolicy, MokaCache, ResourceKind};
+/// use stellar_rs::horizon_client::HorizonClient;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon.stellar.org")?
+///     .set_cache(MokaCache::new(10_000))
+///     .set_cache_policy(CachePolicy::default().set_directive(
+///         ResourceKind::Account,
+///         CacheDirective::Ttl(Duration::from_secs(1)),
+///     ));
+///
+/// // After the account was changed by another client, its cached response can be invalidated.
+/// horizon_client.invalidate_cached_kind(ResourceKind::Account);
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+///
+pub mod cache;
+
+/// Provides retry policies for failed requests.
+///
+/// Requests to Horizon occasionally fail because of a temporary condition, such as a dropped
+/// connection or a `503 Service Unavailable` response. This module contains the
+/// [`RetryPolicy`](crate::retry::RetryPolicy), which can be attached to the
+/// [`HorizonClient`](crate::horizon_client::HorizonClient) to send such requests again after an
+/// exponential backoff.
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::horizon_client::HorizonClient;
+/// use stellar_rs::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?
+///     .set_retry_policy(RetryPolicy::new(3, Duration::from_millis(100), Duration::from_secs(2))?);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod retry;
+
+/// Provides the configuration of the client.
+///
+/// This module contains the [`HorizonClientConfig`](crate::config::HorizonClientConfig), which
+/// bundles the settings of the [`HorizonClient`](crate::horizon_client::HorizonClient), such as the
+/// URL of the Horizon server, timeouts, the retry policy, default headers and the rate limit. The
+/// configuration can be loaded from a TOML file or from environment variables, so that server
+/// deployments can be configured without code changes. It is also re-exported from the
+/// `horizon_client` module.
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::horizon_client::{HorizonClient, HorizonClientConfig};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = HorizonClientConfig::from_toml_file("horizon.toml")?;
+/// let horizon_client = HorizonClient::from_config(&config)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod config;
+
+/// Provides connection statistics.
+///
+/// The [`HorizonClient`](crate::horizon_client::HorizonClient) keeps connections to the Horizon
+/// server open, and reuses them for subsequent requests. This module contains the
+/// [`ConnectionStats`](crate::connection::ConnectionStats) struct, which reports how many
+/// connections were opened and reused. Together with
+/// [`HorizonClient::warm_up`](crate::horizon_client::HorizonClient::warm_up), which establishes a
+/// connection ahead of time, this helps keeping latency-critical requests fast. It also contains
+/// the [`ContentEncoding`](crate::connection::ContentEncoding) enum, which configures the
+/// compression of response bodies, and the [`Ping`](crate::connection::Ping) and
+/// [`LatencyMonitor`](crate::connection::LatencyMonitor) structs, which report the round-trip
+/// latency to the Horizon server, as measured by
+/// [`HorizonClient::ping`](crate::horizon_client::HorizonClient::ping).
+///
+pub mod connection;
+
+/// Provides lifecycle control for streams.
+///
+/// The [`HorizonClient`](crate::horizon_client::HorizonClient) can return streams that keep
+/// fetching pages from the Horizon server, and streams that receive new records as they are
+/// pushed by the server over Server-Sent Events, such as
+/// [`HorizonClient::stream_sse`](crate::horizon_client::HorizonClient::stream_sse). This module
+/// contains the [`StreamOptions`](crate::streaming::StreamOptions) struct, which allows these
+/// streams to be shut down gracefully using a
+/// [`CancellationToken`](crate::streaming::CancellationToken), and to report and resume from
+/// checkpoints, so that indexers can persist their progress and continue exactly where they left
+/// off after a restart.
+///
+/// # Example
+///
+/// ```rust
+/// use stellar_rs::streaming::{CancellationToken, StreamOptions};
+///
+/// let shutdown = CancellationToken::new();
+/// let options = StreamOptions::new()
+///     .set_shutdown_token(shutdown.clone())
+///     .set_checkpoint_callback(|paging_token| {
+///         // Persist the paging token, to pass it to `set_resume_from` after a restart.
+///     });
+/// ```
+///
+pub mod streaming;
+
+/// Provides analytics for trading users, derived from streams of trades and order books.
+///
+/// This module contains a rolling volume-weighted average price
+/// ([`RollingVwap`](crate::analytics::RollingVwap)), a tape that aggregates trades per interval
+/// ([`TradeTape`](crate::analytics::TradeTape)), and the imbalance ratio between the bids and asks
+/// of an order book. Each of them is also available as a derived stream, which can be applied to
+/// the streams returned by the [`HorizonClient`](crate::horizon_client::HorizonClient), such as
+/// [`HorizonClient::stream_trades`](crate::horizon_client::HorizonClient::stream_trades).
+///
+/// # Example
+///
+/// ```rust
+/// # use stellar_rs::analytics::tape_stream;
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::trades::prelude::AllTradesRequest;
+/// # use futures::StreamExt;
+/// # use std::time::Duration;
+/// #
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// let trades = horizon_client.stream_trades(&AllTradesRequest::new().set_limit(200)?);
+/// let tape = tape_stream(trades, Duration::from_secs(60))?;
+/// futures::pin_mut!(tape);
+///
+/// while let Some(interval) = tape.next().await {
+///     let interval = interval?;
+///     println!("{}: {} trades, close {}", interval.start(), interval.trade_count(), interval.close());
+/// #   break;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod analytics;
+
+/// Canned responses, for unit testing code that uses the SDK without a Horizon server.
+///
+/// The response types of the SDK only expose getters, so they cannot be constructed directly.
+/// This module provides the [`TestResponse`] trait, which is implemented for the most commonly
+/// used response types. It allows creating a response with realistic default values using
+/// [`TestResponse::test_default`], or customizing the values of specific fields using a
+/// [`ResponseBuilder`], without hand-writing the complete JSON returned by Horizon.
+///
+/// This module is only available when the `testing` feature is enabled:
+///
+/// ```toml
+/// [dev-dependencies]
+/// stellar-rs = { version = "*", features = ["testing"] }
+/// ```
+///
+/// # Example
+/// ```
+/// use stellar_rs::offers::prelude::OfferResponse;
+/// use stellar_rs::testing::TestResponse;
+///
+/// let offer = OfferResponse::builder()
+///     .set("amount", "150.0000000")
+///     .set("selling.asset_code", "USDC")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(offer.amount(), "150.0000000");
+/// assert_eq!(offer.selling().asset_code().as_deref(), Some("USDC"));
+/// ```
+///
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// Provides the error type of the client.
+///
+/// The methods of the [`HorizonClient`](crate::horizon_client::HorizonClient) return an
+/// [`Error`](crate::error::Error), which tells apart failures to reach the Horizon server, error
+/// responses of the Horizon server itself, responses that are not JSON (such as the HTML error page
+/// of a proxy), and responses that do not match the models of this SDK.
+///
+pub mod error;
+
+/// Provides the date and time type of the requests and responses.
+///
+/// Dates and times, such as the close time of a trade, are exposed as
+/// [`DateTime`](crate::datetime::DateTime), which is a `chrono` type by default. Applications that
+/// standardize on the `time` crate can disable the default features and enable the `time` feature
+/// instead, so that the models expose `time` types and `chrono` is not compiled at all:
+///
+/// ```toml
+/// [dependencies]
+/// stellar-rs = { version = "0.1", default-features = false, features = ["time", "tokio"] }
+/// ```
+///
+pub mod datetime;
+
+/// Provides the async runtime primitives used by the client.
+///
+/// The client only waits on timers, such as the backoff of a retry, and sends requests through
+/// this module. All other asynchronous code uses the runtime-agnostic primitives of the `futures`
+/// crate. By default, the runtime of `tokio` is used. Applications that run on another async
+/// runtime, such as
+/// `async-std` or `smol`, can disable the default features and enable the `futures-timer` feature
+/// instead:
+///
+/// ```toml
+/// [dependencies]
+/// stellar-rs = { version = "0.1", default-features = false, features = ["chrono", "futures-timer"] }
+/// ```
+///
+/// Note that `reqwest`, which sends the requests to Horizon, needs a `tokio` reactor to open
+/// connections. Without the `tokio` feature, requests are therefore sent on a background `tokio`
+/// runtime that the SDK starts on first use, so that the application itself does not need one.
+///
+pub(crate) mod runtime;
+
+/// Provides presets for the well-known Stellar networks, and custom networks.
+///
+/// This module contains the [`Network`](crate::network::Network) enum, which bundles the URL of the
+/// Horizon server and the passphrase of the public, test and future networks, of the
+/// standalone network of a local `stellar/quickstart` container, and of custom networks. The
+/// network set on a [`HorizonClient`](crate::horizon_client::HorizonClient) provides the
+/// passphrase for hashing and signing transactions, and can be verified against the Horizon server
+/// before transactions are submitted. The module also lists the query
+/// parameters that require a minimum protocol version, which the
+/// [`HorizonClient`](crate::horizon_client::HorizonClient) omits when it is pointed at an older
+/// network using
+/// [`HorizonClient::set_protocol_version`](crate::horizon_client::HorizonClient::set_protocol_version).
+///
+/// # Example
+/// ```rust
+/// use stellar_rs::horizon_client::HorizonClient;
+/// use stellar_rs::network::Network;
+///
+/// let horizon_client = HorizonClient::for_network(Network::Sandbox)
+///     .expect("Failed to create HorizonClient")
+///     .set_protocol_version(17);
+/// ```
+///
+pub mod network;
+
+/// Provides faucets, which fund accounts on test and private networks.
+///
+/// This module contains the [`Faucet`](crate::faucet::Faucet) trait, which abstracts funding an
+/// account, and two implementations: [`Friendbot`](crate::faucet::Friendbot), which funds accounts
+/// on the test and future networks, and [`CustomFaucet`](crate::faucet::CustomFaucet), which calls
+/// a faucet at a custom URL, such as the faucet of a private network. Integration tests can fund
+/// accounts through the same API, regardless of the network they run against.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::faucet::{Faucet, Friendbot};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let funding = Friendbot::testnet()
+///     .fund_account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .await?;
+/// println!("Funded in transaction {}", funding.hash());
+/// # Ok(())
+/// # }
+/// ```
+///
+pub mod faucet;
+
+/// Provides the version of the SDK and the range of Horizon versions it supports.
+///
+/// The models of this SDK were tested against the Horizon versions between
+/// [`OLDEST_SUPPORTED_HORIZON_VERSION`](crate::version::OLDEST_SUPPORTED_HORIZON_VERSION) and
+/// [`NEWEST_SUPPORTED_HORIZON_VERSION`](crate::version::NEWEST_SUPPORTED_HORIZON_VERSION). When
+/// responses of a Horizon server unexpectedly fail to deserialize, the server may run a version
+/// outside of this range. [`check_compatibility`](crate::version::check_compatibility) retrieves
+/// the version of a server and reports whether it is supported.
+///
+/// # Example
+/// ```rust
+/// use stellar_rs::version::{Compatibility, HorizonVersion};
+///
+/// let compatibility = Compatibility::of(HorizonVersion::new(2, 31, 0));
+/// assert!(compatibility.is_supported());
+/// ```
+///
+pub mod version;
+
+/// Provides deprecated types, to ease migrating to the consolidated types of the SDK.
+///
+/// Types that used to be defined per module, such as the price of a trade, have been replaced by
+/// types that are shared across the SDK, such as
+/// [`RationalPrice`](crate::models::prelude::RationalPrice) and
+/// [`AssetType`](crate::models::prelude::AssetType). This module keeps the removed types around as
+/// deprecated items, so that code using them still compiles with a warning pointing to their
+/// replacement, and provides conversions from the old types into the new ones. The removed types
+/// are also still available at their previous location.
+///
+/// This module is scheduled for removal in the next major release.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::RationalPrice;
+/// use stellar_rs::trade_aggregations::prelude::Ratio;
+///
+/// # let json = r#"{ "n": "10", "d": "4" }"#;
+/// let ratio: Ratio = serde_json::from_str(json).unwrap();
+/// let price = RationalPrice::try_from(&ratio).unwrap();
+/// assert_eq!(price, RationalPrice::new(5, 2).unwrap());
+/// ```
+///
+pub mod compat;
+
 /// Contains core data structures and traits.
 ///
 /// This module is used by the Stellar Rust SDK to interact with the Horizon API.
@@ -720,6 +1169,17 @@ pub mod payments;
 /// instead of raw HTTP requests and JSON responses.
 pub mod models;
 
+/// Provides the supertrait that seals traits of this crate.
+///
+/// Traits that have [`Sealed`](sealed::Sealed) as a supertrait can be named by users of the
+/// crate, but can not be implemented outside of it. This allows methods to be added to these
+/// traits without breaking downstream code.
+///
+pub(crate) mod sealed {
+    /// The supertrait of sealed traits. It is public, but not reachable from outside the crate.
+    pub trait Sealed {}
+}
+
 /// Extension trait for building query parameter strings from a vector of optional values.
 ///
 /// This trait provides a method to construct a query string from a vector of optional
@@ -730,9 +1190,9 @@ pub mod models;
 /// This trait is typically used internally in constructing URLs with query parameters
 /// by implementors of the [`Request::get_query_parameters`](crate::models::Request::get_query_parameters)
 /// method. It enables a convenient and efficient way to handle optional parameters in
-/// a URL query string.
+/// a URL query string. The trait is sealed, as it is not part of the public API.
 ///
-trait BuildQueryParametersExt<T> {
+trait BuildQueryParametersExt<T>: sealed::Sealed {
     /// Constructs a query string for an HTTP request from the object's properties.
     ///
     /// This method transforms the properties of the implementing object into a URL-encoded query
@@ -741,7 +1201,11 @@ trait BuildQueryParametersExt<T> {
     fn build_query_parameters(self) -> String;
 }
 
-impl<T: ToString> BuildQueryParametersExt<Option<T>> for Vec<Option<T>> {
+impl<T: std::fmt::Display> sealed::Sealed for Vec<Option<T>> {}
+
+impl<T: std::fmt::Display, const N: usize> sealed::Sealed for [Option<T>; N] {}
+
+impl<T: std::fmt::Display> BuildQueryParametersExt<Option<T>> for Vec<Option<T>> {
     /// # Implementation for `Vec<Option<T>>`
     /// Converts each property to a key-value pair, and concatenates pairs with '&'.
     /// Properties that are `None` are omitted from the string.
@@ -751,25 +1215,59 @@ impl<T: ToString> BuildQueryParametersExt<Option<T>> for Vec<Option<T>> {
     /// are no parameters, or all properties are `None`, an empty string is returned.
     ///
     fn build_query_parameters(self) -> String {
-        let params = self
-            .into_iter()
-            // Iterate over each element in the vector.
-            .filter_map(|x|
-                // Use filter_map to process each Option<T>.
-                // If the element is Some, it's transformed to its string representation.
-                // If the element is None, it's filtered out.
-                x.map(|val| val.to_string()))
-            // Collect the transformed values into a Vec<String>.
-            .collect::<Vec<String>>()
-            // Join the Vec<String> elements with '&' to create the query string.
-            .join("&");
-
-        // Check if the resulting params string is empty.
-        match params.is_empty() {
-            // If params is empty, return an empty string.
-            true => "".to_string(),
-            // If params is not empty, prepend a '?' to the params string.
-            false => format!("?{}", params),
+        build_query(self)
+    }
+}
+
+impl<T: std::fmt::Display, const N: usize> BuildQueryParametersExt<Option<T>> for [Option<T>; N] {
+    /// # Implementation for `[Option<T>; N]`
+    /// Behaves like the implementation for `Vec<Option<T>>`, but does not allocate the array of
+    /// parameters on the heap, which makes it the preferred way to build a query string.
+    ///
+    fn build_query_parameters(self) -> String {
+        build_query(self)
+    }
+}
+
+/// Writes the parameters that are `Some` into a single query string, prefixed with '?' and
+/// separated by '&', without collecting them into an intermediate vector of strings first.
+fn build_query<T: std::fmt::Display>(parameters: impl IntoIterator<Item = Option<T>>) -> String {
+    use std::fmt::Write;
+
+    let mut query = String::new();
+    for parameter in parameters.into_iter().flatten() {
+        let length = query.len();
+        query.push(if query.is_empty() { '?' } else { '&' });
+        // Writing to a `String` never fails.
+        let _ = write!(query, "{}", parameter);
+
+        // Omit parameters that are empty, along with their separator.
+        if query.len() == length + 1 {
+            query.truncate(length);
         }
     }
+
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_query_parameters() {
+        let parameters = [
+            Some("cursor=1".to_string()),
+            None,
+            Some(String::new()),
+            Some("limit=2".to_string()),
+        ];
+        assert_eq!(parameters.build_query_parameters(), "?cursor=1&limit=2");
+
+        assert_eq!([None::<String>, None].build_query_parameters(), "");
+        assert_eq!(
+            vec![Some("order=asc")].build_query_parameters(),
+            "?order=asc"
+        );
+    }
 }
@@ -1,13 +1,13 @@
-use crate::{
-    models::{prelude::{AssetData, AssetType}, Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::prelude::{AssetData, AssetType};
+use crate::models::{is_public_key, is_valid_asset, resolve_account_id};
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request for listing all liquidity pools on the Stellar Horizon API.
 ///
 /// `AllLiquidityPoolsRequest` is a struct used to construct queries for retrieving information about liquidity pools from the Horizon server. It includes parameters that allow for pagination control and sorting of the liquidity pool records.
-/// It includes parameters that allow for pagination control and sorting of the liquidity pool records.
+/// The liquidity pools can be filtered by their reserves, of which all must be held by a pool, and by an account
+/// that participates in them.
 ///
 /// # Usage
 /// Instances of `AllLiquidityPoolsRequest` are created and optionally configured using the builder pattern.
@@ -17,36 +17,91 @@ use stellar_rust_sdk_derive::pagination;
 /// ```rust
 /// # use stellar_rs::liquidity_pools::all_liquidity_pools_request::AllLiquidityPoolsRequest;
 /// # use stellar_rs::models::*;
+/// # use stellar_rs::models::prelude::AssetType;
 ///
 /// let request = AllLiquidityPoolsRequest::new()
 ///     .set_cursor(1234).unwrap()
 ///     .set_limit(20).unwrap()
 ///     .set_order(Order::Desc).unwrap()
-///     .add_native_reserve()
-///     .add_alphanumeric4_reserve("USD", "GAXLYH...");
+///     .add_reserve(AssetType::Native).unwrap()
+///     .add_reserve(AssetType::issued("USDC", "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5")).unwrap()
+///     .set_account("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H").unwrap();
 ///
 /// // The request can now be used with a Horizon client to fetch liquidity pools.
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::LIQUIDITY_POOLS_PATH)]
 pub struct AllLiquidityPoolsRequest {
     /// A list of reserves to filter by.
+    #[horizon(assets)]
     reserves: Option<Vec<AssetType>>,
+
+    /// The account that participates in the liquidity pools to filter by.
+    account: Option<String>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllLiquidityPoolsRequest {
     /// Creates a new `AllLiquidityPoolsRequest` with default parameters.
     pub fn new() -> AllLiquidityPoolsRequest {
         AllLiquidityPoolsRequest {
-            cursor: None,
-            limit: None,
-            order: None,
+            page_params: PageParams::new(),
             reserves: None,
+            account: None,
+        }
+    }
+
+    /// Adds a reserve to the request, so that only the liquidity pools that hold the asset are
+    /// returned. Multiple reserves are sent as a single comma-separated `reserves` parameter.
+    ///
+    /// # Arguments
+    /// * `reserve` - The asset of the reserve, such as an [`AssetType`] or an
+    ///   [`Asset`](crate::models::Asset).
+    ///
+    /// # Returns
+    /// The updated request, or an error if the asset code or the issuer of the asset is invalid.
+    ///
+    pub fn add_reserve(
+        mut self,
+        reserve: impl Into<AssetType>,
+    ) -> Result<AllLiquidityPoolsRequest, String> {
+        let reserve = reserve.into();
+        is_valid_asset(&reserve)?;
+
+        self.reserves.get_or_insert_with(Vec::new).push(reserve);
+        Ok(self)
+    }
+
+    /// Specifies the account in the request, so that only the liquidity pools in which the
+    /// account participates are returned.
+    ///
+    /// # Arguments
+    /// * `account` - The public key of the account. A muxed account (`M...`) is resolved to its
+    ///   underlying account.
+    ///
+    pub fn set_account(
+        self,
+        account: impl Into<String>,
+    ) -> Result<AllLiquidityPoolsRequest, String> {
+        let account = resolve_account_id(account.into());
+        if let Err(e) = is_public_key(&account) {
+            return Err(e.to_string());
         }
+
+        Ok(AllLiquidityPoolsRequest {
+            account: Some(account),
+            ..self
+        })
     }
 
     /// Adds a native reserve to the request.
+    #[deprecated(
+        note = "use `add_reserve` instead; `add_native_reserve` will be removed in the next major release"
+    )]
     pub fn add_native_reserve(mut self) -> AllLiquidityPoolsRequest {
         match self.reserves {
             Some(ref mut reserves) => reserves.push(AssetType::Native),
@@ -57,10 +112,15 @@ impl AllLiquidityPoolsRequest {
 
     /// Adds an alphanumeric 4 reserve to the request.
     ///
+    /// Unlike [`add_reserve`](Self::add_reserve), this method does not validate the asset.
+    ///
     /// # Arguments
     /// * `asset_code` - A `String` value representing the asset code of the reserve.
     /// * `asset_issuer` - A `String` value representing the asset issuer of the reserve.
     ///
+    #[deprecated(
+        note = "use `add_reserve` instead; `add_alphanumeric4_reserve` will be removed in the next major release"
+    )]
     pub fn add_alphanumeric4_reserve(
         mut self,
         asset_code: impl Into<String>,
@@ -74,7 +134,7 @@ impl AllLiquidityPoolsRequest {
                 asset_issuer,
             })),
             None => {
-                self.reserves = Some(vec![AssetType::Alphanumeric4(AssetData{
+                self.reserves = Some(vec![AssetType::Alphanumeric4(AssetData {
                     asset_code,
                     asset_issuer,
                 })])
@@ -85,10 +145,15 @@ impl AllLiquidityPoolsRequest {
 
     /// Adds an alphanumeric 12 reserve to the request.
     ///
+    /// Unlike [`add_reserve`](Self::add_reserve), this method does not validate the asset.
+    ///
     /// # Arguments
     /// * `asset_code` - A `String` value representing the asset code of the reserve.
     /// * `asset_issuer` - A `String` value representing the asset issuer of the reserve.
     ///
+    #[deprecated(
+        note = "use `add_reserve` instead; `add_alphanumeric12_reserve` will be removed in the next major release"
+    )]
     pub fn add_alphanumeric12_reserve(
         mut self,
         asset_code: impl Into<String>,
@@ -112,73 +177,31 @@ impl AllLiquidityPoolsRequest {
     }
 }
 
-impl Request for AllLiquidityPoolsRequest {
-    fn get_query_parameters(&self) -> String {
-        let query_reserve_parameters = self
-            .reserves
-            .as_ref()
-            .map_or_else(Vec::new, |reserves| {
-                reserves
-                    .iter()
-                    .enumerate()
-                    .fold(Vec::new(), |mut acc, (i, reserve)| {
-                        let separator = if i == 0 { "reserves=" } else { "%2C" };
-                        match reserve {
-                            AssetType::Native => acc.push(format!("{}native", separator)),
-                            AssetType::Alphanumeric4(reserve)
-                            | AssetType::Alphanumeric12(reserve) => {
-                                acc.push(format!(
-                                    "{}{}%3A{}",
-                                    separator, reserve.asset_code, reserve.asset_issuer
-                                ));
-                            }
-                        }
-                        acc
-                    })
-            })
-            .join("");
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            Some(query_reserve_parameters),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}?{}",
-            base_url,
-            super::LIQUIDITY_POOLS_PATH,
-            self.get_query_parameters()
-        )
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{Order, Request};
 
     #[test]
     fn test_new() {
         let request = AllLiquidityPoolsRequest::new();
-        assert_eq!(request.cursor, None);
-        assert_eq!(request.limit, None);
-        assert_eq!(request.order, None);
+        assert_eq!(request.page_params.cursor(), None);
+        assert_eq!(request.page_params.limit(), None);
+        assert_eq!(request.page_params.order(), None);
         assert_eq!(request.reserves, None);
+        assert_eq!(request.account, None);
     }
 
     #[test]
     fn test_set_cursor() {
         let request = AllLiquidityPoolsRequest::new().set_cursor(1234).unwrap();
-        assert_eq!(request.cursor, Some(1234));
+        assert_eq!(request.page_params.cursor(), Some(1234));
     }
 
     #[test]
     fn test_set_limit() {
         let request = AllLiquidityPoolsRequest::new().set_limit(20).unwrap();
-        assert_eq!(request.limit, Some(20));
+        assert_eq!(request.page_params.limit(), Some(20));
     }
 
     #[test]
@@ -186,16 +209,18 @@ mod tests {
         let request = AllLiquidityPoolsRequest::new()
             .set_order(Order::Desc)
             .unwrap();
-        assert_eq!(request.order, Some(Order::Desc));
+        assert_eq!(request.page_params.order(), Some(&Order::Desc));
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_add_native_reserve() {
         let request = AllLiquidityPoolsRequest::new().add_native_reserve();
         assert_eq!(request.reserves, Some(vec![AssetType::Native]));
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_add_native_reserve_twice() {
         let request = AllLiquidityPoolsRequest::new()
             .add_native_reserve()
@@ -207,6 +232,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_add_alphanumeric4_reserve() {
         let mut request = AllLiquidityPoolsRequest::new();
         request = request.add_alphanumeric4_reserve("USD", "issuer");
@@ -226,6 +252,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_add_alphanumeric12_reserve() {
         let mut request = AllLiquidityPoolsRequest::new();
         request = request.add_alphanumeric12_reserve("LONGASSET", "issuer");
@@ -245,6 +272,47 @@ mod tests {
     }
 
     #[test]
+    fn test_add_reserve() {
+        let issuer = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+        let request = AllLiquidityPoolsRequest::new()
+            .add_reserve(AssetType::Native)
+            .unwrap()
+            .add_reserve(AssetType::issued("USDC", issuer))
+            .unwrap();
+
+        assert_eq!(
+            request.get_query_parameters(),
+            format!("?reserves=native%2CUSDC%3A{}", issuer)
+        );
+
+        assert!(AllLiquidityPoolsRequest::new()
+            .add_reserve(AssetType::issued("USDC", "issuer"))
+            .is_err());
+        assert!(AllLiquidityPoolsRequest::new()
+            .add_reserve(AssetType::issued("", issuer))
+            .is_err());
+    }
+
+    #[test]
+    fn test_set_account() {
+        let account = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+        let request = AllLiquidityPoolsRequest::new()
+            .set_account("MBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OAAAAAAAAAAAPOGVY")
+            .unwrap()
+            .add_reserve(AssetType::Native)
+            .unwrap();
+
+        assert_eq!(
+            request.get_query_parameters(),
+            format!("?reserves=native&account={}", account)
+        );
+        assert!(AllLiquidityPoolsRequest::new()
+            .set_account("invalid")
+            .is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_get_query_parameters() {
         let mut request = AllLiquidityPoolsRequest::new();
         request = request.add_alphanumeric4_reserve("USD", "issuer");
@@ -252,4 +320,18 @@ mod tests {
 
         assert_eq!(query_parameters, "?reserves=USD%3Aissuer");
     }
+
+    #[test]
+    fn test_build_url() {
+        let request = AllLiquidityPoolsRequest::new()
+            .add_reserve(AssetType::Native)
+            .unwrap()
+            .set_limit(2)
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/liquidity_pools?reserves=native&limit=2"
+        );
+    }
 }
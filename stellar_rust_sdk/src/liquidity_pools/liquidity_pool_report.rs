@@ -0,0 +1,323 @@
+use super::response::Reserve;
+use crate::datetime::{self, DateTime};
+use crate::effects::prelude::{Effect, EffectDetail};
+use crate::models::prelude::Amount;
+use crate::operations::operation_id::OperationId;
+use derive_getters::Getters;
+use std::collections::HashMap;
+
+/// Represents an amount of one of the reserves of a liquidity pool, such as the amount of lumens
+/// that was deposited.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[non_exhaustive]
+pub struct ReserveAmount {
+    /// The asset of the reserve, such as `native` or
+    /// `USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5`.
+    asset: String,
+    /// The amount of the asset.
+    amount: Amount,
+}
+
+impl TryFrom<&Reserve> for ReserveAmount {
+    type Error = String;
+
+    fn try_from(reserve: &Reserve) -> Result<Self, Self::Error> {
+        Ok(ReserveAmount {
+            asset: reserve.asset.clone(),
+            amount: reserve.amount.parse()?,
+        })
+    }
+}
+
+/// Describes how a single operation moved value into or out of a liquidity pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LiquidityPoolFlowKind {
+    /// Reserves were deposited into the pool, in exchange for newly issued pool shares.
+    Deposit {
+        /// The reserves that were deposited.
+        reserves: Vec<ReserveAmount>,
+        /// The number of pool shares that were issued to the depositor.
+        shares: Amount,
+    },
+    /// Reserves were withdrawn from the pool, in exchange for pool shares that were redeemed.
+    Withdrawal {
+        /// The reserves that were withdrawn.
+        reserves: Vec<ReserveAmount>,
+        /// The number of pool shares that were redeemed.
+        shares: Amount,
+    },
+    /// The pool traded one reserve for the other, as part of a path payment or an offer that
+    /// crossed the pool.
+    Trade {
+        /// The reserve that the pool sold.
+        sold: ReserveAmount,
+        /// The reserve that the pool bought, including the fee.
+        bought: ReserveAmount,
+        /// The fee that the pool charged on the reserve it bought, which accrues to the holders
+        /// of its shares.
+        fee: Amount,
+    },
+}
+
+/// Represents a single deposit, withdrawal or trade of a liquidity pool.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolFlow {
+    /// The ID of the operation that moved the value.
+    operation_id: OperationId,
+    /// The time at which the operation was included in a ledger.
+    created_at: DateTime,
+    /// The account that deposited, withdrew or traded.
+    account: String,
+    /// What was deposited, withdrawn or traded.
+    kind: LiquidityPoolFlowKind,
+}
+
+/// Represents the value that flowed into and out of a liquidity pool over a period of time, as
+/// returned by
+/// [`HorizonClient::get_liquidity_pool_report`](crate::horizon_client::HorizonClient::get_liquidity_pool_report).
+///
+/// The totals are keyed by the asset of the reserve, such as `native` or
+/// `USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5`. Trades are seen from the
+/// point of view of the pool: `bought` holds the reserves that traders sold to the pool, and
+/// `sold` holds the reserves that the pool paid out to them.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolReport {
+    /// The ID of the liquidity pool.
+    liquidity_pool_id: String,
+    /// The deposits, withdrawals and trades of the pool, from the oldest to the most recent one.
+    flows: Vec<LiquidityPoolFlow>,
+    /// The total amount of every reserve that was deposited.
+    deposited: HashMap<String, Amount>,
+    /// The total amount of every reserve that was withdrawn.
+    withdrawn: HashMap<String, Amount>,
+    /// The total amount of every reserve that the pool bought in trades, including fees.
+    bought: HashMap<String, Amount>,
+    /// The total amount of every reserve that the pool sold in trades.
+    sold: HashMap<String, Amount>,
+    /// The total fees that the pool charged in every reserve.
+    fees: HashMap<String, Amount>,
+    /// The total number of pool shares that were issued for deposits.
+    shares_issued: Amount,
+    /// The total number of pool shares that were redeemed for withdrawals.
+    shares_redeemed: Amount,
+}
+
+impl LiquidityPoolReport {
+    fn new(liquidity_pool_id: &str) -> Self {
+        LiquidityPoolReport {
+            liquidity_pool_id: liquidity_pool_id.to_string(),
+            flows: Vec::new(),
+            deposited: HashMap::new(),
+            withdrawn: HashMap::new(),
+            bought: HashMap::new(),
+            sold: HashMap::new(),
+            fees: HashMap::new(),
+            shares_issued: Amount::default(),
+            shares_redeemed: Amount::default(),
+        }
+    }
+
+    /// Adds a flow to the report, and updates the totals.
+    fn add(&mut self, flow: LiquidityPoolFlow) -> Result<(), String> {
+        match &flow.kind {
+            LiquidityPoolFlowKind::Deposit { reserves, shares } => {
+                for reserve in reserves {
+                    add_to_total(&mut self.deposited, &reserve.asset, reserve.amount)?;
+                }
+                self.shares_issued = checked_add(self.shares_issued, *shares)?;
+            }
+            LiquidityPoolFlowKind::Withdrawal { reserves, shares } => {
+                for reserve in reserves {
+                    add_to_total(&mut self.withdrawn, &reserve.asset, reserve.amount)?;
+                }
+                self.shares_redeemed = checked_add(self.shares_redeemed, *shares)?;
+            }
+            LiquidityPoolFlowKind::Trade { sold, bought, fee } => {
+                add_to_total(&mut self.sold, &sold.asset, sold.amount)?;
+                add_to_total(&mut self.bought, &bought.asset, bought.amount)?;
+                add_to_total(&mut self.fees, &bought.asset, *fee)?;
+            }
+        }
+        self.flows.push(flow);
+        Ok(())
+    }
+}
+
+fn checked_add(total: Amount, amount: Amount) -> Result<Amount, String> {
+    total
+        .checked_add(amount)
+        .ok_or_else(|| "the total amount overflows".to_string())
+}
+
+fn add_to_total(
+    totals: &mut HashMap<String, Amount>,
+    asset: &str,
+    amount: Amount,
+) -> Result<(), String> {
+    let total = totals.entry(asset.to_string()).or_default();
+    *total = checked_add(*total, amount)?;
+    Ok(())
+}
+
+/// Combines the effects of a liquidity pool into a report of its deposits, withdrawals and
+/// trades.
+///
+/// Only the `liquidity_pool_deposited`, `liquidity_pool_withdrew` and `liquidity_pool_trade`
+/// effects of the pool are taken into account, in the order of the effects. Other effects, such
+/// as the balance changes of the accounts involved, are skipped.
+///
+pub(crate) fn build_liquidity_pool_report(
+    liquidity_pool_id: &str,
+    effects: &[Effect],
+) -> Result<LiquidityPoolReport, String> {
+    let mut report = LiquidityPoolReport::new(liquidity_pool_id);
+    for effect in effects {
+        let Some(liquidity_pool) = effect
+            .liquidity_pool()
+            .filter(|liquidity_pool| liquidity_pool.id == liquidity_pool_id)
+        else {
+            continue;
+        };
+
+        let reserves = |reserves: &[Reserve]| {
+            reserves
+                .iter()
+                .map(ReserveAmount::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let kind = match &effect.detail {
+            EffectDetail::LiquidityPoolDeposited(detail) => LiquidityPoolFlowKind::Deposit {
+                reserves: reserves(&detail.reserves_deposited)?,
+                shares: detail.shares_received.parse()?,
+            },
+            EffectDetail::LiquidityPoolWithdrew(detail) => LiquidityPoolFlowKind::Withdrawal {
+                reserves: reserves(&detail.reserves_received)?,
+                shares: detail.shares_redeemed.parse()?,
+            },
+            EffectDetail::LiquidityPoolTrade(detail) => {
+                let bought = ReserveAmount::try_from(&detail.bought)?;
+                // The fee is charged on the reserve that is sold to the pool.
+                let fee = i128::from(bought.amount.stroops()) * i128::from(liquidity_pool.fee_bp)
+                    / 10_000;
+                LiquidityPoolFlowKind::Trade {
+                    sold: ReserveAmount::try_from(&detail.sold)?,
+                    fee: Amount::from_stroops(
+                        i64::try_from(fee).map_err(|_| "the fee overflows".to_string())?,
+                    ),
+                    bought,
+                }
+            }
+            _ => continue,
+        };
+
+        report.add(LiquidityPoolFlow {
+            operation_id: effect.operation_id()?,
+            created_at: datetime::parse_rfc3339(&effect.created_at)?,
+            account: effect.account.clone(),
+            kind,
+        })?;
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static POOL_ID: &str = "dd7b1ab831c273310ddbec6f97870aa83c2fbd78ce22aded37ecbf4f3380fac7";
+    static USDC: &str = "USDC:GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+    fn effect(paging_token: &str, effect_type: &str, fields: &str) -> Effect {
+        let json = format!(
+            r#"{{
+                "_links": {{
+                    "operation": {{"href": "https://horizon-testnet.stellar.org/operations/1"}},
+                    "succeeds": {{"href": "https://horizon-testnet.stellar.org/effects?order=desc"}},
+                    "precedes": {{"href": "https://horizon-testnet.stellar.org/effects?order=asc"}}
+                }},
+                "id": "{paging_token}",
+                "paging_token": "{paging_token}",
+                "account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+                "type": "{effect_type}",
+                "type_i": 0,
+                "created_at": "2024-06-11T21:36:12Z",
+                "liquidity_pool": {{
+                    "id": "{POOL_ID}",
+                    "fee_bp": 30,
+                    "type": "constant_product",
+                    "total_trustlines": "1",
+                    "total_shares": "100.0000000",
+                    "reserves": []
+                }}
+                {fields}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_build_liquidity_pool_report() {
+        let effects = [
+            effect(
+                "1001-1",
+                "liquidity_pool_deposited",
+                &format!(
+                    r#", "reserves_deposited": [
+                        {{"asset": "native", "amount": "100.0000000"}},
+                        {{"asset": "{USDC}", "amount": "50.0000000"}}
+                    ], "shares_received": "70.7106781""#
+                ),
+            ),
+            effect(
+                "2001-1",
+                "liquidity_pool_trade",
+                &format!(
+                    r#", "sold": {{"asset": "{USDC}", "amount": "4.5000000"}},
+                    "bought": {{"asset": "native", "amount": "10.0000000"}}"#
+                ),
+            ),
+            effect(
+                "2001-2",
+                "account_credited",
+                r#", "asset_type": "native", "amount": "10.0000000""#,
+            ),
+            effect(
+                "3001-1",
+                "liquidity_pool_withdrew",
+                &format!(
+                    r#", "reserves_received": [
+                        {{"asset": "native", "amount": "11.0000000"}},
+                        {{"asset": "{USDC}", "amount": "4.5500000"}}
+                    ], "shares_redeemed": "7.0710678""#
+                ),
+            ),
+        ];
+
+        let report = build_liquidity_pool_report(POOL_ID, &effects).unwrap();
+        assert_eq!(report.flows().len(), 3);
+        assert_eq!(report.flows()[1].operation_id().value(), 2001);
+        assert_eq!(report.deposited()["native"].to_string(), "100.0000000");
+        assert_eq!(report.withdrawn()[USDC].to_string(), "4.5500000");
+        assert_eq!(report.bought()["native"].to_string(), "10.0000000");
+        assert_eq!(report.sold()[USDC].to_string(), "4.5000000");
+        assert_eq!(report.fees()["native"].to_string(), "0.0300000");
+        assert_eq!(report.shares_issued().to_string(), "70.7106781");
+        assert_eq!(report.shares_redeemed().to_string(), "7.0710678");
+
+        let effects = [effect(
+            "1001-1",
+            "liquidity_pool_deposited",
+            r#", "reserves_deposited": [], "shares_received": "70.71067811""#,
+        )];
+        assert_eq!(
+            build_liquidity_pool_report(POOL_ID, &effects).unwrap_err(),
+            "Invalid amount: 70.71067811"
+        );
+    }
+}
@@ -15,6 +15,15 @@ pub mod single_liquidity_pool_request;
 /// method.
 pub mod all_liquidity_pools_request;
 
+/// Provides the `LiquidityPoolReport`.
+///
+/// This module provides the `LiquidityPoolReport` struct, which combines the deposits,
+/// withdrawals and trades of a liquidity pool over a period of time, along with the fees the pool
+/// charged. It is returned by the [`HorizonClient::get_liquidity_pool_report`](crate::horizon_client::HorizonClient::get_liquidity_pool_report)
+/// method.
+///
+pub mod liquidity_pool_report;
+
 /// Provides the responses.
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -51,6 +60,7 @@ pub(crate) static LIQUIDITY_POOLS_PATH: &str = "liquidity_pools";
 ///
 /// * From `single_liquidity_pool_request`: All items (e.g. `SingleLiquidityPoolRequest`).
 /// * From `all_liquidity_pools_request`: All items (e.g. `AllLiquidityPoolsRequest`, `Reserve`, etc.).
+/// * From `liquidity_pool_report`: All items (e.g. `LiquidityPoolReport`, `LiquidityPoolFlow`, etc.).
 /// * From `response`: All items (e.g. `AllLiquidityPoolsResponse`, `Reserve`, etc.).
 ///
 /// # Example
@@ -64,13 +74,16 @@ pub(crate) static LIQUIDITY_POOLS_PATH: &str = "liquidity_pools";
 /// ```
 ///
 pub mod prelude {
+    pub use super::liquidity_pool_report::*;
     pub use super::response::*;
     pub use super::single_liquidity_pool_request::*;
 }
 
+#[cfg(test)]
 #[tokio::test]
 async fn test_get_all_liquidity_pools() {
     use crate::horizon_client::HorizonClient;
+    use crate::models::prelude::AssetType;
     use all_liquidity_pools_request::AllLiquidityPoolsRequest;
 
     const RSP_1_LIQUIDITY_POOL_ID: &str =
@@ -116,11 +129,13 @@ async fn test_get_all_liquidity_pools() {
     let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
 
     let all_liquidity_pools_request_1 = AllLiquidityPoolsRequest::new()
-        .add_native_reserve()
-        .add_alphanumeric4_reserve(
+        .add_reserve(AssetType::Native)
+        .unwrap()
+        .add_reserve(AssetType::issued(
             "USDC",
             "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
-        )
+        ))
+        .unwrap()
         .set_limit(2)
         .unwrap();
 
@@ -166,11 +181,13 @@ async fn test_get_all_liquidity_pools() {
         .is_empty());
 
     let all_liquidity_pools_request_2 = AllLiquidityPoolsRequest::new()
-        .add_native_reserve()
-        .add_alphanumeric12_reserve(
+        .add_reserve(AssetType::Native)
+        .unwrap()
+        .add_reserve(AssetType::issued(
             "FLUTTER",
             "GCGTOQSNERFVVJ6Y7YZYDF3MTZIY63KIEFMKA26Q7YPV3AFYD2JSRNYN",
-        )
+        ))
+        .unwrap()
         .set_limit(2)
         .unwrap();
 
@@ -222,10 +239,11 @@ async fn test_get_all_liquidity_pools() {
     );
 
     let all_liquidity_pools_request_3 = AllLiquidityPoolsRequest::new()
-        .add_alphanumeric4_reserve(
+        .add_reserve(AssetType::issued(
             "SDK",
             "GAGTRBIF75N7NUA37JGGJZKXIS4JJKTQERRFWTP5DN4SM4OC2T6QPMQB",
-        )
+        ))
+        .unwrap()
         .set_limit(2)
         .unwrap();
 
@@ -277,6 +295,7 @@ async fn test_get_all_liquidity_pools() {
     );
 }
 
+#[cfg(test)]
 #[tokio::test]
 async fn test_get_single_liquidity_pool() {
     use crate::horizon_client::HorizonClient;
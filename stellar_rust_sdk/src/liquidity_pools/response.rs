@@ -13,6 +13,7 @@ use crate::models::{
 /// embedded records of liquidity pools.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AllLiquidityPoolsResponse {
     /// The links to the current, next, and previous pages of the response.
     #[serde(rename = "_links")]
@@ -28,6 +29,7 @@ pub struct AllLiquidityPoolsResponse {
 /// fee, type, reserves, and other relevant data.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct LiquidityPool {
     /// Navigational links related to the operation of the effect.
     #[serde(rename = "_links")]
@@ -63,6 +65,7 @@ pub struct LiquidityPool {
 /// issuer of the reserve.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Reserve {
     /// The asset code of the reserve.
     pub asset: String,
@@ -76,6 +79,7 @@ pub struct Reserve {
 /// providing quick navigation across different pages of the liquidity pool.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct RecordLink {
     /// The link to the current liquidity pool.
     #[serde(rename = "self")]
@@ -88,13 +92,13 @@ pub struct RecordLink {
 
 impl Response for AllLiquidityPoolsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
 
 impl Response for LiquidityPool {
     fn from_json(json: String) -> Result<Self, String> {
-        let ledger_record = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let ledger_record = crate::models::deserialize_json(&json)?;
 
         Ok(ledger_record)
     }
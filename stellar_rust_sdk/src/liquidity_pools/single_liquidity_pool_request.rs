@@ -1,11 +1,11 @@
 use crate::models::Request;
 
 /// Represents the liquidity pool ID.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct LiquidityPoolId(String);
 
 /// Represents the absence of a liquidity pool ID.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoLiquidityPoolId;
 
 /// Represents a request to fetch details of a single liquidity pool from the Horizon API.
@@ -34,8 +34,8 @@ pub struct NoLiquidityPoolId;
 /// // Use with HorizonClient::get_single_liquidity_pool
 /// ```
 ///
-#[derive(Default)]
-pub struct SingleLiquidityPoolRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct SingleLiquidityPoolRequest<I = NoLiquidityPoolId> {
     /// The liquidity pool's ID.
     pub liquidity_pool_id: I,
 }
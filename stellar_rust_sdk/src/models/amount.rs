@@ -0,0 +1,111 @@
+use super::serde_adapters::{format_stroops, parse_stroops};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents an amount of an asset as an exact number of stroops.
+///
+/// Horizon reports amounts as decimal strings with 7 decimals, such as `"100.5000000"`. Adding
+/// and comparing such strings, or the floating point numbers they parse into, is error-prone. An
+/// `Amount` stores the number of stroops, the smallest unit of an asset (`0.0000001`), so that
+/// amounts can be added and compared exactly. It is formatted and (de)serialized as a decimal
+/// string with 7 decimals, like Horizon does.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::Amount;
+///
+/// let amount: Amount = "100.5".parse().unwrap();
+/// assert_eq!(amount.stroops(), 1_005_000_000);
+/// assert_eq!(amount.to_string(), "100.5000000");
+///
+/// let total = amount.checked_add(Amount::from_stroops(1)).unwrap();
+/// assert_eq!(total.to_string(), "100.5000001");
+/// ```
+///
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct Amount(i64);
+
+impl Amount {
+    /// Creates an amount from a number of stroops.
+    pub fn from_stroops(stroops: i64) -> Self {
+        Amount(stroops)
+    }
+
+    /// Returns the number of stroops of the amount.
+    pub fn stroops(&self) -> i64 {
+        self.0
+    }
+
+    /// Adds two amounts, or returns `None` if the sum overflows.
+    pub fn checked_add(self, other: Amount) -> Option<Amount> {
+        self.0.checked_add(other.0).map(Amount)
+    }
+
+    /// Subtracts an amount from this amount, or returns `None` if the difference overflows.
+    pub fn checked_sub(self, other: Amount) -> Option<Amount> {
+        self.0.checked_sub(other.0).map(Amount)
+    }
+}
+
+impl FromStr for Amount {
+    type Err = String;
+
+    /// Parses an amount with up to 7 decimals, such as `100.5000000`. A leading `-` is accepted
+    /// for negative amounts, such as balance changes.
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        match amount.strip_prefix('-') {
+            Some(magnitude) => parse_stroops(magnitude)
+                .map(|stroops| Amount(-stroops))
+                .map_err(|_| format!("Invalid amount: {}", amount)),
+            None => parse_stroops(amount).map(Amount),
+        }
+    }
+}
+
+impl TryFrom<String> for Amount {
+    type Error = String;
+
+    fn try_from(amount: String) -> Result<Self, Self::Error> {
+        amount.parse()
+    }
+}
+
+impl From<Amount> for String {
+    fn from(amount: Amount) -> Self {
+        amount.to_string()
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_stroops(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount() {
+        assert_eq!("1".parse::<Amount>().unwrap().stroops(), 10_000_000);
+        assert_eq!("-0.0000001".parse::<Amount>().unwrap().stroops(), -1);
+        assert_eq!(Amount::from_stroops(-15_000_000).to_string(), "-1.5000000");
+        assert_eq!(
+            "1.00000001".parse::<Amount>().unwrap_err(),
+            "Invalid amount: 1.00000001"
+        );
+        assert_eq!("--1".parse::<Amount>().unwrap_err(), "Invalid amount: --1");
+
+        let amount: Amount = serde_json::from_str(r#""3.6000000""#).unwrap();
+        assert_eq!(amount.stroops(), 36_000_000);
+        assert_eq!(serde_json::to_string(&amount).unwrap(), r#""3.6000000""#);
+        assert!(Amount::from_stroops(i64::MAX)
+            .checked_add(Amount::from_stroops(1))
+            .is_none());
+    }
+}
@@ -1,7 +1,21 @@
+mod amount;
+mod muxed_account;
+pub mod pagination;
+mod price;
+mod raw_page;
 mod request_models;
 mod response_models;
+pub(crate) mod serde_adapters;
+pub mod strkey;
+mod xdr_asset_conversions;
+
+pub(crate) use muxed_account::{parse_muxed_account, resolve_account_id};
 
 pub mod prelude {
+    pub use super::amount::*;
+    pub use super::muxed_account::MuxedAccount;
+    pub use super::price::*;
+    pub use super::raw_page::*;
     pub use super::request_models::*;
     pub use super::response_models::*;
     pub use super::Request;
@@ -59,6 +73,16 @@ pub(crate) trait PostRequest {
 /// Implementers of this trait should provide the specific logic for these methods based on the
 /// type of request they represent.
 ///
+/// All requests of this crate implement `Eq` and `Hash`. Two requests are equal when they have
+/// the same parameters, and therefore build the same URL, so that requests can be deduplicated or
+/// used as keys of a cache. See also [`HorizonClient::set_request_coalescing`](crate::horizon_client::HorizonClient::set_request_coalescing).
+///
+/// All requests are created with `new()`, which is equal to `Default::default()`. Requests with
+/// required parameters start out without them, and their type parameters default to that state,
+/// so that the request can only be sent once the required setters have been called. Paginated
+/// requests can be reused for another query after calling `reset_pagination()`, and expose their
+/// pagination parameters through the [`Paginatable`](pagination::Paginatable) trait.
+///
 pub trait Request {
     /// Generates a query string from the request's parameters.
     ///
@@ -139,10 +163,39 @@ pub trait Response: Sized {
     fn from_json(json: String) -> Result<Self, String>;
 }
 
+/// Allows responses that are not modeled by the SDK to be retrieved as untyped JSON, for example
+/// using [`HorizonClient::execute`](crate::horizon_client::HorizonClient::execute).
+impl Response for serde_json::Value {
+    fn from_json(json: String) -> Result<Self, String> {
+        deserialize_json(&json)
+    }
+}
+
+/// Deserializes a JSON string into the requested type, reporting the exact location of a failure.
+///
+/// This function is used by the [`Response::from_json`] implementations throughout the crate. Unlike a
+/// plain `serde_json::from_str` call, a failure names the JSON path of the offending field (for example
+/// `_embedded.records[3].last_modified_ledger`) together with the type that was expected, which makes it
+/// straightforward to spot where a Horizon response no longer matches the models of this SDK.
+///
+/// # Arguments
+/// * `json` - A string slice containing the JSON data to be deserialized.
+///
+/// # Returns
+/// Returns the deserialized value, or an `Err(String)` in the form
+/// `"failed to deserialize at `<path>`: <reason>"` if the JSON does not match the expected structure.
+///
+pub(crate) fn deserialize_json<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, String> {
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|e| format!("failed to deserialize at `{}`: {}", e.path(), e.inner()))
+}
+
 /// Validates the format of a Stellar public key.
 ///
 /// This function checks whether the provided string is a valid Stellar public key. A valid
-/// public key must be 56 characters in length and start with the letter 'G'.
+/// public key must be 56 characters in length, start with the letter 'G', and decode as a
+/// [`StrKey`](strkey::StrKey) with a valid checksum.
 ///
 /// # Arguments
 /// * `public_key` - A string slice representing the public key to validate.
@@ -151,16 +204,11 @@ pub trait Response: Sized {
 /// * `Ok(())` if the public key meets the format criteria.
 /// * `Err(String)` with an error message if the public key is invalid.
 ///
-/// # Examples
-/// ```
-/// # use stellar_rs::models::is_public_key;
-/// assert!(is_public_key("GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO").is_ok());
-/// assert!(is_public_key("invalid_key").is_err());
-/// ```
+/// It is a utility function that is used throughout the crate where public key validation is
+/// necessary. It is not part of the public API; users of the crate validate public keys by
+/// parsing them into an [`AccountId`](crate::accounts::prelude::AccountId).
 ///
-/// It is a utility function that can be used throughout the system where public key validation is necessary.
-
-pub fn is_public_key(public_key: &str) -> Result<(), String> {
+pub(crate) fn is_public_key(public_key: &str) -> Result<(), String> {
     if public_key.len() != 56 {
         return Err("Public key must be 56 characters long".to_string());
     }
@@ -169,16 +217,49 @@ pub fn is_public_key(public_key: &str) -> Result<(), String> {
         return Err("Public key must start with G".to_string());
     }
 
+    if strkey::StrKey::decode(public_key).is_err() {
+        return Err("Public key has an invalid checksum".to_string());
+    }
+
     Ok(())
 }
 
+/// Validates that an amount is a positive number with up to 7 decimals, such as `100.5000000`.
+pub(crate) fn is_positive_amount(amount: &str) -> Result<(), String> {
+    match amount.parse::<prelude::Amount>() {
+        Ok(parsed) if parsed.stroops() > 0 => Ok(()),
+        _ => Err(format!("Amount must be a positive number: {}", amount)),
+    }
+}
+
+/// Validates the code and the issuer of an asset, including the checksum of the issuer.
+pub(crate) fn is_valid_asset(asset: &prelude::AssetType) -> Result<(), String> {
+    stellar_xdr::curr::Asset::try_from(asset).map(|_| ())
+}
+
+/// Collects the violations of a set of checks, as returned by the `validate` methods of requests.
+///
+/// Unlike the setters of a request, which fail at the first invalid value, `validate` runs every
+/// check, so that all violations can be reported at once, for example next to the fields of a form.
+///
+pub(crate) fn collect_violations(
+    checks: impl IntoIterator<Item = Result<(), String>>,
+) -> Result<(), Vec<String>> {
+    let violations: Vec<String> = checks.into_iter().filter_map(Result::err).collect();
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
 /// Represents an issued asset. Contains both the asset code and the issuer account ID,
 ///   formatted as "asset_code:issuer_account_id".
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IssuedAsset(String);
 
 /// A marker type to represent the native asset (XLM) without additional data.
-#[derive(Default, Clone, PartialEq, Debug)]
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct NativeAsset;
 
 /// Represents the variants of assets in the Stellar network.
@@ -187,6 +268,9 @@ pub struct NativeAsset;
 /// allowing for differentiation between native assets and issued assets within the Stellar ecosystem.
 /// It encapsulates the details of issued assets, including their code and issuer account ID.
 ///
+/// Assets can be converted into and from the `Asset` type of `stellar_xdr`, using `From` for the
+/// native asset and `TryFrom` for issued assets. The same conversions exist for [`AssetType`](prelude::AssetType).
+///
 /// # Examples
 ///
 /// ```
@@ -198,7 +282,7 @@ pub struct NativeAsset;
 /// let issued_asset = native_asset.set_issued("USD", "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7").unwrap();
 /// ```
 ///
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Asset<T> {
     asset: T,
 }
@@ -261,7 +345,7 @@ impl std::fmt::Display for Asset<IssuedAsset> {
 /// * `Asc` - Indicates ascending order.
 /// * `Desc` - Indicates descending order.
 ///
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Order {
     Asc,
     Desc,
@@ -276,30 +360,29 @@ impl std::fmt::Display for Order {
     }
 }
 
-#[derive(Default, Clone, PartialEq)]
-pub enum IncludeFailed {
-    True,
-    #[default]
-    False,
+/// Represents the related resources that Horizon embeds in the records of a response.
+///
+/// `Join` is used in requests for operations and payments to fetch a related resource in the same
+/// round trip, instead of following the links of every record.
+///
+/// # Variants
+/// * `Transactions` - Embeds the transaction of every record.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Join {
+    Transactions,
 }
 
-impl std::fmt::Display for IncludeFailed {
+impl std::fmt::Display for Join {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            IncludeFailed::True => write!(f, "true"),
-            IncludeFailed::False => write!(f, "false"),
+            Join::Transactions => write!(f, "transactions"),
         }
     }
 }
 
-impl std::fmt::Debug for IncludeFailed {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            IncludeFailed::True => write!(f, "true"),
-            IncludeFailed::False => write!(f, "false"),
-        }
-    }
-}
+#[allow(deprecated)]
+pub use crate::compat::IncludeFailed;
 
 #[cfg(test)]
 mod tests {
@@ -316,6 +399,15 @@ mod tests {
         let result = is_public_key("BAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO");
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), "Public key must start with G");
+        let result = is_public_key("GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFP");
+        assert_eq!(result.unwrap_err(), "Public key has an invalid checksum");
+    }
+
+    #[test]
+    fn test_value_response() {
+        let value = serde_json::Value::from_json(r#"{"horizon_version": "2.31.0"}"#.to_string());
+        assert_eq!(value.unwrap()["horizon_version"], "2.31.0");
+        assert!(serde_json::Value::from_json("<html>".to_string()).is_err());
     }
 
     use stellar_xdr::curr::{LedgerHeader, LedgerHeaderExt, Limits, ReadXdr, StellarValueExt};
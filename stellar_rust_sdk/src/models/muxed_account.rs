@@ -0,0 +1,186 @@
+use super::strkey::StrKey;
+use crate::accounts::prelude::AccountId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents a multiplexed (muxed) account, as introduced in Protocol 13 (CAP-27).
+///
+/// A muxed account combines the public key of an account with a 64-bit ID, so that a single
+/// account, such as the account of an exchange, can be shared by many users, which are told apart
+/// by their ID. A muxed account is written as an address starting with `M`. On the ledger, it is
+/// the underlying account that holds the balances; Horizon reports the muxed account of a
+/// transaction, operation or effect in addition to the underlying account, in its `*_muxed` and
+/// `*_muxed_id` fields.
+///
+/// Horizon indexes the transactions, operations, payments and effects of a muxed account by its
+/// underlying account. Requests that take an account ID therefore accept a muxed account, and
+/// resolve it to its underlying account.
+///
+/// A `MuxedAccount` is (de)serialized as its `M...` address, matching the representation used
+/// by Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::MuxedAccount;
+///
+/// let muxed_account: MuxedAccount =
+///     "MBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OAAAAAAAAAAAPOGVY"
+///         .parse()
+///         .unwrap();
+/// assert_eq!(muxed_account.id(), 123);
+/// assert_eq!(
+///     muxed_account.account_id().as_str(),
+///     "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H"
+/// );
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MuxedAccount {
+    /// The `M...` address of the muxed account.
+    address: String,
+    /// The underlying account.
+    account_id: AccountId,
+    /// The ID of the muxed account.
+    id: u64,
+}
+
+impl MuxedAccount {
+    /// Creates a new `MuxedAccount` from an account and an ID.
+    ///
+    /// # Arguments
+    /// * `account_id` - The public key of the underlying account.
+    /// * `id` - The ID of the muxed account.
+    ///
+    /// # Returns
+    /// The `MuxedAccount`, or an error if `account_id` is not a public key.
+    ///
+    pub fn new(account_id: impl Into<String>, id: u64) -> Result<Self, String> {
+        let account_id = AccountId::new(account_id)?;
+        let public_key = match StrKey::decode(account_id.as_str())? {
+            StrKey::PublicKey(public_key) => public_key,
+            _ => return Err("Public key must start with G".to_string()),
+        };
+
+        Ok(MuxedAccount {
+            address: StrKey::MuxedAccount { public_key, id }.encode(),
+            account_id,
+            id,
+        })
+    }
+
+    /// Parses a muxed account from its `M...` address.
+    ///
+    /// # Returns
+    /// The `MuxedAccount`, or an error if the address is not a valid muxed account address.
+    ///
+    pub fn from_address(address: &str) -> Result<Self, String> {
+        match StrKey::decode(address) {
+            Ok(StrKey::MuxedAccount { public_key, id }) => Ok(MuxedAccount {
+                address: address.to_string(),
+                account_id: AccountId::new(StrKey::PublicKey(public_key).encode())?,
+                id,
+            }),
+            _ => Err(format!("Invalid muxed account: {}", address)),
+        }
+    }
+
+    /// Returns the `M...` address of the muxed account.
+    pub fn as_str(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns the underlying account, which holds the balances of the muxed account.
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    /// Returns the ID of the muxed account.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl FromStr for MuxedAccount {
+    type Err = String;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        MuxedAccount::from_address(address)
+    }
+}
+
+impl TryFrom<String> for MuxedAccount {
+    type Error = String;
+
+    fn try_from(address: String) -> Result<Self, Self::Error> {
+        MuxedAccount::from_address(&address)
+    }
+}
+
+impl From<MuxedAccount> for String {
+    fn from(muxed_account: MuxedAccount) -> Self {
+        muxed_account.address
+    }
+}
+
+impl fmt::Display for MuxedAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+/// Resolves an address to the account that Horizon indexes it by.
+///
+/// Horizon indexes the transactions, operations, payments and effects of a muxed account by its
+/// underlying account, so a `M...` address is replaced by the `G...` address of its underlying
+/// account. Any other value is returned unchanged, to be validated by the caller.
+///
+pub(crate) fn resolve_account_id(address: String) -> String {
+    match MuxedAccount::from_address(&address) {
+        Ok(muxed_account) => muxed_account.account_id.into(),
+        Err(_) => address,
+    }
+}
+
+/// Parses the muxed account of a response from its optional `*_muxed` field.
+pub(crate) fn parse_muxed_account(address: Option<&str>) -> Option<Result<MuxedAccount, String>> {
+    address.map(MuxedAccount::from_address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT_ID: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+    const MUXED_ACCOUNT: &str =
+        "MBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OAAAAAAAAAAAPOGVY";
+
+    #[test]
+    fn test_muxed_account() {
+        let muxed_account = MuxedAccount::new(ACCOUNT_ID, 123).unwrap();
+        assert_eq!(muxed_account.as_str(), MUXED_ACCOUNT);
+        assert_eq!(muxed_account, MUXED_ACCOUNT.parse().unwrap());
+        assert_eq!(muxed_account.account_id().as_str(), ACCOUNT_ID);
+        assert_eq!(muxed_account.id(), 123);
+
+        assert!(MuxedAccount::new(MUXED_ACCOUNT, 1).is_err());
+        assert_eq!(
+            ACCOUNT_ID.parse::<MuxedAccount>().unwrap_err(),
+            format!("Invalid muxed account: {}", ACCOUNT_ID)
+        );
+
+        assert_eq!(
+            serde_json::to_string(&muxed_account).unwrap(),
+            format!("\"{}\"", MUXED_ACCOUNT)
+        );
+        assert!(serde_json::from_str::<MuxedAccount>("\"MBRP\"").is_err());
+    }
+
+    #[test]
+    fn test_resolve_account_id() {
+        assert_eq!(resolve_account_id(MUXED_ACCOUNT.to_string()), ACCOUNT_ID);
+        assert_eq!(resolve_account_id(ACCOUNT_ID.to_string()), ACCOUNT_ID);
+        assert_eq!(resolve_account_id("invalid".to_string()), "invalid");
+    }
+}
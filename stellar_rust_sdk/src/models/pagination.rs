@@ -0,0 +1,170 @@
+use super::Order;
+
+/// The largest number of records Horizon returns in a single page.
+pub const MAX_LIMIT: u8 = 200;
+
+/// Represents the pagination parameters of a request to a collection endpoint: the cursor to
+/// start after, the maximum number of records per page, and their order.
+///
+/// Every paginated request embeds a `PageParams`, and exposes it through the [`Paginatable`]
+/// trait. The setters of the requests, such as `set_cursor` and `set_limit`, delegate to the
+/// setters of this struct, so that all requests validate their pagination parameters the same
+/// way.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::pagination::PageParams;
+/// use stellar_rs::models::Order;
+///
+/// let mut page_params = PageParams::new();
+/// page_params.set_cursor(2314987376641).unwrap();
+/// page_params.set_order(Order::Desc);
+/// assert_eq!(page_params.cursor(), Some(2314987376641));
+/// assert!(page_params.set_limit(0).is_err());
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PageParams {
+    pub(crate) cursor: Option<u64>,
+    pub(crate) limit: Option<u8>,
+    pub(crate) order: Option<Order>,
+}
+
+impl PageParams {
+    /// Creates pagination parameters without a cursor, limit or order, so that Horizon returns
+    /// the first page with its default page size and order.
+    pub fn new() -> Self {
+        PageParams::default()
+    }
+
+    /// Returns the cursor, which is the paging token of the record the page starts after.
+    pub fn cursor(&self) -> Option<u64> {
+        self.cursor
+    }
+
+    /// Returns the maximum number of records per page. Horizon defaults to 10 if it is not set.
+    pub fn limit(&self) -> Option<u8> {
+        self.limit
+    }
+
+    /// Returns the order of the records. Horizon defaults to ascending if it is not set.
+    pub fn order(&self) -> Option<&Order> {
+        self.order.as_ref()
+    }
+
+    /// Sets the cursor, which is typically the `paging_token` of a record.
+    ///
+    /// # Errors
+    /// Returns an error if the cursor is less than 1.
+    ///
+    pub fn set_cursor(&mut self, cursor: u64) -> Result<(), String> {
+        if cursor < 1 {
+            return Err("Cursor must be greater than or equal to 1.".to_string());
+        }
+
+        self.cursor = Some(cursor);
+        Ok(())
+    }
+
+    /// Removes the cursor, so that the records start at the beginning of the collection again.
+    pub fn clear_cursor(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Sets the maximum number of records per page.
+    ///
+    /// # Errors
+    /// Returns an error if the limit is not between 1 and [`MAX_LIMIT`].
+    ///
+    pub fn set_limit(&mut self, limit: u8) -> Result<(), String> {
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(format!("Limit must be between 1 and {}.", MAX_LIMIT));
+        }
+
+        self.limit = Some(limit);
+        Ok(())
+    }
+
+    /// Sets the maximum number of records per page to [`MAX_LIMIT`], so that paging through a
+    /// collection takes the fewest requests.
+    pub fn set_max_limit(&mut self) {
+        self.limit = Some(MAX_LIMIT);
+    }
+
+    /// Sets the order of the records.
+    pub fn set_order(&mut self, order: Order) {
+        self.order = Some(order);
+    }
+
+    /// Clears the cursor, limit and order.
+    pub fn reset(&mut self) {
+        *self = PageParams::default();
+    }
+}
+
+/// Provides access to the pagination parameters of a request to a collection endpoint.
+///
+/// The trait is implemented by every paginated request, so that code that pages through a
+/// collection, such as a loop that fetches page after page, can move the cursor of any request
+/// without knowing its type.
+///
+/// Requests implement the trait by embedding a [`PageParams`] field named `page_params`, and
+/// applying the `#[pagination]` attribute, which also generates the `set_cursor`, `set_limit`,
+/// `set_max_limit`, `set_order` and `reset_pagination` setters of the request.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::pagination::Paginatable;
+/// use stellar_rs::models::Request;
+/// use stellar_rs::transactions::prelude::AllTransactionsRequest;
+///
+/// /// Moves the request to the page after the given paging token.
+/// fn after<R: Paginatable>(mut request: R, paging_token: u64) -> Result<R, String> {
+///     request.page_params_mut().set_cursor(paging_token)?;
+///     Ok(request)
+/// }
+///
+/// let request = AllTransactionsRequest::new().set_limit(2).unwrap();
+/// let next = after(request, 2314987376641).unwrap();
+/// assert_eq!(next.page_params().cursor(), Some(2314987376641));
+/// assert_eq!(next.get_query_parameters(), "?cursor=2314987376641&limit=2");
+/// ```
+///
+pub trait Paginatable {
+    /// Returns the pagination parameters of the request.
+    fn page_params(&self) -> &PageParams;
+
+    /// Returns the pagination parameters of the request, for modification.
+    fn page_params_mut(&mut self) -> &mut PageParams;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_params() {
+        let mut page_params = PageParams::new();
+        assert_eq!(
+            page_params.set_cursor(0),
+            Err("Cursor must be greater than or equal to 1.".to_string())
+        );
+        assert_eq!(
+            page_params.set_limit(201),
+            Err("Limit must be between 1 and 200.".to_string())
+        );
+        assert_eq!(page_params, PageParams::default());
+
+        page_params.set_cursor(12345).unwrap();
+        page_params.set_max_limit();
+        page_params.set_order(Order::Asc);
+        assert_eq!(page_params.cursor(), Some(12345));
+        assert_eq!(page_params.limit(), Some(MAX_LIMIT));
+        assert_eq!(page_params.order(), Some(&Order::Asc));
+
+        page_params.clear_cursor();
+        assert_eq!(page_params.cursor(), None);
+        page_params.reset();
+        assert_eq!(page_params, PageParams::new());
+    }
+}
@@ -0,0 +1,230 @@
+use num_rational::Ratio;
+use std::fmt;
+use std::str::FromStr;
+
+/// The number of decimals Horizon uses when formatting prices.
+const DEFAULT_DECIMALS: usize = 7;
+
+/// The maximum number of decimals a price is formatted with. Any price times `10^19` fits in the
+/// 128-bit integers it is formatted with.
+const MAX_DECIMALS: usize = 19;
+
+/// Represents a price as an exact fraction of two integers.
+///
+/// Prices on the Stellar network are stored as fractions (`price_r`), while Horizon also reports
+/// them as decimal strings (`price`) rounded to 7 decimals. Converting these to floating point
+/// numbers loses precision, which makes comparing and inverting prices error-prone. A
+/// `RationalPrice` performs all arithmetic on a [`Ratio`] of integers instead:
+///
+/// * It is always stored in its reduced form, so equal prices are equal regardless of how they
+///   were written (`2/4` equals `1/2`).
+/// * Prices are compared exactly, without rounding.
+/// * A price can be inverted exactly, to express it in the flipped market (selling the counter
+///   asset for the base asset).
+///
+/// The price fractions of offers, order books and trades can be converted into a `RationalPrice`
+/// using `TryFrom`.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::RationalPrice;
+///
+/// let price: RationalPrice = "0.25".parse().unwrap();
+/// assert_eq!(price, RationalPrice::new(1, 4).unwrap());
+///
+/// let inverted = price.invert().unwrap();
+/// assert_eq!(inverted.to_string(), "4.0000000");
+/// assert!(inverted > price);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RationalPrice {
+    /// The reduced fraction, of which the denominator is never zero.
+    ratio: Ratio<u64>,
+}
+
+impl RationalPrice {
+    /// Creates a new `RationalPrice` from a numerator and a denominator, reducing the fraction.
+    ///
+    /// # Arguments
+    /// * `numerator` - The numerator of the price.
+    /// * `denominator` - The denominator of the price.
+    ///
+    /// # Returns
+    /// The price, or an error if the denominator is zero.
+    ///
+    pub fn new(numerator: u64, denominator: u64) -> Result<Self, String> {
+        if denominator == 0 {
+            return Err("Price denominator must not be zero.".to_string());
+        }
+
+        Ok(RationalPrice {
+            ratio: Ratio::new(numerator, denominator),
+        })
+    }
+
+    /// Returns the numerator of the reduced fraction.
+    pub fn numerator(&self) -> u64 {
+        *self.ratio.numer()
+    }
+
+    /// Returns the denominator of the reduced fraction.
+    pub fn denominator(&self) -> u64 {
+        *self.ratio.denom()
+    }
+
+    /// Returns the price in the flipped market, i.e. the reciprocal of this price.
+    ///
+    /// # Returns
+    /// The inverted price, or an error if this price is zero.
+    ///
+    pub fn invert(&self) -> Result<Self, String> {
+        if self.numerator() == 0 {
+            return Err("A price of zero can not be inverted.".to_string());
+        }
+
+        Ok(RationalPrice {
+            ratio: self.ratio.recip(),
+        })
+    }
+
+    /// Formats the price as a decimal string, rounded half away from zero.
+    ///
+    /// # Arguments
+    /// * `decimals` - The number of decimals, of at most 19. Horizon uses 7 decimals, which is
+    ///   also what the `Display` implementation uses.
+    ///
+    pub fn to_decimal_string(&self, decimals: usize) -> String {
+        let decimals = decimals.min(MAX_DECIMALS);
+        let scale = 10u128.pow(decimals as u32);
+
+        let ratio = Ratio::new_raw(u128::from(self.numerator()), u128::from(self.denominator()));
+        let scaled = (ratio * scale).round().to_integer();
+
+        if decimals == 0 {
+            scaled.to_string()
+        } else {
+            format!(
+                "{}.{:0width$}",
+                scaled / scale,
+                scaled % scale,
+                width = decimals
+            )
+        }
+    }
+}
+
+impl FromStr for RationalPrice {
+    type Err = String;
+
+    /// Parses a non-negative decimal string, such as `"1.2500000"`, into an exact price.
+    fn from_str(price: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid price: {}", price);
+
+        let (whole, fraction) = price.split_once('.').unwrap_or((price, ""));
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            return Err(invalid());
+        }
+
+        // Trailing zeros do not change the value, and would only limit the supported precision.
+        let fraction = fraction.trim_end_matches('0');
+        let denominator = u32::try_from(fraction.len())
+            .ok()
+            .and_then(|decimals| 10u64.checked_pow(decimals))
+            .ok_or_else(invalid)?;
+        let numerator = format!("{}{}", whole, fraction)
+            .parse::<u64>()
+            .map_err(|_| invalid())?;
+
+        RationalPrice::new(numerator, denominator)
+    }
+}
+
+impl fmt::Display for RationalPrice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_decimal_string(DEFAULT_DECIMALS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_new_reduces() {
+        let price = RationalPrice::new(250, 1000).unwrap();
+        assert_eq!(price.numerator(), 1);
+        assert_eq!(price.denominator(), 4);
+        assert_eq!(price, RationalPrice::new(2, 8).unwrap());
+        assert_eq!(
+            RationalPrice::new(0, 5).unwrap(),
+            RationalPrice::new(0, 1).unwrap()
+        );
+        assert!(RationalPrice::new(1, 0).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "1.2500000".parse::<RationalPrice>().unwrap(),
+            RationalPrice::new(5, 4).unwrap()
+        );
+        assert_eq!(
+            "3".parse::<RationalPrice>().unwrap(),
+            RationalPrice::new(3, 1).unwrap()
+        );
+        assert!("".parse::<RationalPrice>().is_err());
+        assert!("-1.5".parse::<RationalPrice>().is_err());
+        assert!("1.2.3".parse::<RationalPrice>().is_err());
+        assert_eq!(
+            "0.1234567890123456789".parse::<RationalPrice>().unwrap(),
+            RationalPrice::new(1234567890123456789, 10000000000000000000).unwrap()
+        );
+        assert!("0.12345678901234567891".parse::<RationalPrice>().is_err());
+    }
+
+    #[test]
+    fn test_to_decimal_string() {
+        let price = RationalPrice::new(1, 3).unwrap();
+        assert_eq!(price.to_string(), "0.3333333");
+        assert_eq!(RationalPrice::new(2, 3).unwrap().to_string(), "0.6666667");
+        assert_eq!(price.to_decimal_string(0), "0");
+        assert_eq!(
+            RationalPrice::new(19999999, 10000000)
+                .unwrap()
+                .to_decimal_string(2),
+            "2.00"
+        );
+        assert_eq!(
+            RationalPrice::new(u64::MAX, 1)
+                .unwrap()
+                .to_decimal_string(19),
+            format!("{}.{}", u64::MAX, "0".repeat(19))
+        );
+    }
+
+    #[test]
+    fn test_invert_and_compare() {
+        let price = RationalPrice::new(2, 7).unwrap();
+        let inverted = price.invert().unwrap();
+        assert_eq!(inverted, RationalPrice::new(7, 2).unwrap());
+        assert_eq!(inverted.invert().unwrap(), price);
+        assert!(RationalPrice::new(0, 1).unwrap().invert().is_err());
+
+        let a = RationalPrice::new(1, 10).unwrap();
+        let b = RationalPrice::new(2, 10).unwrap();
+        assert!(a < b);
+        assert_eq!(
+            RationalPrice::new(3, 10).unwrap(),
+            "0.3".parse::<RationalPrice>().unwrap()
+        );
+        assert_eq!(
+            RationalPrice::new(u64::MAX, u64::MAX - 1)
+                .unwrap()
+                .cmp(&RationalPrice::new(1, 1).unwrap()),
+            Ordering::Greater
+        );
+    }
+}
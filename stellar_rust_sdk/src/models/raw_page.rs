@@ -0,0 +1,176 @@
+use super::response_models::ResponseLinks;
+use super::Response;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+/// Represents a page of records, of which the records are kept as raw JSON.
+///
+/// Deserializing a page into typed responses allocates every field of every record, which
+/// dominates the cost of ingesting large amounts of history. A `RawPage` only locates the
+/// records in the response body, and keeps each of them as a single raw JSON string. Records can
+/// then be deserialized on demand, into any type that implements `Deserialize`. Types that borrow
+/// from the raw JSON (using `&str` or `Cow<str>` fields with `#[serde(borrow)]`), such as
+/// [`BorrowedOperation`](crate::operations::prelude::BorrowedOperation), avoid allocating for the
+/// fields they read.
+///
+/// A `RawPage` is returned by the
+/// [`HorizonClient::fetch_raw`](crate::horizon_client::HorizonClient::fetch_raw) method.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::*;
+/// use stellar_rs::operations::prelude::BorrowedOperation;
+///
+/// let json = r#"{
+///     "_links": {"self": {"href": "https://horizon-testnet.stellar.org/operations"}},
+///     "_embedded": {"records": [{
+///         "id": "2314987376641",
+///         "paging_token": "2314987376641",
+///         "transaction_successful": true,
+///         "source_account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+///         "type": "create_account",
+///         "type_i": 0,
+///         "created_at": "2024-06-11T21:36:12Z",
+///         "transaction_hash": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+///     }]}
+/// }"#;
+///
+/// let page = RawPage::from_json(json.to_string()).unwrap();
+/// let operations: Vec<BorrowedOperation> = page.deserialize_records().unwrap();
+/// assert_eq!(operations[0].type_field(), "create_account");
+/// ```
+///
+#[derive(Debug, Deserialize)]
+pub struct RawPage {
+    /// Navigational links to the current, next, and previous pages.
+    #[serde(rename = "_links")]
+    links: ResponseLinks,
+    /// The records of the page, as raw JSON.
+    #[serde(rename = "_embedded")]
+    embedded: RawEmbedded,
+}
+
+/// The embedded records of a [`RawPage`].
+#[derive(Debug, Deserialize)]
+struct RawEmbedded {
+    records: Vec<Box<RawValue>>,
+}
+
+impl RawPage {
+    /// Returns the navigational links of the page.
+    pub fn links(&self) -> &ResponseLinks {
+        &self.links
+    }
+
+    /// Returns the records of the page, as raw JSON.
+    pub fn records(&self) -> &[Box<RawValue>] {
+        &self.embedded.records
+    }
+
+    /// Returns the URL of the next page, if any.
+    pub fn next_url(&self) -> Option<&str> {
+        self.links.next.as_ref()?.href.as_deref()
+    }
+
+    /// Returns the number of records on the page.
+    pub fn len(&self) -> usize {
+        self.embedded.records.len()
+    }
+
+    /// Returns whether the page contains no records.
+    pub fn is_empty(&self) -> bool {
+        self.embedded.records.is_empty()
+    }
+
+    /// Deserializes a single record of the page.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the record on the page.
+    ///
+    /// # Returns
+    /// The deserialized record, `None` if the index is out of bounds, or an error if the record
+    /// does not match the expected structure.
+    ///
+    pub fn deserialize_record<'a, T: Deserialize<'a>>(
+        &'a self,
+        index: usize,
+    ) -> Option<Result<T, String>> {
+        let record = self.embedded.records.get(index)?;
+        Some(deserialize_raw(record))
+    }
+
+    /// Deserializes all records of the page.
+    ///
+    /// # Returns
+    /// The deserialized records, or an error if any record does not match the expected structure.
+    ///
+    pub fn deserialize_records<'a, T: Deserialize<'a>>(&'a self) -> Result<Vec<T>, String> {
+        self.embedded
+            .records
+            .iter()
+            .map(|record| deserialize_raw(record))
+            .collect()
+    }
+}
+
+impl Response for RawPage {
+    fn from_json(json: String) -> Result<Self, String> {
+        super::deserialize_json(&json)
+    }
+}
+
+/// Deserializes a raw record, which may borrow from the raw JSON.
+fn deserialize_raw<'a, T: Deserialize<'a>>(record: &'a RawValue) -> Result<T, String> {
+    let deserializer = &mut serde_json::Deserializer::from_str(record.get());
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|e| format!("failed to deserialize at `{}`: {}", e.path(), e.inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Record<'a> {
+        id: &'a str,
+    }
+
+    #[test]
+    fn test_raw_page() {
+        let json = r#"{
+            "_links": {
+                "self": {"href": "https://horizon-testnet.stellar.org/operations?cursor=&limit=2&order=asc"},
+                "next": {"href": "https://horizon-testnet.stellar.org/operations?cursor=2&limit=2&order=asc"}
+            },
+            "_embedded": {"records": [{"id": "1", "nested": {"a": [1, 2]}}, {"id": "2"}]}
+        }"#;
+
+        let page = RawPage::from_json(json.to_string()).unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(
+            page.records()[0].get(),
+            r#"{"id": "1", "nested": {"a": [1, 2]}}"#
+        );
+        assert_eq!(
+            page.next_url(),
+            Some("https://horizon-testnet.stellar.org/operations?cursor=2&limit=2&order=asc")
+        );
+
+        let records: Vec<Record> = page.deserialize_records().unwrap();
+        assert_eq!(records[1].id, "2");
+        assert!(page.deserialize_record::<Record>(2).is_none());
+        assert!(page.deserialize_record::<Record>(0).unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_raw_page_invalid_record() {
+        let json = r#"{
+            "_links": {"self": {"href": null}},
+            "_embedded": {"records": [{"id": 1}]}
+        }"#;
+
+        let page = RawPage::from_json(json.to_string()).unwrap();
+        let error = page.deserialize_records::<Record>().err().unwrap();
+        assert!(error.starts_with("failed to deserialize at `id`"));
+    }
+}
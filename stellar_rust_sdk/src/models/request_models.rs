@@ -1,12 +1,39 @@
+use super::{Asset, IssuedAsset, NativeAsset};
+use serde::{Deserialize, Serialize};
+
 /// Contains the details of a non-native asset.
-#[derive(Clone, PartialEq, Debug, Default)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub struct AssetData {
     pub asset_code: String,
     pub asset_issuer: String,
 }
 
 /// Represents the asset type of an asset.
-#[derive(Default, Clone, PartialEq, Debug)]
+///
+/// `AssetType` is the asset model shared by all requests and responses of the SDK. It is
+/// (de)serialized as an object with the fields `asset_type`, `asset_code` and `asset_issuer`,
+/// matching the representation used by Horizon, and formatted in its canonical form, `native` or
+/// `CODE:ISSUER`, which can be parsed back with [`FromStr`](std::str::FromStr). The generic
+/// [`Asset`] converts into an `AssetType`, so that requests accept either.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::AssetType;
+///
+/// let asset: AssetType = "USDC:GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(asset.asset_type(), "credit_alphanum4");
+/// assert_eq!(asset.asset_code(), Some("USDC"));
+/// assert_eq!(
+///     asset.to_query_parameters("selling"),
+///     "selling_asset_type=credit_alphanum4&selling_asset_code=USDC\
+///      &selling_asset_issuer=GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7"
+/// );
+/// ```
+///
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(try_from = "HorizonAsset", into = "HorizonAsset")]
 pub enum AssetType {
     /// A native asset_type type. It holds no value.
     #[default]
@@ -16,3 +43,332 @@ pub enum AssetType {
     /// An alphanumeric 12 asset_type type. It holds an Asset struct with asset code and asset issuer.
     Alphanumeric12(AssetData),
 }
+
+impl AssetType {
+    /// Creates an issued asset, choosing `Alphanumeric4` or `Alphanumeric12` based on the length
+    /// of the asset code.
+    ///
+    /// # Arguments
+    /// * `asset_code` - The code of the asset.
+    /// * `asset_issuer` - The account ID of the issuer of the asset.
+    ///
+    pub fn issued(asset_code: impl Into<String>, asset_issuer: impl Into<String>) -> Self {
+        let asset_data = AssetData {
+            asset_code: asset_code.into(),
+            asset_issuer: asset_issuer.into(),
+        };
+        match asset_data.asset_code.len() {
+            0..=4 => AssetType::Alphanumeric4(asset_data),
+            _ => AssetType::Alphanumeric12(asset_data),
+        }
+    }
+
+    /// Returns the type of the asset as named by Horizon: `native`, `credit_alphanum4` or
+    /// `credit_alphanum12`.
+    pub fn asset_type(&self) -> &'static str {
+        match self {
+            AssetType::Native => "native",
+            AssetType::Alphanumeric4(_) => "credit_alphanum4",
+            AssetType::Alphanumeric12(_) => "credit_alphanum12",
+        }
+    }
+
+    /// Returns the code of the asset, or `None` for the native asset.
+    pub fn asset_code(&self) -> Option<&str> {
+        self.asset_data().map(|data| data.asset_code.as_str())
+    }
+
+    /// Returns the account ID of the issuer of the asset, or `None` for the native asset.
+    pub fn asset_issuer(&self) -> Option<&str> {
+        self.asset_data().map(|data| data.asset_issuer.as_str())
+    }
+
+    /// Returns the code and issuer of the asset, or `None` for the native asset.
+    fn asset_data(&self) -> Option<&AssetData> {
+        match self {
+            AssetType::Native => None,
+            AssetType::Alphanumeric4(asset_data) | AssetType::Alphanumeric12(asset_data) => {
+                Some(asset_data)
+            }
+        }
+    }
+
+    /// Formats the asset as the query parameters Horizon uses to describe an asset, such as the
+    /// selling asset of an order book.
+    ///
+    /// # Arguments
+    /// * `prefix` - The prefix of the parameters, such as `selling` for `selling_asset_type`,
+    ///   `selling_asset_code` and `selling_asset_issuer`.
+    ///
+    /// # Returns
+    /// The `{prefix}_asset_type` parameter, followed by `{prefix}_asset_code` and
+    /// `{prefix}_asset_issuer` for an issued asset, separated by `&`.
+    ///
+    pub fn to_query_parameters(&self, prefix: &str) -> String {
+        match self.asset_data() {
+            None => format!("{}_asset_type={}", prefix, self.asset_type()),
+            Some(asset_data) => format!(
+                "{0}_asset_type={1}&{0}_asset_code={2}&{0}_asset_issuer={3}",
+                prefix,
+                self.asset_type(),
+                asset_data.asset_code,
+                asset_data.asset_issuer
+            ),
+        }
+    }
+
+    /// Combines the type, code and issuer of an asset, as returned by Horizon, into an
+    /// `AssetType`.
+    pub(crate) fn from_horizon(
+        asset_type: &str,
+        asset_code: Option<&str>,
+        asset_issuer: Option<&str>,
+    ) -> Result<Self, String> {
+        let asset_data = || match (asset_code, asset_issuer) {
+            (Some(asset_code), Some(asset_issuer)) => Ok(AssetData {
+                asset_code: asset_code.to_string(),
+                asset_issuer: asset_issuer.to_string(),
+            }),
+            _ => Err("Issued asset is missing its code or issuer".to_string()),
+        };
+
+        match asset_type {
+            "native" => Ok(AssetType::Native),
+            "credit_alphanum4" => Ok(AssetType::Alphanumeric4(asset_data()?)),
+            "credit_alphanum12" => Ok(AssetType::Alphanumeric12(asset_data()?)),
+            other => Err(format!("Unknown asset type: {}", other)),
+        }
+    }
+}
+
+impl From<Asset<NativeAsset>> for AssetType {
+    /// Converts the native asset into an `AssetType`.
+    fn from(_: Asset<NativeAsset>) -> Self {
+        AssetType::Native
+    }
+}
+
+impl From<Asset<IssuedAsset>> for AssetType {
+    /// Converts an issued asset into an `AssetType`, choosing `Alphanumeric4` or `Alphanumeric12`
+    /// based on the length of the asset code.
+    fn from(asset: Asset<IssuedAsset>) -> Self {
+        let asset = asset.to_string();
+        let (asset_code, asset_issuer) = asset.split_once(':').unwrap_or((&asset, ""));
+        AssetType::issued(asset_code, asset_issuer)
+    }
+}
+
+impl std::fmt::Display for AssetType {
+    /// Formats the asset in its canonical form: `native`, or `CODE:ISSUER` for an issued asset.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.asset_data() {
+            None => write!(f, "native"),
+            Some(asset_data) => write!(f, "{}:{}", asset_data.asset_code, asset_data.asset_issuer),
+        }
+    }
+}
+
+impl std::str::FromStr for AssetType {
+    type Err = String;
+
+    /// Parses an asset from its canonical form: `native`, or `CODE:ISSUER` for an issued asset.
+    fn from_str(asset: &str) -> Result<Self, Self::Err> {
+        match asset.split_once(':') {
+            None if asset == "native" => Ok(AssetType::Native),
+            Some((asset_code, asset_issuer))
+                if (1..=12).contains(&asset_code.len()) && !asset_issuer.is_empty() =>
+            {
+                Ok(AssetType::issued(asset_code, asset_issuer))
+            }
+            _ => Err(format!("Invalid asset: {}", asset)),
+        }
+    }
+}
+
+/// Formats a list of assets as a query parameter, such as the `reserves` of a liquidity pool, in
+/// which the assets are written in their canonical form and separated by commas. An empty list
+/// results in an empty string, so that the parameter is omitted.
+pub(crate) fn asset_list_parameter(name: &str, assets: &[AssetType]) -> String {
+    if assets.is_empty() {
+        return String::new();
+    }
+
+    let assets: Vec<String> = assets
+        .iter()
+        .map(|asset| asset.to_string().replace(':', "%3A"))
+        .collect();
+    format!("{}={}", name, assets.join("%2C"))
+}
+
+/// Represents a pair of assets that are traded against each other, such as the selling and buying
+/// asset of an order book.
+///
+/// The `base` asset is the asset that is sold, and the `counter` asset is the asset in which its
+/// price is expressed. Both assets accept anything that converts into an [`AssetType`], such as
+/// the generic [`Asset`].
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::prelude::{AssetPair, AssetType};
+/// use stellar_rs::models::Asset;
+///
+/// let usdc = Asset::new()
+///     .set_issued("USDC", "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .unwrap();
+/// let pair = AssetPair::new(AssetType::Native, usdc);
+/// assert_eq!(pair.reversed().counter(), &AssetType::Native);
+/// ```
+///
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct AssetPair {
+    base: AssetType,
+    counter: AssetType,
+}
+
+impl AssetPair {
+    /// Creates a new `AssetPair`.
+    ///
+    /// # Arguments
+    /// * `base` - The asset that is sold.
+    /// * `counter` - The asset in which the price of the base asset is expressed.
+    ///
+    pub fn new(base: impl Into<AssetType>, counter: impl Into<AssetType>) -> Self {
+        AssetPair {
+            base: base.into(),
+            counter: counter.into(),
+        }
+    }
+
+    /// Returns the asset that is sold.
+    pub fn base(&self) -> &AssetType {
+        &self.base
+    }
+
+    /// Returns the asset in which the price of the base asset is expressed.
+    pub fn counter(&self) -> &AssetType {
+        &self.counter
+    }
+
+    /// Returns the pair with the base and counter asset swapped, such as the other side of an
+    /// order book.
+    pub fn reversed(self) -> Self {
+        AssetPair {
+            base: self.counter,
+            counter: self.base,
+        }
+    }
+}
+
+/// The representation of an asset in the responses of Horizon.
+#[derive(Serialize, Deserialize)]
+struct HorizonAsset {
+    asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asset_issuer: Option<String>,
+}
+
+impl TryFrom<HorizonAsset> for AssetType {
+    type Error = String;
+
+    fn try_from(asset: HorizonAsset) -> Result<Self, Self::Error> {
+        AssetType::from_horizon(
+            &asset.asset_type,
+            asset.asset_code.as_deref(),
+            asset.asset_issuer.as_deref(),
+        )
+    }
+}
+
+impl From<AssetType> for HorizonAsset {
+    fn from(asset: AssetType) -> Self {
+        let (asset_type, asset_data) = match asset {
+            AssetType::Native => ("native", None),
+            AssetType::Alphanumeric4(asset_data) => ("credit_alphanum4", Some(asset_data)),
+            AssetType::Alphanumeric12(asset_data) => ("credit_alphanum12", Some(asset_data)),
+        };
+
+        HorizonAsset {
+            asset_type: asset_type.to_string(),
+            asset_code: asset_data.as_ref().map(|data| data.asset_code.clone()),
+            asset_issuer: asset_data.map(|data| data.asset_issuer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_asset_type_serde() {
+        let json = format!(
+            r#"{{"asset_type":"credit_alphanum4","asset_code":"USDC","asset_issuer":"{}"}}"#,
+            ISSUER
+        );
+        let asset: AssetType = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            asset,
+            AssetType::Alphanumeric4(AssetData {
+                asset_code: "USDC".to_string(),
+                asset_issuer: ISSUER.to_string(),
+            })
+        );
+        assert_eq!(serde_json::to_string(&asset).unwrap(), json);
+
+        let native: AssetType = serde_json::from_str(r#"{"asset_type":"native"}"#).unwrap();
+        assert_eq!(native, AssetType::Native);
+        assert_eq!(
+            serde_json::to_string(&native).unwrap(),
+            r#"{"asset_type":"native"}"#
+        );
+
+        assert!(serde_json::from_str::<AssetType>(r#"{"asset_type":"credit_alphanum4"}"#).is_err());
+        assert!(serde_json::from_str::<AssetType>(r#"{"asset_type":"unknown"}"#).is_err());
+    }
+
+    #[test]
+    fn test_asset_type_formats() {
+        let usdc = AssetType::issued("USDC", ISSUER);
+        assert_eq!(usdc.to_string(), format!("USDC:{}", ISSUER));
+        assert_eq!(usdc, usdc.to_string().parse().unwrap());
+        assert_eq!("native".parse::<AssetType>().unwrap(), AssetType::Native);
+        assert!("USDC".parse::<AssetType>().is_err());
+        assert!("LONGERTHAN12X:GA".parse::<AssetType>().is_err());
+
+        assert_eq!(
+            AssetType::Native.to_query_parameters("base"),
+            "base_asset_type=native"
+        );
+        assert_eq!(
+            AssetType::issued("LONGASSET", ISSUER).to_query_parameters("counter"),
+            format!(
+                "counter_asset_type=credit_alphanum12&counter_asset_code=LONGASSET&counter_asset_issuer={}",
+                ISSUER
+            )
+        );
+
+        assert_eq!(asset_list_parameter("reserves", &[]), "");
+        assert_eq!(
+            asset_list_parameter("reserves", &[AssetType::Native, usdc]),
+            format!("reserves=native%2CUSDC%3A{}", ISSUER)
+        );
+    }
+
+    #[test]
+    fn test_asset_to_asset_type() {
+        assert_eq!(AssetType::from(Asset::new()), AssetType::Native);
+
+        let asset = Asset::new().set_issued("LONGASSET", ISSUER).unwrap();
+        assert_eq!(
+            AssetType::from(asset),
+            AssetType::Alphanumeric12(AssetData {
+                asset_code: "LONGASSET".to_string(),
+                asset_issuer: ISSUER.to_string(),
+            })
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::pagination::PageParams;
 use crate::Getters;
 use serde::{Deserialize, Serialize};
 
@@ -6,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// Contains the links to the current, next, and previous pages of the response.
 ///
 #[derive(Default, Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct ResponseLinks {
     #[serde(rename = "self")]
     pub self_link: Link,
@@ -18,6 +20,7 @@ pub struct ResponseLinks {
 /// Contains an optional url.
 ///
 #[derive(Default, Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Link {
     pub href: Option<String>,
 }
@@ -27,15 +30,60 @@ pub struct Link {
 /// Contains an optional url, and an optional boolean to indicate whether a link is templated or not.
 ///
 #[derive(Default, Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct TemplateLink {
     pub href: Option<String>,
     pub templated: Option<bool>,
 }
 
+impl TemplateLink {
+    /// Resolves the template of the link, such as `{?cursor,limit,order}`, with the given
+    /// pagination parameters. Parameters that are not set are left out of the URL.
+    ///
+    /// # Returns
+    /// The URL of the link, or `None` if the link has no URL.
+    ///
+    pub fn expand(&self, page_params: &PageParams) -> Option<String> {
+        self.href
+            .as_deref()
+            .map(|href| expand_template(href, page_params))
+    }
+}
+
+/// Resolves the template of a link, such as `{?cursor,limit,order}`, with the given pagination
+/// parameters. Links without a template are returned as they are.
+pub(crate) fn expand_template(href: &str, page_params: &PageParams) -> String {
+    let Some((url, template)) = href.split_once('{') else {
+        return href.to_string();
+    };
+
+    let parameters: Vec<String> = template
+        .trim_end_matches('}')
+        .trim_start_matches(['?', '&'])
+        .split(',')
+        .filter_map(|name| {
+            let value = match name {
+                "cursor" => page_params.cursor().map(|cursor| cursor.to_string()),
+                "limit" => page_params.limit().map(|limit| limit.to_string()),
+                "order" => page_params.order().map(|order| order.to_string()),
+                _ => None,
+            }?;
+            Some(format!("{}={}", name, value))
+        })
+        .collect();
+    if parameters.is_empty() {
+        return url.to_string();
+    }
+
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}{}", url, separator, parameters.join("&"))
+}
+
 /// Represents a collection of results in a response.
 ///
 /// Contains a vector, which can hold any type of record returned by the API.
 #[derive(Default, Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Embedded<T> {
     pub records: Vec<T>,
 }
@@ -47,6 +95,7 @@ pub struct Embedded<T> {
 /// asset is controlled and can be used within the Stellar network.
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Flags {
     /// A `bool` indicating whether authorization is required for an account to hold
     ///   or transact with the asset. If `true`, the issuer must approve account holders.
@@ -61,3 +110,47 @@ pub struct Flags {
     ///   If `true`, the issuer can claw back the asset from user accounts.
     auth_clawback_enabled: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Order;
+
+    #[test]
+    fn test_expand_template() {
+        let link = TemplateLink {
+            href: Some(
+                "https://horizon-testnet.stellar.org/ledgers/1/operations{?cursor,limit,order}"
+                    .to_string(),
+            ),
+            templated: Some(true),
+        };
+        assert_eq!(
+            link.expand(&PageParams::new()).unwrap(),
+            "https://horizon-testnet.stellar.org/ledgers/1/operations"
+        );
+
+        let mut page_params = PageParams::new();
+        page_params.set_limit(5).unwrap();
+        page_params.set_order(Order::Desc);
+        assert_eq!(
+            link.expand(&page_params).unwrap(),
+            "https://horizon-testnet.stellar.org/ledgers/1/operations?limit=5&order=desc"
+        );
+
+        assert_eq!(
+            expand_template(
+                "https://horizon-testnet.stellar.org/effects?order=asc{&cursor}",
+                &page_params
+            ),
+            "https://horizon-testnet.stellar.org/effects?order=asc"
+        );
+        assert_eq!(
+            expand_template(
+                "https://horizon-testnet.stellar.org/ledgers/1",
+                &page_params
+            ),
+            "https://horizon-testnet.stellar.org/ledgers/1"
+        );
+    }
+}
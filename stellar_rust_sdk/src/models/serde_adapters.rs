@@ -0,0 +1,214 @@
+use super::prelude::RationalPrice;
+use crate::accounts::required_reserve::STROOPS_PER_XLM;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Formats an amount in stroops with 7 decimals, as returned by the Horizon API.
+pub(crate) fn format_stroops(stroops: i64) -> String {
+    let sign = if stroops < 0 { "-" } else { "" };
+    let stroops = stroops.unsigned_abs();
+    let per_xlm = STROOPS_PER_XLM as u64;
+    format!("{}{}.{:07}", sign, stroops / per_xlm, stroops % per_xlm)
+}
+
+/// Parses an amount with up to 7 decimals, as returned by the Horizon API, into stroops.
+pub(crate) fn parse_stroops(amount: &str) -> Result<i64, String> {
+    let invalid = || format!("Invalid amount: {}", amount);
+
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || fraction.len() > 7 || !is_digits(fraction) {
+        return Err(invalid());
+    }
+
+    let whole: i64 = whole.parse().map_err(|_| invalid())?;
+    let fraction: i64 = format!("{:0<7}", fraction).parse().map_err(|_| invalid())?;
+
+    whole
+        .checked_mul(STROOPS_PER_XLM)
+        .and_then(|stroops| stroops.checked_add(fraction))
+        .ok_or_else(invalid)
+}
+
+/// An integer that Horizon encodes either as a JSON string or as a JSON number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    String(String),
+    Number(u64),
+}
+
+impl StringOrNumber {
+    fn into_u64(self) -> Result<u64, String> {
+        match self {
+            StringOrNumber::String(value) => value
+                .parse()
+                .map_err(|_| format!("Invalid integer: {}", value)),
+            StringOrNumber::Number(value) => Ok(value),
+        }
+    }
+}
+
+/// (De)serializes an amount with 7 decimals, such as `"3.6000000"`, as a number of stroops.
+pub(crate) mod stroops {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        stroops: &i64,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_stroops(*stroops))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        let amount = String::deserialize(deserializer)?;
+        parse_stroops(&amount).map_err(D::Error::custom)
+    }
+}
+
+/// (De)serializes an optional integer that Horizon encodes as a string, such as an offer ID. Plain
+/// JSON numbers are accepted as well; the integer is always serialized as a string.
+pub(crate) mod option_u64_string {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(|value| value.to_string()).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        Option::<StringOrNumber>::deserialize(deserializer)?
+            .map(StringOrNumber::into_u64)
+            .transpose()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// (De)serializes an optional price fraction, such as `{"n": "3", "d": "10"}`, as a
+/// [`RationalPrice`]. Both parts may be encoded as strings or as numbers; they are always
+/// serialized as strings.
+pub(crate) mod option_price_fraction {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Fraction<T> {
+        n: T,
+        d: T,
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        price: &Option<RationalPrice>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        price
+            .map(|price| Fraction {
+                n: price.numerator().to_string(),
+                d: price.denominator().to_string(),
+            })
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RationalPrice>, D::Error> {
+        Option::<Fraction<StringOrNumber>>::deserialize(deserializer)?
+            .map(|fraction| RationalPrice::new(fraction.n.into_u64()?, fraction.d.into_u64()?))
+            .transpose()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// (De)serializes an optional list of signer keys, such as the extra signers of a transaction, as
+/// their strkeys, such as `GA...`, `TA...`, `XA...` or `PA...`.
+pub(crate) mod option_signer_keys {
+    use super::*;
+    use crate::accounts::prelude::SignerKey;
+
+    pub(crate) fn serialize<S: Serializer>(
+        signer_keys: &Option<Vec<SignerKey>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        signer_keys
+            .as_ref()
+            .map(|signer_keys| signer_keys.iter().map(SignerKey::key).collect::<Vec<_>>())
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<SignerKey>>, D::Error> {
+        Option::<Vec<String>>::deserialize(deserializer)?
+            .map(|signer_keys| {
+                signer_keys
+                    .iter()
+                    .map(|signer_key| signer_key.parse())
+                    .collect::<Result<Vec<SignerKey>, _>>()
+            })
+            .transpose()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "stroops")]
+        amount: i64,
+        #[serde(with = "option_u64_string", default)]
+        offer_id: Option<u64>,
+        #[serde(with = "option_price_fraction", default)]
+        price: Option<RationalPrice>,
+    }
+
+    #[test]
+    fn test_parse_stroops() {
+        assert_eq!(parse_stroops("4.9999600"), Ok(49_999_600));
+        assert_eq!(parse_stroops("10"), Ok(100_000_000));
+        assert_eq!(parse_stroops("0.5"), Ok(5_000_000));
+        assert!(parse_stroops("1.00000001").is_err());
+        assert!(parse_stroops("abc").is_err());
+        assert!(parse_stroops("-1.0").is_err());
+    }
+
+    #[test]
+    fn test_adapters_round_trip() {
+        let json =
+            r#"{"amount":"3.6000000","offer_id":"4612796834409107457","price":{"n":"3","d":"10"}}"#;
+        let record: Record = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            record,
+            Record {
+                amount: 36_000_000,
+                offer_id: Some(4612796834409107457),
+                price: Some(RationalPrice::new(3, 10).unwrap()),
+            }
+        );
+        assert_eq!(serde_json::to_string(&record).unwrap(), json);
+    }
+
+    #[test]
+    fn test_adapters_lenient_input() {
+        let record: Record =
+            serde_json::from_str(r#"{"amount":"1","offer_id":20,"price":{"n":10,"d":"4"}}"#)
+                .unwrap();
+        assert_eq!(record.offer_id, Some(20));
+        assert_eq!(record.price, Some(RationalPrice::new(5, 2).unwrap()));
+
+        let record: Record = serde_json::from_str(r#"{"amount":"1","offer_id":null}"#).unwrap();
+        assert_eq!(record.offer_id, None);
+        assert_eq!(record.price, None);
+
+        assert!(serde_json::from_str::<Record>(r#"{"amount":"-1"}"#).is_err());
+        assert!(
+            serde_json::from_str::<Record>(r#"{"amount":"1","price":{"n":"1","d":"0"}}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Record>(r#"{"amount":"1","offer_id":"-1"}"#).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents a key in the strkey format, the base32 encoding Stellar uses for keys and
+/// accounts, such as `GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO`.
+///
+/// A strkey consists of a version byte, which determines the type of the key and the letter the
+/// strkey starts with, the payload of the key, and a CRC16 checksum of both. Decoding a strkey
+/// validates the checksum, so that a mistyped key is rejected before it is sent to Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::models::strkey::StrKey;
+///
+/// let key: StrKey = "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO".parse().unwrap();
+/// assert!(matches!(key, StrKey::PublicKey(_)));
+/// assert_eq!(key.to_string(), "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO");
+///
+/// // A single changed character invalidates the checksum.
+/// assert!("GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFP".parse::<StrKey>().is_err());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrKey {
+    /// An ed25519 public key, which identifies an account. Starts with `G`.
+    PublicKey([u8; 32]),
+    /// An ed25519 secret seed, from which the key pair of an account is derived. Starts with `S`.
+    SecretSeed([u8; 32]),
+    /// A multiplexed account, which is an ed25519 public key combined with a 64-bit ID. Starts
+    /// with `M`.
+    MuxedAccount {
+        /// The ed25519 public key of the underlying account.
+        public_key: [u8; 32],
+        /// The ID that distinguishes the multiplexed account from others of the same account.
+        id: u64,
+    },
+    /// The hash of a pre-authorized transaction, used as a signer. Starts with `T`.
+    PreAuthTx([u8; 32]),
+    /// The SHA-256 hash of a preimage, used as a hash-x signer. Starts with `X`.
+    HashX([u8; 32]),
+}
+
+impl StrKey {
+    /// Decodes a strkey, validating its version byte, length and checksum.
+    ///
+    /// # Errors
+    /// Returns an error if the strkey is not valid, or if it is a valid strkey of a type that is
+    /// not supported, such as a signed payload (`P...`) or a contract (`C...`).
+    ///
+    pub fn decode(key: &str) -> Result<Self, String> {
+        use stellar_strkey::{ed25519, Strkey};
+
+        match Strkey::from_string(key).map_err(|_| format!("Invalid strkey: {}", key))? {
+            Strkey::PublicKeyEd25519(ed25519::PublicKey(bytes)) => Ok(StrKey::PublicKey(bytes)),
+            Strkey::PrivateKeyEd25519(ed25519::PrivateKey(bytes)) => Ok(StrKey::SecretSeed(bytes)),
+            Strkey::MuxedAccountEd25519(ed25519::MuxedAccount { ed25519, id }) => {
+                Ok(StrKey::MuxedAccount {
+                    public_key: ed25519,
+                    id,
+                })
+            }
+            Strkey::PreAuthTx(stellar_strkey::PreAuthTx(bytes)) => Ok(StrKey::PreAuthTx(bytes)),
+            Strkey::HashX(stellar_strkey::HashX(bytes)) => Ok(StrKey::HashX(bytes)),
+            Strkey::SignedPayloadEd25519(_) | Strkey::Contract(_) => {
+                Err(format!("Unsupported strkey type: {}", key))
+            }
+        }
+    }
+
+    /// Encodes the key as a strkey, including its version byte and checksum.
+    pub fn encode(&self) -> String {
+        use stellar_strkey::ed25519;
+
+        match *self {
+            StrKey::PublicKey(bytes) => ed25519::PublicKey(bytes).to_string(),
+            StrKey::SecretSeed(bytes) => ed25519::PrivateKey(bytes).to_string(),
+            StrKey::MuxedAccount { public_key, id } => ed25519::MuxedAccount {
+                ed25519: public_key,
+                id,
+            }
+            .to_string(),
+            StrKey::PreAuthTx(bytes) => stellar_strkey::PreAuthTx(bytes).to_string(),
+            StrKey::HashX(bytes) => stellar_strkey::HashX(bytes).to_string(),
+        }
+    }
+
+    /// Returns the letter the strkey of this type of key starts with.
+    pub fn prefix(&self) -> char {
+        match self {
+            StrKey::PublicKey(_) => 'G',
+            StrKey::SecretSeed(_) => 'S',
+            StrKey::MuxedAccount { .. } => 'M',
+            StrKey::PreAuthTx(_) => 'T',
+            StrKey::HashX(_) => 'X',
+        }
+    }
+}
+
+impl FromStr for StrKey {
+    type Err = String;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        StrKey::decode(key)
+    }
+}
+
+impl fmt::Display for StrKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strkey() {
+        let keys = [
+            StrKey::PublicKey([1; 32]),
+            StrKey::SecretSeed([2; 32]),
+            StrKey::MuxedAccount {
+                public_key: [3; 32],
+                id: 1234,
+            },
+            StrKey::PreAuthTx([4; 32]),
+            StrKey::HashX([5; 32]),
+        ];
+        for key in keys {
+            let encoded = key.encode();
+            assert!(encoded.starts_with(key.prefix()));
+            assert_eq!(encoded.parse(), Ok(key));
+        }
+
+        let public_key = "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO";
+        assert_eq!(StrKey::decode(public_key).unwrap().to_string(), public_key);
+
+        // A changed last character invalidates the checksum.
+        let invalid = "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFP";
+        assert_eq!(
+            StrKey::decode(invalid),
+            Err(format!("Invalid strkey: {}", invalid))
+        );
+
+        let contract = stellar_strkey::Contract([6; 32]).to_string();
+        assert_eq!(
+            StrKey::decode(&contract),
+            Err(format!("Unsupported strkey type: {}", contract))
+        );
+    }
+}
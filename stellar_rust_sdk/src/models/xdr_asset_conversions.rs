@@ -0,0 +1,236 @@
+//! Conversions between the asset types of the SDK and the `Asset` type of `stellar-xdr`.
+//!
+//! These allow the assets used in requests to be used when building or decoding XDR, such as
+//! transaction envelopes, without converting the representation by hand.
+//!
+use super::prelude::{AssetData, AssetType};
+use super::{Asset, IssuedAsset, NativeAsset};
+use std::str::FromStr;
+use stellar_xdr::curr as xdr;
+
+impl From<&Asset<NativeAsset>> for xdr::Asset {
+    fn from(_: &Asset<NativeAsset>) -> Self {
+        xdr::Asset::Native
+    }
+}
+
+impl TryFrom<&Asset<IssuedAsset>> for xdr::Asset {
+    type Error = String;
+
+    /// Converts an issued asset into an XDR asset, choosing `CreditAlphanum4` or
+    /// `CreditAlphanum12` based on the length of the asset code.
+    fn try_from(asset: &Asset<IssuedAsset>) -> Result<Self, Self::Error> {
+        let (asset_code, asset_issuer) = asset
+            .asset
+            .0
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid issued asset: {}", asset.asset.0))?;
+
+        match asset_code.len() {
+            1..=4 => credit_alphanum4(asset_code, asset_issuer),
+            _ => credit_alphanum12(asset_code, asset_issuer),
+        }
+    }
+}
+
+impl TryFrom<&AssetType> for xdr::Asset {
+    type Error = String;
+
+    /// Converts an asset type into an XDR asset. The asset code must fit the chosen variant: 1 to
+    /// 4 characters for `Alphanumeric4`, and 5 to 12 characters for `Alphanumeric12`.
+    fn try_from(asset: &AssetType) -> Result<Self, Self::Error> {
+        match asset {
+            AssetType::Native => Ok(xdr::Asset::Native),
+            AssetType::Alphanumeric4(asset_data) => {
+                credit_alphanum4(&asset_data.asset_code, &asset_data.asset_issuer)
+            }
+            AssetType::Alphanumeric12(asset_data) => {
+                credit_alphanum12(&asset_data.asset_code, &asset_data.asset_issuer)
+            }
+        }
+    }
+}
+
+impl From<&xdr::Asset> for AssetType {
+    fn from(asset: &xdr::Asset) -> Self {
+        match asset {
+            xdr::Asset::Native => AssetType::Native,
+            xdr::Asset::CreditAlphanum4(asset) => AssetType::Alphanumeric4(AssetData {
+                asset_code: asset.asset_code.to_string(),
+                asset_issuer: asset.issuer.to_string(),
+            }),
+            xdr::Asset::CreditAlphanum12(asset) => AssetType::Alphanumeric12(AssetData {
+                asset_code: asset.asset_code.to_string(),
+                asset_issuer: asset.issuer.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&xdr::Asset> for Asset<NativeAsset> {
+    type Error = String;
+
+    /// Converts an XDR asset into the native asset, failing if the XDR asset is an issued asset.
+    fn try_from(asset: &xdr::Asset) -> Result<Self, Self::Error> {
+        match asset {
+            xdr::Asset::Native => Ok(Asset::new()),
+            _ => Err("XDR asset is not the native asset".to_string()),
+        }
+    }
+}
+
+impl TryFrom<&xdr::Asset> for Asset<IssuedAsset> {
+    type Error = String;
+
+    /// Converts an XDR asset into an issued asset, failing if the XDR asset is the native asset.
+    fn try_from(asset: &xdr::Asset) -> Result<Self, Self::Error> {
+        match AssetType::from(asset) {
+            AssetType::Native => Err("XDR asset is not an issued asset".to_string()),
+            AssetType::Alphanumeric4(asset_data) | AssetType::Alphanumeric12(asset_data) => {
+                Asset::new().set_issued(&asset_data.asset_code, &asset_data.asset_issuer)
+            }
+        }
+    }
+}
+
+/// Builds a `CreditAlphanum4` asset, validating the asset code and the issuer.
+fn credit_alphanum4(asset_code: &str, asset_issuer: &str) -> Result<xdr::Asset, String> {
+    validate_asset_code(asset_code, 1..=4)?;
+
+    Ok(xdr::Asset::CreditAlphanum4(xdr::AlphaNum4 {
+        asset_code: xdr::AssetCode4::from_str(asset_code)
+            .map_err(|_| format!("Invalid asset code: {}", asset_code))?,
+        issuer: parse_issuer(asset_issuer)?,
+    }))
+}
+
+/// Builds a `CreditAlphanum12` asset, validating the asset code and the issuer.
+fn credit_alphanum12(asset_code: &str, asset_issuer: &str) -> Result<xdr::Asset, String> {
+    validate_asset_code(asset_code, 5..=12)?;
+
+    Ok(xdr::Asset::CreditAlphanum12(xdr::AlphaNum12 {
+        asset_code: xdr::AssetCode12::from_str(asset_code)
+            .map_err(|_| format!("Invalid asset code: {}", asset_code))?,
+        issuer: parse_issuer(asset_issuer)?,
+    }))
+}
+
+/// Validates that an asset code is alphanumeric, and that its length is within the given range.
+fn validate_asset_code(
+    asset_code: &str,
+    length: std::ops::RangeInclusive<usize>,
+) -> Result<(), String> {
+    if !length.contains(&asset_code.len()) || !asset_code.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(format!(
+            "Asset code must consist of {} to {} alphanumeric characters: {}",
+            length.start(),
+            length.end(),
+            asset_code
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses the public key of an asset issuer, including its checksum.
+fn parse_issuer(asset_issuer: &str) -> Result<xdr::AccountId, String> {
+    xdr::AccountId::from_str(asset_issuer)
+        .map_err(|_| format!("Invalid asset issuer: {}", asset_issuer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    #[test]
+    fn test_native_round_trip() {
+        let xdr_asset = xdr::Asset::from(&Asset::new());
+        assert_eq!(xdr_asset, xdr::Asset::Native);
+        assert_eq!(
+            Asset::<NativeAsset>::try_from(&xdr_asset)
+                .unwrap()
+                .to_string(),
+            "native"
+        );
+        assert_eq!(AssetType::from(&xdr_asset), AssetType::Native);
+        assert!(Asset::<IssuedAsset>::try_from(&xdr_asset).is_err());
+    }
+
+    #[test]
+    fn test_alphanum4_round_trip() {
+        let asset = Asset::new().set_issued("USDC", ISSUER).unwrap();
+        let xdr_asset = xdr::Asset::try_from(&asset).unwrap();
+        assert!(matches!(xdr_asset, xdr::Asset::CreditAlphanum4(_)));
+        assert_eq!(
+            Asset::<IssuedAsset>::try_from(&xdr_asset)
+                .unwrap()
+                .to_string(),
+            format!("USDC:{}", ISSUER)
+        );
+
+        let asset_type = AssetType::from(&xdr_asset);
+        assert_eq!(
+            asset_type,
+            AssetType::Alphanumeric4(AssetData {
+                asset_code: "USDC".to_string(),
+                asset_issuer: ISSUER.to_string(),
+            })
+        );
+        assert_eq!(xdr::Asset::try_from(&asset_type).unwrap(), xdr_asset);
+        assert!(Asset::<NativeAsset>::try_from(&xdr_asset).is_err());
+    }
+
+    #[test]
+    fn test_alphanum12_round_trip() {
+        let asset = Asset::new().set_issued("LONGASSET", ISSUER).unwrap();
+        let xdr_asset = xdr::Asset::try_from(&asset).unwrap();
+        assert!(matches!(xdr_asset, xdr::Asset::CreditAlphanum12(_)));
+        assert_eq!(
+            Asset::<IssuedAsset>::try_from(&xdr_asset)
+                .unwrap()
+                .to_string(),
+            format!("LONGASSET:{}", ISSUER)
+        );
+
+        let asset_type = AssetType::from(&xdr_asset);
+        assert_eq!(
+            asset_type,
+            AssetType::Alphanumeric12(AssetData {
+                asset_code: "LONGASSET".to_string(),
+                asset_issuer: ISSUER.to_string(),
+            })
+        );
+        assert_eq!(xdr::Asset::try_from(&asset_type).unwrap(), xdr_asset);
+    }
+
+    #[test]
+    fn test_invalid_assets() {
+        let asset_data = |asset_code: &str, asset_issuer: &str| AssetData {
+            asset_code: asset_code.to_string(),
+            asset_issuer: asset_issuer.to_string(),
+        };
+
+        // The asset code does not fit the variant.
+        assert!(
+            xdr::Asset::try_from(&AssetType::Alphanumeric4(asset_data("LONGASSET", ISSUER)))
+                .is_err()
+        );
+        assert!(
+            xdr::Asset::try_from(&AssetType::Alphanumeric12(asset_data("USDC", ISSUER))).is_err()
+        );
+        assert!(
+            xdr::Asset::try_from(&AssetType::Alphanumeric4(asset_data("US$", ISSUER))).is_err()
+        );
+
+        // The issuer has an invalid checksum.
+        let issuer = format!("{}A", &ISSUER[..55]);
+        assert_eq!(
+            xdr::Asset::try_from(&AssetType::Alphanumeric4(asset_data("USDC", &issuer)))
+                .unwrap_err(),
+            format!("Invalid asset issuer: {}", issuer)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use url::Url;
+
+/// The query parameters that Horizon only accepts on networks that run a recent enough protocol,
+/// along with the first protocol version that supports them.
+///
+/// When the protocol version of the network is known, the
+/// [`HorizonClient`](crate::horizon_client::HorizonClient) omits these parameters from requests to
+/// networks that run an older protocol, instead of having the request rejected.
+const PROTOCOL_GATED_PARAMETERS: &[(&str, u32)] = &[
+    // Claimable balances were introduced in protocol 14 (CAP-23).
+    ("claimant", 14),
+    // Sponsored reserves were introduced in protocol 15 (CAP-33).
+    ("sponsor", 15),
+    // Liquidity pools were introduced in protocol 18 (CAP-38).
+    ("liquidity_pool", 18),
+    ("reserves", 18),
+];
+
+/// Represents a Stellar network, along with the URL of its Horizon server and its passphrase.
+///
+/// The presets describe the well-known networks, and [`Network::Custom`] describes any other
+/// network, such as a private network. A network can be passed to
+/// [`HorizonClient::for_network`](crate::horizon_client::HorizonClient::for_network), to connect
+/// to its Horizon server with the matching passphrase, which is used to hash and sign
+/// transactions for the network.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::network::Network;
+/// let horizon_client = HorizonClient::for_network(Network::Futurenet).unwrap();
+/// assert_eq!(
+///     horizon_client.network_passphrase(),
+///     Some("Test SDF Future Network ; October 2022")
+/// );
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Network {
+    /// The public network, on which lumens and assets have real value.
+    Pubnet,
+    /// The test network, which is reset periodically.
+    Testnet,
+    /// The future network, which runs upcoming protocol versions, such as new Soroban features,
+    /// before they are released to the test network.
+    Futurenet,
+    /// The standalone network of a local `stellar/quickstart` container.
+    Sandbox,
+    /// Any other network, such as a private network, identified by its passphrase.
+    Custom {
+        /// The URL of the Horizon server of the network.
+        horizon_url: String,
+        /// The passphrase of the network.
+        passphrase: String,
+    },
+}
+
+/// The well-known networks, which are matched by [`Network::from_passphrase`].
+const PRESETS: [Network; 4] = [
+    Network::Pubnet,
+    Network::Testnet,
+    Network::Futurenet,
+    Network::Sandbox,
+];
+
+impl Network {
+    /// Creates a custom network, such as a private network, from the URL of its Horizon server
+    /// and its passphrase.
+    pub fn custom(horizon_url: impl Into<String>, passphrase: impl Into<String>) -> Self {
+        Network::Custom {
+            horizon_url: horizon_url.into(),
+            passphrase: passphrase.into(),
+        }
+    }
+
+    /// Returns the well-known network with the given passphrase, or `None` if the passphrase does
+    /// not belong to any of the presets.
+    pub fn from_passphrase(passphrase: &str) -> Option<Self> {
+        PRESETS
+            .into_iter()
+            .find(|network| network.passphrase() == passphrase)
+    }
+
+    /// Returns the URL of the Horizon server of the network.
+    pub fn horizon_url(&self) -> &str {
+        match self {
+            Network::Pubnet => "https://horizon.stellar.org",
+            Network::Testnet => "https://horizon-testnet.stellar.org",
+            Network::Futurenet => "https://horizon-futurenet.stellar.org",
+            Network::Sandbox => "http://localhost:8000",
+            Network::Custom { horizon_url, .. } => horizon_url,
+        }
+    }
+
+    /// Returns the passphrase of the network, which is part of the hash of its transactions.
+    pub fn passphrase(&self) -> &str {
+        match self {
+            Network::Pubnet => "Public Global Stellar Network ; September 2015",
+            Network::Testnet => "Test SDF Network ; September 2015",
+            Network::Futurenet => "Test SDF Future Network ; October 2022",
+            Network::Sandbox => "Standalone Network ; February 2017",
+            Network::Custom { passphrase, .. } => passphrase,
+        }
+    }
+}
+
+/// Returns the first protocol version that supports the given query parameter, or `None` if the
+/// parameter is supported by all protocol versions.
+pub fn min_protocol_version(parameter: &str) -> Option<u32> {
+    PROTOCOL_GATED_PARAMETERS
+        .iter()
+        .find(|(name, _)| *name == parameter)
+        .map(|(_, version)| *version)
+}
+
+/// Removes the query parameters that are not supported by the given protocol version from a URL.
+pub(crate) fn omit_unsupported_parameters(url: &str, protocol_version: u32) -> String {
+    let Ok(mut parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+    let is_supported =
+        |name: &str| min_protocol_version(name).is_none_or(|min| protocol_version >= min);
+    if parsed.query_pairs().all(|(name, _)| is_supported(&name)) {
+        return url.to_string();
+    }
+
+    let supported: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(name, _)| is_supported(name))
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    if supported.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(supported);
+    }
+    parsed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(
+            Network::Testnet.horizon_url(),
+            "https://horizon-testnet.stellar.org"
+        );
+        assert_eq!(
+            Network::Sandbox.passphrase(),
+            "Standalone Network ; February 2017"
+        );
+    }
+
+    #[test]
+    fn test_custom_network() {
+        let network = Network::custom("https://horizon.example.org", "Private Network ; 2024");
+        assert_eq!(network.horizon_url(), "https://horizon.example.org");
+        assert_eq!(network.passphrase(), "Private Network ; 2024");
+
+        assert_eq!(
+            Network::from_passphrase("Test SDF Network ; September 2015"),
+            Some(Network::Testnet)
+        );
+        assert_eq!(Network::from_passphrase(network.passphrase()), None);
+    }
+
+    #[test]
+    fn test_omit_unsupported_parameters() {
+        let url = "https://horizon.stellar.org/accounts?sponsor=GA&liquidity_pool=abc&limit=10";
+        assert_eq!(omit_unsupported_parameters(url, 20), url);
+        assert_eq!(
+            omit_unsupported_parameters(url, 17),
+            "https://horizon.stellar.org/accounts?sponsor=GA&limit=10"
+        );
+        assert_eq!(
+            omit_unsupported_parameters(url, 13),
+            "https://horizon.stellar.org/accounts?limit=10"
+        );
+        assert_eq!(
+            omit_unsupported_parameters("https://horizon.stellar.org/accounts?sponsor=GA", 13),
+            "https://horizon.stellar.org/accounts"
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::*;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to list all offers from the Stellar Horizon API.
 ///
@@ -32,7 +33,8 @@ use stellar_rust_sdk_derive::pagination;
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OFFERS_PATH)]
 pub struct AllOffersRequest {
     /// Optional. The ID of the sponsor. When set, the response will
     /// only include offers sponsored by the specified account.
@@ -46,30 +48,9 @@ pub struct AllOffersRequest {
     /// Optional. Indicates a buying asset for which offers are being queried.
     /// When set, the response will filter the offers that hold this specific asset.
     buying: Option<Asset<IssuedAsset>>,
-}
-
-impl Request for AllOffersRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.sponsor.as_ref().map(|s| format!("sponsor={}", s)),
-            self.seller.as_ref().map(|s| format!("seller={}", s)),
-            self.selling.as_ref().map(|s| format!("selling={}", s)),
-            self.buying.as_ref().map(|b| format!("buying={}", b)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
 
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}/{}",
-            base_url,
-            super::OFFERS_PATH,
-            self.get_query_parameters()
-        )
-    }
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllOffersRequest {
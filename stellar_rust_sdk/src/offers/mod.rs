@@ -25,6 +25,13 @@ pub mod all_offers_request;
 ///
 pub mod offers_for_account_request;
 
+/// Provides the `OfferId`.
+///
+/// This module provides the `OfferId` struct, a validated offer ID that is shared by the
+/// offer-related requests and responses, and by the [`TradesForOfferRequest`](crate::trades::prelude::TradesForOfferRequest).
+///
+pub mod offer_id;
+
 /// Provides the responses.
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -75,6 +82,7 @@ pub(crate) static OFFERS_PATH: &str = "offers";
 ///
 pub mod prelude {
     pub use super::all_offers_request::*;
+    pub use super::offer_id::*;
     pub use super::offers_for_account_request::*;
     pub use super::response::*;
     pub use super::single_offer_request::*;
@@ -83,7 +91,7 @@ pub mod prelude {
 #[cfg(test)]
 pub mod test {
     use super::prelude::*;
-    use crate::{horizon_client::HorizonClient, models::*};
+    use crate::{datetime, horizon_client::HorizonClient, models::*};
 
     #[tokio::test]
     async fn test_get_single_offer() {
@@ -125,7 +133,7 @@ pub mod test {
             response.links().offer_maker().href().as_ref().unwrap(),
             LINK_OFFER_MAKER
         );
-        assert_eq!(response.id(), OFFER_ID);
+        assert_eq!(response.id().to_string(), OFFER_ID);
         assert_eq!(response.paging_token(), PAGING_TOKEN);
         assert_eq!(response.seller(), SELLER);
         assert_eq!(response.selling().asset_type(), SELLING_ASSET_TYPE);
@@ -151,7 +159,10 @@ pub mod test {
         assert_eq!(response.price_ratio().denominator(), PRICE_R_D);
         assert_eq!(response.price_decimal(), PRICE);
         assert_eq!(response.last_modified_ledger(), LAST_MODIFIED_LEDGER);
-        assert_eq!(response.last_modified_time(), LAST_MODIFIED_TIME);
+        assert_eq!(
+            response.last_modified_time(),
+            &datetime::parse_rfc3339(LAST_MODIFIED_TIME).unwrap()
+        );
     }
 
     #[tokio::test]
@@ -191,7 +202,7 @@ pub mod test {
             record.links().offer_maker().href().as_ref().unwrap(),
             LINK_OFFER_MAKER
         );
-        assert_eq!(record.id(), OFFER_ID);
+        assert_eq!(record.id().to_string(), OFFER_ID);
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.seller(), SELLER);
         assert_eq!(record.selling().asset_type(), SELLING_ASSET_TYPE);
@@ -209,7 +220,10 @@ pub mod test {
         assert_eq!(record.price_ratio().denominator(), PRICE_R_D);
         assert_eq!(record.price_decimal(), PRICE);
         assert_eq!(record.last_modified_ledger(), LAST_MODIFIED_LEDGER);
-        assert_eq!(record.last_modified_time(), LAST_MODIFIED_TIME);
+        assert_eq!(
+            record.last_modified_time(),
+            &datetime::parse_rfc3339(LAST_MODIFIED_TIME).unwrap()
+        );
     }
 
     #[tokio::test]
@@ -256,7 +270,7 @@ pub mod test {
             record.links().offer_maker().href().as_ref().unwrap(),
             LINK_OFFER_MAKER
         );
-        assert_eq!(record.id(), OFFER_ID);
+        assert_eq!(record.id().to_string(), OFFER_ID);
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.seller(), SELLER);
         assert_eq!(record.selling().asset_type(), SELLING_ASSET_TYPE);
@@ -282,7 +296,10 @@ pub mod test {
         assert_eq!(record.price_ratio().denominator(), PRICE_R_D);
         assert_eq!(record.price_decimal(), PRICE);
         assert_eq!(record.last_modified_ledger(), LAST_MODIFIED_LEDGER);
-        assert_eq!(record.last_modified_time(), LAST_MODIFIED_TIME);
+        assert_eq!(
+            record.last_modified_time(),
+            &datetime::parse_rfc3339(LAST_MODIFIED_TIME).unwrap()
+        );
     }
 
     #[tokio::test]
@@ -329,7 +346,7 @@ pub mod test {
             record.links().offer_maker().href().as_ref().unwrap(),
             LINK_OFFER_MAKER
         );
-        assert_eq!(record.id(), OFFER_ID);
+        assert_eq!(record.id().to_string(), OFFER_ID);
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.seller(), SELLER);
         assert_eq!(record.selling().asset_type(), SELLING_ASSET_TYPE);
@@ -355,6 +372,9 @@ pub mod test {
         assert_eq!(record.price_ratio().denominator(), PRICE_R_D);
         assert_eq!(record.price_decimal(), PRICE);
         assert_eq!(record.last_modified_ledger(), LAST_MODIFIED_LEDGER);
-        assert_eq!(record.last_modified_time(), LAST_MODIFIED_TIME);
+        assert_eq!(
+            record.last_modified_time(),
+            &datetime::parse_rfc3339(LAST_MODIFIED_TIME).unwrap()
+        );
     }
 }
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the ID of an offer.
+///
+/// Offer IDs are positive 64-bit integers assigned by the network when an offer is created. The
+/// Horizon API represents them as strings; an `OfferId` validates such a string once, so that
+/// requests can not be built for IDs that can never refer to an offer, such as `0` or negative
+/// numbers.
+///
+/// An `OfferId` is (de)serialized as a string, matching the representation used by Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::offers::prelude::OfferId;
+///
+/// let offer_id: OfferId = "27493".parse().unwrap();
+/// assert_eq!(offer_id.value(), 27493);
+///
+/// assert!("0".parse::<OfferId>().is_err());
+/// assert!("abc".parse::<OfferId>().is_err());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct OfferId(u64);
+
+impl OfferId {
+    /// Creates a new `OfferId`.
+    ///
+    /// # Arguments
+    /// * `offer_id` - The numeric offer ID.
+    ///
+    /// # Returns
+    /// The `OfferId`, or an error if the ID is `0` or exceeds the largest offer ID the network can
+    /// represent (`i64::MAX`).
+    ///
+    pub fn new(offer_id: u64) -> Result<Self, String> {
+        if offer_id < 1 {
+            return Err("offer ID must be greater than or equal to 1".to_string());
+        }
+        if offer_id > i64::MAX as u64 {
+            return Err(format!(
+                "offer ID must be less than or equal to {}",
+                i64::MAX
+            ));
+        }
+
+        Ok(OfferId(offer_id))
+    }
+
+    /// Returns the numeric value of the offer ID.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for OfferId {
+    type Err = String;
+
+    fn from_str(offer_id: &str) -> Result<Self, Self::Err> {
+        let offer_id = offer_id
+            .parse::<u64>()
+            .map_err(|_| format!("invalid offer ID: {}", offer_id))?;
+
+        OfferId::new(offer_id)
+    }
+}
+
+impl TryFrom<String> for OfferId {
+    type Error = String;
+
+    fn try_from(offer_id: String) -> Result<Self, Self::Error> {
+        offer_id.parse()
+    }
+}
+
+impl From<OfferId> for String {
+    fn from(offer_id: OfferId) -> Self {
+        offer_id.to_string()
+    }
+}
+
+impl fmt::Display for OfferId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offer_id() {
+        assert_eq!("27493".parse::<OfferId>().unwrap().value(), 27493);
+        assert_eq!(
+            "0".parse::<OfferId>().unwrap_err(),
+            "offer ID must be greater than or equal to 1"
+        );
+        assert_eq!("-1".parse::<OfferId>().unwrap_err(), "invalid offer ID: -1");
+        assert!(OfferId::new(i64::MAX as u64 + 1).is_err());
+    }
+
+    #[test]
+    fn test_offer_id_serde() {
+        let offer_id: OfferId = serde_json::from_str("\"4612796834409107457\"").unwrap();
+        assert_eq!(offer_id.value(), 4612796834409107457);
+        assert_eq!(
+            serde_json::to_string(&offer_id).unwrap(),
+            "\"4612796834409107457\""
+        );
+        assert!(serde_json::from_str::<OfferId>("\"0\"").is_err());
+    }
+}
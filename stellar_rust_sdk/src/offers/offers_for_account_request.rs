@@ -1,19 +1,31 @@
+use crate::models::pagination::PageParams;
 use crate::models::*;
-use stellar_rust_sdk_derive::pagination;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of an account for which the offers are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct OfferAccountId(String);
 
+impl fmt::Display for OfferAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Represents the absence of the ID of an account for which the offers are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoOfferAccountId;
 
 #[pagination]
-#[derive(Default)]
-pub struct OffersForAccountRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OFFERS_PATH, parent = account_id)]
+pub struct OffersForAccountRequest<I = NoOfferAccountId> {
     /// The ID of the account for which the offers are to be retrieved.
     account_id: I,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl OffersForAccountRequest<NoOfferAccountId> {
@@ -22,43 +34,24 @@ impl OffersForAccountRequest<NoOfferAccountId> {
         OffersForAccountRequest::default()
     }
 
+    /// Sets the account ID for the request.
+    ///
+    /// # Arguments
+    /// * `account_id` - The account ID for which the offers are to be retrieved. A muxed account
+    ///   (`M...`) is resolved to its underlying account.
+    ///
     pub fn set_account_id(
         self,
-        account_id: impl Into<String>
+        account_id: impl Into<String>,
     ) -> Result<OffersForAccountRequest<OfferAccountId>, String> {
-        let account_id = account_id.into();
+        let account_id = resolve_account_id(account_id.into());
         if let Err(e) = is_public_key(&account_id) {
             return Err(e.to_string());
         }
 
         Ok(OffersForAccountRequest {
             account_id: OfferAccountId(account_id),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
-
-impl Request for OffersForAccountRequest<OfferAccountId> {
-    fn get_query_parameters(&self) -> String {
-        let mut query = String::new();
-        query.push_str(&format!("{}", self.account_id.0));
-
-        query.trim_end_matches('&').to_string()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL is not built with query paramaters, but with the account ID as addition to the path.
-        // Therefore there is no `?` but a `/` in the formatted string.
-        // Additionally, this request uses the API endpoint for `accounts`.
-        use crate::accounts::ACCOUNTS_PATH;
-        format!(
-            "{}/{}/{}/{}",
-            base_url,
-            ACCOUNTS_PATH,
-            self.get_query_parameters(),
-            super::OFFERS_PATH
-        )
-    }
-}
@@ -1,3 +1,6 @@
+use super::offer_id::OfferId;
+use crate::accounts::prelude::AccountId;
+use crate::datetime::DateTime;
 use crate::models::prelude::*;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
@@ -9,6 +12,7 @@ use serde::{Deserialize, Serialize};
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AllOffersResponse {
     #[serde(rename = "_links")]
     pub links: ResponseLinks,
@@ -18,7 +22,7 @@ pub struct AllOffersResponse {
 
 impl Response for AllOffersResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
@@ -30,6 +34,7 @@ impl Response for AllOffersResponse {
 /// code (optional) and issuer (optional).
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Transaction {
     /// The type of asset (e.g. "credit_alphanum4", "credit_alphanum12").
     asset_type: String,
@@ -45,6 +50,7 @@ pub struct Transaction {
 /// in a precise manner.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct PriceR {
     /// The numenator.
     #[serde(rename = "n")]
@@ -54,12 +60,22 @@ pub struct PriceR {
     denominator: u32,
 }
 
+impl TryFrom<&PriceR> for RationalPrice {
+    type Error = String;
+
+    /// Converts the price fraction into an exact [`RationalPrice`], failing if the denominator is zero.
+    fn try_from(price: &PriceR) -> Result<Self, Self::Error> {
+        RationalPrice::new(price.numenator as u64, price.denominator as u64)
+    }
+}
+
 /// Represents the navigational links in a single offer response from the Horizon API.
 ///
 /// This struct includes various hyperlinks such as links to the offer itself
 /// and the offer maker.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct OfferResponseLinks {
     /// The link to the offer itself.
     #[serde(rename = "self")]
@@ -75,16 +91,17 @@ pub struct OfferResponseLinks {
 /// the amount, the price and additional data.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct OfferResponse {
     /// Navigational links related to the offer.
     #[serde(rename = "_links")]
     links: OfferResponseLinks,
     /// The unique identifier for the offer.
-    id: String,
+    id: OfferId,
     /// A token used for paging through results.
     paging_token: String,
-    /// The ID of the seller making the offer.
-    seller: String,
+    /// The account ID of the seller making the offer.
+    seller: AccountId,
     /// The asset the offer wants to sell.
     selling: Transaction,
     /// The asset the offer wants to buy.
@@ -100,13 +117,83 @@ pub struct OfferResponse {
     /// The sequence number of the last ledger in which the offer was modified.
     last_modified_ledger: u32,
     /// The time at which the offer was last modified.
-    last_modified_time: String,
-    /// The account ID of the sponsor who is paying the reserves for this offer.
-    sponsor: Option<String>,
+    #[serde(with = "crate::datetime::rfc3339")]
+    last_modified_time: DateTime,
+    /// The account ID of the sponsor who is paying the reserves for this offer, if the offer is
+    /// sponsored.
+    sponsor: Option<AccountId>,
 }
 
 impl Response for OfferResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datetime;
+
+    const OFFER_FIXTURE: &str = r#"{
+        "_links": {
+            "self": {"href": "https://horizon-testnet.stellar.org/offers/27493"},
+            "offer_maker": {"href": "https://horizon-testnet.stellar.org/accounts/GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E"}
+        },
+        "id": "27493",
+        "paging_token": "27493",
+        "seller": "GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E",
+        "selling": {"asset_type": "native"},
+        "buying": {
+            "asset_type": "credit_alphanum4",
+            "asset_code": "USDC",
+            "asset_issuer": "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+        },
+        "amount": "20081.0000000",
+        "price_r": {"n": 50, "d": 467},
+        "price": "0.1070664",
+        "last_modified_ledger": 845805,
+        "last_modified_time": "2024-08-02T08:29:11Z",
+        "sponsor": "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7"
+    }"#;
+
+    #[test]
+    fn test_offer_response_fixture() {
+        let response = OfferResponse::from_json(OFFER_FIXTURE.to_string()).unwrap();
+        assert_eq!(
+            response.seller(),
+            "GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E"
+        );
+        assert_eq!(
+            response.last_modified_time(),
+            &datetime::parse_rfc3339("2024-08-02T08:29:11Z").unwrap()
+        );
+        assert_eq!(
+            response.sponsor().as_ref().unwrap(),
+            "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7"
+        );
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["last_modified_time"], "2024-08-02T08:29:11Z");
+        assert_eq!(
+            json["seller"],
+            "GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E"
+        );
+    }
+
+    #[test]
+    fn test_offer_response_without_sponsor() {
+        let json = OFFER_FIXTURE.replace(
+            r#""sponsor": "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7""#,
+            r#""sponsor": null"#,
+        );
+        let response = OfferResponse::from_json(json).unwrap();
+        assert!(response.sponsor().is_none());
+
+        let json = OFFER_FIXTURE.replace(
+            "GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E\",",
+            "GCXRNJ\",",
+        );
+        assert!(OfferResponse::from_json(json).is_err());
     }
 }
@@ -1,11 +1,8 @@
+use super::offer_id::OfferId;
 use crate::models::*;
 
-/// Represents the offer ID.
-#[derive(Default, Clone)]
-pub struct OfferId(String);
-
 /// Represents the absence of an offer ID.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoOfferId;
 
 /// Represents a request to fetch details of an offer from the Horizon API.
@@ -34,8 +31,8 @@ pub struct NoOfferId;
 /// // Use with HorizonClient::get_single_offer
 /// ```
 ///
-#[derive(Default)]
-pub struct SingleOfferRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct SingleOfferRequest<I = NoOfferId> {
     /// The ID of the offer to be retrieved.
     offer_id: I,
 }
@@ -54,27 +51,20 @@ impl SingleOfferRequest<NoOfferId> {
     /// # Returns
     /// A `SingleOfferRequest` with the specified offer ID, or an error if the offer ID is invalid.
     ///
-    pub fn set_offer_id(self, offer_id: impl Into<String>) -> Result<SingleOfferRequest<OfferId>, String> {
-        let offer_id = offer_id.into();
-        match offer_id.parse::<u32>() {
-            Ok(id) => {
-                if id > 0 {
-                    Ok(SingleOfferRequest {
-                        offer_id: OfferId(offer_id),
-                    })
-                } else {
-                    Err("offer ID must be greater than or equal to 1".to_string())
-                }
-            }
-            Err(_) => Err("invalid offer ID".to_string()),
-        }
+    pub fn set_offer_id(
+        self,
+        offer_id: impl Into<String>,
+    ) -> Result<SingleOfferRequest<OfferId>, String> {
+        Ok(SingleOfferRequest {
+            offer_id: offer_id.into().parse()?,
+        })
     }
 }
 
 impl Request for SingleOfferRequest<OfferId> {
     fn get_query_parameters(&self) -> String {
         let mut query = String::new();
-        query.push_str(&format!("{}", self.offer_id.0));
+        query.push_str(&format!("{}", self.offer_id));
 
         query.trim_end_matches('&').to_string()
     }
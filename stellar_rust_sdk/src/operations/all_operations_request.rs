@@ -1,11 +1,18 @@
-use crate::models::{IncludeFailed, Order, Request};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::Join;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OPERATIONS_PATH)]
 pub struct AllOperationsRequest {
     /// A boolean value that determines whether to include failed operations in the response.
-    include_failed: Option<IncludeFailed>,
+    include_failed: Option<bool>,
+    /// The related resources to embed in every operation.
+    join: Option<Join>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllOperationsRequest {
@@ -18,45 +25,31 @@ impl AllOperationsRequest {
     /// # Arguments
     /// * `include_failed` - A boolean value that determines whether to include failed operations in the response.
     ///
-    pub fn set_include_failed(self, include_failed: IncludeFailed) -> AllOperationsRequest {
+    pub fn set_include_failed(self, include_failed: bool) -> AllOperationsRequest {
         AllOperationsRequest {
             include_failed: Some(include_failed),
             ..self
         }
     }
-}
-
-impl Request for AllOperationsRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|f| format!("include_failed={}", f)),
-        ]
-        .iter()
-        .flatten()
-        .map(ToString::to_string)
-        .collect::<Vec<String>>()
-        .join("&")
-    }
 
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}?{}",
-            base_url,
-            super::OPERATIONS_PATH,
-            self.get_query_parameters()
-        )
+    /// Sets the related resources to embed in every operation.
+    ///
+    /// # Arguments
+    /// * `join` - The [`Join`] to apply. With [`Join::Transactions`], every operation contains the
+    ///   transaction it is part of.
+    ///
+    pub fn set_join(self, join: Join) -> AllOperationsRequest {
+        AllOperationsRequest {
+            join: Some(join),
+            ..self
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Order;
+    use crate::models::{Order, Request};
 
     #[test]
     fn test_all_operations_request() {
@@ -67,11 +60,15 @@ mod tests {
             .unwrap()
             .set_order(Order::Desc)
             .unwrap()
-            .set_include_failed(IncludeFailed::True);
+            .set_include_failed(true);
 
         assert_eq!(
             request.get_query_parameters(),
-            "cursor=1&limit=10&order=desc&include_failed=true"
+            "?include_failed=true&cursor=1&limit=10&order=desc"
+        );
+        assert_eq!(
+            AllOperationsRequest::new().build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/operations"
         );
     }
 }
@@ -38,6 +38,16 @@ pub mod operations_for_ledger_request;
 ///
 pub mod operations_for_liquidity_pool_request;
 
+/// Provides the `OperationsForClaimableBalanceRequest`.
+///
+/// # Usage
+/// This module provides the `OperationsForClaimableBalanceRequest` struct, specifically designed for
+/// constructing requests to query the operations that reference a given claimable balance from the
+/// Horizon server. It is tailored for use with the [`HorizonClient::get_operations_for_claimable_balance`](crate::horizon_client::HorizonClient::get_operations_for_claimable_balance)
+/// method.
+///
+pub mod operations_for_claimable_balance_request;
+
 /// Provides the `OperationsForTransactionRequest`.
 ///
 /// # Usage
@@ -48,6 +58,33 @@ pub mod operations_for_liquidity_pool_request;
 ///
 pub mod operations_for_transaction_request;
 
+/// Provides the `OperationTypeFilter`.
+///
+/// # Usage
+/// This module provides the `OperationTypeFilter` struct, used to select operation types when
+/// filtering operations on the client side with the
+/// [`HorizonClient::stream_operations_filtered`](crate::horizon_client::HorizonClient::stream_operations_filtered)
+/// method.
+///
+pub mod operation_type_filter;
+
+/// Provides the `OperationType`.
+///
+/// # Usage
+/// This module provides the `OperationType` enum, which maps the numeric `type_i` codes and the
+/// names of operation types to variants, so that operations can be matched exhaustively by type.
+///
+pub mod operation_type;
+
+/// Provides the `OperationDetails`.
+///
+/// # Usage
+/// This module provides the `OperationDetails` enum, with a variant per operation type, which
+/// holds the fields that Horizon only returns for operations of that type, such as the amount of
+/// a payment.
+///
+pub mod operation_details;
+
 /// Provides the responses.
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -69,6 +106,13 @@ pub mod response;
 ///
 pub mod single_operation_request;
 
+/// Provides the `OperationId`.
+///
+/// This module provides the `OperationId` struct, a validated operation ID that is shared by the
+/// operation-related requests and responses, and by the [`EffectsForOperationRequest`](crate::effects::prelude::EffectsForOperationRequest).
+///
+pub mod operation_id;
+
 /// The base path for operation-related endpoints in the Horizon API.
 ///
 /// # Usage
@@ -97,7 +141,11 @@ static OPERATIONS_PATH: &str = "operations";
 /// * From `operations_for_account_request`: All items (e.g. `OperationsForAccountRequest`).
 /// * From `operations_for_ledger_request`: All items (e.g. `OperationsForLedgerRequest`).
 /// * From `operations_for_liquidity_pool_request`: All items (e.g. `OperationsForLiquidityPoolRequest`).
+/// * From `operations_for_claimable_balance_request`: All items (e.g. `OperationsForClaimableBalanceRequest`).
 /// * From `operations_for_transaction_request`: All items (e.g. `OperationsForTransactionRequest`).
+/// * From `operation_type_filter`: All items (e.g. `OperationTypeFilter`).
+/// * From `operation_id`: All items (e.g. `OperationId`).
+/// * From `operation_details`: All items (e.g. `OperationDetails`, `PaymentDetails`, etc.).
 /// * From `response`: All items (e.g. `OperationResponse`, `Operation`, etc.).
 ///
 /// # Example
@@ -111,7 +159,12 @@ static OPERATIONS_PATH: &str = "operations";
 /// ```
 pub mod prelude {
     pub use super::all_operations_request::*;
+    pub use super::operation_details::*;
+    pub use super::operation_id::*;
+    pub use super::operation_type::*;
+    pub use super::operation_type_filter::*;
     pub use super::operations_for_account_request::*;
+    pub use super::operations_for_claimable_balance_request::*;
     pub use super::operations_for_ledger_request::*;
     pub use super::operations_for_liquidity_pool_request::*;
     pub use super::operations_for_transaction_request::*;
@@ -122,11 +175,13 @@ pub mod prelude {
 #[cfg(test)]
 pub mod tests {
     use crate::{
+        error::Error,
         horizon_client::*,
         operations::{
+            operation_details::OperationDetails,
             operations_for_account_request::OperationsForAccountRequest,
             prelude::{
-                AllOperationsRequest, OperationsForLedgerRequest,
+                AllOperationsRequest, OperationTypeFilter, OperationsForLedgerRequest,
                 OperationsForLiquidityPoolRequest, OperationsForTransactionRequest,
             },
             response::{Operation, OperationResponse},
@@ -153,7 +208,7 @@ pub mod tests {
 
         let all_operations_request = AllOperationsRequest::new().set_limit(2).unwrap();
 
-        let all_operations_response: Result<OperationResponse, String> = horizon_client
+        let all_operations_response: Result<OperationResponse, Error> = horizon_client
             .get_all_operations(&all_operations_request)
             .await;
 
@@ -173,9 +228,13 @@ pub mod tests {
         assert_eq!(all_operations_response.type_i(), &TYPE_I);
         assert_eq!(all_operations_response.created_at(), CREATED_AT);
         assert_eq!(all_operations_response.transaction_hash(), TRANSACTION_HASH);
-        assert_eq!(all_operations_response.starting_balance(), STARTING_BALANCE);
-        assert_eq!(all_operations_response.funder(), FUNDER);
-        assert_eq!(all_operations_response.account(), ACCOUNT);
+        let OperationDetails::CreateAccount(create_account) = all_operations_response.details()
+        else {
+            panic!("expected a create_account operation");
+        };
+        assert_eq!(create_account.starting_balance(), STARTING_BALANCE);
+        assert_eq!(create_account.funder(), FUNDER);
+        assert_eq!(create_account.account(), ACCOUNT);
     }
 
     #[tokio::test]
@@ -195,10 +254,9 @@ pub mod tests {
 
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
 
-        let single_operation_request =
-            SingleOperationRequest::new().set_operation_id(ID);
+        let single_operation_request = SingleOperationRequest::new().set_operation_id(ID).unwrap();
 
-        let all_operations_response: Result<Operation, String> = horizon_client
+        let all_operations_response: Result<Operation, Error> = horizon_client
             .get_single_operation(&single_operation_request)
             .await;
 
@@ -214,9 +272,12 @@ pub mod tests {
         assert_eq!(binding.type_i(), &TYPE_I);
         assert_eq!(binding.created_at(), CREATED_AT);
         assert_eq!(binding.transaction_hash(), TRANSACTION_HASH);
-        assert_eq!(binding.starting_balance(), STARTING_BALANCE);
-        assert_eq!(binding.funder(), FUNDER);
-        assert_eq!(binding.account(), ACCOUNT);
+        let OperationDetails::CreateAccount(create_account) = binding.details() else {
+            panic!("expected a create_account operation");
+        };
+        assert_eq!(create_account.starting_balance(), STARTING_BALANCE);
+        assert_eq!(create_account.funder(), FUNDER);
+        assert_eq!(create_account.account(), ACCOUNT);
     }
 
     #[tokio::test]
@@ -270,12 +331,14 @@ pub mod tests {
             operation_for_account_response.transaction_hash(),
             TRANSACTION_HASH
         );
-        assert_eq!(
-            operation_for_account_response.starting_balance(),
-            STARTING_BALANCE
-        );
-        assert_eq!(operation_for_account_response.funder(), FUNDER);
-        assert_eq!(operation_for_account_response.account(), ACCOUNT);
+        let OperationDetails::CreateAccount(create_account) =
+            operation_for_account_response.details()
+        else {
+            panic!("expected a create_account operation");
+        };
+        assert_eq!(create_account.starting_balance(), STARTING_BALANCE);
+        assert_eq!(create_account.funder(), FUNDER);
+        assert_eq!(create_account.account(), ACCOUNT);
     }
 
     #[tokio::test]
@@ -323,12 +386,14 @@ pub mod tests {
             operation_for_ledger_response.transaction_hash(),
             TRANSACTION_HASH
         );
-        assert_eq!(
-            operation_for_ledger_response.starting_balance(),
-            STARTING_BALANCE
-        );
-        assert_eq!(operation_for_ledger_response.funder(), FUNDER);
-        assert_eq!(operation_for_ledger_response.account(), ACCOUNT);
+        let OperationDetails::CreateAccount(create_account) =
+            operation_for_ledger_response.details()
+        else {
+            panic!("expected a create_account operation");
+        };
+        assert_eq!(create_account.starting_balance(), STARTING_BALANCE);
+        assert_eq!(create_account.funder(), FUNDER);
+        assert_eq!(create_account.account(), ACCOUNT);
     }
 
     #[tokio::test]
@@ -384,12 +449,14 @@ pub mod tests {
             operation_for_liquidity_pool_response.transaction_hash(),
             TRANSACTION_HASH
         );
-        assert_eq!(
-            operation_for_liquidity_pool_response.starting_balance(),
-            STARTING_BALANCE
-        );
-        assert_eq!(operation_for_liquidity_pool_response.funder(), FUNDER);
-        assert_eq!(operation_for_liquidity_pool_response.account(), ACCOUNT);
+        let OperationDetails::CreateAccount(create_account) =
+            operation_for_liquidity_pool_response.details()
+        else {
+            panic!("expected a create_account operation");
+        };
+        assert_eq!(create_account.starting_balance(), STARTING_BALANCE);
+        assert_eq!(create_account.funder(), FUNDER);
+        assert_eq!(create_account.account(), ACCOUNT);
     }
 
     #[tokio::test]
@@ -413,6 +480,7 @@ pub mod tests {
 
         let operations_for_transaction_request = OperationsForTransactionRequest::new()
             .set_transaction_hash(REQUEST_TRANSACTION_HASH)
+            .unwrap()
             .set_limit(2)
             .unwrap();
 
@@ -448,11 +516,37 @@ pub mod tests {
             operation_for_liquidity_pool_response.transaction_hash(),
             TRANSACTION_HASH
         );
-        assert_eq!(
-            operation_for_liquidity_pool_response.starting_balance(),
-            STARTING_BALANCE
-        );
-        assert_eq!(operation_for_liquidity_pool_response.funder(), FUNDER);
-        assert_eq!(operation_for_liquidity_pool_response.account(), ACCOUNT);
+        let OperationDetails::CreateAccount(create_account) =
+            operation_for_liquidity_pool_response.details()
+        else {
+            panic!("expected a create_account operation");
+        };
+        assert_eq!(create_account.starting_balance(), STARTING_BALANCE);
+        assert_eq!(create_account.funder(), FUNDER);
+        assert_eq!(create_account.account(), ACCOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_stream_operations_filtered() {
+        use futures::StreamExt;
+
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let all_operations_request = AllOperationsRequest::new().set_limit(20).unwrap();
+        let filter = OperationTypeFilter::PAYMENT | OperationTypeFilter::CHANGE_TRUST;
+
+        let operations: Vec<Result<Operation, Error>> = horizon_client
+            .stream_operations_filtered(&all_operations_request, filter)
+            .take(5)
+            .collect()
+            .await;
+
+        assert_eq!(operations.len(), 5);
+        for operation in operations {
+            let operation = operation.unwrap();
+            assert!(
+                operation.type_field() == "payment" || operation.type_field() == "change_trust"
+            );
+        }
     }
 }
@@ -0,0 +1,701 @@
+use derive_getters::Getters;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::operation_type::OperationType;
+use crate::claimable_balances::prelude::Claimant;
+use crate::liquidity_pools::prelude::Reserve;
+use crate::models::prelude::AssetType;
+use crate::offers::prelude::PriceR;
+
+/// Represents the fields of an operation that depend on its type, with a variant per operation
+/// type.
+///
+/// The variant is selected by the `type` field of the operation, such as `payment`, and its
+/// fields are those Horizon returns for operations of that type. Operations of types that this
+/// version of the SDK does not know yet deserialize into [`OperationDetails::Other`], which keeps
+/// their fields as JSON, so that a protocol upgrade does not break the deserialization of pages
+/// of operations. Operations of known types that lack a field of their type fail to deserialize.
+///
+/// # Example
+/// ```
+/// use stellar_rs::operations::prelude::*;
+///
+/// # fn print(operation: &Operation) {
+/// match operation.details() {
+///     OperationDetails::Payment(payment) => {
+///         println!("{} paid {} to {}", payment.from(), payment.amount(), payment.to());
+///     }
+///     OperationDetails::CreateAccount(create_account) => {
+///         println!("{} created {}", create_account.funder(), create_account.account());
+///     }
+///     other => println!("{} operation", other.type_name()),
+/// }
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum OperationDetails {
+    /// A `create_account` operation.
+    CreateAccount(CreateAccountDetails),
+    /// A `payment` operation.
+    Payment(PaymentDetails),
+    /// A `path_payment_strict_receive` operation.
+    PathPaymentStrictReceive(PathPaymentDetails),
+    /// A `manage_sell_offer` operation.
+    ManageSellOffer(ManageOfferDetails),
+    /// A `create_passive_sell_offer` operation.
+    CreatePassiveSellOffer(ManageOfferDetails),
+    /// A `set_options` operation.
+    SetOptions(SetOptionsDetails),
+    /// A `change_trust` operation.
+    ChangeTrust(ChangeTrustDetails),
+    /// An `allow_trust` operation.
+    AllowTrust(AllowTrustDetails),
+    /// An `account_merge` operation.
+    AccountMerge(AccountMergeDetails),
+    /// An `inflation` operation.
+    Inflation,
+    /// A `manage_data` operation.
+    ManageData(ManageDataDetails),
+    /// A `bump_sequence` operation.
+    BumpSequence(BumpSequenceDetails),
+    /// A `manage_buy_offer` operation.
+    ManageBuyOffer(ManageOfferDetails),
+    /// A `path_payment_strict_send` operation.
+    PathPaymentStrictSend(PathPaymentDetails),
+    /// A `create_claimable_balance` operation.
+    CreateClaimableBalance(CreateClaimableBalanceDetails),
+    /// A `claim_claimable_balance` operation.
+    ClaimClaimableBalance(ClaimClaimableBalanceDetails),
+    /// A `begin_sponsoring_future_reserves` operation.
+    BeginSponsoringFutureReserves(BeginSponsoringFutureReservesDetails),
+    /// An `end_sponsoring_future_reserves` operation.
+    EndSponsoringFutureReserves(EndSponsoringFutureReservesDetails),
+    /// A `revoke_sponsorship` operation.
+    RevokeSponsorship(RevokeSponsorshipDetails),
+    /// A `clawback` operation.
+    Clawback(ClawbackDetails),
+    /// A `clawback_claimable_balance` operation.
+    ClawbackClaimableBalance(ClawbackClaimableBalanceDetails),
+    /// A `set_trust_line_flags` operation.
+    SetTrustLineFlags(SetTrustLineFlagsDetails),
+    /// A `liquidity_pool_deposit` operation.
+    LiquidityPoolDeposit(LiquidityPoolDepositDetails),
+    /// A `liquidity_pool_withdraw` operation.
+    LiquidityPoolWithdraw(LiquidityPoolWithdrawDetails),
+    /// An `invoke_host_function` operation.
+    InvokeHostFunction(InvokeHostFunctionDetails),
+    /// An `extend_footprint_ttl` operation.
+    ExtendFootprintTtl(ExtendFootprintTtlDetails),
+    /// A `restore_footprint` operation.
+    RestoreFootprint,
+    /// An operation of a type that this version of the SDK does not know yet. Contains all fields
+    /// of the operation that are not common to all operations, including its `type`.
+    #[serde(untagged, skip_deserializing)]
+    Other(Map<String, Value>),
+}
+
+impl Serialize for OperationDetails {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        OperationDetails::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationDetails {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Map::deserialize(deserializer)?;
+        let is_known = fields
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|name| name.parse::<OperationType>().is_ok());
+        if !is_known {
+            return Ok(OperationDetails::Other(fields));
+        }
+
+        OperationDetails::deserialize(Value::Object(fields)).map_err(de::Error::custom)
+    }
+}
+
+impl OperationDetails {
+    /// Returns the type of the operation, as found in its `type` field, such as `payment`.
+    pub fn type_name(&self) -> &str {
+        let operation_type = match self {
+            OperationDetails::CreateAccount(_) => OperationType::CreateAccount,
+            OperationDetails::Payment(_) => OperationType::Payment,
+            OperationDetails::PathPaymentStrictReceive(_) => {
+                OperationType::PathPaymentStrictReceive
+            }
+            OperationDetails::ManageSellOffer(_) => OperationType::ManageSellOffer,
+            OperationDetails::CreatePassiveSellOffer(_) => OperationType::CreatePassiveSellOffer,
+            OperationDetails::SetOptions(_) => OperationType::SetOptions,
+            OperationDetails::ChangeTrust(_) => OperationType::ChangeTrust,
+            OperationDetails::AllowTrust(_) => OperationType::AllowTrust,
+            OperationDetails::AccountMerge(_) => OperationType::AccountMerge,
+            OperationDetails::Inflation => OperationType::Inflation,
+            OperationDetails::ManageData(_) => OperationType::ManageData,
+            OperationDetails::BumpSequence(_) => OperationType::BumpSequence,
+            OperationDetails::ManageBuyOffer(_) => OperationType::ManageBuyOffer,
+            OperationDetails::PathPaymentStrictSend(_) => OperationType::PathPaymentStrictSend,
+            OperationDetails::CreateClaimableBalance(_) => OperationType::CreateClaimableBalance,
+            OperationDetails::ClaimClaimableBalance(_) => OperationType::ClaimClaimableBalance,
+            OperationDetails::BeginSponsoringFutureReserves(_) => {
+                OperationType::BeginSponsoringFutureReserves
+            }
+            OperationDetails::EndSponsoringFutureReserves(_) => {
+                OperationType::EndSponsoringFutureReserves
+            }
+            OperationDetails::RevokeSponsorship(_) => OperationType::RevokeSponsorship,
+            OperationDetails::Clawback(_) => OperationType::Clawback,
+            OperationDetails::ClawbackClaimableBalance(_) => {
+                OperationType::ClawbackClaimableBalance
+            }
+            OperationDetails::SetTrustLineFlags(_) => OperationType::SetTrustLineFlags,
+            OperationDetails::LiquidityPoolDeposit(_) => OperationType::LiquidityPoolDeposit,
+            OperationDetails::LiquidityPoolWithdraw(_) => OperationType::LiquidityPoolWithdraw,
+            OperationDetails::InvokeHostFunction(_) => OperationType::InvokeHostFunction,
+            OperationDetails::ExtendFootprintTtl(_) => OperationType::ExtendFootprintTtl,
+            OperationDetails::RestoreFootprint => OperationType::RestoreFootprint,
+            OperationDetails::Other(fields) => {
+                return fields
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+            }
+        };
+        operation_type.as_str()
+    }
+}
+
+/// The fields of a `create_account` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct CreateAccountDetails {
+    /// The amount of lumens the new account is funded with.
+    pub starting_balance: String,
+    /// The account that funded the new account.
+    pub funder: String,
+    /// The muxed account of the funder, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funder_muxed: Option<String>,
+    /// The ID of the muxed account of the funder, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funder_muxed_id: Option<String>,
+    /// The new account.
+    pub account: String,
+}
+
+/// The fields of a `payment` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct PaymentDetails {
+    /// The type of the asset that was sent, such as `native` or `credit_alphanum4`.
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The account that sent the payment.
+    pub from: String,
+    /// The muxed account of the sender, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_muxed: Option<String>,
+    /// The ID of the muxed account of the sender, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_muxed_id: Option<String>,
+    /// The account that received the payment.
+    pub to: String,
+    /// The muxed account of the receiver, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_muxed: Option<String>,
+    /// The ID of the muxed account of the receiver, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_muxed_id: Option<String>,
+    /// The amount that was sent.
+    pub amount: String,
+}
+
+impl PaymentDetails {
+    /// Returns the asset that was sent.
+    pub fn asset(&self) -> Result<AssetType, String> {
+        AssetType::from_horizon(
+            &self.asset_type,
+            self.asset_code.as_deref(),
+            self.asset_issuer.as_deref(),
+        )
+    }
+}
+
+/// The fields of a `path_payment_strict_receive` or a `path_payment_strict_send` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct PathPaymentDetails {
+    /// The type of the asset that was received, such as `native` or `credit_alphanum4`.
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The account that sent the payment.
+    pub from: String,
+    /// The muxed account of the sender, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_muxed: Option<String>,
+    /// The ID of the muxed account of the sender, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_muxed_id: Option<String>,
+    /// The account that received the payment.
+    pub to: String,
+    /// The muxed account of the receiver, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_muxed: Option<String>,
+    /// The ID of the muxed account of the receiver, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_muxed_id: Option<String>,
+    /// The amount that was received.
+    pub amount: String,
+    /// The assets the payment was converted through, excluding the source and destination asset.
+    pub path: Vec<AssetType>,
+    /// The amount that was sent.
+    pub source_amount: String,
+    /// The largest amount the sender was willing to send. Only present on
+    /// `path_payment_strict_receive` operations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_max: Option<String>,
+    /// The smallest amount the receiver was willing to receive. Only present on
+    /// `path_payment_strict_send` operations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_min: Option<String>,
+    /// The type of the asset that was sent.
+    pub source_asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_asset_issuer: Option<String>,
+}
+
+impl PathPaymentDetails {
+    /// Returns the asset that was sent.
+    pub fn source_asset(&self) -> Result<AssetType, String> {
+        AssetType::from_horizon(
+            &self.source_asset_type,
+            self.source_asset_code.as_deref(),
+            self.source_asset_issuer.as_deref(),
+        )
+    }
+
+    /// Returns the asset that was received.
+    pub fn destination_asset(&self) -> Result<AssetType, String> {
+        AssetType::from_horizon(
+            &self.asset_type,
+            self.asset_code.as_deref(),
+            self.asset_issuer.as_deref(),
+        )
+    }
+}
+
+/// The fields of a `manage_sell_offer`, `manage_buy_offer` or `create_passive_sell_offer`
+/// operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ManageOfferDetails {
+    /// The ID of the offer, which is `0` if the operation created a new offer. Not present on
+    /// `create_passive_sell_offer` operations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offer_id: Option<String>,
+    /// The amount of the offer, which is `0` if the operation deleted the offer.
+    pub amount: String,
+    /// The price of the offer, as a decimal number.
+    pub price: String,
+    /// The price of the offer, as a fraction.
+    pub price_r: PriceR,
+    pub buying_asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buying_asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buying_asset_issuer: Option<String>,
+    pub selling_asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selling_asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selling_asset_issuer: Option<String>,
+}
+
+/// The fields of a `set_options` operation. Only the options that the operation changed are
+/// present.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct SetOptionsDetails {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inflation_dest: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master_key_weight: Option<u32>,
+    /// The signer that was added, updated or removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_key: Option<String>,
+    /// The weight of the signer, which is `0` if the signer was removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_weight: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_flags: Option<Vec<u32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_flags_s: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_flags: Option<Vec<u32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_flags_s: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub low_threshold: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub med_threshold: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub high_threshold: Option<u32>,
+}
+
+/// The fields of a `change_trust` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ChangeTrustDetails {
+    /// The type of the asset of the trustline, which is `liquidity_pool_shares` for a trustline
+    /// to the shares of a liquidity pool.
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The liquidity pool of a trustline to the shares of a liquidity pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity_pool_id: Option<String>,
+    /// The limit of the trustline, which is `0` if the trustline was removed.
+    pub limit: String,
+    /// The issuer of the asset, if the asset is not a liquidity pool share.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trustee: Option<String>,
+    /// The account that holds the trustline.
+    pub trustor: String,
+}
+
+/// The fields of an `allow_trust` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct AllowTrustDetails {
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The issuer of the asset, which authorized the trustline.
+    pub trustee: String,
+    /// The account that holds the trustline.
+    pub trustor: String,
+    /// Whether the trustline is fully authorized.
+    pub authorize: bool,
+    /// Whether the trustline is authorized to maintain its liabilities only.
+    #[serde(default)]
+    pub authorize_to_maintain_liabilities: bool,
+}
+
+/// The fields of an `account_merge` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct AccountMergeDetails {
+    /// The account that was merged and removed.
+    pub account: String,
+    /// The muxed account of the merged account, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_muxed: Option<String>,
+    /// The ID of the muxed account of the merged account, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_muxed_id: Option<String>,
+    /// The account that received the lumens of the merged account.
+    pub into: String,
+    /// The muxed account of the receiving account, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub into_muxed: Option<String>,
+    /// The ID of the muxed account of the receiving account, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub into_muxed_id: Option<String>,
+}
+
+/// The fields of a `manage_data` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ManageDataDetails {
+    /// The name of the data entry.
+    pub name: String,
+    /// The base64-encoded value of the data entry, or `None` if the entry was removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// The fields of a `bump_sequence` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct BumpSequenceDetails {
+    /// The sequence number the account was bumped to.
+    pub bump_to: String,
+}
+
+/// The fields of a `create_claimable_balance` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct CreateClaimableBalanceDetails {
+    /// The asset of the claimable balance, such as `native` or `USDC:G...`.
+    pub asset: String,
+    /// The amount of the claimable balance.
+    pub amount: String,
+    /// The accounts that can claim the balance, and the conditions under which they can.
+    pub claimants: Vec<Claimant>,
+}
+
+/// The fields of a `claim_claimable_balance` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ClaimClaimableBalanceDetails {
+    /// The ID of the claimable balance that was claimed.
+    pub balance_id: String,
+    /// The account that claimed the balance.
+    pub claimant: String,
+}
+
+/// The fields of a `begin_sponsoring_future_reserves` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct BeginSponsoringFutureReservesDetails {
+    /// The account whose reserves are sponsored.
+    pub sponsored_id: String,
+}
+
+/// The fields of an `end_sponsoring_future_reserves` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct EndSponsoringFutureReservesDetails {
+    /// The account that started sponsoring the reserves.
+    pub begin_sponsor: String,
+}
+
+/// The fields of a `revoke_sponsorship` operation. Only the fields that identify the entry whose
+/// sponsorship was revoked are present.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct RevokeSponsorshipDetails {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimable_balance_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_account_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offer_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trustline_account_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trustline_asset: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trustline_liquidity_pool_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_account_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_key: Option<String>,
+}
+
+/// The fields of a `clawback` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ClawbackDetails {
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The account the asset was clawed back from.
+    pub from: String,
+    /// The muxed account of the account the asset was clawed back from, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_muxed: Option<String>,
+    /// The ID of the muxed account of the account the asset was clawed back from, if it is a muxed account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_muxed_id: Option<String>,
+    /// The amount that was clawed back.
+    pub amount: String,
+}
+
+/// The fields of a `clawback_claimable_balance` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ClawbackClaimableBalanceDetails {
+    /// The ID of the claimable balance that was clawed back.
+    pub balance_id: String,
+}
+
+/// The fields of a `set_trust_line_flags` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct SetTrustLineFlagsDetails {
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The account that holds the trustline.
+    pub trustor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_flags: Option<Vec<u32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_flags_s: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_flags: Option<Vec<u32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clear_flags_s: Option<Vec<String>>,
+}
+
+/// The fields of a `liquidity_pool_deposit` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolDepositDetails {
+    pub liquidity_pool_id: String,
+    /// The largest amounts of the reserves the depositor was willing to deposit.
+    pub reserves_max: Vec<Reserve>,
+    pub min_price: String,
+    pub min_price_r: PriceR,
+    pub max_price: String,
+    pub max_price_r: PriceR,
+    /// The amounts of the reserves that were deposited.
+    pub reserves_deposited: Vec<Reserve>,
+    /// The amount of pool shares the depositor received.
+    pub shares_received: String,
+}
+
+/// The fields of a `liquidity_pool_withdraw` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct LiquidityPoolWithdrawDetails {
+    pub liquidity_pool_id: String,
+    /// The smallest amounts of the reserves the withdrawer was willing to receive.
+    pub reserves_min: Vec<Reserve>,
+    /// The amount of pool shares that were withdrawn.
+    pub shares: String,
+    /// The amounts of the reserves that were received.
+    pub reserves_received: Vec<Reserve>,
+}
+
+/// The fields of an `invoke_host_function` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct InvokeHostFunctionDetails {
+    /// The type of host function, such as `HostFunctionTypeHostFunctionTypeInvokeContract`.
+    pub function: String,
+    /// The parameters of the function, as base64-encoded XDR values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<HostFunctionParameter>>,
+    /// The address of the contract that was created, if any.
+    #[serde(default)]
+    pub address: String,
+    /// The salt of the contract that was created, if any.
+    #[serde(default)]
+    pub salt: String,
+    /// The transfers of Stellar assets that the function made.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_balance_changes: Option<Vec<AssetBalanceChange>>,
+}
+
+/// A parameter of a host function.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct HostFunctionParameter {
+    /// The base64-encoded XDR value of the parameter.
+    pub value: String,
+    /// The type of the parameter, such as `Address` or `Sym`.
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+/// A transfer of a Stellar asset made by a host function.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct AssetBalanceChange {
+    pub asset_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_issuer: Option<String>,
+    /// The type of change, such as `transfer`, `mint`, `clawback` or `burn`.
+    #[serde(rename = "type")]
+    pub type_field: String,
+    #[serde(default)]
+    pub from: String,
+    #[serde(default)]
+    pub to: String,
+    pub amount: String,
+}
+
+/// The fields of an `extend_footprint_ttl` operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct ExtendFootprintTtlDetails {
+    /// The ledger until which the entries of the footprint live.
+    pub extend_to: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::prelude::Operation;
+    use serde_json::json;
+
+    /// Returns the operation of the single operation fixture, with its type-specific fields
+    /// replaced by the given fields.
+    fn operation(fields: Value) -> Operation {
+        let json = include_str!("../../tests/fixtures/operations/single_operation.json");
+        let mut value: Value = serde_json::from_str(json).unwrap();
+        let object = value.as_object_mut().unwrap();
+        for field in ["type", "starting_balance", "funder", "account"] {
+            object.remove(field);
+        }
+        object.extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_operation_details() {
+        let manage_sell_offer = operation(json!({
+            "type": "manage_sell_offer",
+            "offer_id": "0",
+            "amount": "100.0000000",
+            "price": "0.2500000",
+            "price_r": {"n": 1, "d": 4},
+            "buying_asset_type": "native",
+            "selling_asset_type": "credit_alphanum4",
+            "selling_asset_code": "USDC",
+            "selling_asset_issuer": "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+        }));
+        let OperationDetails::ManageSellOffer(offer) = manage_sell_offer.details() else {
+            panic!("expected a manage_sell_offer operation");
+        };
+        assert_eq!(offer.offer_id().as_deref(), Some("0"));
+        assert_eq!(offer.selling_asset_code().as_deref(), Some("USDC"));
+        assert_eq!(manage_sell_offer.type_field(), "manage_sell_offer");
+
+        let inflation = operation(json!({"type": "inflation"}));
+        assert!(matches!(inflation.details(), OperationDetails::Inflation));
+        assert_eq!(inflation.type_field(), "inflation");
+
+        // Operations of known types must have the fields of their type.
+        let json = include_str!("../../tests/fixtures/operations/single_operation.json");
+        let json = json.replace("\"starting_balance\"", "\"balance\"");
+        assert!(serde_json::from_str::<Operation>(&json).is_err());
+
+        // Operations of unknown types keep their fields.
+        let unknown = operation(json!({"type": "teleport", "destination": "moon"}));
+        assert_eq!(unknown.type_field(), "teleport");
+        let value = serde_json::to_value(&unknown).unwrap();
+        assert_eq!(value["type"], "teleport");
+        assert_eq!(value["destination"], "moon");
+    }
+}
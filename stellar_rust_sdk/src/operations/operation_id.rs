@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the ID of an operation.
+///
+/// Operation IDs are positive 64-bit integers, derived from the ledger, transaction and operation
+/// index. The Horizon API represents them as strings; an `OperationId` validates such a string
+/// once, so that a transaction hash or any other string can not be passed where an operation ID
+/// is expected.
+///
+/// An `OperationId` is (de)serialized as a string, matching the representation used by Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::operations::prelude::OperationId;
+///
+/// let operation_id: OperationId = "459561504769".parse().unwrap();
+/// assert_eq!(operation_id.value(), 459561504769);
+///
+/// assert!("0".parse::<OperationId>().is_err());
+/// assert!("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+///     .parse::<OperationId>()
+///     .is_err());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct OperationId(u64);
+
+impl OperationId {
+    /// Creates a new `OperationId`.
+    ///
+    /// # Arguments
+    /// * `operation_id` - The numeric operation ID.
+    ///
+    /// # Returns
+    /// The `OperationId`, or an error if the ID is `0` or exceeds the largest ID Horizon can
+    /// represent (`i64::MAX`).
+    ///
+    pub fn new(operation_id: u64) -> Result<Self, String> {
+        if operation_id < 1 {
+            return Err("operation ID must be greater than or equal to 1".to_string());
+        }
+        if operation_id > i64::MAX as u64 {
+            return Err(format!(
+                "operation ID must be less than or equal to {}",
+                i64::MAX
+            ));
+        }
+
+        Ok(OperationId(operation_id))
+    }
+
+    /// Returns the numeric value of the operation ID.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for OperationId {
+    type Err = String;
+
+    fn from_str(operation_id: &str) -> Result<Self, Self::Err> {
+        let operation_id = operation_id
+            .parse::<u64>()
+            .map_err(|_| format!("invalid operation ID: {}", operation_id))?;
+
+        OperationId::new(operation_id)
+    }
+}
+
+impl TryFrom<String> for OperationId {
+    type Error = String;
+
+    fn try_from(operation_id: String) -> Result<Self, Self::Error> {
+        operation_id.parse()
+    }
+}
+
+impl From<OperationId> for String {
+    fn from(operation_id: OperationId) -> Self {
+        operation_id.to_string()
+    }
+}
+
+impl fmt::Display for OperationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<str> for OperationId {
+    fn eq(&self, other: &str) -> bool {
+        other.parse::<u64>().is_ok_and(|other| other == self.0)
+    }
+}
+
+impl PartialEq<&str> for OperationId {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_id() {
+        assert_eq!(
+            "459561504769".parse::<OperationId>().unwrap().value(),
+            459561504769
+        );
+        assert_eq!(
+            "0".parse::<OperationId>().unwrap_err(),
+            "operation ID must be greater than or equal to 1"
+        );
+        assert_eq!(
+            "-1".parse::<OperationId>().unwrap_err(),
+            "invalid operation ID: -1"
+        );
+        assert!(OperationId::new(i64::MAX as u64 + 1).is_err());
+        assert_eq!(
+            "459561504769".parse::<OperationId>().unwrap(),
+            "459561504769"
+        );
+    }
+
+    #[test]
+    fn test_operation_id_serde() {
+        let operation_id: OperationId = serde_json::from_str("\"2314987376641\"").unwrap();
+        assert_eq!(operation_id.value(), 2314987376641);
+        assert_eq!(
+            serde_json::to_string(&operation_id).unwrap(),
+            "\"2314987376641\""
+        );
+        assert!(serde_json::from_str::<OperationId>("\"0\"").is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use super::operation_type_filter::OperationTypeFilter;
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines the `OperationType` enum, along with the conversions between its variants, their
+/// numeric codes and their names, from a single list.
+macro_rules! operation_types {
+    ($($(#[$doc:meta])* $variant:ident = $type_i:literal, $name:literal;)+) => {
+        /// Represents the type of an operation, as found in the `type_i` and `type` fields of an
+        /// [`Operation`](crate::operations::prelude::Operation).
+        ///
+        /// The enum is exhaustive on purpose: when a protocol upgrade adds an operation type, a
+        /// new variant is added, and every `match` on an `OperationType` that does not handle it
+        /// fails to compile. Operations of types that this version of the SDK does not know yet
+        /// have no `OperationType`, so [`OperationType::from_i`] returns `None` for them.
+        ///
+        /// # Example
+        /// ```
+        /// use stellar_rs::operations::prelude::OperationType;
+        ///
+        /// let operation_type = OperationType::from_i(1).unwrap();
+        /// assert_eq!(operation_type, OperationType::Payment);
+        /// assert_eq!(operation_type.as_str(), "payment");
+        /// assert_eq!("payment".parse(), Ok(OperationType::Payment));
+        /// ```
+        ///
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum OperationType {
+            $($(#[$doc])* $variant,)+
+        }
+
+        impl OperationType {
+            /// All operation types, in the order of their numeric codes.
+            pub const ALL: &'static [OperationType] = &[$(OperationType::$variant,)+];
+
+            /// Returns the operation type with the given numeric code, as found in the `type_i`
+            /// field of an operation, or `None` if the code is unknown.
+            pub fn from_i(type_i: u32) -> Option<Self> {
+                match type_i {
+                    $($type_i => Some(OperationType::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// Returns the numeric code of the operation type.
+            pub fn as_i(&self) -> u32 {
+                match self {
+                    $(OperationType::$variant => $type_i,)+
+                }
+            }
+
+            /// Returns the name of the operation type, as found in the `type` field of an
+            /// operation, such as `payment`.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(OperationType::$variant => $name,)+
+                }
+            }
+        }
+
+        impl FromStr for OperationType {
+            type Err = String;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                match name {
+                    $($name => Ok(OperationType::$variant),)+
+                    _ => Err(format!("Unknown operation type: {}", name)),
+                }
+            }
+        }
+    };
+}
+
+operation_types! {
+    /// Creates and funds a new account.
+    CreateAccount = 0, "create_account";
+    /// Sends an amount of an asset to an account.
+    Payment = 1, "payment";
+    /// Sends an asset through a path of offers, receiving an exact amount.
+    PathPaymentStrictReceive = 2, "path_payment_strict_receive";
+    /// Creates, updates or deletes an offer to sell an asset.
+    ManageSellOffer = 3, "manage_sell_offer";
+    /// Creates an offer that does not take offers at the same price.
+    CreatePassiveSellOffer = 4, "create_passive_sell_offer";
+    /// Changes the settings of an account, such as its signers and thresholds.
+    SetOptions = 5, "set_options";
+    /// Creates, updates or removes a trustline.
+    ChangeTrust = 6, "change_trust";
+    /// Changes the authorization of a trustline. Deprecated in favor of `SetTrustLineFlags`.
+    AllowTrust = 7, "allow_trust";
+    /// Transfers the lumens of an account to another account, and removes it.
+    AccountMerge = 8, "account_merge";
+    /// Runs the inflation process, which has been disabled since protocol 12.
+    Inflation = 9, "inflation";
+    /// Sets, updates or removes a data entry of an account.
+    ManageData = 10, "manage_data";
+    /// Bumps the sequence number of an account.
+    BumpSequence = 11, "bump_sequence";
+    /// Creates, updates or deletes an offer to buy an asset.
+    ManageBuyOffer = 12, "manage_buy_offer";
+    /// Sends an asset through a path of offers, sending an exact amount.
+    PathPaymentStrictSend = 13, "path_payment_strict_send";
+    /// Creates a claimable balance.
+    CreateClaimableBalance = 14, "create_claimable_balance";
+    /// Claims a claimable balance.
+    ClaimClaimableBalance = 15, "claim_claimable_balance";
+    /// Starts sponsoring the reserves of the entries created by another account.
+    BeginSponsoringFutureReserves = 16, "begin_sponsoring_future_reserves";
+    /// Ends the sponsorship started by `BeginSponsoringFutureReserves`.
+    EndSponsoringFutureReserves = 17, "end_sponsoring_future_reserves";
+    /// Revokes or transfers the sponsorship of an entry.
+    RevokeSponsorship = 18, "revoke_sponsorship";
+    /// Burns an amount of an asset held by an account.
+    Clawback = 19, "clawback";
+    /// Burns a claimable balance.
+    ClawbackClaimableBalance = 20, "clawback_claimable_balance";
+    /// Changes the flags of a trustline, such as its authorization.
+    SetTrustLineFlags = 21, "set_trust_line_flags";
+    /// Deposits assets into a liquidity pool.
+    LiquidityPoolDeposit = 22, "liquidity_pool_deposit";
+    /// Withdraws assets from a liquidity pool.
+    LiquidityPoolWithdraw = 23, "liquidity_pool_withdraw";
+    /// Invokes a smart contract function, or uploads or creates a contract.
+    InvokeHostFunction = 24, "invoke_host_function";
+    /// Extends the time to live of contract data entries.
+    ExtendFootprintTtl = 25, "extend_footprint_ttl";
+    /// Restores archived contract data entries.
+    RestoreFootprint = 26, "restore_footprint";
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<OperationType> for OperationTypeFilter {
+    fn from(operation_type: OperationType) -> Self {
+        // The codes of all operation types fit in a `u8`.
+        OperationTypeFilter::from_type_i(operation_type.as_i() as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_type() {
+        for (index, operation_type) in OperationType::ALL.iter().enumerate() {
+            assert_eq!(operation_type.as_i() as usize, index);
+            assert_eq!(
+                OperationType::from_i(operation_type.as_i()),
+                Some(*operation_type)
+            );
+            assert_eq!(operation_type.as_str().parse(), Ok(*operation_type));
+        }
+        assert_eq!(OperationType::from_i(27), None);
+        assert_eq!(
+            "transfer".parse::<OperationType>(),
+            Err("Unknown operation type: transfer".to_string())
+        );
+        assert_eq!(
+            OperationTypeFilter::from(OperationType::ChangeTrust),
+            OperationTypeFilter::CHANGE_TRUST
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use super::response::Operation;
+use std::ops::{BitOr, BitOrAssign};
+
+/// Represents a set of operation types, used to filter operations on the client side.
+///
+/// Horizon does not support filtering operations by type. An `OperationTypeFilter` can be passed to
+/// [`HorizonClient::stream_operations_filtered`](crate::horizon_client::HorizonClient::stream_operations_filtered)
+/// to only yield operations of the selected types. The filter is applied *after* the operations
+/// have been retrieved, so every operation is still transferred from the Horizon server.
+///
+/// Filters are combined with the `|` operator. Besides a constant for each individual operation
+/// type, there are constants for common groups of operation types, such as
+/// [`PAYMENTS`](OperationTypeFilter::PAYMENTS) and [`OFFERS`](OperationTypeFilter::OFFERS).
+///
+/// # Example
+/// ```
+/// use stellar_rs::operations::prelude::OperationTypeFilter;
+///
+/// let filter = OperationTypeFilter::PAYMENTS | OperationTypeFilter::CHANGE_TRUST;
+/// assert!(filter.contains(OperationTypeFilter::PAYMENT));
+/// assert!(!filter.contains(OperationTypeFilter::MANAGE_DATA));
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OperationTypeFilter(u64);
+
+impl OperationTypeFilter {
+    pub const CREATE_ACCOUNT: OperationTypeFilter = OperationTypeFilter::from_type_i(0);
+    pub const PAYMENT: OperationTypeFilter = OperationTypeFilter::from_type_i(1);
+    pub const PATH_PAYMENT_STRICT_RECEIVE: OperationTypeFilter =
+        OperationTypeFilter::from_type_i(2);
+    pub const MANAGE_SELL_OFFER: OperationTypeFilter = OperationTypeFilter::from_type_i(3);
+    pub const CREATE_PASSIVE_SELL_OFFER: OperationTypeFilter = OperationTypeFilter::from_type_i(4);
+    pub const SET_OPTIONS: OperationTypeFilter = OperationTypeFilter::from_type_i(5);
+    pub const CHANGE_TRUST: OperationTypeFilter = OperationTypeFilter::from_type_i(6);
+    pub const ALLOW_TRUST: OperationTypeFilter = OperationTypeFilter::from_type_i(7);
+    pub const ACCOUNT_MERGE: OperationTypeFilter = OperationTypeFilter::from_type_i(8);
+    pub const INFLATION: OperationTypeFilter = OperationTypeFilter::from_type_i(9);
+    pub const MANAGE_DATA: OperationTypeFilter = OperationTypeFilter::from_type_i(10);
+    pub const BUMP_SEQUENCE: OperationTypeFilter = OperationTypeFilter::from_type_i(11);
+    pub const MANAGE_BUY_OFFER: OperationTypeFilter = OperationTypeFilter::from_type_i(12);
+    pub const PATH_PAYMENT_STRICT_SEND: OperationTypeFilter = OperationTypeFilter::from_type_i(13);
+    pub const CREATE_CLAIMABLE_BALANCE: OperationTypeFilter = OperationTypeFilter::from_type_i(14);
+    pub const CLAIM_CLAIMABLE_BALANCE: OperationTypeFilter = OperationTypeFilter::from_type_i(15);
+    pub const BEGIN_SPONSORING_FUTURE_RESERVES: OperationTypeFilter =
+        OperationTypeFilter::from_type_i(16);
+    pub const END_SPONSORING_FUTURE_RESERVES: OperationTypeFilter =
+        OperationTypeFilter::from_type_i(17);
+    pub const REVOKE_SPONSORSHIP: OperationTypeFilter = OperationTypeFilter::from_type_i(18);
+    pub const CLAWBACK: OperationTypeFilter = OperationTypeFilter::from_type_i(19);
+    pub const CLAWBACK_CLAIMABLE_BALANCE: OperationTypeFilter =
+        OperationTypeFilter::from_type_i(20);
+    pub const SET_TRUST_LINE_FLAGS: OperationTypeFilter = OperationTypeFilter::from_type_i(21);
+    pub const LIQUIDITY_POOL_DEPOSIT: OperationTypeFilter = OperationTypeFilter::from_type_i(22);
+    pub const LIQUIDITY_POOL_WITHDRAW: OperationTypeFilter = OperationTypeFilter::from_type_i(23);
+    pub const INVOKE_HOST_FUNCTION: OperationTypeFilter = OperationTypeFilter::from_type_i(24);
+    pub const EXTEND_FOOTPRINT_TTL: OperationTypeFilter = OperationTypeFilter::from_type_i(25);
+    pub const RESTORE_FOOTPRINT: OperationTypeFilter = OperationTypeFilter::from_type_i(26);
+
+    /// The operation types that Horizon considers payments: account creations, payments,
+    /// path payments and account merges.
+    pub const PAYMENTS: OperationTypeFilter = OperationTypeFilter(
+        Self::CREATE_ACCOUNT.0
+            | Self::PAYMENT.0
+            | Self::PATH_PAYMENT_STRICT_RECEIVE.0
+            | Self::PATH_PAYMENT_STRICT_SEND.0
+            | Self::ACCOUNT_MERGE.0,
+    );
+    /// The operation types that create, update or delete offers.
+    pub const OFFERS: OperationTypeFilter = OperationTypeFilter(
+        Self::MANAGE_SELL_OFFER.0 | Self::CREATE_PASSIVE_SELL_OFFER.0 | Self::MANAGE_BUY_OFFER.0,
+    );
+    /// The operation types that create trustlines or change their authorization.
+    pub const TRUSTLINES: OperationTypeFilter = OperationTypeFilter(
+        Self::CHANGE_TRUST.0 | Self::ALLOW_TRUST.0 | Self::SET_TRUST_LINE_FLAGS.0,
+    );
+    /// The operation types that create, claim or claw back claimable balances.
+    pub const CLAIMABLE_BALANCES: OperationTypeFilter = OperationTypeFilter(
+        Self::CREATE_CLAIMABLE_BALANCE.0
+            | Self::CLAIM_CLAIMABLE_BALANCE.0
+            | Self::CLAWBACK_CLAIMABLE_BALANCE.0,
+    );
+    /// The operation types related to sponsored reserves.
+    pub const SPONSORSHIP: OperationTypeFilter = OperationTypeFilter(
+        Self::BEGIN_SPONSORING_FUTURE_RESERVES.0
+            | Self::END_SPONSORING_FUTURE_RESERVES.0
+            | Self::REVOKE_SPONSORSHIP.0,
+    );
+    /// The operation types that deposit into or withdraw from liquidity pools.
+    pub const LIQUIDITY_POOLS: OperationTypeFilter =
+        OperationTypeFilter(Self::LIQUIDITY_POOL_DEPOSIT.0 | Self::LIQUIDITY_POOL_WITHDRAW.0);
+    /// The operation types related to smart contracts.
+    pub const SMART_CONTRACTS: OperationTypeFilter = OperationTypeFilter(
+        Self::INVOKE_HOST_FUNCTION.0 | Self::EXTEND_FOOTPRINT_TTL.0 | Self::RESTORE_FOOTPRINT.0,
+    );
+    /// All operation types.
+    pub const ALL: OperationTypeFilter = OperationTypeFilter(u64::MAX);
+
+    /// Creates a filter matching the operation type with the given numeric identifier, as found in
+    /// the `type_i` field of an [`Operation`].
+    pub const fn from_type_i(type_i: u8) -> OperationTypeFilter {
+        OperationTypeFilter(1 << type_i)
+    }
+
+    /// Returns whether all operation types of `other` are included in this filter.
+    pub fn contains(&self, other: OperationTypeFilter) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns whether the given operation is of one of the types included in this filter.
+    pub fn matches(&self, operation: &Operation) -> bool {
+        match u8::try_from(operation.type_i) {
+            Ok(type_i) if type_i < 64 => self.contains(OperationTypeFilter::from_type_i(type_i)),
+            _ => false,
+        }
+    }
+}
+
+impl BitOr for OperationTypeFilter {
+    type Output = OperationTypeFilter;
+
+    fn bitor(self, rhs: OperationTypeFilter) -> OperationTypeFilter {
+        OperationTypeFilter(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for OperationTypeFilter {
+    fn bitor_assign(&mut self, rhs: OperationTypeFilter) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::operation_details::OperationDetails;
+
+    fn operation(type_i: i64) -> Operation {
+        Operation {
+            links: Default::default(),
+            id: "2314987376641".parse().unwrap(),
+            paging_token: "2314987376641".to_string(),
+            transaction_successful: true,
+            source_account: String::new(),
+            source_account_muxed: None,
+            source_account_muxed_id: None,
+            type_i,
+            created_at: String::new(),
+            transaction_hash: "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+                .parse()
+                .unwrap(),
+            sponsor: None,
+            details: OperationDetails::Other(Default::default()),
+            transaction: None,
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let filter = OperationTypeFilter::PAYMENTS | OperationTypeFilter::CHANGE_TRUST;
+
+        assert!(filter.matches(&operation(0)));
+        assert!(filter.matches(&operation(1)));
+        assert!(filter.matches(&operation(6)));
+        assert!(!filter.matches(&operation(3)));
+        assert!(!filter.matches(&operation(-1)));
+    }
+
+    #[test]
+    fn test_empty_filter_matches_nothing() {
+        let filter = OperationTypeFilter::default();
+        assert!(!filter.matches(&operation(1)));
+        assert!(OperationTypeFilter::ALL.matches(&operation(26)));
+    }
+
+    #[test]
+    fn test_operation_type() {
+        use crate::operations::operation_type::OperationType;
+
+        assert_eq!(
+            operation(6).operation_type(),
+            Some(OperationType::ChangeTrust)
+        );
+        assert_eq!(operation(-1).operation_type(), None);
+        assert!(OperationTypeFilter::PAYMENTS.contains(OperationType::Payment.into()));
+    }
+}
@@ -1,16 +1,21 @@
-use crate::{
-    models::{IncludeFailed, Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::resolve_account_id;
+use crate::models::Join;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OPERATIONS_PATH, parent = account_id)]
 pub struct OperationsForAccountRequest {
     /// The account ID for which to retrieve operations.
     account_id: Option<String>,
     /// A boolean value that determines whether to include failed operations in the response.
-    include_failed: Option<IncludeFailed>,
+    include_failed: Option<bool>,
+    /// The related resources to embed in every operation.
+    join: Option<Join>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl OperationsForAccountRequest {
@@ -23,7 +28,7 @@ impl OperationsForAccountRequest {
     /// # Arguments
     /// * `include_failed` - A boolean value that determines whether to include failed operations in the response.
     ///
-    pub fn set_include_failed(self, include_failed: IncludeFailed) -> OperationsForAccountRequest {
+    pub fn set_include_failed(self, include_failed: bool) -> OperationsForAccountRequest {
         OperationsForAccountRequest {
             include_failed: Some(include_failed),
             ..self
@@ -33,45 +38,34 @@ impl OperationsForAccountRequest {
     /// Sets the account ID for which to retrieve operations.
     ///
     /// # Arguments
-    /// * `account_id` - A `String` representing the account ID.
+    /// * `account_id` - A `String` representing the account ID. A muxed account (`M...`) is
+    ///   resolved to its underlying account.
     ///
     pub fn set_account_id(self, account_id: impl Into<String>) -> OperationsForAccountRequest {
         OperationsForAccountRequest {
-            account_id: Some(account_id.into()),
+            account_id: Some(resolve_account_id(account_id.into())),
             ..self
         }
     }
-}
 
-impl Request for OperationsForAccountRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-        ]
-        .build_query_parameters()
-    }
-    fn build_url(&self, base_url: &str) -> String {
-        let binding = "".to_string();
-        let account_id = self.account_id.as_ref().unwrap_or(&binding);
-        format!(
-            "{}/accounts/{}/{}?{}",
-            base_url,
-            account_id,
-            super::OPERATIONS_PATH,
-            self.get_query_parameters(),
-        )
+    /// Sets the related resources to embed in every operation.
+    ///
+    /// # Arguments
+    /// * `join` - The [`Join`] to apply. With [`Join::Transactions`], every operation contains the
+    ///   transaction it is part of.
+    ///
+    pub fn set_join(self, join: Join) -> OperationsForAccountRequest {
+        OperationsForAccountRequest {
+            join: Some(join),
+            ..self
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Order;
+    use crate::models::{Order, Request};
 
     #[test]
     fn test_all_operations_request() {
@@ -82,11 +76,12 @@ mod tests {
             .unwrap()
             .set_order(Order::Desc)
             .unwrap()
-            .set_include_failed(IncludeFailed::True);
+            .set_include_failed(true)
+            .set_join(Join::Transactions);
 
         assert_eq!(
             request.get_query_parameters(),
-            "?cursor=1&limit=10&order=desc&include_failed=true"
+            "?include_failed=true&join=transactions&cursor=1&limit=10&order=desc"
         );
     }
 }
@@ -0,0 +1,117 @@
+use crate::models::pagination::PageParams;
+use crate::models::Join;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
+
+/// Represents the ID of a claimable balance for which the operations are to be retrieved.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct OperationsClaimableBalanceId(String);
+
+impl fmt::Display for OperationsClaimableBalanceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Represents the absence of an ID of a claimable balance for which the operations are to be retrieved.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct NoOperationsClaimableBalanceId;
+
+/// Represents a request to list the operations that reference a claimable balance, such as the
+/// `create_claimable_balance` and `claim_claimable_balance` operations of the balance.
+///
+/// The struct matches the parameters necessary to construct a request for the
+/// <a href="https://developers.stellar.org/docs/data/horizon/api-reference/cb-retrieve-related-operations">Retrieve Related Operations</a>
+/// endpoint of the Horizon API, and is used with the
+/// [`HorizonClient::get_operations_for_claimable_balance`](crate::horizon_client::HorizonClient::get_operations_for_claimable_balance)
+/// method.
+///
+#[pagination]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OPERATIONS_PATH, parent = claimable_balance_id)]
+pub struct OperationsForClaimableBalanceRequest<I = NoOperationsClaimableBalanceId> {
+    /// The ID of the claimable balance for which the operations are to be retrieved.
+    claimable_balance_id: I,
+    /// Set to true to include failed operations in results. Options include true and false.
+    include_failed: Option<bool>,
+    /// The related resources to embed in every operation.
+    join: Option<Join>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
+}
+
+impl OperationsForClaimableBalanceRequest<NoOperationsClaimableBalanceId> {
+    /// Creates a new `OperationsForClaimableBalanceRequest` with default parameters.
+    pub fn new() -> Self {
+        OperationsForClaimableBalanceRequest::default()
+    }
+
+    /// Sets the claimable balance ID for the request.
+    ///
+    /// # Arguments
+    /// * `claimable_balance_id` - The claimable balance ID for which the operations are to be retrieved.
+    ///
+    pub fn set_claimable_balance_id(
+        self,
+        claimable_balance_id: impl Into<String>,
+    ) -> OperationsForClaimableBalanceRequest<OperationsClaimableBalanceId> {
+        OperationsForClaimableBalanceRequest {
+            claimable_balance_id: OperationsClaimableBalanceId(claimable_balance_id.into()),
+            include_failed: self.include_failed,
+            join: self.join,
+            page_params: self.page_params,
+        }
+    }
+}
+
+impl OperationsForClaimableBalanceRequest<OperationsClaimableBalanceId> {
+    /// Sets whether to include failed operations in the response.
+    ///
+    /// # Arguments
+    /// * `include_failed` - A boolean value that determines whether to include failed operations in the response.
+    ///
+    pub fn set_include_failed(self, include_failed: bool) -> Self {
+        OperationsForClaimableBalanceRequest {
+            include_failed: Some(include_failed),
+            ..self
+        }
+    }
+
+    /// Sets the related resources to embed in every operation.
+    ///
+    /// # Arguments
+    /// * `join` - The [`Join`] to apply. With [`Join::Transactions`], every operation contains the
+    ///   transaction it is part of.
+    ///
+    pub fn set_join(self, join: Join) -> Self {
+        OperationsForClaimableBalanceRequest {
+            join: Some(join),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Order, Request};
+
+    #[test]
+    fn test_operations_for_claimable_balance_request() {
+        let request = OperationsForClaimableBalanceRequest::new()
+            .set_claimable_balance_id(
+                "00000000e7a2e5a4c47cd0d4fe1b3e2d9c1f5b8b1f2c1fa1a4ddf2f8b7a8e5ab4d2e1f30",
+            )
+            .set_cursor(1)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap()
+            .set_join(Join::Transactions);
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/claimable_balances/00000000e7a2e5a4c47cd0d4fe1b3e2d9c1f5b8b1f2c1fa1a4ddf2f8b7a8e5ab4d2e1f30/operations?join=transactions&cursor=1&order=desc"
+        );
+    }
+}
@@ -1,16 +1,20 @@
-use crate::{
-    models::{IncludeFailed, Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::{ledgers::prelude::LedgerSequence, models::Join};
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OPERATIONS_PATH, parent = ledger_sequence)]
 pub struct OperationsForLedgerRequest {
     /// The account ID for which to retrieve operations.
-    ledger_sequence: Option<String>,
+    ledger_sequence: Option<LedgerSequence>,
     /// A boolean value that determines whether to include failed operations in the response.
-    include_failed: Option<IncludeFailed>,
+    include_failed: Option<bool>,
+    /// The related resources to embed in every operation.
+    join: Option<Join>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl OperationsForLedgerRequest {
@@ -23,56 +27,72 @@ impl OperationsForLedgerRequest {
     /// # Arguments
     /// * `include_failed` - A boolean value that determines whether to include failed operations in the response.
     ///
-    pub fn set_include_failed(self, include_failed: IncludeFailed) -> OperationsForLedgerRequest {
+    pub fn set_include_failed(self, include_failed: bool) -> OperationsForLedgerRequest {
         OperationsForLedgerRequest {
             include_failed: Some(include_failed),
             ..self
         }
     }
 
-    /// Sets the account ID for which to retrieve operations.
+    /// Sets the sequence number of the ledger for which to retrieve operations.
     ///
     /// # Arguments
-    /// * `account_id` - A `String` representing the account ID.
+    /// * `ledger_sequence` - The sequence number of the ledger.
     ///
-    pub fn set_account_id(self, ledger_sequence: impl Into<String>) -> OperationsForLedgerRequest {
-        OperationsForLedgerRequest {
-            ledger_sequence: Some(ledger_sequence.into()),
+    /// # Returns
+    /// The `OperationsForLedgerRequest` with the ledger sequence set, or an error if the ledger
+    /// sequence is `0`.
+    ///
+    pub fn set_ledger_sequence(
+        self,
+        ledger_sequence: u32,
+    ) -> Result<OperationsForLedgerRequest, String> {
+        Ok(OperationsForLedgerRequest {
+            ledger_sequence: Some(LedgerSequence::new(ledger_sequence)?),
             ..self
-        }
+        })
     }
-}
 
-impl Request for OperationsForLedgerRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-        ]
-        .build_query_parameters()
+    /// Sets the sequence number of the ledger for which to retrieve operations, despite its name.
+    ///
+    /// # Arguments
+    /// * `ledger_sequence` - The sequence number of the ledger, as a string.
+    ///
+    /// # Returns
+    /// The `OperationsForLedgerRequest` with the ledger sequence set, or an error if the ledger
+    /// sequence is invalid.
+    ///
+    #[deprecated(
+        note = "use `set_ledger_sequence` instead; `set_account_id` will be removed in the next major release"
+    )]
+    pub fn set_account_id(
+        self,
+        ledger_sequence: impl Into<String>,
+    ) -> Result<OperationsForLedgerRequest, String> {
+        Ok(OperationsForLedgerRequest {
+            ledger_sequence: Some(ledger_sequence.into().parse()?),
+            ..self
+        })
     }
 
-    fn build_url(&self, base_url: &str) -> String {
-        let binding = "".to_string();
-        let ledger_sequence = self.ledger_sequence.as_ref().unwrap_or(&binding);
-
-        format!(
-            "{}/ledgers/{}/{}{}",
-            base_url,
-            ledger_sequence,
-            super::OPERATIONS_PATH,
-            self.get_query_parameters()
-        )
+    /// Sets the related resources to embed in every operation.
+    ///
+    /// # Arguments
+    /// * `join` - The [`Join`] to apply. With [`Join::Transactions`], every operation contains the
+    ///   transaction it is part of.
+    ///
+    pub fn set_join(self, join: Join) -> OperationsForLedgerRequest {
+        OperationsForLedgerRequest {
+            join: Some(join),
+            ..self
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{Order, Request};
 
     #[test]
     fn test_operations_for_ledger_request() {
@@ -83,12 +103,17 @@ mod tests {
             .unwrap()
             .set_order(Order::Desc)
             .unwrap()
-            .set_include_failed(IncludeFailed::True)
-            .set_account_id("00000000");
+            .set_include_failed(true)
+            .set_ledger_sequence(1234)
+            .unwrap();
 
         assert_eq!(
             request.get_query_parameters(),
-            "?cursor=12345&limit=200&order=desc&include_failed=true"
+            "?include_failed=true&cursor=12345&limit=200&order=desc"
         );
+
+        assert!(OperationsForLedgerRequest::new()
+            .set_ledger_sequence(0)
+            .is_err());
     }
 }
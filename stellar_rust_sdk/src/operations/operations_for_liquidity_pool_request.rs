@@ -1,16 +1,20 @@
-use crate::{
-    models::{IncludeFailed, Order, Request},
-    BuildQueryParametersExt,
-};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::Join;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OPERATIONS_PATH, parent = liquidity_pool_id)]
 pub struct OperationsForLiquidityPoolRequest {
     /// A unique identifier for the liquidity pool of the operation(s).
     liquidity_pool_id: Option<String>,
     /// Set to true to include failed operations in results. Options include true and false.
-    include_failed: Option<IncludeFailed>,
+    include_failed: Option<bool>,
+    /// The related resources to embed in every operation.
+    join: Option<Join>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl OperationsForLiquidityPoolRequest {
@@ -23,10 +27,7 @@ impl OperationsForLiquidityPoolRequest {
     /// # Arguments
     /// * `include_failed` - A boolean value that determines whether to include failed operations in the response.
     ///
-    pub fn set_include_failed(
-        self,
-        include_failed: IncludeFailed,
-    ) -> OperationsForLiquidityPoolRequest {
+    pub fn set_include_failed(self, include_failed: bool) -> OperationsForLiquidityPoolRequest {
         OperationsForLiquidityPoolRequest {
             include_failed: Some(include_failed),
             ..self
@@ -47,37 +48,25 @@ impl OperationsForLiquidityPoolRequest {
             ..self
         }
     }
-}
 
-impl Request for OperationsForLiquidityPoolRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-        ]
-        .build_query_parameters()
-    }
-    fn build_url(&self, base_url: &str) -> String {
-        let binding = "".to_string();
-        let liquidity_pool_id = self.liquidity_pool_id.as_ref().unwrap_or(&binding);
-        format!(
-            "{}/liquidity_pools/{}/{}{}",
-            base_url,
-            liquidity_pool_id,
-            super::OPERATIONS_PATH,
-            self.get_query_parameters(),
-        )
+    /// Sets the related resources to embed in every operation.
+    ///
+    /// # Arguments
+    /// * `join` - The [`Join`] to apply. With [`Join::Transactions`], every operation contains the
+    ///   transaction it is part of.
+    ///
+    pub fn set_join(self, join: Join) -> OperationsForLiquidityPoolRequest {
+        OperationsForLiquidityPoolRequest {
+            join: Some(join),
+            ..self
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Order;
+    use crate::models::{Order, Request};
 
     #[test]
     fn test_all_operations_request() {
@@ -88,11 +77,11 @@ mod tests {
             .unwrap()
             .set_order(Order::Desc)
             .unwrap()
-            .set_include_failed(IncludeFailed::True);
+            .set_include_failed(true);
 
         assert_eq!(
             request.get_query_parameters(),
-            "?cursor=1&limit=10&order=desc&include_failed=true"
+            "?include_failed=true&cursor=1&limit=10&order=desc"
         );
     }
 }
@@ -1,15 +1,18 @@
-use stellar_rust_sdk_derive::pagination;
-
-use crate::{
-    models::{Order, Request},
-    BuildQueryParametersExt,
-};
+use crate::models::pagination::PageParams;
+use crate::{models::Join, transactions::prelude::TransactionHash};
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::OPERATIONS_PATH, parent = transaction_hash)]
 pub struct OperationsForTransactionRequest {
     /// The hash of the transaction. Optional.
-    transaction_hash: Option<String>,
+    transaction_hash: Option<TransactionHash>,
+    /// The related resources to embed in every operation.
+    join: Option<Join>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl OperationsForTransactionRequest {
@@ -22,42 +25,38 @@ impl OperationsForTransactionRequest {
     /// # Arguments
     /// * `transaction_hash` - A `String` representing the transaction hash.
     ///
-    pub fn set_transaction_hash(self, transaction_hash: impl Into<String>) -> OperationsForTransactionRequest {
-        OperationsForTransactionRequest {
-            transaction_hash: Some(transaction_hash.into()),
+    /// # Returns
+    /// The `OperationsForTransactionRequest` with the transaction hash set, or an error if the
+    /// transaction hash is invalid.
+    ///
+    pub fn set_transaction_hash(
+        self,
+        transaction_hash: impl Into<String>,
+    ) -> Result<OperationsForTransactionRequest, String> {
+        Ok(OperationsForTransactionRequest {
+            transaction_hash: Some(transaction_hash.into().parse()?),
             ..self
-        }
-    }
-}
-
-impl Request for OperationsForTransactionRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
+        })
     }
 
-    fn build_url(&self, base_url: &str) -> String {
-        let transaction_hash = &self.transaction_hash.as_ref().unwrap();
-        use crate::transactions::TRANSACTIONS_PATH;
-        format!(
-            "{}/{}/{}/{}{}",
-            base_url,
-            TRANSACTIONS_PATH,
-            transaction_hash,
-            super::OPERATIONS_PATH,
-            self.get_query_parameters(),
-        )
+    /// Sets the related resources to embed in every operation.
+    ///
+    /// # Arguments
+    /// * `join` - The [`Join`] to apply. With [`Join::Transactions`], every operation contains the
+    ///   transaction it is part of.
+    ///
+    pub fn set_join(self, join: Join) -> OperationsForTransactionRequest {
+        OperationsForTransactionRequest {
+            join: Some(join),
+            ..self
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Order;
+    use crate::models::{Order, Request};
 
     #[test]
     fn test_operations_for_transaction_request() {
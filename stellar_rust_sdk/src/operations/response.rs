@@ -1,12 +1,22 @@
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
+use super::operation_details::{OperationDetails, PathPaymentDetails};
+use super::operation_id::OperationId;
+use super::operation_type::OperationType;
+use crate::effects::prelude::EffectsResponse;
+use crate::error::Error;
+use crate::horizon_client::HorizonClient;
 use crate::models::{
-    prelude::{Embedded, Link, ResponseLinks},
+    parse_muxed_account,
+    prelude::{AssetType, Embedded, Link, MuxedAccount, ResponseLinks, TemplateLink},
     Response,
 };
+use crate::transactions::prelude::{TransactionHash, TransactionResponse};
 
 #[derive(Serialize, Deserialize, Getters, Debug)]
+#[non_exhaustive]
 pub struct OperationResponse {
     #[serde(rename = "_links")]
     pub links: ResponseLinks,
@@ -14,34 +24,207 @@ pub struct OperationResponse {
     pub embedded: Embedded<Operation>,
 }
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
+/// Represents an operation, as returned by the operation endpoints of Horizon.
+///
+/// The fields that all operations have in common, such as the ID and the source account, are
+/// fields of the struct. The fields that depend on the type of the operation, such as the amount
+/// of a payment, are part of its [`details`](Operation::details), which has a variant per
+/// operation type.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Operation {
     #[serde(rename = "_links")]
     pub links: OperationLinks,
-    pub id: String,
+    pub id: OperationId,
     #[serde(rename = "paging_token")]
     pub paging_token: String,
     #[serde(rename = "transaction_successful")]
     pub transaction_successful: bool,
     #[serde(rename = "source_account")]
     pub source_account: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
+    // The muxed account that originated the operation, if it is a muxed account.
+    #[serde(
+        rename = "source_account_muxed",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_account_muxed: Option<String>,
+    // The ID of the muxed account that originated the operation, if it is a muxed account.
+    #[serde(
+        rename = "source_account_muxed_id",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_account_muxed_id: Option<String>,
     #[serde(rename = "type_i")]
     pub type_i: i64,
     #[serde(rename = "created_at")]
     pub created_at: String,
     #[serde(rename = "transaction_hash")]
-    pub transaction_hash: String,
-    #[serde(rename = "starting_balance")]
-    pub starting_balance: String,
-    pub funder: String,
-    pub account: String,
+    pub transaction_hash: TransactionHash,
+    // The account sponsoring the entry created by the operation, if any.
+    #[serde(default)]
+    pub sponsor: Option<String>,
+    // The fields that depend on the type of the operation, selected by its `type` field.
+    #[serde(flatten)]
+    pub details: OperationDetails,
+    // Only present when the operation was requested with `join=transactions`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<TransactionResponse>,
+}
+
+impl Operation {
+    /// Returns the muxed account that originated the operation, or `None` if it was not
+    /// originated by a muxed account.
+    pub fn source_muxed_account(&self) -> Option<Result<MuxedAccount, String>> {
+        parse_muxed_account(self.source_account_muxed.as_deref())
+    }
+
+    /// Returns the type of the operation, as found in its `type` field, such as `payment`.
+    pub fn type_field(&self) -> &str {
+        self.details.type_name()
+    }
+
+    /// Returns the type of the operation, or `None` if its `type_i` is not a known operation type.
+    pub fn operation_type(&self) -> Option<OperationType> {
+        u32::try_from(self.type_i)
+            .ok()
+            .and_then(OperationType::from_i)
+    }
+
+    /// Returns whether the operation is a `path_payment_strict_receive` or a
+    /// `path_payment_strict_send` operation.
+    pub fn is_path_payment(&self) -> bool {
+        self.path_payment().is_some()
+    }
+
+    /// Returns the fields of a path payment, or `None` if the operation is not a path payment.
+    pub fn path_payment(&self) -> Option<&PathPaymentDetails> {
+        match &self.details {
+            OperationDetails::PathPaymentStrictReceive(details)
+            | OperationDetails::PathPaymentStrictSend(details) => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Returns the asset that was sent by a path payment, or `None` if the operation is not a
+    /// path payment.
+    pub fn source_asset(&self) -> Option<Result<AssetType, String>> {
+        self.path_payment().map(PathPaymentDetails::source_asset)
+    }
+
+    /// Returns the asset that was received by a payment or a path payment, or `None` if the
+    /// operation is neither.
+    pub fn destination_asset(&self) -> Option<Result<AssetType, String>> {
+        match &self.details {
+            OperationDetails::Payment(details) => Some(details.asset()),
+            _ => self
+                .path_payment()
+                .map(PathPaymentDetails::destination_asset),
+        }
+    }
+
+    /// Retrieves the transaction containing the operation, by following its `transaction` link.
+    /// Unlike the `transaction` field, which is only set for operations requested with
+    /// `join=transactions`, this sends a request.
+    pub async fn load_transaction(
+        &self,
+        horizon_client: &HorizonClient,
+    ) -> Result<TransactionResponse, Error> {
+        horizon_client
+            .follow_link(self.links.transaction.href.as_deref(), "transaction")
+            .await
+    }
+
+    /// Retrieves the first page of the effects of the operation, by following its `effects` link.
+    /// Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn effects(&self, horizon_client: &HorizonClient) -> Result<EffectsResponse, Error> {
+        horizon_client
+            .follow_link(self.links.effects.href.as_deref(), "effects")
+            .await
+    }
+}
+
+/// Represents the common fields of an operation, borrowed from the raw JSON it was read from.
+///
+/// Unlike [`Operation`], a `BorrowedOperation` does not allocate for its string fields, unless
+/// they contain escaped characters. It is meant for high-volume ingestion, in combination with
+/// [`RawPage`](crate::models::prelude::RawPage) and the
+/// [`HorizonClient::fetch_raw`](crate::horizon_client::HorizonClient::fetch_raw) method.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[non_exhaustive]
+pub struct BorrowedOperation<'a> {
+    #[serde(borrow)]
+    id: Cow<'a, str>,
+    #[serde(borrow)]
+    paging_token: Cow<'a, str>,
+    transaction_successful: bool,
+    #[serde(borrow)]
+    source_account: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    type_field: Cow<'a, str>,
+    type_i: i64,
+    #[serde(borrow)]
+    created_at: Cow<'a, str>,
+    #[serde(borrow)]
+    transaction_hash: Cow<'a, str>,
+}
+
+impl<'a> BorrowedOperation<'a> {
+    /// Returns the ID of the operation.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the paging token of the operation.
+    pub fn paging_token(&self) -> &str {
+        &self.paging_token
+    }
+
+    /// Returns whether the transaction containing the operation was successful.
+    pub fn transaction_successful(&self) -> bool {
+        self.transaction_successful
+    }
+
+    /// Returns the source account of the operation.
+    pub fn source_account(&self) -> &str {
+        &self.source_account
+    }
+
+    /// Returns the type of the operation, such as `payment`.
+    pub fn type_field(&self) -> &str {
+        &self.type_field
+    }
+
+    /// Returns the numeric type of the operation.
+    pub fn type_i(&self) -> i64 {
+        self.type_i
+    }
+
+    /// Returns the type of the operation, or `None` if its `type_i` is not a known operation type.
+    pub fn operation_type(&self) -> Option<OperationType> {
+        u32::try_from(self.type_i)
+            .ok()
+            .and_then(OperationType::from_i)
+    }
+
+    /// Returns the time at which the operation was included in a ledger.
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// Returns the hash of the transaction containing the operation.
+    pub fn transaction_hash(&self) -> &str {
+        &self.transaction_hash
+    }
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct OperationLinks {
     #[serde(rename = "self")]
     pub self_field: Link,
@@ -49,11 +232,14 @@ pub struct OperationLinks {
     pub effects: Link,
     pub succeeds: Link,
     pub precedes: Link,
+    // Only present on operations that can produce trades, such as path payments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trades: Option<TemplateLink>,
 }
 
 impl Response for OperationResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let operation_record = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let operation_record = crate::models::deserialize_json(&json)?;
 
         Ok(operation_record)
     }
@@ -61,7 +247,7 @@ impl Response for OperationResponse {
 
 impl Response for Operation {
     fn from_json(json: String) -> Result<Self, String> {
-        let operation_record = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let operation_record = crate::models::deserialize_json(&json)?;
 
         Ok(operation_record)
     }
@@ -1,10 +1,8 @@
+use super::operation_id::OperationId;
 use crate::models::Request;
 
-/// Represents a query parameter for the ID of an operation.
-pub struct OperationId(pub String);
-
 /// Represents the absence of a query parameter for the ID of an operation.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoOperationId;
 
 /// Represents a request to fetch details of a single operation from the Horizon API.
@@ -24,12 +22,13 @@ pub struct NoOperationId;
 /// # use stellar_rs::operations::prelude::SingleOperationRequest;
 /// # use stellar_rs::models::Request;
 /// let request = SingleOperationRequest::new()
-///    .set_operation_id("123456");
+///    .set_operation_id("123456")
+///    .unwrap();
 /// // Use with HorizonClient::get_single_operation
 /// ```
 ///
-#[derive(Default, Clone)]
-pub struct SingleOperationRequest<I> {
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct SingleOperationRequest<I = NoOperationId> {
     /// The ID of the operation.
     operation_id: I,
 }
@@ -45,17 +44,24 @@ impl SingleOperationRequest<NoOperationId> {
     /// # Arguments
     /// * `operation_id` - A `String` specifying the operation ID.
     ///
-    pub fn set_operation_id(self, operation_id: impl Into<String>) -> SingleOperationRequest<OperationId> {
-        SingleOperationRequest {
-            operation_id: OperationId(operation_id.into()),
-        }
+    /// # Returns
+    /// A `SingleOperationRequest` with the specified operation ID, or an error if the operation ID
+    /// is invalid.
+    ///
+    pub fn set_operation_id(
+        self,
+        operation_id: impl Into<String>,
+    ) -> Result<SingleOperationRequest<OperationId>, String> {
+        Ok(SingleOperationRequest {
+            operation_id: operation_id.into().parse()?,
+        })
     }
 }
 
 impl Request for SingleOperationRequest<OperationId> {
     fn get_query_parameters(&self) -> String {
         let mut query = String::new();
-        query.push_str(&format!("{}", self.operation_id.0));
+        query.push_str(&format!("{}", self.operation_id));
 
         query.trim_end_matches('&').to_string()
     }
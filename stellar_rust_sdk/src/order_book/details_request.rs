@@ -1,13 +1,17 @@
-use crate::models::prelude::AssetType;
-use crate::models::Request;
+use crate::models::prelude::{AssetPair, AssetType};
+use crate::models::{collect_violations, is_valid_asset, Request};
+#[derive(PartialEq, Debug, Eq, Hash)]
 pub struct SellingAsset(AssetType);
+#[derive(Default, PartialEq, Debug, Eq, Hash)]
 pub struct NoSellingAsset;
+#[derive(PartialEq, Debug, Eq, Hash)]
 pub struct BuyingAsset(AssetType);
+#[derive(Default, PartialEq, Debug, Eq, Hash)]
 pub struct NoBuyingAsset;
 
 /// Represents the request for the details of an order book.
-#[derive(PartialEq, Debug)]
-pub struct DetailsRequest<S, B> {
+#[derive(Default, PartialEq, Debug, Eq, Hash)]
+pub struct DetailsRequest<S = NoSellingAsset, B = NoBuyingAsset> {
     /// The selling asset of the order book.
     pub selling_asset: S,
     /// The buying asset of the order book.
@@ -16,24 +20,22 @@ pub struct DetailsRequest<S, B> {
 
 /// Represents the selling asset of the order book with no buying asset or selling asset
 impl DetailsRequest<NoSellingAsset, NoBuyingAsset> {
+    /// Creates a new `DetailsRequest` with default parameters.
     pub fn new() -> Self {
-        DetailsRequest {
-            selling_asset: NoSellingAsset,
-            buying_asset: NoBuyingAsset,
-        }
+        DetailsRequest::default()
     }
 
     /// Sets the selling asset of the order book.
     ///
     /// # Arguments
-    /// * `selling_asset` - An [`AssetType`] enum value specifying the selling asset.
+    /// * `selling_asset` - The selling asset, such as an [`AssetType`] or an [`Asset`](crate::models::Asset).
     ///
     pub fn set_selling_asset(
         self,
-        selling_asset: AssetType,
+        selling_asset: impl Into<AssetType>,
     ) -> Result<DetailsRequest<SellingAsset, NoBuyingAsset>, String> {
         Ok(DetailsRequest {
-            selling_asset: SellingAsset(selling_asset),
+            selling_asset: SellingAsset(selling_asset.into()),
             buying_asset: NoBuyingAsset,
         })
     }
@@ -41,14 +43,30 @@ impl DetailsRequest<NoSellingAsset, NoBuyingAsset> {
     /// Sets the buying asset of the order book.
     ///
     /// # Arguments
-    /// * `buying_asset` - An [`AssetType`] enum value specifying the buying asset.
+    /// * `buying_asset` - The buying asset, such as an [`AssetType`] or an [`Asset`](crate::models::Asset).
     pub fn set_buying_asset(
         self,
-        buying_asset: AssetType,
+        buying_asset: impl Into<AssetType>,
     ) -> Result<DetailsRequest<NoSellingAsset, BuyingAsset>, String> {
         Ok(DetailsRequest {
             selling_asset: NoSellingAsset,
-            buying_asset: BuyingAsset(buying_asset),
+            buying_asset: BuyingAsset(buying_asset.into()),
+        })
+    }
+
+    /// Sets both assets of the order book at once.
+    ///
+    /// # Arguments
+    /// * `asset_pair` - An [`AssetPair`] of which the base asset is the selling asset, and the
+    ///   counter asset is the buying asset.
+    ///
+    pub fn set_asset_pair(
+        self,
+        asset_pair: AssetPair,
+    ) -> Result<DetailsRequest<SellingAsset, BuyingAsset>, String> {
+        Ok(DetailsRequest {
+            selling_asset: SellingAsset(asset_pair.base().clone()),
+            buying_asset: BuyingAsset(asset_pair.counter().clone()),
         })
     }
 }
@@ -58,13 +76,13 @@ impl DetailsRequest<NoSellingAsset, BuyingAsset> {
     /// Sets the selling asset of the order book.
     ///
     /// # Arguments
-    /// * `selling_asset` - An [`AssetType`] enum value specifying the selling asset.
+    /// * `selling_asset` - The selling asset, such as an [`AssetType`] or an [`Asset`](crate::models::Asset).
     pub fn set_selling_asset(
         self,
-        selling_asset: AssetType,
+        selling_asset: impl Into<AssetType>,
     ) -> Result<DetailsRequest<SellingAsset, BuyingAsset>, String> {
         Ok(DetailsRequest {
-            selling_asset: SellingAsset(selling_asset),
+            selling_asset: SellingAsset(selling_asset.into()),
             buying_asset: self.buying_asset,
         })
     }
@@ -75,55 +93,72 @@ impl DetailsRequest<SellingAsset, NoBuyingAsset> {
     /// Sets the buying asset of the order book.
     ///
     /// # Arguments
-    /// * `buying_asset` - An [`AssetType`] enum value specifying the buying asset.
+    /// * `buying_asset` - The buying asset, such as an [`AssetType`] or an [`Asset`](crate::models::Asset).
     pub fn set_buying_asset(
         self,
-        buying_asset: AssetType,
+        buying_asset: impl Into<AssetType>,
     ) -> Result<DetailsRequest<SellingAsset, BuyingAsset>, String> {
         Ok(DetailsRequest {
             selling_asset: self.selling_asset,
-            buying_asset: BuyingAsset(buying_asset),
+            buying_asset: BuyingAsset(buying_asset.into()),
         })
     }
 }
 
-impl Request for DetailsRequest<SellingAsset, BuyingAsset> {
-    fn get_query_parameters(&self) -> String {
-        let mut query: Vec<String> = Vec::new();
-
-        match &self.selling_asset.0 {
-            AssetType::Native => {
-                query.push(format!("selling_asset_type=native"));
-            }
-            AssetType::Alphanumeric4(asset) => {
-                query.push(format!("selling_asset_type=credit_alphanum4"));
-                query.push(format!("&selling_asset_code={}", asset.asset_code));
-                query.push(format!("&selling_asset_issuer={}", asset.asset_issuer));
-            }
-            AssetType::Alphanumeric12(asset) => {
-                query.push(format!("selling_asset_type=credit_alphanum12"));
-                query.push(format!("&selling_asset_code={}", asset.asset_code));
-                query.push(format!("&selling_asset_issuer={}", asset.asset_issuer));
-            }
+impl DetailsRequest<SellingAsset, BuyingAsset> {
+    /// Validates the assets of the order book, and returns every violation instead of only the
+    /// first.
+    ///
+    /// The assets are not checked when they are set. `validate` checks their codes and the
+    /// checksums of their issuers, and that the order book does not trade an asset for itself.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::models::prelude::{AssetData, AssetType};
+    /// use stellar_rs::order_book::prelude::DetailsRequest;
+    ///
+    /// let request = DetailsRequest::new()
+    ///     .set_selling_asset(AssetType::Alphanumeric4(AssetData {
+    ///         asset_code: "US-D".to_string(),
+    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+    ///     }))?
+    ///     .set_buying_asset(AssetType::Alphanumeric4(AssetData {
+    ///         asset_code: "EURT".to_string(),
+    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA6".to_string(),
+    ///     }))?;
+    ///
+    /// assert_eq!(
+    ///     request.validate().unwrap_err(),
+    ///     vec![
+    ///         "Asset code must consist of 1 to 4 alphanumeric characters: US-D",
+    ///         "Invalid asset issuer: GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA6",
+    ///     ]
+    /// );
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut checks = vec![
+            is_valid_asset(&self.selling_asset.0),
+            is_valid_asset(&self.buying_asset.0),
+        ];
+        if self.selling_asset.0 == self.buying_asset.0 {
+            checks.push(Err(
+                "Selling asset and buying asset must differ.".to_string()
+            ));
         }
 
-        match &self.buying_asset.0 {
-            AssetType::Native => {
-                query.push(format!("&buying_asset_type=native"));
-            }
-            AssetType::Alphanumeric4(asset) => {
-                query.push(format!("&buying_asset_type=credit_alphanum4"));
-                query.push(format!("&buying_asset_code={}", asset.asset_code));
-                query.push(format!("&buying_asset_issuer={}", asset.asset_issuer));
-            }
-            AssetType::Alphanumeric12(asset) => {
-                query.push(format!("&buying_asset_type=credit_alphanum12"));
-                query.push(format!("&buying_asset_code={}", asset.asset_code));
-                query.push(format!("&buying_asset_issuer={}", asset.asset_issuer));
-            }
-        }
+        collect_violations(checks)
+    }
+}
 
-        query.join("")
+impl Request for DetailsRequest<SellingAsset, BuyingAsset> {
+    fn get_query_parameters(&self) -> String {
+        format!(
+            "{}&{}",
+            self.selling_asset.0.to_query_parameters("selling"),
+            self.buying_asset.0.to_query_parameters("buying")
+        )
     }
 
     fn build_url(&self, base_url: &str) -> String {
@@ -169,4 +204,59 @@ mod tests {
             "selling_asset_type=credit_alphanum4&selling_asset_code=USDC&selling_asset_issuer=GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5&buying_asset_type=native"
         );
     }
+
+    #[test]
+    fn test_validate() {
+        use super::DetailsRequest;
+        use crate::models::prelude::{AssetData, AssetType};
+
+        let usdc = AssetType::Alphanumeric4(AssetData {
+            asset_code: "USDC".to_string(),
+            asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+        });
+        let details_request = DetailsRequest::new()
+            .set_selling_asset(usdc.clone())
+            .unwrap()
+            .set_buying_asset(AssetType::Native)
+            .unwrap();
+        assert_eq!(details_request.validate(), Ok(()));
+
+        let details_request = DetailsRequest::new()
+            .set_selling_asset(usdc.clone())
+            .unwrap()
+            .set_buying_asset(usdc)
+            .unwrap();
+        assert_eq!(
+            details_request.validate().unwrap_err(),
+            vec!["Selling asset and buying asset must differ."]
+        );
+    }
+
+    #[test]
+    fn test_details_request_asset_pair() {
+        use super::DetailsRequest;
+        use crate::models::prelude::{AssetPair, AssetType};
+        use crate::models::{Asset, Request};
+
+        let usdc = Asset::new()
+            .set_issued(
+                "USDC",
+                "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
+            )
+            .unwrap();
+        let details_request = DetailsRequest::new()
+            .set_asset_pair(AssetPair::new(usdc.clone(), AssetType::Native))
+            .unwrap();
+        let expected = DetailsRequest::new()
+            .set_selling_asset(usdc)
+            .unwrap()
+            .set_buying_asset(Asset::new())
+            .unwrap();
+
+        assert_eq!(details_request, expected);
+        assert_eq!(
+            details_request.get_query_parameters(),
+            "selling_asset_type=credit_alphanum4&selling_asset_code=USDC&selling_asset_issuer=GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5&buying_asset_type=native"
+        );
+    }
 }
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+
+use super::response::{DetailsResponse, PriceR};
+use crate::models::prelude::RationalPrice;
+
+/// Represents the change of a single price level between two snapshots of an order book.
+///
+/// Price levels are identified by their exact [`RationalPrice`], so that two levels whose decimal
+/// prices round to the same string are still told apart. The amounts are those reported by
+/// Horizon for the level.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PriceLevelChange {
+    /// The price level was not in the previous snapshot.
+    Added {
+        /// The price of the level.
+        price: RationalPrice,
+        /// The amount of the level in the current snapshot.
+        amount: String,
+    },
+    /// The price level is no longer in the current snapshot.
+    Removed {
+        /// The price of the level.
+        price: RationalPrice,
+        /// The amount of the level in the previous snapshot.
+        amount: String,
+    },
+    /// The amount of the price level changed.
+    Changed {
+        /// The price of the level.
+        price: RationalPrice,
+        /// The amount of the level in the previous snapshot.
+        previous_amount: String,
+        /// The amount of the level in the current snapshot.
+        amount: String,
+    },
+}
+
+impl PriceLevelChange {
+    /// Returns the price of the level that changed.
+    pub fn price(&self) -> RationalPrice {
+        match self {
+            PriceLevelChange::Added { price, .. }
+            | PriceLevelChange::Removed { price, .. }
+            | PriceLevelChange::Changed { price, .. } => *price,
+        }
+    }
+}
+
+/// Represents the differences between two consecutive snapshots of an order book, such as those
+/// yielded by [`HorizonClient::stream_order_book`](crate::horizon_client::HorizonClient::stream_order_book).
+///
+/// The changes of each side are ordered from the best price to the worst, like the levels of a
+/// [`DetailsResponse`]: bids by descending price, and asks by ascending price. Price levels of
+/// which the amount did not change are left out.
+///
+/// # Example
+/// ```
+/// use stellar_rs::order_book::prelude::*;
+///
+/// # fn example(previous: DetailsResponse, current: DetailsResponse) {
+/// let diff = OrderBookDiff::new(&previous, &current);
+/// for change in diff.asks() {
+///     match change {
+///         PriceLevelChange::Added { price, amount } => println!("{} at {}", amount, price),
+///         PriceLevelChange::Removed { price, .. } => println!("None left at {}", price),
+///         PriceLevelChange::Changed { price, amount, .. } => println!("{} at {}", amount, price),
+///     }
+/// }
+/// # }
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OrderBookDiff {
+    /// The changes of the bids, from the highest price to the lowest.
+    bids: Vec<PriceLevelChange>,
+    /// The changes of the asks, from the lowest price to the highest.
+    asks: Vec<PriceLevelChange>,
+}
+
+impl OrderBookDiff {
+    /// Computes the differences between two snapshots of the same order book.
+    ///
+    /// # Arguments
+    /// * `previous` - The earlier snapshot.
+    /// * `current` - The later snapshot.
+    ///
+    pub fn new(previous: &DetailsResponse, current: &DetailsResponse) -> Self {
+        let mut bids = diff_levels(bid_levels(previous), bid_levels(current));
+        bids.reverse();
+        let asks = diff_levels(ask_levels(previous), ask_levels(current));
+
+        OrderBookDiff { bids, asks }
+    }
+
+    /// Returns the changes of the bids, from the highest price to the lowest.
+    pub fn bids(&self) -> &[PriceLevelChange] {
+        &self.bids
+    }
+
+    /// Returns the changes of the asks, from the lowest price to the highest.
+    pub fn asks(&self) -> &[PriceLevelChange] {
+        &self.asks
+    }
+
+    /// Returns whether the snapshots are equal.
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+}
+
+impl DetailsResponse {
+    /// Computes the differences between an earlier snapshot of the order book and this one.
+    ///
+    /// This is equivalent to [`OrderBookDiff::new`] with `previous` and `self`.
+    ///
+    pub fn diff(&self, previous: &DetailsResponse) -> OrderBookDiff {
+        OrderBookDiff::new(previous, self)
+    }
+}
+
+/// Collects the bids of a snapshot by their exact price.
+fn bid_levels(snapshot: &DetailsResponse) -> BTreeMap<RationalPrice, &str> {
+    price_levels(
+        snapshot
+            .bids()
+            .iter()
+            .map(|bid| (bid.price_ratio(), bid.amount())),
+    )
+}
+
+/// Collects the asks of a snapshot by their exact price.
+fn ask_levels(snapshot: &DetailsResponse) -> BTreeMap<RationalPrice, &str> {
+    price_levels(
+        snapshot
+            .asks()
+            .iter()
+            .map(|ask| (ask.price_ratio(), ask.amount())),
+    )
+}
+
+/// Collects the price levels of one side of an order book by their exact price.
+fn price_levels<'a>(
+    levels: impl Iterator<Item = (&'a PriceR, &'a String)>,
+) -> BTreeMap<RationalPrice, &'a str> {
+    levels
+        // Horizon never returns a price with a zero denominator, which is the only price that
+        // can not be converted.
+        .filter_map(|(price, amount)| Some((RationalPrice::try_from(price).ok()?, amount.as_str())))
+        .collect()
+}
+
+/// Compares the price levels of one side of two snapshots, in ascending order of price.
+fn diff_levels(
+    previous: BTreeMap<RationalPrice, &str>,
+    current: BTreeMap<RationalPrice, &str>,
+) -> Vec<PriceLevelChange> {
+    let mut prices: Vec<RationalPrice> = previous.keys().chain(current.keys()).copied().collect();
+    prices.sort();
+    prices.dedup();
+
+    prices
+        .into_iter()
+        .filter_map(|price| match (previous.get(&price), current.get(&price)) {
+            (None, Some(amount)) => Some(PriceLevelChange::Added {
+                price,
+                amount: amount.to_string(),
+            }),
+            (Some(amount), None) => Some(PriceLevelChange::Removed {
+                price,
+                amount: amount.to_string(),
+            }),
+            (Some(previous_amount), Some(amount)) if previous_amount != amount => {
+                Some(PriceLevelChange::Changed {
+                    price,
+                    previous_amount: previous_amount.to_string(),
+                    amount: amount.to_string(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(bids: &[(u32, u32, &str)], asks: &[(u32, u32, &str)]) -> DetailsResponse {
+        let level = |&(n, d, amount): &(u32, u32, &str)| {
+            serde_json::json!({
+                "price_r": { "n": n, "d": d },
+                "price": RationalPrice::new(n as u64, d as u64).unwrap().to_string(),
+                "amount": amount,
+            })
+        };
+        serde_json::from_value(serde_json::json!({
+            "bids": bids.iter().map(level).collect::<Vec<_>>(),
+            "asks": asks.iter().map(level).collect::<Vec<_>>(),
+            "base": { "asset_type": "native" },
+            "counter": { "asset_type": "native" },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_order_book_diff() {
+        let previous = snapshot(
+            &[(1, 5, "10.0000000"), (1, 4, "20.0000000")],
+            &[(5, 1, "1.0000000")],
+        );
+        let current = snapshot(
+            &[(1, 3, "5.0000000"), (2, 8, "25.0000000")],
+            &[(5, 1, "1.0000000")],
+        );
+
+        let diff = current.diff(&previous);
+        let price = |n, d| RationalPrice::new(n, d).unwrap();
+        assert_eq!(
+            diff.bids(),
+            [
+                PriceLevelChange::Added {
+                    price: price(1, 3),
+                    amount: "5.0000000".to_string(),
+                },
+                PriceLevelChange::Changed {
+                    price: price(1, 4),
+                    previous_amount: "20.0000000".to_string(),
+                    amount: "25.0000000".to_string(),
+                },
+                PriceLevelChange::Removed {
+                    price: price(1, 5),
+                    amount: "10.0000000".to_string(),
+                },
+            ]
+        );
+        assert!(diff.asks().is_empty());
+        assert_eq!(diff.bids()[0].price(), price(1, 3));
+
+        assert!(current.diff(&current).is_empty());
+    }
+}
@@ -1,13 +1,33 @@
 pub mod details_request;
 pub mod response;
 
+/// Provides the differences between two snapshots of an order book, by price level.
+///
+/// # Usage
+/// This module provides [`OrderBookDiff`](diff::OrderBookDiff), which compares consecutive
+/// snapshots, such as those yielded by `HorizonClient::stream_order_book`, for applications that
+/// track an order book incrementally.
+///
+pub mod diff;
+
+/// Provides the state of the order book poller, which polls an order book and only yields the
+/// snapshots that changed.
+///
+/// # Usage
+/// This module is used by the `HorizonClient::poll_order_book` function, and is not part of the
+/// public API.
+///
+pub(crate) mod order_book_poller;
+
 static ORDER_BOOK_PATH: &str = "order_book";
 
 pub mod prelude {
     pub use super::details_request::*;
+    pub use super::diff::*;
     pub use super::response::*;
 }
 
+#[cfg(test)]
 pub mod tests {
 
     #[tokio::test]
@@ -22,10 +42,8 @@ pub mod tests {
         const ASKS_N: &u32 = &5;
         const ASKS_D: &u32 = &1;
         const ASKS_PRICE: &str = "5.0000000";
-        const BASE_ASSET_TYPE: &str = "native";
         const BASE_ASSET_CODE: &str = "IOM";
         const BASE_ASSET_ISSUER: &str = "GCDE6MVFIOYF7YZCSVA6V7MDCFTNWMIOF5PQU3DWPH27AHNX4ERY6AKS";
-        const COUNTER_ASSET_TYPE: &str = "credit_alphanum4";
 
         let horizon_client =
             horizon_client::HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
@@ -61,26 +79,13 @@ pub mod tests {
         // The amount changes all the time
         assert_ne!(binding.asks()[0].amount(), "0");
 
+        assert_eq!(binding.base(), &AssetType::Native);
         assert_eq!(
-            binding.base().asset_type().as_deref(),
-            Some(BASE_ASSET_TYPE)
-        );
-        assert_eq!(binding.base().asset_code().as_deref(), None);
-        assert_eq!(binding.base().asset_issuer().as_deref(), None);
-
-        assert_eq!(
-            binding.counter().asset_type().as_deref(),
-            Some(COUNTER_ASSET_TYPE)
-        );
-
-        assert_eq!(
-            binding.counter().asset_code().as_deref(),
-            Some(BASE_ASSET_CODE)
-        );
-
-        assert_eq!(
-            binding.counter().asset_issuer().as_deref(),
-            Some(BASE_ASSET_ISSUER)
+            binding.counter(),
+            &AssetType::Alphanumeric4(AssetData {
+                asset_code: BASE_ASSET_CODE.to_string(),
+                asset_issuer: BASE_ASSET_ISSUER.to_string(),
+            })
         );
     }
 }
@@ -0,0 +1,153 @@
+use super::response::{Ask, Bid, DetailsResponse};
+use crate::rate_limit::RateLimitInfo;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::{Duration, Instant};
+
+/// The maximum number of times the interval is doubled after consecutive failed polls.
+const MAX_BACKOFF_DOUBLINGS: u32 = 3;
+
+/// The state of an order book poller, shared between the snapshots it yields.
+pub(crate) struct OrderBookPollerState {
+    /// The URL of the order book to poll.
+    pub(crate) url: String,
+    /// The moment at which the order book was last polled, if ever.
+    pub(crate) last_poll: Option<Instant>,
+    /// The number of consecutive polls that failed.
+    pub(crate) consecutive_failures: u32,
+    /// The bids and asks of the previous snapshot, if any.
+    previous: Option<(Vec<Bid>, Vec<Ask>)>,
+    /// The state of the pseudo-random generator of the jitter.
+    jitter_state: u64,
+}
+
+impl OrderBookPollerState {
+    pub(crate) fn new(url: String) -> Self {
+        OrderBookPollerState {
+            url,
+            last_poll: None,
+            consecutive_failures: 0,
+            previous: None,
+            // Every `RandomState` is seeded randomly, so pollers do not share their jitter.
+            jitter_state: RandomState::new().hash_one(0u8),
+        }
+    }
+
+    /// Compares a snapshot with the previous one, and returns whether its bids or asks changed.
+    /// The first snapshot is always considered changed.
+    pub(crate) fn is_changed(&mut self, snapshot: &DetailsResponse) -> bool {
+        let changed = self
+            .previous
+            .as_ref()
+            .is_none_or(|(bids, asks)| *bids != snapshot.bids || *asks != snapshot.asks);
+        if changed {
+            self.previous = Some((snapshot.bids.clone(), snapshot.asks.clone()));
+        }
+        changed
+    }
+
+    /// Returns the time to wait before the next poll.
+    ///
+    /// The interval is measured from the previous poll, and varies by up to 10% in either
+    /// direction, so that pollers started at the same time spread their requests. If the
+    /// consumer took longer than the interval to process the previous snapshot, the next poll is
+    /// sent right away, without catching up on the polls that were missed. After failed polls,
+    /// the interval doubles up to 8 times its length, and once the rate limit of the Horizon
+    /// server is exhausted, polling waits until the rate limit window resets.
+    pub(crate) fn next_delay(
+        &mut self,
+        interval: Duration,
+        rate_limit: Option<RateLimitInfo>,
+        now: Instant,
+    ) -> Duration {
+        let Some(last_poll) = self.last_poll else {
+            return Duration::ZERO;
+        };
+
+        let interval = interval * 2u32.pow(self.consecutive_failures.min(MAX_BACKOFF_DOUBLINGS));
+        let interval = self.jitter(interval);
+        let delay = interval.saturating_sub(now.saturating_duration_since(last_poll));
+
+        match rate_limit {
+            Some(rate_limit) if *rate_limit.remaining() == 0 => {
+                delay.max(Duration::from_secs(*rate_limit.reset()))
+            }
+            _ => delay,
+        }
+    }
+
+    /// Varies a duration randomly by up to 10% in either direction.
+    fn jitter(&mut self, duration: Duration) -> Duration {
+        // SplitMix64, which is plenty for spreading requests.
+        self.jitter_state = self.jitter_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.jitter_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        // A factor between 0.9 and 1.1.
+        let factor = 0.9 + (z as f64 / u64::MAX as f64) * 0.2;
+        duration.mul_f64(factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Response;
+
+    fn snapshot() -> DetailsResponse {
+        let json = include_str!("../../tests/fixtures/order_book/details.json");
+        DetailsResponse::from_json(json.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_is_changed() {
+        let mut state = OrderBookPollerState::new(String::new());
+        let mut snapshot = snapshot();
+
+        assert!(state.is_changed(&snapshot));
+        assert!(!state.is_changed(&snapshot));
+
+        snapshot.bids[0].amount = "1.0000000".to_string();
+        assert!(state.is_changed(&snapshot));
+        assert!(!state.is_changed(&snapshot));
+    }
+
+    #[test]
+    fn test_next_delay() {
+        let interval = Duration::from_secs(10);
+        let mut state = OrderBookPollerState::new(String::new());
+        let now = Instant::now();
+
+        // The first poll is sent right away.
+        assert_eq!(state.next_delay(interval, None, now), Duration::ZERO);
+
+        state.last_poll = Some(now);
+        for _ in 0..100 {
+            let delay = state.next_delay(interval, None, now);
+            assert!(delay >= Duration::from_secs(9) && delay <= Duration::from_secs(11));
+        }
+
+        // A slow consumer does not cause polls to pile up.
+        let later = now + Duration::from_secs(60);
+        assert_eq!(state.next_delay(interval, None, later), Duration::ZERO);
+
+        // Failed polls back off, up to 8 times the interval.
+        state.consecutive_failures = 10;
+        let delay = state.next_delay(interval, None, now);
+        assert!(delay >= Duration::from_secs(72) && delay <= Duration::from_secs(88));
+        state.consecutive_failures = 0;
+
+        // An exhausted rate limit is waited out.
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "3600".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "0".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "120".parse().unwrap());
+        let rate_limit = RateLimitInfo::from_headers(&headers).unwrap();
+        assert_eq!(
+            state.next_delay(interval, Some(rate_limit), later),
+            Duration::from_secs(120)
+        );
+    }
+}
@@ -1,7 +1,13 @@
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
-use crate::models::Response;
+use crate::models::{
+    prelude::{AssetPair, AssetType, RationalPrice},
+    Response,
+};
+
+#[allow(deprecated)]
+pub use crate::compat::{Base, Counter};
 
 /// Represents the response from the Horizon server when querying for the details of an order book.
 ///
@@ -9,20 +15,29 @@ use crate::models::Response;
 /// It includes the bids, asks, base, and counter fields.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct DetailsResponse {
     /// The prices and amounts for the buyside of the asset pair.
     pub bids: Vec<Bid>,
     /// The prices and amounts for the sellside of the asset pair.
     pub asks: Vec<Ask>,
-    /// Details about the base asset.
-    pub base: Base,
-    /// Details about the counter asset.
-    pub counter: Counter,
+    /// The base asset, which is the selling asset of the order book.
+    pub base: AssetType,
+    /// The counter asset, which is the buying asset of the order book.
+    pub counter: AssetType,
+}
+
+impl DetailsResponse {
+    /// Returns the base and counter asset of the order book as an [`AssetPair`].
+    pub fn asset_pair(&self) -> AssetPair {
+        AssetPair::new(self.base.clone(), self.counter.clone())
+    }
 }
 
 /// The prices and amounts for the buyside of the asset pair.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Bid {
     #[serde(rename = "price_r")]
     /// A precise representation of the bid price of the asset pair.
@@ -36,6 +51,7 @@ pub struct Bid {
 /// A precise representation of the ask price of the asset pair.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct PriceR {
     /// The numenator.
     #[serde(rename = "n")]
@@ -45,9 +61,19 @@ pub struct PriceR {
     denominator: u32,
 }
 
+impl TryFrom<&PriceR> for RationalPrice {
+    type Error = String;
+
+    /// Converts the price fraction into an exact [`RationalPrice`], failing if the denominator is zero.
+    fn try_from(price: &PriceR) -> Result<Self, Self::Error> {
+        RationalPrice::new(price.numenator as u64, price.denominator as u64)
+    }
+}
+
 /// The prices and amounts for the sellside of the asset pair.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Ask {
     #[serde(rename = "price_r")]
     /// A precise representation of the ask price of the asset pair.
@@ -58,39 +84,9 @@ pub struct Ask {
     pub amount: String,
 }
 
-/// Details about the base asset.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
-#[serde(rename_all = "camelCase")]
-pub struct Base {
-    /// The type for the base asset. Either native, credit_alphanum4, or credit_alphanum12.
-    #[serde(rename = "asset_type")]
-    pub asset_type: Option<String>,
-    /// The code for the base asset.
-    #[serde(rename = "asset_code")]
-    pub asset_code: Option<String>,
-    /// The Stellar address of the base asset’s issuer.
-    #[serde(rename = "asset_issuer")]
-    pub asset_issuer: Option<String>,
-}
-
-/// Details about the counter asset.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
-#[serde(rename_all = "camelCase")]
-pub struct Counter {
-    /// The type for the counter asset. Either native, credit_alphanum4, or credit_alphanum12.
-    #[serde(rename = "asset_type")]
-    pub asset_type: Option<String>,
-    /// The code for the counter asset.
-    #[serde(rename = "asset_code")]
-    pub asset_code: Option<String>,
-    /// The Stellar address of the counter asset’s issuer.
-    #[serde(rename = "asset_issuer")]
-    pub asset_issuer: Option<String>,
-}
-
 impl Response for DetailsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let operation_record = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let operation_record = crate::models::deserialize_json(&json)?;
 
         Ok(operation_record)
     }
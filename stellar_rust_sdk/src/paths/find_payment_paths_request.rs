@@ -1,4 +1,6 @@
-use crate::models::{is_public_key, Request};
+use crate::models::{
+    collect_violations, is_positive_amount, is_public_key, is_valid_asset, Request,
+};
 use crate::paths::*;
 use crate::BuildQueryParametersExt;
 
@@ -24,11 +26,11 @@ use crate::BuildQueryParametersExt;
 /// let request = FindPaymentsPathRequest::new()
 ///     .set_destination_asset(AssetType::Native).unwrap() // Sets the destination asset to native XLM.
 ///     .set_destination_amount("100.0".to_string()).unwrap() // Sets the amount of the destination asset.
-///     .set_source_account("GCDNJUBQSXK57MSKJ4NSXK5DT5CJMMXMWUE7BN6NTJ6JTH23HQVYXG2C".to_string()).unwrap() // Sets the source account.
-///     .set_destination_account("GAZD7JY7RCZN7KJ27SMUGKDPF7GQTYPXLDU7TFTJNSDB3MLO3M22DEIV".to_string()).unwrap(); // Sets the destination account.
+///     .set_source_account("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7".to_string()).unwrap() // Sets the source account.
+///     .set_destination_account("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H".to_string()).unwrap(); // Sets the destination account.
 /// ```
 ///
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash)]
 pub struct FindPaymentsPathRequest<
     DAs = NoDestinationAsset,
     DAm = NoDestinationAmount,
@@ -47,12 +49,7 @@ pub struct FindPaymentsPathRequest<
 impl FindPaymentsPathRequest<NoDestinationAsset, NoDestinationAmount, NoSourceAccount> {
     /// Creates a new `FindPaymentsPathRequest` with default parameters.
     pub fn new() -> Self {
-        FindPaymentsPathRequest {
-            destination_asset: NoDestinationAsset,
-            destination_amount: NoDestinationAmount,
-            destination_account: None,
-            source_account: NoSourceAccount,
-        }
+        FindPaymentsPathRequest::default()
     }
 }
 
@@ -60,17 +57,18 @@ impl<DAs, DAm, S> FindPaymentsPathRequest<DAs, DAm, S> {
     /// Sets the destination asset for the payment path request.
     ///
     /// # Arguments
-    /// * `destination_asset_type` - The type of asset being sent to the destination account.
+    /// * `destination_asset` - The asset being sent to the destination account, such as an
+    ///   [`AssetType`] or an [`Asset`](crate::models::Asset).
     ///
     /// # Returns
     /// A new instance of `FindPaymentsPathRequest` with the destination asset set.
     ///
     pub fn set_destination_asset(
         self,
-        destination_asset_type: AssetType,
+        destination_asset: impl Into<AssetType>,
     ) -> Result<FindPaymentsPathRequest<DestinationAsset, DAm, S>, String> {
         Ok(FindPaymentsPathRequest {
-            destination_asset: DestinationAsset(destination_asset_type),
+            destination_asset: DestinationAsset(destination_asset.into()),
             destination_amount: self.destination_amount,
             destination_account: self.destination_account,
             source_account: self.source_account,
@@ -151,37 +149,57 @@ impl FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount>
     }
 }
 
-impl Request for FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount> {
-    fn get_query_parameters(&self) -> String {
-        let asset_type_prefix = "destination_asset_type=";
-        let asset_code_prefix = "&destination_asset_code=";
-        let asset_issuer_prefix = "&destination_asset_issuer=";
+impl FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount> {
+    /// Validates the whole request, and returns every violation instead of only the first.
+    ///
+    /// The setters reject invalid accounts one at a time, but the destination asset and amount
+    /// are not checked when they are set, and the fields of the request are public. `validate`
+    /// checks all of them, and collects every violation, so that they can be shown at once.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::models::prelude::{AssetData, AssetType};
+    /// use stellar_rs::paths::prelude::*;
+    ///
+    /// let mut request = FindPaymentsPathRequest::new()
+    ///     .set_destination_asset(AssetType::Alphanumeric4(AssetData {
+    ///         asset_code: "USDC".to_string(),
+    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+    ///     }))?
+    ///     .set_destination_amount("-10")?
+    ///     .set_source_account("GBAC4BTW6UIJOCCUOZ7QATQPVWX6UQVH3ESQ6NEHBMCXJ3MVP4GMT77H")?;
+    /// request.destination_account = Some("GBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLF".to_string());
+    ///
+    /// assert_eq!(
+    ///     request.validate().unwrap_err(),
+    ///     vec![
+    ///         "Amount must be a positive number: -10",
+    ///         "Public key must be 56 characters long",
+    ///     ]
+    /// );
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut checks = vec![
+            is_valid_asset(&self.destination_asset.0),
+            is_positive_amount(&self.destination_amount.0),
+            is_public_key(&self.source_account.0),
+        ];
+        if let Some(destination_account) = &self.destination_account {
+            checks.push(is_public_key(destination_account));
+        }
 
-        // Construct parameters for destination asset.
-        let parameters = match &self.destination_asset {
-            DestinationAsset(AssetType::Native) => format!("{}native", asset_type_prefix),
-            DestinationAsset(AssetType::Alphanumeric4(asset_data))
-            | DestinationAsset(AssetType::Alphanumeric12(asset_data)) => {
-                let asset_type = match self.destination_asset {
-                    DestinationAsset(AssetType::Alphanumeric4(_)) => "credit_alphanum4",
-                    DestinationAsset(AssetType::Alphanumeric12(_)) => "credit_alphanum12",
-                    _ => "", // should not be reached
-                };
+        collect_violations(checks)
+    }
+}
 
-                format!(
-                    "{}{}{}{}{}{}",
-                    asset_type_prefix,
-                    asset_type,
-                    asset_code_prefix,
-                    asset_data.asset_code,
-                    asset_issuer_prefix,
-                    asset_data.asset_issuer
-                )
-            }
-        };
+impl Request for FindPaymentsPathRequest<DestinationAsset, DestinationAmount, SourceAccount> {
+    fn get_query_parameters(&self) -> String {
+        let parameters = self.destination_asset.0.to_query_parameters("destination");
 
         // Construct and return the query parameters.
-        vec![
+        [
             Some(parameters),
             Some(format!("destination_amount={}", self.destination_amount.0)),
             self.destination_account
@@ -1,17 +1,19 @@
-use crate::models::{is_public_key, Request};
+use crate::models::{
+    collect_violations, is_positive_amount, is_public_key, is_valid_asset, Request,
+};
 use crate::paths::*;
 use crate::BuildQueryParametersExt;
 
 /// Represents the absence of either a source account or source asset(s).
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoSource;
 
 /// Represents the source which can be either a vector of assets, or an account.
 /// Exactly one of these must be set, in order to make a valid request.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Source {
     /// A vector of assets available to the sender. Any returned path must start with an asset in this list.
-    SourceAssets(Vec<IssuedOrNative>),
+    SourceAssets(Vec<AssetType>),
     /// The Stellar address of the sender. Any returned path must start with an asset that the sender holds.
     SourceAccount(String),
 }
@@ -44,15 +46,15 @@ impl Default for Source {
 /// let request = ListStrictReceivePaymentPathsRequest::new()
 ///     .set_destination_asset(AssetType::Native).unwrap() // Sets the destination asset to native XLM.
 ///     .set_destination_amount("100.0".to_string()).unwrap() // Sets the amount of the destination asset.
-///     .set_source(Source::SourceAccount("GCDNJUBQSXK57MSKJ4NSXK5DT5CJMMXMWUE7BN6NTJ6JTH23HQVYXG2C".to_string())).unwrap() // Sets the source account.
-///     .set_destination_account("GAZD7JY7RCZN7KJ27SMUGKDPF7GQTYPXLDU7TFTJNSDB3MLO3M22DEIV".to_string()).unwrap(); // Sets the destination account.
+///     .set_source(Source::SourceAccount("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7".to_string())).unwrap() // Sets the source account.
+///     .set_destination_account("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H".to_string()).unwrap(); // Sets the destination account.
 /// ```
 ///
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct ListStrictReceivePaymentPathsRequest<
     DAs = NoDestinationAsset,
     DAm = NoDestinationAmount,
-    S = Source,
+    S = NoSource,
 > {
     /// Represents the asset type being received by the destination account.
     destination_asset: DAs,
@@ -67,12 +69,7 @@ pub struct ListStrictReceivePaymentPathsRequest<
 impl ListStrictReceivePaymentPathsRequest<NoDestinationAsset, NoDestinationAmount, NoSource> {
     /// Creates a new `ListStrictReceivePaymentPathsRequest` with default parameters.
     pub fn new() -> Self {
-        ListStrictReceivePaymentPathsRequest {
-            destination_asset: NoDestinationAsset,
-            destination_amount: NoDestinationAmount,
-            destination_account: None,
-            source: NoSource,
-        }
+        ListStrictReceivePaymentPathsRequest::default()
     }
 }
 
@@ -80,17 +77,18 @@ impl<DAs, DAm, S> ListStrictReceivePaymentPathsRequest<DAs, DAm, S> {
     /// Sets the destination asset for the payment path request.
     ///
     /// # Arguments
-    /// * `destination_asset_type` - The type of asset being received by the destination account.
+    /// * `destination_asset` - The asset being received by the destination account, such as an
+    ///   [`AssetType`] or an [`Asset`](crate::models::Asset).
     ///
     /// # Returns
     /// A new instance of `ListStrictReceivePaymentPathsRequest` with the destination asset set.
     ///
     pub fn set_destination_asset(
         self,
-        destination_asset_type: AssetType,
+        destination_asset: impl Into<AssetType>,
     ) -> Result<ListStrictReceivePaymentPathsRequest<DestinationAsset, DAm, S>, String> {
         Ok(ListStrictReceivePaymentPathsRequest {
-            destination_asset: DestinationAsset(destination_asset_type),
+            destination_asset: DestinationAsset(destination_asset.into()),
             destination_amount: self.destination_amount,
             destination_account: self.destination_account,
             source: self.source,
@@ -163,8 +161,10 @@ impl ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, S
     pub fn set_destination_account(
         self,
         destination_account: impl Into<String>,
-    ) -> Result<ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount>, String>
-    {
+    ) -> Result<
+        ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source>,
+        String,
+    > {
         let destination_account = destination_account.into();
         if let Err(e) = is_public_key(&destination_account) {
             return Err(e.to_string());
@@ -179,64 +179,75 @@ impl ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, S
     }
 }
 
-impl Request for ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source> {
-    fn get_query_parameters(&self) -> String {
-        let asset_type_prefix = "destination_asset_type=";
-        let asset_code_prefix = "&destination_asset_code=";
-        let asset_issuer_prefix = "&destination_asset_issuer=";
+impl ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source> {
+    /// Validates the whole request, and returns every violation instead of only the first.
+    ///
+    /// The assets and the amount are not checked when they are set. `validate` checks them along
+    /// with the accounts, and collects every violation, so that they can be shown at once.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::models::prelude::{AssetData, AssetType};
+    /// use stellar_rs::paths::prelude::*;
+    ///
+    /// let request = ListStrictReceivePaymentPathsRequest::new()
+    ///     .set_destination_asset(AssetType::Native)?
+    ///     .set_destination_amount("ten")?
+    ///     .set_source(Source::SourceAssets(vec![
+    ///         AssetType::Native,
+    ///         AssetType::Alphanumeric4(AssetData {
+    ///             asset_code: "USDC".to_string(),
+    ///             asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA6".to_string(),
+    ///         }),
+    ///     ]))?;
+    ///
+    /// assert_eq!(
+    ///     request.validate().unwrap_err(),
+    ///     vec![
+    ///         "Amount must be a positive number: ten",
+    ///         "Invalid asset issuer: GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA6",
+    ///     ]
+    /// );
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut checks = vec![
+            is_valid_asset(&self.destination_asset.0),
+            is_positive_amount(&self.destination_amount.0),
+        ];
+        if let Some(destination_account) = &self.destination_account {
+            checks.push(is_public_key(destination_account));
+        }
+        match &self.source {
+            Source::SourceAssets(assets) if assets.is_empty() => {
+                checks.push(Err("SourceAssets cannot be empty".to_string()));
+            }
+            Source::SourceAssets(assets) => checks.extend(assets.iter().map(is_valid_asset)),
+            Source::SourceAccount(account) => checks.push(is_public_key(account)),
+        }
 
-        // Construct parameters for destination asset.
-        let destination_asset_parameters = match &self.destination_asset {
-            DestinationAsset(AssetType::Native) => format!("{}native", asset_type_prefix),
-            DestinationAsset(AssetType::Alphanumeric4(asset_data))
-            | DestinationAsset(AssetType::Alphanumeric12(asset_data)) => {
-                let asset_type = match self.destination_asset {
-                    DestinationAsset(AssetType::Alphanumeric4(_)) => "credit_alphanum4",
-                    DestinationAsset(AssetType::Alphanumeric12(_)) => "credit_alphanum12",
-                    _ => "", // should not be reached
-                };
+        collect_violations(checks)
+    }
+}
 
-                format!(
-                    "{}{}{}{}{}{}",
-                    asset_type_prefix,
-                    asset_type,
-                    asset_issuer_prefix,
-                    asset_data.asset_issuer,
-                    asset_code_prefix,
-                    asset_data.asset_code,
-                )
-            }
-        };
+impl Request for ListStrictReceivePaymentPathsRequest<DestinationAsset, DestinationAmount, Source> {
+    fn get_query_parameters(&self) -> String {
+        let destination_asset_parameters =
+            self.destination_asset.0.to_query_parameters("destination");
 
         let source = match &self.source {
             Source::SourceAssets(source_assets) => {
-                // Construct source asset parameters, if any.
-                // If no source asset parameters are set, return an empty vector which will later be ignored.
-                source_assets
-                    .iter()
-                    .enumerate()
-                    .map(|(i, asset)| {
-                        let prefix = if i == 0 { "source_assets=" } else { "%2C" };
-                        match asset {
-                            IssuedOrNative::Native => format!("{}native", prefix),
-                            IssuedOrNative::Issued(asset_data) => {
-                                format!(
-                                    "{}{}%3A{}",
-                                    prefix, asset_data.asset_code, asset_data.asset_issuer
-                                )
-                            }
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join("")
+                // If no source assets are set, the parameter is omitted.
+                asset_list_parameter("source_assets", source_assets)
             }
             Source::SourceAccount(account) => {
                 format!("source_account={}", account)
             }
         };
 
-        // Create query parameters vector.
-        let query_parameters = vec![
+        // Create query parameters array.
+        let query_parameters = [
             Some(destination_asset_parameters),
             Some(format!("destination_amount={}", self.destination_amount.0)),
             self.destination_account
@@ -1,32 +1,34 @@
-use crate::models::{is_public_key, Request};
+use crate::models::{
+    collect_violations, is_positive_amount, is_public_key, is_valid_asset, Request,
+};
 use crate::paths::*;
 use crate::BuildQueryParametersExt;
 
 /// Represents the absence of a source asset for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoSourceAsset;
 
 /// Represents the source asset for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SourceAsset(AssetType);
 
 /// Represents the absence of a source amount for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoSourceAmount;
 
 /// Represents the source amount for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SourceAmount(String);
 
 /// Represents the absence of a source amount for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoDestination;
 
 /// Represents the destination which can be either a vector of assets, or an account.
 /// Exactly one of these must be set, in order to make a valid request.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Destination {
-    DestinationAssets(Vec<IssuedOrNative>),
+    DestinationAssets(Vec<AssetType>),
     DestinationAccount(String),
 }
 
@@ -57,14 +59,14 @@ impl Default for Destination {
 /// let request = ListStrictSendPaymentPathsRequest::new()
 ///     .set_source_asset(AssetType::Native).unwrap() // Sets the source asset to native XLM.
 ///     .set_source_amount("100".to_string()).unwrap() // Sets the amount of the source asset.
-///     .set_destination(Destination::DestinationAccount("GAZD7JY7RCZN7KJ27SMUGKDPF7GQTYPXLDU7TFTJNSDB3MLO3M22DEIV".to_string())).unwrap(); // Sets an account as destination.
+///     .set_destination(Destination::DestinationAccount("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H".to_string())).unwrap(); // Sets an account as destination.
 /// ```
 ///
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct ListStrictSendPaymentPathsRequest<
     SAs = NoSourceAsset,
     SAm = NoSourceAmount,
-    D = Destination,
+    D = NoDestination,
 > {
     /// Represents the asset type being received by the source account.
     source_asset: SAs,
@@ -77,11 +79,7 @@ pub struct ListStrictSendPaymentPathsRequest<
 impl ListStrictSendPaymentPathsRequest<NoSourceAsset, NoSourceAmount, NoDestination> {
     /// Creates a new `ListStrictSendPaymentPathsRequest` with default parameters.
     pub fn new() -> Self {
-        ListStrictSendPaymentPathsRequest {
-            source_asset: NoSourceAsset,
-            source_amount: NoSourceAmount,
-            destination: NoDestination,
-        }
+        ListStrictSendPaymentPathsRequest::default()
     }
 }
 
@@ -89,17 +87,18 @@ impl<SAs, SAm, D> ListStrictSendPaymentPathsRequest<SAs, SAm, D> {
     /// Sets the source asset for the payment path request.
     ///
     /// # Arguments
-    /// * `source_asset_type` - The type of asset being received by the source account.
+    /// * `source_asset` - The asset being sent by the source account, such as an
+    ///   [`AssetType`] or an [`Asset`](crate::models::Asset).
     ///
     /// # Returns
     /// A new instance of `ListStrictSendPaymentPathsRequest` with the source asset set.
     ///
     pub fn set_source_asset(
         self,
-        source_asset_type: AssetType,
+        source_asset: impl Into<AssetType>,
     ) -> Result<ListStrictSendPaymentPathsRequest<SourceAsset, SAm, D>, String> {
         Ok(ListStrictSendPaymentPathsRequest {
-            source_asset: SourceAsset(source_asset_type),
+            source_asset: SourceAsset(source_asset.into()),
             source_amount: self.source_amount,
             destination: self.destination,
         })
@@ -157,64 +156,73 @@ impl<SAs, SAm, D> ListStrictSendPaymentPathsRequest<SAs, SAm, D> {
     }
 }
 
+impl ListStrictSendPaymentPathsRequest<SourceAsset, SourceAmount, Destination> {
+    /// Validates the whole request, and returns every violation instead of only the first.
+    ///
+    /// The assets and the amount are not checked when they are set. `validate` checks them along
+    /// with the destination account, and collects every violation, so that they can be shown at
+    /// once.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::models::prelude::{AssetData, AssetType};
+    /// use stellar_rs::paths::prelude::*;
+    ///
+    /// let request = ListStrictSendPaymentPathsRequest::new()
+    ///     .set_source_asset(AssetType::Alphanumeric12(AssetData {
+    ///         asset_code: "USDC".to_string(),
+    ///         asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5".to_string(),
+    ///     }))?
+    ///     .set_source_amount("0.00000001")?
+    ///     .set_destination(Destination::DestinationAccount(
+    ///         "GBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4".to_string(),
+    ///     ))?;
+    ///
+    /// assert_eq!(
+    ///     request.validate().unwrap_err(),
+    ///     vec![
+    ///         "Asset code must consist of 5 to 12 alphanumeric characters: USDC",
+    ///         "Amount must be a positive number: 0.00000001",
+    ///     ]
+    /// );
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut checks = vec![
+            is_valid_asset(&self.source_asset.0),
+            is_positive_amount(&self.source_amount.0),
+        ];
+        match &self.destination {
+            Destination::DestinationAssets(assets) if assets.is_empty() => {
+                checks.push(Err("DestinationAssets cannot be empty".to_string()));
+            }
+            Destination::DestinationAssets(assets) => {
+                checks.extend(assets.iter().map(is_valid_asset))
+            }
+            Destination::DestinationAccount(account) => checks.push(is_public_key(account)),
+        }
+
+        collect_violations(checks)
+    }
+}
+
 impl Request for ListStrictSendPaymentPathsRequest<SourceAsset, SourceAmount, Destination> {
     fn get_query_parameters(&self) -> String {
-        let asset_type_prefix = "source_asset_type=";
-        let asset_code_prefix = "&source_asset_code=";
-        let asset_issuer_prefix = "&source_asset_issuer=";
-
-        // Construct parameters for source asset.
-        let source_asset_parameters = match &self.source_asset {
-            SourceAsset(AssetType::Native) => format!("{}native", asset_type_prefix),
-            SourceAsset(AssetType::Alphanumeric4(asset_data))
-            | SourceAsset(AssetType::Alphanumeric12(asset_data)) => {
-                let asset_type = match self.source_asset {
-                    SourceAsset(AssetType::Alphanumeric4(_)) => "credit_alphanum4",
-                    SourceAsset(AssetType::Alphanumeric12(_)) => "credit_alphanum12",
-                    _ => "", // should not be reached
-                };
-
-                format!(
-                    "{}{}{}{}{}{}",
-                    asset_type_prefix,
-                    asset_type,
-                    asset_issuer_prefix,
-                    asset_data.asset_issuer,
-                    asset_code_prefix,
-                    asset_data.asset_code,
-                )
-            }
-        };
+        let source_asset_parameters = self.source_asset.0.to_query_parameters("source");
 
         let destination = match &self.destination {
             Destination::DestinationAssets(destination_assets) => {
-                // Construct destination asset parameters, if any.
-                // If no destination asset parameters are set, return an empty vector which will later be ignored.
-                destination_assets
-                    .iter()
-                    .enumerate()
-                    .map(|(i, asset)| {
-                        let prefix = if i == 0 { "destination_assets=" } else { "%2C" };
-                        match asset {
-                            IssuedOrNative::Native => format!("{}native", prefix),
-                            IssuedOrNative::Issued(asset_data) => {
-                                format!(
-                                    "{}{}%3A{}",
-                                    prefix, asset_data.asset_code, asset_data.asset_issuer
-                                )
-                            }
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join("")
+                // If no destination assets are set, the parameter is omitted.
+                asset_list_parameter("destination_assets", destination_assets)
             }
             Destination::DestinationAccount(account) => {
                 format!("destination_account={}", account)
             }
         };
 
-        // Create query parameters vector.
-        let query_parameters = vec![
+        // Create query parameters array.
+        let query_parameters = [
             Some(format!("source_amount={}", self.source_amount.0)),
             Some(destination),
             Some(source_asset_parameters),
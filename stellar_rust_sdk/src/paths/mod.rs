@@ -29,6 +29,15 @@ pub mod list_strict_receive_payment_paths_request;
 ///
 pub mod list_strict_send_payment_paths_request;
 
+/// Provides the `PathPaymentStrictSendIntent` and `PathPaymentStrictReceiveIntent`.
+///
+/// # Usage
+/// This module provides the parameters of the path payment operations, which are created from a
+/// [`Path`](response::Path) returned by one of the payment path endpoints. They can be adjusted
+/// for slippage, and converted into the body of an operation for a transaction.
+///
+pub mod path_payment_intent;
+
 /// Provides the response structures.
 ///
 /// This module defines structures representing the responses from the payment path API.
@@ -52,36 +61,31 @@ pub(crate) static PATHS_STRICT_RECEIVE_PATH: &str = "strict-receive";
 pub(crate) static PATHS_STRICT_SEND_PATH: &str = "strict-send";
 
 /// Represents the absence of a destination asset for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoDestinationAsset;
 
 /// Represents a source asset for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct DestinationAsset(AssetType);
 
 /// Represents the absence of a destination amount for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoDestinationAmount;
 
 /// Represents the destination amount for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct DestinationAmount(String);
 
 /// Represents the absence of a source account for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NoSourceAccount;
 
 /// Represents the source account for a payment path request.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SourceAccount(String);
 
-/// Represents structure of an asset used in the vector of optional assets.
-#[derive(Default, Clone, Debug)]
-pub enum IssuedOrNative {
-    #[default]
-    Native,
-    Issued(AssetData),
-}
+#[allow(deprecated)]
+pub use crate::compat::IssuedOrNative;
 
 /// The `prelude` module of the `paths` module.
 ///
@@ -102,12 +106,14 @@ pub enum IssuedOrNative {
 /// * From `find_payment_paths_request`: All items (e.g. `FindPaymentPathsRequest`).
 /// * From `list_strict_receive_payment_paths_request`: All items (e.g. `ListStrictReceivePaymentPathsRequest`).
 /// * From `list_strict_send_payment_paths_request`: All items (e.g. `ListStrictSendPaymentPathsRequest`).
+/// * From `path_payment_intent`: All items (e.g. `PathPaymentStrictSendIntent`).
 /// * From `response`: All items (e.g. `PaymentPathResponse`, etc.).
 ///
 pub mod prelude {
     pub use super::find_payment_paths_request::*;
     pub use super::list_strict_receive_payment_paths_request::*;
     pub use super::list_strict_send_payment_paths_request::*;
+    pub use super::path_payment_intent::*;
     pub use super::response::*;
     pub use super::{
         DestinationAmount, DestinationAsset, NoDestinationAmount, NoDestinationAsset,
@@ -118,7 +124,7 @@ pub mod prelude {
 #[cfg(test)]
 mod tests {
     use super::prelude::*;
-    use super::{AssetType, IssuedOrNative};
+    use super::AssetType;
     use crate::models::prelude::*;
     use crate::{horizon_client::HorizonClient, models::*};
 
@@ -226,9 +232,9 @@ mod tests {
             .set_destination_amount("42".to_string())
             .unwrap()
             .set_source(Source::SourceAssets(vec![
-                IssuedOrNative::Native,
-                IssuedOrNative::Native,
-                IssuedOrNative::Issued(AssetData {
+                AssetType::Native,
+                AssetType::Native,
+                AssetType::Alphanumeric4(AssetData {
                     asset_code: "USDC".to_string(),
                     asset_issuer: "GBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4"
                         .to_string(),
@@ -237,7 +243,7 @@ mod tests {
             .unwrap();
 
         let expected_parameters: &str =
-            "?destination_asset_type=credit_alphanum4&destination_asset_issuer=GBJJ5OCBXNZWHSJJ4YQ6ECK24MBJSZMLEMINHKGGEWUA5RU2EDMPN6MS&destination_asset_code=USDC&destination_amount=42&source_assets=native%2Cnative%2CUSDC%3AGBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4";
+            "?destination_asset_type=credit_alphanum4&destination_asset_code=USDC&destination_asset_issuer=GBJJ5OCBXNZWHSJJ4YQ6ECK24MBJSZMLEMINHKGGEWUA5RU2EDMPN6MS&destination_amount=42&source_assets=native%2Cnative%2CUSDC%3AGBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4";
 
         assert_eq!(request.get_query_parameters(), expected_parameters);
 
@@ -327,7 +333,7 @@ mod tests {
             .unwrap()
             .set_destination_amount("42".to_string())
             .unwrap()
-            .set_source(Source::SourceAssets(vec![IssuedOrNative::Native]))
+            .set_source(Source::SourceAssets(vec![AssetType::Native]))
             .unwrap()
             .set_destination_account("invalid_account_id");
         assert_eq!(
@@ -351,9 +357,9 @@ mod tests {
             .set_source_amount("42".to_string())
             .unwrap()
             .set_destination(Destination::DestinationAssets(vec![
-                IssuedOrNative::Native,
-                IssuedOrNative::Native,
-                IssuedOrNative::Issued(AssetData {
+                AssetType::Native,
+                AssetType::Native,
+                AssetType::Alphanumeric4(AssetData {
                     asset_code: "USDC".to_string(),
                     asset_issuer: "GBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4"
                         .to_string(),
@@ -362,7 +368,7 @@ mod tests {
             .unwrap();
 
         let expected_parameters: &str =
-            "?source_amount=42&destination_assets=native%2Cnative%2CUSDC%3AGBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4&source_asset_type=credit_alphanum4&source_asset_issuer=GBJJ5OCBXNZWHSJJ4YQ6ECK24MBJSZMLEMINHKGGEWUA5RU2EDMPN6MS&source_asset_code=USDC";
+            "?source_amount=42&destination_assets=native%2Cnative%2CUSDC%3AGBAKINTNEGR7PO6Z6XW2S5ITT5VARNW6DZ5K4OYSLFNEA2CSMUM2UEF4&source_asset_type=credit_alphanum4&source_asset_code=USDC&source_asset_issuer=GBJJ5OCBXNZWHSJJ4YQ6ECK24MBJSZMLEMINHKGGEWUA5RU2EDMPN6MS";
 
         assert_eq!(request.get_query_parameters(), expected_parameters);
 
@@ -463,4 +469,43 @@ mod tests {
             "Public key must be 56 characters long"
         );
     }
+
+    #[test]
+    fn test_validate() {
+        let request = ListStrictReceivePaymentPathsRequest::new()
+            .set_destination_asset(AssetType::Native)
+            .unwrap()
+            .set_destination_amount(DESTINATION_AMOUNT)
+            .unwrap()
+            .set_source(Source::SourceAssets(vec![AssetType::Alphanumeric4(
+                AssetData {
+                    asset_code: "USDC".to_string(),
+                    asset_issuer: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"
+                        .to_string(),
+                },
+            )]))
+            .unwrap();
+        assert_eq!(request.validate(), Ok(()));
+
+        let request = ListStrictSendPaymentPathsRequest::new()
+            .set_source_asset(AssetType::Native)
+            .unwrap()
+            .set_source_amount("0")
+            .unwrap()
+            .set_destination(Destination::DestinationAssets(vec![
+                AssetType::Native,
+                AssetType::Alphanumeric4(AssetData {
+                    asset_code: "".to_string(),
+                    asset_issuer: "".to_string(),
+                }),
+            ]))
+            .unwrap();
+        assert_eq!(
+            request.validate().unwrap_err(),
+            vec![
+                "Amount must be a positive number: 0",
+                "Asset code must consist of 1 to 4 alphanumeric characters: ",
+            ]
+        );
+    }
 }
@@ -0,0 +1,278 @@
+use super::response::Path;
+use crate::models::prelude::AssetType;
+use crate::models::serde_adapters::{format_stroops, parse_stroops};
+use derive_getters::Getters;
+use std::str::FromStr;
+use stellar_xdr::curr as xdr;
+
+/// The number of basis points in a whole, used to express slippage.
+const BASIS_POINTS: i128 = 10_000;
+
+/// Represents the parameters of a `path_payment_strict_send` operation.
+///
+/// A strict send payment sends an exact amount of the send asset, and fails if the destination
+/// would receive less than the minimum amount of the destination asset. An intent is usually
+/// created from a [`Path`] returned by Horizon, using [`Path::to_strict_send_intent`], after
+/// which [`with_slippage`](Self::with_slippage) lowers the minimum amount to allow for price
+/// changes before the transaction is submitted.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::models::Response;
+/// # use stellar_rs::paths::prelude::*;
+/// # let json = r#"{"_embedded": {"records": [{
+/// #     "source_asset_type": "native", "source_amount": "100.0000000",
+/// #     "destination_asset_type": "native", "destination_amount": "100.0000000", "path": []
+/// # }]}}"#;
+/// # let response = PathsResponse::from_json(json.to_string()).unwrap();
+/// let path = &response.embedded().records()[0];
+/// let intent = path.to_strict_send_intent().unwrap().with_slippage(50).unwrap();
+/// assert_eq!(intent.destination_min(), "99.5000000");
+///
+/// let operation = intent
+///     .to_operation_body("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+///     .unwrap();
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct PathPaymentStrictSendIntent {
+    /// The asset that is sent.
+    send_asset: AssetType,
+    /// The exact amount of the send asset that is sent.
+    send_amount: String,
+    /// The asset that is received by the destination.
+    destination_asset: AssetType,
+    /// The minimum amount of the destination asset that the destination has to receive.
+    destination_min: String,
+    /// The intermediary assets through which the payment is converted.
+    path: Vec<AssetType>,
+}
+
+impl PathPaymentStrictSendIntent {
+    /// Lowers the minimum amount that the destination has to receive by the given slippage.
+    ///
+    /// # Arguments
+    /// * `slippage_bps` - The slippage in basis points, where `100` is 1%. At most `10000`.
+    ///
+    pub fn with_slippage(self, slippage_bps: u32) -> Result<Self, String> {
+        let destination_min = apply_slippage(&self.destination_min, slippage_bps, false)?;
+        Ok(Self {
+            destination_min,
+            ..self
+        })
+    }
+
+    /// Builds the body of the `path_payment_strict_send` operation.
+    ///
+    /// # Arguments
+    /// * `destination` - The account, or muxed account, that receives the payment.
+    ///
+    pub fn to_operation_body(&self, destination: &str) -> Result<xdr::OperationBody, String> {
+        Ok(xdr::OperationBody::PathPaymentStrictSend(
+            xdr::PathPaymentStrictSendOp {
+                send_asset: xdr::Asset::try_from(&self.send_asset)?,
+                send_amount: parse_stroops(&self.send_amount)?,
+                destination: parse_destination(destination)?,
+                dest_asset: xdr::Asset::try_from(&self.destination_asset)?,
+                dest_min: parse_stroops(&self.destination_min)?,
+                path: xdr_path(&self.path)?,
+            },
+        ))
+    }
+}
+
+impl TryFrom<&Path> for PathPaymentStrictSendIntent {
+    type Error = String;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        Ok(PathPaymentStrictSendIntent {
+            send_asset: path.source_asset()?,
+            send_amount: path.source_amount().clone(),
+            destination_asset: path.destination_asset()?,
+            destination_min: path.destination_amount().clone(),
+            path: path.path().clone(),
+        })
+    }
+}
+
+/// Represents the parameters of a `path_payment_strict_receive` operation.
+///
+/// A strict receive payment delivers an exact amount of the destination asset, and fails if more
+/// than the maximum amount of the send asset would have to be sent. An intent is usually created
+/// from a [`Path`] returned by Horizon, using [`Path::to_strict_receive_intent`], after which
+/// [`with_slippage`](Self::with_slippage) raises the maximum amount to allow for price changes
+/// before the transaction is submitted.
+///
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct PathPaymentStrictReceiveIntent {
+    /// The asset that is sent.
+    send_asset: AssetType,
+    /// The maximum amount of the send asset that may be sent.
+    send_max: String,
+    /// The asset that is received by the destination.
+    destination_asset: AssetType,
+    /// The exact amount of the destination asset that the destination receives.
+    destination_amount: String,
+    /// The intermediary assets through which the payment is converted.
+    path: Vec<AssetType>,
+}
+
+impl PathPaymentStrictReceiveIntent {
+    /// Raises the maximum amount that may be sent by the given slippage.
+    ///
+    /// # Arguments
+    /// * `slippage_bps` - The slippage in basis points, where `100` is 1%. At most `10000`.
+    ///
+    pub fn with_slippage(self, slippage_bps: u32) -> Result<Self, String> {
+        let send_max = apply_slippage(&self.send_max, slippage_bps, true)?;
+        Ok(Self { send_max, ..self })
+    }
+
+    /// Builds the body of the `path_payment_strict_receive` operation.
+    ///
+    /// # Arguments
+    /// * `destination` - The account, or muxed account, that receives the payment.
+    ///
+    pub fn to_operation_body(&self, destination: &str) -> Result<xdr::OperationBody, String> {
+        Ok(xdr::OperationBody::PathPaymentStrictReceive(
+            xdr::PathPaymentStrictReceiveOp {
+                send_asset: xdr::Asset::try_from(&self.send_asset)?,
+                send_max: parse_stroops(&self.send_max)?,
+                destination: parse_destination(destination)?,
+                dest_asset: xdr::Asset::try_from(&self.destination_asset)?,
+                dest_amount: parse_stroops(&self.destination_amount)?,
+                path: xdr_path(&self.path)?,
+            },
+        ))
+    }
+}
+
+impl TryFrom<&Path> for PathPaymentStrictReceiveIntent {
+    type Error = String;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        Ok(PathPaymentStrictReceiveIntent {
+            send_asset: path.source_asset()?,
+            send_max: path.source_amount().clone(),
+            destination_asset: path.destination_asset()?,
+            destination_amount: path.destination_amount().clone(),
+            path: path.path().clone(),
+        })
+    }
+}
+
+/// Lowers or raises an amount by the given slippage, rounding away from the original amount so
+/// that at least the requested slippage is allowed.
+fn apply_slippage(amount: &str, slippage_bps: u32, raise: bool) -> Result<String, String> {
+    if slippage_bps as i128 > BASIS_POINTS {
+        return Err("Slippage must be at most 10000 basis points".to_string());
+    }
+
+    let stroops = parse_stroops(amount)? as i128;
+    let adjusted = if raise {
+        (stroops * (BASIS_POINTS + slippage_bps as i128) + BASIS_POINTS - 1) / BASIS_POINTS
+    } else {
+        stroops * (BASIS_POINTS - slippage_bps as i128) / BASIS_POINTS
+    };
+
+    i64::try_from(adjusted)
+        .map(format_stroops)
+        .map_err(|_| format!("Invalid amount: {}", amount))
+}
+
+/// Parses the destination of a payment, which is either a public key or a muxed account.
+fn parse_destination(destination: &str) -> Result<xdr::MuxedAccount, String> {
+    xdr::MuxedAccount::from_str(destination)
+        .map_err(|_| format!("Invalid destination: {}", destination))
+}
+
+/// Converts the intermediary assets of a path into XDR. A path consists of at most 5 assets.
+fn xdr_path(path: &[AssetType]) -> Result<xdr::VecM<xdr::Asset, 5>, String> {
+    path.iter()
+        .map(xdr::Asset::try_from)
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| "A path can have at most 5 assets.".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::prelude::AssetData;
+    use crate::models::Response;
+    use crate::paths::prelude::PathsResponse;
+
+    const DESTINATION: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    fn path() -> Path {
+        let json = include_str!("../../tests/fixtures/paths/strict_send_paths.json");
+        let response = PathsResponse::from_json(json.to_string()).unwrap();
+        response.embedded().records()[1].clone()
+    }
+
+    #[test]
+    fn test_strict_send_intent() {
+        let intent = path().to_strict_send_intent().unwrap();
+        assert_eq!(intent.send_asset(), &AssetType::Native);
+        assert_eq!(intent.send_amount(), "100.0000000");
+        assert_eq!(intent.destination_min(), "12.2981431");
+        assert_eq!(
+            intent.path(),
+            &vec![AssetType::Alphanumeric12(AssetData {
+                asset_code: "MBAUDD".to_string(),
+                asset_issuer: "GD2YNRNSJ3EOFJAYGLKGKSIOLX2VU3UFDW3YFNOYMAHB26AEHSZBJU4U"
+                    .to_string(),
+            })]
+        );
+
+        let intent = intent.with_slippage(100).unwrap();
+        assert_eq!(intent.destination_min(), "12.1751616");
+
+        let xdr::OperationBody::PathPaymentStrictSend(operation) =
+            intent.to_operation_body(DESTINATION).unwrap()
+        else {
+            panic!("expected a path_payment_strict_send operation");
+        };
+        assert_eq!(operation.send_asset, xdr::Asset::Native);
+        assert_eq!(operation.send_amount, 1_000_000_000);
+        assert_eq!(operation.dest_min, 121_751_616);
+        assert_eq!(operation.destination.to_string(), DESTINATION);
+        assert_eq!(operation.path.len(), 1);
+    }
+
+    #[test]
+    fn test_strict_receive_intent() {
+        let intent = path()
+            .to_strict_receive_intent()
+            .unwrap()
+            .with_slippage(1)
+            .unwrap();
+        assert_eq!(intent.send_max(), "100.0100000");
+        assert_eq!(intent.destination_amount(), "12.2981431");
+
+        let xdr::OperationBody::PathPaymentStrictReceive(operation) =
+            intent.to_operation_body(DESTINATION).unwrap()
+        else {
+            panic!("expected a path_payment_strict_receive operation");
+        };
+        assert_eq!(operation.send_max, 1_000_100_000);
+        assert_eq!(operation.dest_amount, 122_981_431);
+    }
+
+    #[test]
+    fn test_invalid_intent() {
+        let intent = path().to_strict_send_intent().unwrap();
+        assert_eq!(
+            intent.clone().with_slippage(10_001).unwrap_err(),
+            "Slippage must be at most 10000 basis points"
+        );
+        assert_eq!(
+            intent.to_operation_body("GDQJ").unwrap_err(),
+            "Invalid destination: GDQJ"
+        );
+        assert_eq!(
+            apply_slippage("1.0000001", 5000, true).unwrap(),
+            "1.5000002"
+        );
+    }
+}
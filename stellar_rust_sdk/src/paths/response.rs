@@ -1,8 +1,12 @@
-use crate::models::prelude::Embedded;
+use super::path_payment_intent::{PathPaymentStrictReceiveIntent, PathPaymentStrictSendIntent};
+use crate::models::prelude::{AssetType, Embedded};
 use crate::models::Response;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
+#[allow(deprecated)]
+pub use crate::compat::PathAsset as Asset;
+
 /// Represents the response for a payment paths query.
 ///
 /// This struct defines the overall structure of the response for a query
@@ -10,6 +14,7 @@ use serde::{Deserialize, Serialize};
 /// consist of the details of each payment path.
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct PathsResponse {
     #[serde(rename = "_embedded")]
     embedded: Embedded<Path>,
@@ -22,6 +27,7 @@ pub struct PathsResponse {
 /// that form the path.
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct Path {
     /// The type of the source asset.
     source_asset_type: String,
@@ -39,24 +45,44 @@ pub struct Path {
     destination_asset_issuer: Option<String>,
     /// The amount of the destination asset.
     destination_amount: String,
-    /// A vector of assets forming the path.
-    path: Vec<Asset>,
+    /// The intermediary assets forming the path, in the order in which they are traded.
+    path: Vec<AssetType>,
 }
 
-/// Represents a single asset used in the payment path.
-///
-/// This struct details the information about an asset including its type,
-/// code, and issuer.
-///
-#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
-pub struct Asset {
-    asset_type: String,
-    asset_code: Option<String>,
-    asset_issuer: Option<String>,
+impl Path {
+    /// Returns the source asset of the path.
+    pub fn source_asset(&self) -> Result<AssetType, String> {
+        AssetType::from_horizon(
+            &self.source_asset_type,
+            self.source_asset_code.as_deref(),
+            self.source_asset_issuer.as_deref(),
+        )
+    }
+
+    /// Returns the destination asset of the path.
+    pub fn destination_asset(&self) -> Result<AssetType, String> {
+        AssetType::from_horizon(
+            &self.destination_asset_type,
+            self.destination_asset_code.as_deref(),
+            self.destination_asset_issuer.as_deref(),
+        )
+    }
+
+    /// Converts the path into the parameters of a `path_payment_strict_send` operation, which
+    /// sends exactly the source amount and receives at least the destination amount.
+    pub fn to_strict_send_intent(&self) -> Result<PathPaymentStrictSendIntent, String> {
+        PathPaymentStrictSendIntent::try_from(self)
+    }
+
+    /// Converts the path into the parameters of a `path_payment_strict_receive` operation, which
+    /// receives exactly the destination amount and sends at most the source amount.
+    pub fn to_strict_receive_intent(&self) -> Result<PathPaymentStrictReceiveIntent, String> {
+        PathPaymentStrictReceiveIntent::try_from(self)
+    }
 }
 
 impl Response for PathsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
@@ -29,6 +29,15 @@ pub mod payments_for_ledger_request;
 ///
 pub mod payments_for_transaction_request;
 
+/// Provides the `PaymentHistoryEntry` struct, which represents a single entry of the payment history of an account
+///
+/// # Usage
+/// This module provides the `PaymentHistoryEntry` struct, along with the `PaymentKind` and `PaymentDirection` enums that classify it.
+/// The entries are returned by the `HorizonClient::get_payment_history` function, which combines payments, path payments,
+/// account creations and merges, and claimable balances into a single chronological feed.
+///
+pub mod payment_history;
+
 /// Provides the `PaymentsResponse` struct to parse the response from the Horizon server when querying for payments
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -47,6 +56,7 @@ static PAYMENTS_PATH: &str = "payments";
 
 pub mod prelude {
     pub use super::all_payments_request::*;
+    pub use super::payment_history::*;
     pub use super::payments_for_account_request::*;
     pub use super::payments_for_ledger_request::*;
     pub use super::payments_for_transaction_request::*;
@@ -56,7 +66,8 @@ pub mod prelude {
 #[cfg(test)]
 pub mod test {
     use super::prelude::*;
-    use crate::{horizon_client::HorizonClient, models::IncludeFailed};
+    use crate::error::Error;
+    use crate::horizon_client::HorizonClient;
 
     static ID: &str = "2314987376641";
     static PAGING_TOKEN: &str = "2314987376641";
@@ -70,7 +81,7 @@ pub mod test {
     static STARTING_BALANCE: &str = "10000000000.0000000";
     static FUNDER: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
     static ACCOUNT: &str = "GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR";
-    static LEDGER_SEQUENCE: &str = "48483";
+    static LEDGER_SEQUENCE: u32 = 48483;
 
     #[tokio::test]
     async fn test_get_all_payments() {
@@ -81,7 +92,7 @@ pub mod test {
         let all_payments_request: AllPaymentsRequest =
             AllPaymentsRequest::new().set_limit(1).unwrap();
 
-        let response: Result<PaymentsResponse, String> =
+        let response: Result<PaymentsResponse, Error> =
             horizon_client.get_all_payments(&all_payments_request).await;
 
         assert!(response.is_ok());
@@ -112,7 +123,7 @@ pub mod test {
                 "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
             );
 
-        let response: Result<PaymentsResponse, String> = horizon_client
+        let response: Result<PaymentsResponse, Error> = horizon_client
             .get_payments_for_account(&payments_for_account_request)
             .await;
 
@@ -157,11 +168,12 @@ pub mod test {
 
         let payments_for_ledger_request: PaymentsForLedgerRequest = PaymentsForLedgerRequest::new()
             .set_ledger_sequence(LEDGER_SEQUENCE)
-            .set_include_failed(IncludeFailed::False)
+            .unwrap()
+            .set_include_failed(false)
             .set_limit(1)
             .unwrap();
 
-        let response: Result<PaymentsResponse, String> = horizon_client
+        let response: Result<PaymentsResponse, Error> = horizon_client
             .get_payments_for_ledger(&payments_for_ledger_request)
             .await;
 
@@ -190,11 +202,14 @@ pub mod test {
 
         let payments_for_transaction_request: PaymentsForTransactionRequest =
             PaymentsForTransactionRequest::new()
-                .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020")
+                .set_transaction_hash(
+                    "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020",
+                )
+                .unwrap()
                 .set_limit(1)
                 .unwrap();
 
-        let response: Result<PaymentsResponse, String> = horizon_client
+        let response: Result<PaymentsResponse, Error> = horizon_client
             .get_payments_for_transaction(&payments_for_transaction_request)
             .await;
 
@@ -0,0 +1,326 @@
+use crate::datetime::{self, DateTime};
+use crate::effects::prelude::{Effect, EffectDetail};
+use crate::models::prelude::AssetType;
+use crate::operations::operation_details::OperationDetails;
+use crate::operations::operation_id::OperationId;
+use crate::operations::response::Operation;
+use crate::transactions::transaction_hash::TransactionHash;
+use derive_getters::Getters;
+use std::collections::HashMap;
+
+/// The kind of operation that moved funds into or out of an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PaymentKind {
+    /// A `payment` operation.
+    Payment,
+    /// A `path_payment_strict_receive` or `path_payment_strict_send` operation.
+    PathPayment,
+    /// A `create_account` operation, which funds the new account with its starting balance.
+    CreateAccount,
+    /// An `account_merge` operation, which transfers the lumens of the merged account.
+    AccountMerge,
+    /// A `create_claimable_balance` operation, which locks funds of the creator.
+    ClaimableBalanceCreated,
+    /// A `claim_claimable_balance` operation, which releases a claimable balance to the claimant.
+    ClaimableBalanceClaimed,
+}
+
+impl PaymentKind {
+    /// Returns the kind of payment of an operation type, such as `payment`, or `None` if
+    /// operations of the type are not payments.
+    pub fn from_operation_type(operation_type: &str) -> Option<Self> {
+        match operation_type {
+            "payment" => Some(PaymentKind::Payment),
+            "path_payment_strict_receive" | "path_payment_strict_send" => {
+                Some(PaymentKind::PathPayment)
+            }
+            "create_account" => Some(PaymentKind::CreateAccount),
+            "account_merge" => Some(PaymentKind::AccountMerge),
+            "create_claimable_balance" => Some(PaymentKind::ClaimableBalanceCreated),
+            "claim_claimable_balance" => Some(PaymentKind::ClaimableBalanceClaimed),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a payment moved funds into or out of an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaymentDirection {
+    /// The account received the funds.
+    Incoming,
+    /// The account sent the funds.
+    Outgoing,
+}
+
+/// Represents a single entry of the payment history of an account, as returned by
+/// [`HorizonClient::get_payment_history`](crate::horizon_client::HorizonClient::get_payment_history).
+///
+/// Every entry describes one balance of the account that was credited or debited by a payment
+/// operation. The amount is the amount that was effectively credited or debited, which for path
+/// payments may differ from the amounts in the operation itself: a strict receive path payment
+/// spends at most `source_max`, and a strict send path payment delivers at least
+/// `destination_min`. A path payment from an account to itself results in two entries, one for
+/// each asset.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[non_exhaustive]
+pub struct PaymentHistoryEntry {
+    /// The ID of the operation that moved the funds.
+    operation_id: OperationId,
+    /// The hash of the transaction that contains the operation.
+    transaction_hash: TransactionHash,
+    /// The time at which the transaction was included in a ledger.
+    created_at: DateTime,
+    /// The kind of operation that moved the funds.
+    kind: PaymentKind,
+    /// Whether the funds were moved into or out of the account.
+    direction: PaymentDirection,
+    /// The other account of the payment, if there is a single one. This is the sender of incoming
+    /// payments and the recipient of outgoing payments. Claimable balances have no counterparty,
+    /// since they can have several claimants.
+    counterparty: Option<String>,
+    /// The asset that was credited or debited.
+    asset: AssetType,
+    /// The amount that was effectively credited or debited, such as `100.0000000`.
+    amount: String,
+}
+
+/// Combines the effects and operations of an account into its payment history.
+///
+/// Every `account_credited`, `account_debited` and `account_created` effect of the account that
+/// was produced by a payment operation results in an entry, in the order of the effects. Effects
+/// of other operations, such as trades and liquidity pool deposits, are skipped, as are effects
+/// whose operation is not in `operations`.
+///
+pub(crate) fn build_payment_history(
+    account_id: &str,
+    effects: &[Effect],
+    operations: &HashMap<OperationId, Operation>,
+) -> Result<Vec<PaymentHistoryEntry>, String> {
+    let mut entries = Vec::new();
+    for effect in effects.iter().filter(|effect| effect.account == account_id) {
+        let (direction, balance_change, amount) = match &effect.detail {
+            EffectDetail::AccountCreated(detail) => {
+                (PaymentDirection::Incoming, None, &detail.starting_balance)
+            }
+            EffectDetail::AccountCredited(detail) => {
+                (PaymentDirection::Incoming, Some(detail), &detail.amount)
+            }
+            EffectDetail::AccountDebited(detail) => {
+                (PaymentDirection::Outgoing, Some(detail), &detail.amount)
+            }
+            _ => continue,
+        };
+        let operation_id = effect.operation_id()?;
+        let Some(operation) = operations.get(&operation_id) else {
+            continue;
+        };
+        let Some(kind) = PaymentKind::from_operation_type(operation.type_field()) else {
+            continue;
+        };
+
+        let asset = match balance_change {
+            Some(balance_change) => balance_change.asset()?,
+            None => AssetType::Native,
+        };
+
+        entries.push(PaymentHistoryEntry {
+            operation_id,
+            transaction_hash: operation.transaction_hash.clone(),
+            created_at: datetime::parse_rfc3339(&effect.created_at)?,
+            kind,
+            direction,
+            counterparty: counterparty(operation, direction),
+            asset,
+            amount: amount.clone(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Returns the other account of a payment operation, from the point of view of the account that
+/// moved funds in the given direction. Claimable balances have no counterparty.
+fn counterparty(operation: &Operation, direction: PaymentDirection) -> Option<String> {
+    let (sender, recipient) = match operation.details() {
+        OperationDetails::Payment(payment) => (&payment.from, &payment.to),
+        OperationDetails::PathPaymentStrictReceive(path_payment)
+        | OperationDetails::PathPaymentStrictSend(path_payment) => {
+            (&path_payment.from, &path_payment.to)
+        }
+        OperationDetails::CreateAccount(create_account) => {
+            (&create_account.funder, &create_account.account)
+        }
+        OperationDetails::AccountMerge(account_merge) => {
+            (&account_merge.account, &account_merge.into)
+        }
+        _ => return None,
+    };
+    match direction {
+        PaymentDirection::Incoming => Some(sender.clone()),
+        PaymentDirection::Outgoing => Some(recipient.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::prelude::AssetData;
+
+    static ALICE: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+    static BOB: &str = "GAIH3ULLFQ4DGSECF2AR555KZ4KNDGEKN4AFI4SU2M7B43MGK3QJZNSR";
+    static USDC_ISSUER: &str = "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5";
+
+    fn effect(paging_token: &str, effect_type: &str, fields: &str) -> Effect {
+        let json = format!(
+            r#"{{
+                "_links": {{
+                    "operation": {{"href": "https://horizon-testnet.stellar.org/operations/1"}},
+                    "succeeds": {{"href": "https://horizon-testnet.stellar.org/effects?order=desc"}},
+                    "precedes": {{"href": "https://horizon-testnet.stellar.org/effects?order=asc"}}
+                }},
+                "id": "{paging_token}",
+                "paging_token": "{paging_token}",
+                "account": "{ALICE}",
+                "type": "{effect_type}",
+                "type_i": 0,
+                "created_at": "2024-06-11T21:36:12Z"
+                {fields}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn operation(id: &str, operation_type: &str, fields: &str) -> Operation {
+        let json = format!(
+            r#"{{
+                "_links": {{
+                    "self": {{"href": "https://horizon-testnet.stellar.org/operations/{id}"}},
+                    "transaction": {{"href": "https://horizon-testnet.stellar.org/transactions/b9d0"}},
+                    "effects": {{"href": "https://horizon-testnet.stellar.org/operations/{id}/effects"}},
+                    "succeeds": {{"href": "https://horizon-testnet.stellar.org/effects?order=desc"}},
+                    "precedes": {{"href": "https://horizon-testnet.stellar.org/effects?order=asc"}}
+                }},
+                "id": "{id}",
+                "paging_token": "{id}",
+                "transaction_successful": true,
+                "source_account": "{ALICE}",
+                "type": "{operation_type}",
+                "type_i": 0,
+                "created_at": "2024-06-11T21:36:12Z",
+                "transaction_hash": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+                {fields}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_build_payment_history() {
+        let operations: HashMap<OperationId, Operation> = [
+            operation(
+                "1001",
+                "create_account",
+                &format!(
+                    r#", "funder": "{BOB}", "account": "{ALICE}", "starting_balance": "10.0000000""#
+                ),
+            ),
+            operation(
+                "2001",
+                "path_payment_strict_send",
+                &format!(
+                    r#", "from": "{ALICE}", "to": "{BOB}", "asset_type": "native", "amount": "5.0000000", "path": [], "source_amount": "5.0000000", "destination_min": "4.9000000", "source_asset_type": "native""#
+                ),
+            ),
+            operation(
+                "3001",
+                "manage_sell_offer",
+                r#", "offer_id": "0", "amount": "1.0000000", "price": "1.0000000", "price_r": {"n": 1, "d": 1}, "buying_asset_type": "native", "selling_asset_type": "native""#,
+            ),
+            operation(
+                "4001",
+                "account_merge",
+                &format!(r#", "account": "{ALICE}", "into": "{BOB}""#),
+            ),
+        ]
+        .into_iter()
+        .map(|operation| (operation.id, operation))
+        .collect();
+        let effects = [
+            effect(
+                "1001-1",
+                "account_created",
+                r#", "starting_balance": "10.0000000""#,
+            ),
+            effect(
+                "2001-1",
+                "account_debited",
+                r#", "asset_type": "native", "amount": "5.0000000""#,
+            ),
+            effect(
+                "3001-1",
+                "account_credited",
+                r#", "asset_type": "native", "amount": "1.0000000""#,
+            ),
+            effect(
+                "4001-1",
+                "account_debited",
+                &format!(
+                    r#", "asset_type": "credit_alphanum4", "asset_code": "USDC", "asset_issuer": "{USDC_ISSUER}", "amount": "2.0000000""#
+                ),
+            ),
+            effect("4001-2", "account_removed", ""),
+        ];
+
+        let entries = build_payment_history(ALICE, &effects, &operations).unwrap();
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].kind(), &PaymentKind::CreateAccount);
+        assert_eq!(entries[0].direction(), &PaymentDirection::Incoming);
+        assert_eq!(entries[0].counterparty().as_deref(), Some(BOB));
+        assert_eq!(entries[0].asset(), &AssetType::Native);
+        assert_eq!(entries[0].amount(), "10.0000000");
+
+        assert_eq!(entries[1].kind(), &PaymentKind::PathPayment);
+        assert_eq!(entries[1].direction(), &PaymentDirection::Outgoing);
+        assert_eq!(entries[1].counterparty().as_deref(), Some(BOB));
+        assert_eq!(entries[1].amount(), "5.0000000");
+
+        assert_eq!(entries[2].kind(), &PaymentKind::AccountMerge);
+        assert_eq!(entries[2].operation_id().value(), 4001);
+        assert_eq!(
+            entries[2].asset(),
+            &AssetType::Alphanumeric4(AssetData {
+                asset_code: "USDC".to_string(),
+                asset_issuer: USDC_ISSUER.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_payment_history_invalid_asset() {
+        let operations: HashMap<OperationId, Operation> = [(
+            OperationId::new(1001).unwrap(),
+            operation(
+                "1001",
+                "payment",
+                &format!(
+                    r#", "from": "{BOB}", "to": "{ALICE}", "asset_type": "native", "amount": "1.0000000""#
+                ),
+            ),
+        )]
+        .into_iter()
+        .collect();
+        let effects = [effect(
+            "1001-1",
+            "account_credited",
+            r#", "asset_type": "credit_alphanum4", "asset_code": "USDC", "amount": "1.0000000""#,
+        )];
+
+        assert_eq!(
+            build_payment_history(ALICE, &effects, &operations).unwrap_err(),
+            "Issued asset is missing its code or issuer"
+        );
+    }
+}
@@ -1,13 +1,17 @@
-use crate::models::{Order, Request};
+use crate::models::pagination::PageParams;
 use crate::payments::PAYMENTS_PATH;
-use crate::BuildQueryParametersExt;
-use stellar_rust_sdk_derive::pagination;
+use crate::transactions::prelude::TransactionHash;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = PAYMENTS_PATH, parent = transaction_hash)]
 pub struct PaymentsForTransactionRequest {
     /// The transaction hash of the transaction for which you want to retrieve payments.
-    transaction_hash: Option<String>,
+    transaction_hash: Option<TransactionHash>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl PaymentsForTransactionRequest {
@@ -15,9 +19,7 @@ impl PaymentsForTransactionRequest {
     pub fn new() -> PaymentsForTransactionRequest {
         PaymentsForTransactionRequest {
             transaction_hash: None,
-            cursor: None,
-            limit: None,
-            order: None,
+            page_params: PageParams::new(),
         }
     }
 
@@ -26,34 +28,15 @@ impl PaymentsForTransactionRequest {
     /// # Arguments
     /// * `transaction_hash` - The transaction hash of the transaction for which you want to retrieve payments.
     ///
+    /// # Returns
+    /// The `PaymentsForTransactionRequest` with the transaction hash set, or an error if the
+    /// transaction hash is invalid.
+    ///
     pub fn set_transaction_hash(
         mut self,
         transaction_hash: impl Into<String>,
-    ) -> PaymentsForTransactionRequest {
-        self.transaction_hash = Some(transaction_hash.into());
-        self
-    }
-}
-
-impl Request for PaymentsForTransactionRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        let binding = "".to_string();
-        let transaction_hash = self.transaction_hash.as_ref().unwrap_or(&binding);
-        format!(
-            "{}/transactions/{}/{}?{}",
-            base_url,
-            transaction_hash,
-            PAYMENTS_PATH,
-            self.get_query_parameters()
-        )
+    ) -> Result<PaymentsForTransactionRequest, String> {
+        self.transaction_hash = Some(transaction_hash.into().parse()?);
+        Ok(self)
     }
 }
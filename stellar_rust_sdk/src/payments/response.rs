@@ -1,5 +1,6 @@
 use crate::models::prelude::{Embedded, ResponseLinks};
 use crate::models::Response;
+use crate::operations::prelude::OperationLinks;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize};
 /// It includes the links to the current, next, and previous pages of the response, as well as the
 /// embedded records of payments.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct PaymentsResponse {
     /// The links to the current, next, and previous pages of the response.
     #[serde(rename = "_links")]
@@ -24,11 +26,16 @@ pub struct PaymentsResponse {
 /// transaction success status, source account, type, creation date, transaction hash, starting balance,
 /// funder, and account.
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct Payment {
+    #[serde(rename = "_links", default)]
+    pub links: OperationLinks,
     pub id: String,
     pub paging_token: String,
     pub transaction_successful: bool,
     pub source_account: String,
+    pub source_account_muxed: Option<String>,
+    pub source_account_muxed_id: Option<String>,
     #[serde(rename = "type")]
     pub type_field: String,
     pub type_i: i64,
@@ -36,17 +43,23 @@ pub struct Payment {
     pub transaction_hash: String,
     pub starting_balance: Option<String>,
     pub funder: Option<String>,
+    pub funder_muxed: Option<String>,
+    pub funder_muxed_id: Option<String>,
     pub account: Option<String>,
     pub asset_type: Option<String>,
     pub asset_code: Option<String>,
     pub asset_issuer: Option<String>,
     pub from: Option<String>,
+    pub from_muxed: Option<String>,
+    pub from_muxed_id: Option<String>,
     pub to: Option<String>,
+    pub to_muxed: Option<String>,
+    pub to_muxed_id: Option<String>,
     pub amount: Option<String>,
 }
 
 impl Response for PaymentsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
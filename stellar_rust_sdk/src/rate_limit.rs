@@ -0,0 +1,223 @@
+use derive_getters::Getters;
+use futures::lock::Mutex;
+use reqwest::header::HeaderMap;
+use std::time::{Duration, Instant};
+
+/// The name of the header containing the number of requests allowed per rate limit window.
+pub(crate) static RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+/// The name of the header containing the number of requests left in the current window.
+pub(crate) static RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+/// The name of the header containing the number of seconds until the current window resets.
+pub(crate) static RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Represents the rate limit state reported by the Horizon server.
+///
+/// Horizon includes `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `X-RateLimit-Reset` headers
+/// in its responses. After each call, the [`HorizonClient`](crate::horizon_client::HorizonClient)
+/// parses these headers into a `RateLimitInfo`, which can be retrieved using
+/// [`HorizonClient::last_rate_limit`](crate::horizon_client::HorizonClient::last_rate_limit).
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::ledgers::prelude::*;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// let response = horizon_client.get_all_ledgers(&LedgersRequest::new()).await?;
+///
+/// if let Some(rate_limit) = horizon_client.last_rate_limit() {
+///     println!("{} requests remaining", rate_limit.remaining());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Getters)]
+pub struct RateLimitInfo {
+    /// The maximum number of requests allowed within the current rate limit window.
+    limit: u32,
+    /// The number of requests left within the current rate limit window.
+    remaining: u32,
+    /// The number of seconds until the current rate limit window resets.
+    reset: u64,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limit headers of a Horizon response.
+    ///
+    /// # Arguments
+    /// * `headers` - The headers of the HTTP response.
+    ///
+    /// # Returns
+    /// Returns `Some(RateLimitInfo)` if all three rate limit headers are present and valid,
+    /// otherwise `None`.
+    ///
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimitInfo> {
+        let header = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+
+        Some(RateLimitInfo {
+            limit: u32::try_from(header(RATE_LIMIT_LIMIT_HEADER)?).ok()?,
+            remaining: u32::try_from(header(RATE_LIMIT_REMAINING_HEADER)?).ok()?,
+            reset: header(RATE_LIMIT_RESET_HEADER)?,
+        })
+    }
+}
+
+/// The mutable state of a [`RateLimiter`].
+#[derive(Debug)]
+struct Bucket {
+    /// The number of tokens currently available.
+    tokens: f64,
+    /// The moment at which the bucket was last refilled.
+    last_refill: Instant,
+}
+
+/// A client-side token bucket rate limiter.
+///
+/// The bucket holds up to `capacity` tokens and is refilled at a constant rate of
+/// `refill_per_second` tokens per second. Every request sent by the
+/// [`HorizonClient`](crate::horizon_client::HorizonClient) consumes a single token; when the bucket
+/// is empty, the request waits until a token becomes available. This allows bulk operations to
+/// stay under the Horizon rate limits proactively, instead of reacting to `429 Too Many Requests`
+/// responses.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::rate_limit::RateLimiter;
+/// // Allow bursts of 10 requests, and a sustained rate of 5 requests per second.
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+///     .expect("Failed to create HorizonClient")
+///     .set_rate_limiter(RateLimiter::new(10, 5).unwrap());
+/// ```
+///
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// The maximum number of tokens the bucket can hold.
+    capacity: u32,
+    /// The number of tokens added to the bucket per second.
+    refill_per_second: u32,
+    /// The current state of the bucket.
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter` with a full bucket.
+    ///
+    /// # Arguments
+    /// * `capacity` - The maximum number of tokens, i.e. the largest burst of requests allowed.
+    /// * `refill_per_second` - The number of tokens added per second, i.e. the sustained request rate.
+    ///
+    /// # Returns
+    /// Returns the `RateLimiter`, or an error if either argument is `0`.
+    ///
+    pub fn new(capacity: u32, refill_per_second: u32) -> Result<Self, String> {
+        if capacity < 1 {
+            return Err("Capacity must be greater than or equal to 1.".to_string());
+        }
+        if refill_per_second < 1 {
+            return Err("Refill rate must be greater than or equal to 1.".to_string());
+        }
+
+        Ok(RateLimiter {
+            capacity,
+            refill_per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            }),
+        })
+    }
+
+    /// Returns the maximum number of tokens the bucket can hold.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns the number of tokens added to the bucket per second.
+    pub fn refill_per_second(&self) -> u32 {
+        self.refill_per_second
+    }
+
+    /// Takes a token from the bucket, waiting until one becomes available if the bucket is empty.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                self.refill(&mut bucket);
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_second as f64)
+            };
+
+            crate::runtime::sleep(wait).await;
+        }
+    }
+
+    /// Adds the tokens accumulated since the last refill, up to the capacity of the bucket.
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.refill_per_second as f64).min(self.capacity as f64);
+        bucket.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_rate_limit_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("3600"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("3598"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("42"));
+
+        let info = RateLimitInfo::from_headers(&headers).unwrap();
+        assert_eq!(info.limit(), &3600);
+        assert_eq!(info.remaining(), &3598);
+        assert_eq!(info.reset(), &42);
+    }
+
+    #[test]
+    fn test_rate_limit_info_missing_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("3600"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("invalid"));
+
+        assert_eq!(RateLimitInfo::from_headers(&headers), None);
+    }
+
+    #[test]
+    fn test_rate_limiter_invalid() {
+        assert_eq!(
+            RateLimiter::new(0, 1).unwrap_err(),
+            "Capacity must be greater than or equal to 1."
+        );
+        assert_eq!(
+            RateLimiter::new(1, 0).unwrap_err(),
+            "Refill rate must be greater than or equal to 1."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_waits_when_empty() {
+        let rate_limiter = RateLimiter::new(2, 20).unwrap();
+        let start = Instant::now();
+
+        // The first two tokens are available immediately; the third requires a refill.
+        rate_limiter.acquire().await;
+        rate_limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(40));
+
+        rate_limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::Error;
+use std::time::Duration;
+
+/// A policy for retrying requests that failed because of a transient error.
+///
+/// Horizon occasionally fails requests because of a temporary condition, such as a dropped
+/// connection, a `429 Too Many Requests` or a `503 Service Unavailable` response. Once a
+/// `RetryPolicy` is attached to the [`HorizonClient`](crate::horizon_client::HorizonClient), such
+/// requests are sent again up to `max_retries` times. Before every retry, the client waits for a
+/// backoff that starts at `initial_backoff` and doubles with every retry, up to `max_backoff`.
+/// The errors that are retried are those for which [`Error::is_retryable`] returns `true`.
+///
+/// Only `GET` requests are retried. Submitting a transaction is not idempotent from the point of
+/// view of the caller, so failed submissions are always returned as is.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::retry::RetryPolicy;
+/// # use std::time::Duration;
+/// // Retry up to 3 times, waiting 100, 200 and 400 milliseconds before the retries.
+/// let retry_policy =
+///     RetryPolicy::new(3, Duration::from_millis(100), Duration::from_secs(1)).unwrap();
+/// assert_eq!(retry_policy.backoff(2), Duration::from_millis(400));
+///
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")
+///     .expect("Failed to create HorizonClient")
+///     .set_retry_policy(retry_policy);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of times a failed request is retried.
+    max_retries: u32,
+    /// The time to wait before the first retry.
+    initial_backoff: Duration,
+    /// The maximum time to wait before a retry.
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy`.
+    ///
+    /// # Arguments
+    /// * `max_retries` - The maximum number of times a failed request is retried.
+    /// * `initial_backoff` - The time to wait before the first retry.
+    /// * `max_backoff` - The maximum time to wait before a retry.
+    ///
+    /// # Returns
+    /// Returns the `RetryPolicy`, or an error if the initial backoff exceeds the maximum backoff.
+    ///
+    pub fn new(
+        max_retries: u32,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Result<Self, String> {
+        if initial_backoff > max_backoff {
+            return Err("Initial backoff must not exceed the maximum backoff.".to_string());
+        }
+
+        Ok(RetryPolicy {
+            max_retries,
+            initial_backoff,
+            max_backoff,
+        })
+    }
+
+    /// Returns the maximum number of times a failed request is retried.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the time to wait before the first retry.
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the maximum time to wait before a retry.
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Returns the time to wait before the given retry.
+    ///
+    /// # Arguments
+    /// * `retry` - The number of retries that were made before, starting at `0`.
+    ///
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .checked_mul(2u32.saturating_pow(retry))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+
+    /// Returns whether a request that failed with the given error should be retried.
+    pub(crate) fn should_retry(&self, retry: u32, error: &Error) -> bool {
+        retry < self.max_retries && error.is_retryable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_invalid() {
+        assert_eq!(
+            RetryPolicy::new(1, Duration::from_secs(2), Duration::from_secs(1)).unwrap_err(),
+            "Initial backoff must not exceed the maximum backoff."
+        );
+    }
+
+    #[test]
+    fn test_backoff() {
+        let retry_policy =
+            RetryPolicy::new(10, Duration::from_millis(100), Duration::from_secs(1)).unwrap();
+
+        assert_eq!(retry_policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(retry_policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(retry_policy.backoff(4), Duration::from_secs(1));
+        assert_eq!(retry_policy.backoff(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let retry_policy = RetryPolicy::new(2, Duration::ZERO, Duration::ZERO).unwrap();
+        let error = |status| Error::Horizon {
+            status,
+            body: String::new(),
+        };
+
+        assert!(retry_policy.should_retry(0, &error(503)));
+        assert!(retry_policy.should_retry(1, &error(429)));
+        assert!(!retry_policy.should_retry(2, &error(503)));
+        assert!(!retry_policy.should_retry(0, &error(404)));
+        assert!(!retry_policy.should_retry(0, &Error::Other("invalid".to_string())));
+        assert!(retry_policy.should_retry(
+            0,
+            &Error::StaleResponse {
+                latest_ledger: 1,
+                highest_ledger: 3,
+            }
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+/// Provides the `RootRequest`.
+///
+/// This module provides the `RootRequest` struct, specifically designed for constructing requests
+/// to query the root resource of the Horizon server. It is tailored for use with the
+/// [`HorizonClient::get_root`](crate::horizon_client::HorizonClient::get_root) method.
+///
+pub mod root_request;
+
+/// Provides the responses.
+///
+/// This module defines structures representing the response from the Horizon API when querying
+/// its root resource. The structures are designed to deserialize the JSON response into Rust
+/// objects, enabling straightforward access to the versions, the network passphrase and the
+/// ledger range of the Horizon server.
+///
+/// These structures are equipped with serialization capabilities to handle the JSON data from the
+/// Horizon server and with getter methods for easy field access.
+///
+pub mod response;
+
+pub mod prelude {
+    pub use super::response::*;
+    pub use super::root_request::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::horizon_client::HorizonClient;
+    use crate::network::Network;
+
+    #[tokio::test]
+    async fn test_get_root() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let root_response = horizon_client.get_root().await.unwrap();
+
+        assert_eq!(
+            root_response.network_passphrase(),
+            Network::Testnet.passphrase()
+        );
+        assert_eq!(root_response.network(), Some(Network::Testnet));
+        assert!(root_response.supports_protocol(18));
+        assert!(root_response.parse_horizon_version().is_ok());
+        assert!(root_response.links().friendbot().is_some());
+    }
+}
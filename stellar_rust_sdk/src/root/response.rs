@@ -0,0 +1,122 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::models::prelude::*;
+use crate::network::Network;
+use crate::version::HorizonVersion;
+
+/// Represents the navigational links in the root response from the Horizon API.
+///
+/// This struct includes the templated links to the endpoints of the Horizon server, such as the
+/// accounts, ledgers, transactions and paths endpoints.
+///
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
+pub struct RootLinks {
+    pub account: TemplateLink,
+    pub accounts: TemplateLink,
+    pub account_transactions: TemplateLink,
+    pub claimable_balances: TemplateLink,
+    pub assets: TemplateLink,
+    pub effects: TemplateLink,
+    pub fee_stats: TemplateLink,
+    /// The link to the Friendbot of the network, which is only present on test networks.
+    pub friendbot: Option<TemplateLink>,
+    pub ledger: TemplateLink,
+    pub ledgers: TemplateLink,
+    pub liquidity_pools: TemplateLink,
+    pub offer: TemplateLink,
+    pub offers: TemplateLink,
+    pub operation: TemplateLink,
+    pub operations: TemplateLink,
+    pub order_book: TemplateLink,
+    pub payments: TemplateLink,
+    #[serde(rename = "self")]
+    pub self_link: Link,
+    pub strict_receive_paths: TemplateLink,
+    pub strict_send_paths: TemplateLink,
+    pub trade_aggregations: TemplateLink,
+    pub trades: TemplateLink,
+    pub transaction: TemplateLink,
+    pub transactions: TemplateLink,
+}
+
+/// Represents the root resource of a Horizon server.
+///
+/// This struct describes the Horizon server and the network it serves: the versions of Horizon
+/// and Stellar Core, the passphrase of the network, the protocol versions in use and supported,
+/// and the range of ledgers that have been ingested.
+///
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
+pub struct RootResponse {
+    /// Navigational links to the endpoints of the Horizon server.
+    #[serde(rename = "_links")]
+    pub links: RootLinks,
+    /// The version of Horizon, such as `22.0.0-dc4e13ac`.
+    pub horizon_version: String,
+    /// The version of Stellar Core, such as `stellar-core 22.0.0 (...)`.
+    pub core_version: String,
+    /// The sequence number of the latest ledger ingested by Horizon.
+    pub ingest_latest_ledger: u32,
+    /// The sequence number of the latest ledger in the history of Horizon.
+    pub history_latest_ledger: u32,
+    /// The time at which the latest ledger in the history of Horizon was closed.
+    pub history_latest_ledger_closed_at: String,
+    /// The sequence number of the oldest ledger in the history of Horizon.
+    pub history_elder_ledger: u32,
+    /// The sequence number of the latest ledger known to Stellar Core.
+    pub core_latest_ledger: u32,
+    /// The passphrase of the network served by Horizon.
+    pub network_passphrase: String,
+    /// The protocol version the network currently runs.
+    pub current_protocol_version: u32,
+    /// The newest protocol version supported by Horizon.
+    pub supported_protocol_version: u32,
+    /// The newest protocol version supported by Stellar Core.
+    pub core_supported_protocol_version: u32,
+}
+
+impl RootResponse {
+    /// Parses the version of Horizon.
+    ///
+    /// # Returns
+    /// The [`HorizonVersion`], or an error if the `horizon_version` is not a valid version.
+    ///
+    pub fn parse_horizon_version(&self) -> Result<HorizonVersion, String> {
+        self.horizon_version.parse()
+    }
+
+    /// Returns the well-known [`Network`] with the passphrase of the served network, or `None` if
+    /// the network is not one of the presets, such as a private network.
+    pub fn network(&self) -> Option<Network> {
+        Network::from_passphrase(&self.network_passphrase)
+    }
+
+    /// Returns the number of ledgers Horizon has not ingested yet, which is the difference between
+    /// the latest ledger known to Stellar Core and the latest ledger ingested by Horizon.
+    ///
+    /// A Horizon server that keeps up with the network lags at most a ledger or two behind. A
+    /// larger lag means that the responses of the server are stale.
+    ///
+    pub fn ingestion_lag(&self) -> u32 {
+        self.core_latest_ledger
+            .saturating_sub(self.ingest_latest_ledger)
+    }
+
+    /// Returns whether the network currently runs the given protocol version, or a newer one.
+    ///
+    /// # Arguments
+    /// * `protocol_version` - The protocol version that introduced a feature, such as `18` for
+    ///   liquidity pools.
+    ///
+    pub fn supports_protocol(&self, protocol_version: u32) -> bool {
+        self.current_protocol_version >= protocol_version
+    }
+}
+
+impl Response for RootResponse {
+    fn from_json(json: String) -> Result<Self, String> {
+        crate::models::deserialize_json(&json)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::models::Request;
+
+/// Represents a request to fetch the root resource of a Horizon server.
+///
+/// `RootRequest` is a struct used to construct queries for retrieving the root resource of the
+/// Horizon server, which describes the server and the network it serves. This request does not
+/// include any parameters.
+///
+/// # Usage
+/// The request is sent by the [`HorizonClient::get_root`](crate::horizon_client::HorizonClient::get_root)
+/// method, but it can also be executed directly.
+///
+/// # Example
+/// ```rust
+/// use stellar_rs::root::root_request::RootRequest;
+/// use stellar_rs::models::*;
+///
+/// let request = RootRequest::new();
+/// assert_eq!(
+///     request.build_url("https://horizon-testnet.stellar.org"),
+///     "https://horizon-testnet.stellar.org/"
+/// );
+/// ```
+///
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct RootRequest {}
+
+impl RootRequest {
+    /// Creates a new `RootRequest`.
+    pub fn new() -> RootRequest {
+        RootRequest::default()
+    }
+}
+
+impl Request for RootRequest {
+    fn get_query_parameters(&self) -> String {
+        "".to_string()
+    }
+
+    fn build_url(&self, base_url: &str) -> String {
+        format!("{}/", base_url)
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(any(feature = "tokio", feature = "futures-timer"))]
+use std::time::Duration;
+
+/// Waits until the given duration has elapsed.
+///
+/// With the `tokio` feature, which is enabled by default, this uses the timer of the `tokio`
+/// runtime. With only the `futures-timer` feature enabled, this uses the timer of the
+/// `futures-timer` crate, which runs on a background thread and does not need a specific runtime.
+/// If both features are enabled, `tokio` takes precedence.
+///
+#[cfg(feature = "tokio")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+/// Waits until the given duration has elapsed.
+///
+/// With the `tokio` feature, which is enabled by default, this uses the timer of the `tokio`
+/// runtime. With only the `futures-timer` feature enabled, this uses the timer of the
+/// `futures-timer` crate, which runs on a background thread and does not need a specific runtime.
+/// If both features are enabled, `tokio` takes precedence.
+///
+#[cfg(all(feature = "futures-timer", not(feature = "tokio")))]
+pub(crate) async fn sleep(duration: Duration) {
+    futures_timer::Delay::new(duration).await
+}
+
+/// Sends an HTTP request and awaits the response.
+///
+/// `reqwest` opens connections through the reactor of `tokio`. With the `tokio` feature, the
+/// request is sent on the runtime of the application.
+///
+#[cfg(feature = "tokio")]
+pub(crate) async fn send(request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+    request.send().await
+}
+
+/// Sends an HTTP request and awaits the response.
+///
+/// `reqwest` opens connections through the reactor of `tokio`. With only the `futures-timer`
+/// feature enabled, the application may run on another runtime, so the request is sent on a
+/// background `tokio` runtime of the SDK, unless a `tokio` runtime is running already. The
+/// connection keeps running on the runtime it was opened on, so the body of the response can be
+/// read on any runtime.
+///
+#[cfg(all(feature = "futures-timer", not(feature = "tokio")))]
+pub(crate) async fn send(request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return request.send().await;
+    }
+
+    // The request is built on the background runtime, because `reqwest` starts the timer of
+    // its timeout while building it.
+    IO_RUNTIME
+        .spawn(async move { request.send().await })
+        .await
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+}
+
+#[cfg(all(feature = "futures-timer", not(feature = "tokio")))]
+lazy_static::lazy_static! {
+    /// The background runtime on which requests are sent when no `tokio` runtime is running. It
+    /// runs on a thread of its own for as long as the application runs.
+    static ref IO_RUNTIME: tokio::runtime::Handle = {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to start the runtime of the HTTP client");
+        let handle = runtime.handle().clone();
+        std::thread::Builder::new()
+            .name("stellar-rs-io".to_string())
+            .spawn(move || runtime.block_on(std::future::pending::<()>()))
+            .expect("Failed to start the thread of the HTTP client");
+        handle
+    };
+}
+
+#[cfg(not(any(feature = "tokio", feature = "futures-timer")))]
+compile_error!(
+    "Either the `tokio` or the `futures-timer` feature of `stellar-rs` must be enabled."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_sleep() {
+        let start = Instant::now();
+        sleep(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,446 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// The signature of a checkpoint callback, which receives the paging token of the last processed record.
+type CheckpointCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Represents the options that control the lifecycle of a stream.
+///
+/// Streams returned by the [`HorizonClient`](crate::horizon_client::HorizonClient) keep fetching
+/// pages until the Horizon server runs out of records. `StreamOptions` allows these streams to be
+/// integrated in long-running services, such as indexers:
+///
+/// * A [`CancellationToken`] can be attached to shut the stream down gracefully. Once the token is
+///   cancelled, the stream ends at the next item, aborting any page request that is in flight.
+/// * A checkpoint callback can be attached, which receives the paging token of the last processed
+///   record. It is called every time a page has been fully processed, and once more when the stream
+///   ends or is shut down. A record counts as processed once the next item of the stream is
+///   requested, so the reported paging token never skips a record the consumer has not handled.
+/// * A paging token can be set to resume from, typically the last reported checkpoint. The stream
+///   then starts right after the record with this paging token.
+///
+/// # Example
+/// ```rust
+/// use stellar_rs::streaming::{CancellationToken, StreamOptions};
+///
+/// let shutdown = CancellationToken::new();
+/// let options = StreamOptions::new()
+///     .set_shutdown_token(shutdown.clone())
+///     .set_resume_from("12884905985")
+///     .set_checkpoint_callback(|paging_token| println!("Processed up to {}", paging_token));
+///
+/// // Calling `shutdown.cancel()` from anywhere ends the stream gracefully.
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct StreamOptions {
+    /// The token that shuts the stream down when cancelled.
+    shutdown: Option<CancellationToken>,
+    /// The paging token of the record to resume after.
+    resume_from: Option<String>,
+    /// The callback that receives the paging token of the last processed record.
+    on_checkpoint: Option<CheckpointCallback>,
+}
+
+impl StreamOptions {
+    /// Creates new `StreamOptions`, without a shutdown token, checkpoint callback or resume point.
+    pub fn new() -> Self {
+        StreamOptions::default()
+    }
+
+    /// Sets the token that shuts the stream down when cancelled.
+    ///
+    /// # Arguments
+    /// * `shutdown` - The [`CancellationToken`]. Clones of the token can be cancelled from other
+    ///   tasks.
+    ///
+    pub fn set_shutdown_token(self, shutdown: CancellationToken) -> Self {
+        Self {
+            shutdown: Some(shutdown),
+            ..self
+        }
+    }
+
+    /// Sets the paging token of the record to resume after.
+    ///
+    /// This overrides the cursor of the request the stream is created from.
+    ///
+    /// # Arguments
+    /// * `paging_token` - The paging token, as reported to the checkpoint callback.
+    ///
+    pub fn set_resume_from(self, paging_token: impl Into<String>) -> Self {
+        Self {
+            resume_from: Some(paging_token.into()),
+            ..self
+        }
+    }
+
+    /// Sets the callback that receives the paging token of the last processed record.
+    ///
+    /// # Arguments
+    /// * `on_checkpoint` - The callback. It is called from within the stream, so it should return
+    ///   quickly; persisting the paging token should not block for long.
+    ///
+    pub fn set_checkpoint_callback(
+        self,
+        on_checkpoint: impl Fn(&str) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            on_checkpoint: Some(Arc::new(on_checkpoint)),
+            ..self
+        }
+    }
+
+    /// Returns the shutdown token, if any.
+    pub fn shutdown_token(&self) -> Option<&CancellationToken> {
+        self.shutdown.as_ref()
+    }
+
+    /// Returns the paging token to resume after, if any.
+    pub fn resume_from(&self) -> Option<&str> {
+        self.resume_from.as_deref()
+    }
+
+    /// Returns whether the stream has been shut down.
+    pub(crate) fn is_shut_down(&self) -> bool {
+        self.shutdown
+            .as_ref()
+            .is_some_and(|shutdown| shutdown.is_cancelled())
+    }
+
+    /// Completes when the stream is shut down. Never completes if no shutdown token is set.
+    pub(crate) async fn shut_down(&self) {
+        match &self.shutdown {
+            Some(shutdown) => shutdown.cancelled().await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Applies the resume point, if any, to the URL of the first page.
+    pub(crate) fn first_url(&self, url: String) -> String {
+        match &self.resume_from {
+            Some(paging_token) => with_cursor(url, paging_token),
+            None => url,
+        }
+    }
+}
+
+/// Replaces the cursor in the query of a URL with the given paging token.
+pub(crate) fn with_cursor(url: String, paging_token: &str) -> String {
+    let Ok(mut parsed) = Url::parse(&url) else {
+        return url;
+    };
+
+    let query: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| key != "cursor")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    parsed
+        .query_pairs_mut()
+        .clear()
+        .extend_pairs(query)
+        .append_pair("cursor", paging_token);
+
+    parsed.to_string()
+}
+
+impl fmt::Debug for StreamOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamOptions")
+            .field("shutdown", &self.shutdown)
+            .field("resume_from", &self.resume_from)
+            .field("on_checkpoint", &self.on_checkpoint.is_some())
+            .finish()
+    }
+}
+
+/// The state of a paged stream, shared between the items it yields.
+pub(crate) struct StreamState<T> {
+    /// The URL of the next page to fetch, if any.
+    pub(crate) next_url: Option<String>,
+    /// The records of the current page that have not been processed yet.
+    pub(crate) buffered: VecDeque<T>,
+    /// The paging token of the last yielded record, which is processed once the next item is requested.
+    yielded: Option<String>,
+    /// The paging token of the last processed record.
+    processed: Option<String>,
+    /// The paging token that was last reported to the checkpoint callback.
+    checkpointed: Option<String>,
+}
+
+impl<T> StreamState<T> {
+    pub(crate) fn new(first_url: String) -> Self {
+        StreamState {
+            next_url: Some(first_url),
+            buffered: VecDeque::new(),
+            yielded: None,
+            processed: None,
+            checkpointed: None,
+        }
+    }
+
+    /// Marks the last yielded record as processed. Called whenever the next item is requested.
+    pub(crate) fn confirm_yielded(&mut self) {
+        if let Some(paging_token) = self.yielded.take() {
+            self.processed = Some(paging_token);
+        }
+    }
+
+    /// Records that a record has been yielded.
+    pub(crate) fn set_yielded(&mut self, paging_token: &str) {
+        self.yielded = Some(paging_token.to_string());
+    }
+
+    /// Records that a record has been skipped, which counts as processed immediately.
+    pub(crate) fn set_skipped(&mut self, paging_token: &str) {
+        self.processed = Some(paging_token.to_string());
+    }
+
+    /// Ends the stream, so that no more pages are fetched.
+    pub(crate) fn finish(&mut self) {
+        self.next_url = None;
+        self.buffered.clear();
+    }
+
+    /// Reports the last processed paging token to the checkpoint callback, if it changed since the
+    /// previous checkpoint.
+    pub(crate) fn checkpoint(&mut self, options: &StreamOptions) {
+        let Some(on_checkpoint) = &options.on_checkpoint else {
+            return;
+        };
+        if self.processed.is_some() && self.processed != self.checkpointed {
+            self.checkpointed = self.processed.clone();
+            on_checkpoint(self.checkpointed.as_deref().unwrap_or_default());
+        }
+    }
+}
+
+/// The delay before reconnecting to an event stream, unless the server requests another delay.
+pub(crate) const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Represents an event of a Server-Sent Events stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SseEvent {
+    /// The ID of the event. Horizon sets it to the paging token of the record.
+    pub(crate) id: Option<String>,
+    /// The type of the event, or `None` for a plain message. Horizon sends the records as plain
+    /// messages, and announces a new connection with an `open` event.
+    pub(crate) event: Option<String>,
+    /// The data of the event. Horizon sets it to the record, as JSON.
+    pub(crate) data: String,
+}
+
+/// Parses the body of a Server-Sent Events stream into events, one chunk at a time.
+///
+/// Chunks may end anywhere, even within a line or a UTF-8 character, so the incomplete line at
+/// the end of a chunk is kept until the next chunk completes it.
+///
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    /// The bytes of the line that has not been completed yet.
+    line: Vec<u8>,
+    /// The ID of the event that is being parsed, if any.
+    id: Option<String>,
+    /// The type of the event that is being parsed, if any.
+    event: Option<String>,
+    /// The data of the event that is being parsed, if any.
+    data: Option<String>,
+    /// The ID of the last event, from which to resume after a reconnect.
+    last_event_id: Option<String>,
+    /// The delay before reconnecting that the server requested, if any.
+    reconnect_delay: Option<Duration>,
+}
+
+impl SseParser {
+    /// Parses a chunk of the body, and returns the events it completes.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        let mut events = Vec::new();
+        let mut rest = chunk;
+        while let Some(end) = rest.iter().position(|byte| *byte == b'\n') {
+            self.line.extend_from_slice(&rest[..end]);
+            rest = &rest[end + 1..];
+
+            let line = std::mem::take(&mut self.line);
+            let line = String::from_utf8_lossy(&line);
+            events.extend(self.parse_line(line.strip_suffix('\r').unwrap_or(&line)));
+        }
+        self.line.extend_from_slice(rest);
+
+        events
+    }
+
+    /// Returns the ID of the last event, from which to resume after a reconnect.
+    pub(crate) fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Returns the delay before reconnecting, as requested by the server or the default.
+    pub(crate) fn reconnect_delay(&self) -> Duration {
+        self.reconnect_delay.unwrap_or(DEFAULT_RECONNECT_DELAY)
+    }
+
+    /// Discards the incomplete line and event, when the connection is lost.
+    pub(crate) fn reset(&mut self) {
+        self.line.clear();
+        self.id = None;
+        self.event = None;
+        self.data = None;
+    }
+
+    /// Parses a complete line, and returns the event it completes, if any.
+    fn parse_line(&mut self, line: &str) -> Option<SseEvent> {
+        // An empty line completes the event, which is only dispatched if it has data.
+        if line.is_empty() {
+            let (id, event) = (self.id.take(), self.event.take());
+            return self.data.take().map(|data| SseEvent { id, event, data });
+        }
+
+        // Lines starting with a colon are comments, which Horizon sends to keep the connection
+        // alive.
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "id" => {
+                self.id = Some(value.to_string());
+                self.last_event_id = Some(value.to_string());
+            }
+            "event" => self.event = Some(value.to_string()),
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "retry" => {
+                if let Ok(millis) = value.parse() {
+                    self.reconnect_delay = Some(Duration::from_millis(millis));
+                }
+            }
+            _ => {}
+        }
+
+        None
+    }
+}
+
+/// The connection of a Server-Sent Events stream, shared between the items it yields.
+#[derive(Debug, Default)]
+pub(crate) struct SseConnection {
+    /// The response whose body is being read, or `None` if the stream has to (re)connect.
+    pub(crate) response: Option<reqwest::Response>,
+    /// The parser of the body, which persists across reconnects.
+    pub(crate) parser: SseParser,
+    /// The error that ends the stream, which is yielded after the records received before it.
+    pub(crate) error: Option<crate::error::Error>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_first_url_resume_from() {
+        let options = StreamOptions::new().set_resume_from("12884905985");
+
+        assert_eq!(
+            options.first_url(
+                "https://horizon-testnet.stellar.org/operations?cursor=1&limit=200".to_string()
+            ),
+            "https://horizon-testnet.stellar.org/operations?limit=200&cursor=12884905985"
+        );
+        assert_eq!(
+            StreamOptions::new()
+                .first_url("https://horizon-testnet.stellar.org/operations?limit=200".to_string()),
+            "https://horizon-testnet.stellar.org/operations?limit=200"
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_reports_processed_records() {
+        let checkpoints = Arc::new(Mutex::new(Vec::new()));
+        let reported = checkpoints.clone();
+        let options = StreamOptions::new().set_checkpoint_callback(move |paging_token| {
+            reported.lock().unwrap().push(paging_token.to_string())
+        });
+
+        let mut state = StreamState::<()>::new("https://example.org".to_string());
+        state.set_yielded("1");
+        // The yielded record has not been confirmed as processed yet.
+        state.checkpoint(&options);
+        state.confirm_yielded();
+        state.checkpoint(&options);
+        state.set_skipped("2");
+        state.checkpoint(&options);
+        // Unchanged paging tokens are not reported twice.
+        state.checkpoint(&options);
+
+        assert_eq!(*checkpoints.lock().unwrap(), vec!["1", "2"]);
+    }
+
+    #[test]
+    fn test_shutdown() {
+        let shutdown = CancellationToken::new();
+        let options = StreamOptions::new().set_shutdown_token(shutdown.clone());
+
+        assert!(!options.is_shut_down());
+        shutdown.cancel();
+        assert!(options.is_shut_down());
+        assert!(!StreamOptions::new().is_shut_down());
+    }
+
+    #[test]
+    fn test_sse_parser() {
+        let mut parser = SseParser::default();
+        assert_eq!(parser.reconnect_delay(), DEFAULT_RECONNECT_DELAY);
+
+        let events = parser.push(b"retry: 1000\nevent: open\ndata: \"hello\"\n\n: keep-alive\n\n");
+        assert_eq!(
+            events,
+            vec![SseEvent {
+                id: None,
+                event: Some("open".to_string()),
+                data: "\"hello\"".to_string(),
+            }]
+        );
+        assert_eq!(parser.reconnect_delay(), Duration::from_secs(1));
+
+        // An event may be split over several chunks, even within a line.
+        assert!(parser.push(b"id: 12884905985\r\ndata: {\"id\":").is_empty());
+        let events = parser.push(b" \"12884905985\"}\r\n\r\nid: 12884905986\n");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id.as_deref(), Some("12884905985"));
+        assert_eq!(events[0].event, None);
+        assert_eq!(events[0].data, "{\"id\": \"12884905985\"}");
+        assert_eq!(parser.last_event_id(), Some("12884905986"));
+
+        // Data on several lines is joined by newlines, and the incomplete event is discarded on
+        // a reset.
+        parser.reset();
+        let events = parser.push(b"data: a\ndata\ndata: b\n\n");
+        assert_eq!(events[0].id, None);
+        assert_eq!(events[0].data, "a\n\nb");
+    }
+
+    #[test]
+    fn test_with_cursor() {
+        assert_eq!(
+            with_cursor(
+                "https://horizon-testnet.stellar.org/effects?cursor=1&limit=10".to_string(),
+                "now"
+            ),
+            "https://horizon-testnet.stellar.org/effects?limit=10&cursor=now"
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use crate::accounts::prelude::Account;
+use crate::offers::prelude::OfferResponse;
+use crate::operations::prelude::Operation;
+use crate::transactions::prelude::TransactionResponse;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// A response type that can be fabricated from canned JSON, for use in unit tests.
+pub trait TestResponse: DeserializeOwned {
+    /// The JSON of a realistic response, as returned by the Horizon server.
+    const FIXTURE: &'static str;
+
+    /// Returns the response described by [`TestResponse::FIXTURE`].
+    fn test_default() -> Self {
+        Self::builder()
+            .build()
+            .expect("the fixture of a test response is valid")
+    }
+
+    /// Returns a [`ResponseBuilder`], initialized with the values of [`TestResponse::FIXTURE`].
+    fn builder() -> ResponseBuilder<Self> {
+        ResponseBuilder {
+            json: serde_json::from_str(Self::FIXTURE)
+                .expect("the fixture of a test response is valid JSON"),
+            response: PhantomData,
+        }
+    }
+}
+
+/// A builder that fabricates a response by overriding fields of its canned JSON.
+///
+/// Fields are addressed by their names in the JSON returned by Horizon, which may differ from the
+/// names of the getters. Nested fields are addressed using a dot-separated path, such as
+/// `selling.asset_code` or `_links.self.href`.
+///
+#[derive(Debug, Clone)]
+pub struct ResponseBuilder<T> {
+    /// The JSON of the response to build.
+    json: Value,
+    /// The type of the response to build.
+    response: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> ResponseBuilder<T> {
+    /// Sets the value of a field, creating it and its parents if they do not exist.
+    ///
+    /// # Arguments
+    /// * `path` - The dot-separated path of the field, such as `selling.asset_code`.
+    /// * `value` - The new value of the field. Use `serde_json::Value::Null` to unset an optional
+    ///   field.
+    ///
+    pub fn set(mut self, path: &str, value: impl Into<Value>) -> Self {
+        let mut field = &mut self.json;
+        for key in path.split('.') {
+            if !field.is_object() {
+                *field = Value::Object(Default::default());
+            }
+            field = field
+                .as_object_mut()
+                .expect("the field was just made an object")
+                .entry(key)
+                .or_insert(Value::Null);
+        }
+        *field = value.into();
+
+        self
+    }
+
+    /// Builds the response.
+    ///
+    /// # Returns
+    /// The response, or an error if the overridden fields do not match the structure of the
+    /// response.
+    ///
+    pub fn build(self) -> Result<T, String> {
+        serde_path_to_error::deserialize(self.json)
+            .map_err(|e| format!("failed to deserialize at `{}`: {}", e.path(), e.inner()))
+    }
+}
+
+impl TestResponse for TransactionResponse {
+    const FIXTURE: &'static str = r#"{
+        "_links": {
+            "self": {"href": "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"},
+            "account": {"href": "https://horizon-testnet.stellar.org/accounts/GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H"},
+            "ledger": {"href": "https://horizon-testnet.stellar.org/ledgers/539"},
+            "operations": {"href": "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020/operations{?cursor,limit,order}"},
+            "effects": {"href": "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020/effects{?cursor,limit,order}"},
+            "precedes": {"href": "https://horizon-testnet.stellar.org/transactions?order=asc&cursor=2314987376640"},
+            "succeeds": {"href": "https://horizon-testnet.stellar.org/transactions?order=desc&cursor=2314987376640"},
+            "transaction": {"href": "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"}
+        },
+        "id": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020",
+        "paging_token": "2314987376640",
+        "successful": true,
+        "hash": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020",
+        "ledger": 539,
+        "created_at": "2024-06-11T21:36:12Z",
+        "source_account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+        "source_account_sequence": "2314987376641",
+        "fee_account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+        "fee_charged": "100",
+        "max_fee": "100",
+        "operation_count": 1,
+        "envelope_xdr": "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAAAGQAAAIbAAAQAQAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAANBo20ff6bd2z2QLYzd9GOuQYxNDDLxWCOXmCeQ24W5AAAAAF0h26AAAAAAAAAAAAA==",
+        "result_xdr": "AAAAAAAAAGQAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAA=",
+        "result_meta_xdr": "AAAAAAAAAAA=",
+        "fee_meta_xdr": "AAAAAA==",
+        "memo_type": "none",
+        "signatures": [],
+        "valid_after": "1970-01-01T00:00:00Z",
+        "preconditions": {"timebounds": {"min_time": "0"}}
+    }"#;
+}
+
+impl TestResponse for OfferResponse {
+    const FIXTURE: &'static str = r#"{
+        "_links": {
+            "self": {"href": "https://horizon-testnet.stellar.org/offers/27493"},
+            "offer_maker": {"href": "https://horizon-testnet.stellar.org/accounts/GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E"}
+        },
+        "id": "27493",
+        "paging_token": "27493",
+        "seller": "GCXRNJ23TEHRNXQJEYXGQ3IYGVAWWY6Z2VOOWPP6STTYQCKXIRTNCN3E",
+        "selling": {
+            "asset_type": "credit_alphanum12",
+            "asset_code": "MBAUDD",
+            "asset_issuer": "GD2YNRNSJ3EOFJAYGLKGKSIOLX2VU3UFDW3YFNOYMAHB26AEHSZBJU4U"
+        },
+        "buying": {
+            "asset_type": "credit_alphanum12",
+            "asset_code": "TMB001128",
+            "asset_issuer": "GBH2HB7DZN7PRJP5RED2SQZAKSYYBH43PQCQH3NOYT2Y2KLODQZM3M2F"
+        },
+        "amount": "20081.0000000",
+        "price_r": {"n": 50, "d": 467},
+        "price": "0.1070664",
+        "last_modified_ledger": 845805,
+        "last_modified_time": "2024-08-02T08:29:11Z"
+    }"#;
+}
+
+impl TestResponse for Account {
+    const FIXTURE: &'static str = r#"{
+        "_links": {
+            "self": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE"},
+            "transactions": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/transactions{?cursor,limit,order}", "templated": true},
+            "operations": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/operations{?cursor,limit,order}", "templated": true},
+            "payments": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/payments{?cursor,limit,order}", "templated": true},
+            "effects": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/effects{?cursor,limit,order}", "templated": true},
+            "offers": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/offers{?cursor,limit,order}", "templated": true},
+            "trades": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/trades{?cursor,limit,order}", "templated": true},
+            "data": {"href": "https://horizon-testnet.stellar.org/accounts/GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE/data/{key}", "templated": true}
+        },
+        "id": "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE",
+        "account_id": "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE",
+        "sequence": "5471788335106",
+        "subentry_count": 0,
+        "last_modified_ledger": 14055,
+        "last_modified_time": "2024-06-12T17:21:23Z",
+        "thresholds": {"low_threshold": 0, "med_threshold": 0, "high_threshold": 0},
+        "flags": {
+            "auth_required": true,
+            "auth_revocable": true,
+            "auth_immutable": false,
+            "auth_clawback_enabled": false
+        },
+        "balances": [{
+            "balance": "4.9999600",
+            "buying_liabilities": "0.0000000",
+            "selling_liabilities": "0.0000000",
+            "asset_type": "native"
+        }],
+        "signers": [{
+            "weight": 1,
+            "key": "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE",
+            "type": "ed25519_public_key"
+        }],
+        "data": {},
+        "num_sponsoring": 0,
+        "num_sponsored": 0,
+        "paging_token": "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE"
+    }"#;
+}
+
+impl TestResponse for Operation {
+    const FIXTURE: &'static str = r#"{
+        "_links": {
+            "self": {"href": "https://horizon-testnet.stellar.org/operations/2314987376641"},
+            "transaction": {"href": "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"},
+            "effects": {"href": "https://horizon-testnet.stellar.org/operations/2314987376641/effects"},
+            "succeeds": {"href": "https://horizon-testnet.stellar.org/effects?order=desc&cursor=2314987376641"},
+            "precedes": {"href": "https://horizon-testnet.stellar.org/effects?order=asc&cursor=2314987376641"}
+        },
+        "id": "2314987376641",
+        "paging_token": "2314987376641",
+        "transaction_successful": true,
+        "source_account": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+        "type": "create_account",
+        "type_i": 0,
+        "created_at": "2024-06-11T21:36:12Z",
+        "transaction_hash": "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020",
+        "starting_balance": "10000000000.0000000",
+        "funder": "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H",
+        "account": "GDIGRW2H37U3O5WPMQFWGN35DDVZAYYTIMGLYVQI4XTATZBW4FXEATRE"
+    }"#;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let transaction = TransactionResponse::test_default();
+        assert_eq!(transaction.ledger().value(), 539);
+        assert!(transaction.ledger_entry_changes().is_ok());
+
+        assert_eq!(OfferResponse::test_default().id().to_string(), "27493");
+        assert_eq!(Account::test_default().balances().len(), 1);
+        assert_eq!(Operation::test_default().type_field(), "create_account");
+    }
+
+    #[test]
+    fn test_builder() {
+        let transaction = TransactionResponse::builder()
+            .set("successful", false)
+            .set("memo_type", "text")
+            .set("memo", "invoice 42")
+            .set("_links.self.href", "https://example.org")
+            .build()
+            .unwrap();
+        assert!(!transaction.successful());
+        assert_eq!(transaction.memo().as_deref(), Some("invoice 42"));
+        assert_eq!(
+            transaction.links().self_link().href().as_deref(),
+            Some("https://example.org")
+        );
+
+        let error = OfferResponse::builder()
+            .set("amount", 10)
+            .build()
+            .err()
+            .unwrap();
+        assert!(error.starts_with("failed to deserialize at `amount`"));
+    }
+}
@@ -147,6 +147,104 @@ pub mod test {
         assert_eq!(result.unwrap_err(), "Offset must be smaller than 24 hours.");
     }
 
+    #[test]
+    fn test_validate() {
+        let mut request = TradeAggregationsRequest::new()
+            .set_base_asset(AssetType::Alphanumeric4(AssetData {
+                asset_issuer: BASE_ASSET_ACCOUNT.to_string(),
+                asset_code: BASE_ASSET_CODE.to_string(),
+            }))
+            .unwrap()
+            .set_counter_asset(AssetType::Alphanumeric4(AssetData {
+                asset_issuer: COUNTER_ASSET_ACCOUNT.to_string(),
+                asset_code: COUNTER_ASSET_CODE.to_string(),
+            }))
+            .unwrap()
+            .set_resolution(Resolution(ResolutionData::Duration604800000))
+            .unwrap()
+            .set_offset(3600000)
+            .unwrap();
+        assert_eq!(request.validate(), Ok(()));
+
+        // Violations of fields that are set directly are all reported.
+        request.limit = Some(0);
+        request.offset = Some("one hour".to_string());
+        assert_eq!(
+            request.validate().unwrap_err(),
+            vec![
+                "Limit must be between 1 and 200.",
+                "Offset must be a number of milliseconds.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_range() {
+        let request = TradeAggregationsRequest::new()
+            .set_base_asset(AssetType::Native)
+            .unwrap()
+            .set_counter_asset(AssetType::Alphanumeric4(AssetData {
+                asset_issuer: COUNTER_ASSET_ACCOUNT.to_string(),
+                asset_code: COUNTER_ASSET_CODE.to_string(),
+            }))
+            .unwrap()
+            .set_resolution(Resolution(ResolutionData::Duration60000))
+            .unwrap();
+
+        // 450 minutes, starting 30 seconds into a minute, take three requests of at most 200
+        // minutes, with boundaries aligned to whole minutes.
+        let start_time = 1_717_200_030_000;
+        let end_time = start_time + 450 * 60_000;
+        let ranges: Vec<_> = request
+            .split_range(start_time, end_time)
+            .unwrap()
+            .iter()
+            .map(|request| (request.start_time.unwrap(), request.end_time.unwrap()))
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (start_time, 1_717_212_000_000),
+                (1_717_212_000_000, 1_717_224_000_000),
+                (1_717_224_000_000, end_time),
+            ]
+        );
+
+        assert!(request.split_range(end_time, start_time).is_err());
+    }
+
+    #[test]
+    fn test_typed_resolution() {
+        use crate::models::Request;
+
+        let start_time = crate::datetime::parse_rfc3339("2024-06-01T00:00:00Z").unwrap();
+        let end_time = crate::datetime::parse_rfc3339("2024-06-08T00:00:00Z").unwrap();
+        let request = TradeAggregationsRequest::new()
+            .set_base_asset(AssetType::Native)
+            .unwrap()
+            .set_counter_asset(AssetType::Alphanumeric4(AssetData {
+                asset_issuer: COUNTER_ASSET_ACCOUNT.to_string(),
+                asset_code: COUNTER_ASSET_CODE.to_string(),
+            }))
+            .unwrap()
+            .set_start_date_time(start_time)
+            .set_end_date_time(end_time)
+            .set_typed_resolution::<OneHour>()
+            .set_offset_hours::<1>()
+            .build();
+
+        assert_eq!(request.start_time, Some(1_717_200_000_000));
+        assert_eq!(request.end_date_time(), Some(end_time));
+        assert_eq!(
+            request.resolution,
+            Resolution(ResolutionData::Duration3600000)
+        );
+        assert_eq!(request.validate(), Ok(()));
+        assert!(request.get_query_parameters().ends_with(
+            "&resolution=3600000&start_time=1717200000000&end_time=1717804800000&offset=3600000"
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_trade_aggregations() {
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 /// It includes navigational links and embedded results.
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct AllTradeAggregationsResponse {
     #[serde(rename = "_links")]
     pub links: ResponseLinks,
@@ -17,7 +18,7 @@ pub struct AllTradeAggregationsResponse {
 
 impl Response for AllTradeAggregationsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
@@ -29,6 +30,7 @@ impl Response for AllTradeAggregationsResponse {
 /// in a precise manner.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct Ratio {
     /// The numenator.
     #[serde(rename = "n")]
@@ -44,6 +46,7 @@ pub struct Ratio {
 /// It includes navigational links, a timestamp, a trade count, and additional data.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct TradeAggregationResponse {
     // Start time for this trade aggregation. Represented as milliseconds since epoch.
     timestamp: String,
@@ -79,6 +82,6 @@ pub struct TradeAggregationResponse {
 
 impl Response for TradeAggregationResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
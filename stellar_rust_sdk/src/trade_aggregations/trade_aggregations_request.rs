@@ -1,32 +1,42 @@
+use crate::datetime::{self, DateTime};
 use crate::models::prelude::AssetType;
 use crate::{models::*, BuildQueryParametersExt};
 
+/// The maximum number of segments Horizon returns in a single response.
+const MAX_SEGMENTS: u8 = 200;
+
+/// One hour in milliseconds, the unit of the offset of a trade aggregation.
+const ONE_HOUR: u64 = 3600000;
+
+/// One day in milliseconds, the largest offset of a trade aggregation.
+const ONE_DAY: u64 = 86400000;
+
 /// Represents the base asset. Contains an enum of one of the possible asset types.
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct BaseAsset(AssetType);
 
 /// Represents the absence of a base asset.
-#[derive(PartialEq, Debug)]
+#[derive(Default, PartialEq, Debug, Eq, Hash)]
 pub struct NoBaseAsset;
 
 /// Represents the counter asset. Contains an enum of one of the possible asset types.
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct CounterAsset(AssetType);
 
 /// Represents the absence of a counter asset.
-#[derive(PartialEq, Debug)]
+#[derive(Default, PartialEq, Debug, Eq, Hash)]
 pub struct NoCounterAsset;
 
 /// Represents the absense of a resolution value.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoResolution;
 
 /// Represents the resolution value. It can contain a [`ResolutionData`] enum type.
-#[derive(PartialEq, Debug, Default, Clone)]
+#[derive(PartialEq, Debug, Default, Clone, Eq, Hash)]
 pub struct Resolution(pub ResolutionData);
 
 /// Represents the supported segment duration times in milliseconds.
-#[derive(PartialEq, Debug, Default, Clone)]
+#[derive(PartialEq, Debug, Default, Clone, Eq, Hash)]
 pub enum ResolutionData {
     #[default]
     Duration60000,
@@ -36,18 +46,93 @@ pub enum ResolutionData {
     Duration604800000,
 }
 
+impl ResolutionData {
+    /// Returns the segment duration in milliseconds.
+    pub const fn millis(&self) -> i64 {
+        match self {
+            ResolutionData::Duration60000 => 60000,
+            ResolutionData::Duration300000 => 300000,
+            ResolutionData::Duration900000 => 900000,
+            ResolutionData::Duration3600000 => 3600000,
+            ResolutionData::Duration604800000 => 604800000,
+        }
+    }
+}
+
 impl std::fmt::Display for ResolutionData {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             ResolutionData::Duration60000 => write!(f, "60000"), // 1 minute
             ResolutionData::Duration300000 => write!(f, "300000"), // 5 minutes
             ResolutionData::Duration900000 => write!(f, "900000"), // 15 minutes
-            ResolutionData::Duration3600000 => write!(f, "3600000"), // 1 day
+            ResolutionData::Duration3600000 => write!(f, "3600000"), // 1 hour
             ResolutionData::Duration604800000 => write!(f, "604800000"), // 1 week
         }
     }
 }
 
+/// A resolution that is known at compile time, for building a [`TradeAggregationsRequest`] of
+/// which the offset is validated by the compiler.
+///
+/// The resolution is set with [`TradeAggregationsRequest::set_typed_resolution`], after which an
+/// offset can only be set if the resolution implements [`SupportsOffset`], and only to a number of
+/// hours that fits in the resolution. [`TradeAggregationsRequest::build`] then turns the request
+/// into one with a [`Resolution`], which can be sent with the
+/// [`HorizonClient`](crate::horizon_client::HorizonClient).
+///
+pub trait TypedResolution: Default {
+    /// The resolution of the segments.
+    const RESOLUTION: ResolutionData;
+}
+
+/// A [`TypedResolution`] for which Horizon accepts an offset, which are the resolutions of at
+/// least one hour.
+pub trait SupportsOffset: TypedResolution {}
+
+/// The resolution of one minute, known at compile time.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OneMinute;
+
+/// The resolution of five minutes, known at compile time.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FiveMinutes;
+
+/// The resolution of fifteen minutes, known at compile time.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FifteenMinutes;
+
+/// The resolution of one hour, known at compile time.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OneHour;
+
+/// The resolution of one week, known at compile time.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OneWeek;
+
+impl TypedResolution for OneMinute {
+    const RESOLUTION: ResolutionData = ResolutionData::Duration60000;
+}
+
+impl TypedResolution for FiveMinutes {
+    const RESOLUTION: ResolutionData = ResolutionData::Duration300000;
+}
+
+impl TypedResolution for FifteenMinutes {
+    const RESOLUTION: ResolutionData = ResolutionData::Duration900000;
+}
+
+impl TypedResolution for OneHour {
+    const RESOLUTION: ResolutionData = ResolutionData::Duration3600000;
+}
+
+impl TypedResolution for OneWeek {
+    const RESOLUTION: ResolutionData = ResolutionData::Duration604800000;
+}
+
+impl SupportsOffset for OneHour {}
+
+impl SupportsOffset for OneWeek {}
+
 /// Represents a request to list trade aggregations from the Stellar Horizon API.
 ///
 /// This structure is used to construct a query to retrieve a comprehensive list of trade aggregations, which will be filtered
@@ -79,7 +164,7 @@ impl std::fmt::Display for ResolutionData {
 /// // Use with HorizonClient::get_trade_aggregations
 /// ```
 ///
-#[derive(Clone, Debug, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default, Eq, Hash)]
 pub struct TradeAggregationsRequest<B = NoBaseAsset, C = NoCounterAsset, R = NoResolution> {
     /// The base asset of the trade aggregation.
     pub base_asset: B,
@@ -104,16 +189,7 @@ pub struct TradeAggregationsRequest<B = NoBaseAsset, C = NoCounterAsset, R = NoR
 impl TradeAggregationsRequest<NoBaseAsset, NoCounterAsset, NoResolution> {
     /// Constructor with default values.
     pub fn new() -> Self {
-        TradeAggregationsRequest {
-            base_asset: NoBaseAsset,
-            counter_asset: NoCounterAsset,
-            resolution: NoResolution,
-            start_time: None,
-            end_time: None,
-            offset: None,
-            limit: None,
-            order: None,
-        }
+        TradeAggregationsRequest::default()
     }
 }
 
@@ -122,10 +198,8 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
     ///
     /// # Arguments
     ///
-    /// * `base_asset` - The base asset type to filter the trades. It can be one of the following:
-    ///     - `AssetType::Native`
-    ///     - `AssetType::Alphanumeric4(AssetData)`
-    ///     - `AssetType::Alphanumeric12(AssetData)`
+    /// * `base_asset` - The base asset to filter the trades, such as an [`AssetType`] or an
+    ///   [`Asset`].
     ///
     /// # Returns
     ///
@@ -133,10 +207,10 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
     ///    
     pub fn set_base_asset(
         self,
-        base_asset: AssetType,
+        base_asset: impl Into<AssetType>,
     ) -> Result<TradeAggregationsRequest<BaseAsset, C, R>, String> {
         Ok(TradeAggregationsRequest {
-            base_asset: BaseAsset(base_asset),
+            base_asset: BaseAsset(base_asset.into()),
             counter_asset: self.counter_asset,
             start_time: self.start_time,
             end_time: self.end_time,
@@ -151,10 +225,8 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
     ///
     /// # Arguments
     ///
-    /// * `counter_asset` - The counter asset type to filter the trades. It can be one of the following:
-    ///     - `AssetType::Native`
-    ///     - `AssetType::Alphanumeric4(AssetData)`
-    ///     - `AssetType::Alphanumeric12(AssetData)`
+    /// * `counter_asset` - The counter asset to filter the trades, such as an [`AssetType`] or an
+    ///   [`Asset`].
     ///
     /// # Returns
     ///
@@ -162,11 +234,11 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
     ///  
     pub fn set_counter_asset(
         self,
-        counter_asset: AssetType,
+        counter_asset: impl Into<AssetType>,
     ) -> Result<TradeAggregationsRequest<B, CounterAsset, R>, String> {
         Ok(TradeAggregationsRequest {
             base_asset: self.base_asset,
-            counter_asset: CounterAsset(counter_asset),
+            counter_asset: CounterAsset(counter_asset.into()),
             start_time: self.start_time,
             end_time: self.end_time,
             offset: self.offset,
@@ -202,6 +274,58 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
         })
     }
 
+    /// Specifies the resolution in the request as a type, so that the offset can be validated
+    /// at compile time.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::models::prelude::AssetType;
+    /// use stellar_rs::models::Request;
+    /// use stellar_rs::trade_aggregations::prelude::*;
+    ///
+    /// let request = TradeAggregationsRequest::new()
+    ///     .set_base_asset(AssetType::Native)?
+    ///     .set_counter_asset(AssetType::issued("USDC", "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5"))?
+    ///     .set_typed_resolution::<OneWeek>()
+    ///     .set_offset_hours::<2>()
+    ///     .build();
+    ///
+    /// assert!(request.get_query_parameters().contains("resolution=604800000"));
+    /// assert!(request.get_query_parameters().contains("offset=7200000"));
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    /// Resolutions of less than an hour do not take an offset, and the offset must fit in the
+    /// resolution, so neither of these requests compiles:
+    ///
+    /// ```compile_fail
+    /// # use stellar_rs::trade_aggregations::prelude::*;
+    /// let request = TradeAggregationsRequest::new()
+    ///     .set_typed_resolution::<FifteenMinutes>()
+    ///     .set_offset_hours::<1>();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use stellar_rs::trade_aggregations::prelude::*;
+    /// let request = TradeAggregationsRequest::new()
+    ///     .set_typed_resolution::<OneHour>()
+    ///     .set_offset_hours::<2>();
+    /// ```
+    ///
+    pub fn set_typed_resolution<T: TypedResolution>(self) -> TradeAggregationsRequest<B, C, T> {
+        TradeAggregationsRequest {
+            base_asset: self.base_asset,
+            counter_asset: self.counter_asset,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            // An offset that was set before is validated against the new resolution again.
+            offset: None,
+            resolution: T::default(),
+            limit: self.limit,
+            order: self.order,
+        }
+    }
+
     /// Specifies the start time in the request.
     ///
     /// # Arguments
@@ -222,6 +346,44 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
         Ok(Self { end_time, ..self })
     }
 
+    /// Specifies the start time in the request as a date and time, which is converted to
+    /// milliseconds since epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - The lower time boundary.
+    ///
+    pub fn set_start_date_time(self, start_time: DateTime) -> Self {
+        Self {
+            start_time: Some(datetime::unix_millis(&start_time)),
+            ..self
+        }
+    }
+
+    /// Specifies the end time in the request as a date and time, which is converted to
+    /// milliseconds since epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `end_time` - The upper time boundary.
+    ///
+    pub fn set_end_date_time(self, end_time: DateTime) -> Self {
+        Self {
+            end_time: Some(datetime::unix_millis(&end_time)),
+            ..self
+        }
+    }
+
+    /// Returns the start time of the request as a date and time, if it is set.
+    pub fn start_date_time(&self) -> Option<DateTime> {
+        self.start_time.and_then(datetime::from_unix_millis)
+    }
+
+    /// Returns the end time of the request as a date and time, if it is set.
+    pub fn end_date_time(&self) -> Option<DateTime> {
+        self.end_time.and_then(datetime::from_unix_millis)
+    }
+
     /// Specifies the maximum number of records to be returned.
     ///
     /// # Arguments
@@ -240,6 +402,16 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
         }
     }
 
+    /// Specifies the maximum number of records to be returned as 200, which is the largest page
+    /// size Horizon allows.
+    ///
+    pub fn set_max_limit(self) -> Self {
+        Self {
+            limit: Some(200),
+            ..self
+        }
+    }
+
     /// Specifies the order of records in the record.
     /// Valid options are [`Order::Asc`] (ascending)
     /// and [`Order::Desc`] (descending). If not specified, it defaults to ascending.    /// # Arguments
@@ -253,6 +425,17 @@ impl<B, C, R> TradeAggregationsRequest<B, C, R> {
             ..self
         })
     }
+
+    /// Clears the limit and order, so that the request returns the default number of records in
+    /// the default order again.
+    ///
+    pub fn reset_pagination(self) -> Self {
+        Self {
+            limit: None,
+            order: None,
+            ..self
+        }
+    }
 }
 
 impl<B, C> TradeAggregationsRequest<B, C, Resolution> {
@@ -274,27 +457,8 @@ impl<B, C> TradeAggregationsRequest<B, C, Resolution> {
     /// A `Result` containing either the updated `TradeAggregationsRequest` or an error.
     ///
     pub fn set_offset(self, offset: u64) -> Result<Self, String> {
-        const ONE_HOUR: &u64 = &360000;
-        const ONE_DAY: &u64 = &86400000;
-        let resolution = format!("{}", &self.resolution.0).parse::<u64>().unwrap();
-
-        let conditions = [
-            (
-                &resolution < ONE_HOUR,
-                "Resolution must be greater than 1 hour when setting offset.",
-            ),
-            (&offset % ONE_HOUR != 0, "Offset must be in whole hours."),
-            (
-                &offset > &resolution,
-                "Offset must be smaller than the resolution.",
-            ),
-            (&offset > ONE_DAY, "Offset must be smaller than 24 hours."),
-        ];
-
-        for (condition, message) in conditions {
-            if condition {
-                return Err(message.to_string());
-            }
+        if let Some(violation) = offset_violations(&self.resolution.0, offset).next() {
+            return Err(violation.to_string());
         }
 
         Ok(Self {
@@ -304,60 +468,200 @@ impl<B, C> TradeAggregationsRequest<B, C, Resolution> {
     }
 }
 
+impl<B, C, T: SupportsOffset> TradeAggregationsRequest<B, C, T> {
+    /// Sets the `offset` field in the request to a whole number of hours, which is checked
+    /// against the resolution at compile time.
+    ///
+    /// The offset can be at most the resolution, and at most 24 hours. Unlike
+    /// [`TradeAggregationsRequest::set_offset`], this method does not return a `Result`, because
+    /// an invalid offset does not compile.
+    ///
+    pub fn set_offset_hours<const HOURS: u64>(self) -> Self {
+        const {
+            assert!(
+                HOURS * ONE_HOUR <= T::RESOLUTION.millis() as u64,
+                "Offset must be smaller than the resolution."
+            );
+            assert!(
+                HOURS * ONE_HOUR <= ONE_DAY,
+                "Offset must be smaller than 24 hours."
+            );
+        }
+
+        Self {
+            offset: Some((HOURS * ONE_HOUR).to_string()),
+            ..self
+        }
+    }
+}
+
+impl<B, C, T: TypedResolution> TradeAggregationsRequest<B, C, T> {
+    /// Turns the request with a resolution known at compile time into a request with a
+    /// [`Resolution`], which can be sent with the
+    /// [`HorizonClient`](crate::horizon_client::HorizonClient).
+    pub fn build(self) -> TradeAggregationsRequest<B, C, Resolution> {
+        TradeAggregationsRequest {
+            base_asset: self.base_asset,
+            counter_asset: self.counter_asset,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            offset: self.offset,
+            resolution: Resolution(T::RESOLUTION),
+            limit: self.limit,
+            order: self.order,
+        }
+    }
+}
+
+/// Returns the rules for the offset of a trade aggregation that the given offset violates.
+fn offset_violations(
+    resolution: &ResolutionData,
+    offset: u64,
+) -> impl Iterator<Item = &'static str> {
+    let resolution = resolution.millis() as u64;
+
+    let conditions = [
+        (
+            resolution < ONE_HOUR,
+            "Resolution must be greater than 1 hour when setting offset.",
+        ),
+        (
+            !offset.is_multiple_of(ONE_HOUR),
+            "Offset must be in whole hours.",
+        ),
+        (
+            offset > resolution,
+            "Offset must be smaller than the resolution.",
+        ),
+        (offset > ONE_DAY, "Offset must be smaller than 24 hours."),
+    ];
+
+    conditions
+        .into_iter()
+        .filter(|(condition, _)| *condition)
+        .map(|(_, message)| message)
+}
+
+impl TradeAggregationsRequest<BaseAsset, CounterAsset, Resolution> {
+    /// Validates the whole request, and returns every violation instead of only the first.
+    ///
+    /// The setters reject invalid values one at a time, but the fields of the request are public,
+    /// and the assets are only checked by Horizon. `validate` checks the assets, including the
+    /// checksum of their issuers, the limit, the time range and the offset, and collects all
+    /// violations, so that they can be shown at once.
+    ///
+    /// # Example
+    /// ```
+    /// use stellar_rs::models::prelude::AssetType;
+    /// use stellar_rs::trade_aggregations::prelude::*;
+    ///
+    /// let mut request = TradeAggregationsRequest::new()
+    ///     .set_base_asset(AssetType::Native)?
+    ///     .set_counter_asset(AssetType::Native)?
+    ///     .set_resolution(Resolution(ResolutionData::Duration3600000))?;
+    /// request.start_time = Some(1_717_632_000_000);
+    /// request.end_time = Some(1_717_545_600_000);
+    /// request.offset = Some("5400000".to_string());
+    ///
+    /// assert_eq!(
+    ///     request.validate().unwrap_err(),
+    ///     vec![
+    ///         "Base asset and counter asset must differ.",
+    ///         "Start time must be before end time.",
+    ///         "Offset must be in whole hours.",
+    ///         "Offset must be smaller than the resolution.",
+    ///     ]
+    /// );
+    /// # Ok::<(), String>(())
+    /// ```
+    ///
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut checks = vec![
+            is_valid_asset(&self.base_asset.0),
+            is_valid_asset(&self.counter_asset.0),
+        ];
+        if self.base_asset.0 == self.counter_asset.0 {
+            checks.push(Err("Base asset and counter asset must differ.".to_string()));
+        }
+        if let Some(limit) = self.limit {
+            if !(1..=200).contains(&limit) {
+                checks.push(Err("Limit must be between 1 and 200.".to_string()));
+            }
+        }
+        if let (Some(start_time), Some(end_time)) = (self.start_time, self.end_time) {
+            if start_time >= end_time {
+                checks.push(Err("Start time must be before end time.".to_string()));
+            }
+        }
+        if let Some(offset) = &self.offset {
+            match offset.parse::<u64>() {
+                Ok(offset) => checks.extend(
+                    offset_violations(&self.resolution.0, offset)
+                        .map(|violation| Err(violation.to_string())),
+                ),
+                Err(_) => checks.push(Err("Offset must be a number of milliseconds.".to_string())),
+            }
+        }
+
+        collect_violations(checks)
+    }
+
+    /// Splits a time range into requests that each cover at most 200 segments, which is the
+    /// maximum number of records Horizon returns in a single response.
+    ///
+    /// The boundaries between the requests are aligned to the resolution, like the segments
+    /// themselves, so that no segment is split between two requests. Every request returns its
+    /// segments in ascending order.
+    ///
+    /// # Arguments
+    /// * `start_time` - The lower time boundary, as milliseconds since epoch.
+    /// * `end_time` - The upper time boundary, as milliseconds since epoch.
+    ///
+    pub(crate) fn split_range(&self, start_time: i64, end_time: i64) -> Result<Vec<Self>, String> {
+        if start_time >= end_time {
+            return Err("Start time must be before end time.".to_string());
+        }
+
+        let resolution = self.resolution.0.millis();
+        let span = resolution * i64::from(MAX_SEGMENTS);
+        // The first boundary after the start time that is aligned to the resolution.
+        let mut boundary = (start_time - start_time.rem_euclid(resolution)).saturating_add(span);
+
+        let mut requests = Vec::new();
+        let mut chunk_start = start_time;
+        while chunk_start < end_time {
+            let chunk_end = boundary.min(end_time);
+            requests.push(Self {
+                start_time: Some(chunk_start),
+                end_time: Some(chunk_end),
+                limit: Some(MAX_SEGMENTS),
+                order: Some(Order::Asc),
+                ..self.clone()
+            });
+            chunk_start = chunk_end;
+            boundary = boundary.saturating_add(span);
+        }
+
+        Ok(requests)
+    }
+}
+
 impl Request for TradeAggregationsRequest<BaseAsset, CounterAsset, Resolution> {
     fn get_query_parameters(&self) -> String {
-        let asset_parameters = vec![&self.base_asset.0, &self.counter_asset.0]
-            .iter()
-            .enumerate()
-            .fold(Vec::new(), |mut parameters, (i, asset)| {
-                let asset_type_prefix = if i == 0 {
-                    "base_asset_type="
-                }
-                // no `&` for `base_asset_type`, as the query begins with `?`
-                else {
-                    "&counter_asset_type="
-                };
-                match asset {
-                    AssetType::Native => parameters.push(format!("{}native", asset_type_prefix)),
-                    AssetType::Alphanumeric4(asset_data)
-                    | AssetType::Alphanumeric12(asset_data) => {
-                        let asset_type = match asset {
-                            AssetType::Alphanumeric4(_) => "credit_alphanum4",
-                            AssetType::Alphanumeric12(_) => "credit_alphanum12",
-                            _ => "", // should not be reached
-                        };
-                        let asset_issuer_prefix = if i == 0 {
-                            "&base_asset_issuer="
-                        } else {
-                            "&counter_asset_issuer="
-                        };
-                        let asset_code_prefix = if i == 0 {
-                            "&base_asset_code="
-                        } else {
-                            "&counter_asset_code="
-                        };
-                        parameters.push(format!(
-                            "{}{}{}{}{}{}",
-                            asset_type_prefix,
-                            asset_type,
-                            asset_code_prefix,
-                            asset_data.asset_code,
-                            asset_issuer_prefix,
-                            asset_data.asset_issuer
-                        ));
-                    }
-                }
-                parameters
-            })
-            .join("");
+        let asset_parameters = format!(
+            "{}&{}",
+            self.base_asset.0.to_query_parameters("base"),
+            self.counter_asset.0.to_query_parameters("counter")
+        );
 
-        vec![
+        [
             Some(asset_parameters),
             Some(format!("resolution={}", self.resolution.0)),
             self.start_time
                 .as_ref()
                 .map(|s| format!("start_time={}", s)),
             self.end_time.as_ref().map(|e| format!("end_time={}", e)),
+            self.offset.as_ref().map(|o| format!("offset={}", o)),
             self.limit.as_ref().map(|l| format!("limit={}", l)),
             self.order.as_ref().map(|o| format!("order={}", o)),
         ]
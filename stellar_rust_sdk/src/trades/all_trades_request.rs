@@ -1,10 +1,6 @@
+use crate::models::pagination::PageParams;
 use crate::models::prelude::AssetType;
-use crate::models::*;
-use stellar_rust_sdk_derive::pagination;
-
-/// Represents the base and counter assets. Contains an enum of one of the possible asset types.
-#[derive(PartialEq, Debug)]
-pub struct TradeAsset(AssetType);
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to list all trades from the Stellar Horizon API.
 ///
@@ -34,14 +30,20 @@ pub struct TradeAsset(AssetType);
 /// ```
 ///
 #[pagination]
-#[derive(PartialEq, Default)]
+#[derive(PartialEq, Default, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRADES_PATH)]
 pub struct AllTradesRequest {
     /// The base asset of the trade.
-    pub base_asset: Option<TradeAsset>,
+    #[horizon(asset)]
+    pub base_asset: Option<AssetType>,
     /// The counter asset of the trade.
-    pub counter_asset: Option<TradeAsset>,
+    #[horizon(asset)]
+    pub counter_asset: Option<AssetType>,
     // The offer ID. Used to filter for trades originating from a specific offer.
     pub offer_id: Option<String>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllTradesRequest {
@@ -54,17 +56,18 @@ impl AllTradesRequest {
     ///
     /// # Arguments
     ///
-    /// * `base_asset` - The base asset type to filter the trades. It can be one of the following:
-    ///     - `AssetType::Native`
-    ///     - `AssetType::Alphanumeric4(AssetData)`
-    ///     - `AssetType::Alphanumeric12(AssetData)`
+    /// * `base_asset` - The base asset to filter the trades, such as an [`AssetType`] or an
+    ///   [`Asset`].
     ///
     /// # Returns
     ///
-    /// The updated `AllTradesRequest` with the base asset set.    
-    pub fn set_base_asset(self, base_asset: AssetType) -> Result<AllTradesRequest, String> {
+    /// The updated `AllTradesRequest` with the base asset set.
+    pub fn set_base_asset(
+        self,
+        base_asset: impl Into<AssetType>,
+    ) -> Result<AllTradesRequest, String> {
         Ok(AllTradesRequest {
-            base_asset: Some(TradeAsset(base_asset)),
+            base_asset: Some(base_asset.into()),
             ..self
         })
     }
@@ -73,70 +76,43 @@ impl AllTradesRequest {
     ///
     /// # Arguments
     ///
-    /// * `counter_asset` - The counter asset type to filter the trades. It can be one of the following:
-    ///     - `AssetType::Native`
-    ///     - `AssetType::Alphanumeric4(AssetData)`
-    ///     - `AssetType::Alphanumeric12(AssetData)`
+    /// * `counter_asset` - The counter asset to filter the trades, such as an [`AssetType`] or an
+    ///   [`Asset`].
     ///
     /// # Returns
     ///
-    /// The updated `AllTradesRequest` with the counter asset set.    
-    pub fn set_counter_asset(self, counter_asset: AssetType) -> Result<AllTradesRequest, String> {
+    /// The updated `AllTradesRequest` with the counter asset set.
+    pub fn set_counter_asset(
+        self,
+        counter_asset: impl Into<AssetType>,
+    ) -> Result<AllTradesRequest, String> {
         Ok(AllTradesRequest {
-            counter_asset: Some(TradeAsset(counter_asset)),
+            counter_asset: Some(counter_asset.into()),
             ..self
         })
     }
 }
 
-impl Request for AllTradesRequest {
-    fn get_query_parameters(&self) -> String {
-        let mut query: Vec<String> = Vec::new();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Request;
 
-        if let Some(base_asset) = &self.base_asset {
-            match &base_asset.0 {
-                AssetType::Native => {
-                    query.push(format!("base_asset_type=native"));
-                }
-                AssetType::Alphanumeric4(asset) => {
-                    query.push(format!("base_asset_type=credit_alphanum4"));
-                    query.push(format!("&base_asset_code={}", asset.asset_code));
-                    query.push(format!("&base_asset_issuer={}", asset.asset_issuer));
-                }
-                AssetType::Alphanumeric12(asset) => {
-                    query.push(format!("base_asset_type=credit_alphanum12"));
-                    query.push(format!("&base_asset_code={}", asset.asset_code));
-                    query.push(format!("&base_asset_issuer={}", asset.asset_issuer));
-                }
-            }
-        }
-
-        if let Some(counter_asset) = &self.counter_asset {
-            match &counter_asset.0 {
-                AssetType::Native => {
-                    query.push(format!("&counter_asset_type=native"));
-                }
-                AssetType::Alphanumeric4(asset) => {
-                    query.push(format!("&counter_asset_type=credit_alphanum4"));
-                    query.push(format!("&counter_asset_code={}", asset.asset_code));
-                    query.push(format!("&counter_asset_issuer={}", asset.asset_issuer));
-                }
-                AssetType::Alphanumeric12(asset) => {
-                    query.push(format!("&counter_asset_type=credit_alphanum12"));
-                    query.push(format!("&counter_asset_code={}", asset.asset_code));
-                    query.push(format!("&counter_asset_issuer={}", asset.asset_issuer));
-                }
-            }
-        }
-        query.join("")
-    }
+    #[test]
+    fn test_build_url() {
+        let request = AllTradesRequest::new()
+            .set_base_asset(AssetType::Native)
+            .unwrap()
+            .set_counter_asset(AssetType::issued("USDC", "issuer"))
+            .unwrap()
+            .set_limit(2)
+            .unwrap();
 
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}?{}",
-            base_url,
-            super::TRADES_PATH,
-            self.get_query_parameters()
-        )
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/trades?base_asset_type=native\
+             &counter_asset_type=credit_alphanum4&counter_asset_code=USDC\
+             &counter_asset_issuer=issuer&limit=2"
+        );
     }
 }
@@ -91,7 +91,83 @@ pub mod prelude {
 
 #[cfg(test)]
 pub mod test {
-    use crate::{horizon_client::HorizonClient, trades::prelude::*};
+    use crate::{
+        datetime,
+        horizon_client::HorizonClient,
+        models::prelude::{AssetData, RationalPrice},
+        trades::prelude::*,
+    };
+
+    const TRADE_FIXTURE: &str = r#"{
+        "_links": {
+            "self": {"href": ""},
+            "base": {"href": "https://horizon-testnet.stellar.org/accounts/GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A"},
+            "counter": {"href": "https://horizon-testnet.stellar.org/liquidity_pools/0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3"},
+            "operation": {"href": "https://horizon-testnet.stellar.org/operations/23944442687489"}
+        },
+        "id": "23944442687489-0",
+        "paging_token": "23944442687489-0",
+        "ledger_close_time": "2024-06-12T04:58:59Z",
+        "trade_type": "liquidity_pool",
+        "liquidity_pool_fee_bp": 30,
+        "base_offer_id": "4612796834409107457",
+        "base_account": "GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A",
+        "base_amount": "3.6000000",
+        "base_asset_type": "credit_alphanum4",
+        "base_asset_code": "XETH",
+        "base_asset_issuer": "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI",
+        "counter_liquidity_pool_id": "0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3",
+        "counter_amount": "1.0800000",
+        "counter_asset_type": "native",
+        "base_is_seller": true,
+        "price": {"n": "10", "d": "4"}
+    }"#;
+
+    #[test]
+    fn test_trade_response_fixture() {
+        let response: TradeResponse = serde_json::from_str(TRADE_FIXTURE).unwrap();
+        assert_eq!(
+            response.ledger_close_time(),
+            &datetime::parse_rfc3339("2024-06-12T04:58:59Z").unwrap()
+        );
+        assert_eq!(response.base_offer_id(), &Some(4612796834409107457));
+        assert_eq!(response.counter_offer_id(), &None);
+        assert_eq!(response.base_amount(), &36_000_000);
+        assert_eq!(response.counter_amount(), &10_800_000);
+        assert_eq!(response.price().unwrap(), RationalPrice::new(5, 2).unwrap());
+        assert_eq!(
+            response.base_asset(),
+            TradeAsset::Issued(AssetData {
+                asset_code: "XETH".to_string(),
+                asset_issuer: "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI"
+                    .to_string(),
+            })
+        );
+        assert_eq!(
+            response.counter_asset(),
+            TradeAsset::LiquidityPool(
+                "0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3".to_string()
+            )
+        );
+
+        // Serializing the response yields the representation used by Horizon, with the price in
+        // its reduced form.
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["ledger_close_time"], "2024-06-12T04:58:59Z");
+        assert_eq!(json["base_offer_id"], "4612796834409107457");
+        assert_eq!(json["base_amount"], "3.6000000");
+        assert_eq!(json["price"], serde_json::json!({"n": "5", "d": "2"}));
+
+        let round_trip: TradeResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip.base_amount(), response.base_amount());
+        assert_eq!(round_trip.price(), response.price());
+    }
+
+    #[test]
+    fn test_trade_response_invalid_price() {
+        let json = TRADE_FIXTURE.replace(r#""n": "10""#, r#""n": "-1""#);
+        assert!(serde_json::from_str::<TradeResponse>(&json).is_err());
+    }
 
     #[tokio::test]
     async fn all_trades_request() {
@@ -104,22 +180,22 @@ pub mod test {
         const PAGING_TOKEN: &str = "23944442687489-0";
         const LEDGER_CLOSE_TIME: &str = "2024-06-12T04:58:59Z";
         const TRADE_TYPE: &str = "orderbook";
-        const BASE_OFFER_ID: &str = "20";
+        const BASE_OFFER_ID: &u64 = &20;
         const BASE_ACCOUNT: &str = "GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A";
-        const BASE_AMOUNT: &str = "3.6000000";
+        const BASE_AMOUNT: &i64 = &36_000_000;
         const BASE_ASSET_TYPE: &str = "credit_alphanum4";
         const BASE_ASSET_CODE: &str = "XETH";
         const BASE_ASSET_ISSUER: &str = "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
-        const COUNTER_OFFER_ID: &str = "21";
+        const COUNTER_OFFER_ID: &u64 = &21;
         const COUNTER_ACCOUNT: &str = "GBHRHA3KGRJBXBFER7VHI3WS5SKUXOP5TQ3YITVD7WJ2D3INGK62FZJR";
-        const COUNTER_AMOUNT: &str = "1.0800000";
+        const COUNTER_AMOUNT: &i64 = &10_800_000;
         const COUNTER_ASSET_TYPE: &str = "credit_alphanum4";
         const COUNTER_ASSET_CODE: &str = "XUSD";
         const COUNTER_ASSET_ISSUER: &str =
             "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
         const BASE_IS_SELLER: &bool = &true;
-        const PRICE_N: &str = "3";
-        const PRICE_R: &str = "10";
+        const PRICE_N: u64 = 3;
+        const PRICE_R: u64 = 10;
 
         let all_trades_request = AllTradesRequest::new();
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
@@ -143,7 +219,10 @@ pub mod test {
         );
         assert_eq!(response.id(), ID);
         assert_eq!(response.paging_token(), PAGING_TOKEN);
-        assert_eq!(response.ledger_close_time(), LEDGER_CLOSE_TIME);
+        assert_eq!(
+            response.ledger_close_time(),
+            &datetime::parse_rfc3339(LEDGER_CLOSE_TIME).unwrap()
+        );
         assert_eq!(response.trade_type(), TRADE_TYPE);
         assert_eq!(response.base_offer_id().as_ref().unwrap(), BASE_OFFER_ID);
         assert_eq!(response.base_account().as_ref().unwrap(), BASE_ACCOUNT);
@@ -182,8 +261,10 @@ pub mod test {
             COUNTER_ASSET_ISSUER
         );
         assert_eq!(response.base_is_seller(), BASE_IS_SELLER);
-        assert_eq!(response.price().as_ref().unwrap().numenator(), PRICE_N);
-        assert_eq!(response.price().as_ref().unwrap().denominator(), PRICE_R);
+        assert_eq!(
+            response.price().unwrap(),
+            RationalPrice::new(PRICE_N, PRICE_R).unwrap()
+        );
     }
 
     #[tokio::test]
@@ -198,22 +279,22 @@ pub mod test {
         const PAGING_TOKEN: &str = "23944442687489-0";
         const LEDGER_CLOSE_TIME: &str = "2024-06-12T04:58:59Z";
         const TRADE_TYPE: &str = "orderbook";
-        const BASE_OFFER_ID: &str = "20";
+        const BASE_OFFER_ID: &u64 = &20;
         const BASE_ACCOUNT: &str = "GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A";
-        const BASE_AMOUNT: &str = "3.6000000";
+        const BASE_AMOUNT: &i64 = &36_000_000;
         const BASE_ASSET_TYPE: &str = "credit_alphanum4";
         const BASE_ASSET_CODE: &str = "XETH";
         const BASE_ASSET_ISSUER: &str = "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
-        const COUNTER_OFFER_ID: &str = "21";
+        const COUNTER_OFFER_ID: &u64 = &21;
         const COUNTER_ACCOUNT: &str = "GBHRHA3KGRJBXBFER7VHI3WS5SKUXOP5TQ3YITVD7WJ2D3INGK62FZJR";
-        const COUNTER_AMOUNT: &str = "1.0800000";
+        const COUNTER_AMOUNT: &i64 = &10_800_000;
         const COUNTER_ASSET_TYPE: &str = "credit_alphanum4";
         const COUNTER_ASSET_CODE: &str = "XUSD";
         const COUNTER_ASSET_ISSUER: &str =
             "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
         const BASE_IS_SELLER: &bool = &true;
-        const PRICE_N: &str = "3";
-        const PRICE_R: &str = "10";
+        const PRICE_N: u64 = 3;
+        const PRICE_R: u64 = 10;
 
         let trades_for_account_request = TradesForAccountRequest::new()
             .set_account_id(ACCOUNT_ID)
@@ -241,7 +322,10 @@ pub mod test {
         );
         assert_eq!(response.id(), ID);
         assert_eq!(response.paging_token(), PAGING_TOKEN);
-        assert_eq!(response.ledger_close_time(), LEDGER_CLOSE_TIME);
+        assert_eq!(
+            response.ledger_close_time(),
+            &datetime::parse_rfc3339(LEDGER_CLOSE_TIME).unwrap()
+        );
         assert_eq!(response.trade_type(), TRADE_TYPE);
         assert_eq!(response.base_offer_id().as_ref().unwrap(), BASE_OFFER_ID);
         assert_eq!(response.base_account().as_ref().unwrap(), BASE_ACCOUNT);
@@ -280,8 +364,10 @@ pub mod test {
             COUNTER_ASSET_ISSUER
         );
         assert_eq!(response.base_is_seller(), BASE_IS_SELLER);
-        assert_eq!(response.price().as_ref().unwrap().numenator(), PRICE_N);
-        assert_eq!(response.price().as_ref().unwrap().denominator(), PRICE_R);
+        assert_eq!(
+            response.price().unwrap(),
+            RationalPrice::new(PRICE_N, PRICE_R).unwrap()
+        );
     }
 
     #[tokio::test]
@@ -300,20 +386,20 @@ pub mod test {
         const LIQUIDITY_POOL_FEE_BP: &u32 = &30;
         const BASE_LIQUIDITY_POOL_ID: &str =
             "0b3c88caa5aeada296646c1810893e3b04cba0426cff8ff6a63cf6f35cc7f5b3";
-        const BASE_AMOUNT: &str = "9.3486278";
+        const BASE_AMOUNT: &i64 = &93_486_278;
         const BASE_ASSET_TYPE: &str = "credit_alphanum12";
         const BASE_ASSET_CODE: &str = "FLUTTER";
         const BASE_ASSET_ISSUER: &str = "GCGTOQSNERFVVJ6Y7YZYDF3MTZIY63KIEFMKA26Q7YPV3AFYD2JSRNYN";
-        const COUNTER_OFFER_ID: &str = "4612796834409107457";
+        const COUNTER_OFFER_ID: &u64 = &4612796834409107457;
         const COUNTER_ACCOUNT: &str = "GAV5JC25XAB4ALRUDNPB6TZMHSNXFFONKGMLRKHBC5KYGXOGXEVE2BOW";
-        const COUNTER_AMOUNT: &str = "10.0000000";
+        const COUNTER_AMOUNT: &i64 = &100_000_000;
         const COUNTER_ASSET_TYPE: &str = "credit_alphanum4";
         const COUNTER_ASSET_CODE: &str = "SDK";
         const COUNTER_ASSET_ISSUER: &str =
             "GAGTRBIF75N7NUA37JGGJZKXIS4JJKTQERRFWTP5DN4SM4OC2T6QPMQB";
         const BASE_IS_SELLER: &bool = &true;
-        const PRICE_N: &str = "100000000";
-        const PRICE_D: &str = "93486278";
+        const PRICE_N: u64 = 100000000;
+        const PRICE_D: u64 = 93486278;
 
         let trades_for_liquidity_pool_request = TradesForLiquidityPoolRequest::new()
             .set_liquidity_pool_id(LIQUIDITY_POOL_ID)
@@ -341,7 +427,10 @@ pub mod test {
         );
         assert_eq!(response.id(), ID);
         assert_eq!(response.paging_token(), PAGING_TOKEN);
-        assert_eq!(response.ledger_close_time(), LEDGER_CLOSE_TIME);
+        assert_eq!(
+            response.ledger_close_time(),
+            &datetime::parse_rfc3339(LEDGER_CLOSE_TIME).unwrap()
+        );
         assert_eq!(response.trade_type(), TRADE_TYPE);
         assert_eq!(
             response.liquidity_pool_fee_bp().as_ref().unwrap(),
@@ -386,13 +475,15 @@ pub mod test {
             COUNTER_ASSET_ISSUER
         );
         assert_eq!(response.base_is_seller(), BASE_IS_SELLER);
-        assert_eq!(response.price().as_ref().unwrap().numenator(), PRICE_N);
-        assert_eq!(response.price().as_ref().unwrap().denominator(), PRICE_D);
+        assert_eq!(
+            response.price().unwrap(),
+            RationalPrice::new(PRICE_N, PRICE_D).unwrap()
+        );
     }
 
     #[tokio::test]
     async fn trades_for_offers_request() {
-        const OFFER_ID: &str = "0"; // ID for the request
+        const OFFER_ID: &str = "20"; // ID for the request
         const LINK_SELF: &str = "";
         const LINK_BASE: &str = "https://horizon-testnet.stellar.org/accounts/GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A";
         const LINK_COUNTER: &str = "https://horizon-testnet.stellar.org/accounts/GBHRHA3KGRJBXBFER7VHI3WS5SKUXOP5TQ3YITVD7WJ2D3INGK62FZJR";
@@ -402,21 +493,21 @@ pub mod test {
         const PAGING_TOKEN: &str = "23944442687489-0";
         const LEDGER_CLOSE_TIME: &str = "2024-06-12T04:58:59Z";
         const TRADE_TYPE: &str = "orderbook";
-        const BASE_OFFER_ID: &str = "20";
+        const BASE_OFFER_ID: &u64 = &20;
         const BASE_ACCOUNT: &str = "GCUOMNFW7YG55YHY5S5W7FE247PWODUDUZ4SOVZFEON47KZ7AXFG6D6A";
-        const BASE_AMOUNT: &str = "3.6000000";
+        const BASE_AMOUNT: &i64 = &36_000_000;
         const BASE_ASSET_TYPE: &str = "credit_alphanum4";
         const BASE_ASSET_CODE: &str = "XETH";
         const BASE_ASSET_ISSUER: &str = "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
         const COUNTER_ACCOUNT: &str = "GBHRHA3KGRJBXBFER7VHI3WS5SKUXOP5TQ3YITVD7WJ2D3INGK62FZJR";
-        const COUNTER_AMOUNT: &str = "1.0800000";
+        const COUNTER_AMOUNT: &i64 = &10_800_000;
         const COUNTER_ASSET_TYPE: &str = "credit_alphanum4";
         const COUNTER_ASSET_CODE: &str = "XUSD";
         const COUNTER_ASSET_ISSUER: &str =
             "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
         const BASE_IS_SELLER: &bool = &true;
-        const PRICE_N: &str = "3";
-        const PRICE_D: &str = "10";
+        const PRICE_N: u64 = 3;
+        const PRICE_D: u64 = 10;
 
         let trades_for_offer_request = TradesForOfferRequest::new()
             .set_offer_id(OFFER_ID)
@@ -444,7 +535,10 @@ pub mod test {
         );
         assert_eq!(response.id(), ID);
         assert_eq!(response.paging_token(), PAGING_TOKEN);
-        assert_eq!(response.ledger_close_time(), LEDGER_CLOSE_TIME);
+        assert_eq!(
+            response.ledger_close_time(),
+            &datetime::parse_rfc3339(LEDGER_CLOSE_TIME).unwrap()
+        );
         assert_eq!(response.trade_type(), TRADE_TYPE);
         assert_eq!(response.base_offer_id().as_ref().unwrap(), BASE_OFFER_ID);
         assert_eq!(response.base_account().as_ref().unwrap(), BASE_ACCOUNT);
@@ -479,7 +573,9 @@ pub mod test {
             COUNTER_ASSET_ISSUER
         );
         assert_eq!(response.base_is_seller(), BASE_IS_SELLER);
-        assert_eq!(response.price().as_ref().unwrap().numenator(), PRICE_N);
-        assert_eq!(response.price().as_ref().unwrap().denominator(), PRICE_D);
+        assert_eq!(
+            response.price().unwrap(),
+            RationalPrice::new(PRICE_N, PRICE_D).unwrap()
+        );
     }
 }
@@ -1,4 +1,9 @@
+use crate::datetime::DateTime;
 use crate::models::prelude::*;
+use crate::models::serde_adapters::{option_price_fraction, option_u64_string, stroops};
+
+#[allow(deprecated)]
+pub use crate::compat::Price;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +13,7 @@ use serde::{Deserialize, Serialize};
 /// It includes navigational links and embedded results.
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct AllTradesResponse {
     #[serde(rename = "_links")]
     pub links: ResponseLinks,
@@ -17,7 +23,7 @@ pub struct AllTradesResponse {
 
 impl Response for AllTradesResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
@@ -29,6 +35,7 @@ impl Response for AllTradesResponse {
 /// base asset, counter asset and operation.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct TradeResponseLinks {
     #[serde(rename = "self")]
     self_link: Link,
@@ -37,19 +44,42 @@ pub struct TradeResponseLinks {
     operation: Link,
 }
 
-/// Represents the precise buy and sell price of the trade.
+/// Represents the asset on one side of a trade.
 ///
-/// This struct contains a numenator and a denominator, so that the price ratio can be determined
-/// in a precise manner.
+/// Combines the asset type, code, issuer and liquidity pool ID of a side of a [`TradeResponse`]
+/// into a single value, as returned by [`TradeResponse::base_asset`] and
+/// [`TradeResponse::counter_asset`].
 ///
-#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
-pub struct Price {
-    /// The numenator.
-    #[serde(rename = "n")]
-    numenator: String,
-    /// The denominator.
-    #[serde(rename = "d")]
-    denominator: String,
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TradeAsset {
+    /// The native asset (XLM).
+    Native,
+    /// An asset issued by an account.
+    Issued(AssetData),
+    /// The shares of a liquidity pool, identified by its ID, for a side that was executed
+    /// against the pool.
+    LiquidityPool(String),
+}
+
+impl TradeAsset {
+    /// Converts the asset into the shared [`AssetType`], choosing `Alphanumeric4` or
+    /// `Alphanumeric12` based on the length of the asset code.
+    ///
+    /// # Returns
+    /// The `AssetType`, or `None` for the shares of a liquidity pool, which are not an asset
+    /// that can be traded on the order book.
+    ///
+    pub fn to_asset_type(&self) -> Option<AssetType> {
+        match self {
+            TradeAsset::Native => Some(AssetType::Native),
+            TradeAsset::Issued(asset_data) => Some(AssetType::issued(
+                asset_data.asset_code.as_str(),
+                asset_data.asset_issuer.as_str(),
+            )),
+            TradeAsset::LiquidityPool(_) => None,
+        }
+    }
 }
 
 /// Represents the response for a single trade query in the Horizon API.
@@ -58,6 +88,7 @@ pub struct Price {
 /// It includes navigational links, the id, the base asset, the counter asset, and additional data.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct TradeResponse {
     /// Navigational links related to the trade.
     #[serde(rename = "_links")]
@@ -66,8 +97,13 @@ pub struct TradeResponse {
     id: String,
     /// A pointer to a specific location in a collection of responses, used for pagination control.
     paging_token: String,
-    // An ISO 8601 formatted string of when the ledger with this trade was closed.
-    ledger_close_time: String,
+    // When the ledger with this trade was closed.
+    #[serde(with = "crate::datetime::rfc3339")]
+    ledger_close_time: DateTime,
+    // The ID of the offer that was taken, only present for orderbook trades. Deprecated by Horizon
+    // in favor of `base_offer_id` and `counter_offer_id`.
+    #[serde(with = "option_u64_string", default)]
+    offer_id: Option<u64>,
     // Can be set to `all`, `orderbook`, or `liquidity_pools` to filter only trades executed across a given mechanism.
     trade_type: String,
     // The value for the liquidity pool fee's base points.
@@ -75,11 +111,14 @@ pub struct TradeResponse {
     // The base liquidity pool ID, if this trade was executed against a liquidity pool.
     base_liquidity_pool_id: Option<String>,
     // The base offer ID.
-    base_offer_id: Option<String>,
+    #[serde(with = "option_u64_string", default)]
+    base_offer_id: Option<u64>,
     // The account ID of the base party for this trade.
     base_account: Option<String>,
-    // The amount of the base asset that was moved from `base_account` to `counter_account`.
-    base_amount: String,
+    // The amount of the base asset that was moved from `base_account` to `counter_account`, in
+    // stroops.
+    #[serde(with = "stroops")]
+    base_amount: i64,
     // The type for the base asset. Either `native`, `credit_alphanum4`, or `credit_alphanum12`.
     base_asset_type: Option<String>,
     // The code for the base asset.
@@ -89,11 +128,14 @@ pub struct TradeResponse {
     // The counter liquidity pool ID, if this trade was executed against a liquidity pool.
     counter_liquidity_pool_id: Option<String>,
     // The counter offer ID. Optional.
-    counter_offer_id: Option<String>,
+    #[serde(with = "option_u64_string", default)]
+    counter_offer_id: Option<u64>,
     // The account ID of the counter party for this trade.
     counter_account: Option<String>,
-    // The amount of the counter asset that was moved from `counter_account` to `base_account`.
-    counter_amount: String,
+    // The amount of the counter asset that was moved from `counter_account` to `base_account`, in
+    // stroops.
+    #[serde(with = "stroops")]
+    counter_amount: i64,
     // The type for the counter asset. Either `native`, `credit_alphanum4`, or `credit_alphanum12`.
     counter_asset_type: Option<String>,
     // The code for the counter asset.
@@ -102,12 +144,56 @@ pub struct TradeResponse {
     counter_asset_issuer: Option<String>,
     // Indicates with party is the seller.
     base_is_seller: bool,
-    // An object of a number numerator and number denominator that represents the original offer price.
-    price: Option<Price>,
+    // The original offer price, as an exact fraction.
+    #[serde(with = "option_price_fraction", default)]
+    price: Option<RationalPrice>,
+}
+
+impl TradeResponse {
+    /// Returns the asset on the base side of the trade.
+    pub fn base_asset(&self) -> TradeAsset {
+        trade_asset(
+            &self.base_liquidity_pool_id,
+            &self.base_asset_type,
+            &self.base_asset_code,
+            &self.base_asset_issuer,
+        )
+    }
+
+    /// Returns the asset on the counter side of the trade.
+    pub fn counter_asset(&self) -> TradeAsset {
+        trade_asset(
+            &self.counter_liquidity_pool_id,
+            &self.counter_asset_type,
+            &self.counter_asset_code,
+            &self.counter_asset_issuer,
+        )
+    }
+}
+
+/// Combines the fields describing one side of a trade into a [`TradeAsset`]. A side that was
+/// executed against a liquidity pool is represented by the pool.
+fn trade_asset(
+    liquidity_pool_id: &Option<String>,
+    asset_type: &Option<String>,
+    asset_code: &Option<String>,
+    asset_issuer: &Option<String>,
+) -> TradeAsset {
+    if let Some(liquidity_pool_id) = liquidity_pool_id {
+        return TradeAsset::LiquidityPool(liquidity_pool_id.clone());
+    }
+
+    match asset_type.as_deref() {
+        Some("native") => TradeAsset::Native,
+        _ => TradeAsset::Issued(AssetData {
+            asset_code: asset_code.clone().unwrap_or_default(),
+            asset_issuer: asset_issuer.clone().unwrap_or_default(),
+        }),
+    }
 }
 
 impl Response for TradeResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
     }
 }
@@ -1,19 +1,31 @@
+use crate::models::pagination::PageParams;
 use crate::models::*;
-use stellar_rust_sdk_derive::pagination;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of an account for which the trades are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct TradeAccountId(String);
 
+impl fmt::Display for TradeAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Represents the absence of the ID of an account for which the trades are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTradeAccountId;
 
 #[pagination]
-#[derive(Default)]
-pub struct TradesForAccountRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRADES_PATH, parent = account_id)]
+pub struct TradesForAccountRequest<I = NoTradeAccountId> {
     /// The ID of the account for which the trades are to be retrieved.
     account_id: I,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl TradesForAccountRequest<NoTradeAccountId> {
@@ -25,48 +37,62 @@ impl TradesForAccountRequest<NoTradeAccountId> {
     /// Sets the account ID for the request.
     ///
     /// # Arguments
-    /// * `account_id` - The account ID for which the trades are to be retrieved.
+    /// * `account_id` - The account ID for which the trades are to be retrieved. A muxed account
+    ///   (`M...`) is resolved to its underlying account.
     ///
     /// # Returns
     /// A `TradesForAccountRequest` with the specified account ID, or an error if the account ID is invalid.
     ///
     pub fn set_account_id(
         self,
-        account_id: impl Into<String>
+        account_id: impl Into<String>,
     ) -> Result<TradesForAccountRequest<TradeAccountId>, String> {
-        let account_id = account_id.into();
+        let account_id = resolve_account_id(account_id.into());
         if let Err(e) = is_public_key(&account_id) {
             return Err(e.to_string());
         }
 
         Ok(TradesForAccountRequest {
             account_id: TradeAccountId(account_id),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
 
-impl Request for TradesForAccountRequest<TradeAccountId> {
-    fn get_query_parameters(&self) -> String {
-        let mut query = String::new();
-        query.push_str(&format!("{}", self.account_id.0));
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::pagination::Paginatable;
+
+    #[test]
+    fn test_pagination_across_typestates() {
+        let request = TradesForAccountRequest::new()
+            .set_cursor(1234)
+            .unwrap()
+            .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
 
-        query.trim_end_matches('&').to_string()
+        assert_eq!(request.cursor(), Some(1234));
+        assert_eq!(request.limit(), None);
+        assert_eq!(request.order(), Some(&Order::Desc));
+        assert_eq!(request.page_params().cursor(), Some(1234));
     }
 
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL is not built with query paramaters, but with the account ID as addition to the path.
-        // Therefore there is no `?` but a `/` in the formatted string.
-        // Additionally, this request uses the API endpoint for `accounts`.
-        use crate::accounts::ACCOUNTS_PATH;
-        format!(
-            "{}/{}/{}/{}",
-            base_url,
-            ACCOUNTS_PATH,
-            self.get_query_parameters(),
-            super::TRADES_PATH
-        )
+    #[test]
+    fn test_build_url() {
+        let request = TradesForAccountRequest::new()
+            .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")
+            .unwrap()
+            .set_limit(10)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/accounts/GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H/trades?limit=10&order=desc"
+        );
     }
 }
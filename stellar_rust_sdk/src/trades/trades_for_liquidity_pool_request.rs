@@ -1,22 +1,33 @@
-use crate::models::*;
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of a liquidity pool for which the trades are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct TradeLiquidityPoolId(String);
 
+impl fmt::Display for TradeLiquidityPoolId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Represents the absence of an ID of a liquidity pool for which the trades are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTradeLiquidityPoolId;
 
 #[pagination]
-#[derive(Default)]
-pub struct TradesForLiquidityPoolRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRADES_PATH, parent = liquidity_pool_id)]
+pub struct TradesForLiquidityPoolRequest<I = NoTradeLiquidityPoolId> {
     /// The ID of the liquidity pool for which the trades are to be retrieved.
     liquidity_pool_id: I,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
-impl TradesForLiquidityPoolRequest<TradeLiquidityPoolId> {
+impl TradesForLiquidityPoolRequest<NoTradeLiquidityPoolId> {
     /// Creates a new `TradesForLiquidityPoolRequest` with default parameters.
     pub fn new() -> Self {
         TradesForLiquidityPoolRequest::default()
@@ -36,32 +47,7 @@ impl TradesForLiquidityPoolRequest<TradeLiquidityPoolId> {
     ) -> Result<TradesForLiquidityPoolRequest<TradeLiquidityPoolId>, String> {
         Ok(TradesForLiquidityPoolRequest {
             liquidity_pool_id: TradeLiquidityPoolId(liquidity_pool_id.into()),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
-
-impl Request for TradesForLiquidityPoolRequest<TradeLiquidityPoolId> {
-    fn get_query_parameters(&self) -> String {
-        let mut query = String::new();
-        query.push_str(&format!("{}", self.liquidity_pool_id.0));
-
-        query.trim_end_matches('&').to_string()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL is not built with query paramaters, but with the liquidity pool's ID as addition to the path.
-        // Therefore there is no `?` but a `/` in the formatted string.
-        // Additionally, this request uses the API endpoint for `liquidity_pools`.
-        use crate::liquidity_pools::LIQUIDITY_POOLS_PATH;
-        format!(
-            "{}/{}/{}/{}",
-            base_url,
-            LIQUIDITY_POOLS_PATH,
-            self.get_query_parameters(),
-            super::TRADES_PATH
-        )
-    }
-}
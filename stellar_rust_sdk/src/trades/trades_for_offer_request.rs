@@ -1,22 +1,26 @@
-use crate::models::*;
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::offers::prelude::OfferId;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of an offer for which the trades are to be retrieved.
-#[derive(Default, Clone)]
-pub struct TradeOfferId(String);
+pub type TradeOfferId = OfferId;
 
 /// Represents the absence of an ID of an offer for which the trades are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTradeOfferId;
 
 #[pagination]
-#[derive(Default)]
-pub struct TradesForOfferRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRADES_PATH, parent = offer_id)]
+pub struct TradesForOfferRequest<I = NoTradeOfferId> {
     /// The ID of the offer for which the trades are to be retrieved.
     offer_id: I,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
-impl TradesForOfferRequest<TradeOfferId> {
+impl TradesForOfferRequest<NoTradeOfferId> {
     /// Creates a new `TradesForOfferRequest` with default parameters.
     pub fn new() -> Self {
         TradesForOfferRequest::default()
@@ -35,33 +39,8 @@ impl TradesForOfferRequest<TradeOfferId> {
         offer_id: impl Into<String>,
     ) -> Result<TradesForOfferRequest<TradeOfferId>, String> {
         Ok(TradesForOfferRequest {
-            offer_id: TradeOfferId(offer_id.into()),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            offer_id: offer_id.into().parse()?,
+            page_params: self.page_params,
         })
     }
 }
-
-impl Request for TradesForOfferRequest<TradeOfferId> {
-    fn get_query_parameters(&self) -> String {
-        let mut query = String::new();
-        query.push_str(&format!("{}", self.offer_id.0));
-
-        query.trim_end_matches('&').to_string()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL is not built with query paramaters, but with the offer's ID as addition to the path.
-        // Therefore there is no `?` but a `/` in the formatted string.
-        // Additionally, this request uses the API endpoint for `offers`.
-        use crate::offers::OFFERS_PATH;
-        format!(
-            "{}/{}/{}/{}",
-            base_url,
-            OFFERS_PATH,
-            self.get_query_parameters(),
-            super::TRADES_PATH
-        )
-    }
-}
@@ -1,5 +1,5 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents a request to list all transactions from the Stellar Horizon API.
 ///
@@ -17,10 +17,10 @@ use stellar_rust_sdk_derive::pagination;
 /// # Example
 /// ```
 /// use stellar_rs::transactions::all_transactions_request::AllTransactionsRequest;
-/// use stellar_rs::models::{Order, IncludeFailed};
+/// use stellar_rs::models::Order;
 ///
 /// let request = AllTransactionsRequest::new()
-///     .set_include_failed(IncludeFailed::True).unwrap() // Optional flag to include failed transactions
+///     .set_include_failed(true).unwrap() // Optional flag to include failed transactions
 ///     .set_cursor(123).unwrap() // Optional cursor for pagination
 ///     .set_limit(100).unwrap() // Optional limit for response records
 ///     .set_order(Order::Desc); // Optional order of records
@@ -29,33 +29,14 @@ use stellar_rust_sdk_derive::pagination;
 /// ```
 ///
 #[pagination]
-#[derive(Default)]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRANSACTIONS_PATH)]
 pub struct AllTransactionsRequest {
     // Indicates whether or not to include failed operations in the response.
-    include_failed: Option<IncludeFailed>,
-}
-
-impl Request for AllTransactionsRequest {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-        ]
-        .build_query_parameters()
-    }
+    include_failed: Option<bool>,
 
-    fn build_url(&self, base_url: &str) -> String {
-        format!(
-            "{}/{}{}",
-            base_url,
-            super::TRANSACTIONS_PATH,
-            self.get_query_parameters()
-        )
-    }
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl AllTransactionsRequest {
@@ -70,11 +51,67 @@ impl AllTransactionsRequest {
     /// * `include_failed` (bool) - when set to `true`, failed operations will be included.
     pub fn set_include_failed(
         self,
-        include_failed: IncludeFailed,
+        include_failed: bool,
     ) -> Result<AllTransactionsRequest, String> {
         Ok(AllTransactionsRequest {
             include_failed: Some(include_failed),
             ..self
         })
     }
+
+    /// Sets the paging token of the transaction to resume listing after.
+    ///
+    /// Unlike `set_cursor`, this accepts the paging token as the string returned by Horizon. The
+    /// paging token of a known transaction can be retrieved using
+    /// [`HorizonClient::cursor_for_transaction`](crate::horizon_client::HorizonClient::cursor_for_transaction).
+    /// It replaces the cursor of the request.
+    ///
+    /// # Arguments
+    /// * `paging_token` - The paging token of the transaction to resume after.
+    ///
+    pub fn set_resume_after(
+        self,
+        paging_token: impl Into<String>,
+    ) -> Result<AllTransactionsRequest, String> {
+        let paging_token = paging_token.into();
+        // `parse` accepts a leading `+`, which is not part of a paging token.
+        let cursor = paging_token
+            .parse()
+            .ok()
+            .filter(|_| paging_token.chars().all(|c| c.is_ascii_digit()))
+            .ok_or_else(|| format!("Invalid paging token: {}", paging_token))?;
+
+        let mut request = self;
+        request.page_params.set_cursor(cursor)?;
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Order, Request};
+
+    #[test]
+    fn test_set_resume_after() {
+        let request = AllTransactionsRequest::new()
+            .set_cursor(1)
+            .unwrap()
+            .set_resume_after("1984678453059584")
+            .unwrap()
+            .set_order(Order::Asc)
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/transactions?cursor=1984678453059584&order=asc"
+        );
+        assert_eq!(
+            AllTransactionsRequest::new()
+                .set_resume_after("12abc")
+                .err()
+                .unwrap(),
+            "Invalid paging token: 12abc"
+        );
+    }
 }
@@ -0,0 +1,331 @@
+use super::transaction_hash::{hash_envelope, TransactionHash};
+use crate::models::strkey::StrKey;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use stellar_xdr::curr::{self as xdr, Limits, ReadXdr, WriteXdr};
+
+/// Builds a fee bump transaction, as introduced in Protocol 13 (CAP-15).
+///
+/// A fee bump transaction wraps an already signed transaction, the inner transaction, and lets
+/// another account, the fee source, pay a higher fee for it. This allows a transaction that is
+/// stuck in the queue to be resubmitted with a higher fee, without invalidating the signatures of
+/// the inner transaction.
+///
+/// The fee of a fee bump transaction is the total fee for the inner transaction and the fee bump
+/// itself. Its fee rate, the fee divided by the number of operations of the inner transaction plus
+/// one, must be at least the fee rate of the inner transaction.
+///
+/// The result of [`to_envelope_xdr`](FeeBumpTransaction::to_envelope_xdr) can be submitted with
+/// [`PostTransactionRequest::set_transaction_envelope_xdr`](crate::transactions::prelude::PostTransactionRequest::set_transaction_envelope_xdr).
+///
+/// # Example
+/// ```
+/// use stellar_rs::network::Network;
+/// use stellar_rs::transactions::prelude::*;
+///
+/// # let inner_envelope_xdr = "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAAAGQAAAIbAAAQAQAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAANBo20ff6bd2z2QLYzd9GOuQYxNDDLxWCOXmCeQ24W5AAAAAF0h26AAAAAAAAAAAAA==";
+/// let fee_bump = FeeBumpTransaction::new(inner_envelope_xdr)
+///     .unwrap()
+///     .set_fee_source("GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO")
+///     .unwrap()
+///     .set_fee(1000)
+///     .unwrap();
+///
+/// let hash = fee_bump.hash(Network::Testnet.passphrase()).unwrap();
+/// let request = PostTransactionRequest::new()
+///     .set_transaction_envelope_xdr(fee_bump.to_envelope_xdr().unwrap())
+///     .unwrap();
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeBumpTransaction {
+    /// The envelope of the inner transaction, including its signatures.
+    inner: xdr::TransactionV1Envelope,
+    /// The account that pays the fee of the fee bump transaction.
+    fee_source: Option<xdr::MuxedAccount>,
+    /// The total fee, in stroops, for the inner transaction and the fee bump.
+    fee: Option<i64>,
+    /// The signatures of the fee bump transaction.
+    signatures: Vec<xdr::DecoratedSignature>,
+}
+
+impl FeeBumpTransaction {
+    /// Creates a new `FeeBumpTransaction` that wraps the given transaction.
+    ///
+    /// # Arguments
+    /// * `inner_envelope_xdr` - The base64-encoded XDR of the envelope of the inner transaction.
+    ///
+    /// # Returns
+    /// The fee bump transaction without a fee source and fee, or an error if the envelope could not
+    /// be decoded, or if it is not the envelope of a V1 transaction. A fee bump transaction can not
+    /// wrap a legacy (V0) transaction, nor another fee bump transaction.
+    ///
+    pub fn new(inner_envelope_xdr: &str) -> Result<Self, String> {
+        let envelope =
+            xdr::TransactionEnvelope::from_xdr_base64(inner_envelope_xdr, Limits::none())
+                .map_err(|e| format!("Invalid transaction envelope XDR: {}", e))?;
+
+        match envelope {
+            xdr::TransactionEnvelope::Tx(inner) => Ok(Self {
+                inner,
+                fee_source: None,
+                fee: None,
+                signatures: Vec::new(),
+            }),
+            xdr::TransactionEnvelope::TxV0(_) => {
+                Err("A fee bump transaction can not wrap a V0 transaction.".to_string())
+            }
+            xdr::TransactionEnvelope::TxFeeBump(_) => {
+                Err("A fee bump transaction can not wrap another fee bump transaction.".to_string())
+            }
+        }
+    }
+
+    /// Sets the account that pays the fee of the fee bump transaction.
+    ///
+    /// # Arguments
+    /// * `fee_source` - The public key (`G...`) or muxed account (`M...`) of the fee source.
+    ///
+    /// # Returns
+    /// The updated fee bump transaction, or an error if the fee source is not a valid account.
+    ///
+    pub fn set_fee_source(self, fee_source: &str) -> Result<Self, String> {
+        let fee_source = match StrKey::decode(fee_source)? {
+            StrKey::PublicKey(public_key) => xdr::MuxedAccount::Ed25519(xdr::Uint256(public_key)),
+            StrKey::MuxedAccount { public_key, id } => {
+                xdr::MuxedAccount::MuxedEd25519(xdr::MuxedAccountMed25519 {
+                    id,
+                    ed25519: xdr::Uint256(public_key),
+                })
+            }
+            _ => return Err("Fee source must be a public key or a muxed account.".to_string()),
+        };
+
+        Ok(Self {
+            fee_source: Some(fee_source),
+            signatures: Vec::new(),
+            ..self
+        })
+    }
+
+    /// Sets the total fee of the fee bump transaction.
+    ///
+    /// # Arguments
+    /// * `fee` - The total fee, in stroops, for the inner transaction and the fee bump.
+    ///
+    /// # Returns
+    /// The updated fee bump transaction, or an error if the fee rate of the fee bump transaction
+    /// would be lower than the fee rate of the inner transaction.
+    ///
+    pub fn set_fee(self, fee: i64) -> Result<Self, String> {
+        let min_fee = self.min_fee();
+        if fee < min_fee {
+            return Err(format!("Fee must be at least {} stroops.", min_fee));
+        }
+
+        Ok(Self {
+            fee: Some(fee),
+            signatures: Vec::new(),
+            ..self
+        })
+    }
+
+    /// Returns the lowest fee, in stroops, that can be set on this fee bump transaction.
+    ///
+    /// This is the fee rate of the inner transaction, multiplied by the number of operations of
+    /// the inner transaction plus one for the fee bump itself.
+    ///
+    pub fn min_fee(&self) -> i64 {
+        let operation_count = self.inner.tx.operations.len().max(1) as i64;
+        let inner_fee_rate = i64::from(self.inner.tx.fee) / operation_count;
+        inner_fee_rate * (operation_count + 1)
+    }
+
+    /// Signs the fee bump transaction with the key of the fee source.
+    ///
+    /// Setting the fee source or the fee afterwards discards the signatures, as they no longer
+    /// match the transaction.
+    ///
+    /// # Arguments
+    /// * `secret_seed` - The secret seed (`S...`) of a signer of the fee source.
+    /// * `network_passphrase` - The passphrase of the network the transaction is submitted to,
+    ///   such as [`Network::Testnet.passphrase()`](crate::network::Network::passphrase).
+    ///
+    /// # Returns
+    /// The signed fee bump transaction, or an error if the secret seed is invalid, or if the fee
+    /// source or the fee is not set.
+    ///
+    pub fn sign(mut self, secret_seed: &str, network_passphrase: &str) -> Result<Self, String> {
+        let seed = match StrKey::decode(secret_seed)? {
+            StrKey::SecretSeed(seed) => seed,
+            _ => return Err("Signing key must be a secret seed.".to_string()),
+        };
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&seed)
+            .map_err(|_| "Invalid secret seed.".to_string())?;
+
+        let hash = hash_envelope(self.build_envelope()?, network_passphrase)?;
+        let public_key = key_pair.public_key().as_ref();
+        let mut hint = [0; 4];
+        hint.copy_from_slice(&public_key[public_key.len() - 4..]);
+        let signature = key_pair.sign(&hash).as_ref().to_vec();
+
+        self.signatures.push(xdr::DecoratedSignature {
+            hint: xdr::SignatureHint(hint),
+            signature: signature
+                .try_into()
+                .map_err(|e| format!("Invalid signature: {}", e))?,
+        });
+        Ok(self)
+    }
+
+    /// Computes the hash of the fee bump transaction for the given network.
+    ///
+    /// # Returns
+    /// The hash of the fee bump transaction, which differs from the hash of the inner transaction,
+    /// or an error if the fee source or the fee is not set.
+    ///
+    pub fn hash(&self, network_passphrase: &str) -> Result<TransactionHash, String> {
+        let hash = hash_envelope(self.build_envelope()?, network_passphrase)?;
+        hex::encode(hash).parse()
+    }
+
+    /// Encodes the envelope of the fee bump transaction, including its signatures.
+    ///
+    /// # Returns
+    /// The base64-encoded XDR of the envelope, or an error if the fee source or the fee is not
+    /// set.
+    ///
+    pub fn to_envelope_xdr(&self) -> Result<String, String> {
+        self.build_envelope()?
+            .to_xdr_base64(Limits::none())
+            .map_err(|e| format!("Failed to encode transaction envelope: {}", e))
+    }
+
+    fn build_envelope(&self) -> Result<xdr::TransactionEnvelope, String> {
+        let fee_source = self
+            .fee_source
+            .clone()
+            .ok_or("Fee source must be set.".to_string())?;
+        let fee = self.fee.ok_or("Fee must be set.".to_string())?;
+
+        Ok(xdr::TransactionEnvelope::TxFeeBump(
+            xdr::FeeBumpTransactionEnvelope {
+                tx: xdr::FeeBumpTransaction {
+                    fee_source,
+                    fee,
+                    inner_tx: xdr::FeeBumpTransactionInnerTx::Tx(self.inner.clone()),
+                    ext: xdr::FeeBumpTransactionExt::V0,
+                },
+                signatures: self
+                    .signatures
+                    .clone()
+                    .try_into()
+                    .map_err(|e| format!("Too many signatures: {}", e))?,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::Network;
+    use crate::transactions::transaction_hash::compute_transaction_hash;
+    use ring::signature::{UnparsedPublicKey, ED25519};
+
+    const INNER_ENVELOPE_XDR: &str = "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAAAGQAAAIbAAAQAQAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAANBo20ff6bd2z2QLYzd9GOuQYxNDDLxWCOXmCeQ24W5AAAAAF0h26AAAAAAAAAAAAA==";
+    const FEE_SOURCE: &str = "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO";
+
+    #[test]
+    fn test_fee_bump_transaction() {
+        let fee_bump = FeeBumpTransaction::new(INNER_ENVELOPE_XDR).unwrap();
+        assert_eq!(fee_bump.min_fee(), 200);
+        assert_eq!(
+            fee_bump.to_envelope_xdr(),
+            Err("Fee source must be set.".to_string())
+        );
+        assert_eq!(
+            fee_bump.clone().set_fee(199).err(),
+            Some("Fee must be at least 200 stroops.".to_string())
+        );
+        assert!(fee_bump
+            .clone()
+            .set_fee_source("SAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO")
+            .is_err());
+
+        let fee_bump = fee_bump
+            .set_fee_source(FEE_SOURCE)
+            .unwrap()
+            .set_fee(200)
+            .unwrap();
+        let envelope_xdr = fee_bump.to_envelope_xdr().unwrap();
+        let envelope =
+            xdr::TransactionEnvelope::from_xdr_base64(&envelope_xdr, Limits::none()).unwrap();
+        let xdr::TransactionEnvelope::TxFeeBump(envelope) = envelope else {
+            panic!("Expected a fee bump transaction envelope");
+        };
+        assert_eq!(envelope.tx.fee, 200);
+        assert_eq!(
+            envelope.tx.fee_source,
+            xdr::MuxedAccount::Ed25519(xdr::Uint256(match StrKey::decode(FEE_SOURCE).unwrap() {
+                StrKey::PublicKey(public_key) => public_key,
+                _ => unreachable!(),
+            }))
+        );
+
+        let passphrase = Network::Testnet.passphrase();
+        let hash = fee_bump.hash(passphrase).unwrap();
+        assert_eq!(
+            hash,
+            compute_transaction_hash(&envelope_xdr, passphrase).unwrap()
+        );
+        assert_ne!(
+            hash,
+            compute_transaction_hash(INNER_ENVELOPE_XDR, passphrase).unwrap()
+        );
+
+        assert!(FeeBumpTransaction::new(&envelope_xdr).is_err());
+    }
+
+    #[test]
+    fn test_sign_fee_bump_transaction() {
+        let seed = [7; 32];
+        let secret_seed = StrKey::SecretSeed(seed).encode();
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&seed).unwrap();
+        let public_key = key_pair.public_key().as_ref().to_vec();
+        let fee_source = StrKey::MuxedAccount {
+            public_key: public_key.clone().try_into().unwrap(),
+            id: 42,
+        }
+        .encode();
+
+        let passphrase = Network::Testnet.passphrase();
+        let fee_bump = FeeBumpTransaction::new(INNER_ENVELOPE_XDR)
+            .unwrap()
+            .set_fee_source(&fee_source)
+            .unwrap()
+            .set_fee(1000)
+            .unwrap()
+            .sign(&secret_seed, passphrase)
+            .unwrap();
+
+        let hash = fee_bump.hash(passphrase).unwrap();
+        let envelope = xdr::TransactionEnvelope::from_xdr_base64(
+            fee_bump.to_envelope_xdr().unwrap(),
+            Limits::none(),
+        )
+        .unwrap();
+        let xdr::TransactionEnvelope::TxFeeBump(envelope) = envelope else {
+            panic!("Expected a fee bump transaction envelope");
+        };
+        assert_eq!(envelope.signatures.len(), 1);
+        let signature = &envelope.signatures[0];
+        assert_eq!(signature.hint.0, public_key[28..]);
+        UnparsedPublicKey::new(&ED25519, &public_key)
+            .verify(&hex::decode(hash.as_str()).unwrap(), &signature.signature)
+            .unwrap();
+
+        // Changing the fee invalidates the signatures.
+        let fee_bump = fee_bump.set_fee(2000).unwrap();
+        assert!(fee_bump.signatures.is_empty());
+    }
+}
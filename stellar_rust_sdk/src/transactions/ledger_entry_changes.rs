@@ -0,0 +1,338 @@
+use derive_getters::Getters;
+use stellar_xdr::curr::{
+    AccountEntry, LedgerEntry, LedgerEntryChange, LedgerEntryChanges, LedgerEntryData, Limits,
+    OfferEntry, ReadXdr, TransactionMeta, TrustLineEntry,
+};
+
+/// Represents the state of a single ledger entry before and after a change.
+///
+/// A created entry has no `before` state, and a removed entry has no `after` state. The states are
+/// the XDR ledger entries themselves, so they can be inspected in full or encoded back to XDR.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct LedgerEntryDiff<T> {
+    /// The state of the entry before the change, or `None` if the entry was created.
+    before: Option<T>,
+    /// The state of the entry after the change, or `None` if the entry was removed.
+    after: Option<T>,
+}
+
+impl<T> LedgerEntryDiff<T> {
+    /// Returns whether the entry was created by the change.
+    pub fn is_created(&self) -> bool {
+        self.before.is_none() && self.after.is_some()
+    }
+
+    /// Returns whether the entry was removed by the change.
+    pub fn is_removed(&self) -> bool {
+        self.before.is_some() && self.after.is_none()
+    }
+
+    /// Returns the difference between a numeric property of the entry after and before the change.
+    /// A missing state counts as zero.
+    fn difference(&self, value: impl Fn(&T) -> i64) -> i64 {
+        let before = self.before.as_ref().map(&value).unwrap_or(0);
+        let after = self.after.as_ref().map(&value).unwrap_or(0);
+        after - before
+    }
+}
+
+impl LedgerEntryDiff<AccountEntry> {
+    /// Returns the change of the native balance of the account, in stroops.
+    pub fn balance_change(&self) -> i64 {
+        self.difference(|account| account.balance)
+    }
+}
+
+impl LedgerEntryDiff<TrustLineEntry> {
+    /// Returns the change of the balance of the trustline, in stroops.
+    pub fn balance_change(&self) -> i64 {
+        self.difference(|trustline| trustline.balance)
+    }
+}
+
+impl LedgerEntryDiff<OfferEntry> {
+    /// Returns the change of the amount being sold by the offer, in stroops.
+    pub fn amount_change(&self) -> i64 {
+        self.difference(|offer| offer.amount)
+    }
+}
+
+/// Represents the changes to accounts, trustlines and offers made by a transaction.
+///
+/// The changes are decoded from the `TransactionMeta` XDR of a transaction, as returned by
+/// [`TransactionResponse::ledger_entry_changes`](crate::transactions::prelude::TransactionResponse::ledger_entry_changes).
+/// Every change is reported separately and in the order it was applied, so an entry that is
+/// changed by multiple operations appears multiple times. Changes to other kinds of ledger
+/// entries, such as data entries and claimable balances, are not included.
+///
+/// Fees are charged before the transaction is applied, so the fee charged to the source account is
+/// not part of these changes; it is recorded in the `fee_meta_xdr` of the transaction instead.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::transactions::prelude::*;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+/// let request = SingleTransactionRequest::new()
+///     .set_transaction_hash("b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020".to_string())?;
+/// let transaction = horizon_client.get_single_transaction(&request).await?;
+///
+/// for account in transaction.ledger_entry_changes()?.accounts() {
+///     println!("Balance changed by {} stroops", account.balance_change());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq, Getters)]
+pub struct LedgerEntryDiffs {
+    /// The changes to accounts.
+    accounts: Vec<LedgerEntryDiff<AccountEntry>>,
+    /// The changes to trustlines.
+    trustlines: Vec<LedgerEntryDiff<TrustLineEntry>>,
+    /// The changes to offers.
+    offers: Vec<LedgerEntryDiff<OfferEntry>>,
+}
+
+impl LedgerEntryDiffs {
+    /// Decodes the ledger entry changes from a base64 encoded `TransactionMeta` XDR string.
+    ///
+    /// # Arguments
+    /// * `result_meta_xdr` - The `result_meta_xdr` of a transaction.
+    ///
+    /// # Returns
+    /// The ledger entry changes, or an error if the XDR could not be decoded.
+    ///
+    pub fn from_result_meta_xdr(result_meta_xdr: &str) -> Result<Self, String> {
+        let meta = TransactionMeta::from_xdr_base64(result_meta_xdr, Limits::none())
+            .map_err(|e| format!("Invalid transaction meta XDR: {}", e))?;
+
+        Ok(Self::from_transaction_meta(&meta))
+    }
+
+    /// Collects the ledger entry changes from a decoded `TransactionMeta`.
+    ///
+    /// The changes made before the operations are applied come first, followed by the changes of
+    /// every operation, followed by the changes made after the operations are applied.
+    ///
+    pub fn from_transaction_meta(meta: &TransactionMeta) -> Self {
+        let mut diffs = LedgerEntryDiffs::default();
+
+        match meta {
+            TransactionMeta::V0(operations) => {
+                for operation in operations.iter() {
+                    diffs.push_changes(&operation.changes);
+                }
+            }
+            TransactionMeta::V1(meta) => {
+                diffs.push_changes(&meta.tx_changes);
+                for operation in meta.operations.iter() {
+                    diffs.push_changes(&operation.changes);
+                }
+            }
+            TransactionMeta::V2(meta) => {
+                diffs.push_changes(&meta.tx_changes_before);
+                for operation in meta.operations.iter() {
+                    diffs.push_changes(&operation.changes);
+                }
+                diffs.push_changes(&meta.tx_changes_after);
+            }
+            TransactionMeta::V3(meta) => {
+                diffs.push_changes(&meta.tx_changes_before);
+                for operation in meta.operations.iter() {
+                    diffs.push_changes(&operation.changes);
+                }
+                diffs.push_changes(&meta.tx_changes_after);
+            }
+        }
+
+        diffs
+    }
+
+    /// Returns whether no accounts, trustlines or offers were changed.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.trustlines.is_empty() && self.offers.is_empty()
+    }
+
+    /// Pairs up the changes in a list of ledger entry changes.
+    ///
+    /// Stellar Core records the state of an entry right before it is updated or removed, so every
+    /// `Updated` and `Removed` change is paired with the `State` change preceding it.
+    fn push_changes(&mut self, changes: &LedgerEntryChanges) {
+        let mut state: Option<&LedgerEntry> = None;
+
+        for change in changes.iter() {
+            match change {
+                LedgerEntryChange::State(entry) => state = Some(entry),
+                LedgerEntryChange::Created(entry) => self.push(None, Some(&entry.data)),
+                LedgerEntryChange::Updated(entry) => {
+                    self.push(state.take().map(|s| &s.data), Some(&entry.data))
+                }
+                LedgerEntryChange::Removed(_) => self.push(state.take().map(|s| &s.data), None),
+            }
+        }
+    }
+
+    fn push(&mut self, before: Option<&LedgerEntryData>, after: Option<&LedgerEntryData>) {
+        match before.or(after) {
+            Some(LedgerEntryData::Account(_)) => self.accounts.push(LedgerEntryDiff {
+                before: before.and_then(account_entry),
+                after: after.and_then(account_entry),
+            }),
+            Some(LedgerEntryData::Trustline(_)) => self.trustlines.push(LedgerEntryDiff {
+                before: before.and_then(trustline_entry),
+                after: after.and_then(trustline_entry),
+            }),
+            Some(LedgerEntryData::Offer(_)) => self.offers.push(LedgerEntryDiff {
+                before: before.and_then(offer_entry),
+                after: after.and_then(offer_entry),
+            }),
+            _ => {}
+        }
+    }
+}
+
+fn account_entry(data: &LedgerEntryData) -> Option<AccountEntry> {
+    match data {
+        LedgerEntryData::Account(account) => Some(account.clone()),
+        _ => None,
+    }
+}
+
+fn trustline_entry(data: &LedgerEntryData) -> Option<TrustLineEntry> {
+    match data {
+        LedgerEntryData::Trustline(trustline) => Some(trustline.clone()),
+        _ => None,
+    }
+}
+
+fn offer_entry(data: &LedgerEntryData) -> Option<OfferEntry> {
+    match data {
+        LedgerEntryData::Offer(offer) => Some(offer.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use stellar_xdr::curr::{
+        AccountEntryExt, AccountId, AlphaNum4, Asset, AssetCode4, ExtensionPoint, LedgerEntryExt,
+        LedgerKey, LedgerKeyOffer, OfferEntryExt, OperationMeta, Price, SequenceNumber, Thresholds,
+        TransactionMetaV3, TrustLineAsset, TrustLineEntryExt, WriteXdr,
+    };
+
+    const ACCOUNT: &str = "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO";
+    const ISSUER: &str = "GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7";
+
+    fn entry(data: LedgerEntryData) -> LedgerEntry {
+        LedgerEntry {
+            last_modified_ledger_seq: 1,
+            data,
+            ext: LedgerEntryExt::V0,
+        }
+    }
+
+    fn account(balance: i64) -> LedgerEntryData {
+        LedgerEntryData::Account(AccountEntry {
+            account_id: AccountId::from_str(ACCOUNT).unwrap(),
+            balance,
+            seq_num: SequenceNumber(1),
+            num_sub_entries: 0,
+            inflation_dest: None,
+            flags: 0,
+            home_domain: Default::default(),
+            thresholds: Thresholds([1, 0, 0, 0]),
+            signers: Default::default(),
+            ext: AccountEntryExt::V0,
+        })
+    }
+
+    fn usdc() -> AlphaNum4 {
+        AlphaNum4 {
+            asset_code: AssetCode4(*b"USDC"),
+            issuer: AccountId::from_str(ISSUER).unwrap(),
+        }
+    }
+
+    fn trustline(balance: i64) -> LedgerEntryData {
+        LedgerEntryData::Trustline(TrustLineEntry {
+            account_id: AccountId::from_str(ACCOUNT).unwrap(),
+            asset: TrustLineAsset::CreditAlphanum4(usdc()),
+            balance,
+            limit: i64::MAX,
+            flags: 1,
+            ext: TrustLineEntryExt::V0,
+        })
+    }
+
+    fn offer(amount: i64) -> LedgerEntryData {
+        LedgerEntryData::Offer(OfferEntry {
+            seller_id: AccountId::from_str(ACCOUNT).unwrap(),
+            offer_id: 42,
+            selling: Asset::Native,
+            buying: Asset::CreditAlphanum4(usdc()),
+            amount,
+            price: Price { n: 1, d: 2 },
+            flags: 0,
+            ext: OfferEntryExt::V0,
+        })
+    }
+
+    fn result_meta_xdr() -> String {
+        let operation = OperationMeta {
+            changes: vec![
+                LedgerEntryChange::State(entry(account(1000))),
+                LedgerEntryChange::Updated(entry(account(750))),
+                LedgerEntryChange::Created(entry(trustline(250))),
+                LedgerEntryChange::State(entry(offer(500))),
+                LedgerEntryChange::Removed(LedgerKey::Offer(LedgerKeyOffer {
+                    seller_id: AccountId::from_str(ACCOUNT).unwrap(),
+                    offer_id: 42,
+                })),
+            ]
+            .try_into()
+            .unwrap(),
+        };
+
+        TransactionMeta::V3(TransactionMetaV3 {
+            ext: ExtensionPoint::V0,
+            tx_changes_before: Default::default(),
+            operations: vec![operation].try_into().unwrap(),
+            tx_changes_after: Default::default(),
+            soroban_meta: None,
+        })
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ledger_entry_diffs() {
+        let diffs = LedgerEntryDiffs::from_result_meta_xdr(&result_meta_xdr()).unwrap();
+
+        assert_eq!(diffs.accounts().len(), 1);
+        assert_eq!(diffs.accounts()[0].balance_change(), -250);
+        assert_eq!(diffs.accounts()[0].before().as_ref().unwrap().balance, 1000);
+        assert_eq!(diffs.accounts()[0].after().as_ref().unwrap().balance, 750);
+
+        assert_eq!(diffs.trustlines().len(), 1);
+        assert!(diffs.trustlines()[0].is_created());
+        assert_eq!(diffs.trustlines()[0].balance_change(), 250);
+
+        assert_eq!(diffs.offers().len(), 1);
+        assert!(diffs.offers()[0].is_removed());
+        assert_eq!(diffs.offers()[0].amount_change(), -500);
+        assert_eq!(diffs.offers()[0].before().as_ref().unwrap().offer_id, 42);
+    }
+
+    #[test]
+    fn test_invalid_result_meta_xdr() {
+        assert!(LedgerEntryDiffs::from_result_meta_xdr("invalid")
+            .unwrap_err()
+            .starts_with("Invalid transaction meta XDR"));
+        assert!(LedgerEntryDiffs::default().is_empty());
+    }
+}
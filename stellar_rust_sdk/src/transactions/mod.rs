@@ -8,6 +8,15 @@
 ///
 pub mod single_transaction_request;
 
+/// Provides the `TransactionHash`.
+///
+/// This module provides the `TransactionHash` struct, a validated transaction hash that is shared
+/// by the transaction-related requests and responses, and by the requests for the effects,
+/// operations and payments of a transaction. It also provides `compute_transaction_hash`, which
+/// computes the hash of a transaction envelope for a given network before it is submitted.
+///
+pub mod transaction_hash;
+
 /// Provides the `PostTransactionRequest`.
 ///
 /// # Usage
@@ -17,6 +26,15 @@ pub mod single_transaction_request;
 ///
 pub mod post_transaction_request;
 
+/// Provides the `PostTransactionAsyncRequest`.
+///
+/// # Usage
+/// This module provides the `PostTransactionAsyncRequest` struct, specifically designed for
+/// constructing requests to submit a transaction without waiting for it to be included in a
+/// ledger. It is tailored for use with the [`HorizonClient::post_transaction_async`](crate::horizon_client::HorizonClient::post_transaction_async) method.
+///
+pub mod post_transaction_async_request;
+
 /// Provides the `AllTransactionsRequest`.
 ///
 /// # Usage
@@ -58,6 +76,75 @@ pub mod transactions_for_ledger_request;
 ///
 pub mod transactions_for_liquidity_pool_request;
 
+/// Provides the `TransactionsForClaimableBalanceRequest`.
+///
+/// # Usage
+/// This module provides the `TransactionsForClaimableBalanceRequest` struct, specifically designed
+/// for constructing requests to query the transactions that reference a given claimable balance
+/// from the Horizon server.
+/// It is tailored for use with the [`HorizonClient::get_transactions_for_claimable_balance`](crate::horizon_client::HorizonClient::get_transactions_for_claimable_balance)
+/// method.
+///
+pub mod transactions_for_claimable_balance_request;
+
+/// Provides the `PreconditionsBuilder`.
+///
+/// # Usage
+/// This module provides the `PreconditionsBuilder` struct, which constructs the XDR preconditions
+/// of a transaction. It supports all preconditions introduced in Protocol 19: time bounds, ledger
+/// bounds, the minimum sequence number, age and ledger gap, and extra signers.
+///
+pub mod preconditions_builder;
+
+/// Provides the `FeeBumpTransaction`.
+///
+/// # Usage
+/// This module provides the `FeeBumpTransaction` struct, which wraps a signed transaction in a fee
+/// bump transaction that is paid for by another account. Its envelope can be submitted with the
+/// [`HorizonClient::post_transaction`](crate::horizon_client::HorizonClient::post_transaction) method.
+///
+pub mod fee_bump_transaction;
+
+/// Provides the ledger entry changes of a transaction.
+///
+/// # Usage
+/// This module provides the `LedgerEntryDiffs` struct, which decodes the `result_meta_xdr` of a
+/// transaction into the before and after states of the accounts, trustlines and offers it changed.
+/// It is returned by `TransactionResponse::ledger_entry_changes`.
+///
+pub mod ledger_entry_changes;
+
+/// Provides the verification of the signatures of a transaction.
+///
+/// # Usage
+/// This module provides the `SignatureReport` struct, which is returned by
+/// `TransactionResponse::verify_signatures` and by
+/// [`HorizonClient::verify_transaction_signatures`](crate::horizon_client::HorizonClient::verify_transaction_signatures).
+/// It reports which signers of the account that authorizes a transaction produced its signatures,
+/// and which of its extra signers are missing.
+///
+pub mod signature_verification;
+
+/// Provides the result codes of a failed transaction.
+///
+/// # Usage
+/// This module provides the `TransactionResultCode` and `OperationResultCode` enums, and the
+/// `TransactionResultCodes` struct, which are parsed from the problem that Horizon responds with
+/// when a submitted transaction fails. They are part of
+/// [`Error::TransactionFailed`](crate::error::Error::TransactionFailed).
+///
+pub mod result_codes;
+
+/// Provides the `SubmitTransactionOptions` and the `TransactionSubmissionOutcome`.
+///
+/// # Usage
+/// This module provides the options and the outcome of the
+/// [`HorizonClient::submit_transaction`](crate::horizon_client::HorizonClient::submit_transaction)
+/// method, which resolves whether a transaction was included in a ledger when its submission
+/// times out.
+///
+pub mod transaction_submission;
+
 /// Provides the responses.
 ///
 /// This module defines structures representing the response from the Horizon API when querying
@@ -93,12 +180,19 @@ pub(crate) static TRANSACTIONS_PATH: &str = "transactions";
 /// The `prelude` includes the following re-exports:
 ///
 /// * From `single_transaction_request`: All items (e.g. `SingleTransactionRequest`).
+/// * From `transaction_hash`: All items (e.g. `TransactionHash`).
 /// * From `post_transaction_request`: All items (e.g. `PostTransactionRequest`, `TransactionEnvelope`, `NoTransactionEnvelope`).
+/// * From `post_transaction_async_request`: All items (e.g. `PostTransactionAsyncRequest`).
 /// * From `all_transactions_request`: All items (e.g. `AllTransactionsRequest`).
 /// * From `transactions_for_account_request`: All items (e.g. `TransactionsForAccountRequest`, `TransactionsAccountId`, etc.).
 /// * From `transactions_for_ledger_request`: All items (e.g. `TransactionsForLedgerRequest`, `TransactionsLedgerId`, etc.).
 /// * From `transactions_for_liquidity_pool_request`: All items (e.g. `TransactionsForLiquidityPoolRequest`, `TransactionsLiquidityPoolId`, etc.).
+/// * From `transactions_for_claimable_balance_request`: All items (e.g. `TransactionsForClaimableBalanceRequest`, `TransactionsClaimableBalanceId`, etc.).
+/// * From `preconditions_builder`: All items (e.g. `PreconditionsBuilder`).
+/// * From `fee_bump_transaction`: All items (e.g. `FeeBumpTransaction`).
 /// * From `response`: All items (e.g. `SingleTransactionResponse`, `Preconditions`, etc.).
+/// * From `result_codes`: All items (e.g. `TransactionResultCode`, `OperationResultCode`).
+/// * From `signature_verification`: All items (e.g. `SignatureReport`).
 ///
 /// # Example
 /// ```
@@ -111,10 +205,19 @@ pub(crate) static TRANSACTIONS_PATH: &str = "transactions";
 /// ```
 pub mod prelude {
     pub use super::all_transactions_request::*;
+    pub use super::fee_bump_transaction::*;
+    pub use super::ledger_entry_changes::*;
+    pub use super::post_transaction_async_request::*;
     pub use super::post_transaction_request::*;
+    pub use super::preconditions_builder::*;
     pub use super::response::*;
+    pub use super::result_codes::*;
+    pub use super::signature_verification::*;
     pub use super::single_transaction_request::*;
+    pub use super::transaction_hash::*;
+    pub use super::transaction_submission::*;
     pub use super::transactions_for_account_request::*;
+    pub use super::transactions_for_claimable_balance_request::*;
     pub use super::transactions_for_ledger_request::*;
     pub use super::transactions_for_liquidity_pool_request::*;
 }
@@ -123,7 +226,7 @@ pub mod prelude {
 pub mod test {
     use super::prelude::*;
     use crate::horizon_client::HorizonClient;
-    use crate::models::IncludeFailed;
+    use crate::models::Order;
 
     const LINK_SELF: &str = "https://horizon-testnet.stellar.org/transactions/b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
     const LINK_ACCOUNT: &str = "https://horizon-testnet.stellar.org/accounts/GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
@@ -139,7 +242,7 @@ pub mod test {
     const PAGING_TOKEN: &str = "2314987376640";
     const SUCCESSFUL: &bool = &true;
     const HASH: &str = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
-    const LEDGER: &i64 = &539;
+    const LEDGER: u32 = 539;
     const CREATED_AT: &str = "2024-06-11T21:36:12Z";
     const SOURCE_ACCOUNT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
     const SOURCE_ACCOUNT_SEQUENCE: &str = "1";
@@ -208,7 +311,7 @@ pub mod test {
         assert_eq!(response.paging_token(), PAGING_TOKEN);
         assert_eq!(response.successful(), SUCCESSFUL);
         assert_eq!(response.hash(), HASH);
-        assert_eq!(response.ledger(), LEDGER);
+        assert_eq!(response.ledger().value(), LEDGER);
         assert_eq!(response.created_at(), CREATED_AT);
         assert_eq!(response.source_account(), SOURCE_ACCOUNT);
         assert_eq!(response.source_account_sequence(), SOURCE_ACCOUNT_SEQUENCE);
@@ -230,12 +333,20 @@ pub mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_cursor_for_transaction() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let paging_token = horizon_client.cursor_for_transaction(ID).await.unwrap();
+        assert_eq!(paging_token, PAGING_TOKEN);
+    }
+
     #[tokio::test]
     async fn test_get_all_transactions() {
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
 
         let all_transactions_request = AllTransactionsRequest::new()
-            .set_include_failed(IncludeFailed::True)
+            .set_include_failed(true)
             .unwrap();
 
         let all_transactions_response = horizon_client
@@ -281,7 +392,7 @@ pub mod test {
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.successful(), SUCCESSFUL);
         assert_eq!(record.hash(), HASH);
-        assert_eq!(record.ledger(), LEDGER);
+        assert_eq!(record.ledger().value(), LEDGER);
         assert_eq!(record.created_at(), CREATED_AT);
         assert_eq!(record.source_account(), SOURCE_ACCOUNT);
         assert_eq!(record.source_account_sequence(), SOURCE_ACCOUNT_SEQUENCE);
@@ -356,7 +467,7 @@ pub mod test {
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.successful(), SUCCESSFUL);
         assert_eq!(record.hash(), HASH);
-        assert_eq!(record.ledger(), LEDGER);
+        assert_eq!(record.ledger().value(), LEDGER);
         assert_eq!(record.created_at(), CREATED_AT);
         assert_eq!(record.source_account(), SOURCE_ACCOUNT);
         assert_eq!(record.source_account_sequence(), SOURCE_ACCOUNT_SEQUENCE);
@@ -378,9 +489,35 @@ pub mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_transactions_for_account_desc() {
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        // The cursor lies just after the first transaction of the account, so that the first
+        // transaction is the only record preceding it.
+        let transactions_for_account_request = TransactionsForAccountRequest::new()
+            .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")
+            .unwrap()
+            .set_cursor(2314987376641)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+
+        let transactions_for_account_response = horizon_client
+            .get_transactions_for_account(&transactions_for_account_request)
+            .await;
+
+        assert!(transactions_for_account_response.clone().is_ok());
+        let binding = transactions_for_account_response.unwrap();
+        let record = &binding.embedded().records()[0];
+        assert_eq!(record.id(), ID);
+        assert_eq!(record.paging_token(), PAGING_TOKEN);
+        assert_eq!(record.hash(), HASH);
+    }
+
     #[tokio::test]
     async fn test_get_transactions_for_ledger() {
-        const LEDGER_SEQUENCE: &str = "539";
+        const LEDGER_SEQUENCE: u32 = 539;
 
         let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
 
@@ -433,7 +570,7 @@ pub mod test {
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.successful(), SUCCESSFUL);
         assert_eq!(record.hash(), HASH);
-        assert_eq!(record.ledger(), LEDGER);
+        assert_eq!(record.ledger().value(), LEDGER);
         assert_eq!(record.created_at(), CREATED_AT);
         assert_eq!(record.source_account(), SOURCE_ACCOUNT);
         assert_eq!(record.source_account_sequence(), SOURCE_ACCOUNT_SEQUENCE);
@@ -471,7 +608,7 @@ pub mod test {
         const PAGING_TOKEN: &str = "458990270087168";
         const SUCCESSFUL: &bool = &true;
         const HASH: &str = "1f6abb2a00ba84469f8d95271bf2eec99da10bddb894be11f29f7a7039f0c0a6";
-        const LEDGER: &i64 = &106867;
+        const LEDGER: u32 = 106867;
         const CREATED_AT: &str = "2024-06-18T08:54:13Z";
         const SOURCE_ACCOUNT: &str = "GDB4ZUD465ZQ2FQZ4GNHEWVYJKZVOGSMJOEUGMFVLOOARFS4YKMRBCRV";
         const SOURCE_ACCOUNT_SEQUENCE: &str = "458960205250561";
@@ -538,7 +675,7 @@ pub mod test {
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.successful(), SUCCESSFUL);
         assert_eq!(record.hash(), HASH);
-        assert_eq!(record.ledger(), LEDGER);
+        assert_eq!(record.ledger().value(), LEDGER);
         assert_eq!(record.created_at(), CREATED_AT);
         assert_eq!(record.source_account(), SOURCE_ACCOUNT);
         assert_eq!(record.source_account_sequence(), SOURCE_ACCOUNT_SEQUENCE);
@@ -578,7 +715,7 @@ pub mod test {
         const PAGING_TOKEN: &str = "2314987376640";
         const SUCCESSFUL: &bool = &true;
         const HASH: &str = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
-        const LEDGER: &i64 = &539;
+        const LEDGER: u32 = 539;
         const CREATED_AT: &str = "2024-06-11T21:36:12Z";
         const SOURCE_ACCOUNT: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
         const SOURCE_ACCOUNT_SEQUENCE: &str = "1";
@@ -637,7 +774,7 @@ pub mod test {
         assert_eq!(record.paging_token(), PAGING_TOKEN);
         assert_eq!(record.successful(), SUCCESSFUL);
         assert_eq!(record.hash(), HASH);
-        assert_eq!(record.ledger(), LEDGER);
+        assert_eq!(record.ledger().value(), LEDGER);
         assert_eq!(record.created_at(), CREATED_AT);
         assert_eq!(record.source_account(), SOURCE_ACCOUNT);
         assert_eq!(record.source_account_sequence(), SOURCE_ACCOUNT_SEQUENCE);
@@ -658,4 +795,46 @@ pub mod test {
             MIN_TIME
         );
     }
+
+    #[tokio::test]
+    async fn test_get_transactions_for_liquidity_pool_desc() {
+        const ID: &str = "1f6abb2a00ba84469f8d95271bf2eec99da10bddb894be11f29f7a7039f0c0a6";
+        const PAGING_TOKEN: &str = "458990270087168";
+        const LIQUIDITY_POOL_ID: &str =
+            "0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10";
+
+        let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+
+        let transactions_for_liquidity_pool_request = TransactionsForLiquidityPoolRequest::new()
+            .set_liquidity_pool_id(LIQUIDITY_POOL_ID)
+            .unwrap()
+            .set_cursor(458990270087169)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+
+        let transactions_for_liquidity_pool_response = horizon_client
+            .get_transactions_for_liquidity_pool(&transactions_for_liquidity_pool_request)
+            .await;
+
+        assert!(transactions_for_liquidity_pool_response.clone().is_ok());
+        let binding = transactions_for_liquidity_pool_response.unwrap();
+        let records = binding.embedded().records();
+        assert_eq!(records[0].id(), ID);
+        assert_eq!(records[0].paging_token(), PAGING_TOKEN);
+        assert!(records.windows(2).all(|pair| {
+            pair[0].paging_token().parse::<u64>().unwrap()
+                > pair[1].paging_token().parse::<u64>().unwrap()
+        }));
+    }
+
+    #[test]
+    fn test_async_transaction_status_unknown() {
+        let status: AsyncTransactionStatus = serde_json::from_str("\"PENDING\"").unwrap();
+        assert_eq!(status, AsyncTransactionStatus::Pending);
+
+        // A status that is added to Horizon later does not break the deserialization.
+        let status: AsyncTransactionStatus = serde_json::from_str("\"QUEUED\"").unwrap();
+        assert_eq!(status, AsyncTransactionStatus::Unknown);
+    }
 }
@@ -0,0 +1,110 @@
+use super::post_transaction_request::{NoTransactionEnvelope, TransactionEnvelope};
+use super::transaction_hash::compute_transaction_hash;
+use crate::models::*;
+
+/// The path of the endpoint for asynchronous transaction submission in the Horizon API.
+static TRANSACTIONS_ASYNC_PATH: &str = "transactions_async";
+
+/// Represents a request to submit a transaction asynchronously to the Horizon API.
+///
+/// Unlike a [`PostTransactionRequest`](super::post_transaction_request::PostTransactionRequest),
+/// which waits until the transaction is included in a ledger, this request returns as soon as
+/// Stellar Core has accepted or rejected the transaction. It is designed to be used in conjunction
+/// with the
+/// [`HorizonClient::post_transaction_async`](crate::horizon_client::HorizonClient::post_transaction_async)
+/// method.
+///
+/// The struct matches the parameters necessary to construct a request for the
+/// <a href="https://developers.stellar.org/docs/data/horizon/api-reference/submit-async-transaction">Submit a Transaction Asynchronously</a>
+/// endpoint of the Horizon API.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::transactions::prelude::*;
+/// # let signed_transaction_xdr = "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAAAGQAAAIbAAAQAQAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAANBo20ff6bd2z2QLYzd9GOuQYxNDDLxWCOXmCeQ24W5AAAAAF0h26AAAAAAAAAAAAA==";
+/// let request = PostTransactionAsyncRequest::new()
+///     .set_transaction_envelope_xdr(signed_transaction_xdr)
+///     .unwrap();
+/// // Use with HorizonClient::post_transaction_async
+/// ```
+///
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct PostTransactionAsyncRequest<T = NoTransactionEnvelope> {
+    /// A base64-encoded string containing the transaction envelope XDR.
+    transaction_envelope_xdr: T,
+}
+
+impl PostTransactionAsyncRequest<NoTransactionEnvelope> {
+    /// Creates a new `PostTransactionAsyncRequest` with default parameters.
+    pub fn new() -> Self {
+        PostTransactionAsyncRequest::default()
+    }
+
+    /// Sets the transaction envelope for the request.
+    ///
+    /// # Arguments
+    /// * `transaction_envelope_xdr` - A `String` specifying the transaction envelope XDR.
+    ///
+    pub fn set_transaction_envelope_xdr(
+        self,
+        transaction_envelope_xdr: impl Into<String>,
+    ) -> Result<PostTransactionAsyncRequest<TransactionEnvelope>, String> {
+        Ok(PostTransactionAsyncRequest {
+            transaction_envelope_xdr: TransactionEnvelope::new(transaction_envelope_xdr),
+        })
+    }
+}
+
+impl PostTransactionAsyncRequest<TransactionEnvelope> {
+    /// Computes the hash of the transaction, as it will be assigned by the network.
+    ///
+    /// # Arguments
+    /// * `network_passphrase` - The passphrase of the network, such as
+    ///   `"Test SDF Network ; September 2015"` for the testnet.
+    ///
+    /// # Returns
+    /// The hex-encoded SHA-256 hash of the transaction, or an error if the transaction envelope
+    /// XDR could not be decoded.
+    ///
+    pub fn transaction_hash(&self, network_passphrase: &str) -> Result<String, String> {
+        compute_transaction_hash(self.transaction_envelope_xdr.as_str(), network_passphrase)
+            .map(String::from)
+    }
+}
+
+impl PostRequest for PostTransactionAsyncRequest<TransactionEnvelope> {
+    fn get_body(&self) -> Vec<(String, String)> {
+        vec![(
+            "tx".to_string(),
+            self.transaction_envelope_xdr.as_str().to_string(),
+        )]
+    }
+
+    fn build_url(&self, base_url: &str) -> String {
+        format!("{}/{}", base_url, TRANSACTIONS_ASYNC_PATH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_transaction_async_request() {
+        let request = PostTransactionAsyncRequest::new()
+            .set_transaction_envelope_xdr("AAAA")
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/transactions_async"
+        );
+        assert_eq!(
+            request.get_body(),
+            vec![("tx".to_string(), "AAAA".to_string())]
+        );
+        assert!(request
+            .transaction_hash("Test SDF Network ; September 2015")
+            .is_err());
+    }
+}
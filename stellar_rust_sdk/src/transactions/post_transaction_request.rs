@@ -1,15 +1,28 @@
+use super::transaction_hash::compute_transaction_hash;
 use crate::models::*;
 
 /// Represents the transaction envelope XDR.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct TransactionEnvelope(String);
 
 /// Represents the absence of a transaction envelope XDR.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTransactionEnvelope;
 
-#[derive(Default)]
-pub struct PostTransactionRequest<T> {
+impl TransactionEnvelope {
+    /// Creates a new `TransactionEnvelope` from the given XDR.
+    pub(crate) fn new(transaction_envelope_xdr: impl Into<String>) -> Self {
+        TransactionEnvelope(transaction_envelope_xdr.into())
+    }
+
+    /// Returns the transaction envelope XDR.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct PostTransactionRequest<T = NoTransactionEnvelope> {
     /// A base64-encoded string containing the transaction envelope XDR.
     transaction_envelope_xdr: T,
 }
@@ -35,6 +48,29 @@ impl PostTransactionRequest<NoTransactionEnvelope> {
     }
 }
 
+impl PostTransactionRequest<TransactionEnvelope> {
+    /// Computes the hash of the transaction, as it will be assigned by the network.
+    ///
+    /// The hash depends on the network the transaction is submitted to, so the network
+    /// passphrase is required. See [`compute_transaction_hash`] to compute the hash of an
+    /// envelope directly. It can be used to look up the transaction with
+    /// [`HorizonClient::get_single_transaction`](crate::horizon_client::HorizonClient::get_single_transaction)
+    /// before or after it is submitted.
+    ///
+    /// # Arguments
+    /// * `network_passphrase` - The passphrase of the network, such as
+    ///   `"Test SDF Network ; September 2015"` for the testnet.
+    ///
+    /// # Returns
+    /// The hex-encoded SHA-256 hash of the transaction, or an error if the transaction envelope
+    /// XDR could not be decoded.
+    ///
+    pub fn transaction_hash(&self, network_passphrase: &str) -> Result<String, String> {
+        compute_transaction_hash(&self.transaction_envelope_xdr.0, network_passphrase)
+            .map(String::from)
+    }
+}
+
 impl PostRequest for PostTransactionRequest<TransactionEnvelope> {
     fn get_body(&self) -> Vec<(String, String)> {
         // Return a vector containing a tuple with a key/value pair, to be used in the request's formdata.
@@ -50,3 +86,64 @@ impl PostRequest for PostTransactionRequest<TransactionEnvelope> {
         format!("{}/{}", base_url, super::TRANSACTIONS_PATH)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNED_TRANSACTION_XDR: &str = "AAAAAgAAAABi/B0L0JGythwN1lY0aypo19NHxvLCyO5tBEcCVvwF9wAABEwAAAAAAAAAAQAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAsAAAAAAAAAAAAAAAAQfdFrLDgzSIIugR73qs8U0ZiKbwBUclTTPh5thlbgnAFjRXhdigAAAAAAAAAAAAAAAAAA3b5KF6uk1w1fSKYLrzR8gF2lB+AHAi6oU6CaWhunAskAAAAXSHboAAAAAAAAAAAAAAAAAHfmNeMLin2aTUfxa530ZRn4zwRu7ROAQfUJeJco8HSCAAHGv1JjQAAAAAAAAAAAAAAAAAAAlRt2go9sp7E1a5ZWvr7vin4UPrFQThpQax1lOFm33AAAABdIdugAAAAAAAAAAAAAAAAAmv+knlR6JR2VqWeU0k/4FgvZ/tSV5DEY4gu0iOTKgpUAAAAXSHboAAAAAAAAAAAAAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAF0h26AAAAAABAAAAAACVG3aCj2ynsTVrlla+vu+KfhQ+sVBOGlBrHWU4WbfcAAAABgAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bf/////////8AAAABAAAAAJr/pJ5UeiUdlalnlNJP+BYL2f7UleQxGOILtIjkyoKVAAAABgAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bf/////////8AAAABAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAAQAAAAAAlRt2go9sp7E1a5ZWvr7vin4UPrFQThpQax1lOFm33AAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAJGE5yoAAAAAABAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAAAQAAAACa/6SeVHolHZWpZ5TST/gWC9n+1JXkMRjiC7SI5MqClQAAAAFURVNUAAAAANpaWLojuOtfC0cmMh+DvQTfPDrkfXhblQTdFXrGYc0bAAAJGE5yoAAAAAAAAAAAAAAAAABKBB+2UBMP/abwcm/M1TXO+/JQWhPwkalgqizKmXyRIQx7qh6aAFYAAAAAAAAAAARW/AX3AAAAQDVB8fT2ZXF0PZqtZX9brK0kz+P4G8VKs1DkDklP6ULsvXRexXFBdH4xG8xRAsR1HJeEBH278hiBNNvUwNw6zgzGYc0bAAAAQLgZUU/oYGL7frWDQhJHhCQu9JmfqN03PrJq4/cJrN1OSUWXnmLc94sv8m2L+cxl2p0skr2Jxy+vt1Lcxkv7wAI4WbfcAAAAQHvZEVqlygIProf3jVTZohDWm2WUNrFAFXf1LctTqDCQBHph14Eo+APwrTURLLYTIvNoXeGzBKbL03SsOARWcQLkyoKVAAAAQHAvKv2/Ro4+cNh6bKQO/G9NNiUozYysGwG1GvJQkFjwy/OTsL6WBfuI0Oye84lVBVrQVk2EY1ERFhgdMpuFSg4=";
+
+    #[test]
+    fn test_transaction_hash() {
+        let request = PostTransactionRequest::new()
+            .set_transaction_envelope_xdr(SIGNED_TRANSACTION_XDR)
+            .unwrap();
+
+        assert_eq!(
+            request
+                .transaction_hash("Test SDF Network ; September 2015")
+                .unwrap(),
+            "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+        );
+        // The hash differs per network.
+        assert_ne!(
+            request
+                .transaction_hash("Public Global Stellar Network ; September 2015")
+                .unwrap(),
+            "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+        );
+    }
+
+    #[test]
+    fn test_compute_transaction_hash() {
+        let hash =
+            compute_transaction_hash(SIGNED_TRANSACTION_XDR, "Test SDF Network ; September 2015")
+                .unwrap();
+        assert_eq!(
+            hash,
+            "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+        );
+
+        // The same transaction has a different hash on another network.
+        let hash = compute_transaction_hash(
+            SIGNED_TRANSACTION_XDR,
+            "Public Global Stellar Network ; September 2015",
+        )
+        .unwrap();
+        assert_ne!(
+            hash,
+            "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+        );
+    }
+
+    #[test]
+    fn test_transaction_hash_invalid_xdr() {
+        let request = PostTransactionRequest::new()
+            .set_transaction_envelope_xdr("invalid")
+            .unwrap();
+
+        assert!(request
+            .transaction_hash("Test SDF Network ; September 2015")
+            .is_err());
+    }
+}
@@ -0,0 +1,317 @@
+use std::str::FromStr;
+use stellar_xdr::curr::{
+    Duration, LedgerBounds, Preconditions, PreconditionsV2, SequenceNumber, SignerKey, TimeBounds,
+    TimePoint,
+};
+
+/// The maximum number of extra signers a transaction can require.
+const MAX_EXTRA_SIGNERS: usize = 2;
+
+/// Builds the preconditions of a transaction, as introduced in Protocol 19 (CAP-21).
+///
+/// A transaction can only be included in a ledger when all of its preconditions are met. Besides
+/// the classic time bounds, Protocol 19 introduced ledger bounds, a minimum source account sequence
+/// number, a minimum sequence age, a minimum sequence ledger gap and up to two extra signers.
+///
+/// The builder validates every value as it is set, so that inconsistent bounds or invalid signers
+/// are rejected before a transaction is ever submitted. The result of
+/// [`build`](PreconditionsBuilder::build) is the XDR [`Preconditions`] union, in its most compact form: `PRECOND_NONE` when no
+/// preconditions are set, `PRECOND_TIME` when only time bounds are set, and `PRECOND_V2` otherwise.
+///
+/// # Example
+/// ```
+/// use stellar_rs::transactions::prelude::*;
+///
+/// let preconditions = PreconditionsBuilder::new()
+///     .set_time_bounds(1_700_000_000, 1_700_000_300).unwrap()
+///     .set_ledger_bounds(1000, 2000).unwrap()
+///     .set_min_sequence_age(60)
+///     .add_extra_signer("GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO").unwrap()
+///     .build()
+///     .unwrap();
+/// ```
+///
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct PreconditionsBuilder {
+    /// The time bounds, as UNIX timestamps in seconds. A maximum of `0` means no upper bound.
+    time_bounds: Option<(u64, u64)>,
+    /// The ledger bounds, as ledger sequence numbers. A maximum of `0` means no upper bound.
+    ledger_bounds: Option<(u32, u32)>,
+    /// The minimum sequence number of the source account.
+    min_sequence_number: Option<i64>,
+    /// The minimum number of seconds since the source account sequence number was last changed.
+    min_sequence_age: u64,
+    /// The minimum number of ledgers since the source account sequence number was last changed.
+    min_sequence_ledger_gap: u32,
+    /// The signers that must have signed the transaction, in addition to the regular signers.
+    extra_signers: Vec<SignerKey>,
+}
+
+impl PreconditionsBuilder {
+    /// Creates a new `PreconditionsBuilder` without any preconditions.
+    pub fn new() -> Self {
+        PreconditionsBuilder::default()
+    }
+
+    /// Sets the time bounds of the transaction.
+    ///
+    /// # Arguments
+    /// * `min_time` - The earliest close time, as a UNIX timestamp in seconds, of a ledger the
+    ///   transaction can be included in.
+    /// * `max_time` - The latest close time, as a UNIX timestamp in seconds, of a ledger the
+    ///   transaction can be included in. Use `0` for no upper bound.
+    ///
+    /// # Returns
+    /// The updated builder, or an error if `max_time` is set and smaller than `min_time`.
+    ///
+    pub fn set_time_bounds(self, min_time: u64, max_time: u64) -> Result<Self, String> {
+        if max_time != 0 && max_time < min_time {
+            return Err("Maximum time must be greater than or equal to minimum time.".to_string());
+        }
+
+        Ok(Self {
+            time_bounds: Some((min_time, max_time)),
+            ..self
+        })
+    }
+
+    /// Sets the ledger bounds of the transaction.
+    ///
+    /// # Arguments
+    /// * `min_ledger` - The lowest ledger sequence the transaction can be included in.
+    /// * `max_ledger` - The ledger sequence *before* which the transaction must be included.
+    ///   Use `0` for no upper bound.
+    ///
+    /// # Returns
+    /// The updated builder, or an error if `max_ledger` is set and not greater than `min_ledger`.
+    ///
+    pub fn set_ledger_bounds(self, min_ledger: u32, max_ledger: u32) -> Result<Self, String> {
+        if max_ledger != 0 && max_ledger <= min_ledger {
+            return Err("Maximum ledger must be greater than minimum ledger.".to_string());
+        }
+
+        Ok(Self {
+            ledger_bounds: Some((min_ledger, max_ledger)),
+            ..self
+        })
+    }
+
+    /// Sets the minimum sequence number of the source account.
+    ///
+    /// The transaction is only valid if the sequence number of the source account is greater than
+    /// or equal to this value, and smaller than the sequence number of the transaction itself.
+    ///
+    /// # Arguments
+    /// * `min_sequence_number` - The minimum sequence number.
+    ///
+    /// # Returns
+    /// The updated builder, or an error if the sequence number is negative.
+    ///
+    pub fn set_min_sequence_number(self, min_sequence_number: i64) -> Result<Self, String> {
+        if min_sequence_number < 0 {
+            return Err("Minimum sequence number must not be negative.".to_string());
+        }
+
+        Ok(Self {
+            min_sequence_number: Some(min_sequence_number),
+            ..self
+        })
+    }
+
+    /// Sets the minimum age of the source account sequence number.
+    ///
+    /// # Arguments
+    /// * `min_sequence_age` - The minimum number of seconds that must have passed since the
+    ///   sequence number of the source account was last changed.
+    ///
+    pub fn set_min_sequence_age(self, min_sequence_age: u64) -> Self {
+        Self {
+            min_sequence_age,
+            ..self
+        }
+    }
+
+    /// Sets the minimum ledger gap of the source account sequence number.
+    ///
+    /// # Arguments
+    /// * `min_sequence_ledger_gap` - The minimum number of ledgers that must have closed since
+    ///   the sequence number of the source account was last changed.
+    ///
+    pub fn set_min_sequence_ledger_gap(self, min_sequence_ledger_gap: u32) -> Self {
+        Self {
+            min_sequence_ledger_gap,
+            ..self
+        }
+    }
+
+    /// Adds an extra signer that must have signed the transaction.
+    ///
+    /// # Arguments
+    /// * `signer` - The signer key, encoded as a strkey. This can be an ed25519 public key (`G...`),
+    ///   a pre-authorized transaction hash (`T...`), a hash-x (`X...`) or a signed payload (`P...`).
+    ///
+    /// # Returns
+    /// The updated builder, or an error if the signer key is invalid, already added, or if the
+    /// maximum of two extra signers has been reached.
+    ///
+    pub fn add_extra_signer(mut self, signer: impl Into<String>) -> Result<Self, String> {
+        let signer = signer.into();
+        let signer_key =
+            SignerKey::from_str(&signer).map_err(|_| format!("Invalid signer key: {}", signer))?;
+
+        if self.extra_signers.contains(&signer_key) {
+            return Err(format!("Duplicate extra signer: {}", signer));
+        }
+        if self.extra_signers.len() >= MAX_EXTRA_SIGNERS {
+            return Err("A transaction can have at most 2 extra signers.".to_string());
+        }
+
+        self.extra_signers.push(signer_key);
+        Ok(self)
+    }
+
+    /// Builds the XDR preconditions.
+    ///
+    /// # Returns
+    /// The XDR [`Preconditions`], or an error if the preconditions can not be represented in XDR.
+    ///
+    pub fn build(self) -> Result<Preconditions, String> {
+        let time_bounds = self.time_bounds.map(|(min_time, max_time)| TimeBounds {
+            min_time: TimePoint(min_time),
+            max_time: TimePoint(max_time),
+        });
+
+        let is_v2 = self.ledger_bounds.is_some()
+            || self.min_sequence_number.is_some()
+            || self.min_sequence_age != 0
+            || self.min_sequence_ledger_gap != 0
+            || !self.extra_signers.is_empty();
+
+        if !is_v2 {
+            return Ok(match time_bounds {
+                Some(time_bounds) => Preconditions::Time(time_bounds),
+                None => Preconditions::None,
+            });
+        }
+
+        Ok(Preconditions::V2(PreconditionsV2 {
+            time_bounds,
+            ledger_bounds: self
+                .ledger_bounds
+                .map(|(min_ledger, max_ledger)| LedgerBounds {
+                    min_ledger,
+                    max_ledger,
+                }),
+            min_seq_num: self.min_sequence_number.map(SequenceNumber),
+            min_seq_age: Duration(self.min_sequence_age),
+            min_seq_ledger_gap: self.min_sequence_ledger_gap,
+            extra_signers: self
+                .extra_signers
+                .try_into()
+                .map_err(|_| "A transaction can have at most 2 extra signers.".to_string())?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::{Limits, ReadXdr, WriteXdr};
+
+    const SIGNER: &str = "GAVCBYUQSQA77EOOQMSDDXE6VSWDZRGOZOGMLWGFR6YR4TR243VWBDFO";
+
+    #[test]
+    fn test_build_none() {
+        let preconditions = PreconditionsBuilder::new().build().unwrap();
+        assert_eq!(preconditions, Preconditions::None);
+    }
+
+    #[test]
+    fn test_build_time_bounds_only() {
+        let preconditions = PreconditionsBuilder::new()
+            .set_time_bounds(100, 0)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            preconditions,
+            Preconditions::Time(TimeBounds {
+                min_time: TimePoint(100),
+                max_time: TimePoint(0),
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_v2_round_trip() {
+        let preconditions = PreconditionsBuilder::new()
+            .set_time_bounds(100, 200)
+            .unwrap()
+            .set_ledger_bounds(10, 20)
+            .unwrap()
+            .set_min_sequence_number(5)
+            .unwrap()
+            .set_min_sequence_age(60)
+            .set_min_sequence_ledger_gap(2)
+            .add_extra_signer(SIGNER)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let encoded = preconditions.to_xdr_base64(Limits::none()).unwrap();
+        let decoded = Preconditions::from_xdr_base64(encoded, Limits::none()).unwrap();
+        assert_eq!(decoded, preconditions);
+
+        match decoded {
+            Preconditions::V2(v2) => {
+                assert_eq!(v2.ledger_bounds.unwrap().max_ledger, 20);
+                assert_eq!(v2.min_seq_num, Some(SequenceNumber(5)));
+                assert_eq!(v2.min_seq_age, Duration(60));
+                assert_eq!(v2.min_seq_ledger_gap, 2);
+                assert_eq!(v2.extra_signers.len(), 1);
+            }
+            _ => panic!("Expected V2 preconditions"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_bounds() {
+        assert_eq!(
+            PreconditionsBuilder::new()
+                .set_time_bounds(200, 100)
+                .unwrap_err(),
+            "Maximum time must be greater than or equal to minimum time."
+        );
+        assert_eq!(
+            PreconditionsBuilder::new()
+                .set_ledger_bounds(20, 20)
+                .unwrap_err(),
+            "Maximum ledger must be greater than minimum ledger."
+        );
+    }
+
+    #[test]
+    fn test_extra_signers() {
+        let builder = PreconditionsBuilder::new()
+            .add_extra_signer(SIGNER)
+            .unwrap();
+        assert_eq!(
+            builder.clone().add_extra_signer(SIGNER).unwrap_err(),
+            format!("Duplicate extra signer: {}", SIGNER)
+        );
+        assert!(PreconditionsBuilder::new()
+            .add_extra_signer("invalid")
+            .is_err());
+
+        let builder = builder
+            .add_extra_signer("GDQJUTQYK2MQX2VGDR2FYWLIYAQIEGXTQVTFEMGH2BEWFG4BRUY4CKI7")
+            .unwrap();
+        assert_eq!(
+            builder
+                .add_extra_signer("GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5")
+                .unwrap_err(),
+            "A transaction can have at most 2 extra signers."
+        );
+    }
+}
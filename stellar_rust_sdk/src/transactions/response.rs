@@ -1,6 +1,19 @@
+use crate::accounts::prelude::{Account, SignerKey};
+use crate::effects::prelude::EffectsResponse;
+use crate::error::Error;
+use crate::horizon_client::HorizonClient;
+use crate::ledgers::prelude::{Ledger, LedgerSequence};
+use crate::models::parse_muxed_account;
 use crate::models::prelude::*;
+use crate::models::serde_adapters::option_signer_keys;
+use crate::operations::prelude::OperationResponse;
+use crate::transactions::ledger_entry_changes::LedgerEntryDiffs;
+use crate::transactions::transaction_hash::TransactionHash;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
+use stellar_xdr::curr::{
+    LedgerEntryChanges, Limits, ReadXdr, TransactionEnvelope, TransactionMeta, TransactionResult,
+};
 
 /// Represents the navigational links in a response from the Horizon API.
 ///
@@ -9,13 +22,14 @@ use serde::{Deserialize, Serialize};
 /// the ledger that the transaction was included in.
 ///
 #[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[non_exhaustive]
 pub struct TransactionResponseLinks {
     #[serde(rename = "self")]
     self_link: Link,
     account: Link,
     ledger: Link,
-    operations: Link,
-    effects: Link,
+    operations: TemplateLink,
+    effects: TemplateLink,
     precedes: Link,
     succeeds: Link,
     transaction: Link,
@@ -29,6 +43,7 @@ pub struct TransactionResponseLinks {
 /// and an array of up to 2 additional signers (optional).
 ///
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct Preconditions {
     /// The time range for which this transaction is valid, with bounds as unsigned 64-bit UNIX timestamps.
     timebounds: TimeBounds,
@@ -37,11 +52,12 @@ pub struct Preconditions {
     /// Containing a positive, signed 64-bit integer representing the lowest source account sequence number for which the transaction is valid.
     min_account_sequence: Option<String>,
     /// The minimum duration of time (in seconds as an unsigned 64-bit integer) that must have passed since the source account's sequence number changed for the transaction to be valid.
-    min_account_sequence_age: Option<i64>,
+    min_account_sequence_age: Option<String>,
     /// An unsigned 32-bit integer representing the minimum number of ledgers that must have closed since the source account's sequence number changed for the transaction to be valid.
     min_account_sequence_ledger_gap: Option<i64>,
     /// The list of up to two additional signers that must have corresponding signatures for this transaction to be valid.
-    extra_signers: Option<Vec<String>>,
+    #[serde(with = "option_signer_keys", default)]
+    extra_signers: Option<Vec<SignerKey>>,
 }
 
 /// Represents the time range for which this transaction is valid, with bounds as unsigned 64-bit UNIX timestamps.
@@ -51,6 +67,7 @@ pub struct Preconditions {
 /// and the upper time bound (optional).
 ///
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct TimeBounds {
     /// The lower bound.
     min_time: String,
@@ -65,11 +82,12 @@ pub struct TimeBounds {
 /// and the upper ledger bound (optional).
 ///
 #[derive(Default, Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct LedgerBounds {
     /// The lower bound.
-    min_ledger: String,
+    min_ledger: u32,
     /// The upper bound.
-    max_ledger: Option<String>,
+    max_ledger: Option<u32>,
 }
 
 /// Represents the response for the 'all transactions' query in the Horizon API.
@@ -79,6 +97,7 @@ pub struct LedgerBounds {
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct AllTransactionsResponse {
     #[serde(rename = "_links")]
     links: ResponseLinks,
@@ -88,12 +107,45 @@ pub struct AllTransactionsResponse {
 
 impl Response for AllTransactionsResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        let response = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let response = crate::models::deserialize_json(&json)?;
 
         Ok(response)
     }
 }
 
+/// Represents the fee bump of a fee bump transaction.
+///
+/// # Usage
+/// This struct details the hash and the signatures of the fee bump transaction itself, which
+/// are also the `hash` and `signatures` of the [`TransactionResponse`] it belongs to.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct FeeBumpTransactionDetails {
+    /// The hash of the fee bump transaction.
+    hash: TransactionHash,
+    /// The signatures of the fee bump transaction, made by the fee account.
+    signatures: Vec<String>,
+}
+
+/// Represents the transaction that is wrapped by a fee bump transaction.
+///
+/// # Usage
+/// This struct details the hash, the signatures and the maximum fee of the inner transaction,
+/// which differ from those of the fee bump transaction that wraps it.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct InnerTransactionDetails {
+    /// The hash of the inner transaction.
+    hash: TransactionHash,
+    /// The signatures of the inner transaction, made by the source account.
+    signatures: Vec<String>,
+    /// The maximum fee (in stroops) that the source account of the inner transaction was willing
+    /// to pay.
+    max_fee: String,
+}
+
 /// Represents a single transaction record in the Horizon API response.
 ///
 /// # Usage
@@ -101,6 +153,7 @@ impl Response for AllTransactionsResponse {
 /// hash, creation time, source account, and other relevant data.
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
 pub struct TransactionResponse {
     #[serde(rename = "_links")]
     links: TransactionResponseLinks,
@@ -111,9 +164,9 @@ pub struct TransactionResponse {
     /// Indicates if this transaction was successful or not.
     successful: bool,
     /// A hex-encoded SHA-256 hash of this transaction’s XDR-encoded form.
-    hash: String,
+    hash: TransactionHash,
     /// The sequence number of the ledger that this transaction was included in.
-    ledger: i64,
+    ledger: LedgerSequence,
     /// The date this transaction was created.
     created_at: String,
     /// The account that originates the transaction.
@@ -126,6 +179,10 @@ pub struct TransactionResponse {
     source_account_sequence: String,
     /// The ID of the fee account.
     fee_account: String,
+    /// The muxed fee account, if the fee was paid by a muxed account.
+    fee_account_muxed: Option<String>,
+    /// The ID of the muxed fee account, if the fee was paid by a muxed account.
+    fee_account_muxed_id: Option<String>,
     /// The fee (in stroops) paid by the source account to apply this transaction to the ledger.
     fee_charged: String,
     /// The maximum fee (in stroops) that the source account was willing to pay.
@@ -142,6 +199,8 @@ pub struct TransactionResponse {
     fee_meta_xdr: String,
     /// The optional memo attached to a transaction.
     memo: Option<String>,
+    /// The bytes of a text memo, base64-encoded, as the memo itself may not be valid UTF-8.
+    memo_bytes: Option<String>,
     /// The type of memo. Potential values include `MEMO_TEXT`, `MEMO_ID`, `MEMO_HASH`, `MEMO_RETURN`.
     memo_type: String,
     /// An array of signatures used to sign this transaction.
@@ -152,10 +211,173 @@ pub struct TransactionResponse {
     valid_before: Option<String>,
     /// A set of transaction preconditions affecting its validity.
     preconditions: Option<Preconditions>,
+    /// The details of the fee bump, if this is a fee bump transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fee_bump_transaction: Option<FeeBumpTransactionDetails>,
+    /// The details of the wrapped transaction, if this is a fee bump transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    inner_transaction: Option<InnerTransactionDetails>,
+}
+
+impl TransactionResponse {
+    /// Returns the muxed account that originated the transaction, or `None` if it was not
+    /// originated by a muxed account.
+    pub fn muxed_account(&self) -> Option<Result<MuxedAccount, String>> {
+        parse_muxed_account(self.account_muxed.as_deref())
+    }
+
+    /// Returns the muxed account that paid the fee of the transaction, or `None` if the fee was
+    /// not paid by a muxed account.
+    pub fn fee_muxed_account(&self) -> Option<Result<MuxedAccount, String>> {
+        parse_muxed_account(self.fee_account_muxed.as_deref())
+    }
+
+    /// Decodes the XDR-encoded envelope of the transaction, which holds the transaction itself,
+    /// including its operations, along with its signatures.
+    pub fn decoded_envelope_xdr(&self) -> Result<TransactionEnvelope, String> {
+        TransactionEnvelope::from_xdr_base64(&self.envelope_xdr, Limits::none())
+            .map_err(|e| format!("Invalid transaction envelope XDR: {}", e))
+    }
+
+    /// Decodes the XDR-encoded result of the transaction, which holds the fee charged and the
+    /// result of every operation.
+    pub fn decoded_result_xdr(&self) -> Result<TransactionResult, String> {
+        TransactionResult::from_xdr_base64(&self.result_xdr, Limits::none())
+            .map_err(|e| format!("Invalid transaction result XDR: {}", e))
+    }
+
+    /// Decodes the XDR-encoded meta of the transaction, which holds the changes the transaction
+    /// made to the ledger. See [`TransactionResponse::ledger_entry_changes`] for a summary of
+    /// these changes.
+    pub fn decoded_result_meta_xdr(&self) -> Result<TransactionMeta, String> {
+        TransactionMeta::from_xdr_base64(&self.result_meta_xdr, Limits::none())
+            .map_err(|e| format!("Invalid transaction meta XDR: {}", e))
+    }
+
+    /// Decodes the XDR-encoded changes to the ledger made by charging the fee of the transaction.
+    pub fn decoded_fee_meta_xdr(&self) -> Result<LedgerEntryChanges, String> {
+        LedgerEntryChanges::from_xdr_base64(&self.fee_meta_xdr, Limits::none())
+            .map_err(|e| format!("Invalid fee meta XDR: {}", e))
+    }
+
+    /// Decodes the `result_meta_xdr` of the transaction into the changes it made to accounts,
+    /// trustlines and offers.
+    ///
+    /// # Returns
+    /// The before and after states of every changed account, trustline and offer, or an error if
+    /// the XDR could not be decoded.
+    ///
+    pub fn ledger_entry_changes(&self) -> Result<LedgerEntryDiffs, String> {
+        LedgerEntryDiffs::from_result_meta_xdr(&self.result_meta_xdr)
+    }
+
+    /// Retrieves the source account of the transaction, by following its `account` link.
+    pub async fn account(&self, horizon_client: &HorizonClient) -> Result<Account, Error> {
+        horizon_client
+            .follow_link(self.links.account.href.as_deref(), "account")
+            .await
+    }
+
+    /// Retrieves the ledger that included the transaction, by following its `ledger` link.
+    /// Unlike the `ledger` field, which holds the sequence of the ledger, this sends a request.
+    pub async fn load_ledger(&self, horizon_client: &HorizonClient) -> Result<Ledger, Error> {
+        horizon_client
+            .follow_link(self.links.ledger.href.as_deref(), "ledger")
+            .await
+    }
+
+    /// Retrieves the first page of the operations of the transaction, by following its
+    /// `operations` link. Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn operations(
+        &self,
+        horizon_client: &HorizonClient,
+    ) -> Result<OperationResponse, Error> {
+        horizon_client
+            .follow_link(self.links.operations.href.as_deref(), "operations")
+            .await
+    }
+
+    /// Retrieves the first page of the effects of the transaction, by following its `effects`
+    /// link. Use [`HorizonClient::follow`] to retrieve another page.
+    pub async fn effects(&self, horizon_client: &HorizonClient) -> Result<EffectsResponse, Error> {
+        horizon_client
+            .follow_link(self.links.effects.href.as_deref(), "effects")
+            .await
+    }
 }
 
 impl Response for TransactionResponse {
     fn from_json(json: String) -> Result<Self, String> {
-        serde_json::from_str(&json).map_err(|e| e.to_string())
+        crate::models::deserialize_json(&json)
+    }
+}
+
+/// Represents the status of a transaction that was submitted asynchronously.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum AsyncTransactionStatus {
+    /// The transaction was accepted by Stellar Core, and is waiting to be included in a ledger.
+    Pending,
+    /// The transaction was already submitted, and is still waiting to be included in a ledger.
+    Duplicate,
+    /// Stellar Core could not accept the transaction at the moment, for example because its
+    /// queue is full. The transaction can be submitted again later.
+    TryAgainLater,
+    /// The transaction was rejected by Stellar Core. The reason is given by the error result.
+    Error,
+    /// A status that is not known to this version of the SDK.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Represents the response of a transaction that was submitted asynchronously.
+///
+/// # Usage
+/// This struct is returned by
+/// [`HorizonClient::post_transaction_async`](crate::horizon_client::HorizonClient::post_transaction_async).
+/// It only tells whether Stellar Core accepted the transaction; once the status is `PENDING` or
+/// `DUPLICATE`, the transaction can be awaited with
+/// [`HorizonClient::wait_for_transaction`](crate::horizon_client::HorizonClient::wait_for_transaction).
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct AsyncTransactionResponse {
+    /// The status of the submission.
+    tx_status: AsyncTransactionStatus,
+    /// The hash of the transaction.
+    hash: TransactionHash,
+    /// A base64 encoded string of the raw `TransactionResult` XDR struct, if the status is
+    /// `ERROR`.
+    #[serde(
+        rename = "errorResultXdr",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    error_result_xdr: Option<String>,
+}
+
+impl AsyncTransactionResponse {
+    /// Decodes the XDR-encoded result of a rejected transaction, which holds the reason it was
+    /// rejected.
+    ///
+    /// # Returns
+    /// The result of the transaction, `None` if the transaction was not rejected, or an error if
+    /// the XDR could not be decoded.
+    ///
+    pub fn decoded_error_result_xdr(&self) -> Result<Option<TransactionResult>, String> {
+        self.error_result_xdr
+            .as_deref()
+            .map(|xdr| {
+                TransactionResult::from_xdr_base64(xdr, Limits::none())
+                    .map_err(|e| format!("Invalid transaction result XDR: {}", e))
+            })
+            .transpose()
+    }
+}
+
+impl Response for AsyncTransactionResponse {
+    fn from_json(json: String) -> Result<Self, String> {
+        crate::models::deserialize_json(&json)
     }
 }
@@ -0,0 +1,334 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+use stellar_xdr::curr::{Limits, ReadXdr, TransactionResult};
+
+/// Defines an enum of result codes, which converts from and to the codes reported by Horizon.
+macro_rules! result_codes {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $code:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A result code that is not known to this version of the SDK.
+            Other(String),
+        }
+
+        impl $name {
+            /// Returns the result code as reported by Horizon.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $code,)*
+                    $name::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => $name::$variant,)*
+                    other => $name::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+    };
+}
+
+result_codes! {
+    /// Represents the result code of a transaction, such as `tx_bad_seq`.
+    ///
+    /// Horizon reports the result code of a failed transaction in the `extras` of the
+    /// `transaction_failed` problem. See
+    /// <a href="https://developers.stellar.org/docs/data/horizon/api-reference/errors/result-codes/transactions">Transaction Result Codes</a>.
+    ///
+    pub enum TransactionResultCode {
+        /// The transaction succeeded.
+        Success = "tx_success",
+        /// One of the operations failed; see the result codes of the operations.
+        Failed = "tx_failed",
+        /// The ledger close time is before the lower time bound of the transaction.
+        TooEarly = "tx_too_early",
+        /// The ledger close time is after the upper time bound of the transaction.
+        TooLate = "tx_too_late",
+        /// The transaction has no operations.
+        MissingOperation = "tx_missing_operation",
+        /// The sequence number of the transaction does not follow the sequence number of the
+        /// source account.
+        BadSeq = "tx_bad_seq",
+        /// Too few valid signatures, or the transaction was submitted to the wrong network.
+        BadAuth = "tx_bad_auth",
+        /// The fee would bring the balance of the source account below its reserve.
+        InsufficientBalance = "tx_insufficient_balance",
+        /// The source account does not exist.
+        NoSourceAccount = "tx_no_source_account",
+        /// The fee is too small.
+        InsufficientFee = "tx_insufficient_fee",
+        /// The transaction has unused signatures.
+        BadAuthExtra = "tx_bad_auth_extra",
+        /// An unknown error occurred in Stellar Core.
+        InternalError = "tx_internal_error",
+        /// The transaction type is not supported.
+        NotSupported = "tx_not_supported",
+        /// The inner transaction of a fee bump transaction succeeded.
+        FeeBumpInnerSuccess = "tx_fee_bump_inner_success",
+        /// The inner transaction of a fee bump transaction failed.
+        FeeBumpInnerFailed = "tx_fee_bump_inner_failed",
+        /// The sponsorships of the transaction are not balanced.
+        BadSponsorship = "tx_bad_sponsorship",
+        /// The minimum sequence age or ledger gap precondition is not met.
+        BadMinSeqAgeOrGap = "tx_bad_min_seq_age_or_gap",
+        /// The transaction is malformed.
+        Malformed = "tx_malformed",
+        /// The Soroban resources of the transaction are invalid.
+        SorobanInvalid = "tx_soroban_invalid",
+    }
+}
+
+result_codes! {
+    /// Represents the result code of an operation, such as `op_underfunded`.
+    ///
+    /// Horizon reports the result codes of the operations of a failed transaction in the `extras`
+    /// of the `transaction_failed` problem. Operations that succeeded are reported as
+    /// `op_success`. See
+    /// <a href="https://developers.stellar.org/docs/data/horizon/api-reference/errors/result-codes/operations">Operation Result Codes</a>.
+    ///
+    pub enum OperationResultCode {
+        /// The operation succeeded.
+        Success = "op_success",
+        /// The operation has too few valid signatures.
+        BadAuth = "op_bad_auth",
+        /// The source account of the operation does not exist.
+        NoSourceAccount = "op_no_source_account",
+        /// The operation is not supported.
+        NotSupported = "op_not_supported",
+        /// The operation would exceed the maximum number of subentries of an account.
+        TooManySubentries = "op_too_many_subentries",
+        /// The operation did too much work.
+        ExceededWorkLimit = "op_exceeded_work_limit",
+        /// The operation would exceed the maximum number of sponsored entries of an account.
+        TooManySponsoring = "op_too_many_sponsoring",
+        /// The operation is malformed.
+        Malformed = "op_malformed",
+        /// The source account does not have enough funds.
+        Underfunded = "op_underfunded",
+        /// The operation would bring the balance of an account below its reserve.
+        LowReserve = "op_low_reserve",
+        /// The account or entry to create already exists.
+        AlreadyExists = "op_already_exists",
+        /// The source account does not trust the issuer of the asset.
+        SrcNoTrust = "op_src_no_trust",
+        /// The source account is not authorized to send the asset.
+        SrcNotAuthorized = "op_src_not_authorized",
+        /// The destination account does not exist.
+        NoDestination = "op_no_destination",
+        /// The destination account does not trust the issuer of the asset.
+        NoTrust = "op_no_trust",
+        /// The destination account is not authorized to hold the asset.
+        NotAuthorized = "op_not_authorized",
+        /// The destination account would exceed the limit of its trustline.
+        LineFull = "op_line_full",
+        /// The issuer of the asset does not exist.
+        NoIssuer = "op_no_issuer",
+        /// There are not enough offers to satisfy the path payment.
+        TooFewOffers = "op_too_few_offers",
+        /// The operation would cross an offer of the same account.
+        CrossSelf = "op_cross_self",
+        /// The path payment would send more than the maximum amount.
+        OverSourceMax = "op_over_source_max",
+        /// The path payment would receive less than the minimum amount.
+        UnderDestMin = "op_under_dest_min",
+        /// The account does not trust the issuer of the asset it is selling.
+        SellNoTrust = "op_sell_no_trust",
+        /// The account does not trust the issuer of the asset it is buying.
+        BuyNoTrust = "op_buy_no_trust",
+        /// The account is not authorized to sell the asset.
+        SellNotAuthorized = "op_sell_not_authorized",
+        /// The account is not authorized to buy the asset.
+        BuyNotAuthorized = "op_buy_not_authorized",
+        /// The offer to update or delete does not exist.
+        OfferNotFound = "op_offer_not_found",
+        /// The account would exceed the maximum number of signers.
+        TooManySigners = "op_too_many_signers",
+        /// The flags to set and to clear overlap.
+        BadFlags = "op_bad_flags",
+        /// The flag can not be changed, because the account is immutable.
+        CantChange = "op_cant_change",
+        /// The flag is unknown.
+        UnknownFlag = "op_unknown_flag",
+        /// A threshold is out of range.
+        ThresholdOutOfRange = "op_threshold_out_of_range",
+        /// The signer can not be added.
+        BadSigner = "op_bad_signer",
+        /// The home domain is invalid.
+        InvalidHomeDomain = "op_invalid_home_domain",
+        /// The limit of the trustline is invalid.
+        InvalidLimit = "op_invalid_limit",
+        /// The source account can not trust itself.
+        SelfNotAllowed = "op_self_not_allowed",
+        /// The trustline does not exist.
+        NoTrustLine = "op_no_trust_line",
+        /// The authorization of the trustline can not be revoked.
+        CantRevoke = "op_cant_revoke",
+        /// The account to merge into does not exist.
+        NoAccount = "op_no_account",
+        /// The account to merge has the `AUTH_IMMUTABLE` flag set.
+        ImmutableSet = "op_immutable_set",
+        /// The account to merge still has subentries.
+        HasSubEntries = "op_has_sub_entries",
+        /// The account to merge has a sequence number that is too high.
+        SeqNumTooFar = "op_seq_num_too_far",
+        /// The destination account would exceed its maximum balance.
+        DestFull = "op_dest_full",
+        /// The account to merge sponsors other entries.
+        IsSponsor = "op_is_sponsor",
+        /// The data entry to delete does not exist.
+        DataNameNotFound = "op_data_name_not_found",
+        /// The bump sequence is invalid.
+        BadSeq = "op_bad_seq",
+        /// The claimable balance or sponsorship does not exist.
+        DoesNotExist = "op_does_not_exist",
+        /// The source account is not a claimant of the claimable balance.
+        CannotClaim = "op_cannot_claim",
+        /// The asset does not have clawback enabled.
+        NotClawbackEnabled = "op_not_clawback_enabled",
+        /// The liquidity pool deposit or withdrawal is outside the price bounds.
+        BadPrice = "op_bad_price",
+        /// The liquidity pool is full.
+        PoolFull = "op_pool_full",
+        /// The liquidity pool withdrawal would receive less than the minimum amounts.
+        UnderMinimum = "op_under_minimum",
+    }
+}
+
+/// Represents the result codes of a failed transaction.
+///
+/// # Usage
+/// This struct is part of [`Error::TransactionFailed`](crate::error::Error::TransactionFailed),
+/// which is returned when Horizon rejects a submitted transaction. It holds the result code of
+/// the transaction, and the result codes of its operations in the order of the operations, so
+/// that applications can branch on them.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct TransactionResultCodes {
+    /// The result code of the transaction.
+    transaction: TransactionResultCode,
+    /// The result code of the inner transaction, if the transaction is a fee bump transaction.
+    inner_transaction: Option<TransactionResultCode>,
+    /// The result codes of the operations.
+    operations: Vec<OperationResultCode>,
+    /// A base64 encoded string of the raw `TransactionResult` XDR struct, if reported.
+    result_xdr: Option<String>,
+}
+
+impl TransactionResultCodes {
+    /// Parses the result codes from the `extras` of a `transaction_failed` problem.
+    pub(crate) fn from_extras(extras: &serde_json::Value) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct ResultCodes {
+            transaction: String,
+            inner_transaction: Option<String>,
+            #[serde(default)]
+            operations: Vec<String>,
+        }
+
+        let result_codes: ResultCodes =
+            serde_json::from_value(extras.get("result_codes")?.clone()).ok()?;
+
+        Some(TransactionResultCodes {
+            transaction: result_codes.transaction.as_str().into(),
+            inner_transaction: result_codes
+                .inner_transaction
+                .as_deref()
+                .map(TransactionResultCode::from),
+            operations: result_codes
+                .operations
+                .iter()
+                .map(|code| code.as_str().into())
+                .collect(),
+            result_xdr: extras
+                .get("result_xdr")
+                .and_then(|result_xdr| result_xdr.as_str())
+                .map(str::to_string),
+        })
+    }
+
+    /// Returns the operations that failed, along with their index in the transaction.
+    pub fn failed_operations(&self) -> Vec<(usize, &OperationResultCode)> {
+        self.operations
+            .iter()
+            .enumerate()
+            .filter(|(_, code)| **code != OperationResultCode::Success)
+            .collect()
+    }
+
+    /// Decodes the XDR-encoded result of the transaction.
+    ///
+    /// # Returns
+    /// The result of the transaction, `None` if Horizon did not report it, or an error if the
+    /// XDR could not be decoded.
+    ///
+    pub fn decoded_result_xdr(&self) -> Result<Option<TransactionResult>, String> {
+        self.result_xdr
+            .as_deref()
+            .map(|xdr| {
+                TransactionResult::from_xdr_base64(xdr, Limits::none())
+                    .map_err(|e| format!("Invalid transaction result XDR: {}", e))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_codes() {
+        let extras = serde_json::json!({
+            "envelope_xdr": "AAAA",
+            "result_codes": {
+                "transaction": "tx_failed",
+                "operations": ["op_success", "op_underfunded", "op_made_up"]
+            },
+            "result_xdr": "AAAAAAAAAGT////7AAAAAA=="
+        });
+
+        let result_codes = TransactionResultCodes::from_extras(&extras).unwrap();
+        assert_eq!(*result_codes.transaction(), TransactionResultCode::Failed);
+        assert_eq!(*result_codes.inner_transaction(), None);
+        assert_eq!(
+            result_codes.failed_operations(),
+            vec![
+                (1, &OperationResultCode::Underfunded),
+                (2, &OperationResultCode::Other("op_made_up".to_string()))
+            ]
+        );
+        assert_eq!(result_codes.operations()[2].as_str(), "op_made_up");
+        assert_eq!(
+            result_codes
+                .decoded_result_xdr()
+                .unwrap()
+                .unwrap()
+                .fee_charged,
+            100
+        );
+
+        assert_eq!(
+            TransactionResultCode::from("tx_bad_seq").to_string(),
+            "tx_bad_seq"
+        );
+        assert!(TransactionResultCodes::from_extras(&serde_json::json!({})).is_none());
+    }
+}
@@ -0,0 +1,339 @@
+use super::response::TransactionResponse;
+use super::transaction_hash::{hash_envelope, sha256, TransactionHash};
+use crate::accounts::prelude::{Signer, SignerKey};
+use base64::Engine;
+use derive_getters::Getters;
+use std::str::FromStr;
+use stellar_xdr::curr::{self as xdr, Limits, ReadXdr};
+
+/// Represents a signature attached to a transaction, along with the signer that produced it.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct SignatureCheck {
+    /// The base64-encoded signature, as listed in the `signatures` of the transaction.
+    signature: String,
+    /// The key of the signer that produced the signature, if it was produced by a signer of the
+    /// account or by an extra signer of the transaction.
+    signer: Option<SignerKey>,
+    /// The weight of the signer on the account, or `None` if the signature was not produced by a
+    /// signer of the account.
+    weight: Option<u32>,
+}
+
+/// Represents the outcome of verifying the signatures of a transaction, as returned by
+/// [`TransactionResponse::verify_signatures`].
+///
+/// The signatures are verified against the signers of the account that authorizes the
+/// transaction: the source account, or the fee account of a fee bump transaction. Signatures
+/// that were produced by none of these signers, such as signatures of the source accounts of
+/// operations, have no signer in the report.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct SignatureReport {
+    /// The hash of the transaction, which the signatures sign.
+    hash: TransactionHash,
+    /// The signatures attached to the transaction, in the order of the envelope.
+    signatures: Vec<SignatureCheck>,
+    /// The weight of the pre-authorized transaction signer of the account for this transaction,
+    /// if the account has one.
+    pre_authorized_weight: Option<u32>,
+    /// The extra signers of the transaction that none of the signatures satisfies.
+    missing_extra_signers: Vec<SignerKey>,
+}
+
+impl SignatureReport {
+    /// Returns the total weight of the signers of the account that signed the transaction,
+    /// including a pre-authorized transaction signer. A signer that signed more than once is
+    /// counted once.
+    pub fn signed_weight(&self) -> u32 {
+        let mut counted: Vec<&SignerKey> = Vec::new();
+        let mut weight = self.pre_authorized_weight.unwrap_or(0);
+        for check in &self.signatures {
+            if let (Some(signer), Some(signer_weight)) = (&check.signer, check.weight) {
+                if !counted.contains(&signer) {
+                    counted.push(signer);
+                    weight += signer_weight;
+                }
+            }
+        }
+        weight
+    }
+
+    /// Returns the keys of the signers of the account that signed the transaction, which can be
+    /// passed to [`MultisigPolicy::missing_weight`](crate::accounts::prelude::MultisigPolicy::missing_weight).
+    pub fn signer_keys(&self) -> impl Iterator<Item = &str> {
+        self.signatures
+            .iter()
+            .filter(|check| check.weight.is_some())
+            .filter_map(|check| check.signer.as_ref().map(SignerKey::key))
+    }
+
+    /// Returns whether every signature was produced by a signer of the account or by an extra
+    /// signer of the transaction.
+    pub fn all_signatures_recognized(&self) -> bool {
+        self.signatures.iter().all(|check| check.signer.is_some())
+    }
+}
+
+impl TransactionResponse {
+    /// Verifies the signatures of the transaction against the given signers.
+    ///
+    /// This method decodes the envelope of the transaction, recomputes its hash for the given
+    /// network, and checks which of the signers produced each of the attached signatures. The
+    /// signers are those of the account that authorizes the transaction: the source account, or
+    /// the fee account of a fee bump transaction. Signers of type `ed25519_public_key` and
+    /// `sha256_hash` are matched against the signatures, and a `preauth_tx` signer matches if it
+    /// pre-authorized this very transaction. The extra signers of the transaction are checked as
+    /// well.
+    ///
+    /// The signers of an account may have changed since the transaction was applied, so a
+    /// signature that was valid at the time may no longer be produced by a current signer. To
+    /// fetch the current signers along the way, use
+    /// [`HorizonClient::verify_transaction_signatures`](crate::horizon_client::HorizonClient::verify_transaction_signatures).
+    ///
+    /// # Arguments
+    /// * `network_passphrase` - The passphrase of the network the transaction was applied on,
+    ///   such as [`Network::Testnet.passphrase()`](crate::network::Network::passphrase).
+    /// * `signers` - The signers of the account that authorizes the transaction.
+    ///
+    /// # Returns
+    /// A [`SignatureReport`], or an error if the envelope could not be decoded, or if the
+    /// recomputed hash does not match the hash of the transaction, which means the transaction
+    /// was not applied on the given network.
+    ///
+    pub fn verify_signatures(
+        &self,
+        network_passphrase: &str,
+        signers: &[Signer],
+    ) -> Result<SignatureReport, String> {
+        let envelope =
+            xdr::TransactionEnvelope::from_xdr_base64(self.envelope_xdr(), Limits::none())
+                .map_err(|e| format!("Invalid transaction envelope XDR: {}", e))?;
+
+        let (signatures, extra_signers) = match &envelope {
+            xdr::TransactionEnvelope::TxV0(envelope) => (&envelope.signatures, Vec::new()),
+            xdr::TransactionEnvelope::Tx(envelope) => {
+                let extra_signers = match &envelope.tx.cond {
+                    xdr::Preconditions::V2(preconditions) => preconditions.extra_signers.to_vec(),
+                    _ => Vec::new(),
+                };
+                (&envelope.signatures, extra_signers)
+            }
+            // The signatures of a fee bump transaction authorize the fee account, which can not
+            // be subject to the extra signers of the inner transaction.
+            xdr::TransactionEnvelope::TxFeeBump(envelope) => (&envelope.signatures, Vec::new()),
+        };
+        let signatures = signatures.to_vec();
+
+        let hash = hash_envelope(envelope, network_passphrase)?;
+        let transaction_hash = TransactionHash::from_str(&hex::encode(hash))?;
+        if transaction_hash != *self.hash() {
+            return Err(format!(
+                "The transaction {} was not applied on the network \"{}\"",
+                self.hash(),
+                network_passphrase
+            ));
+        }
+
+        // The candidates that may have produced the signatures, along with their weights on the
+        // account. The signers of the account take precedence over the extra signers.
+        let mut candidates = Vec::new();
+        for signer in signers {
+            let key = xdr::SignerKey::from_str(signer.key())
+                .map_err(|_| format!("Invalid signer key: {}", signer.key()))?;
+            candidates.push((key, signer.signer_key(), Some(*signer.weight())));
+        }
+        for key in &extra_signers {
+            candidates.push((key.clone(), SignerKey::from_str(&key.to_string())?, None));
+        }
+
+        let pre_authorized_weight = candidates.iter().find_map(|(key, _, weight)| match key {
+            xdr::SignerKey::PreAuthTx(pre_authorized) if pre_authorized.0 == hash => *weight,
+            _ => None,
+        });
+
+        let checks: Vec<SignatureCheck> = signatures
+            .iter()
+            .map(|signature| {
+                let candidate = candidates
+                    .iter()
+                    .find(|(key, _, _)| is_produced_by(signature, key, &hash));
+
+                SignatureCheck {
+                    signature: base64::engine::general_purpose::STANDARD
+                        .encode(signature.signature.as_slice()),
+                    signer: candidate.map(|(_, signer_key, _)| signer_key.clone()),
+                    weight: candidate.and_then(|(_, _, weight)| *weight),
+                }
+            })
+            .collect();
+
+        let missing_extra_signers = candidates
+            .into_iter()
+            .filter(|(_, _, weight)| weight.is_none())
+            .map(|(_, signer_key, _)| signer_key)
+            .filter(|extra_signer| {
+                !checks
+                    .iter()
+                    .any(|check| check.signer.as_ref() == Some(extra_signer))
+            })
+            .collect();
+
+        Ok(SignatureReport {
+            hash: transaction_hash,
+            signatures: checks,
+            pre_authorized_weight,
+            missing_extra_signers,
+        })
+    }
+}
+
+/// Returns whether a signature was produced by the given signer, for a transaction with the given
+/// hash.
+fn is_produced_by(
+    signature: &xdr::DecoratedSignature,
+    key: &xdr::SignerKey,
+    hash: &[u8; 32],
+) -> bool {
+    let verify = |public_key: &[u8; 32], message: &[u8]| {
+        ring::signature::UnparsedPublicKey::new(&ring::signature::ED25519, public_key)
+            .verify(message, signature.signature.as_slice())
+            .is_ok()
+    };
+
+    match key {
+        xdr::SignerKey::Ed25519(public_key) => verify(&public_key.0, hash),
+        // The signature of a hash signer is the preimage of the hash.
+        xdr::SignerKey::HashX(hash_x) => sha256(signature.signature.as_slice()) == hash_x.0,
+        xdr::SignerKey::Ed25519SignedPayload(signed_payload) => {
+            verify(&signed_payload.ed25519.0, signed_payload.payload.as_slice())
+        }
+        // Pre-authorized transactions are authorized without a signature.
+        xdr::SignerKey::PreAuthTx(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Response;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use stellar_xdr::curr::WriteXdr;
+
+    const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+    fn key_pair(seed: u8) -> (Ed25519KeyPair, SignerKey) {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&[seed; 32]).unwrap();
+        let public_key: [u8; 32] = key_pair.public_key().as_ref().try_into().unwrap();
+        let key = xdr::SignerKey::Ed25519(xdr::Uint256(public_key)).to_string();
+        (key_pair, key.parse().unwrap())
+    }
+
+    fn signer(key: &SignerKey, weight: u32) -> Signer {
+        serde_json::from_value(serde_json::json!({
+            "weight": weight,
+            "key": key.key(),
+            "type": "ed25519_public_key",
+        }))
+        .unwrap()
+    }
+
+    /// Builds a transaction of the source account, signed by the given key pairs.
+    fn transaction(
+        source: &SignerKey,
+        extra_signers: &[&SignerKey],
+        key_pairs: &[&Ed25519KeyPair],
+    ) -> TransactionResponse {
+        let xdr_key = |key: &SignerKey| xdr::SignerKey::from_str(key.key()).unwrap();
+        let xdr::SignerKey::Ed25519(source) = xdr_key(source) else {
+            unreachable!()
+        };
+        let extra_signers: Vec<xdr::SignerKey> = extra_signers.iter().map(|k| xdr_key(k)).collect();
+        let tx = xdr::Transaction {
+            source_account: xdr::MuxedAccount::Ed25519(source),
+            fee: 100,
+            seq_num: xdr::SequenceNumber(1),
+            cond: xdr::Preconditions::V2(xdr::PreconditionsV2 {
+                time_bounds: None,
+                ledger_bounds: None,
+                min_seq_num: None,
+                min_seq_age: xdr::Duration(0),
+                min_seq_ledger_gap: 0,
+                extra_signers: extra_signers.try_into().unwrap(),
+            }),
+            memo: xdr::Memo::None,
+            operations: vec![xdr::Operation {
+                source_account: None,
+                body: xdr::OperationBody::BumpSequence(xdr::BumpSequenceOp {
+                    bump_to: xdr::SequenceNumber(2),
+                }),
+            }]
+            .try_into()
+            .unwrap(),
+            ext: xdr::TransactionExt::V0,
+        };
+        let mut envelope = xdr::TransactionEnvelope::Tx(xdr::TransactionV1Envelope {
+            tx,
+            signatures: Default::default(),
+        });
+        let hash = hash_envelope(envelope.clone(), PASSPHRASE).unwrap();
+
+        if let xdr::TransactionEnvelope::Tx(envelope) = &mut envelope {
+            let signatures: Vec<xdr::DecoratedSignature> = key_pairs
+                .iter()
+                .map(|key_pair| xdr::DecoratedSignature {
+                    hint: xdr::SignatureHint(
+                        key_pair.public_key().as_ref()[28..].try_into().unwrap(),
+                    ),
+                    signature: key_pair.sign(&hash).as_ref().to_vec().try_into().unwrap(),
+                })
+                .collect();
+            envelope.signatures = signatures.try_into().unwrap();
+        }
+
+        let json = include_str!("../../tests/fixtures/transactions/single_transaction.json");
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        value["hash"] = hex::encode(hash).into();
+        value["envelope_xdr"] = envelope.to_xdr_base64(Limits::none()).unwrap().into();
+        TransactionResponse::from_json(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_verify_signatures() {
+        let (master, master_key) = key_pair(1);
+        let (cosigner, cosigner_key) = key_pair(2);
+        let (stranger, _) = key_pair(3);
+        let (_, extra_signer_key) = key_pair(4);
+        let signers = [signer(&master_key, 1), signer(&cosigner_key, 2)];
+
+        let transaction = transaction(
+            &master_key,
+            &[&extra_signer_key],
+            &[&master, &stranger, &cosigner, &master],
+        );
+        let report = transaction.verify_signatures(PASSPHRASE, &signers).unwrap();
+
+        assert_eq!(report.hash(), transaction.hash());
+        let signers: Vec<_> = report
+            .signatures()
+            .iter()
+            .map(|check| check.signer().clone())
+            .collect();
+        assert_eq!(
+            signers,
+            vec![
+                Some(master_key.clone()),
+                None,
+                Some(cosigner_key),
+                Some(master_key)
+            ]
+        );
+        assert_eq!(report.signed_weight(), 3);
+        assert!(!report.all_signatures_recognized());
+        assert_eq!(report.missing_extra_signers(), &vec![extra_signer_key]);
+
+        // A different network yields a different hash.
+        assert!(transaction
+            .verify_signatures("Public Global Stellar Network ; September 2015", &[])
+            .is_err());
+    }
+}
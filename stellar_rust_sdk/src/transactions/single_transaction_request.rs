@@ -1,15 +1,12 @@
+use super::transaction_hash::TransactionHash;
 use crate::models::*;
 
-/// Represents the transaction hash.
-#[derive(Default, Clone)]
-pub struct TransactionHash(String);
-
 /// Represents the absence of a transaction hash.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTransactionHash;
 
-#[derive(Default)]
-pub struct SingleTransactionRequest<T> {
+#[derive(Default, PartialEq, Eq, Hash)]
+pub struct SingleTransactionRequest<T = NoTransactionHash> {
     /// Transaction hash must be a hex-encoded, lowercase SHA-256, 64 char string.
     transaction_hash: T,
 }
@@ -29,20 +26,16 @@ impl SingleTransactionRequest<NoTransactionHash> {
         self,
         transaction_hash: impl Into<String>,
     ) -> Result<SingleTransactionRequest<TransactionHash>, String> {
-        let transaction_hash = transaction_hash.into();
-        match transaction_hash.len() {
-            64 => Ok(SingleTransactionRequest {
-                transaction_hash: TransactionHash(transaction_hash),
-            }),
-            _ => Err("Transaction hash must be 64 characters long".to_string()),
-        }
+        Ok(SingleTransactionRequest {
+            transaction_hash: transaction_hash.into().parse()?,
+        })
     }
 }
 
 impl Request for SingleTransactionRequest<TransactionHash> {
     fn get_query_parameters(&self) -> String {
         let mut query = String::new();
-        query.push_str(&format!("{}", self.transaction_hash.0));
+        query.push_str(&format!("{}", self.transaction_hash));
 
         query.trim_end_matches('&').to_string()
     }
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use stellar_xdr::curr::{self as xdr, Limits, ReadXdr, WriteXdr};
+
+/// Represents the hash of a transaction.
+///
+/// A transaction hash is the hex-encoded SHA-256 hash of the XDR-encoded transaction, which is a
+/// string of 64 hexadecimal characters. A `TransactionHash` validates such a string once, and
+/// normalizes it to lowercase, so that an operation ID or any other string can not be passed where
+/// a transaction hash is expected.
+///
+/// A `TransactionHash` is (de)serialized as a string, matching the representation used by Horizon.
+///
+/// # Example
+/// ```
+/// use stellar_rs::transactions::prelude::TransactionHash;
+///
+/// let hash: TransactionHash = "B9D0B2292C4E09E8EB22D036171491E87B8D2086BF8B265874C8D182CB9C9020"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(
+///     hash.as_str(),
+///     "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020"
+/// );
+///
+/// assert!("459561504769".parse::<TransactionHash>().is_err());
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TransactionHash(String);
+
+impl TransactionHash {
+    /// Returns the hash as a lowercase, hex-encoded string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for TransactionHash {
+    type Err = String;
+
+    fn from_str(transaction_hash: &str) -> Result<Self, Self::Err> {
+        if transaction_hash.len() != 64 {
+            return Err("Transaction hash must be 64 characters long".to_string());
+        }
+        if !transaction_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err("Transaction hash must consist of hexadecimal characters".to_string());
+        }
+
+        Ok(TransactionHash(transaction_hash.to_ascii_lowercase()))
+    }
+}
+
+impl TryFrom<String> for TransactionHash {
+    type Error = String;
+
+    fn try_from(transaction_hash: String) -> Result<Self, Self::Error> {
+        transaction_hash.parse()
+    }
+}
+
+impl From<TransactionHash> for String {
+    fn from(transaction_hash: TransactionHash) -> Self {
+        transaction_hash.0
+    }
+}
+
+impl AsRef<str> for TransactionHash {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TransactionHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<str> for TransactionHash {
+    fn eq(&self, other: &str) -> bool {
+        self.0.eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for TransactionHash {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Computes the hash that the network will assign to a transaction, without submitting it.
+///
+/// The hash is the SHA-256 hash of the transaction, tagged with the ID of the network it is
+/// submitted to, so the same envelope yields a different hash on every network. It is the hash
+/// Horizon reports for the transaction, and the payload that the signatures of the transaction
+/// sign. This allows correlating a transaction before it is submitted, checking whether a
+/// transaction was already included before resubmitting it, and verifying signatures locally.
+///
+/// Signatures are not part of the hash, so signing a transaction does not change its hash. The
+/// hash of a fee bump transaction is the hash of the fee bump, not of the inner transaction.
+///
+/// # Arguments
+/// * `envelope_xdr` - The base64-encoded transaction envelope XDR.
+/// * `network_passphrase` - The passphrase of the network, such as
+///   `"Test SDF Network ; September 2015"` for the testnet.
+///
+/// # Returns
+/// The hash of the transaction, or an error if the transaction envelope XDR could not be decoded.
+///
+/// # Example
+/// ```
+/// # use stellar_rs::transactions::prelude::compute_transaction_hash;
+/// # fn example(envelope_xdr: &str) -> Result<(), String> {
+/// let hash = compute_transaction_hash(envelope_xdr, "Test SDF Network ; September 2015")?;
+/// println!("The transaction will be known as {}", hash);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn compute_transaction_hash(
+    envelope_xdr: &str,
+    network_passphrase: &str,
+) -> Result<TransactionHash, String> {
+    let envelope = xdr::TransactionEnvelope::from_xdr_base64(envelope_xdr, Limits::none())
+        .map_err(|e| format!("Invalid transaction envelope XDR: {}", e))?;
+
+    Ok(TransactionHash(hex::encode(hash_envelope(
+        envelope,
+        network_passphrase,
+    )?)))
+}
+
+/// Computes the hash of a decoded transaction envelope for the given network, which is the
+/// payload that the signatures of the transaction sign.
+pub(crate) fn hash_envelope(
+    envelope: xdr::TransactionEnvelope,
+    network_passphrase: &str,
+) -> Result<[u8; 32], String> {
+    let tagged_transaction = match envelope {
+        // Legacy (V0) envelopes are hashed as if they were V1 transactions.
+        xdr::TransactionEnvelope::TxV0(envelope) => {
+            let tx = envelope.tx;
+            xdr::TransactionSignaturePayloadTaggedTransaction::Tx(xdr::Transaction {
+                source_account: xdr::MuxedAccount::Ed25519(tx.source_account_ed25519),
+                fee: tx.fee,
+                seq_num: tx.seq_num,
+                cond: match tx.time_bounds {
+                    Some(time_bounds) => xdr::Preconditions::Time(time_bounds),
+                    None => xdr::Preconditions::None,
+                },
+                memo: tx.memo,
+                operations: tx.operations,
+                ext: xdr::TransactionExt::V0,
+            })
+        }
+        xdr::TransactionEnvelope::Tx(envelope) => {
+            xdr::TransactionSignaturePayloadTaggedTransaction::Tx(envelope.tx)
+        }
+        xdr::TransactionEnvelope::TxFeeBump(envelope) => {
+            xdr::TransactionSignaturePayloadTaggedTransaction::TxFeeBump(envelope.tx)
+        }
+    };
+
+    let payload = xdr::TransactionSignaturePayload {
+        network_id: xdr::Hash(sha256(network_passphrase.as_bytes())),
+        tagged_transaction,
+    };
+    let payload = payload
+        .to_xdr(Limits::none())
+        .map_err(|e| format!("Failed to encode transaction signature payload: {}", e))?;
+
+    Ok(sha256(&payload))
+}
+
+/// Calculates the SHA-256 digest of the given data.
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let digest = ring::digest::digest(&ring::digest::SHA256, data);
+    let mut hash = [0; 32];
+    hash.copy_from_slice(digest.as_ref());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "b9d0b2292c4e09e8eb22d036171491e87b8d2086bf8b265874c8d182cb9c9020";
+
+    #[test]
+    fn test_transaction_hash() {
+        let hash: TransactionHash = HASH.to_uppercase().parse().unwrap();
+        assert_eq!(hash.as_str(), HASH);
+        assert_eq!(hash, HASH);
+        assert_eq!(
+            "abc".parse::<TransactionHash>().unwrap_err(),
+            "Transaction hash must be 64 characters long"
+        );
+        assert_eq!(
+            HASH.replace('b', "g")
+                .parse::<TransactionHash>()
+                .unwrap_err(),
+            "Transaction hash must consist of hexadecimal characters"
+        );
+    }
+
+    #[test]
+    fn test_transaction_hash_serde() {
+        let hash: TransactionHash = serde_json::from_str(&format!("\"{}\"", HASH)).unwrap();
+        assert_eq!(hash.as_str(), HASH);
+        assert_eq!(
+            serde_json::to_string(&hash).unwrap(),
+            format!("\"{}\"", HASH)
+        );
+        assert!(serde_json::from_str::<TransactionHash>("\"459561504769\"").is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use super::response::TransactionResponse;
+use std::time::Duration;
+
+/// The default interval between two attempts to find a transaction after a submission timed out.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The default number of attempts to find a transaction after a submission timed out.
+const DEFAULT_MAX_POLLS: u32 = 6;
+
+/// Represents the options for submitting a transaction using
+/// [`HorizonClient::submit_transaction`](crate::horizon_client::HorizonClient::submit_transaction).
+///
+/// When Horizon does not receive a result from Stellar Core in time, it responds with a
+/// `504 Gateway Timeout`. The transaction may still be included in a ledger afterwards, so the
+/// submission must not simply be treated as failed. These options determine how the client
+/// resolves the outcome of such a submission: it computes the hash of the transaction locally,
+/// which requires the network passphrase, and looks up the transaction up to `max_polls` times,
+/// waiting `poll_interval` before every attempt.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use stellar_rs::transactions::prelude::*;
+///
+/// let options = SubmitTransactionOptions::new("Test SDF Network ; September 2015")
+///     .set_poll_interval(Duration::from_secs(2))
+///     .set_max_polls(10)
+///     .unwrap();
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmitTransactionOptions {
+    /// The passphrase of the network the transaction is submitted to.
+    network_passphrase: String,
+    /// The time to wait before every attempt to find the transaction.
+    poll_interval: Duration,
+    /// The maximum number of attempts to find the transaction.
+    max_polls: u32,
+}
+
+impl SubmitTransactionOptions {
+    /// Creates new `SubmitTransactionOptions` with the default polling behaviour: up to 6
+    /// attempts, 5 seconds apart, which covers the time a transaction can spend in the queue of
+    /// Stellar Core before being included or dropped.
+    ///
+    /// # Arguments
+    /// * `network_passphrase` - The passphrase of the network the transaction is submitted to.
+    ///
+    pub fn new(network_passphrase: impl Into<String>) -> Self {
+        SubmitTransactionOptions {
+            network_passphrase: network_passphrase.into(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_polls: DEFAULT_MAX_POLLS,
+        }
+    }
+
+    /// Sets the time to wait before every attempt to find the transaction.
+    pub fn set_poll_interval(self, poll_interval: Duration) -> Self {
+        Self {
+            poll_interval,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of attempts to find the transaction.
+    ///
+    /// # Returns
+    /// The updated options, or an error if `max_polls` is `0`.
+    ///
+    pub fn set_max_polls(self, max_polls: u32) -> Result<Self, String> {
+        if max_polls < 1 {
+            return Err("Maximum number of polls must be greater than or equal to 1.".to_string());
+        }
+
+        Ok(Self { max_polls, ..self })
+    }
+
+    /// Returns the passphrase of the network the transaction is submitted to.
+    pub fn network_passphrase(&self) -> &str {
+        &self.network_passphrase
+    }
+
+    /// Returns the time to wait before every attempt to find the transaction.
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// Returns the maximum number of attempts to find the transaction.
+    pub fn max_polls(&self) -> u32 {
+        self.max_polls
+    }
+}
+
+/// Represents the outcome of a transaction submitted using
+/// [`HorizonClient::submit_transaction`](crate::horizon_client::HorizonClient::submit_transaction).
+///
+#[derive(Debug, Clone)]
+pub enum TransactionSubmissionOutcome {
+    /// The transaction was submitted and included in a ledger, and Horizon responded in time.
+    Submitted(TransactionResponse),
+    /// The submission timed out, but the transaction was found in a ledger afterwards.
+    ConfirmedAfterTimeout(TransactionResponse),
+    /// The submission timed out, and the transaction was not found in a ledger before the maximum
+    /// number of polls was reached. The transaction may still be included later, or it may have
+    /// been dropped; resubmitting the same transaction envelope is safe either way.
+    Pending {
+        /// The hash of the transaction, which can be used to look it up later.
+        hash: String,
+    },
+}
+
+impl TransactionSubmissionOutcome {
+    /// Returns the transaction, if it was found in a ledger.
+    pub fn transaction(&self) -> Option<&TransactionResponse> {
+        match self {
+            TransactionSubmissionOutcome::Submitted(transaction)
+            | TransactionSubmissionOutcome::ConfirmedAfterTimeout(transaction) => Some(transaction),
+            TransactionSubmissionOutcome::Pending { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submit_transaction_options() {
+        let options = SubmitTransactionOptions::new("Test SDF Network ; September 2015");
+        assert_eq!(options.poll_interval(), DEFAULT_POLL_INTERVAL);
+        assert_eq!(options.max_polls(), DEFAULT_MAX_POLLS);
+        assert_eq!(
+            options.clone().set_max_polls(0).unwrap_err(),
+            "Maximum number of polls must be greater than or equal to 1."
+        );
+        assert_eq!(options.set_max_polls(3).unwrap().max_polls(), 3);
+    }
+}
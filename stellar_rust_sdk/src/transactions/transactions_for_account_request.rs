@@ -1,21 +1,33 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use crate::models::*;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of an account for which the transactions are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct TransactionsAccountId(String);
 
+impl fmt::Display for TransactionsAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Represents the absence of an ID of an account for which the transactions are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTransactionsAccountId;
 
 #[pagination]
-#[derive(Default)]
-pub struct TransactionsForAccountRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRANSACTIONS_PATH, parent = account_id)]
+pub struct TransactionsForAccountRequest<I = NoTransactionsAccountId> {
     /// The ID of the account for which the transactions are to be retrieved.
     account_id: I,
     // Indicates whether or not to include failed operations in the response.
     include_failed: Option<bool>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl TransactionsForAccountRequest<NoTransactionsAccountId> {
@@ -27,21 +39,20 @@ impl TransactionsForAccountRequest<NoTransactionsAccountId> {
     /// Sets the account ID for the request.
     ///
     /// # Arguments
-    /// * `account_id` - The account ID for which the transactions are to be retrieved.
+    /// * `account_id` - The account ID for which the transactions are to be retrieved. A muxed
+    ///   account (`M...`) is resolved to its underlying account.
     ///
     /// # Returns
     /// A `TransactionsForAccountRequest` with the specified account ID, or an error if the account ID is invalid.
     ///
     pub fn set_account_id(
         self,
-        account_id: impl Into<String>
+        account_id: impl Into<String>,
     ) -> Result<TransactionsForAccountRequest<TransactionsAccountId>, String> {
         Ok(TransactionsForAccountRequest {
-            account_id: TransactionsAccountId(account_id.into()),
+            account_id: TransactionsAccountId(resolve_account_id(account_id.into())),
             include_failed: self.include_failed,
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
@@ -63,38 +74,42 @@ impl TransactionsForAccountRequest<TransactionsAccountId> {
         Ok(TransactionsForAccountRequest {
             account_id: self.account_id,
             include_failed: Some(include_failed),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
 
-impl Request for TransactionsForAccountRequest<TransactionsAccountId> {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-        ]
-        .build_query_parameters()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_cursor() {
+        // Paging tokens of transactions do not fit in a `u32`.
+        let request = TransactionsForAccountRequest::new()
+            .set_account_id("GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H")
+            .unwrap()
+            .set_cursor(2314987376641)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/accounts/GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H/transactions?cursor=2314987376641&order=desc"
+        );
     }
 
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL comprises paths and query parameters.
-        // Additionally, this request uses the API endpoint for `accounts`.
-        let account_id = &self.account_id.0;
-        use crate::accounts::ACCOUNTS_PATH;
-        format!(
-            "{}/{}/{}/{}{}",
-            base_url,
-            ACCOUNTS_PATH,
-            account_id,
-            super::TRANSACTIONS_PATH,
-            self.get_query_parameters(),
-        )
+    #[test]
+    fn test_muxed_account_id() {
+        // The transactions of a muxed account are indexed by its underlying account.
+        let request = TransactionsForAccountRequest::new()
+            .set_account_id("MBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OAAAAAAAAAAAPOGVY")
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/accounts/GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H/transactions"
+        );
     }
 }
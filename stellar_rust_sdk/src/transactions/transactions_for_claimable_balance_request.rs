@@ -0,0 +1,105 @@
+use crate::models::pagination::PageParams;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
+
+/// Represents the ID of a claimable balance for which the transactions are to be retrieved.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionsClaimableBalanceId(String);
+
+impl fmt::Display for TransactionsClaimableBalanceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Represents the absence of an ID of a claimable balance for which the transactions are to be retrieved.
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
+pub struct NoTransactionsClaimableBalanceId;
+
+/// Represents a request to list the transactions that reference a claimable balance, such as the
+/// transactions that created, claimed or clawed back the balance.
+///
+/// The struct matches the parameters necessary to construct a request for the
+/// <a href="https://developers.stellar.org/docs/data/horizon/api-reference/cb-retrieve-related-transactions">Retrieve Related Transactions</a>
+/// endpoint of the Horizon API, and is used with the
+/// [`HorizonClient::get_transactions_for_claimable_balance`](crate::horizon_client::HorizonClient::get_transactions_for_claimable_balance)
+/// method.
+///
+#[pagination]
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRANSACTIONS_PATH, parent = claimable_balance_id)]
+pub struct TransactionsForClaimableBalanceRequest<I = NoTransactionsClaimableBalanceId> {
+    /// The ID of the claimable balance for which the transactions are to be retrieved.
+    claimable_balance_id: I,
+    // Indicates whether or not to include failed transactions in the response.
+    include_failed: Option<bool>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
+}
+
+impl TransactionsForClaimableBalanceRequest<NoTransactionsClaimableBalanceId> {
+    /// Creates a new `TransactionsForClaimableBalanceRequest` with default parameters.
+    pub fn new() -> Self {
+        TransactionsForClaimableBalanceRequest::default()
+    }
+
+    /// Sets the claimable balance ID for the request.
+    ///
+    /// # Arguments
+    /// * `claimable_balance_id` - The claimable balance ID for which the transactions are to be retrieved.
+    ///
+    /// # Returns
+    /// A `TransactionsForClaimableBalanceRequest` with the specified claimable balance ID.
+    ///
+    pub fn set_claimable_balance_id(
+        self,
+        claimable_balance_id: impl Into<String>,
+    ) -> TransactionsForClaimableBalanceRequest<TransactionsClaimableBalanceId> {
+        TransactionsForClaimableBalanceRequest {
+            claimable_balance_id: TransactionsClaimableBalanceId(claimable_balance_id.into()),
+            include_failed: self.include_failed,
+            page_params: self.page_params,
+        }
+    }
+}
+
+impl TransactionsForClaimableBalanceRequest<TransactionsClaimableBalanceId> {
+    /// Sets the `include_failed` field for the request. Can only be set on a request that
+    /// has a set claimable balance id.
+    ///
+    /// # Arguments
+    /// * `include_failed` - A `bool` to indicate whether or not to include failed transactions.
+    ///
+    pub fn set_include_failed(
+        self,
+        include_failed: bool,
+    ) -> TransactionsForClaimableBalanceRequest<TransactionsClaimableBalanceId> {
+        TransactionsForClaimableBalanceRequest {
+            include_failed: Some(include_failed),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Request;
+
+    #[test]
+    fn test_transactions_for_claimable_balance_request() {
+        let request = TransactionsForClaimableBalanceRequest::new()
+            .set_claimable_balance_id(
+                "00000000e7a2e5a4c47cd0d4fe1b3e2d9c1f5b8b1f2c1fa1a4ddf2f8b7a8e5ab4d2e1f30",
+            )
+            .set_include_failed(true)
+            .set_limit(5)
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/claimable_balances/00000000e7a2e5a4c47cd0d4fe1b3e2d9c1f5b8b1f2c1fa1a4ddf2f8b7a8e5ab4d2e1f30/transactions?include_failed=true&limit=5"
+        );
+    }
+}
@@ -1,21 +1,25 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::ledgers::prelude::LedgerSequence;
+use crate::models::pagination::PageParams;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of a ledger for which the transactions are to be retrieved.
-#[derive(Default, Clone)]
-pub struct TransactionsLedgerId(String);
+pub type TransactionsLedgerId = LedgerSequence;
 
 /// Represents the absence of an ID of a ledger for which the transactions are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTransactionsLedgerId;
 
 #[pagination]
-#[derive(Default)]
-pub struct TransactionsForLedgerRequest<S> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRANSACTIONS_PATH, parent = ledger_sequence)]
+pub struct TransactionsForLedgerRequest<S = NoTransactionsLedgerId> {
     /// The ID of the ledger for which the transactions are to be retrieved.
     ledger_sequence: S,
     // Indicates whether or not to include failed operations in the response.
     include_failed: Option<bool>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl TransactionsForLedgerRequest<NoTransactionsLedgerId> {
@@ -27,21 +31,21 @@ impl TransactionsForLedgerRequest<NoTransactionsLedgerId> {
     /// Sets the ledger ID for the request.
     ///
     /// # Arguments
-    /// * `ledger_id` - The ledger ID for which the transactions are to be retrieved.
+    /// * `ledger_sequence` - The sequence number of the ledger for which the transactions are to
+    ///   be retrieved.
     ///
     /// # Returns
-    /// A `TransactionsForLedgerRequest` with the specified ledger ID.
+    /// A `TransactionsForLedgerRequest` with the specified ledger ID, or an error if the ledger
+    /// sequence is `0`.
     ///
     pub fn set_ledger_sequence(
         self,
-        ledger_sequence: impl Into<String>,
+        ledger_sequence: u32,
     ) -> Result<TransactionsForLedgerRequest<TransactionsLedgerId>, String> {
         Ok(TransactionsForLedgerRequest {
-            ledger_sequence: TransactionsLedgerId(ledger_sequence.into()),
+            ledger_sequence: LedgerSequence::new(ledger_sequence)?,
             include_failed: self.include_failed,
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
@@ -63,38 +67,7 @@ impl TransactionsForLedgerRequest<TransactionsLedgerId> {
         Ok(TransactionsForLedgerRequest {
             ledger_sequence: self.ledger_sequence,
             include_failed: Some(include_failed),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
-
-impl Request for TransactionsForLedgerRequest<TransactionsLedgerId> {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-        ]
-        .build_query_parameters()
-    }
-
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL comprises paths and query parameters.
-        // Additionally, this request uses the API endpoint for `ledgers`.
-        let ledger_sequence = &self.ledger_sequence.0;
-        use crate::ledgers::LEDGERS_PATH;
-        format!(
-            "{}/{}/{}/{}{}",
-            base_url,
-            LEDGERS_PATH,
-            ledger_sequence,
-            super::TRANSACTIONS_PATH,
-            self.get_query_parameters(),
-        )
-    }
-}
@@ -1,21 +1,32 @@
-use crate::{models::*, BuildQueryParametersExt};
-use stellar_rust_sdk_derive::pagination;
+use crate::models::pagination::PageParams;
+use std::fmt;
+use stellar_rust_sdk_derive::{pagination, HorizonRequest};
 
 /// Represents the ID of a liquidity pool for which the transactions are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct TransactionsLiquidityPoolId(String);
 
+impl fmt::Display for TransactionsLiquidityPoolId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// Represents the absence of an ID of a liquidity pool for which the transactions are to be retrieved.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, PartialEq, Eq, Hash)]
 pub struct NoTransactionsLiquidityPoolId;
 
 #[pagination]
-#[derive(Default)]
-pub struct TransactionsForLiquidityPoolRequest<I> {
+#[derive(Default, PartialEq, Eq, Hash, HorizonRequest)]
+#[horizon(path = super::TRANSACTIONS_PATH, parent = liquidity_pool_id)]
+pub struct TransactionsForLiquidityPoolRequest<I = NoTransactionsLiquidityPoolId> {
     /// The ID of the liquidity pool for which the transactions are to be retrieved.
     liquidity_pool_id: I,
     // Indicates whether or not to include failed operations in the response.
     include_failed: Option<bool>,
+
+    /// The pagination parameters of the request.
+    page_params: PageParams,
 }
 
 impl TransactionsForLiquidityPoolRequest<NoTransactionsLiquidityPoolId> {
@@ -39,9 +50,7 @@ impl TransactionsForLiquidityPoolRequest<NoTransactionsLiquidityPoolId> {
         Ok(TransactionsForLiquidityPoolRequest {
             liquidity_pool_id: TransactionsLiquidityPoolId(liquidity_pool_id.into()),
             include_failed: self.include_failed,
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
@@ -63,38 +72,38 @@ impl TransactionsForLiquidityPoolRequest<TransactionsLiquidityPoolId> {
         Ok(TransactionsForLiquidityPoolRequest {
             liquidity_pool_id: self.liquidity_pool_id,
             include_failed: Some(include_failed),
-            cursor: self.cursor,
-            limit: self.limit,
-            order: self.order,
+            page_params: self.page_params,
         })
     }
 }
 
-impl Request for TransactionsForLiquidityPoolRequest<TransactionsLiquidityPoolId> {
-    fn get_query_parameters(&self) -> String {
-        vec![
-            self.cursor.as_ref().map(|c| format!("cursor={}", c)),
-            self.limit.as_ref().map(|l| format!("limit={}", l)),
-            self.order.as_ref().map(|o| format!("order={}", o)),
-            self.include_failed
-                .as_ref()
-                .map(|i| format!("include_failed={}", i)),
-        ]
-        .build_query_parameters()
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Order, Request};
+
+    #[test]
+    fn test_large_cursor() {
+        // Paging tokens of transactions do not fit in a `u32`.
+        let request = TransactionsForLiquidityPoolRequest::new()
+            .set_liquidity_pool_id(
+                "0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10",
+            )
+            .unwrap()
+            .set_cursor(458990270087168)
+            .unwrap()
+            .set_order(Order::Desc)
+            .unwrap();
+
+        assert_eq!(
+            request.build_url("https://horizon-testnet.stellar.org"),
+            "https://horizon-testnet.stellar.org/liquidity_pools/0066b15f5d0dc0be771209c33f3e4126383e58183a598eae8b3813024c6a6d10/transactions?cursor=458990270087168&order=desc"
+        );
 
-    fn build_url(&self, base_url: &str) -> String {
-        // This URL comprises paths and query parameters.
-        // Additionally, this request uses the API endpoint for `liquidity_pools`.
-        let liquidity_pool_id = &self.liquidity_pool_id.0;
-        use crate::liquidity_pools::LIQUIDITY_POOLS_PATH;
-        format!(
-            "{}/{}/{}/{}{}",
-            base_url,
-            LIQUIDITY_POOLS_PATH,
-            liquidity_pool_id,
-            super::TRANSACTIONS_PATH,
-            self.get_query_parameters(),
-        )
+        let request = request.set_cursor(u64::MAX).unwrap();
+        assert_eq!(request.page_params.cursor(), Some(u64::MAX));
+        assert!(request
+            .get_query_parameters()
+            .contains("cursor=18446744073709551615"));
     }
 }
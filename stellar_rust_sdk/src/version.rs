@@ -0,0 +1,212 @@
+use crate::error::Error;
+use crate::horizon_client::HorizonClient;
+use std::fmt;
+use std::str::FromStr;
+
+/// The version of this SDK.
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The oldest Horizon version the models of this SDK were tested against.
+pub const OLDEST_SUPPORTED_HORIZON_VERSION: HorizonVersion = HorizonVersion::new(2, 27, 0);
+
+/// The newest Horizon version the models of this SDK were tested against. Patch releases of this
+/// version are supported as well.
+pub const NEWEST_SUPPORTED_HORIZON_VERSION: HorizonVersion = HorizonVersion::new(22, 0, 0);
+
+/// Represents the version of a Horizon server, such as `22.0.0`.
+///
+/// Horizon reports its version in the `horizon_version` field of its root endpoint, optionally
+/// prefixed with a `v` and followed by a build suffix, such as `22.0.0-dc4e13ac`. The prefix and
+/// the suffix are ignored when parsing.
+///
+/// # Example
+/// ```
+/// use stellar_rs::version::HorizonVersion;
+///
+/// let version: HorizonVersion = "22.0.1-dc4e13ac".parse().unwrap();
+/// assert_eq!(version, HorizonVersion::new(22, 0, 1));
+/// assert!(version > "2.31.0".parse().unwrap());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HorizonVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl HorizonVersion {
+    /// Creates a version from its major, minor and patch numbers.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        HorizonVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Returns the major version number.
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// Returns the minor version number.
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// Returns the patch version number.
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+}
+
+impl FromStr for HorizonVersion {
+    type Err = String;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid Horizon version: {}", version);
+
+        let trimmed = version.trim();
+        let trimmed = trimmed.strip_prefix('v').unwrap_or(trimmed);
+        let core = trimmed.split(['-', '+']).next().unwrap_or_default();
+        let numbers = core
+            .split('.')
+            .map(|number| number.parse::<u32>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match numbers[..] {
+            [major, minor, patch] => Ok(HorizonVersion::new(major, minor, patch)),
+            [major, minor] => Ok(HorizonVersion::new(major, minor, 0)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for HorizonVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Describes whether the version of a Horizon server is supported by this SDK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Compatibility {
+    /// The version is within the range of versions this SDK was tested against.
+    Supported(HorizonVersion),
+    /// The version is older than
+    /// [`OLDEST_SUPPORTED_HORIZON_VERSION`](crate::version::OLDEST_SUPPORTED_HORIZON_VERSION).
+    /// Responses may lack fields the models require, so that they fail to deserialize.
+    TooOld(HorizonVersion),
+    /// The version is newer than
+    /// [`NEWEST_SUPPORTED_HORIZON_VERSION`](crate::version::NEWEST_SUPPORTED_HORIZON_VERSION).
+    /// Most responses are expected to deserialize, but fields that were added or changed since
+    /// may be missing from the models or fail to deserialize.
+    TooNew(HorizonVersion),
+}
+
+impl Compatibility {
+    /// Returns the compatibility of a Horizon version with this SDK.
+    pub fn of(version: HorizonVersion) -> Self {
+        if version < OLDEST_SUPPORTED_HORIZON_VERSION {
+            Compatibility::TooOld(version)
+        } else if (version.major, version.minor)
+            > (
+                NEWEST_SUPPORTED_HORIZON_VERSION.major,
+                NEWEST_SUPPORTED_HORIZON_VERSION.minor,
+            )
+        {
+            Compatibility::TooNew(version)
+        } else {
+            Compatibility::Supported(version)
+        }
+    }
+
+    /// Returns the version of the Horizon server.
+    pub fn horizon_version(&self) -> HorizonVersion {
+        match self {
+            Compatibility::Supported(version)
+            | Compatibility::TooOld(version)
+            | Compatibility::TooNew(version) => *version,
+        }
+    }
+
+    /// Returns whether the version is within the range of versions this SDK was tested against.
+    pub fn is_supported(&self) -> bool {
+        matches!(self, Compatibility::Supported(_))
+    }
+}
+
+/// Retrieves the version of a Horizon server from its root endpoint, and checks whether it is
+/// supported by this SDK.
+///
+/// Responses of a Horizon server that is much older or newer than the versions this SDK was
+/// tested against may fail to deserialize. Checking the compatibility when an application starts
+/// makes such failures easier to diagnose.
+///
+/// # Example
+/// ```rust
+/// # use stellar_rs::horizon_client::HorizonClient;
+/// # use stellar_rs::version::{check_compatibility, Compatibility};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org")?;
+///
+/// match check_compatibility(&horizon_client).await? {
+///     Compatibility::Supported(_) => {}
+///     compatibility => eprintln!(
+///         "Horizon {} is not supported by stellar-rs {}",
+///         compatibility.horizon_version(),
+///         stellar_rs::version::SDK_VERSION
+///     ),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+pub async fn check_compatibility(horizon_client: &HorizonClient) -> Result<Compatibility, Error> {
+    let root = horizon_client.get_root().await?;
+
+    Ok(Compatibility::of(root.parse_horizon_version()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horizon_version() {
+        assert_eq!(
+            "v2.31.0".parse::<HorizonVersion>().unwrap(),
+            HorizonVersion::new(2, 31, 0)
+        );
+        assert_eq!(
+            "22.0.0-dc4e13ac2f2d6ffdfc5196f51e3a3ed5fb9a0d1a"
+                .parse::<HorizonVersion>()
+                .unwrap()
+                .to_string(),
+            "22.0.0"
+        );
+        assert_eq!(
+            "snapshot".parse::<HorizonVersion>().unwrap_err(),
+            "Invalid Horizon version: snapshot"
+        );
+    }
+
+    #[test]
+    fn test_compatibility() {
+        let compatibility = |version: &str| Compatibility::of(version.parse().unwrap());
+
+        assert_eq!(
+            compatibility("2.26.1"),
+            Compatibility::TooOld(HorizonVersion::new(2, 26, 1))
+        );
+        assert!(compatibility("2.27.0").is_supported());
+        assert!(compatibility("22.0.3").is_supported());
+        assert_eq!(
+            compatibility("22.1.0").horizon_version(),
+            HorizonVersion::new(22, 1, 0)
+        );
+        assert!(!compatibility("23.0.0").is_supported());
+    }
+}
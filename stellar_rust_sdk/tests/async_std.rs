@@ -0,0 +1,74 @@
+//! Tests that exercise the client on the `async-std` runtime.
+//!
+//! These tests only run with the `futures-timer` feature enabled and the `tokio` feature disabled,
+//! because the timers of `tokio` need a `tokio` runtime:
+//!
+//! ```sh
+//! cargo test --no-default-features --features chrono,futures-timer --test async_std
+//! ```
+#![cfg(not(feature = "tokio"))]
+
+use async_std::io::{ReadExt, WriteExt};
+use async_std::net::TcpListener;
+use futures::StreamExt;
+use std::time::{Duration, Instant};
+use stellar_rs::horizon_client::HorizonClient;
+use stellar_rs::rate_limit::RateLimiter;
+use stellar_rs::streaming::{CancellationToken, StreamOptions};
+use stellar_rs::trades::prelude::AllTradesRequest;
+
+/// Serves a single response on a local listener, and returns the base URL of the listener.
+async fn serve(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://localhost:{}", listener.local_addr().unwrap().port());
+
+    async_std::task::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buffer = [0; 4096];
+        let _ = stream.read(&mut buffer).await.unwrap();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+    });
+
+    base_url
+}
+
+#[async_std::test]
+async fn test_rate_limiter_waits_for_tokens() {
+    let rate_limiter = RateLimiter::new(1, 20).unwrap();
+
+    let start = Instant::now();
+    rate_limiter.acquire().await;
+    rate_limiter.acquire().await;
+    rate_limiter.acquire().await;
+    assert!(start.elapsed() >= Duration::from_millis(90));
+}
+
+#[async_std::test]
+async fn test_stream_shuts_down() {
+    let horizon_client = HorizonClient::new("https://horizon-testnet.stellar.org").unwrap();
+    let shutdown = CancellationToken::new();
+    shutdown.cancel();
+
+    let options = StreamOptions::new().set_shutdown_token(shutdown);
+    let trades = horizon_client.stream_trades_with_options(&AllTradesRequest::new(), options);
+    futures::pin_mut!(trades);
+    assert!(trades.next().await.is_none());
+}
+
+#[async_std::test]
+async fn test_request() {
+    let base_url = serve(r#"{"database_connected":true,"core_up":true,"core_synced":true}"#).await;
+    let horizon_client = HorizonClient::new(base_url)
+        .unwrap()
+        .set_timeout(Duration::from_secs(5))
+        .unwrap();
+
+    let health = horizon_client.get_health().await.unwrap();
+    assert!(health.is_healthy());
+    assert_eq!(horizon_client.connection_stats().connections(), &1);
+}
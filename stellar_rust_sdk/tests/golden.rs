@@ -0,0 +1,315 @@
+//! Golden tests for the response models.
+//!
+//! Every file in `tests/fixtures` is a response captured from Horizon, organized by module. Each
+//! test deserializes a fixture into its response model, serializes the model again, and asserts
+//! that no field of the fixture was lost along the way. A failing test usually means that Horizon
+//! returns a field that the model does not declare yet.
+//!
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+use stellar_rs::accounts::prelude::*;
+use stellar_rs::assets::prelude::*;
+use stellar_rs::claimable_balances::prelude::*;
+use stellar_rs::effects::prelude::*;
+use stellar_rs::fee_stats::prelude::*;
+use stellar_rs::ledgers::prelude::*;
+use stellar_rs::liquidity_pools::prelude::*;
+use stellar_rs::models::prelude::AssetType;
+use stellar_rs::models::Response;
+use stellar_rs::network::Network;
+use stellar_rs::offers::prelude::*;
+use stellar_rs::operations::prelude::*;
+use stellar_rs::order_book::prelude::*;
+use stellar_rs::paths::prelude::*;
+use stellar_rs::payments::prelude::*;
+use stellar_rs::root::prelude::*;
+use stellar_rs::trade_aggregations::prelude::*;
+use stellar_rs::trades::prelude::*;
+use stellar_rs::transactions::prelude::*;
+use stellar_rs::version::HorizonVersion;
+
+/// Deserializes the fixture at the given path, relative to `tests/fixtures`, into `T`, and asserts
+/// that serializing the result retains every field of the fixture.
+fn assert_golden<T: Response + Serialize>(fixture: &str) -> T {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixture);
+    let json = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+
+    let response = T::from_json(json.clone())
+        .unwrap_or_else(|e| panic!("failed to deserialize {}: {}", fixture, e));
+
+    let expected: Value = serde_json::from_str(&json).unwrap();
+    let actual = serde_json::to_value(&response).unwrap();
+    let mut lost = Vec::new();
+    collect_lost_fields(&expected, &actual, "$", &mut lost);
+    assert!(
+        lost.is_empty(),
+        "fields of {} were lost during deserialization: {}",
+        fixture,
+        lost.join(", ")
+    );
+
+    response
+}
+
+/// Collects the paths of the fields in `expected` that are missing from `actual`. Fields that are
+/// `null` in `expected` may be omitted from `actual`.
+fn collect_lost_fields(expected: &Value, actual: &Value, path: &str, lost: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let field_path = format!("{}.{}", path, key);
+                match actual.get(key) {
+                    Some(actual) => collect_lost_fields(value, actual, &field_path, lost),
+                    None if value.is_null() => {}
+                    None => lost.push(field_path),
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            if expected.len() != actual.len() {
+                lost.push(path.to_string());
+            }
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                collect_lost_fields(expected, actual, &format!("{}[{}]", path, index), lost);
+            }
+        }
+        (Value::Object(_), _) | (Value::Array(_), _) => lost.push(path.to_string()),
+        _ => {}
+    }
+}
+
+#[test]
+fn test_single_account() {
+    let account = assert_golden::<Account>("accounts/single_account.json");
+    assert_eq!(account.balances().len(), 3);
+
+    // The fixture holds USDC, liquidity pool shares and the native asset.
+    let flags: Vec<_> = account.balances().iter().map(|b| b.flags()).collect();
+    assert!(*flags[0].unwrap().is_authorized());
+    assert!(!*flags[0].unwrap().is_clawback_enabled());
+    assert!(!flags[0].unwrap().is_frozen());
+    assert!(flags[1].unwrap().is_frozen());
+    assert_eq!(flags[2], None);
+    assert!(account.balances()[1].liquidity_pool_id().is_some());
+}
+
+#[test]
+fn test_account_data() {
+    let data = assert_golden::<AccountDataResponse>("accounts/account_data.json");
+    assert_eq!(data.decoded_value().unwrap(), b"true");
+}
+
+#[test]
+fn test_all_assets() {
+    assert_golden::<AllAssetsResponse>("assets/all_assets.json");
+}
+
+#[test]
+fn test_single_claimable_balance() {
+    assert_golden::<ClaimableBalance>("claimable_balances/single_claimable_balance.json");
+}
+
+#[test]
+fn test_effects_for_account() {
+    let effects = assert_golden::<EffectsResponse>("effects/effects_for_account.json");
+    assert_eq!(effects.embedded().records().len(), 3);
+}
+
+#[test]
+fn test_fee_stats() {
+    assert_golden::<FeeStatsResponse>("fee_stats/fee_stats.json");
+}
+
+#[test]
+fn test_single_ledger() {
+    let ledger = assert_golden::<Ledger>("ledgers/single_ledger.json");
+    assert_eq!(ledger.sequence().value(), 1049845);
+}
+
+#[test]
+fn test_single_liquidity_pool() {
+    assert_golden::<LiquidityPool>("liquidity_pools/single_liquidity_pool.json");
+}
+
+#[test]
+fn test_single_offer() {
+    assert_golden::<OfferResponse>("offers/single_offer.json");
+}
+
+#[test]
+fn test_single_operation() {
+    assert_golden::<Operation>("operations/single_operation.json");
+}
+
+#[test]
+fn test_payment_operation_with_muxed_accounts() {
+    let operation = assert_golden::<Operation>("operations/muxed_payment.json");
+    let muxed_account = operation.source_muxed_account().unwrap().unwrap();
+    assert_eq!(
+        muxed_account.account_id().as_str(),
+        operation.source_account()
+    );
+    assert_eq!(
+        muxed_account.id().to_string(),
+        operation.source_account_muxed_id().clone().unwrap()
+    );
+
+    let OperationDetails::Payment(payment) = operation.details() else {
+        panic!("expected a payment operation");
+    };
+    assert!(payment.to_muxed().is_some());
+    assert_eq!(payment.to_muxed_id().as_deref(), Some("123"));
+}
+
+#[test]
+fn test_path_payment_operation() {
+    let operation = assert_golden::<Operation>("operations/path_payment.json");
+    assert!(matches!(
+        operation.details(),
+        OperationDetails::PathPaymentStrictSend(_)
+    ));
+    let path_payment = operation.path_payment().unwrap();
+    assert_eq!(path_payment.destination_min().as_deref(), Some("1.0000000"));
+    assert_eq!(path_payment.source_max(), &None);
+    assert!(path_payment.path().is_empty());
+    assert!(matches!(
+        operation.source_asset(),
+        Some(Ok(AssetType::Alphanumeric4(_)))
+    ));
+    assert_eq!(operation.destination_asset(), Some(Ok(AssetType::Native)));
+    assert!(operation.links().trades().is_some());
+}
+
+#[test]
+fn test_operations_with_transactions() {
+    let operations =
+        assert_golden::<OperationResponse>("operations/operations_with_transactions.json");
+    let operation = &operations.embedded().records()[0];
+    let transaction = operation.transaction().as_ref().unwrap();
+    assert_eq!(transaction.hash(), operation.transaction_hash().as_str());
+}
+
+#[test]
+fn test_order_book_details() {
+    let order_book = assert_golden::<DetailsResponse>("order_book/details.json");
+    assert_eq!(order_book.bids().len(), 2);
+    assert_eq!(order_book.asks().len(), 2);
+    assert_eq!(order_book.asset_pair().base(), order_book.base());
+}
+
+#[test]
+fn test_strict_send_paths() {
+    assert_golden::<PathsResponse>("paths/strict_send_paths.json");
+}
+
+#[test]
+fn test_payments_with_muxed_accounts() {
+    let payments = assert_golden::<PaymentsResponse>("payments/payments_muxed.json");
+    assert_eq!(payments.embedded().records().len(), 2);
+}
+
+#[test]
+fn test_root() {
+    let root = assert_golden::<RootResponse>("root/root.json");
+    assert_eq!(root.network(), Some(Network::Testnet));
+    assert_eq!(
+        root.parse_horizon_version().unwrap(),
+        HorizonVersion::new(22, 0, 0)
+    );
+    assert!(root.supports_protocol(22));
+    assert!(!root.supports_protocol(23));
+    assert_eq!(root.ingestion_lag(), 0);
+}
+
+#[test]
+fn test_trade_aggregations() {
+    assert_golden::<AllTradeAggregationsResponse>("trade_aggregations/trade_aggregations.json");
+}
+
+#[test]
+fn test_liquidity_pool_and_orderbook_trades() {
+    let trades = assert_golden::<AllTradesResponse>("trades/all_trades.json");
+    assert_eq!(trades.embedded().records().len(), 2);
+
+    let orderbook_trade = &trades.embedded().records()[1];
+    assert_eq!(
+        orderbook_trade.base_asset().to_asset_type(),
+        Some(AssetType::Native)
+    );
+    assert_eq!(
+        orderbook_trade
+            .counter_asset()
+            .to_asset_type()
+            .unwrap()
+            .to_string(),
+        "MBAUDD:GD2YNRNSJ3EOFJAYGLKGKSIOLX2VU3UFDW3YFNOYMAHB26AEHSZBJU4U"
+    );
+}
+
+#[test]
+fn test_single_transaction() {
+    let transaction = assert_golden::<TransactionResponse>("transactions/single_transaction.json");
+    assert!(transaction.successful());
+
+    let envelope = transaction.decoded_envelope_xdr().unwrap();
+    let stellar_xdr::curr::TransactionEnvelope::Tx(envelope) = envelope else {
+        panic!("expected a v1 transaction envelope");
+    };
+    assert_eq!(
+        envelope.tx.operations.len() as i64,
+        *transaction.operation_count()
+    );
+    assert_eq!(
+        transaction.decoded_result_xdr().unwrap().fee_charged,
+        transaction.fee_charged().parse::<i64>().unwrap()
+    );
+    assert!(transaction.decoded_result_meta_xdr().is_ok());
+    assert!(transaction.decoded_fee_meta_xdr().is_ok());
+}
+
+#[test]
+fn test_failed_transaction() {
+    let transaction = assert_golden::<TransactionResponse>("transactions/failed_transaction.json");
+    assert!(!transaction.successful());
+}
+
+#[test]
+fn test_transaction_with_muxed_accounts() {
+    let transaction = assert_golden::<TransactionResponse>("transactions/muxed_transaction.json");
+    let muxed_account = transaction.muxed_account().unwrap().unwrap();
+    assert_eq!(muxed_account.id(), 123);
+    assert_eq!(
+        muxed_account.account_id().as_str(),
+        transaction.source_account()
+    );
+    assert_eq!(
+        transaction.fee_muxed_account().unwrap().unwrap(),
+        muxed_account
+    );
+}
+
+#[test]
+fn test_fee_bump_transaction() {
+    let transaction =
+        assert_golden::<TransactionResponse>("transactions/fee_bump_transaction.json");
+
+    let fee_bump = transaction.fee_bump_transaction().as_ref().unwrap();
+    assert_eq!(fee_bump.hash(), transaction.hash());
+    let inner = transaction.inner_transaction().as_ref().unwrap();
+    assert_ne!(inner.hash(), transaction.hash());
+    assert_eq!(inner.max_fee(), "100");
+
+    let envelope = transaction.decoded_envelope_xdr().unwrap();
+    let stellar_xdr::curr::TransactionEnvelope::TxFeeBump(envelope) = envelope else {
+        panic!("expected a fee bump transaction envelope");
+    };
+    assert_eq!(
+        envelope.tx.fee,
+        transaction.max_fee().parse::<i64>().unwrap()
+    );
+    assert_eq!(envelope.signatures.len(), fee_bump.signatures().len());
+}
@@ -1,55 +1,151 @@
 extern crate proc_macro2;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse::Nothing, parse_macro_input, Field, Fields, ItemStruct};
+use syn::{
+    parse::Nothing, parse_macro_input, parse_quote, spanned::Spanned, DeriveInput, Expr, Fields,
+    Ident, ItemStruct, LitStr, Type,
+};
 
 /// The procedural attribute macro to add pagination functionality to request structs.
 ///
-/// This macro automatically injects pagination-related fields and methods into a struct
-/// to facilitate paginated API requests. Specifically, it adds three optional fields
-/// and three methods:
+/// Paginated requests embed their pagination parameters in a field named `page_params`, of type
+/// `PageParams`, which holds the cursor, limit and order of the request. This macro implements
+/// the `Paginatable` trait for the struct, which exposes that field to code that pages through
+/// collections, and adds three getters and five setters that delegate to it:
 ///
-/// - `cursor`: An `Option<u32>` field that represents the pagination cursor. The cursor
-///   is used to track the current position in a paginated dataset. The `set_cursor` method
-///   allows setting this field, with a validation that ensures the cursor is greater than
-///   or equal to 1.
+/// - `cursor`, `limit` and `order`: Return the cursor, limit and order of the request, so that
+///   the pagination parameters can be read without going through the `Paginatable` trait.
 ///
-/// - `limit`: An `Option<u8>` field that specifies the maximum number of items to retrieve
-///   in a single page. The `set_limit` method allows setting this field, ensuring that the
-///   limit is within a valid range (between 1 and 200).
+/// - `set_cursor`: Sets the cursor, which points to the current location in the collection of
+///   responses and is pulled from the `paging_token` value of a record. The cursor must be
+///   greater than or equal to 1.
 ///
-/// - `order`: An `Option<Order>` field that defines the sort order of the paginated results.
-///   The `set_order` method allows setting this field without additional validation, as the
-///   sort order is context-dependent.
+/// - `set_limit`: Sets the maximum number of records to retrieve in a single page, which must be
+///   between 1 and 200.
+///
+/// - `set_max_limit`: Sets the limit to 200, the largest page size Horizon allows.
+///
+/// - `set_order`: Sets the sort order of the records, without additional validation, as the sort
+///   order is context-dependent.
+///
+/// - `reset_pagination`: Clears the cursor, limit and order again, so that a request can be
+///   reused for another query without carrying over the cursor of a previous page.
+///
+/// The getters and setters are documented, so that they show up in the documentation of the
+/// request structs like any other member. The generated code refers to `crate::models::Order` and
+/// `crate::models::pagination`, so it is meant for the request structs of the SDK itself.
+///
+/// Structs with generic parameters, such as requests that track their required fields with
+/// typestate parameters, get the getters, setters and trait implementation for every state. The
+/// setters return `Self`, so they can be called before and after a typestate transition, and the
+/// pagination parameters carry over as long as the transition moves the `page_params` field to
+/// the new state.
 ///
 /// # Usage
 ///
-/// Apply the `#[pagination]` attribute to a struct to automatically add pagination
-/// functionality.
+/// Add a `page_params` field to the struct, and apply the `#[pagination]` attribute to it.
+///
+/// ```
+/// # use stellar_rust_sdk_derive::pagination;
+/// # mod models {
+/// #     pub enum Order { Asc, Desc }
+/// #     pub mod pagination {
+/// #         #[derive(Default)]
+/// #         pub struct PageParams { pub cursor: Option<u64>, pub limit: Option<u8> }
+/// #         impl PageParams {
+/// #             pub fn cursor(&self) -> Option<u64> { self.cursor }
+/// #             pub fn limit(&self) -> Option<u8> { self.limit }
+/// #             pub fn order(&self) -> Option<&super::Order> { None }
+/// #             pub fn set_cursor(&mut self, cursor: u64) -> Result<(), String> {
+/// #                 self.cursor = Some(cursor);
+/// #                 Ok(())
+/// #             }
+/// #             pub fn set_limit(&mut self, limit: u8) -> Result<(), String> {
+/// #                 self.limit = Some(limit);
+/// #                 Ok(())
+/// #             }
+/// #             pub fn set_max_limit(&mut self) { self.limit = Some(200); }
+/// #             pub fn set_order(&mut self, _order: super::Order) {}
+/// #             pub fn reset(&mut self) { *self = PageParams::default(); }
+/// #         }
+/// #         pub trait Paginatable {
+/// #             fn page_params(&self) -> &PageParams;
+/// #             fn page_params_mut(&mut self) -> &mut PageParams;
+/// #         }
+/// #     }
+/// # }
+/// # use models::pagination::{PageParams, Paginatable};
+/// #[pagination]
+/// #[derive(Default)]
+/// pub struct ExampleRequest {
+///     account_id: Option<String>,
+///     page_params: PageParams,
+/// }
+///
+/// # fn main() {
+/// let request = ExampleRequest::default().set_limit(20).unwrap();
+/// assert_eq!(request.limit(), Some(20));
+/// assert_eq!(request.page_params().limit, Some(20));
+///
+/// let request = ExampleRequest::default().set_max_limit();
+/// assert_eq!(request.limit(), Some(200));
+///
+/// let mut request = request.set_cursor(42).unwrap().reset_pagination();
+/// request.page_params_mut().cursor = Some(43);
+/// assert_eq!((request.page_params.cursor, request.page_params.limit), (Some(43), None));
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// The macro emits a compile error when it is applied to a struct without named fields, such as
+/// a tuple struct or a unit struct:
+///
+/// ```compile_fail
+/// # use stellar_rust_sdk_derive::pagination;
+/// #[pagination]
+/// pub struct ExampleRequest(String);
+/// ```
+///
+/// It also emits a compile error when the struct does not contain a `page_params` field, such as
+/// a struct that declares the pagination parameters as separate fields:
+///
+/// ```compile_fail
+/// # use stellar_rust_sdk_derive::pagination;
+/// #[pagination]
+/// pub struct ExampleRequest {
+///     limit: Option<u32>,
+/// }
+/// ```
 ///
 #[proc_macro_attribute]
 pub fn pagination(args: TokenStream, input: TokenStream) -> TokenStream {
-    let mut input = parse_macro_input!(input as ItemStruct);
+    let input = parse_macro_input!(input as ItemStruct);
 
     // No arguments should be passed, but if they are, parse them as `Nothing` to prevent misuse.
     let _ = parse_macro_input!(args as Nothing);
 
-    // Create required fields to be added to the struct.
-    let cursor_field: Field = syn::parse_quote! {
-        pub cursor: Option<u32>
-    };
-    let limit_field: Field = syn::parse_quote! {
-        pub limit: Option<u8>
-    };
-    let order_field: Field = syn::parse_quote! {
-        pub order: Option<Order>
+    // The struct must have named fields, one of which holds the pagination parameters.
+    let Fields::Named(ref fields) = input.fields else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "`#[pagination]` can only be applied to structs with named fields",
+        )
+        .to_compile_error()
+        .into();
     };
-
-    // Add the fields to the struct.
-    if let Fields::Named(ref mut fields) = input.fields {
-        fields.named.push(cursor_field);
-        fields.named.push(limit_field);
-        fields.named.push(order_field);
+    if !fields.named.iter().any(|field| {
+        field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| ident == "page_params")
+    }) {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "`#[pagination]` requires a `page_params: PageParams` field",
+        )
+        .to_compile_error()
+        .into();
     }
 
     let struct_name = &input.ident;
@@ -61,29 +157,456 @@ pub fn pagination(args: TokenStream, input: TokenStream) -> TokenStream {
     let expanded = quote! {
         #input
         impl #impl_generics #struct_name #type_generics #where_clause {
-            pub fn set_cursor(self, cursor: u32) -> Result<Self, String> {
-                // Always accept the cursor since it's non-optional in the setter
-                if cursor < 1 {
-                    return Err("Cursor must be greater than or equal to 1.".to_string());
-                }
+            /// Returns the cursor for pagination, if it is set.
+            pub fn cursor(&self) -> Option<u64> {
+                self.page_params.cursor()
+            }
 
-                Ok(Self { cursor: Some(cursor), ..self })
+            /// Returns the maximum number of records to return, if it is set. Horizon defaults
+            /// to 10 records.
+            pub fn limit(&self) -> Option<u8> {
+                self.page_params.limit()
             }
 
-            pub fn set_limit(self, limit: u8) -> Result<Self, String> {
-                // Validate limit if necessary
-                if !(1..=200).contains(&limit) {
-                    Err("Limit must be between 1 and 200.".to_string())
-                } else {
-                    Ok(Self { limit: Some(limit), ..self })
-                }
+            /// Returns the order of the returned records, if it is set. Horizon defaults to
+            /// ascending order.
+            pub fn order(&self) -> Option<&crate::models::Order> {
+                self.page_params.order()
+            }
+
+            /// Sets the cursor for pagination.
+            ///
+            /// # Arguments
+            /// * `cursor` - A `u64` value pointing to a specific location in a collection of
+            ///   responses, typically the `paging_token` of a record.
+            ///
+            /// # Returns
+            /// The updated request, or an error if the cursor is less than 1.
+            ///
+            pub fn set_cursor(mut self, cursor: u64) -> Result<Self, String> {
+                self.page_params.set_cursor(cursor)?;
+                Ok(self)
+            }
+
+            /// Sets the maximum number of records to return.
+            ///
+            /// # Arguments
+            /// * `limit` - A `u8` value specifying the maximum number of records, ranging from
+            ///   1 to 200.
+            ///
+            /// # Returns
+            /// The updated request, or an error if the limit is out of range.
+            ///
+            pub fn set_limit(mut self, limit: u8) -> Result<Self, String> {
+                self.page_params.set_limit(limit)?;
+                Ok(self)
             }
 
-            pub fn set_order(self, order: Order) -> Result<Self, String> {
+            /// Sets the maximum number of records to return to 200, which is the largest page
+            /// size Horizon allows.
+            ///
+            /// Requesting the largest pages takes the fewest requests to page through a collection.
+            ///
+            pub fn set_max_limit(mut self) -> Self {
+                self.page_params.set_max_limit();
+                self
+            }
+
+            /// Sets the order of the returned records.
+            ///
+            /// # Arguments
+            /// * `order` - The order of the records, either ascending or descending.
+            ///
+            pub fn set_order(mut self, order: crate::models::Order) -> Result<Self, String> {
                 // No validation required for setting the order in this context
-                Ok(Self { order: Some(order), ..self })
+                self.page_params.set_order(order);
+                Ok(self)
+            }
+
+            /// Clears the cursor, limit and order, so that the request starts at the first page
+            /// with the default page size and order again.
+            ///
+            /// This is useful when a request is reused for another query, since the cursor of a
+            /// page is only meaningful for the query that returned it.
+            ///
+            pub fn reset_pagination(mut self) -> Self {
+                self.page_params.reset();
+                self
+            }
+        }
+
+        impl #impl_generics crate::models::pagination::Paginatable for #struct_name #type_generics #where_clause {
+            fn page_params(&self) -> &crate::models::pagination::PageParams {
+                &self.page_params
+            }
+
+            fn page_params_mut(&mut self) -> &mut crate::models::pagination::PageParams {
+                &mut self.page_params
             }
         }
     };
     TokenStream::from(expanded)
 }
+
+/// The derive macro to implement the `Request` trait for request structs that consist of a path
+/// and query parameters.
+///
+/// Most requests to Horizon follow the same pattern: every field of the request becomes a query
+/// parameter, and the URL is the base URL, followed by the path of the endpoint and the query
+/// string. Some endpoints are nested under another resource, such as
+/// `/accounts/{account_id}/payments`, in which case one of the fields becomes part of the path
+/// instead. This macro generates `get_query_parameters` and `build_url` for such requests, so
+/// that every request builds its URL the same way.
+///
+/// The generated implementation refers to `crate::models::Request` and
+/// `crate::BuildQueryParametersExt`, so it is meant for the request structs of the SDK itself.
+///
+/// # Attributes
+///
+/// The struct takes a `#[horizon(...)]` attribute with the following arguments:
+///
+/// - `path`: The path of the endpoint, such as `"payments"` or a constant such as
+///   `PAYMENTS_PATH`. Required.
+/// - `parent`: The field that identifies the resource the endpoint is nested under. Its value is
+///   placed in the path instead of the query string, and left empty if it is not set. A field
+///   that is not an `Option` must implement `Display`. For requests that track whether the
+///   parent is set with a typestate parameter, `Request` is therefore only implemented for the
+///   state in which it is set, so that a request without its parent can not be sent.
+/// - `parent_path`: The path of the resource the endpoint is nested under. Defaults to the name
+///   of the `parent` field without its `_id`, `_hash` or `_sequence` suffix, in plural, so that
+///   `account_id` results in `accounts`.
+///
+/// The fields take an optional `#[horizon(...)]` attribute with the following arguments:
+///
+/// - `rename`: The name of the query parameter, which defaults to the name of the field.
+/// - `skip`: Leaves the field out of the query string.
+/// - `asset`: Flattens an `AssetType` into the `{prefix}_asset_type`, `{prefix}_asset_code` and
+///   `{prefix}_asset_issuer` query parameters. The prefix defaults to the name of the field
+///   without its `_asset` suffix, so that `selling_asset` results in `selling_asset_type`, and can
+///   be set with `rename`.
+/// - `assets`: Writes a list of `AssetType`s as a single query parameter, in which the assets are
+///   written in their canonical form and separated by commas, such as
+///   `reserves=native%2CUSDC%3AG...`. The parameter is left out if the list is empty.
+///
+/// Query parameters are written in the order of the fields, and only if they are set. Fields of
+/// type `Option` are set if they are `Some`, other fields are always set. A field of type
+/// `PageParams` results in the `cursor`, `limit` and `order` query parameters, so paginated
+/// requests declare their `page_params` field last, to add them as the last query parameters.
+///
+/// # Usage
+///
+/// ```
+/// # use stellar_rust_sdk_derive::{pagination, HorizonRequest};
+/// # mod models {
+/// #     pub enum Order { Asc, Desc }
+/// #     impl std::fmt::Display for Order {
+/// #         fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+/// #             f.write_str(match self { Order::Asc => "asc", Order::Desc => "desc" })
+/// #         }
+/// #     }
+/// #     pub trait Request {
+/// #         fn get_query_parameters(&self) -> String;
+/// #         fn build_url(&self, base_url: &str) -> String;
+/// #     }
+/// #     pub mod pagination {
+/// #         #[derive(Default)]
+/// #         pub struct PageParams { limit: Option<u8> }
+/// #         impl PageParams {
+/// #             pub fn cursor(&self) -> Option<u64> { None }
+/// #             pub fn limit(&self) -> Option<u8> { self.limit }
+/// #             pub fn order(&self) -> Option<&super::Order> { None }
+/// #             pub fn set_cursor(&mut self, _cursor: u64) -> Result<(), String> { Ok(()) }
+/// #             pub fn set_limit(&mut self, limit: u8) -> Result<(), String> {
+/// #                 self.limit = Some(limit);
+/// #                 Ok(())
+/// #             }
+/// #             pub fn set_max_limit(&mut self) {}
+/// #             pub fn set_order(&mut self, _order: super::Order) {}
+/// #             pub fn reset(&mut self) {}
+/// #         }
+/// #         pub trait Paginatable {
+/// #             fn page_params(&self) -> &PageParams;
+/// #             fn page_params_mut(&mut self) -> &mut PageParams;
+/// #         }
+/// #     }
+/// # }
+/// # pub(crate) trait BuildQueryParametersExt {
+/// #     fn build_query_parameters(self) -> String;
+/// # }
+/// # impl<const N: usize> BuildQueryParametersExt for [Option<String>; N] {
+/// #     fn build_query_parameters(self) -> String {
+/// #         let parameters: Vec<String> = self.into_iter().flatten().collect();
+/// #         match parameters.is_empty() {
+/// #             true => String::new(),
+/// #             false => format!("?{}", parameters.join("&")),
+/// #         }
+/// #     }
+/// # }
+/// # use models::{pagination::PageParams, Request};
+/// #[pagination]
+/// #[derive(Default, HorizonRequest)]
+/// #[horizon(path = "offers", parent = account_id)]
+/// pub struct OffersForAccountRequest {
+///     account_id: Option<String>,
+///     #[horizon(rename = "selling")]
+///     selling_asset: Option<String>,
+///     page_params: PageParams,
+/// }
+///
+/// # fn main() {
+/// let request = OffersForAccountRequest {
+///     account_id: Some("GABC".to_string()),
+///     selling_asset: Some("native".to_string()),
+///     ..Default::default()
+/// }
+/// .set_limit(2)
+/// .unwrap();
+///
+/// assert_eq!(
+///     request.build_url("https://horizon.stellar.org"),
+///     "https://horizon.stellar.org/accounts/GABC/offers?selling=native&limit=2"
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// The macro emits a compile error when it is applied to anything other than a struct with named
+/// fields, when the `path` argument is missing, when the `parent` argument does not name a field
+/// of the struct, or when a field is marked as both `asset` and `assets`:
+///
+/// ```compile_fail
+/// # use stellar_rust_sdk_derive::HorizonRequest;
+/// #[derive(HorizonRequest)]
+/// #[horizon(path = "payments", parent = account_id)]
+/// pub struct ExampleRequest {
+///     transaction_hash: Option<String>,
+/// }
+/// ```
+///
+#[proc_macro_derive(HorizonRequest, attributes(horizon))]
+pub fn derive_horizon_request(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_horizon_request(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_horizon_request(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_name = &input.ident;
+
+    // Parse the arguments of the `#[horizon(...)]` attribute of the struct.
+    let mut path: Option<Expr> = None;
+    let mut parent: Option<Ident> = None;
+    let mut parent_path: Option<Expr> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("horizon"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("path") {
+                path = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("parent") {
+                parent = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("parent_path") {
+                parent_path = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `path`, `parent` or `parent_path`"));
+            }
+            Ok(())
+        })?;
+    }
+    let Some(path) = path else {
+        return Err(syn::Error::new_spanned(
+            struct_name,
+            "`#[derive(HorizonRequest)]` requires a `#[horizon(path = ...)]` attribute",
+        ));
+    };
+
+    let syn::Data::Struct(syn::DataStruct {
+        fields: Fields::Named(ref fields),
+        ..
+    }) = input.data
+    else {
+        return Err(syn::Error::new_spanned(
+            struct_name,
+            "`#[derive(HorizonRequest)]` can only be applied to structs with named fields",
+        ));
+    };
+
+    // Collect a query parameter for every field, except for the parent and skipped fields.
+    let mut parameters = Vec::new();
+    let mut parent_field = None;
+    for field in &fields.named {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        if parent.as_ref() == Some(ident) {
+            parent_field = Some(field);
+            continue;
+        }
+
+        let mut name: Option<LitStr> = None;
+        let mut skip = false;
+        let mut asset: Option<proc_macro2::Span> = None;
+        let mut assets: Option<proc_macro2::Span> = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("horizon"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("asset") {
+                    asset = Some(meta.path.span());
+                } else if meta.path.is_ident("assets") {
+                    assets = Some(meta.path.span());
+                } else {
+                    return Err(meta.error("expected `rename`, `skip`, `asset` or `assets`"));
+                }
+                Ok(())
+            })?;
+        }
+        if skip {
+            continue;
+        }
+        if let (Some(_), Some(span)) = (asset, assets) {
+            return Err(syn::Error::new(
+                span,
+                "a field cannot be marked as both `asset` and `assets`",
+            ));
+        }
+
+        if is_page_params(&field.ty) {
+            parameters
+                .push(quote! { self.#ident.cursor().map(|cursor| format!("cursor={}", cursor)) });
+            parameters.push(quote! { self.#ident.limit().map(|limit| format!("limit={}", limit)) });
+            parameters.push(quote! { self.#ident.order().map(|order| format!("order={}", order)) });
+            continue;
+        }
+
+        if asset.is_some() {
+            let field_name = ident.to_string();
+            let prefix = name.unwrap_or_else(|| {
+                let prefix = field_name.strip_suffix("_asset").unwrap_or(&field_name);
+                LitStr::new(prefix, ident.span())
+            });
+            parameters.push(if is_option(&field.ty) {
+                quote! { self.#ident.as_ref().map(|asset| asset.to_query_parameters(#prefix)) }
+            } else {
+                quote! { Some(self.#ident.to_query_parameters(#prefix)) }
+            });
+            continue;
+        }
+
+        let name = name.unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        if assets.is_some() {
+            parameters.push(if is_option(&field.ty) {
+                quote! {
+                    self.#ident
+                        .as_deref()
+                        .map(|assets| crate::models::prelude::asset_list_parameter(#name, assets))
+                }
+            } else {
+                quote! { Some(crate::models::prelude::asset_list_parameter(#name, &self.#ident)) }
+            });
+            continue;
+        }
+
+        parameters.push(if is_option(&field.ty) {
+            quote! { self.#ident.as_ref().map(|value| format!("{}={}", #name, value)) }
+        } else {
+            quote! { Some(format!("{}={}", #name, self.#ident)) }
+        });
+    }
+    let parameter_count = parameters.len();
+
+    let mut generics = input.generics.clone();
+    let build_url = match parent {
+        Some(parent) => {
+            let Some(parent_field) = parent_field else {
+                return Err(syn::Error::new_spanned(
+                    &parent,
+                    format!("`{}` does not have a field named `{}`", struct_name, parent),
+                ));
+            };
+            let parent_path = match parent_path {
+                Some(parent_path) => quote! { #parent_path },
+                None => {
+                    let name = parent.to_string();
+                    let resource = ["_id", "_hash", "_sequence"]
+                        .iter()
+                        .find_map(|suffix| name.strip_suffix(suffix))
+                        .unwrap_or(&name);
+                    let resource = LitStr::new(&format!("{}s", resource), parent.span());
+                    quote! { #resource }
+                }
+            };
+            let parent_value = if is_option(&parent_field.ty) {
+                quote! { self.#parent.as_ref().map(ToString::to_string).unwrap_or_default() }
+            } else {
+                let ty = &parent_field.ty;
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote! { #ty: ::std::fmt::Display });
+                quote! { self.#parent }
+            };
+            quote! {
+                format!(
+                    "{}/{}/{}/{}{}",
+                    base_url,
+                    #parent_path,
+                    #parent_value,
+                    #path,
+                    self.get_query_parameters()
+                )
+            }
+        }
+        None => quote! {
+            format!("{}/{}{}", base_url, #path, self.get_query_parameters())
+        },
+    };
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics crate::models::Request for #struct_name #type_generics #where_clause {
+            fn get_query_parameters(&self) -> String {
+                use crate::BuildQueryParametersExt;
+                let parameters: [Option<String>; #parameter_count] = [#(#parameters),*];
+                parameters.build_query_parameters()
+            }
+
+            fn build_url(&self, base_url: &str) -> String {
+                #build_url
+            }
+        }
+    })
+}
+
+/// Returns whether a type is an `Option`, judging by the last segment of its path.
+fn is_option(ty: &Type) -> bool {
+    last_segment_is(ty, "Option")
+}
+
+/// Returns whether a type is `PageParams`, judging by the last segment of its path.
+fn is_page_params(ty: &Type) -> bool {
+    last_segment_is(ty, "PageParams")
+}
+
+/// Returns whether the last segment of the path of a type is the given identifier.
+fn last_segment_is(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name),
+        _ => false,
+    }
+}